@@ -0,0 +1,273 @@
+//! # API Keys Controller
+//!
+//! Handles HTTP endpoints for API key operations including:
+//! - Listing API keys
+//! - Creating API keys
+//! - Revoking API keys
+
+use crate::{
+    jobs::JobProducerTrait,
+    models::{
+        ApiError, ApiKeyCreateRequest, ApiKeyCreateResponse, ApiKeyRepoModel, ApiKeyResponse,
+        ApiResponse, NetworkRepoModel, NotificationRepoModel, PaginationMeta, PaginationQuery,
+        RelayerPoolRepoModel, RelayerRepoModel, RepositoryError, SecretString, SignerRepoModel,
+        ThinDataAppState, TransactionRepoModel,
+    },
+    repositories::{
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        TransactionCounterTrait, TransactionRepository,
+    },
+    utils::generate_api_key_value,
+};
+use actix_web::HttpResponse;
+use eyre::Result;
+
+/// Lists all API keys with pagination support.
+///
+/// # Arguments
+///
+/// * `query` - The pagination query parameters.
+/// * `state` - The application state containing the API key repository.
+///
+/// # Returns
+///
+/// A paginated list of API keys, without their values.
+pub async fn list_api_keys<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    query: PaginationQuery,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let api_keys = state.api_key_repository.list_paginated(query).await?;
+
+    let mapped_api_keys: Vec<ApiKeyResponse> =
+        api_keys.items.into_iter().map(|k| k.into()).collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        mapped_api_keys,
+        PaginationMeta {
+            total_items: api_keys.total,
+            current_page: api_keys.page,
+            per_page: api_keys.per_page,
+        },
+    )))
+}
+
+/// Creates a new API key.
+///
+/// # Arguments
+///
+/// * `request` - The API key creation request.
+/// * `state` - The application state containing the API key repository.
+///
+/// # Returns
+///
+/// The created API key, including its value. The value is only returned here;
+/// it cannot be retrieved afterwards.
+pub async fn create_api_key<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    request: ApiKeyCreateRequest,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    request.validate()?;
+
+    for relayer_id in &request.allowed_relayers {
+        match state.relayer_repository.get_by_id(relayer_id.clone()).await {
+            Ok(_) => {}
+            Err(RepositoryError::NotFound(_)) => {
+                return Err(ApiError::BadRequest(format!(
+                    "Relayer {} not found",
+                    relayer_id
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    let value = generate_api_key_value();
+    let api_key = ApiKeyRepoModel {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: request.name,
+        value: SecretString::new(&value),
+        scopes: request.scopes,
+        allowed_relayers: request.allowed_relayers,
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+
+    let created_api_key = state.api_key_repository.create(api_key).await?;
+
+    let response = ApiKeyCreateResponse {
+        api_key: ApiKeyResponse::from(created_api_key),
+        key: value,
+    };
+    Ok(HttpResponse::Created().json(ApiResponse::success(response)))
+}
+
+/// Revokes an API key by ID.
+///
+/// # Arguments
+///
+/// * `api_key_id` - The ID of the API key to revoke.
+/// * `state` - The application state containing the API key repository.
+///
+/// # Returns
+///
+/// A success response or an error if the API key does not exist.
+pub async fn delete_api_key<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    api_key_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    state.api_key_repository.delete_by_id(api_key_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success("API key revoked successfully")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::ApiKeyScope,
+        repositories::ApiKeyRepositoryStorage,
+        utils::mocks::mockutils::{create_mock_app_state, create_mock_relayer},
+    };
+    use actix_web::{body::to_bytes, web::ThinData};
+    use std::sync::Arc;
+
+    fn create_request(allowed_relayers: Vec<&str>) -> ApiKeyCreateRequest {
+        ApiKeyCreateRequest {
+            name: "ci".to_string(),
+            scopes: vec![ApiKeyScope::SendTransactions],
+            allowed_relayers: allowed_relayers.into_iter().map(String::from).collect(),
+        }
+    }
+
+    /// Builds mock app state backed by a shared API key repository so several
+    /// controller calls observe the same keys.
+    macro_rules! state_with_repository {
+        ($repository:expr, $relayers:expr) => {{
+            let mut app_state = create_mock_app_state($relayers, None, None, None, None).await;
+            app_state.api_key_repository = Arc::clone($repository);
+            ThinData(app_state)
+        }};
+    }
+
+    #[actix_web::test]
+    async fn test_create_api_key_returns_value_once() {
+        let repository = Arc::new(ApiKeyRepositoryStorage::new_in_memory());
+        let relayers = Some(vec![create_mock_relayer("relayer-1".to_string(), false)]);
+
+        let response = create_api_key(
+            create_request(vec!["relayer-1"]),
+            state_with_repository!(&repository, relayers),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), actix_web::http::StatusCode::CREATED);
+
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let key = json["data"]["key"].as_str().unwrap().to_string();
+        let id = json["data"]["id"].as_str().unwrap().to_string();
+        assert_eq!(json["data"]["allowed_relayers"][0], "relayer-1");
+
+        // The stored key matches the returned value
+        let stored = repository
+            .get_by_value(&SecretString::new(&key))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.id, id);
+
+        // Listing does not expose the value
+        let response = list_api_keys(
+            PaginationQuery {
+                page: 1,
+                per_page: 10,
+            },
+            state_with_repository!(&repository, None),
+        )
+        .await
+        .unwrap();
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(&id));
+        assert!(!body.contains(&key));
+    }
+
+    #[actix_web::test]
+    async fn test_create_api_key_unknown_relayer() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let result =
+            create_api_key(create_request(vec!["missing-relayer"]), ThinData(app_state)).await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_create_api_key_invalid_request() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+        let mut request = create_request(vec![]);
+        request.scopes.clear();
+
+        let result = create_api_key(request, ThinData(app_state)).await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_delete_api_key() {
+        let repository = Arc::new(ApiKeyRepositoryStorage::new_in_memory());
+
+        let response = create_api_key(
+            create_request(vec![]),
+            state_with_repository!(&repository, None),
+        )
+        .await
+        .unwrap();
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let id = json["data"]["id"].as_str().unwrap().to_string();
+
+        let response = delete_api_key(id.clone(), state_with_repository!(&repository, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), actix_web::http::StatusCode::OK);
+
+        let result = delete_api_key(id, state_with_repository!(&repository, None)).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
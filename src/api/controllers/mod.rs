@@ -5,11 +5,17 @@
 //! ## Controllers
 //!
 //! * `relayer` - Transaction and relayer management endpoints
+//! * `relayer_pool` - Relayer pool management and routing endpoints
 //! * `plugin` - Plugin endpoints
+//! * `networks` - Network management endpoints
 //! * `notifications` - Notification management endpoints
 //! * `signers` - Signer management endpoints
+//! * `api_key` - API key management endpoints
 
+pub mod api_key;
+pub mod network;
 pub mod notification;
 pub mod plugin;
 pub mod relayer;
+pub mod relayer_pool;
 pub mod signer;
@@ -0,0 +1,784 @@
+//! # Networks Controller
+//!
+//! Handles HTTP endpoints for network operations including:
+//! - Listing networks
+//! - Getting network details
+//! - Creating networks
+//! - Updating networks
+//! - Deleting networks
+//!
+//! Networks created through the API go through the same inheritance resolution and
+//! validation as networks loaded from the configuration files.
+
+use crate::{
+    config::{network::InheritanceResolver, NetworkFileConfig},
+    jobs::JobProducerTrait,
+    models::{
+        ApiError, ApiResponse, NetworkCreateRequest, NetworkRepoModel, NetworkResponse,
+        NetworkUpdateRequest, NotificationRepoModel, PaginationMeta, PaginationQuery,
+        RelayerPoolRepoModel, RelayerRepoModel, SignerRepoModel, ThinDataAppState,
+        TransactionRepoModel,
+    },
+    repositories::{
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        TransactionCounterTrait, TransactionRepository,
+    },
+};
+
+use actix_web::HttpResponse;
+use eyre::Result;
+
+/// Resolves the `from` parent of a new network against the networks in the repository.
+///
+/// Networks in the repository are stored with their own inheritance already resolved,
+/// so only the direct parent needs to be looked up.
+async fn resolve_inheritance<NR>(
+    config: NetworkFileConfig,
+    network_repository: &NR,
+) -> Result<NetworkFileConfig, ApiError>
+where
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+{
+    let Some(parent_name) = config.inherits_from() else {
+        return Ok(config);
+    };
+    let network_name = config.network_name();
+
+    if parent_name == network_name {
+        return Err(ApiError::BadRequest(format!(
+            "Network '{}' cannot inherit from itself",
+            network_name
+        )));
+    }
+
+    let network_type = config.network_type().into();
+    let parent = network_repository
+        .get_by_name(network_type, parent_name)
+        .await?
+        .ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "{} network '{}' inherits from non-existent network '{}'",
+                network_type, network_name, parent_name
+            ))
+        })?;
+
+    let mut parent_config = NetworkFileConfig::from(parent.config);
+    match &mut parent_config {
+        NetworkFileConfig::Evm(evm) => evm.common.from = None,
+        NetworkFileConfig::Solana(solana) => solana.common.from = None,
+        NetworkFileConfig::Stellar(stellar) => stellar.common.from = None,
+    }
+
+    let lookup_fn = |name: &str| (name == parent_name).then_some(&parent_config);
+    let resolver = InheritanceResolver::new(&lookup_fn);
+
+    let resolved = match &config {
+        NetworkFileConfig::Evm(evm) => resolver
+            .resolve_evm_inheritance(evm, network_name, parent_name)
+            .map(NetworkFileConfig::Evm),
+        NetworkFileConfig::Solana(solana) => resolver
+            .resolve_solana_inheritance(solana, network_name, parent_name)
+            .map(NetworkFileConfig::Solana),
+        NetworkFileConfig::Stellar(stellar) => resolver
+            .resolve_stellar_inheritance(stellar, network_name, parent_name)
+            .map(NetworkFileConfig::Stellar),
+    };
+
+    resolved.map_err(|e| ApiError::BadRequest(e.to_string()))
+}
+
+/// Returns the networks that inherit from `network` through `from`.
+async fn child_networks<NR>(
+    network: &NetworkRepoModel,
+    network_repository: &NR,
+) -> Result<Vec<NetworkRepoModel>, ApiError>
+where
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+{
+    Ok(network_repository
+        .list_all()
+        .await?
+        .into_iter()
+        .filter(|n| {
+            n.network_type == network.network_type
+                && n.common().from.as_deref() == Some(network.name.as_str())
+        })
+        .collect())
+}
+
+/// Lists all networks with pagination support.
+///
+/// # Arguments
+///
+/// * `query` - The pagination query parameters.
+/// * `state` - The application state containing the network repository.
+///
+/// # Returns
+///
+/// A paginated list of networks.
+pub async fn list_networks<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    query: PaginationQuery,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let networks = state.network_repository.list_paginated(query).await?;
+
+    let mapped_networks: Vec<NetworkResponse> =
+        networks.items.into_iter().map(|n| n.into()).collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        mapped_networks,
+        PaginationMeta {
+            total_items: networks.total,
+            current_page: networks.page,
+            per_page: networks.per_page,
+        },
+    )))
+}
+
+/// Retrieves details of a specific network by ID.
+///
+/// # Arguments
+///
+/// * `network_id` - The ID of the network to retrieve (e.g. `evm:sepolia`).
+/// * `state` - The application state containing the network repository.
+///
+/// # Returns
+///
+/// The network details or an error if not found.
+pub async fn get_network<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    network_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let network = state.network_repository.get_by_id(network_id).await?;
+
+    let response = NetworkResponse::from(network);
+    Ok(HttpResponse::Ok().json(ApiResponse::success(response)))
+}
+
+/// Creates a new network.
+///
+/// # Arguments
+///
+/// * `request` - The network creation request.
+/// * `state` - The application state containing the network repository.
+///
+/// # Returns
+///
+/// The created network or an error if creation fails.
+pub async fn create_network<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    request: NetworkCreateRequest,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let config = NetworkFileConfig::try_from(request)?;
+    let network_type = config.network_type().into();
+
+    if state
+        .network_repository
+        .get_by_name(network_type, config.network_name())
+        .await?
+        .is_some()
+    {
+        return Err(ApiError::BadRequest(format!(
+            "{} network '{}' already exists",
+            network_type,
+            config.network_name()
+        )));
+    }
+
+    let config = resolve_inheritance(config, state.network_repository.as_ref()).await?;
+    config
+        .validate()
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let network_model =
+        NetworkRepoModel::try_from(config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let created_network = state.network_repository.create(network_model).await?;
+
+    let response = NetworkResponse::from(created_network);
+    Ok(HttpResponse::Created().json(ApiResponse::success(response)))
+}
+
+/// Updates an existing network.
+///
+/// The update is passed on to the networks inheriting from it, for the fields they
+/// inherited. The update is rejected if it would leave any of them invalid.
+///
+/// # Arguments
+///
+/// * `network_id` - The ID of the network to update.
+/// * `request` - The network update request.
+/// * `state` - The application state containing the network repository.
+///
+/// # Returns
+///
+/// The updated network or an error if update fails.
+pub async fn update_network<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    network_id: String,
+    request: NetworkUpdateRequest,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let existing_network = state
+        .network_repository
+        .get_by_id(network_id.clone())
+        .await?;
+
+    // Apply update (with validation)
+    let updated = existing_network.apply_update(&request)?;
+
+    // Pass the update on to the networks inheriting from this one, and from those in turn
+    let mut updated_children = Vec::new();
+    let mut parents = vec![(existing_network, request)];
+    while let Some((parent, parent_request)) = parents.pop() {
+        for child in child_networks(&parent, state.network_repository.as_ref()).await? {
+            let child_request = child.inherited_update(&parent, &parent_request);
+            if child_request == NetworkUpdateRequest::default() {
+                continue;
+            }
+            let updated_child = child.apply_update(&child_request).map_err(|e| {
+                ApiError::BadRequest(format!(
+                    "Network '{}' inherits from '{}' and would become invalid: {}",
+                    child.id, parent.id, e
+                ))
+            })?;
+            updated_children.push(updated_child);
+            parents.push((child, child_request));
+        }
+    }
+
+    let saved_network = state.network_repository.update(network_id, updated).await?;
+    for child in updated_children {
+        state
+            .network_repository
+            .update(child.id.clone(), child)
+            .await?;
+    }
+
+    let response = NetworkResponse::from(saved_network);
+    Ok(HttpResponse::Ok().json(ApiResponse::success(response)))
+}
+
+/// Deletes a network by ID.
+///
+/// # Arguments
+///
+/// * `network_id` - The ID of the network to delete.
+/// * `state` - The application state containing the network repository.
+///
+/// # Returns
+///
+/// A success response or an error if deletion fails.
+///
+/// # Security
+///
+/// This endpoint ensures that networks cannot be deleted while relayers are still
+/// configured to use them.
+pub async fn delete_network<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    network_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let network = state
+        .network_repository
+        .get_by_id(network_id.clone())
+        .await?;
+
+    // Check if any relayers are using this network
+    let connected_relayers: Vec<RelayerRepoModel> = state
+        .relayer_repository
+        .list_all()
+        .await?
+        .into_iter()
+        .filter(|r| r.network_type == network.network_type && r.network == network.name)
+        .collect();
+
+    let children = child_networks(&network, state.network_repository.as_ref()).await?;
+    if !children.is_empty() {
+        let child_ids: Vec<String> = children.into_iter().map(|n| n.id).collect();
+        return Err(ApiError::BadRequest(format!(
+            "Cannot delete network '{}' because {} network(s) inherit from it: {}. Please delete these networks first.",
+            network_id,
+            child_ids.len(),
+            child_ids.join(", ")
+        )));
+    }
+
+    if !connected_relayers.is_empty() {
+        let relayer_names: Vec<String> =
+            connected_relayers.iter().map(|r| r.name.clone()).collect();
+        return Err(ApiError::BadRequest(format!(
+            "Cannot delete network '{}' because it is being used by {} relayer(s): {}. Please remove or reconfigure these relayers before deleting the network.",
+            network_id,
+            connected_relayers.len(),
+            relayer_names.join(", ")
+        )));
+    }
+
+    state.network_repository.delete_by_id(network_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success("Network deleted successfully")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{NetworkConfigData, RelayerNetworkType},
+        repositories::NetworkRepositoryStorage,
+        utils::mocks::mockutils::{
+            create_mock_app_state, create_mock_network, create_mock_relayer,
+        },
+    };
+    use actix_web::web::ThinData;
+    use std::sync::Arc;
+
+    fn create_test_network_model(name: &str) -> NetworkRepoModel {
+        let mut network = create_mock_network();
+        network.id = NetworkRepoModel::create_id(network.network_type, name);
+        network.name = name.to_string();
+        if let NetworkConfigData::Evm(evm) = &mut network.config {
+            evm.common.network = name.to_string();
+        }
+        network
+    }
+
+    fn create_inheriting_network_model(name: &str, parent: &str) -> NetworkRepoModel {
+        let mut network = create_test_network_model(name);
+        if let NetworkConfigData::Evm(evm) = &mut network.config {
+            evm.common.from = Some(parent.to_string());
+        }
+        network
+    }
+
+    /// Builds mock app state backed by a shared network repository so controller calls
+    /// and assertions observe the same networks.
+    macro_rules! state_with_repository {
+        ($repository:expr) => {{
+            let mut app_state = create_mock_app_state(None, None, None, None, None).await;
+            app_state.network_repository = Arc::clone($repository);
+            ThinData(app_state)
+        }};
+    }
+
+    fn create_test_network_create_request(name: &str) -> NetworkCreateRequest {
+        NetworkCreateRequest {
+            r#type: RelayerNetworkType::Evm,
+            network: name.to_string(),
+            from: None,
+            rpc_urls: Some(vec!["https://rpc.example.com".to_string()]),
+            explorer_urls: None,
+            average_blocktime_ms: Some(2000),
+            is_testnet: Some(true),
+            tags: None,
+            chain_id: Some(1337),
+            required_confirmations: Some(1),
+            features: Some(vec!["eip1559".to_string()]),
+            symbol: Some("ETH".to_string()),
+            gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
+            passphrase: None,
+        }
+    }
+
+    async fn response_data(response: HttpResponse) -> NetworkResponse {
+        let body = actix_web::body::to_bytes(response.into_body())
+            .await
+            .unwrap();
+        let api_response: ApiResponse<NetworkResponse> = serde_json::from_slice(&body).unwrap();
+        api_response.data.unwrap()
+    }
+
+    #[actix_web::test]
+    async fn test_list_networks() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            Some(vec![
+                create_test_network_model("mainnet"),
+                create_test_network_model("sepolia"),
+            ]),
+            None,
+            None,
+        )
+        .await;
+
+        let query = PaginationQuery {
+            page: 1,
+            per_page: 10,
+        };
+
+        let response = list_networks(query, ThinData(app_state)).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = actix_web::body::to_bytes(response.into_body())
+            .await
+            .unwrap();
+        let api_response: ApiResponse<Vec<NetworkResponse>> =
+            serde_json::from_slice(&body).unwrap();
+
+        let data = api_response.data.unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(api_response.pagination.unwrap().total_items, 2);
+    }
+
+    #[actix_web::test]
+    async fn test_get_network_success() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            Some(vec![create_test_network_model("sepolia")]),
+            None,
+            None,
+        )
+        .await;
+
+        let response = get_network("evm:sepolia".to_string(), ThinData(app_state))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let data = response_data(response).await;
+        assert_eq!(data.id, "evm:sepolia");
+        assert_eq!(data.network, "sepolia");
+    }
+
+    #[actix_web::test]
+    async fn test_get_network_not_found() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let result = get_network("evm:unknown".to_string(), ThinData(app_state)).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_create_network_success() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+        let request = create_test_network_create_request("devnet");
+
+        let response = create_network(request, ThinData(app_state)).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let data = response_data(response).await;
+        assert_eq!(data.id, "evm:devnet");
+        assert_eq!(data.chain_id, Some(1337));
+    }
+
+    #[actix_web::test]
+    async fn test_create_network_resolves_inheritance() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            Some(vec![create_test_network_model("mainnet")]),
+            None,
+            None,
+        )
+        .await;
+
+        let mut request = create_test_network_create_request("mainnet-fork");
+        request.from = Some("mainnet".to_string());
+        request.rpc_urls = None;
+        request.symbol = None;
+        request.required_confirmations = None;
+
+        let response = create_network(request, ThinData(app_state)).await.unwrap();
+        assert_eq!(response.status(), 201);
+
+        let data = response_data(response).await;
+        assert_eq!(data.from, Some("mainnet".to_string()));
+        assert_eq!(data.chain_id, Some(1337));
+        assert_eq!(data.symbol, Some("testETH".to_string()));
+        assert_eq!(data.required_confirmations, Some(1));
+        assert_eq!(
+            data.rpc_urls,
+            Some(vec!["http://localhost:8545".to_string()])
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_create_network_with_missing_parent() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let mut request = create_test_network_create_request("mainnet-fork");
+        request.from = Some("mainnet".to_string());
+
+        let result = create_network(request, ThinData(app_state)).await;
+        match result {
+            Err(ApiError::BadRequest(msg)) => assert!(msg.contains("non-existent network")),
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_create_network_inheriting_from_itself() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let mut request = create_test_network_create_request("loop");
+        request.from = Some("loop".to_string());
+
+        let result = create_network(request, ThinData(app_state)).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_create_network_validation_error() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let mut request = create_test_network_create_request("devnet");
+        request.chain_id = None;
+
+        let result = create_network(request, ThinData(app_state)).await;
+        match result {
+            Err(ApiError::BadRequest(msg)) => assert!(msg.contains("chain_id")),
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_create_network_duplicate() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            Some(vec![create_test_network_model("devnet")]),
+            None,
+            None,
+        )
+        .await;
+
+        let request = create_test_network_create_request("devnet");
+
+        let result = create_network(request, ThinData(app_state)).await;
+        match result {
+            Err(ApiError::BadRequest(msg)) => assert!(msg.contains("already exists")),
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_update_network_success() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            Some(vec![create_test_network_model("sepolia")]),
+            None,
+            None,
+        )
+        .await;
+
+        let request = NetworkUpdateRequest {
+            rpc_urls: Some(vec!["https://sepolia.example.com".to_string()]),
+            ..Default::default()
+        };
+
+        let response = update_network("evm:sepolia".to_string(), request, ThinData(app_state))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let data = response_data(response).await;
+        assert_eq!(
+            data.rpc_urls,
+            Some(vec!["https://sepolia.example.com".to_string()])
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_update_network_updates_inheriting_networks() {
+        let repository = Arc::new(NetworkRepositoryStorage::new_in_memory());
+        repository
+            .create(create_test_network_model("mainnet"))
+            .await
+            .unwrap();
+        let mut fork = create_inheriting_network_model("mainnet-fork", "mainnet");
+        if let NetworkConfigData::Evm(evm) = &mut fork.config {
+            evm.symbol = Some("FORK".to_string());
+        }
+        repository.create(fork).await.unwrap();
+
+        let request = NetworkUpdateRequest {
+            rpc_urls: Some(vec!["https://mainnet.example.com".to_string()]),
+            symbol: Some("ETH".to_string()),
+            ..Default::default()
+        };
+        update_network(
+            "evm:mainnet".to_string(),
+            request,
+            state_with_repository!(&repository),
+        )
+        .await
+        .unwrap();
+
+        let fork = repository
+            .get_by_id("evm:mainnet-fork".to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            fork.common().rpc_urls,
+            Some(vec!["https://mainnet.example.com".to_string()])
+        );
+        // Values the network overrides are kept
+        let NetworkConfigData::Evm(evm) = fork.config else {
+            panic!("Expected EVM network");
+        };
+        assert_eq!(evm.symbol, Some("FORK".to_string()));
+    }
+
+    #[actix_web::test]
+    async fn test_update_network_not_found() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let result = update_network(
+            "evm:unknown".to_string(),
+            NetworkUpdateRequest::default(),
+            ThinData(app_state),
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_delete_network_success() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            Some(vec![create_test_network_model("sepolia")]),
+            None,
+            None,
+        )
+        .await;
+
+        let response = delete_network("evm:sepolia".to_string(), ThinData(app_state))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_network_in_use_by_relayer() {
+        let mut relayer = create_mock_relayer("relayer-1".to_string(), false);
+        relayer.network = "sepolia".to_string();
+
+        let app_state = create_mock_app_state(
+            Some(vec![relayer]),
+            None,
+            Some(vec![create_test_network_model("sepolia")]),
+            None,
+            None,
+        )
+        .await;
+
+        let result = delete_network("evm:sepolia".to_string(), ThinData(app_state)).await;
+        match result {
+            Err(ApiError::BadRequest(msg)) => {
+                assert!(msg.contains("Cannot delete network 'evm:sepolia'"));
+                assert!(msg.contains("Relayer relayer-1"));
+            }
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_delete_network_with_inheriting_networks() {
+        let repository = Arc::new(NetworkRepositoryStorage::new_in_memory());
+        repository
+            .create(create_test_network_model("mainnet"))
+            .await
+            .unwrap();
+        repository
+            .create(create_inheriting_network_model("mainnet-fork", "mainnet"))
+            .await
+            .unwrap();
+
+        let result = delete_network(
+            "evm:mainnet".to_string(),
+            state_with_repository!(&repository),
+        )
+        .await;
+        match result {
+            Err(ApiError::BadRequest(msg)) => {
+                assert!(msg.contains("Cannot delete network 'evm:mainnet'"));
+                assert!(msg.contains("evm:mainnet-fork"));
+            }
+            _ => panic!("Expected BadRequest error"),
+        }
+
+        // The network can be deleted once nothing inherits from it
+        repository
+            .delete_by_id("evm:mainnet-fork".to_string())
+            .await
+            .unwrap();
+        let response = delete_network(
+            "evm:mainnet".to_string(),
+            state_with_repository!(&repository),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_network_not_found() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let result = delete_network("evm:unknown".to_string(), ThinData(app_state)).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
@@ -12,10 +12,11 @@ use crate::{
     models::{
         ApiError, ApiResponse, NetworkRepoModel, Notification, NotificationCreateRequest,
         NotificationRepoModel, NotificationResponse, NotificationUpdateRequest, PaginationMeta,
-        PaginationQuery, RelayerRepoModel, SignerRepoModel, ThinDataAppState, TransactionRepoModel,
+        PaginationQuery, RelayerPoolRepoModel, RelayerRepoModel, SignerRepoModel, ThinDataAppState,
+        TransactionRepoModel,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
 };
@@ -33,9 +34,9 @@ use eyre::Result;
 /// # Returns
 ///
 /// A paginated list of notifications.
-pub async fn list_notifications<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn list_notifications<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     query: PaginationQuery,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -46,6 +47,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let notifications = state.notification_repository.list_paginated(query).await?;
 
@@ -72,9 +75,9 @@ where
 /// # Returns
 ///
 /// The notification details or an error if not found.
-pub async fn get_notification<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_notification<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     notification_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -85,6 +88,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let notification = state
         .notification_repository
@@ -105,9 +110,9 @@ where
 /// # Returns
 ///
 /// The created notification or an error if creation fails.
-pub async fn create_notification<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn create_notification<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     request: NotificationCreateRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -118,6 +123,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     // Convert request to core notification (validates automatically)
     let notification = Notification::try_from(request)?;
@@ -144,10 +151,10 @@ where
 /// # Returns
 ///
 /// The updated notification or an error if update fails.
-pub async fn update_notification<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn update_notification<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     notification_id: String,
     request: NotificationUpdateRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -158,6 +165,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     // Get the existing notification from repository
     let existing_repo_model = state
@@ -193,9 +202,9 @@ where
 /// This endpoint ensures that notifications cannot be deleted if they are still being
 /// used by any relayers. This prevents breaking existing relayer configurations
 /// and maintains system integrity.
-pub async fn delete_notification<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn delete_notification<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     notification_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -206,6 +215,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     // First check if the notification exists
     let _notification = state
@@ -740,6 +751,7 @@ mod tests {
             notification_id: Some("connected-notification".to_string()), // References our notification
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
         app_state.relayer_repository.create(relayer).await.unwrap();
 
@@ -786,6 +798,7 @@ mod tests {
             notification_id: Some("cleanup-notification".to_string()),
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
         app_state.relayer_repository.create(relayer).await.unwrap();
 
@@ -842,6 +855,7 @@ mod tests {
                 notification_id: Some("multi-relayer-notification".to_string()),
                 system_disabled: false,
                 custom_rpc_urls: None,
+                signer_rotations: vec![],
             },
             crate::models::RelayerRepoModel {
                 id: "relayer-2".to_string(),
@@ -857,6 +871,7 @@ mod tests {
                 notification_id: Some("multi-relayer-notification".to_string()),
                 system_disabled: false,
                 custom_rpc_urls: None,
+                signer_rotations: vec![],
             },
             crate::models::RelayerRepoModel {
                 id: "relayer-3".to_string(),
@@ -872,6 +887,7 @@ mod tests {
                 notification_id: Some("multi-relayer-notification".to_string()),
                 system_disabled: true, // Even disabled relayers should block deletion
                 custom_rpc_urls: None,
+                signer_rotations: vec![],
             },
         ];
 
@@ -934,6 +950,7 @@ mod tests {
             notification_id: Some("notification-to-delete".to_string()), // This one blocks deletion
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer2 = crate::models::RelayerRepoModel {
@@ -950,6 +967,7 @@ mod tests {
             notification_id: Some("other-notification".to_string()), // This one uses different notification
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer3 = crate::models::RelayerRepoModel {
@@ -966,6 +984,7 @@ mod tests {
             notification_id: None, // This one has no notification
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         app_state.relayer_repository.create(relayer1).await.unwrap();
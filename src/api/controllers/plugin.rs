@@ -6,11 +6,11 @@ use crate::{
     jobs::JobProducerTrait,
     models::{
         ApiError, ApiResponse, NetworkRepoModel, NotificationRepoModel, PaginationMeta,
-        PaginationQuery, PluginCallRequest, PluginModel, RelayerRepoModel, SignerRepoModel,
-        ThinDataAppState, TransactionRepoModel,
+        PaginationQuery, PluginCallRequest, PluginModel, RelayerPoolRepoModel, RelayerRepoModel,
+        SignerRepoModel, ThinDataAppState, TransactionRepoModel,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
     services::plugins::{PluginCallResponse, PluginRunner, PluginService, PluginServiceTrait},
@@ -30,10 +30,10 @@ use std::sync::Arc;
 /// # Returns
 ///
 /// The result of the plugin call.
-pub async fn call_plugin<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn call_plugin<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     plugin_id: String,
     plugin_call_request: PluginCallRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -44,6 +44,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let plugin = state
         .plugin_repository
@@ -75,9 +77,9 @@ where
 /// # Returns
 ///
 /// The result of the plugin list.
-pub async fn list_plugins<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn list_plugins<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     query: PaginationQuery,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -88,6 +90,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let plugins = state.plugin_repository.list_paginated(query).await?;
 
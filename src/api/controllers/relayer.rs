@@ -9,30 +9,38 @@
 //! - Submitting transactions
 //! - Signing messages
 //! - JSON-RPC proxy
+//! - Rotating signers
 use crate::{
+    config::ServerConfig,
+    constants::IDEMPOTENT_REPLAYED_HEADER,
     domain::{
         get_network_relayer, get_network_relayer_by_model, get_relayer_by_id,
-        get_relayer_transaction_by_model, get_transaction_by_id as get_tx_by_id, Relayer,
-        RelayerFactory, RelayerFactoryTrait, SignDataRequest, SignDataResponse,
-        SignTransactionRequest, SignTypedDataRequest, Transaction,
+        get_relayer_transaction_by_model, get_transaction_by_id as get_tx_by_id,
+        reserve_transaction_idempotency_key, sign_evm_authorizations, start_signer_rotation,
+        validate_transaction_dependencies, IdempotencyReservation, Relayer, RelayerFactory,
+        RelayerFactoryTrait, SignDataRequest, SignDataResponse, SignTransactionRequest,
+        SignTypedDataRequest, Transaction,
     },
     jobs::JobProducerTrait,
     models::{
         convert_to_internal_rpc_request, deserialize_policy_for_network_type, ApiError,
-        ApiResponse, CreateRelayerRequest, DefaultAppState, NetworkRepoModel,
-        NetworkTransactionRequest, NetworkType, NotificationRepoModel, PaginationMeta,
-        PaginationQuery, Relayer as RelayerDomainModel, RelayerRepoModel, RelayerRepoUpdater,
-        RelayerResponse, Signer as SignerDomainModel, SignerRepoModel, ThinDataAppState,
-        TransactionRepoModel, TransactionResponse, TransactionStatus, UpdateRelayerRequestRaw,
+        ApiKeyIdentity, ApiResponse, BatchTransactionRequest, BatchTransactionResponse,
+        BatchTransactionResult, CreateRelayerRequest, DefaultAppState, ForwardTransactionRequest,
+        NetworkRepoModel, NetworkTransactionRequest, NetworkType, NotificationRepoModel,
+        PaginationMeta, PaginationQuery, Relayer as RelayerDomainModel, RelayerPoolRepoModel,
+        RelayerRepoModel, RelayerRepoUpdater, RelayerResponse, RotateSignerRequest,
+        Signer as SignerDomainModel, SignerRepoModel, ThinDataAppState, TransactionRepoModel,
+        TransactionResponse, TransactionStatus, UpdateRelayerRequestRaw,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
     services::{Signer, SignerFactory},
 };
 use actix_web::{web, HttpResponse};
 use eyre::Result;
+use log::warn;
 
 /// Lists all relayers with pagination support.
 ///
@@ -44,9 +52,9 @@ use eyre::Result;
 /// # Returns
 ///
 /// A paginated list of relayers.
-pub async fn list_relayers<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn list_relayers<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     query: PaginationQuery,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -57,6 +65,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayers = state.relayer_repository.list_paginated(query).await?;
 
@@ -83,9 +93,9 @@ where
 /// # Returns
 ///
 /// The details of the specified relayer.
-pub async fn get_relayer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_relayer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -96,6 +106,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id, &state).await?;
 
@@ -124,9 +136,9 @@ where
 /// - **Network Validation**: Confirms the specified network exists for the given network type
 ///
 /// All validations must pass before the relayer is created, ensuring referential integrity and security constraints.
-pub async fn create_relayer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn create_relayer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     request: CreateRelayerRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -137,6 +149,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     // Convert request to domain relayer (validates automatically)
     let relayer = RelayerDomainModel::try_from(request)?;
@@ -219,10 +233,10 @@ where
 /// # Returns
 ///
 /// The updated relayer information.
-pub async fn update_relayer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn update_relayer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     patch: serde_json::Value,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -233,6 +247,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
 
@@ -290,9 +306,9 @@ where
 ///
 /// This endpoint ensures that relayers cannot be deleted if they have any pending
 /// or active transactions. This prevents data loss and maintains system integrity.
-pub async fn delete_relayer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn delete_relayer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -303,6 +319,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     // Check if the relayer exists
     let _relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
@@ -328,6 +346,15 @@ where
         )));
     }
 
+    // Pools would keep routing to a relayer that no longer exists
+    let pools = state.relayer_pool_repository.list_all().await?;
+    if let Some(pool) = pools.iter().find(|p| p.relayer_ids.contains(&relayer_id)) {
+        return Err(ApiError::BadRequest(format!(
+            "Cannot delete relayer '{}' because it is a member of pool '{}'. Please remove it from the pool first.",
+            relayer_id, pool.id
+        )));
+    }
+
     // Safe to delete - no transactions associated with this relayer
     state.relayer_repository.delete_by_id(relayer_id).await?;
 
@@ -344,9 +371,9 @@ where
 /// # Returns
 ///
 /// The status of the specified relayer.
-pub async fn get_relayer_status<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_relayer_status<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -357,6 +384,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_network_relayer(relayer_id, &state).await?;
 
@@ -365,6 +394,39 @@ where
     Ok(HttpResponse::Ok().json(ApiResponse::success(status)))
 }
 
+/// Retrieves the nonce health of a specific relayer.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer to analyze.
+/// * `state` - The application state containing the relayer repository.
+///
+/// # Returns
+///
+/// The nonce analysis of the specified relayer, including any nonce gaps.
+pub async fn get_relayer_nonce_health<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let relayer = get_network_relayer(relayer_id, &state).await?;
+
+    let nonce_health = relayer.get_nonce_health().await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(nonce_health)))
+}
+
 /// Retrieves the balance of a specific relayer.
 ///
 /// # Arguments
@@ -375,9 +437,9 @@ where
 /// # Returns
 ///
 /// The balance of the specified relayer.
-pub async fn get_relayer_balance<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_relayer_balance<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -388,6 +450,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_network_relayer(relayer_id, &state).await?;
 
@@ -402,6 +466,8 @@ where
 ///
 /// * `relayer_id` - The ID of the relayer to send the transaction through.
 /// * `request` - The transaction request data.
+/// * `idempotency_key` - The idempotency key supplied in the request header, if any.
+/// * `identity` - The API key submitting the transaction.
 /// * `state` - The application state containing the relayer repository.
 ///
 /// # Returns
@@ -410,19 +476,221 @@ where
 pub async fn send_transaction(
     relayer_id: String,
     request: serde_json::Value,
+    idempotency_key: Option<String>,
+    identity: ApiKeyIdentity,
     state: web::ThinData<DefaultAppState>,
 ) -> Result<HttpResponse, ApiError> {
     let relayer_repo_model = get_relayer_by_id(relayer_id, &state).await?;
     relayer_repo_model.validate_active_state()?;
+    relayer_repo_model.validate_no_active_signer_rotation()?;
 
-    let relayer = get_network_relayer(relayer_repo_model.id.clone(), &state).await?;
-
-    let tx_request: NetworkTransactionRequest =
+    let mut tx_request: NetworkTransactionRequest =
         NetworkTransactionRequest::from_json(&relayer_repo_model.network_type, request.clone())?;
 
+    if let Some(idempotency_key) = idempotency_key {
+        tx_request.set_idempotency_key(idempotency_key);
+    }
+
     tx_request.validate(&relayer_repo_model)?;
 
-    let transaction = relayer.process_transaction_request(tx_request).await?;
+    let mut reservation = None;
+    if let Some(idempotency_key) = tx_request.idempotency_key() {
+        match reserve_transaction_idempotency_key(
+            &relayer_repo_model.id,
+            idempotency_key,
+            ServerConfig::get_idempotency_window_seconds(),
+            &state,
+        )
+        .await?
+        {
+            IdempotencyReservation::Existing(transaction) => {
+                let transaction_response: TransactionResponse = (*transaction).into();
+                return Ok(HttpResponse::Ok()
+                    .insert_header((IDEMPOTENT_REPLAYED_HEADER, "true"))
+                    .json(ApiResponse::success(transaction_response)));
+            }
+            IdempotencyReservation::Reserved(reservation_id) => {
+                reservation = Some((idempotency_key.to_string(), reservation_id));
+            }
+        }
+    }
+
+    let result = submit_transaction(&relayer_repo_model, tx_request, &identity, &state).await;
+
+    if let (Err(_), Some((idempotency_key, reservation_id))) = (&result, reservation) {
+        if let Err(e) = state
+            .transaction_repository
+            .release_idempotency_key(&relayer_repo_model.id, &idempotency_key, &reservation_id)
+            .await
+        {
+            warn!(
+                "Failed to release idempotency key for relayer {}: {}",
+                relayer_repo_model.id, e
+            );
+        }
+    }
+
+    let transaction_response: TransactionResponse = result?.into();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(transaction_response)))
+}
+
+/// Creates a validated transaction request on its relayer.
+async fn submit_transaction(
+    relayer_repo_model: &RelayerRepoModel,
+    mut tx_request: NetworkTransactionRequest,
+    identity: &ApiKeyIdentity,
+    state: &web::ThinData<DefaultAppState>,
+) -> Result<TransactionRepoModel, ApiError> {
+    if let Some(depends_on) = tx_request.depends_on() {
+        validate_transaction_dependencies(depends_on, identity, state).await?;
+    }
+
+    sign_evm_authorizations(relayer_repo_model, &mut tx_request, identity, state).await?;
+
+    let relayer = get_network_relayer(relayer_repo_model.id.clone(), state).await?;
+
+    Ok(relayer.process_transaction_request(tx_request).await?)
+}
+
+/// Submits a batch of transactions for a specific relayer.
+///
+/// Every entry is validated before anything is created. Valid entries are processed
+/// together and receive contiguous nonces; invalid entries are reported with their
+/// error. With `all_or_nothing`, a single invalid entry rejects the whole batch.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer.
+/// * `request` - The batch of transactions to submit.
+/// * `identity` - The API key submitting the batch.
+/// * `state` - The application state containing the relayer repository.
+///
+/// # Returns
+///
+/// The outcome of each transaction in the batch.
+pub async fn send_transaction_batch(
+    relayer_id: String,
+    request: BatchTransactionRequest,
+    identity: ApiKeyIdentity,
+    state: web::ThinData<DefaultAppState>,
+) -> Result<HttpResponse, ApiError> {
+    request.validate()?;
+
+    let relayer_repo_model = get_relayer_by_id(relayer_id, &state).await?;
+    relayer_repo_model.validate_active_state()?;
+    relayer_repo_model.validate_no_active_signer_rotation()?;
+
+    let relayer = get_network_relayer(relayer_repo_model.id.clone(), &state).await?;
+
+    let mut results = Vec::with_capacity(request.transactions.len());
+    let mut valid_indices = Vec::new();
+    let mut tx_requests = Vec::new();
+    for (index, json) in request.transactions.into_iter().enumerate() {
+        let tx_request =
+            NetworkTransactionRequest::from_json(&relayer_repo_model.network_type, json).and_then(
+                |tx_request| {
+                    tx_request.validate(&relayer_repo_model)?;
+                    if tx_request.is_scheduled() {
+                        return Err(ApiError::BadRequest(
+                            "Scheduled transactions cannot be submitted in a batch".to_string(),
+                        ));
+                    }
+                    if tx_request.depends_on().is_some() {
+                        return Err(ApiError::BadRequest(
+                            "Dependent transactions cannot be submitted in a batch".to_string(),
+                        ));
+                    }
+                    if tx_request.idempotency_key().is_some() {
+                        return Err(ApiError::BadRequest(
+                            "Idempotency keys are not supported in a batch".to_string(),
+                        ));
+                    }
+                    Ok(tx_request)
+                },
+            );
+        let tx_request = match tx_request {
+            Ok(mut tx_request) => {
+                sign_evm_authorizations(&relayer_repo_model, &mut tx_request, &identity, &state)
+                    .await
+                    .map(|_| tx_request)
+            }
+            Err(e) => Err(e),
+        };
+        match tx_request {
+            Ok(tx_request) => {
+                valid_indices.push(index);
+                tx_requests.push(tx_request);
+            }
+            Err(e) => results.push(BatchTransactionResult::failed(index, e.to_string())),
+        }
+    }
+
+    let invalid_count = results.len();
+    if tx_requests.is_empty() || (request.all_or_nothing && invalid_count > 0) {
+        results.extend(valid_indices.into_iter().map(|index| {
+            BatchTransactionResult::failed(
+                index,
+                "Not submitted: the batch contains invalid transactions",
+            )
+        }));
+        results.sort_by_key(|result| result.index);
+
+        return Ok(HttpResponse::BadRequest().json(ApiResponse::new(
+            Some(BatchTransactionResponse::from(results)),
+            Some(format!(
+                "Batch rejected: {} invalid transactions",
+                invalid_count
+            )),
+            None,
+        )));
+    }
+
+    let transactions = relayer.process_transaction_batch(tx_requests).await?;
+
+    results.extend(
+        valid_indices
+            .into_iter()
+            .zip(transactions)
+            .map(|(index, transaction)| BatchTransactionResult::created(index, transaction.id)),
+    );
+    results.sort_by_key(|result| result.index);
+
+    Ok(
+        HttpResponse::Ok().json(ApiResponse::success(BatchTransactionResponse::from(
+            results,
+        ))),
+    )
+}
+
+/// Relays an ERC-2771 forward request through the relayer's trusted forwarder.
+///
+/// The forwarded call is checked against the relayer's policies before the forwarder
+/// verifies the request signature and nonce.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer.
+/// * `request` - The forward request and its signature.
+/// * `state` - The application state containing the relayer repository.
+///
+/// # Returns
+///
+/// The transaction calling the trusted forwarder.
+pub async fn send_forward_request(
+    relayer_id: String,
+    request: ForwardTransactionRequest,
+    state: web::ThinData<DefaultAppState>,
+) -> Result<HttpResponse, ApiError> {
+    let relayer_repo_model = get_relayer_by_id(relayer_id, &state).await?;
+    relayer_repo_model.validate_active_state()?;
+    relayer_repo_model.validate_no_active_signer_rotation()?;
+
+    request.validate(&relayer_repo_model)?;
+
+    let relayer = get_network_relayer(relayer_repo_model.id.clone(), &state).await?;
+
+    let transaction = relayer.process_forward_request(request).await?;
 
     let transaction_response: TransactionResponse = transaction.into();
 
@@ -440,10 +708,10 @@ pub async fn send_transaction(
 /// # Returns
 ///
 /// The details of the specified transaction.
-pub async fn get_transaction_by_id<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_transaction_by_id<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     transaction_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -454,6 +722,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     if relayer_id.is_empty() || transaction_id.is_empty() {
         return Ok(HttpResponse::Ok().json(ApiResponse::<()>::error(
@@ -481,10 +751,10 @@ where
 /// # Returns
 ///
 /// The details of the specified transaction.
-pub async fn get_transaction_by_nonce<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_transaction_by_nonce<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     nonce: u64,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -495,6 +765,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
 
@@ -527,10 +799,10 @@ where
 /// # Returns
 ///
 /// A paginated list of transactions
-pub async fn list_transactions<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn list_transactions<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     query: PaginationQuery,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -541,6 +813,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     get_relayer_by_id(relayer_id.clone(), &state).await?;
 
@@ -572,9 +846,9 @@ where
 /// # Returns
 ///
 /// A success response with details about cancelled and failed transactions.
-pub async fn delete_pending_transactions<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn delete_pending_transactions<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -585,6 +859,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id, &state).await?;
     relayer.validate_active_state()?;
@@ -634,6 +910,7 @@ pub async fn cancel_transaction(
 /// * `relayer_id` - The ID of the relayer.
 /// * `transaction_id` - The ID of the transaction to replace.
 /// * `request` - The new transaction request data.
+/// * `identity` - The API key replacing the transaction.
 /// * `state` - The application state containing the transaction repository.
 ///
 /// # Returns
@@ -643,14 +920,16 @@ pub async fn replace_transaction(
     relayer_id: String,
     transaction_id: String,
     request: serde_json::Value,
+    identity: ApiKeyIdentity,
     state: web::ThinData<DefaultAppState>,
 ) -> Result<HttpResponse, ApiError> {
     let relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
     relayer.validate_active_state()?;
 
-    let new_tx_request: NetworkTransactionRequest =
+    let mut new_tx_request: NetworkTransactionRequest =
         NetworkTransactionRequest::from_json(&relayer.network_type, request.clone())?;
     new_tx_request.validate(&relayer)?;
+    sign_evm_authorizations(&relayer, &mut new_tx_request, &identity, &state).await?;
 
     let transaction_to_replace = state
         .transaction_repository
@@ -678,10 +957,10 @@ pub async fn replace_transaction(
 /// # Returns
 ///
 /// The signed data response.
-pub async fn sign_data<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn sign_data<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     request: SignDataRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -692,6 +971,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
     relayer.validate_active_state()?;
@@ -717,10 +998,10 @@ where
 /// # Returns
 ///
 /// The signed typed data response.
-pub async fn sign_typed_data<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn sign_typed_data<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     request: SignTypedDataRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -731,9 +1012,12 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
     relayer.validate_active_state()?;
+    request.validate()?;
     let network_relayer = get_network_relayer_by_model(relayer, &state).await?;
 
     let result = network_relayer.sign_typed_data(request).await?;
@@ -752,10 +1036,10 @@ where
 /// # Returns
 ///
 /// The result of the JSON-RPC call.
-pub async fn relayer_rpc<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn relayer_rpc<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     request: serde_json::Value,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -766,6 +1050,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
     relayer.validate_active_state()?;
@@ -788,10 +1074,10 @@ where
 /// # Returns
 ///
 /// The signed transaction response.
-pub async fn sign_transaction<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn sign_transaction<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     relayer_id: String,
     request: SignTransactionRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -802,6 +1088,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let relayer = get_relayer_by_id(relayer_id.clone(), &state).await?;
     relayer.validate_active_state()?;
@@ -813,19 +1101,85 @@ where
     Ok(HttpResponse::Ok().json(ApiResponse::success(result)))
 }
 
+/// Starts rotating the signer of a relayer.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer.
+/// * `request` - The new signer and rotation options.
+/// * `state` - The application state containing the relayer and signer repositories.
+///
+/// # Returns
+///
+/// The recorded signer rotation.
+pub async fn rotate_relayer_signer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer_id: String,
+    request: RotateSignerRequest,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let rotation = start_signer_rotation(relayer_id, request, &state).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(rotation)))
+}
+
+/// Lists the signer rotations of a relayer, oldest first.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer.
+/// * `state` - The application state containing the relayer repository.
+///
+/// # Returns
+///
+/// The signer rotation history of the relayer.
+pub async fn list_signer_rotations<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let relayer = get_relayer_by_id(relayer_id, &state).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(relayer.signer_rotations)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         domain::SignTransactionRequestStellar,
         models::{
-            ApiResponse, CreateRelayerPolicyRequest, CreateRelayerRequest, RelayerEvmPolicy,
-            RelayerNetworkPolicyResponse, RelayerNetworkType, RelayerResponse, RelayerSolanaPolicy,
-            RelayerStellarPolicy, SolanaFeePaymentStrategy,
+            ApiResponse, CreateRelayerPolicyRequest, CreateRelayerRequest,
+            PendingTransactionsAction, RelayerEvmPolicy, RelayerNetworkPolicyResponse,
+            RelayerNetworkType, RelayerResponse, RelayerSolanaPolicy, RelayerStellarPolicy,
+            SignerRotation, SolanaFeePaymentStrategy,
         },
         utils::mocks::mockutils::{
             create_mock_app_state, create_mock_network, create_mock_notification,
-            create_mock_relayer, create_mock_signer, create_mock_transaction,
+            create_mock_relayer, create_mock_signer, create_mock_solana_relayer,
+            create_mock_transaction,
         },
     };
     use actix_web::body::to_bytes;
@@ -974,6 +1328,16 @@ mod tests {
             min_balance: Some(1000000000000000000),
             eip1559_pricing: Some(true),
             private_transactions: Some(false),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
             gas_limit_estimation: Some(true),
             whitelist_receivers: Some(vec![
                 "0x1234567890123456789012345678901234567890".to_string()
@@ -1023,6 +1387,16 @@ mod tests {
             eip1559_pricing: Some(false),
             min_balance: None,
             private_transactions: None,
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
             gas_limit_estimation: None,
             whitelist_receivers: None,
         }));
@@ -1988,6 +2362,35 @@ mod tests {
         }
     }
 
+    #[actix_web::test]
+    async fn test_delete_relayer_pool_member() {
+        let relayer = create_mock_relayer("pool-member".to_string(), false);
+        let app_state = create_mock_app_state(Some(vec![relayer]), None, None, None, None).await;
+        app_state
+            .relayer_pool_repository
+            .create(RelayerPoolRepoModel {
+                id: "pool-1".to_string(),
+                name: "Pool".to_string(),
+                network: "test".to_string(),
+                network_type: NetworkType::Evm,
+                relayer_ids: vec!["pool-member".to_string()],
+            })
+            .await
+            .unwrap();
+
+        let result = delete_relayer(
+            "pool-member".to_string(),
+            actix_web::web::ThinData(app_state),
+        )
+        .await;
+
+        if let Err(ApiError::BadRequest(msg)) = result {
+            assert!(msg.contains("member of pool 'pool-1'"));
+        } else {
+            panic!("Expected BadRequest error for pool member");
+        }
+    }
+
     #[actix_web::test]
     async fn test_delete_relayer_nonexistent() {
         let app_state = create_mock_app_state(None, None, None, None, None).await;
@@ -2112,4 +2515,90 @@ mod tests {
             panic!("Expected ForbiddenError for system disabled relayer");
         }
     }
+
+    #[actix_web::test]
+    async fn test_sign_typed_data_invalid_request() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let app_state = create_mock_app_state(Some(vec![relayer]), None, None, None, None).await;
+
+        let request = SignTypedDataRequest {
+            domain_separator: Some("0x1234".to_string()),
+            hash_struct_message: None,
+            typed_data: None,
+        };
+
+        let result = sign_typed_data(
+            "test-relayer".to_string(),
+            request,
+            actix_web::web::ThinData(app_state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    // SIGNER ROTATION TESTS
+
+    #[actix_web::test]
+    async fn test_list_signer_rotations_empty() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let app_state = create_mock_app_state(Some(vec![relayer]), None, None, None, None).await;
+
+        let result = list_signer_rotations(
+            "test-relayer".to_string(),
+            actix_web::web::ThinData(app_state),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.status(), 200);
+        let body = to_bytes(result.into_body()).await.unwrap();
+        let api_response: ApiResponse<Vec<SignerRotation>> = serde_json::from_slice(&body).unwrap();
+        assert!(api_response.data.unwrap().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_list_signer_rotations_relayer_not_found() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+
+        let result = list_signer_rotations(
+            "missing-relayer".to_string(),
+            actix_web::web::ThinData(app_state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_rotate_relayer_signer_sweep_not_supported_for_solana() {
+        let relayer = create_mock_solana_relayer("solana-relayer".to_string(), false);
+        let app_state = create_mock_app_state(
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer()]),
+            None,
+            None,
+            None,
+        )
+        .await;
+
+        let request = RotateSignerRequest {
+            signer_id: "new-signer".to_string(),
+            pending_transactions: PendingTransactionsAction::Drain,
+            sweep_balance: true,
+        };
+
+        let result = rotate_relayer_signer(
+            "solana-relayer".to_string(),
+            request,
+            actix_web::web::ThinData(app_state),
+        )
+        .await;
+
+        if let Err(ApiError::BadRequest(msg)) = result {
+            assert!(msg.contains("Balance sweep is not supported"));
+        } else {
+            panic!("Expected BadRequest for Solana balance sweep");
+        }
+    }
 }
@@ -0,0 +1,473 @@
+//! # Relayer Pools Controller
+//!
+//! Handles HTTP endpoints for relayer pool operations including:
+//! - Managing relayer pools
+//! - Aggregating the status of pool members
+//! - Routing transactions to the least loaded pool member
+
+use crate::{
+    api::controllers::relayer,
+    config::ServerConfig,
+    constants::IDEMPOTENT_REPLAYED_HEADER,
+    domain::{
+        get_relayer_pool_status as get_pool_status, release_pool_idempotency_key,
+        reserve_pool_idempotency_key, resolve_pool_network, select_pool_relayer,
+        IdempotencyReservation,
+    },
+    jobs::JobProducerTrait,
+    models::{
+        ApiError, ApiKeyIdentity, ApiResponse, CreateRelayerPoolRequest, DefaultAppState,
+        NetworkRepoModel, NotificationRepoModel, PaginationMeta, PaginationQuery,
+        RelayerPoolRepoModel, RelayerPoolResponse, RelayerRepoModel, SignerRepoModel,
+        ThinDataAppState, TransactionRepoModel, TransactionResponse, UpdateRelayerPoolRequest,
+    },
+    repositories::{
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        TransactionCounterTrait, TransactionRepository,
+    },
+    utils::generate_uuid,
+};
+use actix_web::{web, HttpResponse};
+use eyre::Result;
+
+/// Lists all relayer pools with pagination support.
+///
+/// # Arguments
+///
+/// * `query` - The pagination query parameters.
+/// * `state` - The application state containing the relayer pool repository.
+///
+/// # Returns
+///
+/// A paginated list of relayer pools.
+pub async fn list_relayer_pools<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    query: PaginationQuery,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let pools = state.relayer_pool_repository.list_paginated(query).await?;
+
+    let mapped_pools: Vec<RelayerPoolResponse> =
+        pools.items.into_iter().map(|p| p.into()).collect();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        mapped_pools,
+        PaginationMeta {
+            total_items: pools.total,
+            current_page: pools.page,
+            per_page: pools.per_page,
+        },
+    )))
+}
+
+/// Retrieves details of a specific relayer pool by ID.
+///
+/// # Arguments
+///
+/// * `pool_id` - The ID of the relayer pool to retrieve.
+/// * `state` - The application state containing the relayer pool repository.
+///
+/// # Returns
+///
+/// The details of the specified relayer pool.
+pub async fn get_relayer_pool<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let pool = state.relayer_pool_repository.get_by_id(pool_id).await?;
+
+    let pool_response: RelayerPoolResponse = pool.into();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(pool_response)))
+}
+
+/// Creates a new relayer pool.
+///
+/// The network of the pool is the network shared by its members.
+///
+/// # Arguments
+///
+/// * `request` - The relayer pool creation request.
+/// * `state` - The application state containing the relayer pool repository.
+///
+/// # Returns
+///
+/// The created relayer pool.
+pub async fn create_relayer_pool<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    request: CreateRelayerPoolRequest,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    request.validate()?;
+
+    let (network, network_type) = resolve_pool_network(&request.relayer_ids, &state).await?;
+
+    let pool = RelayerPoolRepoModel {
+        id: request.id.unwrap_or_else(generate_uuid),
+        name: request.name,
+        network,
+        network_type,
+        relayer_ids: request.relayer_ids,
+    };
+
+    let created_pool = state.relayer_pool_repository.create(pool).await?;
+
+    let pool_response: RelayerPoolResponse = created_pool.into();
+
+    Ok(HttpResponse::Created().json(ApiResponse::success(pool_response)))
+}
+
+/// Updates the name or the members of a relayer pool.
+///
+/// # Arguments
+///
+/// * `pool_id` - The ID of the relayer pool to update.
+/// * `request` - The relayer pool update request.
+/// * `state` - The application state containing the relayer pool repository.
+///
+/// # Returns
+///
+/// The updated relayer pool.
+pub async fn update_relayer_pool<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool_id: String,
+    request: UpdateRelayerPoolRequest,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    request.validate()?;
+
+    let mut pool = state
+        .relayer_pool_repository
+        .get_by_id(pool_id.clone())
+        .await?;
+
+    if let Some(name) = request.name {
+        pool.name = name;
+    }
+
+    if let Some(relayer_ids) = request.relayer_ids {
+        let (network, network_type) = resolve_pool_network(&relayer_ids, &state).await?;
+        pool.network = network;
+        pool.network_type = network_type;
+        pool.relayer_ids = relayer_ids;
+    }
+
+    let updated_pool = state.relayer_pool_repository.update(pool_id, pool).await?;
+
+    let pool_response: RelayerPoolResponse = updated_pool.into();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(pool_response)))
+}
+
+/// Deletes a relayer pool. Its members are left untouched.
+///
+/// # Arguments
+///
+/// * `pool_id` - The ID of the relayer pool to delete.
+/// * `state` - The application state containing the relayer pool repository.
+///
+/// # Returns
+///
+/// A success response or an error if the relayer pool does not exist.
+pub async fn delete_relayer_pool<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    state.relayer_pool_repository.delete_by_id(pool_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success("Relayer pool deleted successfully")))
+}
+
+/// Retrieves the status of a relayer pool, aggregated from the status of its members.
+///
+/// # Arguments
+///
+/// * `pool_id` - The ID of the relayer pool.
+/// * `state` - The application state containing the relayer pool repository.
+///
+/// # Returns
+///
+/// The status of the relayer pool and of each of its members.
+pub async fn get_relayer_pool_status<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool_id: String,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<HttpResponse, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let pool = state.relayer_pool_repository.get_by_id(pool_id).await?;
+
+    let status = get_pool_status(&pool, &state).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::success(status)))
+}
+
+/// Sends a transaction through the least loaded member of a relayer pool.
+///
+/// A transaction whose idempotency key was already used with any member of the pool is
+/// replayed instead of being routed again. The key is reserved for the pool while the
+/// transaction is routed, so concurrent retries are not sent to different members.
+///
+/// # Arguments
+///
+/// * `pool_id` - The ID of the relayer pool.
+/// * `request` - The transaction request data.
+/// * `idempotency_key` - The idempotency key supplied in the request header, if any.
+/// * `identity` - The API key submitting the transaction.
+/// * `state` - The application state containing the relayer pool repository.
+///
+/// # Returns
+///
+/// The response of the transaction processing.
+pub async fn send_pool_transaction(
+    pool_id: String,
+    request: serde_json::Value,
+    idempotency_key: Option<String>,
+    identity: ApiKeyIdentity,
+    state: web::ThinData<DefaultAppState>,
+) -> Result<HttpResponse, ApiError> {
+    let pool = state.relayer_pool_repository.get_by_id(pool_id).await?;
+
+    // The header takes precedence over the key in the request body
+    let replay_key = idempotency_key.clone().or_else(|| {
+        request
+            .get("idempotency_key")
+            .and_then(|key| key.as_str())
+            .map(String::from)
+    });
+
+    let mut reservation = None;
+    if let Some(replay_key) = replay_key {
+        match reserve_pool_idempotency_key(
+            &pool,
+            &replay_key,
+            ServerConfig::get_idempotency_window_seconds(),
+            &state,
+        )
+        .await?
+        {
+            IdempotencyReservation::Existing(transaction) => {
+                let transaction_response: TransactionResponse = (*transaction).into();
+                return Ok(HttpResponse::Ok()
+                    .insert_header((IDEMPOTENT_REPLAYED_HEADER, "true"))
+                    .json(ApiResponse::success(transaction_response)));
+            }
+            IdempotencyReservation::Reserved(reservation_id) => {
+                reservation = Some((replay_key, reservation_id));
+            }
+        }
+    }
+
+    let result = match select_pool_relayer(&pool, &state).await {
+        Ok(selected_relayer) => {
+            relayer::send_transaction(
+                selected_relayer.id,
+                request,
+                idempotency_key,
+                identity,
+                state.clone(),
+            )
+            .await
+        }
+        Err(e) => Err(e),
+    };
+
+    if let Some((replay_key, reservation_id)) = reservation {
+        release_pool_idempotency_key(&pool, &replay_key, &reservation_id, &state).await;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::NetworkType,
+        repositories::RelayerPoolRepositoryStorage,
+        utils::mocks::mockutils::{
+            create_mock_app_state, create_mock_relayer, create_mock_solana_relayer,
+        },
+    };
+    use actix_web::{body::to_bytes, web::ThinData};
+    use std::sync::Arc;
+
+    fn create_request(id: &str, relayer_ids: &[&str]) -> CreateRelayerPoolRequest {
+        CreateRelayerPoolRequest {
+            id: Some(id.to_string()),
+            name: "Pool".to_string(),
+            relayer_ids: relayer_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    fn create_relayers() -> Option<Vec<RelayerRepoModel>> {
+        Some(vec![
+            create_mock_relayer("relayer-1".to_string(), false),
+            create_mock_relayer("relayer-2".to_string(), false),
+            create_mock_solana_relayer("solana-relayer".to_string(), false),
+        ])
+    }
+
+    /// Builds mock app state backed by a shared relayer pool repository so several
+    /// controller calls observe the same pools.
+    macro_rules! state_with_repository {
+        ($repository:expr) => {{
+            let mut app_state =
+                create_mock_app_state(create_relayers(), None, None, None, None).await;
+            app_state.relayer_pool_repository = Arc::clone($repository);
+            ThinData(app_state)
+        }};
+    }
+
+    #[actix_web::test]
+    async fn test_create_relayer_pool_takes_network_from_members() {
+        let repository = Arc::new(RelayerPoolRepositoryStorage::new_in_memory());
+
+        let response = create_relayer_pool(
+            create_request("pool-1", &["relayer-1", "relayer-2"]),
+            state_with_repository!(&repository),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), 201);
+
+        let body = to_bytes(response.into_body()).await.unwrap();
+        let api_response: ApiResponse<RelayerPoolResponse> = serde_json::from_slice(&body).unwrap();
+        let pool = api_response.data.unwrap();
+        assert_eq!(pool.network, "test");
+        assert_eq!(pool.network_type, NetworkType::Evm);
+
+        let stored = repository.get_by_id("pool-1".to_string()).await.unwrap();
+        assert_eq!(stored.relayer_ids, vec!["relayer-1", "relayer-2"]);
+    }
+
+    #[actix_web::test]
+    async fn test_create_relayer_pool_rejects_mixed_networks() {
+        let repository = Arc::new(RelayerPoolRepositoryStorage::new_in_memory());
+
+        let result = create_relayer_pool(
+            create_request("pool-1", &["relayer-1", "solana-relayer"]),
+            state_with_repository!(&repository),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+        assert!(!repository.has_entries().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_update_relayer_pool_members() {
+        let repository = Arc::new(RelayerPoolRepositoryStorage::new_in_memory());
+        create_relayer_pool(
+            create_request("pool-1", &["relayer-1"]),
+            state_with_repository!(&repository),
+        )
+        .await
+        .unwrap();
+
+        let request = UpdateRelayerPoolRequest {
+            relayer_ids: Some(vec!["relayer-2".to_string()]),
+            ..Default::default()
+        };
+        let response = update_relayer_pool(
+            "pool-1".to_string(),
+            request,
+            state_with_repository!(&repository),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let stored = repository.get_by_id("pool-1".to_string()).await.unwrap();
+        assert_eq!(stored.name, "Pool");
+        assert_eq!(stored.relayer_ids, vec!["relayer-2"]);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_relayer_pool() {
+        let repository = Arc::new(RelayerPoolRepositoryStorage::new_in_memory());
+        create_relayer_pool(
+            create_request("pool-1", &["relayer-1"]),
+            state_with_repository!(&repository),
+        )
+        .await
+        .unwrap();
+
+        let response =
+            delete_relayer_pool("pool-1".to_string(), state_with_repository!(&repository))
+                .await
+                .unwrap();
+        assert_eq!(response.status(), 200);
+        assert!(!repository.has_entries().await.unwrap());
+
+        let result =
+            get_relayer_pool("pool-1".to_string(), state_with_repository!(&repository)).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
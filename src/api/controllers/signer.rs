@@ -11,11 +11,12 @@ use crate::{
     jobs::JobProducerTrait,
     models::{
         ApiError, ApiResponse, NetworkRepoModel, NotificationRepoModel, PaginationMeta,
-        PaginationQuery, RelayerRepoModel, Signer, SignerCreateRequest, SignerRepoModel,
-        SignerResponse, SignerUpdateRequest, ThinDataAppState, TransactionRepoModel,
+        PaginationQuery, RelayerPoolRepoModel, RelayerRepoModel, Signer, SignerCreateRequest,
+        SignerRepoModel, SignerResponse, SignerUpdateRequest, ThinDataAppState,
+        TransactionRepoModel,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
 };
@@ -32,9 +33,9 @@ use eyre::Result;
 /// # Returns
 ///
 /// A paginated list of signers.
-pub async fn list_signers<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn list_signers<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     query: PaginationQuery,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -45,6 +46,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let signers = state.signer_repository.list_paginated(query).await?;
 
@@ -70,9 +73,9 @@ where
 /// # Returns
 ///
 /// The signer details or an error if not found.
-pub async fn get_signer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_signer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     signer_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -83,6 +86,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     let signer = state.signer_repository.get_by_id(signer_id).await?;
 
@@ -107,9 +112,9 @@ where
 /// (keys, credentials, etc.) should be provided through configuration files or
 /// other secure channels. This is a security measure to prevent sensitive data
 /// from being transmitted through API requests.
-pub async fn create_signer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn create_signer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     request: SignerCreateRequest,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -120,6 +125,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     // Convert request to domain model (validates automatically and includes placeholder config)
     let signer = Signer::try_from(request)?;
@@ -149,10 +156,10 @@ where
 ///
 /// Signer updates are not supported for security reasons. To modify a signer,
 /// delete the existing one and create a new signer with the desired configuration.
-pub async fn update_signer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn update_signer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     _signer_id: String,
     _request: SignerUpdateRequest,
-    _state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    _state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -163,6 +170,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     Err(ApiError::BadRequest(
         "Signer updates are not allowed for security reasons. Please delete the existing signer and create a new one with the desired configuration.".to_string()
@@ -185,9 +194,9 @@ where
 /// This endpoint ensures that signers cannot be deleted if they are still being
 /// used by any relayers. This prevents breaking existing relayer configurations
 /// and maintains system integrity.
-pub async fn delete_signer<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn delete_signer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     signer_id: String,
-    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<HttpResponse, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -198,6 +207,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     // First check if the signer exists
     let _signer = state.signer_repository.get_by_id(signer_id.clone()).await?;
@@ -874,6 +885,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
         app_state.relayer_repository.create(relayer).await.unwrap();
 
@@ -934,6 +946,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
         app_state.relayer_repository.create(relayer).await.unwrap();
 
@@ -1003,6 +1016,7 @@ mod tests {
                 notification_id: None,
                 system_disabled: false,
                 custom_rpc_urls: None,
+                signer_rotations: vec![],
             },
             crate::models::RelayerRepoModel {
                 id: "relayer-2".to_string(),
@@ -1018,6 +1032,7 @@ mod tests {
                 notification_id: None,
                 system_disabled: false,
                 custom_rpc_urls: None,
+                signer_rotations: vec![],
             },
             crate::models::RelayerRepoModel {
                 id: "relayer-3".to_string(),
@@ -1033,6 +1048,7 @@ mod tests {
                 notification_id: None,
                 system_disabled: true, // Even disabled relayers should block deletion
                 custom_rpc_urls: None,
+                signer_rotations: vec![],
             },
         ];
 
@@ -1087,6 +1103,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer2 = crate::models::RelayerRepoModel {
@@ -1103,6 +1120,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         app_state.relayer_repository.create(relayer1).await.unwrap();
@@ -0,0 +1,90 @@
+//! This module defines the HTTP routes for API key operations.
+//! It includes handlers for listing, creating and revoking API keys.
+//! The routes are integrated with the Actix-web framework and interact with the API key controller.
+
+use crate::{
+    api::controllers::api_key,
+    models::{ApiKeyCreateRequest, DefaultAppState, PaginationQuery},
+};
+use actix_web::{delete, get, post, web, Responder};
+
+/// Lists all API keys with pagination support.
+#[get("/api-keys")]
+async fn list_api_keys(
+    query: web::Query<PaginationQuery>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    api_key::list_api_keys(query.into_inner(), data).await
+}
+
+/// Creates a new API key.
+#[post("/api-keys")]
+async fn create_api_key(
+    request: web::Json<ApiKeyCreateRequest>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    api_key::create_api_key(request.into_inner(), data).await
+}
+
+/// Revokes an API key by ID.
+#[delete("/api-keys/{api_key_id}")]
+async fn delete_api_key(
+    api_key_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    api_key::delete_api_key(api_key_id.into_inner(), data).await
+}
+
+/// Configures the API key routes.
+pub fn init(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_api_keys)
+        .service(create_api_key)
+        .service(delete_api_key);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mocks::mockutils::create_mock_app_state;
+    use actix_web::{http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_api_key_routes_are_registered() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(app_state))
+                .configure(init),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/api-keys").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "GET /api-keys route not registered"
+        );
+
+        let req = test::TestRequest::post()
+            .uri("/api-keys")
+            .set_json(serde_json::json!({"name": "ci", "scopes": ["read"]}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "POST /api-keys route not registered"
+        );
+
+        let req = test::TestRequest::delete()
+            .uri("/api-keys/test-id")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "DELETE /api-keys/{{id}} route not registered"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::models::{ApiKeyCreateRequest, ApiKeyCreateResponse, ApiKeyResponse, ApiResponse};
+
+/// API key routes implementation
+///
+/// Note: OpenAPI documentation for these endpoints can be found in the `openapi.rs` file
+///
+/// Lists all API keys with pagination support.
+#[utoipa::path(
+  get,
+  path = "/api/v1/api-keys",
+  tag = "API Keys",
+  operation_id = "listApiKeys",
+  security(
+      ("bearer_auth" = [])
+  ),
+  params(
+      ("page" = Option<usize>, Query, description = "Page number for pagination (starts at 1)"),
+      ("per_page" = Option<usize>, Query, description = "Number of items per page (default: 10)")
+  ),
+  responses(
+      (
+          status = 200,
+          description = "API key list retrieved successfully",
+          body = ApiResponse<Vec<ApiKeyResponse>>
+      ),
+      (
+          status = 401,
+          description = "Unauthorized",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "Unauthorized",
+              "data": null
+          })
+      ),
+      (
+          status = 403,
+          description = "Forbidden",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "API key is missing the admin scope",
+              "data": null
+          })
+      ),
+      (
+          status = 500,
+          description = "Internal Server Error",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "Internal Server Error",
+              "data": null
+          })
+      )
+  )
+)]
+#[allow(dead_code)]
+fn doc_list_api_keys() {}
+
+/// Creates a new API key.
+///
+/// The key value is only included in this response and cannot be retrieved again.
+#[utoipa::path(
+  post,
+  path = "/api/v1/api-keys",
+  tag = "API Keys",
+  operation_id = "createApiKey",
+  security(
+      ("bearer_auth" = [])
+  ),
+  request_body = ApiKeyCreateRequest,
+  responses(
+      (
+          status = 201,
+          description = "API key created successfully",
+          body = ApiResponse<ApiKeyCreateResponse>
+      ),
+      (
+          status = 400,
+          description = "Bad Request",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "API key must have at least one scope",
+              "data": null
+          })
+      ),
+      (
+          status = 401,
+          description = "Unauthorized",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "Unauthorized",
+              "data": null
+          })
+      ),
+      (
+          status = 403,
+          description = "Forbidden",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "API key is missing the admin scope",
+              "data": null
+          })
+      ),
+      (
+          status = 500,
+          description = "Internal Server Error",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "Internal Server Error",
+              "data": null
+          })
+      )
+  )
+)]
+#[allow(dead_code)]
+fn doc_create_api_key() {}
+
+/// Revokes an API key by ID.
+#[utoipa::path(
+  delete,
+  path = "/api/v1/api-keys/{api_key_id}",
+  tag = "API Keys",
+  operation_id = "deleteApiKey",
+  security(
+      ("bearer_auth" = [])
+  ),
+  params(
+      ("api_key_id" = String, Path, description = "API key ID")
+  ),
+  responses(
+      (
+          status = 200,
+          description = "API key revoked successfully",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": true,
+              "message": "API key revoked successfully",
+              "data": "API key revoked successfully"
+          })
+      ),
+      (
+          status = 401,
+          description = "Unauthorized",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "Unauthorized",
+              "data": null
+          })
+      ),
+      (
+          status = 403,
+          description = "Forbidden",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "API key is missing the admin scope",
+              "data": null
+          })
+      ),
+      (
+          status = 404,
+          description = "API key not found",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "API key not found",
+              "data": null
+          })
+      ),
+      (
+          status = 500,
+          description = "Internal Server Error",
+          body = ApiResponse<String>,
+          example = json!({
+              "success": false,
+              "message": "Internal Server Error",
+              "data": null
+          })
+      )
+  )
+)]
+#[allow(dead_code)]
+fn doc_delete_api_key() {}
@@ -1,4 +1,7 @@
+pub mod api_key_docs;
+pub mod network_docs;
 pub mod notification_docs;
 pub mod plugin_docs;
 pub mod relayer_docs;
+pub mod relayer_pool_docs;
 pub mod signer_docs;
@@ -0,0 +1,305 @@
+use crate::models::{ApiResponse, NetworkCreateRequest, NetworkResponse, NetworkUpdateRequest};
+
+/// Network routes implementation
+///
+/// Note: OpenAPI documentation for these endpoints can be found in the `openapi.rs` file
+///
+/// Lists all networks with pagination support.
+#[utoipa::path(
+    get,
+    path = "/api/v1/networks",
+    tag = "Networks",
+    operation_id = "listNetworks",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("page" = Option<usize>, Query, description = "Page number for pagination (starts at 1)"),
+        ("per_page" = Option<usize>, Query, description = "Number of items per page (default: 10)")
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Network list retrieved successfully",
+            body = ApiResponse<Vec<NetworkResponse>>
+        ),
+        (
+            status = 400,
+            description = "Bad Request",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal Server Error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        )
+    )
+)]
+#[allow(dead_code)]
+fn doc_list_networks() {}
+
+/// Retrieves details of a specific network by ID.
+#[utoipa::path(
+    get,
+    path = "/api/v1/networks/{network_id}",
+    tag = "Networks",
+    operation_id = "getNetwork",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("network_id" = String, Path, description = "Network ID, composed of the network type and name (e.g. evm:sepolia)")
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Network retrieved successfully",
+            body = ApiResponse<NetworkResponse>
+        ),
+        (
+            status = 400,
+            description = "Bad Request",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Network not found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Network not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal Server Error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        )
+    )
+)]
+#[allow(dead_code)]
+fn doc_get_network() {}
+
+/// Creates a new network.
+#[utoipa::path(
+    post,
+    path = "/api/v1/networks",
+    tag = "Networks",
+    operation_id = "createNetwork",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = NetworkCreateRequest,
+    responses(
+        (
+            status = 201,
+            description = "Network created successfully",
+            body = ApiResponse<NetworkResponse>
+        ),
+        (
+            status = 400,
+            description = "Bad Request",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal Server Error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        )
+    )
+)]
+#[allow(dead_code)]
+fn doc_create_network() {}
+
+/// Updates an existing network.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/networks/{network_id}",
+    tag = "Networks",
+    operation_id = "updateNetwork",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("network_id" = String, Path, description = "Network ID, composed of the network type and name (e.g. evm:sepolia)")
+    ),
+    request_body = NetworkUpdateRequest,
+    responses(
+        (
+            status = 200,
+            description = "Network updated successfully",
+            body = ApiResponse<NetworkResponse>
+        ),
+        (
+            status = 400,
+            description = "Bad Request",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Network not found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Network not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal Server Error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        )
+    )
+)]
+#[allow(dead_code)]
+fn doc_update_network() {}
+
+/// Deletes a network by ID.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/networks/{network_id}",
+    tag = "Networks",
+    operation_id = "deleteNetwork",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("network_id" = String, Path, description = "Network ID, composed of the network type and name (e.g. evm:sepolia)")
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Network deleted successfully",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": true,
+                "message": "Network deleted successfully",
+                "data": "Network deleted successfully"
+            })
+        ),
+        (
+            status = 400,
+            description = "Bad Request",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Network not found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Network not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal Server Error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        )
+    )
+)]
+#[allow(dead_code)]
+fn doc_delete_network() {}
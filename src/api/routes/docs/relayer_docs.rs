@@ -9,6 +9,10 @@
 //! - `POST /api/v1/relayers`: Create a new relayer
 //! - `PATCH /api/v1/relayers/{id}`: Update a relayer
 //! - `DELETE /api/v1/relayers/{id}`: Delete a relayer
+//! - `POST /api/v1/relayers/{id}/signer-rotations`: Rotate the signer of a relayer
+//! - `GET /api/v1/relayers/{id}/signer-rotations`: List signer rotations of a relayer
+//! - `POST /api/v1/relayers/{id}/transactions/batch`: Send a batch of transactions
+//! - `POST /api/v1/relayers/{id}/transactions/forward`: Relay an ERC-2771 forward request
 
 use crate::{
     domain::{
@@ -16,9 +20,11 @@ use crate::{
         SignTransactionRequest, SignTypedDataRequest,
     },
     models::{
-        ApiResponse, CreateRelayerRequest, DeletePendingTransactionsResponse, JsonRpcRequest,
+        ApiResponse, BatchTransactionRequest, BatchTransactionResponse, CreateRelayerRequest,
+        DeletePendingTransactionsResponse, ForwardTransactionRequest, JsonRpcRequest,
         JsonRpcResponse, NetworkRpcRequest, NetworkRpcResult, NetworkTransactionRequest,
-        RelayerResponse, RelayerStatus, TransactionResponse, UpdateRelayerRequest,
+        NonceHealth, RelayerResponse, RelayerStatus, RotateSignerRequest, SignerRotation,
+        TransactionResponse, UpdateRelayerRequest,
     },
 };
 
@@ -426,6 +432,75 @@ fn doc_delete_relayer() {}
 #[allow(dead_code)]
 fn doc_get_relayer_status() {}
 
+/// Analyzes the nonces of a specific EVM relayer and reports nonce gaps.
+#[utoipa::path(
+    get,
+    path = "/api/v1/relayers/{relayer_id}/nonce-health",
+    tag = "Relayers",
+    operation_id = "getRelayerNonceHealth",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("relayer_id" = String, Path, description = "The unique identifier of the relayer")
+    ),
+    responses(
+        (status = 200, description = "Relayer nonce health retrieved successfully", body = ApiResponse<NonceHealth>),
+        (
+            status = 400,
+            description = "BadRequest",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer with ID relayer_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 429,
+            description = "Too Many Requests",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Too Many Requests",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_get_relayer_nonce_health() {}
+
 /// Retrieves the balance of a specific relayer.
 #[utoipa::path(
     get,
@@ -505,11 +580,19 @@ fn doc_get_relayer_balance() {}
         ("bearer_auth" = [])
     ),
     params(
-        ("relayer_id" = String, Path, description = "The unique identifier of the relayer")
+        ("relayer_id" = String, Path, description = "The unique identifier of the relayer"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Key identifying retries of the same submission. Overrides `idempotency_key` in the request body")
     ),
     request_body = NetworkTransactionRequest,
     responses(
-        (status = 200, description = "Relayer transactions sent successfully", body = ApiResponse<TransactionResponse>),
+        (
+            status = 200,
+            description = "Relayer transactions sent successfully",
+            body = ApiResponse<TransactionResponse>,
+            headers(
+                ("Idempotent-Replayed" = String, description = "Set to `true` when the response returns a previously submitted transaction")
+            )
+        ),
         (
             status = 400,
             description = "BadRequest",
@@ -565,6 +648,161 @@ fn doc_get_relayer_balance() {}
 #[allow(dead_code)]
 fn doc_send_transaction() {}
 
+/// Sends a batch of transactions through the specified relayer.
+///
+/// All transactions are validated before any is created. Valid transactions are assigned
+/// contiguous nonces in request order. With `all_or_nothing`, one invalid transaction
+/// rejects the whole batch.
+#[utoipa::path(
+    post,
+    path = "/api/v1/relayers/{relayer_id}/transactions/batch",
+    tag = "Relayers",
+    operation_id = "sendTransactionBatch",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("relayer_id" = String, Path, description = "The unique identifier of the relayer")
+    ),
+    request_body = BatchTransactionRequest,
+    responses(
+        (status = 200, description = "Relayer transaction batch processed", body = ApiResponse<BatchTransactionResponse>),
+        (
+            status = 400,
+            description = "Batch rejected",
+            body = ApiResponse<BatchTransactionResponse>,
+            example = json!({
+                "success": false,
+                "error": "Batch rejected: 1 invalid transactions",
+                "data": {
+                    "created": 0,
+                    "failed": 2,
+                    "results": [
+                        { "index": 0, "error": "Not submitted: the batch contains invalid transactions" },
+                        { "index": 1, "error": "Bad Request: Invalid destination address" }
+                    ]
+                }
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer with ID relayer_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 429,
+            description = "Too Many Requests",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Too Many Requests",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_send_transaction_batch() {}
+
+/// Relays an ERC-2771 forward request through the relayer's trusted forwarder.
+///
+/// The forwarded call is checked against the relayer's policies, and the forwarder
+/// verifies the request signature and nonce before the relayer submits a transaction
+/// calling the forwarder's `execute` function.
+#[utoipa::path(
+    post,
+    path = "/api/v1/relayers/{relayer_id}/transactions/forward",
+    tag = "Relayers",
+    operation_id = "sendForwardRequest",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("relayer_id" = String, Path, description = "The unique identifier of the relayer")
+    ),
+    request_body = ForwardTransactionRequest,
+    responses(
+        (status = 200, description = "Forward request relayed successfully", body = ApiResponse<TransactionResponse>),
+        (
+            status = 400,
+            description = "BadRequest",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer with ID relayer_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 429,
+            description = "Too Many Requests",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Too Many Requests",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_send_forward_request() {}
+
 /// Retrieves a specific transaction by its ID.
 #[utoipa::path(
     get,
@@ -1275,3 +1513,135 @@ fn doc_sign_transaction() {}
 )]
 #[allow(dead_code)]
 fn doc_rpc() {}
+
+/// Starts rotating the signer of the specified relayer.
+///
+/// New transactions are rejected while the rotation is in progress. The relayer switches
+/// to the new signer once transactions pending on the current address are final.
+#[utoipa::path(
+    post,
+    path = "/api/v1/relayers/{relayer_id}/signer-rotations",
+    tag = "Relayers",
+    operation_id = "rotateRelayerSigner",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("relayer_id" = String, Path, description = "The unique identifier of the relayer"),
+    ),
+    request_body = RotateSignerRequest,
+    responses(
+        (status = 200, description = "Signer rotation started", body = ApiResponse<SignerRotation>),
+        (
+            status = 400,
+            description = "BadRequest",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer with ID relayer_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 429,
+            description = "Too Many Requests",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Too Many Requests",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_rotate_relayer_signer() {}
+
+/// Lists the signer rotations of the specified relayer, oldest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/relayers/{relayer_id}/signer-rotations",
+    tag = "Relayers",
+    operation_id = "listSignerRotations",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("relayer_id" = String, Path, description = "The unique identifier of the relayer"),
+    ),
+    responses(
+        (status = 200, description = "Signer rotation history", body = ApiResponse<Vec<SignerRotation>>),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer with ID relayer_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 429,
+            description = "Too Many Requests",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Too Many Requests",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_list_signer_rotations() {}
@@ -0,0 +1,399 @@
+use crate::models::{
+    ApiResponse, CreateRelayerPoolRequest, NetworkTransactionRequest, RelayerPoolResponse,
+    RelayerPoolStatus, TransactionResponse, UpdateRelayerPoolRequest,
+};
+
+/// Relayer pool routes implementation
+///
+/// Note: OpenAPI documentation for these endpoints can be found in the `openapi.rs` file
+///
+/// Lists all relayer pools with pagination support.
+#[utoipa::path(
+    get,
+    path = "/api/v1/pools",
+    tag = "Relayer Pools",
+    operation_id = "listRelayerPools",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("page" = Option<usize>, Query, description = "Page number for pagination (starts at 1)"),
+        ("per_page" = Option<usize>, Query, description = "Number of items per page (default: 10)")
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Relayer pool list retrieved successfully",
+            body = ApiResponse<Vec<RelayerPoolResponse>>
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_list_relayer_pools() {}
+
+/// Retrieves details of a specific relayer pool by ID.
+#[utoipa::path(
+    get,
+    path = "/api/v1/pools/{pool_id}",
+    tag = "Relayer Pools",
+    operation_id = "getRelayerPool",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("pool_id" = String, Path, description = "The unique identifier of the relayer pool")
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Relayer pool retrieved successfully",
+            body = ApiResponse<RelayerPoolResponse>
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer pool with ID pool_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_get_relayer_pool() {}
+
+/// Creates a new relayer pool.
+///
+/// All member relayers must operate on the same network, which becomes the network of the pool.
+#[utoipa::path(
+    post,
+    path = "/api/v1/pools",
+    tag = "Relayer Pools",
+    operation_id = "createRelayerPool",
+    security(
+        ("bearer_auth" = [])
+    ),
+    request_body = CreateRelayerPoolRequest,
+    responses(
+        (
+            status = 201,
+            description = "Relayer pool created successfully",
+            body = ApiResponse<RelayerPoolResponse>
+        ),
+        (
+            status = 400,
+            description = "BadRequest",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_create_relayer_pool() {}
+
+/// Updates the name or the members of a relayer pool.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/pools/{pool_id}",
+    tag = "Relayer Pools",
+    operation_id = "updateRelayerPool",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("pool_id" = String, Path, description = "The unique identifier of the relayer pool")
+    ),
+    request_body = UpdateRelayerPoolRequest,
+    responses(
+        (
+            status = 200,
+            description = "Relayer pool updated successfully",
+            body = ApiResponse<RelayerPoolResponse>
+        ),
+        (
+            status = 400,
+            description = "BadRequest",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer pool with ID pool_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_update_relayer_pool() {}
+
+/// Deletes a relayer pool by ID. The member relayers are not affected.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/pools/{pool_id}",
+    tag = "Relayer Pools",
+    operation_id = "deleteRelayerPool",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("pool_id" = String, Path, description = "The unique identifier of the relayer pool")
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Relayer pool deleted successfully",
+            body = ApiResponse<String>
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer pool with ID pool_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_delete_relayer_pool() {}
+
+/// Retrieves the status of a relayer pool, aggregated from the status of its members.
+#[utoipa::path(
+    get,
+    path = "/api/v1/pools/{pool_id}/status",
+    tag = "Relayer Pools",
+    operation_id = "getRelayerPoolStatus",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("pool_id" = String, Path, description = "The unique identifier of the relayer pool")
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Relayer pool status retrieved successfully",
+            body = ApiResponse<RelayerPoolStatus>
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer pool with ID pool_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_get_relayer_pool_status() {}
+
+/// Sends a transaction through a relayer pool.
+///
+/// The transaction is routed to the active member with the fewest pending transactions
+/// that holds the minimum balance of its policy.
+#[utoipa::path(
+    post,
+    path = "/api/v1/pools/{pool_id}/transactions",
+    tag = "Relayer Pools",
+    operation_id = "sendPoolTransaction",
+    security(
+        ("bearer_auth" = [])
+    ),
+    params(
+        ("pool_id" = String, Path, description = "The unique identifier of the relayer pool"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Key identifying retries of the same submission across all members of the pool. Overrides `idempotency_key` in the request body")
+    ),
+    request_body = NetworkTransactionRequest,
+    responses(
+        (
+            status = 200,
+            description = "Transaction sent successfully",
+            body = ApiResponse<TransactionResponse>,
+            headers(
+                ("Idempotent-Replayed" = String, description = "Set to `true` when the response returns a previously submitted transaction")
+            )
+        ),
+        (
+            status = 400,
+            description = "BadRequest",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Bad Request",
+                "data": null
+            })
+        ),
+        (
+            status = 401,
+            description = "Unauthorized",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Unauthorized",
+                "data": null
+            })
+        ),
+        (
+            status = 404,
+            description = "Not Found",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Relayer pool with ID pool_id not found",
+                "data": null
+            })
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = ApiResponse<String>,
+            example = json!({
+                "success": false,
+                "message": "Internal Server Error",
+                "data": null
+            })
+        ),
+    )
+)]
+#[allow(dead_code)]
+fn doc_send_pool_transaction() {}
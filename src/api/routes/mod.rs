@@ -6,23 +6,32 @@
 //!
 //! * `/health` - Health check endpoints
 //! * `/relayers` - Relayer management endpoints
+//! * `/pools` - Relayer pool management endpoints
+//! * `/networks` - Network management endpoints
 //! * `/notifications` - Notification management endpoints
 //! * `/signers` - Signer management endpoints
+//! * `/api-keys` - API key management endpoints
 
+pub mod api_key;
 pub mod docs;
 pub mod health;
 pub mod metrics;
+pub mod network;
 pub mod notification;
 pub mod plugin;
 pub mod relayer;
+pub mod relayer_pool;
 pub mod signer;
 
 use actix_web::web;
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
     cfg.configure(health::init)
         .configure(relayer::init)
+        .configure(relayer_pool::init)
         .configure(plugin::init)
         .configure(metrics::init)
+        .configure(network::init)
         .configure(notification::init)
-        .configure(signer::init);
+        .configure(signer::init)
+        .configure(api_key::init);
 }
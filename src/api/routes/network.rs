@@ -0,0 +1,145 @@
+//! This module defines the HTTP routes for network operations.
+//! It includes handlers for listing, retrieving, creating, updating, and deleting networks.
+//! The routes are integrated with the Actix-web framework and interact with the network controller.
+
+use crate::{
+    api::controllers::network,
+    models::{DefaultAppState, NetworkCreateRequest, NetworkUpdateRequest, PaginationQuery},
+};
+use actix_web::{delete, get, patch, post, web, Responder};
+
+/// Lists all networks with pagination support.
+#[get("/networks")]
+async fn list_networks(
+    query: web::Query<PaginationQuery>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    network::list_networks(query.into_inner(), data).await
+}
+
+/// Retrieves details of a specific network by ID.
+#[get("/networks/{network_id}")]
+async fn get_network(
+    network_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    network::get_network(network_id.into_inner(), data).await
+}
+
+/// Creates a new network.
+#[post("/networks")]
+async fn create_network(
+    request: web::Json<NetworkCreateRequest>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    network::create_network(request.into_inner(), data).await
+}
+
+/// Updates an existing network.
+#[patch("/networks/{network_id}")]
+async fn update_network(
+    network_id: web::Path<String>,
+    request: web::Json<NetworkUpdateRequest>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    network::update_network(network_id.into_inner(), request.into_inner(), data).await
+}
+
+/// Deletes a network by ID.
+#[delete("/networks/{network_id}")]
+async fn delete_network(
+    network_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    network::delete_network(network_id.into_inner(), data).await
+}
+
+/// Configures the network routes.
+pub fn init(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_networks)
+        .service(get_network)
+        .service(create_network)
+        .service(update_network)
+        .service(delete_network);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mocks::mockutils::create_mock_app_state;
+    use actix_web::{http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_network_routes_are_registered() {
+        // Arrange - Create app with network routes
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(app_state))
+                .configure(init),
+        )
+        .await;
+
+        // Test GET /networks - should not return 404 (route exists)
+        let req = test::TestRequest::get().uri("/networks").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "GET /networks route not registered"
+        );
+
+        // Test GET /networks/{id} - should not return 404
+        let req = test::TestRequest::get()
+            .uri("/networks/evm:test")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "GET /networks/{{id}} route not registered"
+        );
+
+        // Test POST /networks - should not return 404
+        let req = test::TestRequest::post()
+            .uri("/networks")
+            .set_json(serde_json::json!({
+                "type": "evm",
+                "network": "devnet",
+                "rpc_urls": ["https://rpc.example.com"],
+                "chain_id": 1337,
+                "required_confirmations": 1,
+                "symbol": "ETH"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "POST /networks route not registered"
+        );
+
+        // Test PATCH /networks/{id} - should not return 404
+        let req = test::TestRequest::patch()
+            .uri("/networks/evm:test")
+            .set_json(serde_json::json!({"rpc_urls": ["https://updated.com"]}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "PATCH /networks/{{id}} route not registered"
+        );
+
+        // Test DELETE /networks/{id} - should not return 404
+        let req = test::TestRequest::delete()
+            .uri("/networks/evm:test")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_ne!(
+            resp.status(),
+            StatusCode::NOT_FOUND,
+            "DELETE /networks/{{id}} route not registered"
+        );
+    }
+}
@@ -3,10 +3,14 @@
 //! The routes are integrated with the Actix-web framework and interact with the relayer controller.
 use crate::{
     api::controllers::relayer,
+    constants::IDEMPOTENCY_KEY_HEADER,
     domain::{SignDataRequest, SignTransactionRequest, SignTypedDataRequest},
-    models::{CreateRelayerRequest, DefaultAppState, PaginationQuery},
+    models::{
+        ApiKeyIdentity, BatchTransactionRequest, CreateRelayerRequest, DefaultAppState,
+        ForwardTransactionRequest, PaginationQuery, RotateSignerRequest,
+    },
 };
-use actix_web::{delete, get, patch, post, put, web, Responder};
+use actix_web::{delete, get, patch, post, put, web, HttpRequest, Responder};
 use serde::Deserialize;
 use utoipa::ToSchema;
 
@@ -65,6 +69,15 @@ async fn get_relayer_status(
     relayer::get_relayer_status(relayer_id.into_inner(), data).await
 }
 
+/// Retrieves the nonce health of a specific relayer.
+#[get("/relayers/{relayer_id}/nonce-health")]
+async fn get_relayer_nonce_health(
+    relayer_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer::get_relayer_nonce_health(relayer_id.into_inner(), data).await
+}
+
 /// Retrieves the balance of a specific relayer.
 #[get("/relayers/{relayer_id}/balance")]
 async fn get_relayer_balance(
@@ -79,9 +92,51 @@ async fn get_relayer_balance(
 async fn send_transaction(
     relayer_id: web::Path<String>,
     req: web::Json<serde_json::Value>,
+    http_req: HttpRequest,
+    identity: web::ReqData<ApiKeyIdentity>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    let idempotency_key = http_req
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from);
+
+    relayer::send_transaction(
+        relayer_id.into_inner(),
+        req.into_inner(),
+        idempotency_key,
+        identity.into_inner(),
+        data,
+    )
+    .await
+}
+
+/// Sends a batch of transactions through the specified relayer.
+#[post("/relayers/{relayer_id}/transactions/batch")]
+async fn send_transaction_batch(
+    relayer_id: web::Path<String>,
+    req: web::Json<BatchTransactionRequest>,
+    identity: web::ReqData<ApiKeyIdentity>,
     data: web::ThinData<DefaultAppState>,
 ) -> impl Responder {
-    relayer::send_transaction(relayer_id.into_inner(), req.into_inner(), data).await
+    relayer::send_transaction_batch(
+        relayer_id.into_inner(),
+        req.into_inner(),
+        identity.into_inner(),
+        data,
+    )
+    .await
+}
+
+/// Relays a signed ERC-2771 forward request through the relayer's trusted forwarder.
+#[post("/relayers/{relayer_id}/transactions/forward")]
+async fn send_forward_request(
+    relayer_id: web::Path<String>,
+    req: web::Json<ForwardTransactionRequest>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer::send_forward_request(relayer_id.into_inner(), req.into_inner(), data).await
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -144,10 +199,18 @@ async fn cancel_transaction(
 async fn replace_transaction(
     path: web::Path<TransactionPath>,
     req: web::Json<serde_json::Value>,
+    identity: web::ReqData<ApiKeyIdentity>,
     data: web::ThinData<DefaultAppState>,
 ) -> impl Responder {
     let path = path.into_inner();
-    relayer::replace_transaction(path.relayer_id, path.transaction_id, req.into_inner(), data).await
+    relayer::replace_transaction(
+        path.relayer_id,
+        path.transaction_id,
+        req.into_inner(),
+        identity.into_inner(),
+        data,
+    )
+    .await
 }
 
 /// Signs data using the specified relayer.
@@ -190,10 +253,31 @@ async fn rpc(
     relayer::relayer_rpc(relayer_id.into_inner(), req.into_inner(), data).await
 }
 
+/// Starts rotating the signer of the specified relayer.
+#[post("/relayers/{relayer_id}/signer-rotations")]
+async fn rotate_relayer_signer(
+    relayer_id: web::Path<String>,
+    req: web::Json<RotateSignerRequest>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer::rotate_relayer_signer(relayer_id.into_inner(), req.into_inner(), data).await
+}
+
+/// Lists the signer rotations of the specified relayer.
+#[get("/relayers/{relayer_id}/signer-rotations")]
+async fn list_signer_rotations(
+    relayer_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer::list_signer_rotations(relayer_id.into_inner(), data).await
+}
+
 /// Initializes the routes for the relayer module.
 pub fn init(cfg: &mut web::ServiceConfig) {
     // Register routes with literal segments before routes with path parameters
     cfg.service(delete_pending_transactions); // /relayers/{id}/transactions/pending
+    cfg.service(send_transaction_batch); // /relayers/{id}/transactions/batch
+    cfg.service(send_forward_request); // /relayers/{id}/transactions/forward
 
     // Then register other routes
     cfg.service(cancel_transaction); // /relayers/{id}/transactions/{tx_id}
@@ -204,10 +288,13 @@ pub fn init(cfg: &mut web::ServiceConfig) {
     cfg.service(list_transactions); // /relayers/{id}/transactions
     cfg.service(get_relayer_status); // /relayers/{id}/status
     cfg.service(get_relayer_balance); // /relayers/{id}/balance
+    cfg.service(get_relayer_nonce_health); // /relayers/{id}/nonce-health
     cfg.service(sign); // /relayers/{id}/sign
     cfg.service(sign_typed_data); // /relayers/{id}/sign-typed-data
     cfg.service(sign_transaction); // /relayers/{id}/sign-transaction
     cfg.service(rpc); // /relayers/{id}/rpc
+    cfg.service(rotate_relayer_signer); // /relayers/{id}/signer-rotations
+    cfg.service(list_signer_rotations); // /relayers/{id}/signer-rotations
     cfg.service(get_relayer); // /relayers/{id}
     cfg.service(create_relayer); // /relayers
     cfg.service(update_relayer); // /relayers/{id}
@@ -228,9 +315,10 @@ mod tests {
             TransactionRepoModel, TransactionStatus, U256,
         },
         repositories::{
-            NetworkRepositoryStorage, NotificationRepositoryStorage, PluginRepositoryStorage,
-            RelayerRepositoryStorage, Repository, SignerRepositoryStorage,
-            TransactionCounterRepositoryStorage, TransactionRepositoryStorage,
+            ApiKeyRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage,
+            PluginRepositoryStorage, RelayerPoolRepositoryStorage, RelayerRepositoryStorage,
+            Repository, SignerRepositoryStorage, TransactionCounterRepositoryStorage,
+            TransactionRepositoryStorage,
         },
     };
     use actix_web::{http::StatusCode, test, App};
@@ -246,6 +334,8 @@ mod tests {
         SignerRepositoryStorage,
         TransactionCounterRepositoryStorage,
         PluginRepositoryStorage,
+        ApiKeyRepositoryStorage,
+        RelayerPoolRepositoryStorage,
     > {
         let relayer_repo = Arc::new(RelayerRepositoryStorage::new_in_memory());
         let transaction_repo = Arc::new(TransactionRepositoryStorage::new_in_memory());
@@ -274,6 +364,8 @@ mod tests {
                 features: None,
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             }),
         };
         network_repo.create(test_network).await.unwrap();
@@ -300,6 +392,7 @@ mod tests {
             policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
         relayer_repo.create(test_relayer).await.unwrap();
 
@@ -328,12 +421,26 @@ mod tests {
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: vec!["0xabcdef".to_string()],
             network_type: NetworkType::Evm,
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
         transaction_repo.create(test_transaction).await.unwrap();
@@ -349,6 +456,8 @@ mod tests {
             ),
             job_producer: Arc::new(MockJobProducerTrait::new()),
             plugin_repository: Arc::new(PluginRepositoryStorage::new_in_memory()),
+            api_key_repository: Arc::new(ApiKeyRepositoryStorage::new_in_memory()),
+            relayer_pool_repository: Arc::new(RelayerPoolRepositoryStorage::new_in_memory()),
         }
     }
 
@@ -398,6 +507,13 @@ mod tests {
         let resp = test::call_service(&app, req).await;
         assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
 
+        // Test GET /relayers/{id}/nonce-health
+        let req = test::TestRequest::get()
+            .uri("/relayers/test-id/nonce-health")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
         // Test POST /relayers/{id}/transactions
         let req = test::TestRequest::post()
             .uri("/relayers/test-id/transactions")
@@ -406,6 +522,32 @@ mod tests {
         let resp = test::call_service(&app, req).await;
         assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
 
+        // Test POST /relayers/{id}/transactions/batch
+        let req = test::TestRequest::post()
+            .uri("/relayers/test-id/transactions/batch")
+            .set_json(serde_json::json!({"transactions": [{}]}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        // Test POST /relayers/{id}/transactions/forward
+        let req = test::TestRequest::post()
+            .uri("/relayers/test-id/transactions/forward")
+            .set_json(serde_json::json!({
+                "request": {
+                    "from": "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+                    "to": "0x5FbDB2315678afecb367f032d93F642f64180aa3",
+                    "value": 0,
+                    "gas": 100000,
+                    "nonce": 0,
+                    "data": "0x"
+                },
+                "signature": "0x"
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
         // Test GET /relayers/{id}/transactions/{tx_id}
         let req = test::TestRequest::get()
             .uri("/relayers/test-id/transactions/tx-123")
@@ -483,6 +625,21 @@ mod tests {
         let resp = test::call_service(&app, req).await;
         assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
 
+        // Test POST /relayers/{id}/signer-rotations
+        let req = test::TestRequest::post()
+            .uri("/relayers/test-id/signer-rotations")
+            .set_json(serde_json::json!({"signer_id": "new-signer"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+        // Test GET /relayers/{id}/signer-rotations
+        let req = test::TestRequest::get()
+            .uri("/relayers/test-id/signer-rotations")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
         Ok(())
     }
 }
@@ -0,0 +1,166 @@
+//! This module defines the HTTP routes for relayer pool operations.
+//! It includes handlers for managing relayer pools and sending transactions through them.
+//! The routes are integrated with the Actix-web framework and interact with the relayer pool controller.
+
+use crate::{
+    api::controllers::relayer_pool,
+    constants::IDEMPOTENCY_KEY_HEADER,
+    models::{
+        ApiKeyIdentity, CreateRelayerPoolRequest, DefaultAppState, PaginationQuery,
+        UpdateRelayerPoolRequest,
+    },
+};
+use actix_web::{delete, get, patch, post, web, HttpRequest, Responder};
+
+/// Lists all relayer pools with pagination support.
+#[get("/pools")]
+async fn list_relayer_pools(
+    query: web::Query<PaginationQuery>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer_pool::list_relayer_pools(query.into_inner(), data).await
+}
+
+/// Retrieves details of a specific relayer pool by ID.
+#[get("/pools/{pool_id}")]
+async fn get_relayer_pool(
+    pool_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer_pool::get_relayer_pool(pool_id.into_inner(), data).await
+}
+
+/// Creates a new relayer pool.
+#[post("/pools")]
+async fn create_relayer_pool(
+    request: web::Json<CreateRelayerPoolRequest>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer_pool::create_relayer_pool(request.into_inner(), data).await
+}
+
+/// Updates a relayer pool.
+#[patch("/pools/{pool_id}")]
+async fn update_relayer_pool(
+    pool_id: web::Path<String>,
+    request: web::Json<UpdateRelayerPoolRequest>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer_pool::update_relayer_pool(pool_id.into_inner(), request.into_inner(), data).await
+}
+
+/// Deletes a relayer pool by ID.
+#[delete("/pools/{pool_id}")]
+async fn delete_relayer_pool(
+    pool_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer_pool::delete_relayer_pool(pool_id.into_inner(), data).await
+}
+
+/// Retrieves the aggregated status of a relayer pool.
+#[get("/pools/{pool_id}/status")]
+async fn get_relayer_pool_status(
+    pool_id: web::Path<String>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    relayer_pool::get_relayer_pool_status(pool_id.into_inner(), data).await
+}
+
+/// Sends a transaction through the least loaded member of a relayer pool.
+#[post("/pools/{pool_id}/transactions")]
+async fn send_pool_transaction(
+    pool_id: web::Path<String>,
+    req: web::Json<serde_json::Value>,
+    http_req: HttpRequest,
+    identity: web::ReqData<ApiKeyIdentity>,
+    data: web::ThinData<DefaultAppState>,
+) -> impl Responder {
+    let idempotency_key = http_req
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from);
+
+    relayer_pool::send_pool_transaction(
+        pool_id.into_inner(),
+        req.into_inner(),
+        idempotency_key,
+        identity.into_inner(),
+        data,
+    )
+    .await
+}
+
+/// Configures the relayer pool routes.
+pub fn init(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_relayer_pools)
+        .service(create_relayer_pool)
+        .service(get_relayer_pool_status)
+        .service(send_pool_transaction)
+        .service(get_relayer_pool)
+        .service(update_relayer_pool)
+        .service(delete_relayer_pool);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mocks::mockutils::create_mock_app_state;
+    use actix_web::{http::StatusCode, test, web, App};
+
+    #[actix_web::test]
+    async fn test_relayer_pool_routes_are_registered() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(app_state))
+                .configure(init),
+        )
+        .await;
+
+        let requests = vec![
+            ("GET /pools", test::TestRequest::get().uri("/pools")),
+            (
+                "POST /pools",
+                test::TestRequest::post()
+                    .uri("/pools")
+                    .set_json(serde_json::json!({"name": "pool", "relayer_ids": ["r1"]})),
+            ),
+            (
+                "GET /pools/{id}",
+                test::TestRequest::get().uri("/pools/test-id"),
+            ),
+            (
+                "PATCH /pools/{id}",
+                test::TestRequest::patch()
+                    .uri("/pools/test-id")
+                    .set_json(serde_json::json!({"name": "pool"})),
+            ),
+            (
+                "DELETE /pools/{id}",
+                test::TestRequest::delete().uri("/pools/test-id"),
+            ),
+            (
+                "GET /pools/{id}/status",
+                test::TestRequest::get().uri("/pools/test-id/status"),
+            ),
+            (
+                "POST /pools/{id}/transactions",
+                test::TestRequest::post()
+                    .uri("/pools/test-id/transactions")
+                    .set_json(serde_json::json!({})),
+            ),
+        ];
+
+        for (route, req) in requests {
+            let resp = test::call_service(&app, req.to_request()).await;
+            assert_ne!(
+                resp.status(),
+                StatusCode::NOT_FOUND,
+                "{} route not registered",
+                route
+            );
+        }
+    }
+}
@@ -7,9 +7,9 @@ use crate::{
     jobs::{self, Queue},
     models::{AppState, DefaultAppState},
     repositories::{
-        NetworkRepositoryStorage, NotificationRepositoryStorage, PluginRepositoryStorage,
-        RelayerRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage,
-        TransactionRepositoryStorage,
+        ApiKeyRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage,
+        PluginRepositoryStorage, RelayerPoolRepositoryStorage, RelayerRepositoryStorage,
+        SignerRepositoryStorage, TransactionCounterRepositoryStorage, TransactionRepositoryStorage,
     },
     utils::initialize_redis_connection,
 };
@@ -26,6 +26,8 @@ pub struct RepositoryCollection {
     pub network: Arc<NetworkRepositoryStorage>,
     pub transaction_counter: Arc<TransactionCounterRepositoryStorage>,
     pub plugin: Arc<PluginRepositoryStorage>,
+    pub api_key: Arc<ApiKeyRepositoryStorage>,
+    pub relayer_pool: Arc<RelayerPoolRepositoryStorage>,
 }
 
 /// Initializes repositories based on the server configuration
@@ -45,6 +47,8 @@ pub async fn initialize_repositories(config: &ServerConfig) -> eyre::Result<Repo
             network: Arc::new(NetworkRepositoryStorage::new_in_memory()),
             transaction_counter: Arc::new(TransactionCounterRepositoryStorage::new_in_memory()),
             plugin: Arc::new(PluginRepositoryStorage::new_in_memory()),
+            api_key: Arc::new(ApiKeyRepositoryStorage::new_in_memory()),
+            relayer_pool: Arc::new(RelayerPoolRepositoryStorage::new_in_memory()),
         },
         RepositoryStorageType::Redis => {
             warn!("⚠️ Redis repository storage support is experimental. Use with caution.");
@@ -82,6 +86,14 @@ pub async fn initialize_repositories(config: &ServerConfig) -> eyre::Result<Repo
                     config.redis_key_prefix.clone(),
                 )?),
                 plugin: Arc::new(PluginRepositoryStorage::new_redis(
+                    connection_manager.clone(),
+                    config.redis_key_prefix.clone(),
+                )?),
+                api_key: Arc::new(ApiKeyRepositoryStorage::new_redis(
+                    connection_manager.clone(),
+                    config.redis_key_prefix.clone(),
+                )?),
+                relayer_pool: Arc::new(RelayerPoolRepositoryStorage::new_redis(
                     connection_manager,
                     config.redis_key_prefix.clone(),
                 )?),
@@ -120,6 +132,8 @@ pub async fn initialize_app_state(
         transaction_counter_store: repositories.transaction_counter,
         job_producer,
         plugin_repository: repositories.plugin,
+        api_key_repository: repositories.api_key,
+        relayer_pool_repository: repositories.relayer_pool,
     });
 
     Ok(app_state)
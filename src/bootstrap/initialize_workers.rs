@@ -4,7 +4,8 @@
 //! including job processors and other long-running tasks.
 use crate::{
     jobs::{
-        notification_handler, solana_token_swap_cron_handler, solana_token_swap_request_handler,
+        nonce_gap_repair_handler, notification_handler, signer_rotation_handler,
+        solana_token_swap_cron_handler, solana_token_swap_request_handler,
         transaction_cleanup_handler, transaction_request_handler, transaction_status_handler,
         transaction_submission_handler, BackoffRetryPolicy,
     },
@@ -30,6 +31,8 @@ const TRANSACTION_STATUS_CHECKER: &str = "transaction_status_checker";
 const NOTIFICATION_SENDER: &str = "notification_sender";
 const SOLANA_TOKEN_SWAP_REQUEST: &str = "solana_token_swap_request";
 const TRANSACTION_CLEANUP: &str = "transaction_cleanup";
+const SIGNER_ROTATION: &str = "signer_rotation";
+const NONCE_GAP_REPAIR: &str = "nonce_gap_repair";
 
 pub async fn initialize_workers(app_state: ThinData<DefaultAppState>) -> Result<()> {
     let queue = app_state.job_producer.get_queue().await?;
@@ -103,6 +106,34 @@ pub async fn initialize_workers(app_state: ThinData<DefaultAppState>) -> Result<
         ))
         .build_fn(transaction_cleanup_handler);
 
+    let signer_rotation_queue_worker = WorkerBuilder::new(SIGNER_ROTATION)
+        .layer(ErrorHandlingLayer::new())
+        .enable_tracing()
+        .catch_panic()
+        .rate_limit(DEFAULT_RATE_LIMIT, DEFAULT_RATE_LIMIT_DURATION)
+        .retry(BackoffRetryPolicy::default())
+        .concurrency(1)
+        .data(app_state.clone())
+        .backend(CronStream::new(
+            // every minute
+            apalis_cron::Schedule::from_str("0 * * * * *").unwrap(),
+        ))
+        .build_fn(signer_rotation_handler);
+
+    let nonce_gap_repair_queue_worker = WorkerBuilder::new(NONCE_GAP_REPAIR)
+        .layer(ErrorHandlingLayer::new())
+        .enable_tracing()
+        .catch_panic()
+        .rate_limit(DEFAULT_RATE_LIMIT, DEFAULT_RATE_LIMIT_DURATION)
+        .retry(BackoffRetryPolicy::default())
+        .concurrency(1)
+        .data(app_state.clone())
+        .backend(CronStream::new(
+            // every 5 minutes
+            apalis_cron::Schedule::from_str("0 */5 * * * *").unwrap(),
+        ))
+        .build_fn(nonce_gap_repair_handler);
+
     let monitor = Monitor::new()
         .register(transaction_request_queue_worker)
         .register(transaction_submission_queue_worker)
@@ -110,6 +141,8 @@ pub async fn initialize_workers(app_state: ThinData<DefaultAppState>) -> Result<
         .register(notification_queue_worker)
         .register(solana_token_swap_request_queue_worker)
         .register(transaction_cleanup_queue_worker)
+        .register(signer_rotation_queue_worker)
+        .register(nonce_gap_repair_queue_worker)
         .on_event(monitor_handle_event)
         .shutdown_timeout(Duration::from_millis(5000));
 
@@ -40,8 +40,10 @@ pub use plugin::*;
 
 pub mod network;
 pub use network::{
-    EvmNetworkConfig, GasPriceCacheConfig, NetworkConfigCommon, NetworkFileConfig,
-    NetworksFileConfig, SolanaNetworkConfig, StellarNetworkConfig,
+    EvmNetworkConfig, FeeHistoryGasOracleConfig, GasOracleConfig, GasOracleUnit,
+    GasPriceCacheConfig, HttpGasOracleConfig, NetworkConfigCommon, NetworkFileConfig,
+    NetworksFileConfig, PrivateRelayConfig, PrivateRelayMethod, SolanaNetworkConfig,
+    SpeedFieldMapping, SpeedPercentiles, StellarNetworkConfig,
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
@@ -235,6 +237,8 @@ mod tests {
                 features: None,
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             })])
             .expect("Failed to create NetworksFileConfig for test"),
             plugins: Some(vec![PluginFileConfig {
@@ -272,6 +276,8 @@ mod tests {
                 features: None,
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             })])
             .unwrap(),
             plugins: Some(vec![]),
@@ -300,6 +306,8 @@ mod tests {
                 features: None,
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             })])
             .unwrap(),
             plugins: Some(vec![]),
@@ -866,6 +874,8 @@ mod tests {
             features: None,
             symbol: Some("ETH".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         })];
 
         let config = NetworksFileConfig::new(networks).unwrap();
@@ -1213,6 +1223,8 @@ mod tests {
                 features: None,
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             })])
             .unwrap(),
             plugins: Some(vec![]),
@@ -1248,6 +1260,8 @@ mod tests {
                 features: None,
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             })])
             .unwrap(),
             plugins: Some(vec![]),
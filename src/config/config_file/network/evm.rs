@@ -12,6 +12,7 @@
 use super::common::{merge_optional_string_vecs, NetworkConfigCommon};
 use crate::config::ConfigFileError;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Default value for gas price cache enabled flag
 fn default_gas_cache_enabled() -> bool {
@@ -29,7 +30,7 @@ fn default_gas_cache_expire_after_ms() -> u64 {
 }
 
 /// Configuration for gas price caching
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct GasPriceCacheConfig {
     /// Enable gas price caching for this network
@@ -86,6 +87,232 @@ impl GasPriceCacheConfig {
     }
 }
 
+/// Default value for the number of blocks sampled by the fee history gas oracle
+fn default_gas_oracle_block_count() -> u64 {
+    20
+}
+
+/// Reward percentiles sampled for each transaction speed
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeedPercentiles {
+    pub safe_low: u8,
+    pub average: u8,
+    pub fast: u8,
+    pub fastest: u8,
+}
+
+impl Default for SpeedPercentiles {
+    fn default() -> Self {
+        Self {
+            safe_low: 30,
+            average: 50,
+            fast: 85,
+            fastest: 99,
+        }
+    }
+}
+
+/// Configuration of the fee history gas oracle
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FeeHistoryGasOracleConfig {
+    /// Number of recent blocks sampled with `eth_feeHistory`
+    #[serde(default = "default_gas_oracle_block_count")]
+    pub block_count: u64,
+
+    /// Priority fee reward percentiles used for each speed
+    #[serde(default)]
+    pub reward_percentiles: SpeedPercentiles,
+}
+
+impl Default for FeeHistoryGasOracleConfig {
+    fn default() -> Self {
+        Self {
+            block_count: default_gas_oracle_block_count(),
+            reward_percentiles: SpeedPercentiles::default(),
+        }
+    }
+}
+
+/// JSON pointers (RFC 6901) locating the value of each speed in an oracle response
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeedFieldMapping {
+    pub safe_low: String,
+    pub average: String,
+    pub fast: String,
+    pub fastest: String,
+}
+
+impl SpeedFieldMapping {
+    fn pointers(&self) -> [&str; 4] {
+        [&self.safe_low, &self.average, &self.fast, &self.fastest]
+    }
+}
+
+/// Unit of the prices returned by an HTTP gas oracle
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum GasOracleUnit {
+    Wei,
+    #[default]
+    Gwei,
+}
+
+/// Configuration of a gas oracle served as JSON over HTTP
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HttpGasOracleConfig {
+    /// URL the oracle is queried at with a GET request
+    pub url: String,
+
+    /// Unit of the prices in the response
+    #[serde(default)]
+    pub unit: GasOracleUnit,
+
+    /// Fields holding the max priority fee per gas of each speed
+    pub max_priority_fee_per_gas: SpeedFieldMapping,
+
+    /// Fields holding the legacy gas price of each speed. When not set, legacy prices are
+    /// the base fee plus the priority fee of the speed.
+    pub gas_price: Option<SpeedFieldMapping>,
+
+    /// Field holding the base fee per gas. When not set, the base fee of the latest block is
+    /// used.
+    pub base_fee_per_gas: Option<String>,
+}
+
+/// Strategy used to price transactions on a network
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, Default, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GasOracleConfig {
+    /// `eth_gasPrice` and `eth_feeHistory` with fixed speed multipliers
+    #[default]
+    Node,
+    /// Priority fee percentiles over a window of recent blocks
+    FeeHistory(FeeHistoryGasOracleConfig),
+    /// External oracle serving prices as JSON
+    Http(Box<HttpGasOracleConfig>),
+}
+
+impl GasOracleConfig {
+    /// Validates the gas oracle configuration
+    ///
+    /// # Returns
+    /// - `Ok(())` if the configuration is valid
+    /// - `Err(ConfigFileError)` if validation fails
+    pub fn validate(&self) -> Result<(), ConfigFileError> {
+        match self {
+            GasOracleConfig::Node => Ok(()),
+            GasOracleConfig::FeeHistory(config) => {
+                if config.block_count == 0 || config.block_count > 1024 {
+                    return Err(ConfigFileError::InvalidFormat(
+                        "Gas oracle block_count must be between 1 and 1024".into(),
+                    ));
+                }
+
+                let percentiles = config.reward_percentiles;
+                let ordered = [
+                    percentiles.safe_low,
+                    percentiles.average,
+                    percentiles.fast,
+                    percentiles.fastest,
+                ];
+                if ordered.iter().any(|p| *p > 100) || ordered.windows(2).any(|w| w[0] > w[1]) {
+                    return Err(ConfigFileError::InvalidFormat(
+                        "Gas oracle reward_percentiles must be ascending values up to 100".into(),
+                    ));
+                }
+                Ok(())
+            }
+            GasOracleConfig::Http(config) => {
+                reqwest::Url::parse(&config.url).map_err(|_| {
+                    ConfigFileError::InvalidFormat(format!(
+                        "Invalid gas oracle URL: {}",
+                        config.url
+                    ))
+                })?;
+
+                let pointers = config
+                    .max_priority_fee_per_gas
+                    .pointers()
+                    .into_iter()
+                    .chain(
+                        config
+                            .gas_price
+                            .iter()
+                            .flat_map(|mapping| mapping.pointers()),
+                    )
+                    .chain(config.base_fee_per_gas.as_deref());
+                for pointer in pointers {
+                    if !pointer.starts_with('/') {
+                        return Err(ConfigFileError::InvalidFormat(format!(
+                            "Gas oracle field '{}' must be a JSON pointer starting with '/'",
+                            pointer
+                        )));
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// JSON-RPC method used to submit transactions to a private relay
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Default, ToSchema)]
+pub enum PrivateRelayMethod {
+    /// Submits a single transaction (Flashbots Protect style)
+    #[default]
+    #[serde(rename = "eth_sendPrivateTransaction")]
+    SendPrivateTransaction,
+    /// Submits a single-transaction bundle targeting the next block
+    #[serde(rename = "eth_sendBundle")]
+    SendBundle,
+}
+
+/// Configuration for submitting transactions through a private relay instead of the
+/// public mempool
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PrivateRelayConfig {
+    /// URL of the private relay JSON-RPC endpoint
+    pub url: String,
+
+    /// JSON-RPC method used to submit transactions
+    #[serde(default)]
+    pub method: PrivateRelayMethod,
+
+    /// Name of the environment variable holding the hex-encoded private key used to sign
+    /// the `X-Flashbots-Signature` authentication header. Requests are unsigned if not set.
+    pub auth_key_env: Option<String>,
+}
+
+impl PrivateRelayConfig {
+    /// Validates the private relay configuration
+    ///
+    /// # Returns
+    /// - `Ok(())` if the configuration is valid
+    /// - `Err(ConfigFileError)` if validation fails
+    pub fn validate(&self) -> Result<(), ConfigFileError> {
+        reqwest::Url::parse(&self.url).map_err(|_| {
+            ConfigFileError::InvalidFormat(format!("Invalid private relay URL: {}", self.url))
+        })?;
+
+        if self
+            .auth_key_env
+            .as_ref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
+            return Err(ConfigFileError::InvalidFormat(
+                "Private relay auth_key_env must not be empty".into(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 /// Configuration specific to EVM-compatible networks.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(deny_unknown_fields)]
@@ -104,6 +331,10 @@ pub struct EvmNetworkConfig {
     pub symbol: Option<String>,
     /// Gas price cache configuration
     pub gas_price_cache: Option<GasPriceCacheConfig>,
+    /// Private relay used by relayers with `private_transactions` enabled
+    pub private_relay: Option<PrivateRelayConfig>,
+    /// Gas oracle used to price transactions
+    pub gas_oracle: Option<GasOracleConfig>,
 }
 
 impl EvmNetworkConfig {
@@ -135,6 +366,14 @@ impl EvmNetworkConfig {
             gas_price_cache.validate()?;
         }
 
+        if let Some(private_relay) = &self.private_relay {
+            private_relay.validate()?;
+        }
+
+        if let Some(gas_oracle) = &self.gas_oracle {
+            gas_oracle.validate()?;
+        }
+
         Ok(())
     }
 
@@ -158,6 +397,14 @@ impl EvmNetworkConfig {
                 .gas_price_cache
                 .clone()
                 .or_else(|| parent.gas_price_cache.clone()),
+            private_relay: self
+                .private_relay
+                .clone()
+                .or_else(|| parent.private_relay.clone()),
+            gas_oracle: self
+                .gas_oracle
+                .clone()
+                .or_else(|| parent.gas_oracle.clone()),
         }
     }
 }
@@ -313,6 +560,8 @@ mod tests {
                 stale_after_ms: 20_000,
                 expire_after_ms: 100_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let child = EvmNetworkConfig {
@@ -334,6 +583,8 @@ mod tests {
                 stale_after_ms: 40_000,
                 expire_after_ms: 200_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = child.merge_with_parent(&parent);
@@ -393,6 +644,8 @@ mod tests {
                 stale_after_ms: 20_000,
                 expire_after_ms: 100_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let child = create_evm_network_for_inheritance_test("ethereum-testnet", "ethereum-mainnet");
@@ -448,6 +701,8 @@ mod tests {
                 stale_after_ms: 20_000,
                 expire_after_ms: 100_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let child = EvmNetworkConfig {
@@ -469,6 +724,8 @@ mod tests {
                 stale_after_ms: 40_000,
                 expire_after_ms: 200_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = child.merge_with_parent(&parent);
@@ -530,6 +787,8 @@ mod tests {
             features: None,
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let child = EvmNetworkConfig {
@@ -547,6 +806,8 @@ mod tests {
             features: None,
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = child.merge_with_parent(&parent);
@@ -589,6 +850,8 @@ mod tests {
                 stale_after_ms: 20_000,
                 expire_after_ms: 100_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let child = EvmNetworkConfig {
@@ -610,6 +873,8 @@ mod tests {
             ]),
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = child.merge_with_parent(&parent);
@@ -667,6 +932,8 @@ mod tests {
                 stale_after_ms: 20_000,
                 expire_after_ms: 100_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let child = EvmNetworkConfig {
@@ -684,6 +951,8 @@ mod tests {
             features: None,
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = child.merge_with_parent(&parent);
@@ -739,6 +1008,8 @@ mod tests {
                 stale_after_ms: 20_000,
                 expire_after_ms: 100_000,
             }),
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let child = EvmNetworkConfig {
@@ -756,6 +1027,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string()]),
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = child.merge_with_parent(&parent);
@@ -893,11 +1166,169 @@ mod tests {
     fn test_gas_price_cache_default_values() {
         let config = GasPriceCacheConfig::default();
 
-        assert_eq!(config.enabled, false);
+        assert!(!config.enabled);
         assert_eq!(config.stale_after_ms, 20_000);
         assert_eq!(config.expire_after_ms, 45_000);
 
         // Validation should pass for default values
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_private_relay_validation_valid_config() {
+        let mut config = create_evm_network("ethereum-mainnet");
+        config.private_relay = Some(PrivateRelayConfig {
+            url: "https://relay.flashbots.net".to_string(),
+            method: PrivateRelayMethod::SendBundle,
+            auth_key_env: Some("FLASHBOTS_AUTH_KEY".to_string()),
+        });
+
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_private_relay_validation_invalid_url() {
+        let mut config = create_evm_network("ethereum-mainnet");
+        config.private_relay = Some(PrivateRelayConfig {
+            url: "not a url".to_string(),
+            method: PrivateRelayMethod::default(),
+            auth_key_env: None,
+        });
+
+        let result = config.validate();
+        assert!(matches!(result, Err(ConfigFileError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_private_relay_validation_empty_auth_key_env() {
+        let mut config = create_evm_network("ethereum-mainnet");
+        config.private_relay = Some(PrivateRelayConfig {
+            url: "https://relay.flashbots.net".to_string(),
+            method: PrivateRelayMethod::default(),
+            auth_key_env: Some(" ".to_string()),
+        });
+
+        let result = config.validate();
+        assert!(matches!(result, Err(ConfigFileError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_private_relay_deserialization() {
+        let config: PrivateRelayConfig =
+            serde_json::from_str(r#"{"url": "https://rpc.flashbots.net"}"#).unwrap();
+        assert_eq!(config.method, PrivateRelayMethod::SendPrivateTransaction);
+        assert!(config.auth_key_env.is_none());
+
+        let config: PrivateRelayConfig = serde_json::from_str(
+            r#"{"url": "https://relay.flashbots.net", "method": "eth_sendBundle"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.method, PrivateRelayMethod::SendBundle);
+    }
+
+    #[test]
+    fn test_gas_oracle_deserialization() {
+        let config: GasOracleConfig = serde_json::from_str(r#"{"type": "node"}"#).unwrap();
+        assert_eq!(config, GasOracleConfig::Node);
+
+        let config: GasOracleConfig =
+            serde_json::from_str(r#"{"type": "fee_history", "block_count": 10}"#).unwrap();
+        assert_eq!(
+            config,
+            GasOracleConfig::FeeHistory(FeeHistoryGasOracleConfig {
+                block_count: 10,
+                reward_percentiles: SpeedPercentiles::default(),
+            })
+        );
+
+        let config: GasOracleConfig = serde_json::from_str(
+            r#"{
+                "type": "http",
+                "url": "https://gasstation.polygon.technology/v2",
+                "max_priority_fee_per_gas": {
+                    "safe_low": "/safeLow/maxPriorityFee",
+                    "average": "/standard/maxPriorityFee",
+                    "fast": "/fast/maxPriorityFee",
+                    "fastest": "/fast/maxPriorityFee"
+                },
+                "base_fee_per_gas": "/estimatedBaseFee"
+            }"#,
+        )
+        .unwrap();
+        let GasOracleConfig::Http(http) = &config else {
+            panic!("expected an HTTP gas oracle");
+        };
+        assert_eq!(http.unit, GasOracleUnit::Gwei);
+        assert!(http.gas_price.is_none());
+        assert!(config.validate().is_ok());
+
+        assert!(serde_json::from_str::<GasOracleConfig>(
+            r#"{"type": "fee_history", "blocks": 10}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_gas_oracle_validation() {
+        let mut config = create_evm_network("ethereum-mainnet");
+        config.gas_oracle = Some(GasOracleConfig::FeeHistory(
+            FeeHistoryGasOracleConfig::default(),
+        ));
+        assert!(config.validate().is_ok());
+
+        config.gas_oracle = Some(GasOracleConfig::FeeHistory(FeeHistoryGasOracleConfig {
+            block_count: 0,
+            reward_percentiles: SpeedPercentiles::default(),
+        }));
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigFileError::InvalidFormat(_))
+        ));
+
+        config.gas_oracle = Some(GasOracleConfig::FeeHistory(FeeHistoryGasOracleConfig {
+            block_count: 20,
+            reward_percentiles: SpeedPercentiles {
+                safe_low: 50,
+                average: 40,
+                fast: 85,
+                fastest: 99,
+            },
+        }));
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigFileError::InvalidFormat(_))
+        ));
+
+        let mapping = SpeedFieldMapping {
+            safe_low: "/safeLow".to_string(),
+            average: "/standard".to_string(),
+            fast: "fast".to_string(),
+            fastest: "/fastest".to_string(),
+        };
+        config.gas_oracle = Some(GasOracleConfig::Http(Box::new(HttpGasOracleConfig {
+            url: "https://oracle.example.com".to_string(),
+            unit: GasOracleUnit::Wei,
+            max_priority_fee_per_gas: mapping,
+            gas_price: None,
+            base_fee_per_gas: None,
+        })));
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigFileError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_with_parent_inherits_private_relay() {
+        let mut parent = create_evm_network("ethereum-mainnet");
+        parent.private_relay = Some(PrivateRelayConfig {
+            url: "https://rpc.flashbots.net".to_string(),
+            method: PrivateRelayMethod::default(),
+            auth_key_env: None,
+        });
+        let child = create_evm_network_with_parent("ethereum-private", "ethereum-mainnet");
+
+        let result = child.merge_with_parent(&parent);
+        assert_eq!(result.private_relay, parent.private_relay);
+    }
 }
@@ -159,6 +159,8 @@ mod tests {
             features: None,
             symbol: None, // Will inherit from parent
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = resolver.resolve_evm_inheritance(&child_config, "child", "parent");
@@ -205,6 +207,8 @@ mod tests {
             features: None,
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
         networks.insert(
             "parent".to_string(),
@@ -230,6 +234,8 @@ mod tests {
             features: None,
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = resolver.resolve_evm_inheritance(&child_config, "child", "parent");
@@ -501,6 +507,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string(), "london".to_string()]),
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
         networks.insert(
             "grandparent".to_string(),
@@ -522,6 +530,8 @@ mod tests {
             features: None,
             symbol: None,
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
         networks.insert("parent".to_string(), NetworkFileConfig::Evm(parent_config));
 
@@ -543,6 +553,8 @@ mod tests {
             features: None,
             symbol: Some("CUSTOM".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = resolver.resolve_evm_inheritance(&child_config, "child", "parent");
@@ -677,6 +689,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string(), "london".to_string()]),
             symbol: Some("ETH".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
         networks.insert("parent".to_string(), NetworkFileConfig::Evm(parent_config));
 
@@ -699,6 +713,8 @@ mod tests {
             features: Some(vec!["berlin".to_string()]), // Override (merge behavior depends on implementation)
             symbol: None,                               // Inherit
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let result = resolver.resolve_evm_inheritance(&child_config, "child", "parent");
@@ -48,6 +48,8 @@ pub fn create_evm_network(network: &str) -> EvmNetworkConfig {
         features: Some(vec!["eip1559".to_string()]),
         symbol: Some("ETH".to_string()),
         gas_price_cache: None,
+        private_relay: None,
+        gas_oracle: None,
     }
 }
 
@@ -60,6 +62,8 @@ pub fn create_evm_network_with_parent(network: &str, parent: &str) -> EvmNetwork
         features: None,        // Will inherit from parent
         symbol: Some("ETH".to_string()), // Required field
         gas_price_cache: None,
+        private_relay: None,
+        gas_oracle: None,
     }
 }
 
@@ -72,6 +76,8 @@ pub fn create_evm_network_for_inheritance_test(network: &str, parent: &str) -> E
         features: None,               // Will inherit from parent
         symbol: None,                 // Will inherit from parent
         gas_price_cache: None,
+        private_relay: None,
+        gas_oracle: None,
     }
 }
 
@@ -92,6 +98,8 @@ pub fn create_invalid_evm_network(network: &str) -> EvmNetworkConfig {
         features: None,
         symbol: None,
         gas_price_cache: None,
+        private_relay: None,
+        gas_oracle: None,
     }
 }
 
@@ -1,16 +1,67 @@
-//! This module provides rate limiting functionality using API keys.
+//! This module provides rate limiting functionality using API keys and client addresses.
+//!
+//! Requests are first bucketed by client IP address, before authentication, so invalid
+//! tokens cannot be tried without limit. Authenticated requests are then bucketed by the
+//! API key identity resolved during authentication, so each named key has its own limit
+//! regardless of how its token is formatted.
 
 use crate::constants::{AUTHORIZATION_HEADER_NAME, PUBLIC_ENDPOINTS};
+use crate::models::ApiKeyIdentity;
 use actix_governor::governor::clock::{Clock, DefaultClock, QuantaInstant};
 use actix_governor::governor::NotUntil;
 use actix_governor::{KeyExtractor, SimpleKeyExtractionError};
 use actix_web::{
     dev::ServiceRequest,
     http::{header::ContentType, StatusCode},
-    HttpResponse, HttpResponseBuilder,
+    HttpMessage, HttpResponse, HttpResponseBuilder,
 };
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 
+/// Response body returned when a request exceeds a rate limit
+fn too_many_requests_response(
+    negative: &NotUntil<QuantaInstant>,
+    mut response: HttpResponseBuilder,
+) -> HttpResponse {
+    let wait_time = negative
+        .wait_time_from(DefaultClock::default().now())
+        .as_secs();
+    response.content_type(ContentType::json())
+        .body(
+            format!(
+                r#"{{ "success": false, "code":429, "error": "TooManyRequests", "message": "Too Many Requests", "after": {wait_time}}}"#
+            )
+        )
+}
+
+/// Rate limits requests by client IP address, ahead of authentication.
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct ClientIpRateLimit;
+
+impl KeyExtractor for ClientIpRateLimit {
+    type Key = IpAddr;
+    type KeyExtractionError = SimpleKeyExtractionError<&'static str>;
+
+    fn extract(&self, req: &ServiceRequest) -> Result<Self::Key, Self::KeyExtractionError> {
+        req.peer_addr().map(|addr| addr.ip()).ok_or_else(|| {
+            Self::KeyExtractionError::new(
+                r#"{"success": false, "code": 500, "error": "InternalError", "message": "Could not determine the client address"}"#,
+            )
+            .set_content_type(ContentType::json())
+            .set_status_code(StatusCode::INTERNAL_SERVER_ERROR)
+        })
+    }
+
+    fn exceed_rate_limit_response(
+        &self,
+        negative: &NotUntil<QuantaInstant>,
+        response: HttpResponseBuilder,
+    ) -> HttpResponse {
+        too_many_requests_response(negative, response)
+    }
+}
+
+/// Rate limits authenticated requests by API key.
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
 pub struct ApiKeyRateLimit;
 
@@ -27,6 +78,11 @@ impl KeyExtractor for ApiKeyRateLimit {
         if is_public_endpoint {
             return Ok("swagger-ui-exempt".to_string());
         }
+
+        if let Some(identity) = req.extensions().get::<ApiKeyIdentity>() {
+            return Ok(identity.rate_limit_key());
+        }
+
         req.headers()
             .get(AUTHORIZATION_HEADER_NAME)
             .and_then(|token| token.to_str().ok())
@@ -43,17 +99,9 @@ impl KeyExtractor for ApiKeyRateLimit {
     fn exceed_rate_limit_response(
         &self,
         negative: &NotUntil<QuantaInstant>,
-        mut response: HttpResponseBuilder,
+        response: HttpResponseBuilder,
     ) -> HttpResponse {
-        let wait_time = negative
-            .wait_time_from(DefaultClock::default().now())
-            .as_secs();
-        response.content_type(ContentType::json())
-            .body(
-                format!(
-                    r#"{{ "success": false, "code":429, "error": "TooManyRequests", "message": "Too Many Requests", "after": {wait_time}}}"#
-                )
-            )
+        too_many_requests_response(negative, response)
     }
 }
 
@@ -62,8 +110,9 @@ mod tests {
 
     use super::*;
     use actix_governor::governor::{Quota, RateLimiter};
-    use actix_web::test::TestRequest;
-    use actix_web::{body::MessageBody, http::header::HeaderValue};
+    use actix_governor::{Governor, GovernorConfigBuilder};
+    use actix_web::test::{self, TestRequest};
+    use actix_web::{body::MessageBody, http::header::HeaderValue, web, App};
     use std::num::NonZeroU32;
 
     #[tokio::test]
@@ -95,6 +144,97 @@ mod tests {
         assert_eq!(result.unwrap(), expected_key);
     }
 
+    #[tokio::test]
+    async fn test_extract_with_resolved_identity() {
+        let req = TestRequest::default()
+            .insert_header((AUTHORIZATION_HEADER_NAME, "Bearer some-token"))
+            .to_srv_request();
+        req.extensions_mut().insert(ApiKeyIdentity {
+            id: "key-1".to_string(),
+            scopes: vec![],
+            allowed_relayers: vec![],
+        });
+
+        let extractor = ApiKeyRateLimit;
+        let result = extractor.extract(&req);
+
+        assert_eq!(result.unwrap(), "api-key:key-1");
+    }
+
+    #[tokio::test]
+    async fn test_extract_public_endpoint() {
+        let req = TestRequest::default()
+            .uri("/api-docs/openapi.json")
+            .to_srv_request();
+
+        let extractor = ApiKeyRateLimit;
+        let result = extractor.extract(&req);
+
+        assert_eq!(result.unwrap(), "swagger-ui-exempt");
+    }
+
+    #[tokio::test]
+    async fn test_extract_client_ip() {
+        let req = TestRequest::default()
+            .peer_addr("192.168.1.10:52000".parse().unwrap())
+            .insert_header((AUTHORIZATION_HEADER_NAME, "Bearer invalid-token"))
+            .to_srv_request();
+
+        let result = ClientIpRateLimit.extract(&req);
+
+        assert_eq!(result.unwrap(), "192.168.1.10".parse::<IpAddr>().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_extract_client_ip_without_peer_address() {
+        let req = TestRequest::default().to_srv_request();
+
+        assert!(ClientIpRateLimit.extract(&req).is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_api_keys_behind_one_address_keep_their_own_limits() {
+        let key_config = GovernorConfigBuilder::default()
+            .requests_per_minute(1)
+            .burst_size(2)
+            .key_extractor(ApiKeyRateLimit)
+            .finish()
+            .unwrap();
+        let client_config = GovernorConfigBuilder::default()
+            .requests_per_minute(1)
+            .burst_size(10)
+            .key_extractor(ClientIpRateLimit)
+            .finish()
+            .unwrap();
+        let app = test::init_service(
+            App::new()
+                .wrap(Governor::new(&key_config))
+                .wrap(Governor::new(&client_config))
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        let request = |key: &str| {
+            TestRequest::get()
+                .uri("/")
+                .peer_addr("10.0.0.1:40000".parse().unwrap())
+                .insert_header((AUTHORIZATION_HEADER_NAME, key.to_string()))
+                .to_request()
+        };
+
+        for _ in 0..2 {
+            let response = test::call_service(&app, request("key-a")).await;
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        let response = test::call_service(&app, request("key-a")).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // The exhausted key does not use up the budget of another key from the same address
+        for _ in 0..2 {
+            let response = test::call_service(&app, request("key-b")).await;
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
     #[tokio::test]
     async fn test_exceed_rate_limit_response() {
         let extractor = ApiKeyRateLimit;
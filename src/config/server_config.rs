@@ -38,6 +38,10 @@ pub struct ServerConfig {
     pub rate_limit_requests_per_second: u64,
     /// The maximum burst size for rate limiting.
     pub rate_limit_burst_size: u32,
+    /// The number of requests allowed per second from a single client address.
+    pub client_rate_limit_requests_per_second: u64,
+    /// The maximum burst size for rate limiting by client address.
+    pub client_rate_limit_burst_size: u32,
     /// The port number for exposing metrics.
     pub metrics_port: u16,
     /// Enable Swagger UI.
@@ -64,6 +68,8 @@ pub struct ServerConfig {
     pub storage_encryption_key: Option<SecretString>,
     /// Transaction expiration time in hours for transactions in final states.
     pub transaction_expiration_hours: u64,
+    /// Time window in seconds during which a repeated idempotency key returns the original transaction.
+    pub idempotency_window_seconds: u64,
 }
 
 impl ServerConfig {
@@ -81,6 +87,8 @@ impl ServerConfig {
     /// - `CONFIG_DIR` defaults to `"config/config.json"`.
     /// - `RATE_LIMIT_REQUESTS_PER_SECOND` defaults to `100`.
     /// - `RATE_LIMIT_BURST_SIZE` defaults to `300`.
+    /// - `CLIENT_RATE_LIMIT_REQUESTS_PER_SECOND` defaults to `1000`.
+    /// - `CLIENT_RATE_LIMIT_BURST_SIZE` defaults to `3000`.
     /// - `METRICS_PORT` defaults to `8081`.
     /// - `PROVIDER_MAX_RETRIES` defaults to `3`.
     /// - `PROVIDER_RETRY_BASE_DELAY_MS` defaults to `100`.
@@ -88,6 +96,7 @@ impl ServerConfig {
     /// - `PROVIDER_MAX_FAILOVERS` defaults to `3`.
     /// - `REPOSITORY_STORAGE_TYPE` defaults to `"in_memory"`.
     /// - `TRANSACTION_EXPIRATION_HOURS` defaults to `4`.
+    /// - `IDEMPOTENCY_WINDOW_SECONDS` defaults to `86400`.
     pub fn from_env() -> Self {
         Self {
             host: Self::get_host(),
@@ -97,6 +106,9 @@ impl ServerConfig {
             api_key: Self::get_api_key(), // Uses panicking version as required
             rate_limit_requests_per_second: Self::get_rate_limit_requests_per_second(),
             rate_limit_burst_size: Self::get_rate_limit_burst_size(),
+            client_rate_limit_requests_per_second: Self::get_client_rate_limit_requests_per_second(
+            ),
+            client_rate_limit_burst_size: Self::get_client_rate_limit_burst_size(),
             metrics_port: Self::get_metrics_port(),
             enable_swagger: Self::get_enable_swagger(),
             redis_connection_timeout_ms: Self::get_redis_connection_timeout_ms(),
@@ -110,6 +122,7 @@ impl ServerConfig {
             reset_storage_on_start: Self::get_reset_storage_on_start(),
             storage_encryption_key: Self::get_storage_encryption_key(),
             transaction_expiration_hours: Self::get_transaction_expiration_hours(),
+            idempotency_window_seconds: Self::get_idempotency_window_seconds(),
         }
     }
 
@@ -194,6 +207,23 @@ impl ServerConfig {
             .unwrap_or(300)
     }
 
+    /// Gets the per client address rate limit requests per second from environment variable
+    /// or default
+    pub fn get_client_rate_limit_requests_per_second() -> u64 {
+        env::var("CLIENT_RATE_LIMIT_REQUESTS_PER_SECOND")
+            .unwrap_or_else(|_| "1000".to_string())
+            .parse()
+            .unwrap_or(1000)
+    }
+
+    /// Gets the per client address rate limit burst size from environment variable or default
+    pub fn get_client_rate_limit_burst_size() -> u32 {
+        env::var("CLIENT_RATE_LIMIT_BURST_SIZE")
+            .unwrap_or_else(|_| "3000".to_string())
+            .parse()
+            .unwrap_or(3000)
+    }
+
     /// Gets the metrics port from environment variable or default
     pub fn get_metrics_port() -> u16 {
         env::var("METRICS_PORT")
@@ -291,6 +321,14 @@ impl ServerConfig {
             .parse()
             .unwrap_or(4)
     }
+
+    /// Gets the idempotency window in seconds from environment variable or default
+    pub fn get_idempotency_window_seconds() -> u64 {
+        env::var("IDEMPOTENCY_WINDOW_SECONDS")
+            .unwrap_or_else(|_| "86400".to_string())
+            .parse()
+            .unwrap_or(86400)
+    }
 }
 
 #[cfg(test)]
@@ -316,6 +354,8 @@ mod tests {
         env::remove_var("API_KEY");
         env::remove_var("RATE_LIMIT_REQUESTS_PER_SECOND");
         env::remove_var("RATE_LIMIT_BURST_SIZE");
+        env::remove_var("CLIENT_RATE_LIMIT_REQUESTS_PER_SECOND");
+        env::remove_var("CLIENT_RATE_LIMIT_BURST_SIZE");
         env::remove_var("METRICS_PORT");
         env::remove_var("REDIS_CONNECTION_TIMEOUT_MS");
         env::remove_var("RPC_TIMEOUT_MS");
@@ -326,6 +366,7 @@ mod tests {
         env::remove_var("REPOSITORY_STORAGE_TYPE");
         env::remove_var("RESET_STORAGE_ON_START");
         env::remove_var("TRANSACTION_EXPIRATION_HOURS");
+        env::remove_var("IDEMPOTENCY_WINDOW_SECONDS");
         // Set required variables for most tests
         env::set_var("REDIS_URL", "redis://localhost:6379");
         env::set_var("API_KEY", "7EF1CB7C-5003-4696-B384-C72AF8C3E15D");
@@ -352,6 +393,8 @@ mod tests {
         );
         assert_eq!(config.rate_limit_requests_per_second, 100);
         assert_eq!(config.rate_limit_burst_size, 300);
+        assert_eq!(config.client_rate_limit_requests_per_second, 1000);
+        assert_eq!(config.client_rate_limit_burst_size, 3000);
         assert_eq!(config.metrics_port, 8081);
         assert_eq!(config.redis_connection_timeout_ms, 5000);
         assert_eq!(config.rpc_timeout_ms, 10000);
@@ -365,6 +408,7 @@ mod tests {
         );
         assert!(!config.reset_storage_on_start);
         assert_eq!(config.transaction_expiration_hours, 4);
+        assert_eq!(config.idempotency_window_seconds, 86400);
     }
 
     #[test]
@@ -426,6 +470,8 @@ mod tests {
         env::set_var("API_KEY", "7EF1CB7C-5003-4696-B384-C72AF8C3E15D");
         env::set_var("RATE_LIMIT_REQUESTS_PER_SECOND", "200");
         env::set_var("RATE_LIMIT_BURST_SIZE", "500");
+        env::set_var("CLIENT_RATE_LIMIT_REQUESTS_PER_SECOND", "2000");
+        env::set_var("CLIENT_RATE_LIMIT_BURST_SIZE", "5000");
         env::set_var("METRICS_PORT", "9091");
         env::set_var("REDIS_CONNECTION_TIMEOUT_MS", "10000");
         env::set_var("RPC_TIMEOUT_MS", "33333");
@@ -448,6 +494,8 @@ mod tests {
         );
         assert_eq!(config.rate_limit_requests_per_second, 200);
         assert_eq!(config.rate_limit_burst_size, 500);
+        assert_eq!(config.client_rate_limit_requests_per_second, 2000);
+        assert_eq!(config.client_rate_limit_burst_size, 5000);
         assert_eq!(config.metrics_port, 9091);
         assert_eq!(config.redis_connection_timeout_ms, 10000);
         assert_eq!(config.rpc_timeout_ms, 33333);
@@ -539,6 +587,7 @@ mod tests {
         assert!(!ServerConfig::get_reset_storage_on_start());
         assert!(ServerConfig::get_storage_encryption_key().is_none());
         assert_eq!(ServerConfig::get_transaction_expiration_hours(), 4);
+        assert_eq!(ServerConfig::get_idempotency_window_seconds(), 86400);
     }
 
     #[test]
@@ -679,6 +728,14 @@ mod tests {
             config.rate_limit_burst_size,
             ServerConfig::get_rate_limit_burst_size()
         );
+        assert_eq!(
+            config.client_rate_limit_requests_per_second,
+            ServerConfig::get_client_rate_limit_requests_per_second()
+        );
+        assert_eq!(
+            config.client_rate_limit_burst_size,
+            ServerConfig::get_client_rate_limit_burst_size()
+        );
         assert_eq!(config.metrics_port, ServerConfig::get_metrics_port());
         assert_eq!(config.enable_swagger, ServerConfig::get_enable_swagger());
         assert_eq!(
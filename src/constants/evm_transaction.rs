@@ -12,10 +12,21 @@ pub const GAS_TX_CREATE_CONTRACT: u64 = 53000;
 
 pub const GAS_TX_DATA_ZERO: u64 = 4; // Cost per zero byte in data
 pub const GAS_TX_DATA_NONZERO: u64 = 16; // Cost per non-zero byte in data
+pub const GAS_PER_AUTHORIZATION: u64 = 25_000; // Cost per EIP-7702 authorization
+pub const GAS_PER_ACCESS_LIST_ADDRESS: u64 = 2_400; // Cost per EIP-2930 access list address
+pub const GAS_PER_ACCESS_LIST_STORAGE_KEY: u64 = 1_900; // Cost per EIP-2930 access list storage key
 
 /// Gas limit buffer multiplier for automatic gas limit estimation, 10% increase
 pub const GAS_LIMIT_BUFFER_MULTIPLIER: u64 = 110;
 
+/// Gas added to a forward request's gas for the trusted forwarder's own work when gas limit
+/// estimation is disabled
+pub const FORWARDER_EXECUTE_GAS_OVERHEAD: u64 = 100_000;
+
+/// Multiple of the transfer fee left behind when sweeping a relayer balance, leaving room
+/// for gas price bumps on resubmission
+pub const SWEEP_FEE_RESERVE_MULTIPLIER: u128 = 3;
+
 /// Minimum gas price bump factor for transaction replacements (10% increase)
 pub const MIN_BUMP_FACTOR: f64 = 1.1;
 
@@ -24,6 +35,12 @@ pub const MAXIMUM_TX_ATTEMPTS: usize = 50;
 // Maximum number of NOOP transactions to attempt
 pub const MAXIMUM_NOOP_RETRY_ATTEMPTS: u32 = 50;
 
+/// Seconds a transaction must have been waiting behind a nonce gap before the gap is
+/// filled, leaving time for nonces that are still being assigned to be stored
+pub const NONCE_GAP_REPAIR_MIN_AGE_SECONDS: i64 = 120;
+/// Maximum number of nonce gaps filled per relayer in a single repair run
+pub const MAXIMUM_NONCE_GAP_REPAIRS: usize = 25;
+
 /// Time to resubmit for Arbitrum networks
 pub const ARBITRUM_TIME_TO_RESUBMIT: i64 = 20_000;
 
@@ -36,3 +53,25 @@ pub const GAS_PRICE_CACHE_REFRESH_TIMEOUT_SECS: u64 = 300;
 
 /// Number of historical blocks to fetch for fee history analysis
 pub const HISTORICAL_BLOCKS: u64 = 4;
+
+/// Number of blocks a private relay may keep trying to include a private transaction
+pub const PRIVATE_TX_MAX_BLOCKS: u64 = 25;
+
+/// Timeout in seconds for requests to a private relay
+pub const PRIVATE_RELAY_TIMEOUT_SECONDS: u64 = 30;
+
+/// Timeout in seconds for requests to an HTTP gas oracle
+pub const GAS_ORACLE_TIMEOUT_SECONDS: u64 = 10;
+
+/// Seconds user operations are collected into a `handleOps` bundle before it is submitted
+pub const USER_OPERATION_BUNDLE_WINDOW_SECONDS: i64 = 2;
+
+/// Maximum number of user operations carried by a single `handleOps` bundle
+pub const MAX_USER_OPERATIONS_PER_BUNDLE: usize = 10;
+
+/// Gas added on top of the bundled user operation limits for the `handleOps` call itself
+pub const USER_OPERATION_BUNDLE_GAS_OVERHEAD: u64 = 50_000;
+
+/// Fixed per-operation overhead included in the suggested pre-verification gas
+/// (transaction base cost plus the EntryPoint's per-operation bookkeeping)
+pub const USER_OPERATION_PRE_VERIFICATION_OVERHEAD: u64 = 39_300;
@@ -1 +1,2 @@
 pub const OPTIMISM_GAS_PRICE_ORACLE_ADDRESS: &str = "0x420000000000000000000000000000000000000F";
+pub const ARBITRUM_NODE_INTERFACE_ADDRESS: &str = "0x00000000000000000000000000000000000000C8";
@@ -13,6 +13,10 @@ pub const DEFAULT_EVM_GAS_PRICE_CAP: u128 = 100_000_000_000;
 pub const DEFAULT_EVM_EIP1559_ENABLED: bool = true;
 /// Default gas limit estimation enabled
 pub const DEFAULT_EVM_GAS_LIMIT_ESTIMATION: bool = true;
+/// Default pre-submission simulation disabled
+pub const DEFAULT_EVM_SIMULATE_TRANSACTIONS: bool = false;
+/// Default access list generation disabled
+pub const DEFAULT_EVM_ACCESS_LIST_GENERATION: bool = false;
 
 // === Stellar Policy Defaults ===
 /// Default concurrent transactions enabled setting for Stellar
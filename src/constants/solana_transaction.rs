@@ -0,0 +1,7 @@
+//! Constants for Solana transaction processing.
+//!
+//! This module contains default values used throughout the Solana transaction
+//! handling logic, including job delays.
+
+/// Default delay (in seconds) for transaction status check job after submission
+pub const SOLANA_STATUS_CHECK_JOB_DELAY_SECONDS: i64 = 5;
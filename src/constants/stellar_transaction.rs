@@ -12,3 +12,6 @@ pub const STELLAR_DEFAULT_MAX_FEE: i64 = 1_000_000;
 pub const STELLAR_STATUS_CHECK_JOB_DELAY_SECONDS: i64 = 5;
 /// Default delay (in seconds) for retrying transaction after bad sequence error
 pub const STELLAR_BAD_SEQUENCE_RETRY_DELAY_SECONDS: i64 = 2;
+/// Fee multiplier for the no-op replacing a submitted transaction on cancellation.
+/// Stellar Core only replaces a queued transaction with one paying at least 10x its fee.
+pub const STELLAR_CANCELLATION_FEE_MULTIPLIER: u32 = 10;
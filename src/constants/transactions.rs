@@ -10,6 +10,39 @@ pub const FINAL_TRANSACTION_STATUSES: &[TransactionStatus] = &[
     TransactionStatus::Expired,
 ];
 
+/// Maximum number of transactions accepted in a single batch submission.
+pub const MAX_BATCH_TRANSACTIONS: usize = 500;
+
+/// Maximum length of a transaction idempotency key.
+pub const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 255;
+
+/// HTTP header carrying the transaction idempotency key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// HTTP response header set when a repeated idempotency key returns the original transaction.
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// Number of times a submission waits for a concurrent submission with the same idempotency
+/// key to create its transaction.
+pub const IDEMPOTENCY_RESERVATION_ATTEMPTS: usize = 20;
+
+/// Delay between checks for the transaction of a concurrent submission with the same
+/// idempotency key.
+pub const IDEMPOTENCY_RESERVATION_RETRY_MILLIS: u64 = 100;
+
+/// How long an idempotency key submitted to a relayer pool stays reserved while the
+/// transaction is routed to a member.
+pub const POOL_IDEMPOTENCY_RESERVATION_TTL_SECONDS: u64 = 60;
+
+/// Maximum number of prerequisite transactions a transaction can depend on.
+pub const MAX_TRANSACTION_DEPENDENCIES: usize = 16;
+
+/// Delay before the dependencies of a waiting transaction are checked again.
+pub const TRANSACTION_DEPENDENCY_RECHECK_SECONDS: i64 = 10;
+
+/// Longest a transaction waits for its prerequisites when it has no `valid_until`.
+pub const MAX_TRANSACTION_DEPENDENCY_WAIT_SECONDS: i64 = 24 * 60 * 60;
+
 #[cfg(test)]
 mod tests {
     use super::*;
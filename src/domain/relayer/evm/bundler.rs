@@ -0,0 +1,933 @@
+//! ERC-4337 bundler mode for EVM relayers.
+//!
+//! Relayers with an `entry_point` policy accept user operations through their JSON-RPC
+//! endpoint. Each operation is validated by simulating `handleOps` against the EntryPoint
+//! and added to an open bundle: a pending `handleOps` transaction that collects operations
+//! for [`USER_OPERATION_BUNDLE_WINDOW_SECONDS`] before its `TransactionRequest` job runs.
+//! The job seals the bundle before reading it, and an operation arriving after that opens
+//! a new bundle.
+//! From there the bundle follows the regular EVM transaction lifecycle, and the hashes of
+//! the operations it carries are indexed so their status can be read back from it.
+use std::str::FromStr;
+
+use alloy::{
+    primitives::{Address, Bytes, B256, U256},
+    rpc::types::{BlockNumberOrTag, TransactionInput, TransactionRequest as CallRequest},
+};
+use chrono::{DateTime, Duration, Utc};
+use log::{debug, info};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+use crate::{
+    constants::{
+        GAS_LIMIT_BUFFER_MULTIPLIER, MAX_USER_OPERATIONS_PER_BUNDLE,
+        USER_OPERATION_BUNDLE_GAS_OVERHEAD, USER_OPERATION_BUNDLE_WINDOW_SECONDS,
+    },
+    domain::transaction::evm::decode_revert_reason,
+    jobs::{JobProducerTrait, TransactionRequest},
+    models::{
+        Erc4337ErrorCodes, EvmNetwork, EvmTransactionRequest, JsonRpcId, JsonRpcResponse,
+        NetworkRepoModel, NetworkRpcResult, NetworkTransactionData, NetworkTransactionRequest,
+        NetworkType, RelayerRepoModel, RepositoryError, RpcErrorCodes, TransactionReceipt,
+        TransactionRepoModel, TransactionStatus, UserOperation, UserOperationByHash,
+        UserOperationGasEstimate,
+    },
+    repositories::{NetworkRepository, Repository, TransactionRepository},
+    services::{EvmProviderTrait, ProviderError},
+};
+
+use super::{
+    create_error_response, create_success_response, decode_failed_op, decode_handle_ops,
+    encode_handle_ops, map_provider_error, pre_verification_gas, user_operation_gas_limit,
+    user_operation_hash, user_operation_receipt, PackedUserOperation,
+};
+
+/// JSON-RPC methods served by relayers in bundler mode.
+pub const BUNDLER_RPC_METHODS: &[&str] = &[
+    "eth_sendUserOperation",
+    "eth_estimateUserOperationGas",
+    "eth_getUserOperationReceipt",
+    "eth_getUserOperationByHash",
+    "eth_supportedEntryPoints",
+];
+
+/// Verification gas made available while estimating a user operation.
+const ESTIMATION_VERIFICATION_GAS_CAP: u64 = 10_000_000;
+
+/// Call gas assumed for accounts that are deployed by the operation itself, whose
+/// execution cannot be estimated before deployment.
+const UNDEPLOYED_ACCOUNT_CALL_GAS: u64 = 200_000;
+
+/// Serializes bundle updates within this process, so concurrent submissions join the same
+/// bundle. Whether the bundle is still open is checked by the repository when the operation
+/// is added.
+static BUNDLE_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// Returns true if `method` is one of the ERC-4337 bundler methods.
+pub fn is_bundler_rpc_method(method: &str) -> bool {
+    BUNDLER_RPC_METHODS.contains(&method)
+}
+
+/// Error returned to the caller of a bundler method.
+#[derive(Debug)]
+struct BundlerRpcError {
+    code: i32,
+    message: &'static str,
+    description: String,
+}
+
+impl BundlerRpcError {
+    fn invalid_params(description: impl Into<String>) -> Self {
+        Self {
+            code: RpcErrorCodes::INVALID_PARAMS,
+            message: "Invalid params",
+            description: description.into(),
+        }
+    }
+
+    fn internal(error: impl std::fmt::Display) -> Self {
+        Self {
+            code: RpcErrorCodes::INTERNAL_ERROR,
+            message: "Internal error",
+            description: error.to_string(),
+        }
+    }
+
+    /// A user operation rejected by the EntryPoint; `AA3x` reasons come from the paymaster.
+    fn rejected(reason: String) -> Self {
+        let code = if reason.starts_with("AA3") {
+            Erc4337ErrorCodes::REJECTED_BY_PAYMASTER
+        } else {
+            Erc4337ErrorCodes::REJECTED_BY_ENTRY_POINT
+        };
+        Self {
+            code,
+            message: "User operation rejected",
+            description: reason,
+        }
+    }
+
+    /// Maps a failed EntryPoint call, decoding `FailedOp` reverts into rejections.
+    fn from_entry_point_call(error: ProviderError) -> Self {
+        match error {
+            ProviderError::Reverted { message, data } => Self::rejected(match data {
+                Some(data) => {
+                    decode_failed_op(&data).unwrap_or_else(|| decode_revert_reason(&data))
+                }
+                None => message,
+            }),
+            other => other.into(),
+        }
+    }
+}
+
+impl From<ProviderError> for BundlerRpcError {
+    fn from(error: ProviderError) -> Self {
+        let (code, message) = map_provider_error(&error);
+        Self {
+            code,
+            message,
+            description: error.to_string(),
+        }
+    }
+}
+
+impl From<RepositoryError> for BundlerRpcError {
+    fn from(error: RepositoryError) -> Self {
+        Self::internal(error)
+    }
+}
+
+/// Serves the ERC-4337 bundler methods of an EVM relayer.
+pub struct EvmBundler<'a, P, NR, TR, J> {
+    relayer: &'a RelayerRepoModel,
+    network: &'a EvmNetwork,
+    entry_point: Address,
+    provider: &'a P,
+    network_repository: &'a NR,
+    transaction_repository: &'a TR,
+    job_producer: &'a J,
+}
+
+impl<'a, P, NR, TR, J> EvmBundler<'a, P, NR, TR, J>
+where
+    P: EvmProviderTrait + Send + Sync,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    TR: Repository<TransactionRepoModel, String> + TransactionRepository + Send + Sync + 'static,
+    J: JobProducerTrait + Send + Sync + 'static,
+{
+    /// Creates a bundler submitting `handleOps` bundles to `entry_point`.
+    pub fn new(
+        relayer: &'a RelayerRepoModel,
+        network: &'a EvmNetwork,
+        entry_point: Address,
+        provider: &'a P,
+        network_repository: &'a NR,
+        transaction_repository: &'a TR,
+        job_producer: &'a J,
+    ) -> Self {
+        Self {
+            relayer,
+            network,
+            entry_point,
+            provider,
+            network_repository,
+            transaction_repository,
+            job_producer,
+        }
+    }
+
+    /// Handles a bundler JSON-RPC request.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The JSON-RPC request ID.
+    /// * `method` - One of [`BUNDLER_RPC_METHODS`].
+    /// * `params` - The positional request parameters.
+    ///
+    /// # Returns
+    ///
+    /// The JSON-RPC response, carrying an error if the request could not be served.
+    pub async fn handle_request(
+        &self,
+        id: Option<JsonRpcId>,
+        method: &str,
+        params: Value,
+    ) -> JsonRpcResponse<NetworkRpcResult> {
+        let result = match method {
+            "eth_sendUserOperation" => self.send_user_operation(params).await,
+            "eth_estimateUserOperationGas" => self.estimate_user_operation_gas(params).await,
+            "eth_getUserOperationReceipt" => self.get_user_operation_receipt(params).await,
+            "eth_getUserOperationByHash" => self.get_user_operation_by_hash(params).await,
+            "eth_supportedEntryPoints" => Ok(json!([self.entry_point])),
+            _ => Err(BundlerRpcError {
+                code: RpcErrorCodes::METHOD_NOT_FOUND,
+                message: "Method not found",
+                description: format!("Unsupported bundler method: {}", method),
+            }),
+        };
+
+        match result {
+            Ok(value) => create_success_response(id, value),
+            Err(error) => {
+                debug!("Bundler request {} failed: {:?}", method, error);
+                create_error_response(id, error.code, error.message, &error.description)
+            }
+        }
+    }
+
+    /// Validates a user operation and adds it to the open bundle.
+    async fn send_user_operation(&self, params: Value) -> Result<Value, BundlerRpcError> {
+        let (op, entry_point): (UserOperation, Address) = parse_params(params)?;
+        self.check_entry_point(entry_point)?;
+
+        let packed = PackedUserOperation::from(&op);
+        let hash = user_operation_hash(&packed, self.entry_point, self.network.id());
+        // Operations of failed bundles may be submitted again
+        let known_bundle = self
+            .transaction_repository
+            .find_by_user_operation_hash(&self.relayer.id, &hash.to_string())
+            .await?;
+        if known_bundle.is_some_and(|bundle| {
+            !matches!(
+                bundle.status,
+                TransactionStatus::Failed
+                    | TransactionStatus::Canceled
+                    | TransactionStatus::Expired
+            )
+        }) {
+            return Err(BundlerRpcError::invalid_params(format!(
+                "User operation {} is already known",
+                hash
+            )));
+        }
+
+        self.check_fees(&op).await?;
+        self.simulate_validation(&packed).await?;
+        self.add_to_bundle(&op, packed, hash).await?;
+
+        info!(
+            "Accepted user operation {} from {} on relayer {}",
+            hash, op.sender, self.relayer.id
+        );
+        Ok(json!(hash))
+    }
+
+    /// Estimates the gas limits of a user operation.
+    ///
+    /// The call gas comes from executing the account call on behalf of the EntryPoint, and
+    /// the verification gas from estimating `handleOps` with that call gas. Fees are zeroed
+    /// for the estimate so the account needs no deposit.
+    async fn estimate_user_operation_gas(&self, params: Value) -> Result<Value, BundlerRpcError> {
+        let (mut op, entry_point): (UserOperation, Address) = parse_params(params)?;
+        self.check_entry_point(entry_point)?;
+        op.max_fee_per_gas = U256::ZERO;
+        op.max_priority_fee_per_gas = U256::ZERO;
+
+        let call_execution_gas = if op.call_data.is_empty() {
+            0
+        } else if op.factory.is_some() {
+            UNDEPLOYED_ACCOUNT_CALL_GAS
+        } else {
+            self.estimate_gas(self.entry_point, op.sender, &op.call_data)
+                .await
+                .map_err(BundlerRpcError::from_entry_point_call)?
+        };
+        let call_gas_limit = call_execution_gas * GAS_LIMIT_BUFFER_MULTIPLIER / 100;
+
+        op.call_gas_limit = U256::from(call_gas_limit);
+        op.verification_gas_limit = U256::from(ESTIMATION_VERIFICATION_GAS_CAP);
+        if op.paymaster.is_some() {
+            op.paymaster_verification_gas_limit = Some(U256::from(ESTIMATION_VERIFICATION_GAS_CAP));
+        }
+        let packed = PackedUserOperation::from(&op);
+        let pre_verification_gas = pre_verification_gas(&packed);
+
+        let beneficiary = self.beneficiary()?;
+        let total_gas = self
+            .estimate_gas(
+                beneficiary,
+                self.entry_point,
+                &encode_handle_ops(vec![packed], beneficiary),
+            )
+            .await
+            .map_err(BundlerRpcError::from_entry_point_call)?;
+        let verification_gas_limit =
+            total_gas.saturating_sub(call_execution_gas) * GAS_LIMIT_BUFFER_MULTIPLIER / 100;
+
+        let estimate = UserOperationGasEstimate {
+            pre_verification_gas: U256::from(pre_verification_gas),
+            verification_gas_limit: U256::from(verification_gas_limit),
+            call_gas_limit: U256::from(call_gas_limit),
+            paymaster_verification_gas_limit: op
+                .paymaster
+                .map(|_| U256::from(verification_gas_limit)),
+        };
+        serde_json::to_value(estimate).map_err(BundlerRpcError::internal)
+    }
+
+    /// Returns the receipt of a user operation, or `null` until its bundle is mined.
+    async fn get_user_operation_receipt(&self, params: Value) -> Result<Value, BundlerRpcError> {
+        let (hash,): (B256,) = parse_params(params)?;
+        let Some(bundle) = self
+            .transaction_repository
+            .find_by_user_operation_hash(&self.relayer.id, &hash.to_string())
+            .await?
+        else {
+            return Ok(Value::Null);
+        };
+        let Some(receipt) = self.find_bundle_receipt(&bundle).await? else {
+            return Ok(Value::Null);
+        };
+
+        match user_operation_receipt(&receipt, self.entry_point, hash) {
+            Some(op_receipt) => serde_json::to_value(op_receipt).map_err(BundlerRpcError::internal),
+            None => Ok(Value::Null),
+        }
+    }
+
+    /// Returns a user operation with the bundle transaction carrying it.
+    async fn get_user_operation_by_hash(&self, params: Value) -> Result<Value, BundlerRpcError> {
+        let (hash,): (B256,) = parse_params(params)?;
+        let Some(bundle) = self
+            .transaction_repository
+            .find_by_user_operation_hash(&self.relayer.id, &hash.to_string())
+            .await?
+        else {
+            return Ok(Value::Null);
+        };
+
+        let Some(op) = bundle_operations(&bundle)?
+            .into_iter()
+            .find(|op| user_operation_hash(op, self.entry_point, self.network.id()) == hash)
+        else {
+            return Ok(Value::Null);
+        };
+        let receipt = self.find_bundle_receipt(&bundle).await?;
+
+        let result = UserOperationByHash {
+            user_operation: UserOperation::from(&op),
+            entry_point: self.entry_point,
+            transaction_hash: receipt.as_ref().map(|r| r.transaction_hash),
+            block_hash: receipt.as_ref().and_then(|r| r.block_hash),
+            block_number: receipt
+                .as_ref()
+                .and_then(|r| r.block_number)
+                .map(U256::from),
+        };
+        serde_json::to_value(result).map_err(BundlerRpcError::internal)
+    }
+
+    fn check_entry_point(&self, entry_point: Address) -> Result<(), BundlerRpcError> {
+        if entry_point != self.entry_point {
+            return Err(BundlerRpcError::invalid_params(format!(
+                "Unsupported entry point {}, expected {}",
+                entry_point, self.entry_point
+            )));
+        }
+        Ok(())
+    }
+
+    fn beneficiary(&self) -> Result<Address, BundlerRpcError> {
+        Address::from_str(&self.relayer.address).map_err(BundlerRpcError::internal)
+    }
+
+    /// Rejects operations whose fees would not cover the relayer's cost of including them.
+    async fn check_fees(&self, op: &UserOperation) -> Result<(), BundlerRpcError> {
+        if op.max_priority_fee_per_gas > op.max_fee_per_gas {
+            return Err(BundlerRpcError::invalid_params(
+                "maxPriorityFeePerGas cannot exceed maxFeePerGas",
+            ));
+        }
+        let gas_price = self.provider.get_gas_price().await?;
+        if op.max_fee_per_gas < U256::from(gas_price) {
+            return Err(BundlerRpcError::invalid_params(format!(
+                "maxFeePerGas {} is below the network gas price {}",
+                op.max_fee_per_gas, gas_price
+            )));
+        }
+        Ok(())
+    }
+
+    /// Simulates `handleOps` with the single operation, surfacing `FailedOp` rejections.
+    async fn simulate_validation(&self, op: &PackedUserOperation) -> Result<(), BundlerRpcError> {
+        let beneficiary = self.beneficiary()?;
+        let call = CallRequest {
+            from: Some(beneficiary),
+            to: Some(self.entry_point.into()),
+            input: TransactionInput::new(encode_handle_ops(vec![op.clone()], beneficiary)),
+            ..Default::default()
+        };
+
+        self.provider
+            .call_contract(&call, BlockNumberOrTag::Pending)
+            .await
+            .map(|_| ())
+            .map_err(BundlerRpcError::from_entry_point_call)
+    }
+
+    async fn estimate_gas(
+        &self,
+        from: Address,
+        to: Address,
+        data: &Bytes,
+    ) -> Result<u64, ProviderError> {
+        let result = self
+            .provider
+            .raw_request_dyn(
+                "eth_estimateGas",
+                json!([{ "from": from, "to": to, "data": data }]),
+            )
+            .await?;
+        let gas: U256 = serde_json::from_value(result)
+            .map_err(|e| ProviderError::Other(format!("Invalid gas estimate: {}", e)))?;
+        Ok(gas.saturating_to())
+    }
+
+    /// Adds an operation to the open bundle, starting a new bundle if none is open.
+    async fn add_to_bundle(
+        &self,
+        op: &UserOperation,
+        packed: PackedUserOperation,
+        hash: B256,
+    ) -> Result<(), BundlerRpcError> {
+        let _guard = BUNDLE_LOCK.lock().await;
+        let beneficiary = self.beneficiary()?;
+
+        if let Some(bundle) = self.find_open_bundle().await? {
+            let mut ops = bundle_operations(&bundle)?;
+            let operation_count = ops.len();
+            ops.push(packed.clone());
+
+            let mut evm_data = bundle
+                .network_data
+                .get_evm_transaction_data()
+                .map_err(BundlerRpcError::internal)?;
+            evm_data.data = Some(encode_handle_ops(ops, beneficiary).to_string());
+            evm_data.gas_limit =
+                Some(evm_data.gas_limit.unwrap_or_default() + user_operation_gas_limit(op));
+            evm_data
+                .user_operation_hashes
+                .get_or_insert_with(Vec::new)
+                .push(hash.to_string());
+
+            let updated = self
+                .transaction_repository
+                .update_open_bundle(
+                    bundle.id.clone(),
+                    operation_count,
+                    NetworkTransactionData::Evm(evm_data),
+                )
+                .await?;
+            if updated.is_some() {
+                debug!("Added user operation {} to bundle {}", hash, bundle.id);
+                return Ok(());
+            }
+            // The bundle is being sent or took another operation, so open a new one
+            debug!(
+                "Bundle {} closed before user operation {} was added",
+                bundle.id, hash
+            );
+        }
+
+        let request = NetworkTransactionRequest::Evm(EvmTransactionRequest {
+            to: Some(self.entry_point.to_checksum(None)),
+            value: U256::ZERO,
+            data: Some(encode_handle_ops(vec![packed], beneficiary).to_string()),
+            gas_limit: Some(USER_OPERATION_BUNDLE_GAS_OVERHEAD + user_operation_gas_limit(op)),
+            ..Default::default()
+        });
+        let network_model = self
+            .network_repository
+            .get_by_name(NetworkType::Evm, &self.relayer.network)
+            .await?
+            .ok_or_else(|| {
+                BundlerRpcError::internal(format!("Network {} not found", self.relayer.network))
+            })?;
+        let mut bundle = TransactionRepoModel::try_from((&request, self.relayer, &network_model))
+            .map_err(BundlerRpcError::internal)?;
+        if let NetworkTransactionData::Evm(evm_data) = &mut bundle.network_data {
+            evm_data.user_operation_hashes = Some(vec![hash.to_string()]);
+        }
+
+        self.transaction_repository.create(bundle.clone()).await?;
+        self.job_producer
+            .produce_transaction_request_job(
+                TransactionRequest::new(bundle.id.clone(), bundle.relayer_id.clone()),
+                Some(Utc::now().timestamp() + USER_OPERATION_BUNDLE_WINDOW_SECONDS),
+            )
+            .await
+            .map_err(BundlerRpcError::internal)?;
+        debug!("Opened bundle {} with user operation {}", bundle.id, hash);
+        Ok(())
+    }
+
+    /// Finds a bundle still collecting operations: pending, inside its collection window
+    /// and below the bundle size limit.
+    async fn find_open_bundle(&self) -> Result<Option<TransactionRepoModel>, BundlerRpcError> {
+        let pending = self
+            .transaction_repository
+            .find_by_status(&self.relayer.id, &[TransactionStatus::Pending])
+            .await?;
+        let window_start = Utc::now() - Duration::seconds(USER_OPERATION_BUNDLE_WINDOW_SECONDS);
+
+        Ok(pending.into_iter().find(|tx| {
+            let Ok(evm_data) = tx.network_data.get_evm_transaction_data() else {
+                return false;
+            };
+
+            DateTime::parse_from_rfc3339(&tx.created_at)
+                .is_ok_and(|created_at| created_at > window_start)
+                && evm_data.nonce.is_none()
+                && evm_data
+                    .user_operation_hashes
+                    .is_some_and(|hashes| hashes.len() < MAX_USER_OPERATIONS_PER_BUNDLE)
+        }))
+    }
+
+    /// Returns the receipt of whichever submission of the bundle was mined, if any.
+    async fn find_bundle_receipt(
+        &self,
+        bundle: &TransactionRepoModel,
+    ) -> Result<Option<TransactionReceipt>, BundlerRpcError> {
+        for tx_hash in bundle.hashes.iter().rev() {
+            if let Some(receipt) = self.provider.get_transaction_receipt(tx_hash).await? {
+                return Ok(Some(receipt));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Decodes the operations carried by a bundle transaction.
+fn bundle_operations(
+    bundle: &TransactionRepoModel,
+) -> Result<Vec<PackedUserOperation>, BundlerRpcError> {
+    let evm_data = bundle
+        .network_data
+        .get_evm_transaction_data()
+        .map_err(BundlerRpcError::internal)?;
+
+    evm_data
+        .data
+        .as_deref()
+        .and_then(|data| Bytes::from_str(data).ok())
+        .and_then(|data| decode_handle_ops(&data))
+        .ok_or_else(|| {
+            BundlerRpcError::internal(format!("Transaction {} is not a bundle", bundle.id))
+        })
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, BundlerRpcError> {
+    serde_json::from_value(params).map_err(|e| BundlerRpcError::invalid_params(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{EvmNetworkConfig, NetworkConfigCommon},
+        jobs::MockJobProducerTrait,
+        models::{EvmRpcResult, RelayerEvmPolicy, RelayerNetworkPolicy},
+        repositories::{InMemoryTransactionRepository, MockNetworkRepository},
+        services::MockEvmProviderTrait,
+    };
+    use alloy::sol_types::SolError;
+    use std::future::ready;
+
+    const ENTRY_POINT: &str = "0x0000000071727De22E5E9d8BAf0edAc6f37da032";
+
+    fn create_test_relayer() -> RelayerRepoModel {
+        RelayerRepoModel {
+            id: "bundler-relayer".to_string(),
+            name: "Bundler Relayer".to_string(),
+            network: "mainnet".to_string(),
+            address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+            paused: false,
+            system_disabled: false,
+            signer_id: "test-signer-id".to_string(),
+            notification_id: None,
+            policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                entry_point: Some(ENTRY_POINT.to_string()),
+                ..Default::default()
+            }),
+            network_type: NetworkType::Evm,
+            custom_rpc_urls: None,
+            signer_rotations: vec![],
+        }
+    }
+
+    fn create_test_network() -> EvmNetwork {
+        EvmNetwork::try_from(create_test_network_repo_model()).unwrap()
+    }
+
+    fn create_test_network_repo_model() -> NetworkRepoModel {
+        NetworkRepoModel::new_evm(EvmNetworkConfig {
+            common: NetworkConfigCommon {
+                network: "mainnet".to_string(),
+                from: None,
+                rpc_urls: Some(vec!["https://rpc.example.com".to_string()]),
+                explorer_urls: None,
+                average_blocktime_ms: Some(12000),
+                is_testnet: Some(false),
+                tags: None,
+            },
+            chain_id: Some(1),
+            required_confirmations: Some(1),
+            features: Some(vec!["eip1559".to_string()]),
+            symbol: Some("ETH".to_string()),
+            gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
+        })
+    }
+
+    fn create_test_user_operation(nonce: u64) -> UserOperation {
+        UserOperation {
+            sender: Address::from_str("0x70997970C51812dc3A010C7d01b50e0d17dc79C8").unwrap(),
+            nonce: U256::from(nonce),
+            call_data: Bytes::from(vec![0xde, 0xad, 0xbe, 0xef]),
+            call_gas_limit: U256::from(100_000),
+            verification_gas_limit: U256::from(150_000),
+            pre_verification_gas: U256::from(50_000),
+            max_fee_per_gas: U256::from(2_000_000_000u64),
+            max_priority_fee_per_gas: U256::from(1_000_000_000u64),
+            signature: Bytes::from(vec![0x55; 65]),
+            ..Default::default()
+        }
+    }
+
+    fn send_params(op: &UserOperation, entry_point: &str) -> Value {
+        json!([op, entry_point])
+    }
+
+    fn network_repository() -> MockNetworkRepository {
+        let mut network_repo = MockNetworkRepository::new();
+        network_repo
+            .expect_get_by_name()
+            .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+        network_repo
+    }
+
+    fn accepting_provider() -> MockEvmProviderTrait {
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_gas_price()
+            .returning(|| Box::pin(ready(Ok(1_000_000_000u128))));
+        provider
+            .expect_call_contract()
+            .returning(|_, _| Box::pin(ready(Ok(Bytes::new()))));
+        provider
+    }
+
+    fn result_value(response: JsonRpcResponse<NetworkRpcResult>) -> Value {
+        match response.result {
+            Some(NetworkRpcResult::Evm(EvmRpcResult::RawRpcResult(value))) => value,
+            other => panic!("Expected a result, got {:?} ({:?})", other, response.error),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_user_operations_share_a_bundle() {
+        let relayer = create_test_relayer();
+        let network = create_test_network();
+        let provider = accepting_provider();
+        let network_repo = network_repository();
+        let tx_repo = InMemoryTransactionRepository::new();
+        let mut job_producer = MockJobProducerTrait::new();
+        job_producer
+            .expect_produce_transaction_request_job()
+            .withf(|_, scheduled_on| scheduled_on.is_some())
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        let bundler = EvmBundler::new(
+            &relayer,
+            &network,
+            Address::from_str(ENTRY_POINT).unwrap(),
+            &provider,
+            &network_repo,
+            &tx_repo,
+            &job_producer,
+        );
+
+        let first = create_test_user_operation(0);
+        let second = create_test_user_operation(1);
+        let first_hash = result_value(
+            bundler
+                .handle_request(
+                    None,
+                    "eth_sendUserOperation",
+                    send_params(&first, ENTRY_POINT),
+                )
+                .await,
+        );
+        let second_hash = result_value(
+            bundler
+                .handle_request(
+                    None,
+                    "eth_sendUserOperation",
+                    send_params(&second, ENTRY_POINT),
+                )
+                .await,
+        );
+
+        let bundles = tx_repo.list_all().await.unwrap();
+        assert_eq!(bundles.len(), 1);
+        let evm_data = bundles[0].network_data.get_evm_transaction_data().unwrap();
+        assert_eq!(evm_data.to, Some(ENTRY_POINT.to_string()));
+        assert_eq!(
+            evm_data.user_operation_hashes,
+            Some(vec![
+                first_hash.as_str().unwrap().to_string(),
+                second_hash.as_str().unwrap().to_string()
+            ])
+        );
+        assert_eq!(
+            evm_data.gas_limit,
+            Some(
+                USER_OPERATION_BUNDLE_GAS_OVERHEAD
+                    + user_operation_gas_limit(&first)
+                    + user_operation_gas_limit(&second)
+            )
+        );
+        assert_eq!(bundle_operations(&bundles[0]).unwrap().len(), 2);
+
+        // The same operation cannot be submitted twice while its bundle is pending
+        let response = bundler
+            .handle_request(
+                None,
+                "eth_sendUserOperation",
+                send_params(&first, ENTRY_POINT),
+            )
+            .await;
+        assert_eq!(response.error.unwrap().code, RpcErrorCodes::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_send_user_operation_after_bundle_is_sealed_opens_new_bundle() {
+        let relayer = create_test_relayer();
+        let network = create_test_network();
+        let provider = accepting_provider();
+        let network_repo = network_repository();
+        let tx_repo = InMemoryTransactionRepository::new();
+        let mut job_producer = MockJobProducerTrait::new();
+        job_producer
+            .expect_produce_transaction_request_job()
+            .times(2)
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        let bundler = EvmBundler::new(
+            &relayer,
+            &network,
+            Address::from_str(ENTRY_POINT).unwrap(),
+            &provider,
+            &network_repo,
+            &tx_repo,
+            &job_producer,
+        );
+
+        let first_hash = result_value(
+            bundler
+                .handle_request(
+                    None,
+                    "eth_sendUserOperation",
+                    send_params(&create_test_user_operation(0), ENTRY_POINT),
+                )
+                .await,
+        );
+        let first_bundle = tx_repo.list_all().await.unwrap().remove(0);
+
+        // The bundle's job starts preparing it while it is still inside its window
+        tx_repo.seal_bundle(first_bundle.id.clone()).await.unwrap();
+
+        let second_hash = result_value(
+            bundler
+                .handle_request(
+                    None,
+                    "eth_sendUserOperation",
+                    send_params(&create_test_user_operation(1), ENTRY_POINT),
+                )
+                .await,
+        );
+
+        let sealed = tx_repo.get_by_id(first_bundle.id.clone()).await.unwrap();
+        let evm_data = sealed.network_data.get_evm_transaction_data().unwrap();
+        assert_eq!(
+            evm_data.user_operation_hashes,
+            Some(vec![first_hash.as_str().unwrap().to_string()])
+        );
+
+        let second_bundle = tx_repo
+            .find_by_user_operation_hash(&relayer.id, second_hash.as_str().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_ne!(second_bundle.id, first_bundle.id);
+        assert_eq!(tx_repo.count().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_user_operation_rejected_by_entry_point() {
+        let relayer = create_test_relayer();
+        let network = create_test_network();
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_gas_price()
+            .returning(|| Box::pin(ready(Ok(1_000_000_000u128))));
+        provider.expect_call_contract().returning(|_, _| {
+            let data = super::super::entry_point::IEntryPoint::FailedOp {
+                opIndex: U256::ZERO,
+                reason: "AA23 reverted".to_string(),
+            }
+            .abi_encode();
+            Box::pin(ready(Err(ProviderError::Reverted {
+                message: "execution reverted".to_string(),
+                data: Some(data.into()),
+            })))
+        });
+        let network_repo = MockNetworkRepository::new();
+        let tx_repo = InMemoryTransactionRepository::new();
+        let job_producer = MockJobProducerTrait::new();
+        let bundler = EvmBundler::new(
+            &relayer,
+            &network,
+            Address::from_str(ENTRY_POINT).unwrap(),
+            &provider,
+            &network_repo,
+            &tx_repo,
+            &job_producer,
+        );
+
+        let response = bundler
+            .handle_request(
+                None,
+                "eth_sendUserOperation",
+                send_params(&create_test_user_operation(0), ENTRY_POINT),
+            )
+            .await;
+
+        let error = response.error.unwrap();
+        assert_eq!(error.code, Erc4337ErrorCodes::REJECTED_BY_ENTRY_POINT);
+        assert_eq!(error.description, "AA23 reverted");
+        assert_eq!(tx_repo.count().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_send_user_operation_checks_entry_point_and_fees() {
+        let relayer = create_test_relayer();
+        let network = create_test_network();
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_gas_price()
+            .returning(|| Box::pin(ready(Ok(5_000_000_000u128))));
+        let network_repo = MockNetworkRepository::new();
+        let tx_repo = InMemoryTransactionRepository::new();
+        let job_producer = MockJobProducerTrait::new();
+        let bundler = EvmBundler::new(
+            &relayer,
+            &network,
+            Address::from_str(ENTRY_POINT).unwrap(),
+            &provider,
+            &network_repo,
+            &tx_repo,
+            &job_producer,
+        );
+        let op = create_test_user_operation(0);
+
+        let response = bundler
+            .handle_request(
+                None,
+                "eth_sendUserOperation",
+                send_params(&op, "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789"),
+            )
+            .await;
+        let error = response.error.unwrap();
+        assert!(error.description.contains("Unsupported entry point"));
+
+        let response = bundler
+            .handle_request(None, "eth_sendUserOperation", send_params(&op, ENTRY_POINT))
+            .await;
+        let error = response.error.unwrap();
+        assert_eq!(error.code, RpcErrorCodes::INVALID_PARAMS);
+        assert!(error.description.contains("below the network gas price"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_operation_has_no_receipt() {
+        let relayer = create_test_relayer();
+        let network = create_test_network();
+        let provider = MockEvmProviderTrait::new();
+        let network_repo = MockNetworkRepository::new();
+        let tx_repo = InMemoryTransactionRepository::new();
+        let job_producer = MockJobProducerTrait::new();
+        let bundler = EvmBundler::new(
+            &relayer,
+            &network,
+            Address::from_str(ENTRY_POINT).unwrap(),
+            &provider,
+            &network_repo,
+            &tx_repo,
+            &job_producer,
+        );
+        let params = json!([B256::repeat_byte(0xab)]);
+
+        let receipt = bundler
+            .handle_request(None, "eth_getUserOperationReceipt", params.clone())
+            .await;
+        assert_eq!(result_value(receipt), Value::Null);
+
+        let op = bundler
+            .handle_request(None, "eth_getUserOperationByHash", params)
+            .await;
+        assert_eq!(result_value(op), Value::Null);
+
+        let entry_points = bundler
+            .handle_request(None, "eth_supportedEntryPoints", json!([]))
+            .await;
+        assert_eq!(
+            result_value(entry_points),
+            json!([Address::from_str(ENTRY_POINT).unwrap()])
+        );
+    }
+}
@@ -0,0 +1,477 @@
+//! ERC-4337 EntryPoint v0.7 bindings and user operation encoding.
+//!
+//! Converts between the RPC representation of a user operation and the packed form the
+//! EntryPoint expects, computes user operation hashes, and decodes the `handleOps` call
+//! data, revert errors and events the bundler works with.
+use alloy::{
+    primitives::{keccak256, Address, Bytes, B256, U256},
+    sol,
+    sol_types::{SolCall, SolError, SolEvent, SolValue},
+};
+
+use crate::{
+    constants::{GAS_TX_DATA_NONZERO, GAS_TX_DATA_ZERO, USER_OPERATION_PRE_VERIFICATION_OVERHEAD},
+    domain::transaction::evm::decode_revert_reason,
+    models::{TransactionReceipt, UserOperation, UserOperationReceipt},
+};
+
+sol! {
+    #[derive(Debug, PartialEq)]
+    struct PackedUserOperation {
+        address sender;
+        uint256 nonce;
+        bytes initCode;
+        bytes callData;
+        bytes32 accountGasLimits;
+        uint256 preVerificationGas;
+        bytes32 gasFees;
+        bytes paymasterAndData;
+        bytes signature;
+    }
+
+    interface IEntryPoint {
+        function handleOps(PackedUserOperation[] calldata ops, address payable beneficiary) external;
+
+        error FailedOp(uint256 opIndex, string reason);
+        error FailedOpWithRevert(uint256 opIndex, string reason, bytes inner);
+
+        event UserOperationEvent(
+            bytes32 indexed userOpHash,
+            address indexed sender,
+            address indexed paymaster,
+            uint256 nonce,
+            bool success,
+            uint256 actualGasCost,
+            uint256 actualGasUsed
+        );
+        event UserOperationRevertReason(
+            bytes32 indexed userOpHash,
+            address indexed sender,
+            uint256 nonce,
+            bytes revertReason
+        );
+    }
+}
+
+/// Signature length assumed when pricing the call data of an unsigned user operation
+const DUMMY_SIGNATURE_LENGTH: u64 = 65;
+
+/// Packs two 128-bit values into a single word, `high` in the upper half.
+fn pack_u128_pair(high: U256, low: U256) -> B256 {
+    let mask = U256::from(u128::MAX);
+    B256::from(((high & mask) << 128) | (low & mask))
+}
+
+/// Splits a word packed by [`pack_u128_pair`] back into its two halves.
+fn unpack_u128_pair(word: &B256) -> (U256, U256) {
+    (
+        U256::from_be_slice(&word[..16]),
+        U256::from_be_slice(&word[16..]),
+    )
+}
+
+impl From<&UserOperation> for PackedUserOperation {
+    fn from(op: &UserOperation) -> Self {
+        let init_code = match op.factory {
+            Some(factory) => [
+                factory.as_slice(),
+                op.factory_data
+                    .as_ref()
+                    .map_or(&[][..], |data| data.as_ref()),
+            ]
+            .concat(),
+            None => Vec::new(),
+        };
+        let paymaster_and_data = match op.paymaster {
+            Some(paymaster) => [
+                paymaster.as_slice(),
+                pack_u128_pair(
+                    op.paymaster_verification_gas_limit.unwrap_or_default(),
+                    op.paymaster_post_op_gas_limit.unwrap_or_default(),
+                )
+                .as_slice(),
+                op.paymaster_data
+                    .as_ref()
+                    .map_or(&[][..], |data| data.as_ref()),
+            ]
+            .concat(),
+            None => Vec::new(),
+        };
+
+        Self {
+            sender: op.sender,
+            nonce: op.nonce,
+            initCode: init_code.into(),
+            callData: op.call_data.clone(),
+            accountGasLimits: pack_u128_pair(op.verification_gas_limit, op.call_gas_limit),
+            preVerificationGas: op.pre_verification_gas,
+            gasFees: pack_u128_pair(op.max_priority_fee_per_gas, op.max_fee_per_gas),
+            paymasterAndData: paymaster_and_data.into(),
+            signature: op.signature.clone(),
+        }
+    }
+}
+
+impl From<&PackedUserOperation> for UserOperation {
+    fn from(op: &PackedUserOperation) -> Self {
+        let (verification_gas_limit, call_gas_limit) = unpack_u128_pair(&op.accountGasLimits);
+        let (max_priority_fee_per_gas, max_fee_per_gas) = unpack_u128_pair(&op.gasFees);
+
+        let (factory, factory_data) = match op.initCode.len() {
+            len if len >= Address::len_bytes() => (
+                Some(Address::from_slice(&op.initCode[..20])),
+                Some(Bytes::copy_from_slice(&op.initCode[20..])),
+            ),
+            _ => (None, None),
+        };
+
+        let mut user_operation = Self {
+            sender: op.sender,
+            nonce: op.nonce,
+            factory,
+            factory_data,
+            call_data: op.callData.clone(),
+            call_gas_limit,
+            verification_gas_limit,
+            pre_verification_gas: op.preVerificationGas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            signature: op.signature.clone(),
+            ..Default::default()
+        };
+
+        // paymaster (20 bytes) | verification gas (16 bytes) | post-op gas (16 bytes) | data
+        if op.paymasterAndData.len() >= 52 {
+            let data = &op.paymasterAndData;
+            let (verification_gas, post_op_gas) =
+                unpack_u128_pair(&B256::from_slice(&data[20..52]));
+            user_operation.paymaster = Some(Address::from_slice(&data[..20]));
+            user_operation.paymaster_verification_gas_limit = Some(verification_gas);
+            user_operation.paymaster_post_op_gas_limit = Some(post_op_gas);
+            user_operation.paymaster_data = Some(Bytes::copy_from_slice(&data[52..]));
+        }
+
+        user_operation
+    }
+}
+
+/// Computes the hash identifying a user operation, as returned by `getUserOpHash`.
+pub fn user_operation_hash(op: &PackedUserOperation, entry_point: Address, chain_id: u64) -> B256 {
+    let encoded = (
+        op.sender,
+        op.nonce,
+        keccak256(&op.initCode),
+        keccak256(&op.callData),
+        op.accountGasLimits,
+        op.preVerificationGas,
+        op.gasFees,
+        keccak256(&op.paymasterAndData),
+    )
+        .abi_encode();
+
+    keccak256((keccak256(encoded), entry_point, U256::from(chain_id)).abi_encode())
+}
+
+/// Encodes a `handleOps` call paying the bundle's fees to `beneficiary`.
+pub fn encode_handle_ops(ops: Vec<PackedUserOperation>, beneficiary: Address) -> Bytes {
+    IEntryPoint::handleOpsCall { ops, beneficiary }
+        .abi_encode()
+        .into()
+}
+
+/// Decodes the user operations carried by `handleOps` call data.
+pub fn decode_handle_ops(data: &[u8]) -> Option<Vec<PackedUserOperation>> {
+    IEntryPoint::handleOpsCall::abi_decode(data)
+        .ok()
+        .map(|call| call.ops)
+}
+
+/// Decodes the `FailedOp` revert the EntryPoint raises when a user operation fails validation.
+pub fn decode_failed_op(data: &[u8]) -> Option<String> {
+    if let Ok(failed) = IEntryPoint::FailedOp::abi_decode(data) {
+        return Some(failed.reason);
+    }
+    IEntryPoint::FailedOpWithRevert::abi_decode(data)
+        .ok()
+        .map(|failed| {
+            format!(
+                "{} ({})",
+                failed.reason,
+                decode_revert_reason(&failed.inner)
+            )
+        })
+}
+
+/// Gas the bundle needs to reserve for a user operation.
+pub fn user_operation_gas_limit(op: &UserOperation) -> u64 {
+    [
+        op.pre_verification_gas,
+        op.verification_gas_limit,
+        op.call_gas_limit,
+        op.paymaster_verification_gas_limit.unwrap_or_default(),
+        op.paymaster_post_op_gas_limit.unwrap_or_default(),
+    ]
+    .into_iter()
+    .fold(0u64, |total, gas| {
+        total.saturating_add(gas.saturating_to::<u64>())
+    })
+}
+
+/// Suggested pre-verification gas, covering the operation's share of the bundle call data.
+pub fn pre_verification_gas(op: &PackedUserOperation) -> u64 {
+    let encoded = op.abi_encode();
+    let zero_bytes = encoded.iter().filter(|byte| **byte == 0).count() as u64;
+    let mut data_gas =
+        zero_bytes * GAS_TX_DATA_ZERO + (encoded.len() as u64 - zero_bytes) * GAS_TX_DATA_NONZERO;
+    if op.signature.is_empty() {
+        data_gas += DUMMY_SIGNATURE_LENGTH * GAS_TX_DATA_NONZERO;
+    }
+
+    USER_OPERATION_PRE_VERIFICATION_OVERHEAD + data_gas
+}
+
+/// Builds the receipt of a user operation from the receipt of the bundle that carried it.
+///
+/// The operation's logs are those emitted between the previous operation's
+/// `UserOperationEvent` and its own. Returns `None` if the bundle has no event for the
+/// operation.
+pub fn user_operation_receipt(
+    receipt: &TransactionReceipt,
+    entry_point: Address,
+    user_op_hash: B256,
+) -> Option<UserOperationReceipt> {
+    let logs = receipt.inner.inner.logs();
+    let mut start = 0;
+
+    for (index, log) in logs.iter().enumerate() {
+        if log.address() != entry_point {
+            continue;
+        }
+        let Ok(event) = IEntryPoint::UserOperationEvent::decode_log(&log.inner) else {
+            continue;
+        };
+        if event.userOpHash != user_op_hash {
+            start = index + 1;
+            continue;
+        }
+
+        let op_logs = logs[start..index].to_vec();
+        let reason = op_logs.iter().find_map(|log| {
+            IEntryPoint::UserOperationRevertReason::decode_log(&log.inner)
+                .ok()
+                .filter(|revert| revert.userOpHash == user_op_hash)
+                .map(|revert| revert.data.revertReason)
+        });
+
+        return Some(UserOperationReceipt {
+            user_op_hash,
+            entry_point,
+            sender: event.sender,
+            nonce: event.nonce,
+            paymaster: event.paymaster,
+            actual_gas_cost: event.actualGasCost,
+            actual_gas_used: event.actualGasUsed,
+            success: event.success,
+            reason,
+            logs: op_logs,
+            receipt: receipt.clone(),
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use std::str::FromStr;
+
+    fn entry_point() -> Address {
+        Address::from_str("0x0000000071727De22E5E9d8BAf0edAc6f37da032").unwrap()
+    }
+
+    fn create_test_user_operation() -> UserOperation {
+        UserOperation {
+            sender: Address::from_str("0x70997970C51812dc3A010C7d01b50e0d17dc79C8").unwrap(),
+            nonce: U256::from(7),
+            factory: Some(Address::repeat_byte(0x11)),
+            factory_data: Some(Bytes::from(vec![0xaa, 0xbb])),
+            call_data: Bytes::from(vec![0xde, 0xad, 0xbe, 0xef]),
+            call_gas_limit: U256::from(100_000),
+            verification_gas_limit: U256::from(200_000),
+            pre_verification_gas: U256::from(50_000),
+            max_fee_per_gas: U256::from(2_000_000_000u64),
+            max_priority_fee_per_gas: U256::from(1_000_000_000u64),
+            paymaster: Some(Address::repeat_byte(0x22)),
+            paymaster_verification_gas_limit: Some(U256::from(30_000)),
+            paymaster_post_op_gas_limit: Some(U256::from(10_000)),
+            paymaster_data: Some(Bytes::from(vec![0x01])),
+            signature: Bytes::from(vec![0x55; 65]),
+        }
+    }
+
+    #[test]
+    fn test_pack_and_unpack_user_operation() {
+        let op = create_test_user_operation();
+        let packed = PackedUserOperation::from(&op);
+
+        assert_eq!(packed.initCode.len(), 22);
+        assert_eq!(packed.paymasterAndData.len(), 53);
+        assert_eq!(
+            unpack_u128_pair(&packed.accountGasLimits),
+            (U256::from(200_000), U256::from(100_000))
+        );
+        assert_eq!(UserOperation::from(&packed), op);
+    }
+
+    #[test]
+    fn test_unpack_user_operation_without_factory_or_paymaster() {
+        let op = UserOperation {
+            factory: None,
+            factory_data: None,
+            paymaster: None,
+            paymaster_verification_gas_limit: None,
+            paymaster_post_op_gas_limit: None,
+            paymaster_data: None,
+            ..create_test_user_operation()
+        };
+        let packed = PackedUserOperation::from(&op);
+
+        assert!(packed.initCode.is_empty());
+        assert!(packed.paymasterAndData.is_empty());
+        assert_eq!(UserOperation::from(&packed), op);
+    }
+
+    #[test]
+    fn test_user_operation_hash_binds_entry_point_and_chain() {
+        let packed = PackedUserOperation::from(&create_test_user_operation());
+        let hash = user_operation_hash(&packed, entry_point(), 1);
+
+        assert_eq!(hash, user_operation_hash(&packed, entry_point(), 1));
+        assert_ne!(hash, user_operation_hash(&packed, entry_point(), 10));
+        assert_ne!(hash, user_operation_hash(&packed, Address::ZERO, 1));
+    }
+
+    #[test]
+    fn test_handle_ops_round_trip() {
+        let packed = PackedUserOperation::from(&create_test_user_operation());
+        let data = encode_handle_ops(vec![packed.clone(), packed.clone()], Address::ZERO);
+
+        assert_eq!(data[..4], IEntryPoint::handleOpsCall::SELECTOR);
+        assert_eq!(decode_handle_ops(&data), Some(vec![packed.clone(), packed]));
+        assert_eq!(decode_handle_ops(&[0x12, 0x34]), None);
+    }
+
+    #[test]
+    fn test_decode_failed_op() {
+        let data = IEntryPoint::FailedOp {
+            opIndex: U256::ZERO,
+            reason: "AA21 didn't pay prefund".to_string(),
+        }
+        .abi_encode();
+
+        assert_eq!(
+            decode_failed_op(&data),
+            Some("AA21 didn't pay prefund".to_string())
+        );
+        assert_eq!(decode_failed_op(&[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+
+    fn create_test_log(address: Address, data: alloy::primitives::LogData) -> Value {
+        serde_json::to_value(alloy::rpc::types::Log {
+            inner: alloy::primitives::Log { address, data },
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn create_test_receipt(logs: Vec<Value>) -> TransactionReceipt {
+        serde_json::from_value(json!({
+            "transactionHash": B256::repeat_byte(0x01),
+            "transactionIndex": "0x0",
+            "blockHash": B256::repeat_byte(0x02),
+            "blockNumber": "0x10",
+            "from": Address::repeat_byte(0x03),
+            "to": entry_point(),
+            "cumulativeGasUsed": "0x30000",
+            "gasUsed": "0x30000",
+            "contractAddress": null,
+            "logs": logs,
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "type": "0x2",
+            "status": "0x1",
+            "effectiveGasPrice": "0x3b9aca00"
+        }))
+        .unwrap()
+    }
+
+    fn user_operation_event(hash: B256, success: bool) -> alloy::primitives::LogData {
+        IEntryPoint::UserOperationEvent {
+            userOpHash: hash,
+            sender: Address::repeat_byte(0x04),
+            paymaster: Address::ZERO,
+            nonce: U256::from(1),
+            success,
+            actualGasCost: U256::from(1_000),
+            actualGasUsed: U256::from(100),
+        }
+        .encode_log_data()
+    }
+
+    #[test]
+    fn test_user_operation_receipt_selects_operation_logs() {
+        let first = B256::repeat_byte(0xaa);
+        let second = B256::repeat_byte(0xbb);
+        let account_log = alloy::primitives::LogData::new_unchecked(vec![], Bytes::from(vec![1]));
+        let revert = IEntryPoint::UserOperationRevertReason {
+            userOpHash: second,
+            sender: Address::repeat_byte(0x04),
+            nonce: U256::from(1),
+            revertReason: Bytes::from(vec![0xde, 0xad]),
+        }
+        .encode_log_data();
+        let receipt = create_test_receipt(vec![
+            create_test_log(entry_point(), user_operation_event(first, true)),
+            create_test_log(Address::repeat_byte(0x04), account_log),
+            create_test_log(entry_point(), revert),
+            create_test_log(entry_point(), user_operation_event(second, false)),
+        ]);
+
+        let op_receipt = user_operation_receipt(&receipt, entry_point(), second).unwrap();
+        assert!(!op_receipt.success);
+        assert_eq!(op_receipt.logs.len(), 2);
+        assert_eq!(op_receipt.reason, Some(Bytes::from(vec![0xde, 0xad])));
+        assert_eq!(op_receipt.actual_gas_cost, U256::from(1_000));
+
+        let op_receipt = user_operation_receipt(&receipt, entry_point(), first).unwrap();
+        assert!(op_receipt.success);
+        assert!(op_receipt.logs.is_empty());
+
+        assert!(user_operation_receipt(&receipt, entry_point(), B256::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_user_operation_gas_limit_sums_all_limits() {
+        assert_eq!(
+            user_operation_gas_limit(&create_test_user_operation()),
+            50_000 + 200_000 + 100_000 + 30_000 + 10_000
+        );
+    }
+
+    #[test]
+    fn test_pre_verification_gas_prices_missing_signature() {
+        let signed = PackedUserOperation::from(&create_test_user_operation());
+        let unsigned = PackedUserOperation {
+            signature: Bytes::new(),
+            ..signed.clone()
+        };
+
+        // The missing signature is priced as a 65-byte one, up to the ABI padding
+        assert!(pre_verification_gas(&signed) > USER_OPERATION_PRE_VERIFICATION_OVERHEAD);
+        assert!(
+            pre_verification_gas(&signed).abs_diff(pre_verification_gas(&unsigned))
+                < 32 * GAS_TX_DATA_NONZERO
+        );
+    }
+}
@@ -24,21 +24,28 @@
 /// To use the `EvmRelayer`, create an instance using the `new` method, providing the necessary
 /// components. Then, call the appropriate methods to process transactions, sign data, and
 /// manage the relayer's state.
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use crate::{
-    constants::EVM_SMALLEST_UNIT_NAME,
+    constants::{
+        DEFAULT_EVM_GAS_LIMIT_ESTIMATION, DEFAULT_EVM_MIN_BALANCE, DEFAULT_GAS_LIMIT,
+        DEFAULT_TRANSACTION_SPEED, EVM_SMALLEST_UNIT_NAME, FORWARDER_EXECUTE_GAS_OVERHEAD,
+        MAXIMUM_NONCE_GAP_REPAIRS, NONCE_GAP_REPAIR_MIN_AGE_SECONDS, SWEEP_FEE_RESERVE_MULTIPLIER,
+    },
     domain::{
         relayer::{Relayer, RelayerError},
+        transaction::evm::make_noop,
         BalanceResponse, SignDataRequest, SignDataResponse, SignTransactionExternalResponse,
         SignTransactionRequest, SignTypedDataRequest,
     },
     jobs::{JobProducerTrait, TransactionRequest},
     models::{
         produce_relayer_disabled_payload, DeletePendingTransactionsResponse, EvmNetwork,
-        JsonRpcRequest, JsonRpcResponse, NetworkRepoModel, NetworkRpcRequest, NetworkRpcResult,
-        NetworkTransactionRequest, NetworkType, RelayerRepoModel, RelayerStatus, RepositoryError,
-        RpcErrorCodes, TransactionRepoModel, TransactionStatus,
+        EvmTransactionRequest, ForwardTransactionRequest, JsonRpcId, JsonRpcRequest,
+        JsonRpcResponse, NetworkRepoModel, NetworkRpcRequest, NetworkRpcResult,
+        NetworkTransactionData, NetworkTransactionRequest, NetworkType, NonceHealth,
+        RelayerRepoModel, RelayerStatus, RepositoryError, RpcErrorCodes, TransactionRepoModel,
+        TransactionStatus, TransactionUpdateRequest, U256,
     },
     repositories::{NetworkRepository, RelayerRepository, Repository, TransactionRepository},
     services::{
@@ -46,12 +53,15 @@ use crate::{
         TransactionCounterServiceTrait,
     },
 };
+use alloy::primitives::Address as AlloyAddress;
 use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use eyre::Result;
 use log::{debug, info, warn};
 
 use super::{
-    create_error_response, create_success_response, map_provider_error, EvmTransactionValidator,
+    create_error_response, create_success_response, is_bundler_rpc_method, map_provider_error,
+    verify_forward_request, EvmBundler, EvmTransactionValidator,
 };
 
 #[allow(dead_code)]
@@ -159,6 +169,165 @@ where
         Ok(())
     }
 
+    /// Releases nonces reserved for a batch that could not be stored.
+    ///
+    /// The counter is only rolled back if it still points right after the reservation;
+    /// otherwise the gap is left for the nonce gap repair to fill.
+    async fn release_reserved_nonces(&self, first_nonce: u64, count: u64) {
+        match self
+            .transaction_counter_service
+            .compare_and_set(first_nonce + count, first_nonce)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => warn!(
+                "Could not release nonces {} to {} for relayer {}",
+                first_nonce,
+                first_nonce + count - 1,
+                self.relayer.id
+            ),
+            Err(e) => warn!(
+                "Failed to release reserved nonces for relayer {}: {}",
+                self.relayer.id, e
+            ),
+        }
+    }
+
+    /// Analyzes the relayer's nonces.
+    ///
+    /// A gap is a nonce between the node's pending nonce and the next nonce the relayer
+    /// will assign that is not held by any unconfirmed transaction, e.g. because the
+    /// transaction holding it was deleted or failed after the nonce was consumed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `NonceHealth` together with the unconfirmed transactions
+    /// holding a nonce, or a `RelayerError` if the operation fails.
+    async fn analyze_nonces(
+        &self,
+    ) -> Result<(NonceHealth, Vec<TransactionRepoModel>), RelayerError> {
+        let on_chain_nonce = self
+            .provider
+            .get_transaction_count(&self.relayer.address)
+            .await
+            .map_err(|e| RelayerError::ProviderError(format!("Failed to get nonce: {}", e)))?;
+        let pending_nonce = self
+            .provider
+            .get_pending_transaction_count(&self.relayer.address)
+            .await
+            .map_err(|e| {
+                RelayerError::ProviderError(format!("Failed to get pending nonce: {}", e))
+            })?
+            .max(on_chain_nonce);
+        let next_nonce = self
+            .transaction_counter_service
+            .get()
+            .await?
+            .unwrap_or(on_chain_nonce);
+
+        let unconfirmed_statuses = [
+            TransactionStatus::Pending,
+            TransactionStatus::Sent,
+            TransactionStatus::Submitted,
+            TransactionStatus::Mined,
+        ];
+        let transactions: Vec<TransactionRepoModel> = self
+            .transaction_repository
+            .find_by_status(&self.relayer.id, &unconfirmed_statuses[..])
+            .await
+            .map_err(RelayerError::from)?
+            .into_iter()
+            .filter(|tx| {
+                tx.network_data
+                    .get_evm_transaction_data()
+                    .ok()
+                    .and_then(|evm_data| evm_data.nonce)
+                    .is_some_and(|nonce| nonce >= on_chain_nonce)
+            })
+            .collect();
+
+        let mut assigned_nonces: Vec<u64> = transactions
+            .iter()
+            .filter_map(|tx| tx.network_data.get_evm_transaction_data().ok()?.nonce)
+            .collect();
+        assigned_nonces.sort_unstable();
+        assigned_nonces.dedup();
+
+        let gaps: Vec<u64> = (pending_nonce..next_nonce)
+            .filter(|nonce| assigned_nonces.binary_search(nonce).is_err())
+            .collect();
+
+        let health = NonceHealth {
+            on_chain_nonce,
+            pending_nonce,
+            next_nonce,
+            assigned_nonces,
+            healthy: gaps.is_empty(),
+            gaps,
+        };
+
+        Ok((health, transactions))
+    }
+
+    /// Creates a NOOP self-transfer holding the given nonce and queues it for submission.
+    ///
+    /// # Arguments
+    ///
+    /// * `network_model` - The network the relayer operates on.
+    /// * `nonce` - The nonce to fill.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the NOOP `TransactionRepoModel` or a `RelayerError`.
+    async fn fill_nonce_gap(
+        &self,
+        network_model: &NetworkRepoModel,
+        nonce: u64,
+    ) -> Result<TransactionRepoModel, RelayerError> {
+        let request = NetworkTransactionRequest::Evm(EvmTransactionRequest {
+            to: Some(self.relayer.address.clone()),
+            value: U256::ZERO,
+            data: None,
+            gas_limit: None,
+            gas_price: None,
+            speed: Some(DEFAULT_TRANSACTION_SPEED),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        });
+
+        let mut transaction =
+            TransactionRepoModel::try_from((&request, &self.relayer, network_model))?;
+        if let NetworkTransactionData::Evm(evm_data) = &mut transaction.network_data {
+            make_noop(evm_data, &self.network, Some(&self.provider))
+                .await
+                .map_err(|e| RelayerError::ProviderError(e.to_string()))?;
+            evm_data.nonce = Some(nonce);
+        }
+        transaction.noop_count = Some(1);
+
+        self.transaction_repository
+            .create(transaction.clone())
+            .await
+            .map_err(|e| RepositoryError::TransactionFailure(e.to_string()))?;
+
+        self.job_producer
+            .produce_transaction_request_job(
+                TransactionRequest::new(transaction.id.clone(), transaction.relayer_id.clone()),
+                None,
+            )
+            .await?;
+
+        Ok(transaction)
+    }
+
     /// Validates the RPC connection to the blockchain provider.
     ///
     /// # Returns
@@ -173,6 +342,51 @@ where
         Ok(())
     }
 
+    /// Serves an ERC-4337 bundler method, available when the relayer has an entry point policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The JSON-RPC request ID.
+    /// * `method` - The bundler method.
+    /// * `params` - The request parameters.
+    ///
+    /// # Returns
+    ///
+    /// The JSON-RPC response of the bundler.
+    async fn handle_bundler_rpc(
+        &self,
+        id: Option<JsonRpcId>,
+        method: &str,
+        params: serde_json::Value,
+    ) -> JsonRpcResponse<NetworkRpcResult> {
+        let entry_point = self
+            .relayer
+            .policies
+            .get_evm_policy()
+            .entry_point
+            .and_then(|entry_point| AlloyAddress::from_str(&entry_point).ok());
+        let Some(entry_point) = entry_point else {
+            return create_error_response(
+                id,
+                RpcErrorCodes::METHOD_NOT_FOUND,
+                "Method not found",
+                "Bundler mode is not enabled for this relayer",
+            );
+        };
+
+        EvmBundler::new(
+            &self.relayer,
+            &self.network,
+            entry_point,
+            &self.provider,
+            self.network_repository.as_ref(),
+            self.transaction_repository.as_ref(),
+            self.job_producer.as_ref(),
+        )
+        .handle_request(id, method, params)
+        .await
+    }
+
     /// Initiates transaction cancellation via the job queue system.
     ///
     /// # Arguments
@@ -252,13 +466,187 @@ where
         self.job_producer
             .produce_transaction_request_job(
                 TransactionRequest::new(transaction.id.clone(), transaction.relayer_id.clone()),
-                None,
+                transaction.scheduled_on(),
             )
             .await?;
 
         Ok(transaction)
     }
 
+    /// Processes a batch of transaction requests with contiguous nonces.
+    ///
+    /// All requests are converted before any nonce is reserved, so an invalid request
+    /// leaves the counter untouched. If storing a transaction fails, the transactions
+    /// already stored are removed and the reservation is released when no other
+    /// transaction has taken a nonce in the meantime. If queuing a transaction fails,
+    /// it and the transactions after it are marked as failed and their nonces are
+    /// released the same way; otherwise the nonce gap repair fills them.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_requests` - The network transaction requests to process.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the created `TransactionRepoModel`s or a `RelayerError`.
+    async fn process_transaction_batch(
+        &self,
+        tx_requests: Vec<NetworkTransactionRequest>,
+    ) -> Result<Vec<TransactionRepoModel>, RelayerError> {
+        if tx_requests.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let network_model = self
+            .network_repository
+            .get_by_name(NetworkType::Evm, &self.relayer.network)
+            .await?
+            .ok_or_else(|| {
+                RelayerError::NetworkConfiguration(format!(
+                    "Network {} not found",
+                    self.relayer.network
+                ))
+            })?;
+
+        let mut transactions = tx_requests
+            .iter()
+            .map(|request| TransactionRepoModel::try_from((request, &self.relayer, &network_model)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let count = transactions.len() as u64;
+        let first_nonce = self
+            .transaction_counter_service
+            .get_and_increment_by(count)
+            .await?;
+
+        for (offset, transaction) in transactions.iter_mut().enumerate() {
+            if let NetworkTransactionData::Evm(evm_data) = &mut transaction.network_data {
+                evm_data.nonce = Some(first_nonce + offset as u64);
+            }
+        }
+
+        debug!(
+            "Reserved nonces {} to {} for batch of {} transactions on relayer {}",
+            first_nonce,
+            first_nonce + count - 1,
+            count,
+            self.relayer.id
+        );
+
+        let mut created_ids = Vec::with_capacity(transactions.len());
+        for transaction in &transactions {
+            if let Err(e) = self
+                .transaction_repository
+                .create(transaction.clone())
+                .await
+            {
+                warn!(
+                    "Failed to store batch transaction for relayer {}: {}",
+                    self.relayer.id, e
+                );
+                for id in created_ids {
+                    if let Err(e) = self.transaction_repository.delete_by_id(id).await {
+                        warn!("Failed to remove batch transaction: {}", e);
+                    }
+                }
+                self.release_reserved_nonces(first_nonce, count).await;
+                return Err(RepositoryError::TransactionFailure(e.to_string()).into());
+            }
+            created_ids.push(transaction.id.clone());
+        }
+
+        for (queued, transaction) in transactions.iter().enumerate() {
+            if let Err(e) = self
+                .job_producer
+                .produce_transaction_request_job(
+                    TransactionRequest::new(transaction.id.clone(), transaction.relayer_id.clone()),
+                    None,
+                )
+                .await
+            {
+                warn!(
+                    "Failed to queue batch transaction for relayer {}: {}",
+                    self.relayer.id, e
+                );
+                for unqueued in &transactions[queued..] {
+                    let update = TransactionUpdateRequest {
+                        status: Some(TransactionStatus::Failed),
+                        status_reason: Some("Failed to queue batch transaction".to_string()),
+                        ..Default::default()
+                    };
+                    if let Err(e) = self
+                        .transaction_repository
+                        .partial_update(unqueued.id.clone(), update)
+                        .await
+                    {
+                        warn!("Failed to mark batch transaction as failed: {}", e);
+                    }
+                }
+                let queued = queued as u64;
+                self.release_reserved_nonces(first_nonce + queued, count - queued)
+                    .await;
+                return Err(e.into());
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Relays a forward request through the trusted forwarder of the relayer's policy.
+    ///
+    /// The gas limit is estimated for the `execute` call unless estimation is disabled,
+    /// in which case the request's gas plus the forwarder overhead is used.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The signed forward request.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `TransactionRepoModel` or a `RelayerError`.
+    async fn process_forward_request(
+        &self,
+        request: ForwardTransactionRequest,
+    ) -> Result<TransactionRepoModel, RelayerError> {
+        let policy = self.relayer.policies.get_evm_policy();
+        let forwarder = policy.trusted_forwarder.clone().ok_or_else(|| {
+            RelayerError::NotSupported("Relayer has no trusted forwarder configured".to_string())
+        })?;
+        let forwarder_address = AlloyAddress::from_str(&forwarder).map_err(|e| {
+            RelayerError::PolicyConfigurationError(format!(
+                "Invalid trusted forwarder address: {}",
+                e
+            ))
+        })?;
+
+        let data = verify_forward_request(&self.provider, forwarder_address, &request).await?;
+
+        let gas_limit = if policy
+            .gas_limit_estimation
+            .unwrap_or(DEFAULT_EVM_GAS_LIMIT_ESTIMATION)
+        {
+            None
+        } else {
+            Some(request.request.gas + FORWARDER_EXECUTE_GAS_OVERHEAD)
+        };
+
+        info!(
+            "Relaying forward request from {} through forwarder {} on relayer {}",
+            request.request.from, forwarder, self.relayer.id
+        );
+
+        self.process_transaction_request(NetworkTransactionRequest::Evm(EvmTransactionRequest {
+            to: Some(forwarder),
+            value: request.request.value,
+            data: Some(data.to_string()),
+            gas_limit,
+            speed: Some(request.speed.unwrap_or(DEFAULT_TRANSACTION_SPEED)),
+            valid_until: request.valid_until,
+            ..Default::default()
+        }))
+        .await
+    }
+
     /// Retrieves the balance of the relayer's address.
     ///
     /// # Returns
@@ -329,6 +717,85 @@ where
         })
     }
 
+    /// Compares the on-chain nonces with the nonces held by the relayer's transactions.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `NonceHealth` or a `RelayerError`.
+    async fn get_nonce_health(&self) -> Result<NonceHealth, RelayerError> {
+        let (health, _) = self.analyze_nonces().await?;
+        Ok(health)
+    }
+
+    /// Fills nonce gaps with NOOP self-transfers.
+    ///
+    /// Only gaps below a transaction that was broadcast at least
+    /// `NONCE_GAP_REPAIR_MIN_AGE_SECONDS` ago are filled. Nonces are assigned in increasing
+    /// order before broadcast, so nonces that were just consumed and are about to be stored
+    /// with their transaction are left alone, however long that transaction was queued.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the NOOP transactions created or a `RelayerError`.
+    async fn repair_nonce_gaps(&self) -> Result<Vec<TransactionRepoModel>, RelayerError> {
+        let (health, transactions) = self.analyze_nonces().await?;
+        if health.healthy {
+            return Ok(vec![]);
+        }
+
+        let threshold = Utc::now() - Duration::seconds(NONCE_GAP_REPAIR_MIN_AGE_SECONDS);
+        let Some(highest_blocked_nonce) = transactions
+            .iter()
+            .filter(|tx| {
+                tx.sent_at
+                    .as_deref()
+                    .and_then(|sent_at| DateTime::parse_from_rfc3339(sent_at).ok())
+                    .is_some_and(|sent_at| sent_at < threshold)
+            })
+            .filter_map(|tx| tx.network_data.get_evm_transaction_data().ok()?.nonce)
+            .max()
+        else {
+            debug!(
+                "Nonce gaps {:?} of relayer {} are not blocking any transaction yet",
+                health.gaps, self.relayer.id
+            );
+            return Ok(vec![]);
+        };
+
+        let gaps: Vec<u64> = health
+            .gaps
+            .into_iter()
+            .filter(|nonce| *nonce < highest_blocked_nonce)
+            .take(MAXIMUM_NONCE_GAP_REPAIRS)
+            .collect();
+        if gaps.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let network_model = self
+            .network_repository
+            .get_by_name(NetworkType::Evm, &self.relayer.network)
+            .await?
+            .ok_or_else(|| {
+                RelayerError::NetworkConfiguration(format!(
+                    "Network {} not found",
+                    self.relayer.network
+                ))
+            })?;
+
+        info!(
+            "Filling nonce gaps {:?} of relayer {} with NOOP transactions",
+            gaps, self.relayer.id
+        );
+
+        let mut noop_transactions = Vec::with_capacity(gaps.len());
+        for nonce in gaps {
+            noop_transactions.push(self.fill_nonce_gap(&network_model, nonce).await?);
+        }
+
+        Ok(noop_transactions)
+    }
+
     /// Deletes pending transactions.
     ///
     /// # Returns
@@ -471,6 +938,12 @@ where
             crate::models::EvmRpcRequest::RawRpcRequest { method, params } => (method, params),
         };
 
+        if is_bundler_rpc_method(&method) {
+            return Ok(self
+                .handle_bundler_rpc(request.id, &method, params_json)
+                .await);
+        }
+
         // Forward the RPC call to the provider
         match self.provider.raw_request_dyn(&method, params_json).await {
             Ok(result_value) => Ok(create_success_response(request.id, result_value)),
@@ -565,6 +1038,69 @@ where
             "Transaction signing not supported for EVM".to_string(),
         ))
     }
+
+    /// Transfers the relayer's native balance to `destination`.
+    ///
+    /// The transfer keeps the policy minimum balance plus a reserve covering the transfer
+    /// fee, so the transaction can still be resubmitted at a higher gas price.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The address receiving the balance.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the transfer transaction, `None` if nothing is left to transfer,
+    /// or a `RelayerError` on failure.
+    async fn sweep_balance(
+        &self,
+        destination: &str,
+    ) -> Result<Option<TransactionRepoModel>, RelayerError> {
+        let balance = self.provider.get_balance(&self.relayer.address).await?;
+        let gas_price = self.provider.get_gas_price().await?;
+
+        let policy = self.relayer.policies.get_evm_policy();
+        let min_balance = U256::from(policy.min_balance.unwrap_or(DEFAULT_EVM_MIN_BALANCE));
+        let fee_reserve = U256::from(gas_price)
+            * U256::from(DEFAULT_GAS_LIMIT)
+            * U256::from(SWEEP_FEE_RESERVE_MULTIPLIER);
+
+        let value = balance.saturating_sub(min_balance + fee_reserve);
+        if value.is_zero() {
+            info!(
+                "Balance of relayer {} is too low to sweep to {}",
+                self.relayer.id, destination
+            );
+            return Ok(None);
+        }
+
+        let request = NetworkTransactionRequest::Evm(EvmTransactionRequest {
+            to: Some(destination.to_string()),
+            value,
+            data: None,
+            gas_limit: Some(DEFAULT_GAS_LIMIT),
+            gas_price: Some(gas_price),
+            speed: None,
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        });
+
+        let transaction = self.process_transaction_request(request).await?;
+        info!(
+            "Sweeping {} wei from relayer {} to {} in transaction {}",
+            value, self.relayer.id, destination, transaction.id
+        );
+
+        Ok(Some(transaction))
+    }
 }
 
 #[cfg(test)]
@@ -580,6 +1116,7 @@ mod tests {
         repositories::{MockNetworkRepository, MockRelayerRepository, MockTransactionRepository},
         services::{MockEvmProviderTrait, MockTransactionCounterServiceTrait, ProviderError},
     };
+    use alloy::sol_types::{SolCall, SolValue};
     use mockall::predicate::*;
     use std::future::ready;
 
@@ -606,6 +1143,8 @@ mod tests {
             features: vec!["eip1559".to_string()],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -629,6 +1168,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string()]),
             symbol: Some("ETH".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         NetworkRepoModel::new_evm(config)
@@ -650,10 +1191,21 @@ mod tests {
                 gas_price_cap: Some(100000000000), // 100 Gwei
                 eip1559_pricing: Some(true),
                 private_transactions: Some(false),
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
                 gas_limit_estimation: Some(true),
             }),
             network_type: NetworkType::Evm,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -678,18 +1230,302 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_balance() {
-        let (mut provider, relayer_repo, network_repo, tx_repo, job_producer, signer, counter) =
-            setup_mocks();
-        let relayer_model = create_test_relayer();
+    async fn test_get_balance() {
+        let (mut provider, relayer_repo, network_repo, tx_repo, job_producer, signer, counter) =
+            setup_mocks();
+        let relayer_model = create_test_relayer();
+
+        provider
+            .expect_get_balance()
+            .with(eq("0xSender"))
+            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64))))); // 1 ETH
+
+        let relayer = EvmRelayer::new(
+            relayer_model,
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let balance = relayer.get_balance().await.unwrap();
+        assert_eq!(balance.balance, 1000000000000000000u128);
+        assert_eq!(balance.unit, EVM_SMALLEST_UNIT_NAME);
+    }
+
+    #[tokio::test]
+    async fn test_process_transaction_request() {
+        let (
+            provider,
+            relayer_repo,
+            mut network_repo,
+            mut tx_repo,
+            mut job_producer,
+            signer,
+            counter,
+        ) = setup_mocks();
+        let relayer_model = create_test_relayer();
+
+        let network_tx = NetworkTransactionRequest::Evm(crate::models::EvmTransactionRequest {
+            to: Some("0xRecipient".to_string()),
+            value: U256::from(1000000000000000000u64),
+            data: Some("0xData".to_string()),
+            gas_limit: Some(21000),
+            gas_price: Some(20000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            speed: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        });
+
+        network_repo
+            .expect_get_by_name()
+            .with(eq(NetworkType::Evm), eq("mainnet"))
+            .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+
+        tx_repo.expect_create().returning(Ok);
+        job_producer
+            .expect_produce_transaction_request_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let relayer = EvmRelayer::new(
+            relayer_model,
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let result = relayer.process_transaction_request(network_tx).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_process_transaction_request_schedules_delayed_transaction() {
+        let (
+            provider,
+            relayer_repo,
+            mut network_repo,
+            mut tx_repo,
+            mut job_producer,
+            signer,
+            counter,
+        ) = setup_mocks();
+        let execute_after = Utc::now() + Duration::hours(1);
+
+        let network_tx = NetworkTransactionRequest::Evm(crate::models::EvmTransactionRequest {
+            to: Some("0xRecipient".to_string()),
+            gas_limit: Some(21000),
+            gas_price: Some(20000000000),
+            execute_after: Some(execute_after.to_rfc3339()),
+            ..Default::default()
+        });
+
+        network_repo
+            .expect_get_by_name()
+            .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+        tx_repo
+            .expect_create()
+            .withf(|tx| tx.status == TransactionStatus::Pending && tx.execute_after.is_some())
+            .returning(Ok);
+        job_producer
+            .expect_produce_transaction_request_job()
+            .withf(move |_, scheduled_on| *scheduled_on == Some(execute_after.timestamp()))
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let transaction = relayer
+            .process_transaction_request(network_tx)
+            .await
+            .unwrap();
+        assert_eq!(transaction.scheduled_on(), Some(execute_after.timestamp()));
+    }
+
+    fn create_test_forward_request() -> ForwardTransactionRequest {
+        ForwardTransactionRequest {
+            request: crate::models::EvmForwardRequest {
+                from: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
+                to: "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512".to_string(),
+                value: U256::ZERO,
+                gas: 100_000,
+                nonce: U256::from(2u64),
+                data: "0xa9059cbb".to_string(),
+            },
+            signature: format!("0x{}", "11".repeat(65)),
+            speed: None,
+            valid_until: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_forward_request_wraps_execute_call() {
+        let (
+            mut provider,
+            relayer_repo,
+            mut network_repo,
+            mut tx_repo,
+            mut job_producer,
+            signer,
+            counter,
+        ) = setup_mocks();
+        let forwarder = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+
+        provider.expect_call_contract().returning(|request, _| {
+            let input = request.input.input().cloned().unwrap_or_default();
+            let output = if input.starts_with(&crate::domain::IForwarder::getNonceCall::SELECTOR) {
+                U256::from(2u64).abi_encode()
+            } else {
+                true.abi_encode()
+            };
+            Box::pin(ready(Ok(alloy::primitives::Bytes::from(output))))
+        });
+        network_repo
+            .expect_get_by_name()
+            .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+        tx_repo.expect_create().returning(Ok);
+        job_producer
+            .expect_produce_transaction_request_job()
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let mut relayer_model = create_test_relayer();
+        relayer_model.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+            trusted_forwarder: Some(forwarder.to_string()),
+            ..Default::default()
+        });
+        let relayer = EvmRelayer::new(
+            relayer_model,
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let transaction = relayer
+            .process_forward_request(create_test_forward_request())
+            .await
+            .unwrap();
+
+        let evm_data = transaction.network_data.get_evm_transaction_data().unwrap();
+        assert_eq!(evm_data.to, Some(forwarder.to_string()));
+        assert!(evm_data
+            .data
+            .unwrap()
+            .starts_with(&alloy::hex::encode_prefixed(
+                crate::domain::IForwarder::executeCall::SELECTOR
+            )));
+    }
+
+    #[tokio::test]
+    async fn test_process_forward_request_requires_trusted_forwarder() {
+        let (provider, relayer_repo, network_repo, tx_repo, job_producer, signer, counter) =
+            setup_mocks();
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let result = relayer
+            .process_forward_request(create_test_forward_request())
+            .await;
+        assert!(matches!(result, Err(RelayerError::NotSupported(_))));
+    }
+
+    fn create_test_batch_request() -> NetworkTransactionRequest {
+        NetworkTransactionRequest::Evm(crate::models::EvmTransactionRequest {
+            to: Some("0xRecipient".to_string()),
+            value: U256::from(1u64),
+            data: None,
+            gas_limit: Some(21000),
+            gas_price: Some(20000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            speed: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_process_transaction_batch_assigns_contiguous_nonces() {
+        let (
+            provider,
+            relayer_repo,
+            mut network_repo,
+            mut tx_repo,
+            mut job_producer,
+            signer,
+            mut counter,
+        ) = setup_mocks();
 
-        provider
-            .expect_get_balance()
-            .with(eq("0xSender"))
-            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64))))); // 1 ETH
+        network_repo
+            .expect_get_by_name()
+            .with(eq(NetworkType::Evm), eq("mainnet"))
+            .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+        counter
+            .expect_get_and_increment_by()
+            .with(eq(3u64))
+            .times(1)
+            .returning(|_| Box::pin(ready(Ok(10))));
+        counter.expect_get_and_increment().never();
+        tx_repo.expect_create().times(3).returning(Ok);
+        job_producer
+            .expect_produce_transaction_request_job()
+            .times(3)
+            .returning(|_, _| Box::pin(ready(Ok(()))));
 
         let relayer = EvmRelayer::new(
-            relayer_model,
+            create_test_relayer(),
             signer,
             provider,
             create_test_evm_network(),
@@ -701,13 +1537,24 @@ mod tests {
         )
         .unwrap();
 
-        let balance = relayer.get_balance().await.unwrap();
-        assert_eq!(balance.balance, 1000000000000000000u128);
-        assert_eq!(balance.unit, EVM_SMALLEST_UNIT_NAME);
+        let transactions = relayer
+            .process_transaction_batch(vec![
+                create_test_batch_request(),
+                create_test_batch_request(),
+                create_test_batch_request(),
+            ])
+            .await
+            .unwrap();
+
+        let nonces: Vec<_> = transactions
+            .iter()
+            .map(|tx| tx.network_data.get_evm_transaction_data().unwrap().nonce)
+            .collect();
+        assert_eq!(nonces, vec![Some(10), Some(11), Some(12)]);
     }
 
     #[tokio::test]
-    async fn test_process_transaction_request() {
+    async fn test_process_transaction_batch_rolls_back_on_store_failure() {
         let (
             provider,
             relayer_repo,
@@ -715,34 +1562,114 @@ mod tests {
             mut tx_repo,
             mut job_producer,
             signer,
-            counter,
+            mut counter,
         ) = setup_mocks();
-        let relayer_model = create_test_relayer();
 
-        let network_tx = NetworkTransactionRequest::Evm(crate::models::EvmTransactionRequest {
-            to: Some("0xRecipient".to_string()),
-            value: U256::from(1000000000000000000u64),
-            data: Some("0xData".to_string()),
-            gas_limit: Some(21000),
-            gas_price: Some(20000000000),
-            max_fee_per_gas: None,
-            max_priority_fee_per_gas: None,
-            speed: None,
-            valid_until: None,
+        network_repo
+            .expect_get_by_name()
+            .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+        counter
+            .expect_get_and_increment_by()
+            .returning(|_| Box::pin(ready(Ok(10))));
+        counter
+            .expect_compare_and_set()
+            .with(eq(12u64), eq(10u64))
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(true))));
+
+        let mut created = 0;
+        tx_repo.expect_create().returning(move |tx| {
+            created += 1;
+            if created == 2 {
+                Err(RepositoryError::ConnectionError(
+                    "Redis unavailable".to_string(),
+                ))
+            } else {
+                Ok(tx)
+            }
         });
+        tx_repo.expect_delete_by_id().times(1).returning(|_| Ok(()));
+        job_producer
+            .expect_produce_transaction_request_job()
+            .never();
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let result = relayer
+            .process_transaction_batch(vec![
+                create_test_batch_request(),
+                create_test_batch_request(),
+            ])
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_process_transaction_batch_fails_unqueued_transactions() {
+        let (
+            provider,
+            relayer_repo,
+            mut network_repo,
+            mut tx_repo,
+            mut job_producer,
+            signer,
+            mut counter,
+        ) = setup_mocks();
 
         network_repo
             .expect_get_by_name()
-            .with(eq(NetworkType::Evm), eq("mainnet"))
             .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+        counter
+            .expect_get_and_increment_by()
+            .returning(|_| Box::pin(ready(Ok(10))));
+        counter
+            .expect_compare_and_set()
+            .with(eq(13u64), eq(11u64))
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(true))));
+        tx_repo.expect_create().times(3).returning(Ok);
+        tx_repo.expect_delete_by_id().never();
+        tx_repo
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Failed))
+            .times(2)
+            .returning(|id, _| {
+                Ok(TransactionRepoModel {
+                    id,
+                    status: TransactionStatus::Failed,
+                    ..TransactionRepoModel::default()
+                })
+            });
 
-        tx_repo.expect_create().returning(Ok);
+        let mut queued = 0;
         job_producer
             .expect_produce_transaction_request_job()
-            .returning(|_, _| Box::pin(ready(Ok(()))));
+            .times(2)
+            .returning(move |_, _| {
+                queued += 1;
+                if queued == 2 {
+                    Box::pin(ready(Err(crate::jobs::JobProducerError::QueueError(
+                        "Queue unavailable".to_string(),
+                    ))))
+                } else {
+                    Box::pin(ready(Ok(())))
+                }
+            });
 
         let relayer = EvmRelayer::new(
-            relayer_model,
+            create_test_relayer(),
             signer,
             provider,
             create_test_evm_network(),
@@ -754,8 +1681,15 @@ mod tests {
         )
         .unwrap();
 
-        let result = relayer.process_transaction_request(network_tx).await;
-        assert!(result.is_ok());
+        let result = relayer
+            .process_transaction_batch(vec![
+                create_test_batch_request(),
+                create_test_batch_request(),
+                create_test_batch_request(),
+            ])
+            .await;
+
+        assert!(result.is_err());
     }
 
     #[tokio::test]
@@ -1041,6 +1975,245 @@ mod tests {
         }
     }
 
+    fn create_nonce_transaction(
+        id: &str,
+        status: TransactionStatus,
+        nonce: u64,
+        sent_at: Option<DateTime<Utc>>,
+    ) -> TransactionRepoModel {
+        TransactionRepoModel {
+            id: id.to_string(),
+            relayer_id: "test-relayer-id".to_string(),
+            status,
+            sent_at: sent_at.map(|sent_at| sent_at.to_rfc3339()),
+            network_data: NetworkTransactionData::Evm(crate::models::EvmTransactionData {
+                nonce: Some(nonce),
+                ..Default::default()
+            }),
+            ..TransactionRepoModel::default()
+        }
+    }
+
+    fn setup_nonce_mocks(
+        provider: &mut MockEvmProviderTrait,
+        counter: &mut MockTransactionCounterServiceTrait,
+        tx_repo: &mut MockTransactionRepository,
+        nonces: (u64, u64, u64),
+        transactions: Vec<TransactionRepoModel>,
+    ) {
+        let (on_chain_nonce, pending_nonce, next_nonce) = nonces;
+        provider
+            .expect_get_transaction_count()
+            .returning(move |_| Box::pin(ready(Ok(on_chain_nonce))));
+        provider
+            .expect_get_pending_transaction_count()
+            .returning(move |_| Box::pin(ready(Ok(pending_nonce))));
+        counter
+            .expect_get()
+            .returning(move || Box::pin(ready(Ok(Some(next_nonce)))));
+        tx_repo
+            .expect_find_by_status()
+            .withf(|relayer_id, statuses| {
+                relayer_id == "test-relayer-id"
+                    && statuses
+                        == [
+                            TransactionStatus::Pending,
+                            TransactionStatus::Sent,
+                            TransactionStatus::Submitted,
+                            TransactionStatus::Mined,
+                        ]
+            })
+            .returning(move |_, _| Ok(transactions.clone()));
+    }
+
+    #[tokio::test]
+    async fn test_get_nonce_health_detects_gaps() {
+        let (
+            mut provider,
+            relayer_repo,
+            network_repo,
+            mut tx_repo,
+            job_producer,
+            signer,
+            mut counter,
+        ) = setup_mocks();
+        let now = Utc::now();
+        setup_nonce_mocks(
+            &mut provider,
+            &mut counter,
+            &mut tx_repo,
+            (10, 11, 15),
+            vec![
+                create_nonce_transaction("mined", TransactionStatus::Mined, 9, Some(now)),
+                create_nonce_transaction("submitted", TransactionStatus::Submitted, 12, Some(now)),
+                create_nonce_transaction("pending", TransactionStatus::Pending, 13, None),
+            ],
+        );
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let health = relayer.get_nonce_health().await.unwrap();
+        assert_eq!(health.on_chain_nonce, 10);
+        assert_eq!(health.pending_nonce, 11);
+        assert_eq!(health.next_nonce, 15);
+        assert_eq!(health.assigned_nonces, vec![12, 13]);
+        assert_eq!(health.gaps, vec![11, 14]);
+        assert!(!health.healthy);
+    }
+
+    #[tokio::test]
+    async fn test_repair_nonce_gaps_fills_blocking_gaps() {
+        let (
+            mut provider,
+            relayer_repo,
+            mut network_repo,
+            mut tx_repo,
+            mut job_producer,
+            signer,
+            mut counter,
+        ) = setup_mocks();
+        let now = Utc::now();
+        setup_nonce_mocks(
+            &mut provider,
+            &mut counter,
+            &mut tx_repo,
+            (10, 10, 15),
+            vec![
+                create_nonce_transaction(
+                    "stuck",
+                    TransactionStatus::Submitted,
+                    12,
+                    Some(now - Duration::minutes(10)),
+                ),
+                create_nonce_transaction("recent", TransactionStatus::Pending, 14, None),
+            ],
+        );
+        network_repo
+            .expect_get_by_name()
+            .with(eq(NetworkType::Evm), eq("mainnet"))
+            .returning(|_, _| Ok(Some(create_test_network_repo_model())));
+        tx_repo.expect_create().times(2).returning(Ok);
+        job_producer
+            .expect_produce_transaction_request_job()
+            .times(2)
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let noops = relayer.repair_nonce_gaps().await.unwrap();
+        assert_eq!(noops.len(), 2);
+        for (noop, nonce) in noops.iter().zip([10, 11]) {
+            let evm_data = noop.network_data.get_evm_transaction_data().unwrap();
+            assert_eq!(evm_data.nonce, Some(nonce));
+            assert_eq!(evm_data.to.as_deref(), Some("0xSender"));
+            assert!(crate::domain::transaction::evm::is_noop(&evm_data));
+            assert_eq!(noop.noop_count, Some(1));
+            assert_eq!(noop.status, TransactionStatus::Pending);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repair_nonce_gaps_waits_for_recent_transactions() {
+        let (
+            mut provider,
+            relayer_repo,
+            network_repo,
+            mut tx_repo,
+            job_producer,
+            signer,
+            mut counter,
+        ) = setup_mocks();
+        setup_nonce_mocks(
+            &mut provider,
+            &mut counter,
+            &mut tx_repo,
+            (10, 10, 12),
+            vec![create_nonce_transaction(
+                "recent",
+                TransactionStatus::Submitted,
+                11,
+                Some(Utc::now()),
+            )],
+        );
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let noops = relayer.repair_nonce_gaps().await.unwrap();
+        assert!(noops.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_repair_nonce_gaps_ignores_unsent_transactions() {
+        let (
+            mut provider,
+            relayer_repo,
+            network_repo,
+            mut tx_repo,
+            job_producer,
+            signer,
+            mut counter,
+        ) = setup_mocks();
+        // Queued for a long time, but its nonce was only just assigned
+        let mut queued = create_nonce_transaction("queued", TransactionStatus::Sent, 11, None);
+        queued.created_at = (Utc::now() - Duration::hours(1)).to_rfc3339();
+        setup_nonce_mocks(
+            &mut provider,
+            &mut counter,
+            &mut tx_repo,
+            (10, 10, 12),
+            vec![queued],
+        );
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let noops = relayer.repair_nonce_gaps().await.unwrap();
+        assert!(noops.is_empty());
+    }
+
     #[tokio::test]
     async fn test_get_status_provider_nonce_error() {
         let (mut provider, relayer_repo, network_repo, tx_repo, job_producer, signer, counter) =
@@ -1722,6 +2895,42 @@ mod tests {
         assert_eq!(error.code, -32603); // RpcErrorCodes::INTERNAL_ERROR
     }
 
+    #[tokio::test]
+    async fn test_rpc_bundler_method_requires_entry_point() {
+        let (provider, relayer_repo, network_repo, tx_repo, job_producer, signer, counter) =
+            setup_mocks();
+
+        let relayer = EvmRelayer::new(
+            create_test_relayer(),
+            signer,
+            provider,
+            create_test_evm_network(),
+            Arc::new(relayer_repo),
+            Arc::new(network_repo),
+            Arc::new(tx_repo),
+            Arc::new(counter),
+            Arc::new(job_producer),
+        )
+        .unwrap();
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            params: NetworkRpcRequest::Evm(EvmRpcRequest::RawRpcRequest {
+                method: "eth_supportedEntryPoints".to_string(),
+                params: serde_json::json!([]),
+            }),
+            id: Some(JsonRpcId::Number(1)),
+        };
+
+        let response = relayer.rpc(request).await.unwrap();
+        let error = response.error.unwrap();
+        assert_eq!(error.code, RpcErrorCodes::METHOD_NOT_FOUND);
+        assert_eq!(
+            error.description,
+            "Bundler mode is not enabled for this relayer"
+        );
+    }
+
     #[tokio::test]
     async fn test_rpc_invalid_params() {
         let (mut provider, relayer_repo, network_repo, tx_repo, job_producer, signer, counter) =
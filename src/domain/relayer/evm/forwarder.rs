@@ -0,0 +1,261 @@
+//! ERC-2771 trusted forwarder bindings and forward request verification.
+//!
+//! Relayers with a `trusted_forwarder` policy relay requests signed by end users. The
+//! forwarder contract is asked to verify the request before it is wrapped into a call to
+//! its `execute` function, so requests it would reject never reach the network.
+use alloy::{
+    primitives::{Address, Bytes, U256},
+    rpc::types::{BlockNumberOrTag, TransactionInput, TransactionRequest as CallRequest},
+    sol,
+    sol_types::SolCall,
+};
+use std::str::FromStr;
+
+use crate::{
+    domain::transaction::evm::decode_revert_reason,
+    models::{EvmForwardRequest, ForwardTransactionRequest, RelayerError},
+    services::{EvmProviderTrait, ProviderError},
+};
+
+sol! {
+    #[derive(Debug, PartialEq)]
+    struct ForwardRequest {
+        address from;
+        address to;
+        uint256 value;
+        uint256 gas;
+        uint256 nonce;
+        bytes data;
+    }
+
+    interface IForwarder {
+        function getNonce(address from) external view returns (uint256);
+        function verify(ForwardRequest calldata req, bytes calldata signature) external view returns (bool);
+        function execute(ForwardRequest calldata req, bytes calldata signature) external payable returns (bool, bytes memory);
+    }
+}
+
+impl TryFrom<&EvmForwardRequest> for ForwardRequest {
+    type Error = RelayerError;
+
+    fn try_from(request: &EvmForwardRequest) -> Result<Self, Self::Error> {
+        let address = |value: &str| {
+            Address::from_str(value)
+                .map_err(|e| RelayerError::ValidationError(format!("Invalid address: {}", e)))
+        };
+        Ok(Self {
+            from: address(&request.from)?,
+            to: address(&request.to)?,
+            value: request.value,
+            gas: U256::from(request.gas),
+            nonce: request.nonce,
+            data: Bytes::from_str(&request.data)
+                .map_err(|e| RelayerError::ValidationError(format!("Invalid data: {}", e)))?,
+        })
+    }
+}
+
+/// Encodes the forwarder `execute` call relaying `request`.
+pub fn encode_forwarder_execute(request: ForwardRequest, signature: Bytes) -> Bytes {
+    IForwarder::executeCall {
+        req: request,
+        signature,
+    }
+    .abi_encode()
+    .into()
+}
+
+/// Checks a forward request against the forwarder's nonce for the signer and its
+/// signature verification.
+///
+/// # Arguments
+///
+/// * `provider` - The provider used to call the forwarder.
+/// * `forwarder` - The trusted forwarder of the relayer.
+/// * `request` - The signed forward request.
+///
+/// # Returns
+///
+/// A `Result` containing the `execute` call data relaying the request, or a `RelayerError`
+/// if the forwarder would reject it.
+pub async fn verify_forward_request<P: EvmProviderTrait>(
+    provider: &P,
+    forwarder: Address,
+    request: &ForwardTransactionRequest,
+) -> Result<Bytes, RelayerError> {
+    let forward_request = ForwardRequest::try_from(&request.request)?;
+    let signature = Bytes::from_str(&request.signature)
+        .map_err(|e| RelayerError::ValidationError(format!("Invalid signature: {}", e)))?;
+
+    let nonce = call_forwarder(
+        provider,
+        forwarder,
+        IForwarder::getNonceCall {
+            from: forward_request.from,
+        },
+    )
+    .await?;
+    if nonce != forward_request.nonce {
+        return Err(RelayerError::ValidationError(format!(
+            "Forward request nonce {} does not match the forwarder nonce {} of {}",
+            forward_request.nonce, nonce, forward_request.from
+        )));
+    }
+
+    let valid = call_forwarder(
+        provider,
+        forwarder,
+        IForwarder::verifyCall {
+            req: forward_request.clone(),
+            signature: signature.clone(),
+        },
+    )
+    .await?;
+    if !valid {
+        return Err(RelayerError::ValidationError(
+            "Forward request signature does not match the request signer".to_string(),
+        ));
+    }
+
+    Ok(encode_forwarder_execute(forward_request, signature))
+}
+
+/// Calls a view function of the forwarder, treating reverts as rejected requests.
+async fn call_forwarder<P: EvmProviderTrait, C: SolCall>(
+    provider: &P,
+    forwarder: Address,
+    call: C,
+) -> Result<C::Return, RelayerError> {
+    let request = CallRequest {
+        to: Some(forwarder.into()),
+        input: TransactionInput::new(call.abi_encode().into()),
+        ..Default::default()
+    };
+
+    let output = provider
+        .call_contract(&request, BlockNumberOrTag::Latest)
+        .await
+        .map_err(|e| match e {
+            ProviderError::Reverted { message, data } => RelayerError::ValidationError(format!(
+                "Forwarder rejected the request: {}",
+                data.map(|data| decode_revert_reason(&data))
+                    .unwrap_or(message)
+            )),
+            other => other.into(),
+        })?;
+
+    C::abi_decode_returns(&output)
+        .map_err(|e| RelayerError::ProviderError(format!("Invalid forwarder response: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::MockEvmProviderTrait;
+    use alloy::sol_types::SolValue;
+
+    const FORWARDER: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+
+    fn create_request(nonce: u64) -> ForwardTransactionRequest {
+        ForwardTransactionRequest {
+            request: EvmForwardRequest {
+                from: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string(),
+                to: "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512".to_string(),
+                value: U256::ZERO,
+                gas: 100_000,
+                nonce: U256::from(nonce),
+                data: "0xa9059cbb".to_string(),
+            },
+            signature: format!("0x{}", "11".repeat(65)),
+            speed: None,
+            valid_until: None,
+        }
+    }
+
+    /// Mocks the forwarder answering `getNonce` with `nonce` and `verify` with `valid`.
+    fn mock_forwarder(nonce: u64, valid: bool) -> MockEvmProviderTrait {
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_call_contract()
+            .returning(move |request, _| {
+                let input = request.input.input().cloned().unwrap_or_default();
+                let output = if input.starts_with(&IForwarder::getNonceCall::SELECTOR) {
+                    U256::from(nonce).abi_encode()
+                } else {
+                    valid.abi_encode()
+                };
+                Box::pin(async move { Ok(Bytes::from(output)) })
+            });
+        provider
+    }
+
+    #[tokio::test]
+    async fn test_verify_forward_request() {
+        let provider = mock_forwarder(3, true);
+        let request = create_request(3);
+
+        let data =
+            verify_forward_request(&provider, Address::from_str(FORWARDER).unwrap(), &request)
+                .await
+                .unwrap();
+
+        let call = IForwarder::executeCall::abi_decode(&data).unwrap();
+        assert_eq!(
+            call.req,
+            ForwardRequest::try_from(&request.request).unwrap()
+        );
+        assert_eq!(call.signature.len(), 65);
+    }
+
+    #[tokio::test]
+    async fn test_verify_forward_request_rejects_stale_nonce() {
+        let provider = mock_forwarder(4, true);
+
+        let result = verify_forward_request(
+            &provider,
+            Address::from_str(FORWARDER).unwrap(),
+            &create_request(3),
+        )
+        .await;
+
+        assert!(matches!(result, Err(RelayerError::ValidationError(msg)) if msg.contains("nonce")));
+    }
+
+    #[tokio::test]
+    async fn test_verify_forward_request_rejects_invalid_signature() {
+        let provider = mock_forwarder(3, false);
+
+        let result = verify_forward_request(
+            &provider,
+            Address::from_str(FORWARDER).unwrap(),
+            &create_request(3),
+        )
+        .await;
+
+        assert!(
+            matches!(result, Err(RelayerError::ValidationError(msg)) if msg.contains("signature"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_forward_request_maps_reverts() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider.expect_call_contract().returning(|_, _| {
+            Box::pin(async {
+                Err(ProviderError::Reverted {
+                    message: "ECDSA: invalid signature".to_string(),
+                    data: None,
+                })
+            })
+        });
+
+        let result = verify_forward_request(
+            &provider,
+            Address::from_str(FORWARDER).unwrap(),
+            &create_request(3),
+        )
+        .await;
+
+        assert!(matches!(result, Err(RelayerError::ValidationError(msg)) if msg.contains("ECDSA")));
+    }
+}
@@ -2,10 +2,16 @@
 /// Ethereum Virtual Machine (EVM) based blockchains. It includes
 /// the `evm_relayer` submodule which contains the core logic for
 /// relaying transactions and events between different EVM networks.
+mod bundler;
+mod entry_point;
 mod evm_relayer;
+mod forwarder;
 mod rpc_utils;
 mod validations;
 
+pub use bundler::*;
+pub use entry_point::*;
 pub use evm_relayer::*;
+pub use forwarder::*;
 pub use rpc_utils::*;
 pub use validations::*;
@@ -9,6 +9,7 @@
 //! that share common interfaces for transaction handling and monitoring.
 
 use actix_web::web::ThinData;
+use alloy::dyn_abi::TypedData;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use utoipa::ToSchema;
@@ -19,18 +20,20 @@ use mockall::automock;
 use crate::{
     jobs::JobProducerTrait,
     models::{
-        AppState, DecoratedSignature, DeletePendingTransactionsResponse, EvmNetwork,
-        EvmTransactionDataSignature, JsonRpcRequest, JsonRpcResponse, NetworkRepoModel,
-        NetworkRpcRequest, NetworkRpcResult, NetworkTransactionRequest, NetworkType,
-        NotificationRepoModel, RelayerError, RelayerRepoModel, RelayerStatus, SignerRepoModel,
-        StellarNetwork, TransactionError, TransactionRepoModel,
+        ApiError, AppState, DecoratedSignature, DeletePendingTransactionsResponse, EvmNetwork,
+        EvmTransactionDataSignature, ForwardTransactionRequest, JsonRpcRequest, JsonRpcResponse,
+        NetworkRepoModel, NetworkRpcRequest, NetworkRpcResult, NetworkTransactionRequest,
+        NetworkType, NonceHealth, NotificationRepoModel, RelayerError, RelayerPoolRepoModel,
+        RelayerRepoModel, RelayerStatus, SignerRepoModel, StellarNetwork, TransactionError,
+        TransactionRepoModel,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
     services::{
-        get_network_provider, EvmSignerFactory, StellarSignerFactory, TransactionCounterService,
+        eip712_signing_payload, get_network_provider, EvmSignerFactory, StellarSignerFactory,
+        TransactionCounterService,
     },
 };
 
@@ -38,11 +41,15 @@ use async_trait::async_trait;
 use eyre::Result;
 
 mod evm;
+mod pool;
+mod signer_rotation;
 mod solana;
 mod stellar;
 mod util;
 
 pub use evm::*;
+pub use pool::*;
+pub use signer_rotation::*;
 pub use solana::*;
 pub use stellar::*;
 pub use util::*;
@@ -68,6 +75,43 @@ pub trait Relayer {
         tx_request: NetworkTransactionRequest,
     ) -> Result<TransactionRepoModel, RelayerError>;
 
+    /// Processes a batch of transaction requests as a single unit.
+    ///
+    /// Nonces for the whole batch are reserved at once, so the transactions are
+    /// assigned contiguous nonces in request order. Either every transaction is
+    /// created, or none is. Transactions that cannot be queued are marked as failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_requests` - The validated transaction requests, in submission order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the created `TransactionRepoModel`s in request order,
+    /// or a `RelayerError` on failure.
+    async fn process_transaction_batch(
+        &self,
+        tx_requests: Vec<NetworkTransactionRequest>,
+    ) -> Result<Vec<TransactionRepoModel>, RelayerError>;
+
+    /// Relays an ERC-2771 forward request through the relayer's trusted forwarder.
+    ///
+    /// The forwarder verifies the request signature and nonce before the request is
+    /// wrapped into a transaction calling the forwarder's `execute` function.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The forward request signed by the end user.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the created `TransactionRepoModel` on success, or a
+    /// `RelayerError` on failure.
+    async fn process_forward_request(
+        &self,
+        request: ForwardTransactionRequest,
+    ) -> Result<TransactionRepoModel, RelayerError>;
+
     /// Retrieves the current balance of the relayer.
     ///
     /// # Returns
@@ -164,6 +208,35 @@ pub trait Relayer {
         &self,
         request: &SignTransactionRequest,
     ) -> Result<SignTransactionExternalResponse, RelayerError>;
+
+    /// Transfers the relayer's native balance to another address.
+    ///
+    /// # Arguments
+    ///
+    /// * `destination` - The address receiving the balance.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the transfer `TransactionRepoModel`, `None` if the balance
+    /// is too low to cover the transfer fee, or a `RelayerError` on failure.
+    async fn sweep_balance(
+        &self,
+        destination: &str,
+    ) -> Result<Option<TransactionRepoModel>, RelayerError>;
+
+    /// Compares the on-chain nonces with the nonces held by the relayer's transactions.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `NonceHealth` analysis, or a `RelayerError` on failure.
+    async fn get_nonce_health(&self) -> Result<NonceHealth, RelayerError>;
+
+    /// Fills nonce gaps that block the relayer's transactions with NOOP transactions.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the NOOP transactions created, or a `RelayerError` on failure.
+    async fn repair_nonce_gaps(&self) -> Result<Vec<TransactionRepoModel>, RelayerError>;
 }
 
 /// Solana Relayer Dex Trait
@@ -185,6 +258,21 @@ pub trait SolanaRelayerDexTrait {
 #[allow(dead_code)]
 #[cfg_attr(test, automock)]
 pub trait SolanaRelayerTrait {
+    /// Processes a transaction request.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_request` - The transaction request to be processed.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `TransactionRepoModel` on success, or a
+    /// `RelayerError` on failure.
+    async fn process_transaction_request(
+        &self,
+        tx_request: NetworkTransactionRequest,
+    ) -> Result<TransactionRepoModel, RelayerError>;
+
     /// Retrieves the current balance of the relayer.
     ///
     /// # Returns
@@ -250,13 +338,39 @@ impl<
     ) -> Result<TransactionRepoModel, RelayerError> {
         match self {
             NetworkRelayer::Evm(relayer) => relayer.process_transaction_request(tx_request).await,
-            NetworkRelayer::Solana(_) => solana_not_supported_relayer(),
+            NetworkRelayer::Solana(relayer) => {
+                relayer.process_transaction_request(tx_request).await
+            }
             NetworkRelayer::Stellar(relayer) => {
                 relayer.process_transaction_request(tx_request).await
             }
         }
     }
 
+    async fn process_transaction_batch(
+        &self,
+        tx_requests: Vec<NetworkTransactionRequest>,
+    ) -> Result<Vec<TransactionRepoModel>, RelayerError> {
+        match self {
+            NetworkRelayer::Evm(relayer) => relayer.process_transaction_batch(tx_requests).await,
+            NetworkRelayer::Solana(_) => solana_not_supported_relayer(),
+            NetworkRelayer::Stellar(relayer) => {
+                relayer.process_transaction_batch(tx_requests).await
+            }
+        }
+    }
+
+    async fn process_forward_request(
+        &self,
+        request: ForwardTransactionRequest,
+    ) -> Result<TransactionRepoModel, RelayerError> {
+        match self {
+            NetworkRelayer::Evm(relayer) => relayer.process_forward_request(request).await,
+            NetworkRelayer::Solana(_) => solana_not_supported_relayer(),
+            NetworkRelayer::Stellar(relayer) => relayer.process_forward_request(request).await,
+        }
+    }
+
     async fn get_balance(&self) -> Result<BalanceResponse, RelayerError> {
         match self {
             NetworkRelayer::Evm(relayer) => relayer.get_balance().await,
@@ -343,6 +457,33 @@ impl<
             NetworkRelayer::Stellar(relayer) => relayer.sign_transaction(request).await,
         }
     }
+
+    async fn sweep_balance(
+        &self,
+        destination: &str,
+    ) -> Result<Option<TransactionRepoModel>, RelayerError> {
+        match self {
+            NetworkRelayer::Evm(relayer) => relayer.sweep_balance(destination).await,
+            NetworkRelayer::Solana(_) => solana_not_supported_relayer(),
+            NetworkRelayer::Stellar(relayer) => relayer.sweep_balance(destination).await,
+        }
+    }
+
+    async fn get_nonce_health(&self) -> Result<NonceHealth, RelayerError> {
+        match self {
+            NetworkRelayer::Evm(relayer) => relayer.get_nonce_health().await,
+            NetworkRelayer::Solana(_) => solana_not_supported_relayer(),
+            NetworkRelayer::Stellar(relayer) => relayer.get_nonce_health().await,
+        }
+    }
+
+    async fn repair_nonce_gaps(&self) -> Result<Vec<TransactionRepoModel>, RelayerError> {
+        match self {
+            NetworkRelayer::Evm(relayer) => relayer.repair_nonce_gaps().await,
+            NetworkRelayer::Solana(_) => solana_not_supported_relayer(),
+            NetworkRelayer::Stellar(relayer) => relayer.repair_nonce_gaps().await,
+        }
+    }
 }
 
 #[async_trait]
@@ -355,12 +496,14 @@ pub trait RelayerFactoryTrait<
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 >
 {
     async fn create_relayer(
         relayer: RelayerRepoModel,
         signer: SignerRepoModel,
-        state: &ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<NetworkRelayer<J, TR, RR, NR, TCR>, RelayerError>;
 }
 
@@ -376,12 +519,14 @@ impl<
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
-    > RelayerFactoryTrait<J, RR, TR, NR, NFR, SR, TCR, PR> for RelayerFactory
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+    > RelayerFactoryTrait<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR> for RelayerFactory
 {
     async fn create_relayer(
         relayer: RelayerRepoModel,
         signer: SignerRepoModel,
-        state: &ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<NetworkRelayer<J, TR, RR, NR, TCR>, RelayerError> {
         match relayer.network_type {
             NetworkType::Evm => {
@@ -506,10 +651,31 @@ pub enum SignDataResponse {
     Solana(SignDataResponseSolana),
 }
 
+/// Request to sign EIP-712 typed data.
+///
+/// Either the full `typed_data` or both pre-computed hashes must be provided. When
+/// `typed_data` is present, the domain separator and struct hash are computed from it.
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct SignTypedDataRequest {
-    pub domain_separator: String,
-    pub hash_struct_message: String,
+    /// Hex-encoded EIP-712 domain separator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_separator: Option<String>,
+    /// Hex-encoded `hashStruct(message)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_struct_message: Option<String>,
+    /// Typed data in the `eth_signTypedData_v4` format (`types`, `primaryType`, `domain`
+    /// and `message`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub typed_data: Option<TypedData>,
+}
+
+impl SignTypedDataRequest {
+    pub fn validate(&self) -> Result<(), ApiError> {
+        eip712_signing_payload(self)
+            .map(|_| ())
+            .map_err(|e| ApiError::BadRequest(e.to_string()))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -0,0 +1,754 @@
+//! Routing and status of relayer pools.
+//!
+//! Each transaction submitted to a pool is routed to the eligible member with the fewest
+//! pending transactions. A member is eligible when it is neither paused nor disabled, is
+//! not rotating its signer, and holds at least the minimum balance of its policy. Ties
+//! are broken by the order of the members in the pool.
+use std::time::Duration;
+
+use log::{debug, warn};
+use uuid::Uuid;
+
+use crate::{
+    constants::{
+        IDEMPOTENCY_RESERVATION_ATTEMPTS, IDEMPOTENCY_RESERVATION_RETRY_MILLIS,
+        POOL_IDEMPOTENCY_RESERVATION_TTL_SECONDS,
+    },
+    domain::{find_transaction_by_idempotency_key, relayer::Relayer, IdempotencyReservation},
+    jobs::JobProducerTrait,
+    models::{
+        ApiError, NetworkRepoModel, NetworkType, NotificationRepoModel, RelayerPoolMemberStatus,
+        RelayerPoolRepoModel, RelayerPoolStatus, RelayerRepoModel, RelayerStatus, RepositoryError,
+        SignerRepoModel, ThinDataAppState, TransactionRepoModel, TransactionStatus,
+    },
+    repositories::{
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        TransactionCounterTrait, TransactionRepository,
+    },
+};
+
+use super::get_network_relayer_by_model;
+
+/// Transaction statuses that count towards the load of a pool member
+const PENDING_TRANSACTION_STATUSES: [TransactionStatus; 3] = [
+    TransactionStatus::Pending,
+    TransactionStatus::Sent,
+    TransactionStatus::Submitted,
+];
+
+/// Returns the scope under which idempotency keys of a pool are reserved.
+///
+/// Relayer IDs cannot contain a colon, so the scope never matches a relayer.
+fn pool_idempotency_scope(pool_id: &str) -> String {
+    format!("pool:{}", pool_id)
+}
+
+/// Resolves the network shared by the given relayers.
+///
+/// # Arguments
+///
+/// * `relayer_ids` - The IDs of the pool members.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<(String, NetworkType), ApiError>` - The network and network type of the members,
+///   or a `BadRequest` if a relayer does not exist or the members use different networks.
+pub async fn resolve_pool_network<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer_ids: &[String],
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<(String, NetworkType), ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let mut network: Option<(String, NetworkType)> = None;
+
+    for relayer_id in relayer_ids {
+        let relayer = match state.relayer_repository.get_by_id(relayer_id.clone()).await {
+            Ok(relayer) => relayer,
+            Err(RepositoryError::NotFound(_)) => {
+                return Err(ApiError::BadRequest(format!(
+                    "Relayer '{}' does not exist",
+                    relayer_id
+                )))
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        match &network {
+            None => network = Some((relayer.network, relayer.network_type)),
+            Some((name, network_type))
+                if *name != relayer.network || *network_type != relayer.network_type =>
+            {
+                return Err(ApiError::BadRequest(format!(
+                    "Relayer '{}' operates on {} network '{}', but the pool operates on {} network '{}'",
+                    relayer.id, relayer.network_type, relayer.network, network_type, name
+                )));
+            }
+            Some(_) => {}
+        }
+    }
+
+    network.ok_or_else(|| ApiError::BadRequest("Pool must have at least one relayer".to_string()))
+}
+
+/// Returns the members of a pool that may receive transactions, least loaded first.
+///
+/// Members that no longer exist, are paused, disabled, rotating their signer or moved to
+/// another network are skipped. The balance of the members is not checked.
+///
+/// # Arguments
+///
+/// * `pool` - The relayer pool.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<Vec<RelayerRepoModel>, ApiError>` - The eligible members, ordered by pending
+///   transactions and then by their position in the pool.
+pub async fn get_pool_candidates<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool: &RelayerPoolRepoModel,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<Vec<RelayerRepoModel>, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let mut candidates = Vec::with_capacity(pool.relayer_ids.len());
+
+    for relayer_id in &pool.relayer_ids {
+        let relayer = match state.relayer_repository.get_by_id(relayer_id.clone()).await {
+            Ok(relayer) => relayer,
+            Err(RepositoryError::NotFound(_)) => {
+                warn!("Relayer {} of pool {} does not exist", relayer_id, pool.id);
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        if relayer.validate_active_state().is_err()
+            || relayer.validate_no_active_signer_rotation().is_err()
+            || relayer.network != pool.network
+            || relayer.network_type != pool.network_type
+        {
+            debug!("Skipping relayer {} of pool {}", relayer.id, pool.id);
+            continue;
+        }
+
+        let pending_count = state
+            .transaction_repository
+            .find_by_status(&relayer.id, &PENDING_TRANSACTION_STATUSES)
+            .await?
+            .len();
+        candidates.push((pending_count, relayer));
+    }
+
+    // Stable sort, so members with the same load keep the order of the pool
+    candidates.sort_by_key(|(pending_count, _)| *pending_count);
+
+    Ok(candidates.into_iter().map(|(_, relayer)| relayer).collect())
+}
+
+/// Selects the member of a pool that receives the next transaction.
+///
+/// # Arguments
+///
+/// * `pool` - The relayer pool.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<RelayerRepoModel, ApiError>` - The least loaded member that is active, not
+///   rotating its signer and holds the minimum balance, or a `BadRequest` if no member can
+///   accept transactions.
+pub async fn select_pool_relayer<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool: &RelayerPoolRepoModel,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<RelayerRepoModel, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    for relayer in get_pool_candidates(pool, state).await? {
+        let network_relayer = get_network_relayer_by_model(relayer.clone(), state).await?;
+
+        match network_relayer.validate_min_balance().await {
+            Ok(()) => return Ok(relayer),
+            Err(e) => debug!("Skipping relayer {} of pool {}: {}", relayer.id, pool.id, e),
+        }
+    }
+
+    Err(ApiError::BadRequest(format!(
+        "No relayer of pool '{}' can accept transactions",
+        pool.id
+    )))
+}
+
+/// Finds a transaction previously submitted to any member of a pool with the given
+/// idempotency key.
+///
+/// # Arguments
+///
+/// * `pool` - The relayer pool.
+/// * `idempotency_key` - The idempotency key supplied with the submission.
+/// * `window_seconds` - How long a key identifies the original transaction.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<Option<TransactionRepoModel>, ApiError>` - The original transaction, if any.
+pub async fn find_pool_transaction_by_idempotency_key<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool: &RelayerPoolRepoModel,
+    idempotency_key: &str,
+    window_seconds: u64,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<Option<TransactionRepoModel>, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    for relayer_id in &pool.relayer_ids {
+        let existing =
+            find_transaction_by_idempotency_key(relayer_id, idempotency_key, window_seconds, state)
+                .await?;
+        if existing.is_some() {
+            return Ok(existing);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Reserves an idempotency key for a transaction about to be routed through a pool.
+///
+/// The key is reserved for the whole pool, so of concurrent submissions with the same
+/// key only one is routed to a member. The others wait until it finishes and then return
+/// its transaction. Members are searched again after reserving, because a concurrent
+/// submission may have created its transaction and released the key in the meantime.
+///
+/// # Arguments
+///
+/// * `pool` - The relayer pool.
+/// * `idempotency_key` - The idempotency key supplied with the submission.
+/// * `window_seconds` - How long a key identifies the original transaction.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<IdempotencyReservation, ApiError>` - The reservation or the original
+///   transaction, or a `Conflict` if the concurrent submission does not finish in time.
+pub async fn reserve_pool_idempotency_key<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool: &RelayerPoolRepoModel,
+    idempotency_key: &str,
+    window_seconds: u64,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<IdempotencyReservation, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let scope = pool_idempotency_scope(&pool.id);
+    let reservation_id = Uuid::new_v4().to_string();
+
+    for _ in 0..IDEMPOTENCY_RESERVATION_ATTEMPTS {
+        let reserved = state
+            .transaction_repository
+            .reserve_idempotency_key(
+                &scope,
+                idempotency_key,
+                &reservation_id,
+                POOL_IDEMPOTENCY_RESERVATION_TTL_SECONDS,
+            )
+            .await?
+            .is_none();
+
+        let existing =
+            find_pool_transaction_by_idempotency_key(pool, idempotency_key, window_seconds, state)
+                .await?;
+
+        if let Some(transaction) = existing {
+            if reserved {
+                release_pool_idempotency_key(pool, idempotency_key, &reservation_id, state).await;
+            }
+            return Ok(IdempotencyReservation::Existing(Box::new(transaction)));
+        }
+
+        if reserved {
+            return Ok(IdempotencyReservation::Reserved(reservation_id));
+        }
+
+        tokio::time::sleep(Duration::from_millis(IDEMPOTENCY_RESERVATION_RETRY_MILLIS)).await;
+    }
+
+    Err(ApiError::Conflict(
+        "A transaction with this idempotency key is still being submitted".to_string(),
+    ))
+}
+
+/// Releases an idempotency key reserved for a pool.
+///
+/// Once routed, the transaction is found through its member, so the key is released
+/// whether or not the transaction was created. A failure is only logged, as the
+/// reservation expires on its own.
+///
+/// # Arguments
+///
+/// * `pool` - The relayer pool.
+/// * `idempotency_key` - The reserved idempotency key.
+/// * `reservation_id` - The ID the key was reserved under.
+/// * `state` - A reference to the application state.
+pub async fn release_pool_idempotency_key<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool: &RelayerPoolRepoModel,
+    idempotency_key: &str,
+    reservation_id: &str,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    if let Err(e) = state
+        .transaction_repository
+        .release_idempotency_key(
+            &pool_idempotency_scope(&pool.id),
+            idempotency_key,
+            reservation_id,
+        )
+        .await
+    {
+        warn!(
+            "Failed to release idempotency key for pool {}: {}",
+            pool.id, e
+        );
+    }
+}
+
+/// Aggregates the status of the members of a pool.
+///
+/// A member whose status cannot be retrieved is reported with the error instead of
+/// failing the whole request.
+///
+/// # Arguments
+///
+/// * `pool` - The relayer pool.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<RelayerPoolStatus, ApiError>` - The status of the pool.
+pub async fn get_relayer_pool_status<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    pool: &RelayerPoolRepoModel,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<RelayerPoolStatus, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let mut status = RelayerPoolStatus {
+        id: pool.id.clone(),
+        network: pool.network.clone(),
+        active_relayers_count: 0,
+        pending_transactions_count: 0,
+        relayers: Vec::with_capacity(pool.relayer_ids.len()),
+    };
+
+    for relayer_id in &pool.relayer_ids {
+        let relayer_status = match state.relayer_repository.get_by_id(relayer_id.clone()).await {
+            Ok(relayer) => match get_network_relayer_by_model(relayer, state).await {
+                Ok(network_relayer) => network_relayer
+                    .get_status()
+                    .await
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            },
+            Err(e) => Err(e.to_string()),
+        };
+
+        let member_status = match relayer_status {
+            Ok(relayer_status) => {
+                let (pending_transactions_count, paused, system_disabled) = match &relayer_status {
+                    RelayerStatus::Evm {
+                        pending_transactions_count,
+                        paused,
+                        system_disabled,
+                        ..
+                    }
+                    | RelayerStatus::Stellar {
+                        pending_transactions_count,
+                        paused,
+                        system_disabled,
+                        ..
+                    }
+                    | RelayerStatus::Solana {
+                        pending_transactions_count,
+                        paused,
+                        system_disabled,
+                        ..
+                    } => (*pending_transactions_count, *paused, *system_disabled),
+                };
+
+                status.pending_transactions_count += pending_transactions_count;
+                if !paused && !system_disabled {
+                    status.active_relayers_count += 1;
+                }
+
+                RelayerPoolMemberStatus {
+                    relayer_id: relayer_id.clone(),
+                    status: Some(relayer_status),
+                    error: None,
+                }
+            }
+            Err(e) => RelayerPoolMemberStatus {
+                relayer_id: relayer_id.clone(),
+                status: None,
+                error: Some(e),
+            },
+        };
+        status.relayers.push(member_status);
+    }
+
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{PendingTransactionsAction, SignerRotation, SignerRotationStatus},
+        utils::mocks::mockutils::{
+            create_mock_app_state, create_mock_relayer, create_mock_solana_relayer,
+            create_mock_transaction,
+        },
+    };
+    use actix_web::web::ThinData;
+
+    fn create_pool(relayer_ids: &[&str]) -> RelayerPoolRepoModel {
+        RelayerPoolRepoModel {
+            id: "pool-1".to_string(),
+            name: "Pool".to_string(),
+            network: "test".to_string(),
+            network_type: NetworkType::Evm,
+            relayer_ids: relayer_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    fn create_pending_transaction(id: &str, relayer_id: &str) -> TransactionRepoModel {
+        TransactionRepoModel {
+            id: id.to_string(),
+            relayer_id: relayer_id.to_string(),
+            status: TransactionStatus::Submitted,
+            ..create_mock_transaction()
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_resolve_pool_network() {
+        let state = create_mock_app_state(
+            Some(vec![
+                create_mock_relayer("relayer-1".to_string(), false),
+                create_mock_relayer("relayer-2".to_string(), true),
+                create_mock_solana_relayer("relayer-3".to_string(), false),
+            ]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+        let state = ThinData(state);
+
+        let (network, network_type) =
+            resolve_pool_network(&["relayer-1".to_string(), "relayer-2".to_string()], &state)
+                .await
+                .unwrap();
+        assert_eq!(network, "test");
+        assert_eq!(network_type, NetworkType::Evm);
+
+        let result =
+            resolve_pool_network(&["relayer-1".to_string(), "relayer-3".to_string()], &state).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+
+        let result =
+            resolve_pool_network(&["relayer-1".to_string(), "missing".to_string()], &state).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(msg)) if msg.contains("missing")));
+    }
+
+    #[actix_web::test]
+    async fn test_get_pool_candidates_orders_by_pending_transactions() {
+        let state = create_mock_app_state(
+            Some(vec![
+                create_mock_relayer("relayer-1".to_string(), false),
+                create_mock_relayer("relayer-2".to_string(), false),
+                create_mock_relayer("relayer-3".to_string(), false),
+            ]),
+            None,
+            None,
+            None,
+            Some(vec![
+                create_pending_transaction("tx-1", "relayer-1"),
+                create_pending_transaction("tx-2", "relayer-1"),
+                create_pending_transaction("tx-3", "relayer-2"),
+            ]),
+        )
+        .await;
+        let state = ThinData(state);
+
+        let candidates = get_pool_candidates(
+            &create_pool(&["relayer-1", "relayer-2", "relayer-3"]),
+            &state,
+        )
+        .await
+        .unwrap();
+        let ids: Vec<_> = candidates.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["relayer-3", "relayer-2", "relayer-1"]);
+    }
+
+    #[actix_web::test]
+    async fn test_get_pool_candidates_skips_ineligible_members() {
+        let mut disabled = create_mock_relayer("disabled".to_string(), false);
+        disabled.system_disabled = true;
+        let mut rotating = create_mock_relayer("rotating".to_string(), false);
+        rotating.signer_rotations = vec![SignerRotation {
+            old_signer_id: "old".to_string(),
+            old_address: "0x1".to_string(),
+            new_signer_id: "new".to_string(),
+            new_address: "0x2".to_string(),
+            pending_transactions: PendingTransactionsAction::Drain,
+            sweep_balance: false,
+            sweep_transaction_id: None,
+            status: SignerRotationStatus::Draining,
+            status_reason: None,
+            requested_at: "2025-01-01T00:00:00Z".to_string(),
+            completed_at: None,
+        }];
+        let mut moved = create_mock_relayer("moved".to_string(), false);
+        moved.network = "other".to_string();
+
+        let state = create_mock_app_state(
+            Some(vec![
+                create_mock_relayer("paused".to_string(), true),
+                disabled,
+                rotating,
+                moved,
+                create_mock_relayer("active".to_string(), false),
+            ]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+        let state = ThinData(state);
+
+        let pool = create_pool(&[
+            "missing", "paused", "disabled", "rotating", "moved", "active",
+        ]);
+        let candidates = get_pool_candidates(&pool, &state).await.unwrap();
+        let ids: Vec<_> = candidates.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["active"]);
+    }
+
+    #[actix_web::test]
+    async fn test_select_pool_relayer_without_eligible_members() {
+        let state = create_mock_app_state(
+            Some(vec![create_mock_relayer("paused".to_string(), true)]),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+        let state = ThinData(state);
+
+        let result = select_pool_relayer(&create_pool(&["paused"]), &state).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_select_pool_relayer_skips_disabled_and_rotating_members() {
+        let mut disabled = create_mock_relayer("disabled".to_string(), false);
+        disabled.system_disabled = true;
+        let mut rotating = create_mock_relayer("rotating".to_string(), false);
+        rotating.signer_rotations = vec![SignerRotation {
+            old_signer_id: "old".to_string(),
+            old_address: "0x1".to_string(),
+            new_signer_id: "new".to_string(),
+            new_address: "0x2".to_string(),
+            pending_transactions: PendingTransactionsAction::Cancel,
+            sweep_balance: false,
+            sweep_transaction_id: None,
+            status: SignerRotationStatus::Sweeping,
+            status_reason: None,
+            requested_at: "2025-01-01T00:00:00Z".to_string(),
+            completed_at: None,
+        }];
+        let state =
+            create_mock_app_state(Some(vec![disabled, rotating]), None, None, None, None).await;
+        let state = ThinData(state);
+
+        let result = select_pool_relayer(&create_pool(&["disabled", "rotating"]), &state).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_reserve_pool_idempotency_key() {
+        let state = create_mock_app_state(None, None, None, None, None).await;
+        let state = ThinData(state);
+        let pool = create_pool(&["relayer-1", "relayer-2"]);
+
+        let reservation_id = match reserve_pool_idempotency_key(&pool, "key-1", 3600, &state)
+            .await
+            .unwrap()
+        {
+            IdempotencyReservation::Reserved(reservation_id) => reservation_id,
+            IdempotencyReservation::Existing(_) => panic!("expected a reservation"),
+        };
+
+        // A concurrent submission finds the transaction once the first one creates it
+        state
+            .transaction_repository
+            .create(TransactionRepoModel {
+                idempotency_key: Some("key-1".to_string()),
+                created_at: chrono::Utc::now().to_rfc3339(),
+                ..create_pending_transaction("tx-1", "relayer-2")
+            })
+            .await
+            .unwrap();
+        let result = reserve_pool_idempotency_key(&pool, "key-1", 3600, &state)
+            .await
+            .unwrap();
+        assert!(
+            matches!(result, IdempotencyReservation::Existing(transaction) if transaction.id == "tx-1")
+        );
+
+        release_pool_idempotency_key(&pool, "key-1", &reservation_id, &state).await;
+        let result = reserve_pool_idempotency_key(&pool, "key-1", 3600, &state)
+            .await
+            .unwrap();
+        assert!(matches!(result, IdempotencyReservation::Existing(_)));
+    }
+
+    #[actix_web::test]
+    async fn test_released_pool_idempotency_key_can_be_reserved_again() {
+        let state = create_mock_app_state(None, None, None, None, None).await;
+        let state = ThinData(state);
+        let pool = create_pool(&["relayer-1"]);
+
+        let IdempotencyReservation::Reserved(reservation_id) =
+            reserve_pool_idempotency_key(&pool, "key-1", 3600, &state)
+                .await
+                .unwrap()
+        else {
+            panic!("expected a reservation");
+        };
+        let holder = state
+            .transaction_repository
+            .reserve_idempotency_key(&pool_idempotency_scope("pool-1"), "key-1", "other", 60)
+            .await
+            .unwrap();
+        assert_eq!(holder, Some(reservation_id.clone()));
+
+        // A failed send releases the key, so a retry is routed again
+        release_pool_idempotency_key(&pool, "key-1", &reservation_id, &state).await;
+        let result = reserve_pool_idempotency_key(&pool, "key-1", 3600, &state)
+            .await
+            .unwrap();
+        assert!(matches!(result, IdempotencyReservation::Reserved(_)));
+    }
+
+    #[actix_web::test]
+    async fn test_find_pool_transaction_by_idempotency_key() {
+        let transaction = TransactionRepoModel {
+            idempotency_key: Some("key-1".to_string()),
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            ..create_pending_transaction("tx-1", "relayer-2")
+        };
+        let state = create_mock_app_state(None, None, None, None, Some(vec![transaction])).await;
+        let state = ThinData(state);
+        let pool = create_pool(&["relayer-1", "relayer-2"]);
+
+        let found = find_pool_transaction_by_idempotency_key(&pool, "key-1", 3600, &state)
+            .await
+            .unwrap();
+        assert_eq!(found.unwrap().id, "tx-1");
+
+        let found = find_pool_transaction_by_idempotency_key(&pool, "key-2", 3600, &state)
+            .await
+            .unwrap();
+        assert!(found.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_get_relayer_pool_status_reports_member_errors() {
+        let state = create_mock_app_state(None, None, None, None, None).await;
+        let state = ThinData(state);
+
+        let status = get_relayer_pool_status(&create_pool(&["missing"]), &state)
+            .await
+            .unwrap();
+        assert_eq!(status.active_relayers_count, 0);
+        assert_eq!(status.pending_transactions_count, 0);
+        assert_eq!(status.relayers.len(), 1);
+        assert!(status.relayers[0].status.is_none());
+        assert!(status.relayers[0].error.is_some());
+    }
+}
@@ -0,0 +1,616 @@
+//! Signer rotation for existing relayers.
+//!
+//! A rotation switches the signer of a relayer without recreating it:
+//!
+//! 1. The rotation is recorded on the relayer and new transactions are rejected.
+//! 2. Transactions pending on the old address are drained, or cancelled first.
+//! 3. Optionally, the native balance of the old address is swept to the new address.
+//! 4. The relayer switches to the new signer and re-syncs its nonce or sequence.
+//!
+//! Steps 2 to 4 are driven by `advance_signer_rotation`, which is called when the
+//! rotation is requested and then periodically by the signer rotation worker.
+use chrono::Utc;
+use log::{info, warn};
+
+use crate::{
+    domain::relayer::{Relayer, RelayerFactory, RelayerFactoryTrait},
+    jobs::JobProducerTrait,
+    models::{
+        ApiError, NetworkRepoModel, NetworkType, NotificationRepoModel, PendingTransactionsAction,
+        RelayerPoolRepoModel, RelayerRepoModel, RepositoryError, RotateSignerRequest,
+        Signer as SignerDomainModel, SignerRepoModel, SignerRotation, SignerRotationStatus,
+        ThinDataAppState, TransactionRepoModel, TransactionStatus,
+    },
+    repositories::{
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        TransactionCounterTrait, TransactionRepository,
+    },
+    services::{Signer, SignerFactory},
+};
+
+use super::{get_network_relayer_by_model, get_relayer_by_id};
+
+/// Transaction statuses that still hold a nonce or sequence on the old address
+const UNFINISHED_TRANSACTION_STATUSES: [TransactionStatus; 3] = [
+    TransactionStatus::Pending,
+    TransactionStatus::Sent,
+    TransactionStatus::Submitted,
+];
+
+/// Starts rotating the signer of a relayer.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer to rotate.
+/// * `request` - The new signer and rotation options.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<SignerRotation, ApiError>` - The recorded rotation, which may already be
+///   completed if nothing was pending on the old address.
+pub async fn start_signer_rotation<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer_id: String,
+    request: RotateSignerRequest,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<SignerRotation, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let mut relayer = get_relayer_by_id(relayer_id, state).await?;
+
+    if relayer.active_signer_rotation().is_some() {
+        return Err(ApiError::BadRequest(format!(
+            "Relayer '{}' already has a signer rotation in progress",
+            relayer.id
+        )));
+    }
+
+    if request.signer_id == relayer.signer_id {
+        return Err(ApiError::BadRequest(format!(
+            "Relayer '{}' already uses signer '{}'",
+            relayer.id, request.signer_id
+        )));
+    }
+
+    if request.sweep_balance && relayer.network_type != NetworkType::Evm {
+        return Err(ApiError::BadRequest(format!(
+            "Balance sweep is not supported for {} relayers",
+            relayer.network_type
+        )));
+    }
+
+    // Scheduled transactions hold the old address until they are due, so draining would wait
+    // for them, and a recurring series never ends on its own
+    if request.pending_transactions == PendingTransactionsAction::Drain {
+        let scheduled = state
+            .transaction_repository
+            .find_by_status(&relayer.id, &[TransactionStatus::Pending])
+            .await?
+            .into_iter()
+            .filter(|transaction| !transaction.is_due())
+            .count();
+        if scheduled > 0 {
+            return Err(ApiError::BadRequest(format!(
+                "Relayer '{}' has {} scheduled transactions that are not due yet; cancel them or \
+                 rotate with pending_transactions set to cancel",
+                relayer.id, scheduled
+            )));
+        }
+    }
+
+    let signer_model = state
+        .signer_repository
+        .get_by_id(request.signer_id.clone())
+        .await?;
+
+    let relayers = state
+        .relayer_repository
+        .list_by_signer_id(&request.signer_id)
+        .await?;
+    if let Some(existing_relayer) = relayers
+        .iter()
+        .find(|r| r.network == relayer.network && r.id != relayer.id)
+    {
+        return Err(ApiError::BadRequest(format!(
+            "Cannot rotate signer: signer '{}' is already in use by relayer '{}' on network '{}'",
+            request.signer_id, existing_relayer.id, relayer.network
+        )));
+    }
+
+    let new_address = SignerFactory::create_signer(
+        &relayer.network_type,
+        &SignerDomainModel::from(signer_model),
+    )
+    .await
+    .map_err(|e| ApiError::BadRequest(e.to_string()))?
+    .address()
+    .await
+    .map_err(|e| ApiError::InternalError(e.to_string()))?
+    .to_string();
+
+    if new_address == relayer.address {
+        return Err(ApiError::BadRequest(format!(
+            "Signer '{}' resolves to the current relayer address {}",
+            request.signer_id, new_address
+        )));
+    }
+
+    if request.pending_transactions == PendingTransactionsAction::Cancel {
+        let network_relayer = get_network_relayer_by_model(relayer.clone(), state).await?;
+        let result = network_relayer.delete_pending_transactions().await?;
+        info!(
+            "Queued {} pending transactions of relayer {} for cancellation before signer rotation",
+            result.queued_for_cancellation_transaction_ids.len(),
+            relayer.id
+        );
+    }
+
+    relayer.signer_rotations.push(SignerRotation {
+        old_signer_id: relayer.signer_id.clone(),
+        old_address: relayer.address.clone(),
+        new_signer_id: request.signer_id,
+        new_address,
+        pending_transactions: request.pending_transactions,
+        sweep_balance: request.sweep_balance,
+        sweep_transaction_id: None,
+        status: SignerRotationStatus::Draining,
+        status_reason: None,
+        requested_at: Utc::now().to_rfc3339(),
+        completed_at: None,
+    });
+
+    let relayer = state
+        .relayer_repository
+        .update(relayer.id.clone(), relayer)
+        .await?;
+    info!("Started signer rotation for relayer {}", relayer.id);
+
+    let relayer = advance_signer_rotation(relayer, state).await?;
+
+    relayer
+        .signer_rotations
+        .last()
+        .cloned()
+        .ok_or_else(|| ApiError::InternalError("Signer rotation was not recorded".to_string()))
+}
+
+/// Moves the active signer rotation of a relayer forward, if there is one.
+///
+/// # Arguments
+///
+/// * `relayer` - The relayer whose rotation should be advanced.
+/// * `state` - A reference to the application state.
+///
+/// # Returns
+///
+/// * `Result<RelayerRepoModel, ApiError>` - The relayer after the rotation was advanced.
+pub async fn advance_signer_rotation<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    mut relayer: RelayerRepoModel,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<RelayerRepoModel, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let Some(rotation) = relayer.active_signer_rotation().cloned() else {
+        return Ok(relayer);
+    };
+
+    let unfinished = state
+        .transaction_repository
+        .find_by_status(&relayer.id, &UNFINISHED_TRANSACTION_STATUSES)
+        .await?;
+    if !unfinished.is_empty() {
+        info!(
+            "Signer rotation for relayer {} is waiting for {} transactions on {}",
+            relayer.id,
+            unfinished.len(),
+            rotation.old_address
+        );
+        return Ok(relayer);
+    }
+
+    if rotation.sweep_balance && rotation.status == SignerRotationStatus::Draining {
+        let network_relayer = get_network_relayer_by_model(relayer.clone(), state).await?;
+        if let Some(transaction) = network_relayer.sweep_balance(&rotation.new_address).await? {
+            let current = current_rotation_mut(&mut relayer)?;
+            current.status = SignerRotationStatus::Sweeping;
+            current.sweep_transaction_id = Some(transaction.id);
+
+            return Ok(state
+                .relayer_repository
+                .update(relayer.id.clone(), relayer)
+                .await?);
+        }
+    }
+
+    let signer_model = match state
+        .signer_repository
+        .get_by_id(rotation.new_signer_id.clone())
+        .await
+    {
+        Ok(signer_model) => signer_model,
+        Err(RepositoryError::NotFound(_)) => {
+            warn!(
+                "Signer rotation for relayer {} failed: signer {} no longer exists",
+                relayer.id, rotation.new_signer_id
+            );
+            let current = current_rotation_mut(&mut relayer)?;
+            current.status = SignerRotationStatus::Failed;
+            current.status_reason = Some(format!(
+                "Signer '{}' no longer exists",
+                rotation.new_signer_id
+            ));
+            current.completed_at = Some(Utc::now().to_rfc3339());
+
+            return Ok(state
+                .relayer_repository
+                .update(relayer.id.clone(), relayer)
+                .await?);
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    relayer.signer_id = rotation.new_signer_id.clone();
+    relayer.address = rotation.new_address.clone();
+    let current = current_rotation_mut(&mut relayer)?;
+    current.status = SignerRotationStatus::Completed;
+    current.completed_at = Some(Utc::now().to_rfc3339());
+
+    let relayer = state
+        .relayer_repository
+        .update(relayer.id.clone(), relayer)
+        .await?;
+    info!(
+        "Relayer {} rotated from signer {} to signer {}",
+        relayer.id, rotation.old_signer_id, rotation.new_signer_id
+    );
+
+    // Re-sync the nonce or sequence of the new address
+    let network_relayer = RelayerFactory::create_relayer(relayer.clone(), signer_model, state)
+        .await
+        .map_err(ApiError::from)?;
+    if let Err(e) = network_relayer.initialize_relayer().await {
+        warn!(
+            "Failed to initialize relayer {} after signer rotation: {}",
+            relayer.id, e
+        );
+    }
+
+    Ok(relayer)
+}
+
+fn current_rotation_mut(relayer: &mut RelayerRepoModel) -> Result<&mut SignerRotation, ApiError> {
+    relayer
+        .signer_rotations
+        .last_mut()
+        .ok_or_else(|| ApiError::InternalError("Signer rotation not found".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{LocalSignerConfigStorage, SignerConfigStorage},
+        utils::mocks::mockutils::{
+            create_mock_app_state, create_mock_network, create_mock_relayer, create_mock_signer,
+            create_mock_transaction,
+        },
+    };
+    use actix_web::web::ThinData;
+    use lazy_static::lazy_static;
+    use secrets::SecretVec;
+    use std::env;
+    use tokio::sync::Mutex;
+
+    lazy_static! {
+        static ref ENV_MUTEX: Mutex<()> = Mutex::new(());
+    }
+
+    fn setup_test_env() {
+        env::set_var("API_KEY", "7EF1CB7C-5003-4696-B384-C72AF8C3E15D"); // noboost nosemgrep
+        env::set_var("REDIS_URL", "redis://localhost:6379");
+    }
+
+    fn cleanup_test_env() {
+        env::remove_var("API_KEY");
+        env::remove_var("REDIS_URL");
+    }
+
+    fn create_signer(id: &str, key: u8) -> SignerRepoModel {
+        SignerRepoModel {
+            id: id.to_string(),
+            config: SignerConfigStorage::Local(LocalSignerConfigStorage {
+                raw_key: SecretVec::new(32, |v| v.fill(key)),
+            }),
+        }
+    }
+
+    fn rotation_request(signer_id: &str) -> RotateSignerRequest {
+        RotateSignerRequest {
+            signer_id: signer_id.to_string(),
+            pending_transactions: PendingTransactionsAction::Drain,
+            sweep_balance: false,
+        }
+    }
+
+    fn create_rotating_relayer(new_signer_id: &str) -> RelayerRepoModel {
+        let mut relayer = create_mock_relayer("test-relayer".to_string(), false);
+        relayer.signer_rotations.push(SignerRotation {
+            old_signer_id: relayer.signer_id.clone(),
+            old_address: relayer.address.clone(),
+            new_signer_id: new_signer_id.to_string(),
+            new_address: "0xnew".to_string(),
+            pending_transactions: PendingTransactionsAction::Drain,
+            sweep_balance: false,
+            sweep_transaction_id: None,
+            status: SignerRotationStatus::Draining,
+            status_reason: None,
+            requested_at: Utc::now().to_rfc3339(),
+            completed_at: None,
+        });
+        relayer
+    }
+
+    #[actix_web::test]
+    async fn test_start_signer_rotation_completes_without_pending_transactions() {
+        let _lock = ENV_MUTEX.lock().await;
+        setup_test_env();
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let state = create_mock_app_state(
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer(), create_signer("new-signer", 2)]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+        let state = ThinData(state);
+
+        let rotation = start_signer_rotation(
+            "test-relayer".to_string(),
+            rotation_request("new-signer"),
+            &state,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(rotation.status, SignerRotationStatus::Completed);
+        assert_eq!(rotation.new_signer_id, "new-signer");
+        assert!(rotation.completed_at.is_some());
+
+        let relayer = state
+            .relayer_repository
+            .get_by_id("test-relayer".to_string())
+            .await
+            .unwrap();
+        assert_eq!(relayer.signer_id, "new-signer");
+        assert_eq!(relayer.address, rotation.new_address);
+        assert_eq!(relayer.signer_rotations.len(), 1);
+        cleanup_test_env();
+    }
+
+    #[actix_web::test]
+    async fn test_start_signer_rotation_waits_for_pending_transactions() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let mut transaction = create_mock_transaction();
+        transaction.relayer_id = "test-relayer".to_string();
+        transaction.status = TransactionStatus::Submitted;
+        let state = create_mock_app_state(
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer(), create_signer("new-signer", 2)]),
+            Some(vec![create_mock_network()]),
+            None,
+            Some(vec![transaction]),
+        )
+        .await;
+        let state = ThinData(state);
+
+        let rotation = start_signer_rotation(
+            "test-relayer".to_string(),
+            rotation_request("new-signer"),
+            &state,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(rotation.status, SignerRotationStatus::Draining);
+
+        let relayer = state
+            .relayer_repository
+            .get_by_id("test-relayer".to_string())
+            .await
+            .unwrap();
+        assert_eq!(relayer.signer_id, "test");
+        assert!(relayer.validate_no_active_signer_rotation().is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_start_signer_rotation_rejects_drain_with_scheduled_transactions() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let mut transaction = create_mock_transaction();
+        transaction.relayer_id = "test-relayer".to_string();
+        transaction.status = TransactionStatus::Pending;
+        transaction.execute_after = Some((Utc::now() + chrono::Duration::hours(1)).to_rfc3339());
+        transaction.schedule = Some("0 0 * * * *".to_string());
+        let state = create_mock_app_state(
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer(), create_signer("new-signer", 2)]),
+            Some(vec![create_mock_network()]),
+            None,
+            Some(vec![transaction]),
+        )
+        .await;
+        let state = ThinData(state);
+
+        let result = start_signer_rotation(
+            "test-relayer".to_string(),
+            rotation_request("new-signer"),
+            &state,
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(msg)) if msg.contains("scheduled")));
+        let relayer = state
+            .relayer_repository
+            .get_by_id("test-relayer".to_string())
+            .await
+            .unwrap();
+        assert!(relayer.signer_rotations.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn test_start_signer_rotation_rejects_same_signer() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let state = create_mock_app_state(
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer()]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+
+        let result = start_signer_rotation(
+            "test-relayer".to_string(),
+            rotation_request("test"),
+            &ThinData(state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_start_signer_rotation_rejects_unknown_signer() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let state = create_mock_app_state(
+            Some(vec![relayer]),
+            Some(vec![create_mock_signer()]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+
+        let result = start_signer_rotation(
+            "test-relayer".to_string(),
+            rotation_request("missing-signer"),
+            &ThinData(state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_start_signer_rotation_rejects_signer_in_use_on_network() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let mut other = create_mock_relayer("other-relayer".to_string(), false);
+        other.signer_id = "new-signer".to_string();
+        let state = create_mock_app_state(
+            Some(vec![relayer, other]),
+            Some(vec![create_mock_signer(), create_signer("new-signer", 2)]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+
+        let result = start_signer_rotation(
+            "test-relayer".to_string(),
+            rotation_request("new-signer"),
+            &ThinData(state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_start_signer_rotation_rejects_active_rotation() {
+        let relayer = create_rotating_relayer("new-signer");
+        let state = create_mock_app_state(
+            Some(vec![relayer]),
+            Some(vec![
+                create_mock_signer(),
+                create_signer("new-signer", 2),
+                create_signer("another-signer", 3),
+            ]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+
+        let result = start_signer_rotation(
+            "test-relayer".to_string(),
+            rotation_request("another-signer"),
+            &ThinData(state),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_advance_signer_rotation_fails_when_signer_removed() {
+        let relayer = create_rotating_relayer("removed-signer");
+        let state = create_mock_app_state(
+            Some(vec![relayer.clone()]),
+            Some(vec![create_mock_signer()]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+
+        let relayer = advance_signer_rotation(relayer, &ThinData(state))
+            .await
+            .unwrap();
+
+        let rotation = relayer.signer_rotations.last().unwrap();
+        assert_eq!(rotation.status, SignerRotationStatus::Failed);
+        assert!(rotation.status_reason.is_some());
+        assert_eq!(relayer.signer_id, "test");
+        assert!(relayer.validate_no_active_signer_rotation().is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_advance_signer_rotation_without_active_rotation_is_noop() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let state = create_mock_app_state(
+            Some(vec![relayer.clone()]),
+            Some(vec![create_mock_signer()]),
+            Some(vec![create_mock_network()]),
+            None,
+            None,
+        )
+        .await;
+
+        let advanced = advance_signer_rotation(relayer.clone(), &ThinData(state))
+            .await
+            .unwrap();
+
+        assert_eq!(advanced.signer_id, relayer.signer_id);
+        assert!(advanced.signer_rotations.is_empty());
+    }
+}
@@ -94,6 +94,8 @@ where
 
         let network_transaction = NetworkTransactionRequest::Solana(SolanaTransactionRequest {
             transaction: params.transaction.clone(),
+            idempotency_key: None,
+            depends_on: None,
         });
 
         let transaction =
@@ -63,6 +63,7 @@ pub fn setup_test_context() -> (
         notification_id: None,
         system_disabled: false,
         custom_rpc_urls: None,
+        signer_rotations: vec![],
     };
 
     let network = create_mock_solana_network();
@@ -167,6 +168,7 @@ pub fn setup_test_context_relayer_fee_strategy() -> RelayerFeeStrategyTestContex
         notification_id: None,
         system_disabled: false,
         custom_rpc_urls: None,
+        signer_rotations: vec![],
     };
 
     // Setup mock signer
@@ -303,6 +305,7 @@ pub fn setup_test_context_user_fee_strategy() -> UserFeeStrategyTestContext {
         notification_id: None,
         system_disabled: false,
         custom_rpc_urls: None,
+        signer_rotations: vec![],
     };
 
     let mock_signer = MockSolanaSignTrait::new();
@@ -428,6 +431,7 @@ pub fn setup_test_context_single_tx_user_fee_strategy() -> UserFeeStrategySingle
         notification_id: None,
         system_disabled: false,
         custom_rpc_urls: None,
+        signer_rotations: vec![],
     };
 
     let mock_signer = MockSolanaSignTrait::new();
@@ -2701,6 +2701,10 @@ mod tests {
             network_type: crate::models::NetworkType::Solana,
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
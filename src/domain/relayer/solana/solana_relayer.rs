@@ -18,12 +18,13 @@ use crate::{
         relayer::RelayerError, BalanceResponse, DexStrategy, SolanaRelayerDexTrait,
         SolanaRelayerTrait, SolanaRpcHandlerType, SwapParams,
     },
-    jobs::{JobProducerTrait, SolanaTokenSwapRequest},
+    jobs::{JobProducerTrait, SolanaTokenSwapRequest, TransactionRequest},
     models::{
         produce_relayer_disabled_payload, produce_solana_dex_webhook_payload, JsonRpcRequest,
-        JsonRpcResponse, NetworkRepoModel, NetworkRpcRequest, NetworkRpcResult, NetworkType,
-        RelayerNetworkPolicy, RelayerRepoModel, RelayerSolanaPolicy, SolanaAllowedTokensPolicy,
-        SolanaDexPayload, SolanaNetwork, TransactionRepoModel,
+        JsonRpcResponse, NetworkRepoModel, NetworkRpcRequest, NetworkRpcResult,
+        NetworkTransactionRequest, NetworkType, RelayerNetworkPolicy, RelayerRepoModel,
+        RelayerSolanaPolicy, RepositoryError, SolanaAllowedTokensPolicy, SolanaDexPayload,
+        SolanaNetwork, TransactionRepoModel,
     },
     repositories::{NetworkRepository, RelayerRepository, Repository, TransactionRepository},
     services::{
@@ -513,6 +514,38 @@ where
     SP: SolanaProviderTrait + Send + Sync + 'static,
     NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
 {
+    async fn process_transaction_request(
+        &self,
+        network_transaction: NetworkTransactionRequest,
+    ) -> Result<TransactionRepoModel, RelayerError> {
+        let network_model = self
+            .network_repository
+            .get_by_name(NetworkType::Solana, &self.relayer.network)
+            .await?
+            .ok_or_else(|| {
+                RelayerError::NetworkConfiguration(format!(
+                    "Network {} not found",
+                    self.relayer.network
+                ))
+            })?;
+        let transaction =
+            TransactionRepoModel::try_from((&network_transaction, &self.relayer, &network_model))?;
+
+        self.transaction_repository
+            .create(transaction.clone())
+            .await
+            .map_err(|e| RepositoryError::TransactionFailure(e.to_string()))?;
+
+        self.job_producer
+            .produce_transaction_request_job(
+                TransactionRequest::new(transaction.id.clone(), transaction.relayer_id.clone()),
+                None,
+            )
+            .await?;
+
+        Ok(transaction)
+    }
+
     async fn get_balance(&self) -> Result<BalanceResponse, RelayerError> {
         let address = &self.relayer.address;
         let balance = self.provider.get_balance(address).await?;
@@ -1791,4 +1824,71 @@ mod tests {
         };
         assert_eq!(features.features, vec!["gasless".to_string()]);
     }
+
+    #[tokio::test]
+    async fn test_process_transaction_request_enqueues_job() {
+        let mut ctx = TestCtx::default();
+
+        let mut tx_repo = MockTransactionRepository::new();
+        tx_repo
+            .expect_create()
+            .withf(|tx| {
+                tx.status == crate::models::TransactionStatus::Pending
+                    && tx.network_type == NetworkType::Solana
+            })
+            .times(1)
+            .returning(Ok);
+        ctx.tx_repo = Arc::new(tx_repo);
+
+        let mut job_producer = MockJobProducerTrait::new();
+        job_producer
+            .expect_produce_transaction_request_job()
+            .withf(|job, delay| job.relayer_id == "test-id" && delay.is_none())
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        ctx.job_producer = Arc::new(job_producer);
+
+        let solana_relayer = ctx.into_relayer().await;
+        let request = NetworkTransactionRequest::Solana(crate::models::SolanaTransactionRequest {
+            transaction: crate::models::EncodedSerializedTransaction::new("AQID".to_string()),
+            idempotency_key: None,
+            depends_on: None,
+        });
+
+        let tx = solana_relayer
+            .process_transaction_request(request)
+            .await
+            .unwrap();
+
+        assert_eq!(tx.relayer_id, "test-id");
+        assert_eq!(
+            tx.network_data
+                .get_solana_transaction_data()
+                .unwrap()
+                .transaction,
+            "AQID"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_process_transaction_request_repository_failure() {
+        let mut ctx = TestCtx::default();
+
+        let mut tx_repo = MockTransactionRepository::new();
+        tx_repo
+            .expect_create()
+            .returning(|_| Err(RepositoryError::Unknown("db down".to_string())));
+        ctx.tx_repo = Arc::new(tx_repo);
+
+        let solana_relayer = ctx.into_relayer().await;
+        let request = NetworkTransactionRequest::Solana(crate::models::SolanaTransactionRequest {
+            transaction: crate::models::EncodedSerializedTransaction::new("AQID".to_string()),
+            idempotency_key: None,
+            depends_on: None,
+        });
+
+        let result = solana_relayer.process_transaction_request(request).await;
+
+        assert!(result.is_err());
+    }
 }
@@ -23,16 +23,18 @@
 use crate::{
     constants::STELLAR_SMALLEST_UNIT_NAME,
     domain::{
+        create_error_response, map_provider_error,
         transaction::stellar::fetch_next_sequence_from_chain, BalanceResponse, SignDataRequest,
         SignDataResponse, SignTransactionExternalResponse, SignTransactionExternalResponseStellar,
         SignTransactionRequest, SignTypedDataRequest,
     },
-    jobs::{JobProducerTrait, TransactionRequest},
+    jobs::{JobProducerTrait, TransactionRequest, TransactionSend},
     models::{
-        produce_relayer_disabled_payload, DeletePendingTransactionsResponse, JsonRpcRequest,
-        JsonRpcResponse, NetworkRepoModel, NetworkRpcRequest, NetworkRpcResult,
-        NetworkTransactionRequest, NetworkType, RelayerRepoModel, RelayerStatus, RepositoryError,
-        StellarNetwork, StellarRpcResult, TransactionRepoModel, TransactionStatus,
+        produce_relayer_disabled_payload, DeletePendingTransactionsResponse,
+        ForwardTransactionRequest, JsonRpcRequest, JsonRpcResponse, NetworkRepoModel,
+        NetworkRpcRequest, NetworkRpcResult, NetworkTransactionRequest, NetworkType, NonceHealth,
+        RelayerRepoModel, RelayerStatus, RepositoryError, RpcErrorCodes, StellarNetwork,
+        TransactionRepoModel, TransactionStatus,
     },
     repositories::{NetworkRepository, RelayerRepository, Repository, TransactionRepository},
     services::{
@@ -225,6 +227,23 @@ where
         }
         Ok(())
     }
+
+    /// Initiates transaction cancellation via the job queue system.
+    async fn cancel_transaction_via_job(
+        &self,
+        transaction: &TransactionRepoModel,
+    ) -> Result<(), RelayerError> {
+        let cancel_job = TransactionSend::cancel(
+            transaction.id.clone(),
+            transaction.relayer_id.clone(),
+            "Cancelled via delete_pending_transactions".to_string(),
+        );
+
+        self.job_producer
+            .produce_submit_transaction_job(cancel_job, None)
+            .await
+            .map_err(RelayerError::from)
+    }
 }
 
 #[async_trait]
@@ -263,7 +282,7 @@ where
         self.job_producer
             .produce_transaction_request_job(
                 TransactionRequest::new(transaction.id.clone(), transaction.relayer_id.clone()),
-                None,
+                transaction.scheduled_on(),
             )
             .await?;
 
@@ -334,11 +353,69 @@ where
     async fn delete_pending_transactions(
         &self,
     ) -> Result<DeletePendingTransactionsResponse, RelayerError> {
-        println!("Stellar delete_pending_transactions...");
+        let pending_statuses = [
+            TransactionStatus::Pending,
+            TransactionStatus::Sent,
+            TransactionStatus::Submitted,
+        ];
+
+        let pending_transactions = self
+            .transaction_repository
+            .find_by_status(&self.relayer.id, &pending_statuses[..])
+            .await
+            .map_err(RelayerError::from)?;
+
+        if pending_transactions.is_empty() {
+            info!(
+                "No pending transactions found for relayer: {}",
+                self.relayer.id
+            );
+            return Ok(DeletePendingTransactionsResponse {
+                queued_for_cancellation_transaction_ids: vec![],
+                failed_to_queue_transaction_ids: vec![],
+                total_processed: 0,
+            });
+        }
+
+        info!(
+            "Processing {} pending transactions for relayer: {}",
+            pending_transactions.len(),
+            self.relayer.id
+        );
+
+        let mut cancelled_transaction_ids = Vec::new();
+        let mut failed_transaction_ids = Vec::new();
+
+        // Unsent transactions are marked canceled, while those holding a sequence number are
+        // replaced with a bump-sequence no-op by the cancel job.
+        for transaction in pending_transactions {
+            match self.cancel_transaction_via_job(&transaction).await {
+                Ok(()) => {
+                    info!(
+                        "Initiated cancellation for transaction {} with status {:?} for relayer {}",
+                        transaction.id, transaction.status, self.relayer.id
+                    );
+                    cancelled_transaction_ids.push(transaction.id);
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to cancel transaction {} for relayer {}: {}",
+                        transaction.id, self.relayer.id, e
+                    );
+                    failed_transaction_ids.push(transaction.id);
+                }
+            }
+        }
+
+        let total_processed = cancelled_transaction_ids.len() + failed_transaction_ids.len();
+
+        info!("Completed processing pending transactions for relayer {}: {} queued for cancellation, {} failed to queue",
+              self.relayer.id, cancelled_transaction_ids.len(), failed_transaction_ids.len());
+
         Ok(DeletePendingTransactionsResponse {
-            queued_for_cancellation_transaction_ids: vec![],
-            failed_to_queue_transaction_ids: vec![],
-            total_processed: 0,
+            queued_for_cancellation_transaction_ids: cancelled_transaction_ids,
+            failed_to_queue_transaction_ids: failed_transaction_ids,
+            total_processed: total_processed as u32,
         })
     }
 
@@ -359,17 +436,73 @@ where
 
     async fn rpc(
         &self,
-        _request: JsonRpcRequest<NetworkRpcRequest>,
+        request: JsonRpcRequest<NetworkRpcRequest>,
     ) -> Result<JsonRpcResponse<NetworkRpcResult>, RelayerError> {
-        println!("Stellar rpc...");
-        Ok(JsonRpcResponse {
-            id: None,
-            jsonrpc: "2.0".to_string(),
-            result: Some(NetworkRpcResult::Stellar(
-                StellarRpcResult::GenericRpcResult("".to_string()),
+        let stellar_request = match request.params {
+            NetworkRpcRequest::Stellar(stellar_req) => stellar_req,
+            _ => {
+                return Ok(create_error_response(
+                    request.id,
+                    RpcErrorCodes::INVALID_PARAMS,
+                    "Invalid params",
+                    "Expected Stellar network request",
+                ))
+            }
+        };
+
+        if let Err(description) = stellar_request.validate() {
+            return Ok(create_error_response(
+                request.id,
+                RpcErrorCodes::INVALID_PARAMS,
+                "Invalid params",
+                &description,
+            ));
+        }
+
+        let params = match stellar_request.params() {
+            Ok(params) => params,
+            Err(e) => {
+                return Ok(create_error_response(
+                    request.id,
+                    RpcErrorCodes::INVALID_PARAMS,
+                    "Invalid params",
+                    &e.to_string(),
+                ))
+            }
+        };
+
+        // Forward the RPC call to the provider
+        let raw_result = match self
+            .provider
+            .raw_request_dyn(stellar_request.method(), params)
+            .await
+        {
+            Ok(raw_result) => raw_result,
+            Err(provider_error) => {
+                let (error_code, error_message) = map_provider_error(&provider_error);
+                return Ok(create_error_response(
+                    request.id,
+                    error_code,
+                    error_message,
+                    &provider_error.to_string(),
+                ));
+            }
+        };
+
+        match stellar_request.parse_result(raw_result) {
+            Ok(result) => Ok(JsonRpcResponse {
+                id: request.id,
+                jsonrpc: "2.0".to_string(),
+                result: Some(NetworkRpcResult::Stellar(result)),
+                error: None,
+            }),
+            Err(e) => Ok(create_error_response(
+                request.id,
+                RpcErrorCodes::INTERNAL_ERROR,
+                "Internal error",
+                &format!("Unexpected {} response: {}", stellar_request.method(), e),
             )),
-            error: None,
-        })
+        }
     }
 
     async fn validate_min_balance(&self) -> Result<(), RelayerError> {
@@ -430,6 +563,45 @@ where
             },
         ))
     }
+
+    async fn process_transaction_batch(
+        &self,
+        _tx_requests: Vec<NetworkTransactionRequest>,
+    ) -> Result<Vec<TransactionRepoModel>, RelayerError> {
+        Err(RelayerError::NotSupported(
+            "Batch transaction submission not supported for Stellar".to_string(),
+        ))
+    }
+
+    async fn process_forward_request(
+        &self,
+        _request: ForwardTransactionRequest,
+    ) -> Result<TransactionRepoModel, RelayerError> {
+        Err(RelayerError::NotSupported(
+            "ERC-2771 forwarding not supported for Stellar".to_string(),
+        ))
+    }
+
+    async fn sweep_balance(
+        &self,
+        _destination: &str,
+    ) -> Result<Option<TransactionRepoModel>, RelayerError> {
+        Err(RelayerError::NotSupported(
+            "Balance sweep not supported for Stellar".to_string(),
+        ))
+    }
+
+    async fn get_nonce_health(&self) -> Result<NonceHealth, RelayerError> {
+        Err(RelayerError::NotSupported(
+            "Nonce health not supported for Stellar".to_string(),
+        ))
+    }
+
+    async fn repair_nonce_gaps(&self) -> Result<Vec<TransactionRepoModel>, RelayerError> {
+        Err(RelayerError::NotSupported(
+            "Nonce gap repair not supported for Stellar".to_string(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -439,16 +611,20 @@ mod tests {
         config::{NetworkConfigCommon, StellarNetworkConfig},
         constants::STELLAR_SMALLEST_UNIT_NAME,
         domain::{SignTransactionRequestStellar, SignXdrTransactionResponseStellar},
-        jobs::MockJobProducerTrait,
+        jobs::{JobProducerError, MockJobProducerTrait, TransactionCommand},
         models::{
-            NetworkConfigData, NetworkRepoModel, NetworkType, RelayerNetworkPolicy,
-            RelayerRepoModel, RelayerStellarPolicy, SignerError,
+            EvmRpcRequest, GetFeeStatsRequestParams, GetLatestLedgerRequestParams,
+            GetTransactionRequestParams, JsonRpcId, NetworkConfigData, NetworkRepoModel,
+            NetworkType, OpenZeppelinErrorCodes, RelayerNetworkPolicy, RelayerRepoModel,
+            RelayerStellarPolicy, SignerError, SimulateTransactionRequestParams, StellarRpcRequest,
+            StellarRpcResult,
         },
         repositories::{
             InMemoryNetworkRepository, MockRelayerRepository, MockTransactionRepository,
         },
         services::{
             MockStellarProviderTrait, MockStellarSignTrait, MockTransactionCounterServiceTrait,
+            ProviderError,
         },
     };
     use eyre::eyre;
@@ -482,6 +658,7 @@ mod tests {
                 notification_id: Some("notification-id".to_string()),
                 system_disabled: false,
                 custom_rpc_urls: None,
+                signer_rotations: vec![],
             };
 
             TestCtx {
@@ -1086,4 +1263,313 @@ mod tests {
             _ => panic!("Expected Stellar response"),
         }
     }
+
+    #[tokio::test]
+    async fn test_delete_pending_transactions_queues_cancel_jobs() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+        let relayer_model = ctx.relayer_model.clone();
+
+        let pending_transactions: Vec<TransactionRepoModel> = [
+            ("tx1", TransactionStatus::Pending),
+            ("tx2", TransactionStatus::Sent),
+            ("tx3", TransactionStatus::Submitted),
+        ]
+        .into_iter()
+        .map(|(id, status)| TransactionRepoModel {
+            id: id.to_string(),
+            relayer_id: relayer_model.id.clone(),
+            status,
+            ..TransactionRepoModel::default()
+        })
+        .collect();
+
+        let mut tx_repo = MockTransactionRepository::new();
+        tx_repo
+            .expect_find_by_status()
+            .withf(|relayer_id, statuses| {
+                relayer_id == "test-relayer-id"
+                    && statuses
+                        == [
+                            TransactionStatus::Pending,
+                            TransactionStatus::Sent,
+                            TransactionStatus::Submitted,
+                        ]
+            })
+            .returning(move |_, _| Ok(pending_transactions.clone()))
+            .once();
+
+        let mut job_producer = MockJobProducerTrait::new();
+        job_producer
+            .expect_produce_submit_transaction_job()
+            .withf(|job, delay| {
+                matches!(job.command, TransactionCommand::Cancel { .. }) && delay.is_none()
+            })
+            .returning(|job, _| {
+                if job.transaction_id == "tx3" {
+                    Box::pin(async { Err(JobProducerError::QueueError("down".to_string())) })
+                } else {
+                    Box::pin(async { Ok(()) })
+                }
+            })
+            .times(3);
+
+        let relayer = StellarRelayer::new(
+            relayer_model,
+            MockStellarSignTrait::new(),
+            MockStellarProviderTrait::new(),
+            StellarRelayerDependencies::new(
+                Arc::new(MockRelayerRepository::new()),
+                ctx.network_repository.clone(),
+                Arc::new(tx_repo),
+                Arc::new(MockTransactionCounterServiceTrait::new()),
+                Arc::new(job_producer),
+            ),
+        )
+        .await
+        .unwrap();
+
+        let result = relayer.delete_pending_transactions().await.unwrap();
+        assert_eq!(
+            result.queued_for_cancellation_transaction_ids,
+            vec!["tx1".to_string(), "tx2".to_string()]
+        );
+        assert_eq!(
+            result.failed_to_queue_transaction_ids,
+            vec!["tx3".to_string()]
+        );
+        assert_eq!(result.total_processed, 3);
+    }
+
+    #[tokio::test]
+    async fn test_delete_pending_transactions_no_pending() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+
+        let mut tx_repo = MockTransactionRepository::new();
+        tx_repo
+            .expect_find_by_status()
+            .returning(|_, _| Ok(vec![]))
+            .once();
+        let mut job_producer = MockJobProducerTrait::new();
+        job_producer.expect_produce_submit_transaction_job().never();
+
+        let relayer = StellarRelayer::new(
+            ctx.relayer_model.clone(),
+            MockStellarSignTrait::new(),
+            MockStellarProviderTrait::new(),
+            StellarRelayerDependencies::new(
+                Arc::new(MockRelayerRepository::new()),
+                ctx.network_repository.clone(),
+                Arc::new(tx_repo),
+                Arc::new(MockTransactionCounterServiceTrait::new()),
+                Arc::new(job_producer),
+            ),
+        )
+        .await
+        .unwrap();
+
+        let result = relayer.delete_pending_transactions().await.unwrap();
+        assert!(result.queued_for_cancellation_transaction_ids.is_empty());
+        assert_eq!(result.total_processed, 0);
+    }
+
+    async fn create_rpc_test_relayer(
+        ctx: &TestCtx,
+        provider: MockStellarProviderTrait,
+    ) -> StellarRelayer<
+        MockStellarProviderTrait,
+        MockRelayerRepository,
+        InMemoryNetworkRepository,
+        MockTransactionRepository,
+        MockJobProducerTrait,
+        MockTransactionCounterServiceTrait,
+        MockStellarSignTrait,
+    > {
+        StellarRelayer::new(
+            ctx.relayer_model.clone(),
+            MockStellarSignTrait::new(),
+            provider,
+            StellarRelayerDependencies::new(
+                Arc::new(MockRelayerRepository::new()),
+                ctx.network_repository.clone(),
+                Arc::new(MockTransactionRepository::new()),
+                Arc::new(MockTransactionCounterServiceTrait::new()),
+                Arc::new(MockJobProducerTrait::new()),
+            ),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_rpc_get_latest_ledger() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+        let mut provider = MockStellarProviderTrait::new();
+        provider
+            .expect_raw_request_dyn()
+            .withf(|method, params| method == "getLatestLedger" && params.is_null())
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(serde_json::json!({
+                        "id": "c73c5eac58a441d4eb733c35253ae85f783e018f7be5ef974258fed067aabb36",
+                        "protocolVersion": 22,
+                        "sequence": 2539605
+                    }))
+                })
+            });
+        let relayer = create_rpc_test_relayer(&ctx, provider).await;
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            params: NetworkRpcRequest::Stellar(StellarRpcRequest::GetLatestLedger(
+                GetLatestLedgerRequestParams {},
+            )),
+            id: Some(JsonRpcId::Number(7)),
+        };
+
+        let response = relayer.rpc(request).await.unwrap();
+        assert_eq!(response.id, Some(JsonRpcId::Number(7)));
+        assert!(response.error.is_none());
+        match response.result {
+            Some(NetworkRpcResult::Stellar(StellarRpcResult::GetLatestLedger(result))) => {
+                assert_eq!(result.sequence, 2539605);
+                assert_eq!(result.protocol_version, 22);
+            }
+            other => panic!("Expected GetLatestLedger result, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rpc_get_transaction_forwards_params() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+        let hash = "a".repeat(64);
+        let expected_params = serde_json::json!({ "hash": hash.clone() });
+        let mut provider = MockStellarProviderTrait::new();
+        provider
+            .expect_raw_request_dyn()
+            .withf(move |method, params| method == "getTransaction" && *params == expected_params)
+            .returning(|_, _| {
+                Box::pin(async {
+                    Ok(serde_json::json!({
+                        "status": "SUCCESS",
+                        "latestLedger": 2540076,
+                        "latestLedgerCloseTime": "1700086333",
+                        "oldestLedger": 2538637,
+                        "oldestLedgerCloseTime": "1700078796",
+                        "ledger": 2540000,
+                        "envelopeXdr": "AAAA"
+                    }))
+                })
+            });
+        let relayer = create_rpc_test_relayer(&ctx, provider).await;
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            params: NetworkRpcRequest::Stellar(StellarRpcRequest::GetTransaction(
+                GetTransactionRequestParams { hash },
+            )),
+            id: Some(JsonRpcId::Number(1)),
+        };
+
+        let response = relayer.rpc(request).await.unwrap();
+        match response.result {
+            Some(NetworkRpcResult::Stellar(StellarRpcResult::GetTransaction(result))) => {
+                assert_eq!(result.status, "SUCCESS");
+                assert_eq!(result.ledger, Some(2540000));
+            }
+            other => panic!("Expected GetTransaction result, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rpc_invalid_params_not_forwarded() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+        // No raw_request_dyn expectation: the request must be rejected before forwarding
+        let relayer = create_rpc_test_relayer(&ctx, MockStellarProviderTrait::new()).await;
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            params: NetworkRpcRequest::Stellar(StellarRpcRequest::SimulateTransaction(
+                SimulateTransactionRequestParams {
+                    transaction: "not-xdr".to_string(),
+                    resource_config: None,
+                },
+            )),
+            id: Some(JsonRpcId::Number(1)),
+        };
+
+        let response = relayer.rpc(request).await.unwrap();
+        assert!(response.result.is_none());
+        assert_eq!(response.error.unwrap().code, RpcErrorCodes::INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_provider_error_mapping() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+        let mut provider = MockStellarProviderTrait::new();
+        provider
+            .expect_raw_request_dyn()
+            .returning(|_, _| Box::pin(async { Err(ProviderError::Timeout) }));
+        let relayer = create_rpc_test_relayer(&ctx, provider).await;
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            params: NetworkRpcRequest::Stellar(StellarRpcRequest::GetFeeStats(
+                GetFeeStatsRequestParams {},
+            )),
+            id: Some(JsonRpcId::Number(1)),
+        };
+
+        let response = relayer.rpc(request).await.unwrap();
+        assert!(response.result.is_none());
+        let error = response.error.unwrap();
+        assert_eq!(error.code, OpenZeppelinErrorCodes::TIMEOUT);
+        assert_eq!(error.message, "Request timeout");
+    }
+
+    #[tokio::test]
+    async fn test_rpc_unexpected_result_shape() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+        let mut provider = MockStellarProviderTrait::new();
+        provider
+            .expect_raw_request_dyn()
+            .returning(|_, _| Box::pin(async { Ok(serde_json::json!({ "unexpected": true })) }));
+        let relayer = create_rpc_test_relayer(&ctx, provider).await;
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            params: NetworkRpcRequest::Stellar(StellarRpcRequest::GetLatestLedger(
+                GetLatestLedgerRequestParams {},
+            )),
+            id: Some(JsonRpcId::Number(1)),
+        };
+
+        let response = relayer.rpc(request).await.unwrap();
+        assert_eq!(response.error.unwrap().code, RpcErrorCodes::INTERNAL_ERROR);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_non_stellar_request() {
+        let ctx = TestCtx::default();
+        ctx.setup_network().await;
+        let relayer = create_rpc_test_relayer(&ctx, MockStellarProviderTrait::new()).await;
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            params: NetworkRpcRequest::Evm(EvmRpcRequest::RawRpcRequest {
+                method: "eth_blockNumber".to_string(),
+                params: serde_json::Value::Null,
+            }),
+            id: Some(JsonRpcId::Number(1)),
+        };
+
+        let response = relayer.rpc(request).await.unwrap();
+        assert_eq!(response.error.unwrap().code, RpcErrorCodes::INVALID_PARAMS);
+    }
 }
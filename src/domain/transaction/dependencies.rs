@@ -0,0 +1,452 @@
+//! Ordering of transactions that depend on other transactions.
+//!
+//! A transaction can name prerequisite transactions, on any relayer and network the submitting
+//! API key may access, that must be confirmed before it is sent. Its transaction request job holds it in the pending state until
+//! every prerequisite is confirmed, and fails it once any prerequisite ends without being
+//! confirmed or it has waited past its deadline.
+use chrono::{DateTime, Utc};
+use log::info;
+
+use crate::{
+    constants::MAX_TRANSACTION_DEPENDENCY_WAIT_SECONDS,
+    jobs::JobProducerTrait,
+    models::{
+        produce_transaction_update_notification_payload, ApiError, ApiKeyIdentity,
+        NetworkRepoModel, NotificationRepoModel, RelayerPoolRepoModel, RelayerRepoModel,
+        RepositoryError, SignerRepoModel, ThinDataAppState, TransactionRepoModel,
+        TransactionStatus, TransactionUpdateRequest,
+    },
+    repositories::{
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        TransactionCounterTrait, TransactionRepository,
+    },
+};
+
+/// Whether the prerequisites of a transaction allow it to be sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DependencyState {
+    /// Every prerequisite is confirmed.
+    Ready,
+    /// At least one prerequisite has not reached a final status yet.
+    Waiting,
+    /// A prerequisite ended without being confirmed, with the reason.
+    Failed(String),
+}
+
+/// Checks the prerequisites of a transaction.
+///
+/// A prerequisite that no longer exists counts as failed. The prerequisites were checked
+/// against the submitting API key by [`validate_transaction_dependencies`]. A transaction
+/// still waiting after its `valid_until`, or `MAX_TRANSACTION_DEPENDENCY_WAIT_SECONDS` after
+/// it became due when it has none, fails as well.
+///
+/// # Arguments
+///
+/// * `transaction` - The dependent transaction.
+/// * `transaction_repository` - The repository holding the prerequisites.
+///
+/// # Returns
+///
+/// A `Result` containing the `DependencyState` or a `RepositoryError`.
+pub async fn check_transaction_dependencies<TR>(
+    transaction: &TransactionRepoModel,
+    transaction_repository: &TR,
+) -> Result<DependencyState, RepositoryError>
+where
+    TR: Repository<TransactionRepoModel, String>,
+{
+    let mut waiting = false;
+    for id in transaction.depends_on.iter().flatten() {
+        let prerequisite = match transaction_repository.get_by_id(id.clone()).await {
+            Ok(prerequisite) => prerequisite,
+            Err(RepositoryError::NotFound(_)) => {
+                return Ok(DependencyState::Failed(format!(
+                    "Prerequisite transaction {} was not found",
+                    id
+                )));
+            }
+            Err(e) => return Err(e),
+        };
+
+        match prerequisite.status {
+            TransactionStatus::Confirmed => {}
+            TransactionStatus::Failed
+            | TransactionStatus::Expired
+            | TransactionStatus::Canceled => {
+                return Ok(DependencyState::Failed(format!(
+                    "Prerequisite transaction {} is {}",
+                    id,
+                    prerequisite.status.to_string().to_lowercase()
+                )));
+            }
+            _ => waiting = true,
+        }
+    }
+
+    if !waiting {
+        return Ok(DependencyState::Ready);
+    }
+
+    Ok(match dependency_wait_deadline(transaction) {
+        Some(deadline) if deadline <= Utc::now().timestamp() => DependencyState::Failed(
+            "Prerequisite transactions were not confirmed in time".to_string(),
+        ),
+        _ => DependencyState::Waiting,
+    })
+}
+
+/// Returns the unix timestamp until which a transaction waits for its prerequisites.
+fn dependency_wait_deadline(transaction: &TransactionRepoModel) -> Option<i64> {
+    let parse = |timestamp: &str| {
+        DateTime::parse_from_rfc3339(timestamp)
+            .ok()
+            .map(|timestamp| timestamp.timestamp())
+    };
+
+    if let Some(valid_until) = transaction.valid_until.as_deref().and_then(parse) {
+        return Some(valid_until);
+    }
+    transaction
+        .scheduled_on()
+        .or_else(|| parse(&transaction.created_at))
+        .map(|due| due + MAX_TRANSACTION_DEPENDENCY_WAIT_SECONDS)
+}
+
+/// Checks that the prerequisites of a new transaction exist on relayers the API key may access.
+///
+/// Prerequisites on other relayers are reported as not found, so a key cannot learn about
+/// transactions outside its scope.
+///
+/// # Arguments
+///
+/// * `depends_on` - The IDs of the prerequisite transactions.
+/// * `identity` - The API key submitting the transaction.
+/// * `state` - A reference to the application state, wrapped in `ThinData`.
+///
+/// # Returns
+///
+/// An empty `Result`, or an `ApiError` naming the first prerequisite that does not exist.
+pub async fn validate_transaction_dependencies<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    depends_on: &[String],
+    identity: &ApiKeyIdentity,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<(), ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    for id in depends_on {
+        match state.transaction_repository.get_by_id(id.clone()).await {
+            Ok(prerequisite) if identity.can_access_relayer(&prerequisite.relayer_id) => {}
+            Ok(_) | Err(RepositoryError::NotFound(_)) => {
+                return Err(ApiError::BadRequest(format!(
+                    "Prerequisite transaction {} not found",
+                    id
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
+/// Fails a transaction whose prerequisites can no longer be confirmed.
+///
+/// The relayer's notification, if any, is sent with the failed transaction.
+///
+/// # Arguments
+///
+/// * `transaction` - The dependent transaction.
+/// * `reason` - Why the prerequisites cannot be confirmed.
+/// * `state` - A reference to the application state, wrapped in `ThinData`.
+///
+/// # Returns
+///
+/// A `Result` containing the failed `TransactionRepoModel` or an `ApiError`.
+pub async fn fail_dependent_transaction<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    transaction: &TransactionRepoModel,
+    reason: String,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<TransactionRepoModel, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    info!("Failing transaction {}: {}", transaction.id, reason);
+    let failed = state
+        .transaction_repository
+        .partial_update(
+            transaction.id.clone(),
+            TransactionUpdateRequest {
+                status: Some(TransactionStatus::Failed),
+                status_reason: Some(reason),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let relayer = state
+        .relayer_repository
+        .get_by_id(failed.relayer_id.clone())
+        .await?;
+    if let Some(notification_id) = &relayer.notification_id {
+        state
+            .job_producer
+            .produce_send_notification_job(
+                produce_transaction_update_notification_payload(notification_id, &failed),
+                None,
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(e.to_string()))?;
+    }
+
+    Ok(failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        repositories::TransactionRepositoryStorage,
+        utils::mocks::mockutils::{create_mock_app_state, create_mock_relayer},
+    };
+    use actix_web::web::ThinData;
+    use chrono::Duration;
+
+    fn create_transaction(id: &str, status: TransactionStatus) -> TransactionRepoModel {
+        TransactionRepoModel {
+            id: id.to_string(),
+            relayer_id: "test-relayer".to_string(),
+            status,
+            created_at: Utc::now().to_rfc3339(),
+            ..TransactionRepoModel::default()
+        }
+    }
+
+    fn create_dependent(depends_on: &[&str]) -> TransactionRepoModel {
+        TransactionRepoModel {
+            depends_on: Some(depends_on.iter().map(|id| id.to_string()).collect()),
+            ..create_transaction("dependent", TransactionStatus::Pending)
+        }
+    }
+
+    async fn create_repository(
+        transactions: Vec<TransactionRepoModel>,
+    ) -> TransactionRepositoryStorage {
+        let repository = TransactionRepositoryStorage::new_in_memory();
+        for transaction in transactions {
+            repository.create(transaction).await.unwrap();
+        }
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_dependencies_ready() {
+        let repository = create_repository(vec![
+            create_transaction("approve", TransactionStatus::Confirmed),
+            create_transaction("deposit", TransactionStatus::Confirmed),
+        ])
+        .await;
+
+        let state =
+            check_transaction_dependencies(&create_dependent(&["approve", "deposit"]), &repository)
+                .await
+                .unwrap();
+        assert_eq!(state, DependencyState::Ready);
+
+        let independent = create_transaction("independent", TransactionStatus::Pending);
+        let state = check_transaction_dependencies(&independent, &repository)
+            .await
+            .unwrap();
+        assert_eq!(state, DependencyState::Ready);
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_dependencies_waiting() {
+        let repository = create_repository(vec![
+            create_transaction("approve", TransactionStatus::Confirmed),
+            create_transaction("deposit", TransactionStatus::Submitted),
+        ])
+        .await;
+
+        let state =
+            check_transaction_dependencies(&create_dependent(&["approve", "deposit"]), &repository)
+                .await
+                .unwrap();
+        assert_eq!(state, DependencyState::Waiting);
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_dependencies_failed() {
+        let repository = create_repository(vec![
+            create_transaction("approve", TransactionStatus::Pending),
+            create_transaction("deposit", TransactionStatus::Expired),
+        ])
+        .await;
+
+        let state =
+            check_transaction_dependencies(&create_dependent(&["approve", "deposit"]), &repository)
+                .await
+                .unwrap();
+        assert_eq!(
+            state,
+            DependencyState::Failed("Prerequisite transaction deposit is expired".to_string())
+        );
+
+        let state = check_transaction_dependencies(&create_dependent(&["missing"]), &repository)
+            .await
+            .unwrap();
+        assert_eq!(
+            state,
+            DependencyState::Failed("Prerequisite transaction missing was not found".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_transaction_dependencies_wait_expired() {
+        let repository = create_repository(vec![create_transaction(
+            "approve",
+            TransactionStatus::Submitted,
+        )])
+        .await;
+        let expired = "Prerequisite transactions were not confirmed in time".to_string();
+
+        let stale = TransactionRepoModel {
+            created_at: (Utc::now()
+                - Duration::seconds(MAX_TRANSACTION_DEPENDENCY_WAIT_SECONDS + 60))
+            .to_rfc3339(),
+            ..create_dependent(&["approve"])
+        };
+        let state = check_transaction_dependencies(&stale, &repository)
+            .await
+            .unwrap();
+        assert_eq!(state, DependencyState::Failed(expired.clone()));
+
+        let past_valid_until = TransactionRepoModel {
+            valid_until: Some((Utc::now() - Duration::seconds(60)).to_rfc3339()),
+            ..create_dependent(&["approve"])
+        };
+        let state = check_transaction_dependencies(&past_valid_until, &repository)
+            .await
+            .unwrap();
+        assert_eq!(state, DependencyState::Failed(expired));
+
+        // A later valid_until extends the wait beyond the default
+        let long_lived = TransactionRepoModel {
+            valid_until: Some((Utc::now() + Duration::seconds(60)).to_rfc3339()),
+            ..stale
+        };
+        let state = check_transaction_dependencies(&long_lived, &repository)
+            .await
+            .unwrap();
+        assert_eq!(state, DependencyState::Waiting);
+    }
+
+    #[tokio::test]
+    async fn test_validate_transaction_dependencies() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            None,
+            None,
+            Some(vec![create_transaction(
+                "approve",
+                TransactionStatus::Pending,
+            )]),
+        )
+        .await;
+        let state = ThinData(app_state);
+
+        let identity = ApiKeyIdentity::root();
+
+        assert!(
+            validate_transaction_dependencies(&["approve".to_string()], &identity, &state)
+                .await
+                .is_ok()
+        );
+        let result =
+            validate_transaction_dependencies(&["missing".to_string()], &identity, &state).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_validate_transaction_dependencies_outside_key_scope() {
+        let app_state = create_mock_app_state(
+            None,
+            None,
+            None,
+            None,
+            Some(vec![create_transaction(
+                "approve",
+                TransactionStatus::Pending,
+            )]),
+        )
+        .await;
+        let state = ThinData(app_state);
+        let scoped = |relayer_id: &str| ApiKeyIdentity {
+            id: "scoped-key".to_string(),
+            scopes: vec![],
+            allowed_relayers: vec![relayer_id.to_string()],
+        };
+
+        assert!(validate_transaction_dependencies(
+            &["approve".to_string()],
+            &scoped("test-relayer"),
+            &state
+        )
+        .await
+        .is_ok());
+        let result = validate_transaction_dependencies(
+            &["approve".to_string()],
+            &scoped("other-relayer"),
+            &state,
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::BadRequest(msg)) if msg.contains("not found")));
+    }
+
+    #[tokio::test]
+    async fn test_fail_dependent_transaction() {
+        let relayer = create_mock_relayer("test-relayer".to_string(), false);
+        let dependent = create_dependent(&["approve"]);
+        let app_state = create_mock_app_state(
+            Some(vec![relayer]),
+            None,
+            None,
+            None,
+            Some(vec![dependent.clone()]),
+        )
+        .await;
+        let state = ThinData(app_state);
+
+        let failed = fail_dependent_transaction(
+            &dependent,
+            "Prerequisite transaction approve is canceled".to_string(),
+            &state,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(failed.status, TransactionStatus::Failed);
+        assert_eq!(
+            failed.status_reason,
+            Some("Prerequisite transaction approve is canceled".to_string())
+        );
+    }
+}
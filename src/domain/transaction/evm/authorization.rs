@@ -0,0 +1,166 @@
+//! EIP-7702 authorization handling for set-code transactions.
+//!
+//! Authorizations that name a `signer_id` are signed by that signer before the transaction
+//! is created, so only fully signed authorizations reach the transaction repository. A signer
+//! may only be used when the relayer's `authorization_signers` policy lists it or the caller's
+//! API key has unrestricted admin access.
+use std::str::FromStr;
+
+use alloy::{eips::eip7702::Authorization, primitives::Address as AlloyAddress};
+
+use crate::{
+    jobs::JobProducerTrait,
+    models::{
+        ApiError, ApiKeyIdentity, ApiKeyScope, EvmAuthorizationRequest, EvmNetwork,
+        NetworkRepoModel, NetworkTransactionRequest, NetworkType, NotificationRepoModel,
+        RelayerError, RelayerPoolRepoModel, RelayerRepoModel, SignerRepoModel, ThinDataAppState,
+        TransactionRepoModel, U256,
+    },
+    repositories::{
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        TransactionCounterTrait, TransactionRepository,
+    },
+    services::{
+        get_network_provider, AuthorizationSignerTrait, EvmProviderTrait, EvmSignerFactory, Signer,
+    },
+};
+
+/// Completes the authorization list of an EVM transaction request.
+///
+/// Authorizations without a chain ID are bound to the relayer's network. Authorizations with a
+/// `signer_id` are signed by that signer, using the authority's current account nonce when none
+/// is given. Requests without an authorization list are left untouched.
+///
+/// # Arguments
+///
+/// * `relayer` - The relayer the transaction is submitted to.
+/// * `request` - The transaction request to complete.
+/// * `identity` - The API key submitting the transaction.
+/// * `state` - A reference to the application state, wrapped in `ThinData`.
+///
+/// # Returns
+///
+/// `Ok(())` once every authorization is signed, or an `ApiError` if one cannot be signed.
+pub async fn sign_evm_authorizations<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer: &RelayerRepoModel,
+    request: &mut NetworkTransactionRequest,
+    identity: &ApiKeyIdentity,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<(), ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let NetworkTransactionRequest::Evm(evm_request) = request else {
+        return Ok(());
+    };
+    let Some(authorization_list) = evm_request.authorization_list.as_mut() else {
+        return Ok(());
+    };
+    if authorization_list
+        .iter()
+        .all(|authorization| authorization.is_signed() && authorization.chain_id.is_some())
+    {
+        return Ok(());
+    }
+
+    let network_model = state
+        .network_repository
+        .get_by_name(NetworkType::Evm, &relayer.network)
+        .await?
+        .ok_or_else(|| {
+            RelayerError::NetworkConfiguration(format!("Network {} not found", relayer.network))
+        })?;
+    let network = EvmNetwork::try_from(network_model)?;
+
+    for authorization in authorization_list.iter_mut() {
+        let chain_id = *authorization.chain_id.get_or_insert(network.id());
+
+        let Some(signer_id) = authorization.signer_id.clone() else {
+            continue;
+        };
+        if authorization.is_signed() {
+            continue;
+        }
+        if signer_id == relayer.signer_id {
+            return Err(ApiError::BadRequest(
+                "Authorizations cannot be signed with the relayer's own signer".to_string(),
+            ));
+        }
+        if !may_sign_authorizations(relayer, &signer_id, identity) {
+            return Err(ApiError::ForbiddenError(format!(
+                "Signer {} may not sign authorizations for relayer {}",
+                signer_id, relayer.id
+            )));
+        }
+
+        let signer_model = state.signer_repository.get_by_id(signer_id).await?;
+        let signer = EvmSignerFactory::create_evm_signer(signer_model.into())
+            .await
+            .map_err(RelayerError::from)?;
+
+        let nonce = match authorization.nonce {
+            Some(nonce) => nonce,
+            None => {
+                let authority = signer.address().await.map_err(RelayerError::from)?;
+                let provider = get_network_provider(&network, relayer.custom_rpc_urls.clone())
+                    .map_err(RelayerError::from)?;
+                provider
+                    .get_transaction_count(&authority.to_string())
+                    .await
+                    .map_err(RelayerError::from)?
+            }
+        };
+
+        let signed = signer
+            .sign_authorization(Authorization {
+                chain_id: U256::from(chain_id),
+                address: parse_authorization_address(authorization)?,
+                nonce,
+            })
+            .await
+            .map_err(RelayerError::from)?;
+
+        authorization.nonce = Some(nonce);
+        authorization.y_parity = Some(signed.y_parity());
+        authorization.r = Some(format!("0x{:064x}", signed.r()));
+        authorization.s = Some(format!("0x{:064x}", signed.s()));
+    }
+
+    Ok(())
+}
+
+/// Returns true if the signer is listed in the relayer's `authorization_signers` policy, or
+/// the identity is an unrestricted admin key that already controls every signer.
+fn may_sign_authorizations(
+    relayer: &RelayerRepoModel,
+    signer_id: &str,
+    identity: &ApiKeyIdentity,
+) -> bool {
+    let listed = relayer
+        .policies
+        .get_evm_policy()
+        .authorization_signers
+        .is_some_and(|signers| signers.iter().any(|id| id == signer_id));
+
+    listed || (identity.has_scope(ApiKeyScope::Admin) && identity.is_unrestricted())
+}
+
+fn parse_authorization_address(
+    authorization: &EvmAuthorizationRequest,
+) -> Result<AlloyAddress, ApiError> {
+    AlloyAddress::from_str(&authorization.address).map_err(|_| {
+        ApiError::BadRequest(format!(
+            "Invalid authorization address: {}",
+            authorization.address
+        ))
+    })
+}
@@ -4,6 +4,7 @@
 //! managing notifications for transactions. The module leverages various
 //! services and repositories to perform these operations asynchronously.
 
+use alloy::rpc::types::{BlockNumberOrTag, TransactionRequest as CallRequest};
 use async_trait::async_trait;
 use chrono::Utc;
 use eyre::Result;
@@ -11,20 +12,29 @@ use log::{debug, error, info, warn};
 use std::sync::Arc;
 
 use crate::{
-    constants::{DEFAULT_EVM_GAS_LIMIT_ESTIMATION, GAS_LIMIT_BUFFER_MULTIPLIER},
+    constants::{
+        DEFAULT_EVM_ACCESS_LIST_GENERATION, DEFAULT_EVM_GAS_LIMIT_ESTIMATION,
+        DEFAULT_EVM_SIMULATE_TRANSACTIONS, DEFAULT_TRANSACTION_SPEED, GAS_LIMIT_BUFFER_MULTIPLIER,
+    },
     domain::{
         transaction::{
-            evm::{is_pending_transaction, PriceCalculator, PriceCalculatorTrait},
+            evm::{
+                decode_revert_reason, is_noop, is_pending_transaction, make_noop, PriceCalculator,
+                PriceCalculatorTrait,
+            },
             Transaction,
         },
         EvmTransactionValidator,
     },
-    jobs::{JobProducer, JobProducerTrait, TransactionSend, TransactionStatusCheck},
+    jobs::{
+        JobProducer, JobProducerTrait, TransactionRequest, TransactionSend, TransactionStatusCheck,
+    },
     models::{
-        produce_transaction_update_notification_payload, EvmNetwork, EvmTransactionData,
-        NetworkRepoModel, NetworkTransactionData, NetworkTransactionRequest, NetworkType,
-        RelayerEvmPolicy, RelayerRepoModel, TransactionError, TransactionRepoModel,
-        TransactionStatus, TransactionUpdateRequest,
+        produce_spending_limit_exceeded_payload, produce_transaction_update_notification_payload,
+        EvmAccessListItem, EvmNetwork, EvmTransactionData, EvmTransactionRequest, NetworkRepoModel,
+        NetworkTransactionData, NetworkTransactionRequest, NetworkType, RelayerEvmPolicy,
+        RelayerRepoModel, RelayerSpend, TransactionError, TransactionRepoModel, TransactionStatus,
+        TransactionUpdateRequest, U256,
     },
     repositories::{
         NetworkRepository, NetworkRepositoryStorage, RelayerRepository, RelayerRepositoryStorage,
@@ -32,7 +42,8 @@ use crate::{
         TransactionRepository, TransactionRepositoryStorage,
     },
     services::{
-        gas::evm_gas_price::EvmGasPriceService, EvmProvider, EvmProviderTrait, EvmSigner, Signer,
+        gas::evm_gas_price::EvmGasPriceService, EvmProvider, EvmProviderTrait, EvmSigner,
+        PrivateRelayService, ProviderError, Signer,
     },
     utils::get_evm_default_gas_limit_for_tx,
 };
@@ -60,6 +71,7 @@ where
     relayer: RelayerRepoModel,
     transaction_counter_service: Arc<TCR>,
     price_calculator: PC,
+    private_relay: Option<PrivateRelayService>,
 }
 
 #[allow(dead_code, clippy::too_many_arguments)]
@@ -111,9 +123,56 @@ where
             job_producer,
             price_calculator,
             signer,
+            private_relay: None,
         })
     }
 
+    /// Sets the private relay used to submit transactions when the relayer policy has
+    /// `private_transactions` enabled.
+    pub fn with_private_relay(mut self, private_relay: PrivateRelayService) -> Self {
+        self.private_relay = Some(private_relay);
+        self
+    }
+
+    /// Returns the private relay, if one is configured.
+    pub fn private_relay(&self) -> Option<&PrivateRelayService> {
+        self.private_relay.as_ref()
+    }
+
+    /// Returns true if the relayer policy requires transactions to bypass the public mempool.
+    pub fn uses_private_transactions(&self) -> bool {
+        self.relayer
+            .policies
+            .get_evm_policy()
+            .private_transactions
+            .unwrap_or(false)
+    }
+
+    /// Broadcasts a signed raw transaction.
+    ///
+    /// Relayers with `private_transactions` enabled submit through the network's private relay
+    /// and never fall back to the public mempool.
+    pub async fn broadcast_raw_transaction(&self, raw_tx: &[u8]) -> Result<(), TransactionError> {
+        if !self.uses_private_transactions() {
+            self.provider.send_raw_transaction(raw_tx).await?;
+            return Ok(());
+        }
+
+        let private_relay = self.private_relay.as_ref().ok_or_else(|| {
+            TransactionError::NetworkConfiguration(
+                "Private transactions are enabled but the network has no private relay configured"
+                    .to_string(),
+            )
+        })?;
+
+        let current_block = self.provider.get_block_number().await?;
+        private_relay
+            .send_raw_transaction(raw_tx, current_block)
+            .await?;
+
+        Ok(())
+    }
+
     /// Returns a reference to the provider.
     pub fn provider(&self) -> &P {
         &self.provider
@@ -242,10 +301,7 @@ where
     /// # Returns
     ///
     /// A `Result` indicating success or a `TransactionError` if insufficient balance.
-    async fn ensure_sufficient_balance(
-        &self,
-        total_cost: crate::models::U256,
-    ) -> Result<(), TransactionError> {
+    async fn ensure_sufficient_balance(&self, total_cost: U256) -> Result<(), TransactionError> {
         EvmTransactionValidator::validate_sufficient_relayer_balance(
             total_cost,
             &self.relayer().address,
@@ -258,108 +314,236 @@ where
         })
     }
 
-    /// Estimates the gas limit for a transaction.
-    ///
-    /// # Arguments
-    ///
-    /// * `evm_data` - The EVM transaction data.
-    /// * `relayer_policy` - The relayer policy.
+    /// Returns what a transaction spends on value and fees, in wei.
+    fn transaction_spend(value: U256, total_cost: U256) -> RelayerSpend {
+        RelayerSpend {
+            value: u128::try_from(value).unwrap_or(u128::MAX),
+            gas: u128::try_from(total_cost.saturating_sub(value)).unwrap_or(u128::MAX),
+        }
+    }
+
+    /// Records the transaction's spend if it fits the relayer's rolling window budgets.
     ///
-    async fn estimate_tx_gas_limit(
+    /// The check and the record are a single repository operation, so transactions priced
+    /// concurrently on other instances always count against each other. Recording a
+    /// transaction again replaces its earlier spend, so a replacement only counts the
+    /// difference.
+    async fn enforce_spending_limits(
         &self,
-        evm_data: &EvmTransactionData,
-        relayer_policy: &RelayerEvmPolicy,
-    ) -> Result<u64, TransactionError> {
-        if !relayer_policy
-            .gas_limit_estimation
-            .unwrap_or(DEFAULT_EVM_GAS_LIMIT_ESTIMATION)
-        {
-            warn!(
-                "Gas limit estimation is disabled for relayer: {:?}",
-                self.relayer().id
-            );
-            return Err(TransactionError::UnexpectedError(
-                "Gas limit estimation is disabled".to_string(),
-            ));
+        tx_id: &str,
+        value: U256,
+        total_cost: U256,
+    ) -> Result<(), TransactionError> {
+        let policy = self.relayer().policies.get_evm_policy();
+        if !policy.has_spending_window_limits() {
+            return Ok(());
         }
 
-        let estimated_gas = self.provider.estimate_gas(evm_data).await.map_err(|e| {
-            warn!("Failed to estimate gas: {:?} for tx: {:?}", e, evm_data);
-            TransactionError::UnexpectedError(format!("Failed to estimate gas: {}", e))
-        })?;
+        let window = policy.spending_window.unwrap_or_default();
+        let now = Utc::now().timestamp();
+        let exceeded = self
+            .relayer_repository
+            .record_spend_within_limit(
+                &self.relayer().id,
+                tx_id,
+                Self::transaction_spend(value, total_cost),
+                now,
+                now - window.duration_seconds(),
+                policy.spend_limit(),
+            )
+            .await?;
 
-        Ok(estimated_gas * GAS_LIMIT_BUFFER_MULTIPLIER / 100)
+        let Some(total) = exceeded else {
+            return Ok(());
+        };
+        let reason = match (policy.max_total_value, policy.max_gas_spend) {
+            (Some(max_total_value), _) if total.value > max_total_value => format!(
+                "Total value {} in the last {:?} window exceeds the limit of {}",
+                total.value, window, max_total_value
+            ),
+            (_, max_gas_spend) => format!(
+                "Gas spend {} in the last {:?} window exceeds the limit of {}",
+                total.gas,
+                window,
+                max_gas_spend.unwrap_or_default()
+            ),
+        };
+        Err(TransactionError::SpendingLimitExceeded(reason))
     }
-}
 
-#[async_trait]
-impl<P, RR, NR, TR, J, S, TCR, PC> Transaction
-    for EvmRelayerTransaction<P, RR, NR, TR, J, S, TCR, PC>
-where
-    P: EvmProviderTrait + Send + Sync + 'static,
-    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
-    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
-    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
-    J: JobProducerTrait + Send + Sync + 'static,
-    S: Signer + Send + Sync + 'static,
-    TCR: TransactionCounterTrait + Send + Sync + 'static,
-    PC: PriceCalculatorTrait + Send + Sync + 'static,
-{
-    /// Prepares a transaction for submission.
-    ///
-    /// # Arguments
+    /// Re-records the spend of a repriced transaction without checking the budgets.
     ///
-    /// * `tx` - The transaction model to prepare.
-    ///
-    /// # Returns
-    ///
-    /// A result containing the updated transaction model or a `TransactionError`.
-    async fn prepare_transaction(
+    /// Fee bumps of transactions already in flight must not be held back, but still count
+    /// against the budgets of later transactions.
+    async fn rerecord_spend(
         &self,
-        tx: TransactionRepoModel,
-    ) -> Result<TransactionRepoModel, TransactionError> {
-        info!("Preparing transaction: {:?}", tx.id);
+        tx_id: &str,
+        value: U256,
+        total_cost: U256,
+    ) -> Result<(), TransactionError> {
+        let policy = self.relayer().policies.get_evm_policy();
+        if !policy.has_spending_window_limits() {
+            return Ok(());
+        }
 
-        let mut evm_data = tx.network_data.get_evm_transaction_data()?;
-        let relayer = self.relayer();
+        self.relayer_repository
+            .record_spend(
+                &self.relayer().id,
+                tx_id,
+                Self::transaction_spend(value, total_cost),
+                Utc::now().timestamp(),
+            )
+            .await?;
+        Ok(())
+    }
 
-        if evm_data.gas_limit.is_none() {
-            match self
-                .estimate_tx_gas_limit(&evm_data, &relayer.policies.get_evm_policy())
-                .await
-            {
-                Ok(estimated_gas_limit) => {
-                    evm_data.gas_limit = Some(estimated_gas_limit);
-                }
-                Err(estimation_error) => {
-                    error!(
-                        "Failed to estimate gas limit for tx: {} : {:?}",
-                        tx.id, estimation_error
-                    );
+    /// Removes the spend recorded for a transaction that will never be mined, so it stops
+    /// counting against the rolling window budgets.
+    pub(super) async fn release_spend(&self, tx_id: &str) {
+        if !self
+            .relayer()
+            .policies
+            .get_evm_policy()
+            .has_spending_window_limits()
+        {
+            return;
+        }
 
-                    let default_gas_limit = get_evm_default_gas_limit_for_tx(&evm_data);
-                    info!(
-                        "Fallback to default gas limit: {} for tx: {}",
-                        default_gas_limit, tx.id
-                    );
-                    evm_data.gas_limit = Some(default_gas_limit);
-                }
-            }
+        if let Err(e) = self
+            .relayer_repository
+            .remove_spend(&self.relayer().id, tx_id)
+            .await
+        {
+            warn!("Failed to release spend of transaction {}: {}", tx_id, e);
         }
+    }
 
-        // set the gas price
-        let price_params: PriceParams = self
-            .price_calculator
-            .get_transaction_price_params(&evm_data, relayer)
-            .await?;
+    /// Hands back the nonce reserved for a transaction that failed before it was sent.
+    ///
+    /// The counter is only rolled back if it still points right after the nonce; otherwise
+    /// the nonce is filled with a NOOP self-transfer so later transactions are not stuck
+    /// behind it.
+    async fn release_reserved_nonce(&self, tx: &TransactionRepoModel, reserved_nonce: Option<u64>) {
+        let Some(nonce) = reserved_nonce else {
+            return;
+        };
 
-        debug!("Gas price: {:?}", price_params.gas_price);
-        // increment the nonce
-        let nonce = self
+        match self
             .transaction_counter_service
-            .get_and_increment(&self.relayer.id, &self.relayer.address)
+            .compare_and_set(&self.relayer.id, &self.relayer.address, nonce + 1, nonce)
             .await
-            .map_err(|e| TransactionError::UnexpectedError(e.to_string()))?;
+        {
+            Ok(true) => return,
+            Ok(false) => {}
+            Err(e) => warn!(
+                "Failed to release nonce {} of transaction {}: {}",
+                nonce, tx.id, e
+            ),
+        }
+
+        if let Err(e) = self.fill_reserved_nonce(tx, nonce).await {
+            warn!(
+                "Failed to fill nonce {} of transaction {}: {}",
+                nonce, tx.id, e
+            );
+        }
+    }
+
+    /// Creates a NOOP self-transfer holding the nonce of a failed transaction and queues it.
+    async fn fill_reserved_nonce(
+        &self,
+        tx: &TransactionRepoModel,
+        nonce: u64,
+    ) -> Result<(), TransactionError> {
+        let evm_data = tx.network_data.get_evm_transaction_data()?;
+        // A NOOP that failed is left to the nonce gap repair, so failures do not cascade
+        if is_noop(&evm_data) {
+            warn!(
+                "NOOP transaction {} failed, leaving nonce {} to the nonce gap repair",
+                tx.id, nonce
+            );
+            return Ok(());
+        }
+
+        let network_model = self
+            .network_repository()
+            .get_by_chain_id(NetworkType::Evm, evm_data.chain_id)
+            .await?
+            .ok_or_else(|| {
+                TransactionError::NetworkConfiguration(format!(
+                    "Network with chain_id {} not found",
+                    evm_data.chain_id
+                ))
+            })?;
+
+        let network = EvmNetwork::try_from(network_model.clone()).map_err(|e| {
+            TransactionError::NetworkConfiguration(format!(
+                "Failed to convert network model: {}",
+                e
+            ))
+        })?;
+
+        let request = NetworkTransactionRequest::Evm(EvmTransactionRequest {
+            to: Some(self.relayer.address.clone()),
+            value: U256::ZERO,
+            data: None,
+            gas_limit: None,
+            gas_price: None,
+            speed: Some(DEFAULT_TRANSACTION_SPEED),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        });
+
+        let mut noop_tx =
+            TransactionRepoModel::try_from((&request, self.relayer(), &network_model))
+                .map_err(|e| TransactionError::UnexpectedError(e.to_string()))?;
+        if let NetworkTransactionData::Evm(noop_data) = &mut noop_tx.network_data {
+            make_noop(noop_data, &network, Some(self.provider())).await?;
+            noop_data.nonce = Some(nonce);
+        }
+        noop_tx.noop_count = Some(1);
+
+        info!(
+            "Filling nonce {} of failed transaction {} with NOOP transaction {}",
+            nonce, tx.id, noop_tx.id
+        );
+
+        self.transaction_repository.create(noop_tx.clone()).await?;
+        self.job_producer
+            .produce_transaction_request_job(
+                TransactionRequest::new(noop_tx.id.clone(), noop_tx.relayer_id.clone()),
+                None,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Signs a priced transaction, checks the relayer can pay for it and queues its submission.
+    async fn sign_priced_transaction(
+        &self,
+        tx: TransactionRepoModel,
+        evm_data: EvmTransactionData,
+        price_params: PriceParams,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        // Use the nonce reserved at submission (batch requests), otherwise increment the nonce
+        let reserved_nonce = evm_data.nonce;
+        let nonce = match reserved_nonce {
+            Some(nonce) => nonce,
+            None => self
+                .transaction_counter_service
+                .get_and_increment(&self.relayer.id, &self.relayer.address)
+                .await
+                .map_err(|e| TransactionError::UnexpectedError(e.to_string()))?,
+        };
 
         let updated_evm_data = evm_data
             .with_price_params(price_params.clone())
@@ -397,6 +581,8 @@ where
                     .partial_update(tx.id.clone(), update)
                     .await?;
 
+                self.release_reserved_nonce(&updated_tx, reserved_nonce)
+                    .await;
                 let _ = self.send_transaction_update_notification(&updated_tx).await;
                 return Err(balance_error);
             }
@@ -430,60 +616,382 @@ where
             )
             .await?;
 
-        self.send_transaction_update_notification(&updated_tx)
-            .await?;
-
         Ok(updated_tx)
     }
 
-    /// Submits a transaction for processing.
+    /// Sends a spending limit notification if a notification ID is configured.
+    async fn send_spending_limit_exceeded_notification(
+        &self,
+        tx: &TransactionRepoModel,
+        reason: &str,
+    ) -> Result<(), TransactionError> {
+        if let Some(notification_id) = &self.relayer().notification_id {
+            self.job_producer()
+                .produce_send_notification_job(
+                    produce_spending_limit_exceeded_payload(notification_id, tx, reason),
+                    None,
+                )
+                .await
+                .map_err(|e| {
+                    TransactionError::UnexpectedError(format!("Failed to send notification: {}", e))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Estimates the gas limit for a transaction.
     ///
     /// # Arguments
     ///
-    /// * `tx` - The transaction model to submit.
-    ///
-    /// # Returns
+    /// * `evm_data` - The EVM transaction data.
+    /// * `relayer_policy` - The relayer policy.
     ///
-    /// A result containing the updated transaction model or a `TransactionError`.
-    async fn submit_transaction(
+    async fn estimate_tx_gas_limit(
         &self,
-        tx: TransactionRepoModel,
-    ) -> Result<TransactionRepoModel, TransactionError> {
-        info!("submitting transaction for tx: {:?}", tx.id);
+        evm_data: &EvmTransactionData,
+        relayer_policy: &RelayerEvmPolicy,
+    ) -> Result<u64, TransactionError> {
+        if !relayer_policy
+            .gas_limit_estimation
+            .unwrap_or(DEFAULT_EVM_GAS_LIMIT_ESTIMATION)
+        {
+            warn!(
+                "Gas limit estimation is disabled for relayer: {:?}",
+                self.relayer().id
+            );
+            return Err(TransactionError::UnexpectedError(
+                "Gas limit estimation is disabled".to_string(),
+            ));
+        }
 
-        let evm_tx_data = tx.network_data.get_evm_transaction_data()?;
-        let raw_tx = evm_tx_data.raw.as_ref().ok_or_else(|| {
-            TransactionError::InvalidType("Raw transaction data is missing".to_string())
+        let estimated_gas = self.provider.estimate_gas(evm_data).await.map_err(|e| {
+            warn!("Failed to estimate gas: {:?} for tx: {:?}", e, evm_data);
+            TransactionError::UnexpectedError(format!("Failed to estimate gas: {}", e))
         })?;
 
-        self.provider.send_raw_transaction(raw_tx).await?;
-
-        let update = TransactionUpdateRequest {
-            status: Some(TransactionStatus::Submitted),
-            sent_at: Some(Utc::now().to_rfc3339()),
-            ..Default::default()
-        };
-
-        let updated_tx = self
-            .transaction_repository
-            .partial_update(tx.id.clone(), update)
-            .await?;
-
-        // Schedule status check
-        self.job_producer
-            .produce_check_transaction_status_job(
-                TransactionStatusCheck::new(updated_tx.id.clone(), updated_tx.relayer_id.clone()),
-                None,
-            )
-            .await?;
-
-        self.send_transaction_update_notification(&updated_tx)
-            .await?;
-
-        Ok(updated_tx)
+        Ok(estimated_gas * GAS_LIMIT_BUFFER_MULTIPLIER / 100)
     }
 
-    /// Handles the status of a transaction.
+    /// Simulates a transaction against the pending block.
+    ///
+    /// Pricing and nonce are left out of the call so only the execution is checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `evm_data` - The EVM transaction data.
+    ///
+    /// # Returns
+    ///
+    /// The decoded revert reason if the transaction would revert, `None` otherwise.
+    async fn simulate_transaction(
+        &self,
+        evm_data: &EvmTransactionData,
+    ) -> Result<Option<String>, TransactionError> {
+        let mut call = CallRequest::try_from(evm_data)?;
+        call.nonce = None;
+        call.gas_price = None;
+        call.max_fee_per_gas = None;
+        call.max_priority_fee_per_gas = None;
+
+        match self
+            .provider
+            .call_contract(&call, BlockNumberOrTag::Pending)
+            .await
+        {
+            Ok(_) => Ok(None),
+            Err(ProviderError::Reverted { message, data }) => Ok(Some(match data {
+                Some(data) => decode_revert_reason(&data),
+                None => message,
+            })),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Generates an access list for a transaction with `eth_createAccessList`.
+    ///
+    /// The access list is only kept if attaching it lowers the gas estimate of the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `evm_data` - The EVM transaction data.
+    ///
+    /// # Returns
+    ///
+    /// The access list and the gas estimate with it attached, or `None` if it does not save gas.
+    async fn generate_access_list(
+        &self,
+        evm_data: &EvmTransactionData,
+    ) -> Result<Option<(Vec<EvmAccessListItem>, u64)>, TransactionError> {
+        let mut call = CallRequest::try_from(evm_data)?;
+        call.nonce = None;
+        call.gas_price = None;
+        call.max_fee_per_gas = None;
+        call.max_priority_fee_per_gas = None;
+
+        let result = self
+            .provider
+            .create_access_list(&call, BlockNumberOrTag::Pending)
+            .await?;
+        if result.error.is_some() || result.access_list.is_empty() {
+            return Ok(None);
+        }
+        let access_list: Vec<EvmAccessListItem> = result
+            .access_list
+            .iter()
+            .map(EvmAccessListItem::from)
+            .collect();
+
+        let estimate_without = self.provider.estimate_gas(evm_data).await?;
+        let estimate_with = self
+            .provider
+            .estimate_gas(&EvmTransactionData {
+                access_list: Some(access_list.clone()),
+                ..evm_data.clone()
+            })
+            .await?;
+
+        Ok((estimate_with < estimate_without).then_some((access_list, estimate_with)))
+    }
+}
+
+#[async_trait]
+impl<P, RR, NR, TR, J, S, TCR, PC> Transaction
+    for EvmRelayerTransaction<P, RR, NR, TR, J, S, TCR, PC>
+where
+    P: EvmProviderTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    J: JobProducerTrait + Send + Sync + 'static,
+    S: Signer + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PC: PriceCalculatorTrait + Send + Sync + 'static,
+{
+    /// Prepares a transaction for submission.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The transaction model to prepare.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the updated transaction model or a `TransactionError`.
+    async fn prepare_transaction(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        info!("Preparing transaction: {:?}", tx.id);
+
+        // A user operation bundle is sealed before it is read, so no operation is added to
+        // it while it is being signed
+        let tx = if tx
+            .network_data
+            .get_evm_transaction_data()?
+            .user_operation_hashes
+            .is_some()
+        {
+            self.transaction_repository
+                .seal_bundle(tx.id.clone())
+                .await?;
+            self.transaction_repository.get_by_id(tx.id.clone()).await?
+        } else {
+            tx
+        };
+
+        let mut evm_data = tx.network_data.get_evm_transaction_data()?;
+        let relayer = self.relayer();
+
+        let generate_access_list = relayer
+            .policies
+            .get_evm_policy()
+            .access_list_generation
+            .unwrap_or(DEFAULT_EVM_ACCESS_LIST_GENERATION);
+        if generate_access_list
+            && evm_data.access_list.is_none()
+            && evm_data.data_to_bytes().is_ok_and(|data| !data.is_empty())
+        {
+            match self.generate_access_list(&evm_data).await {
+                Ok(Some((access_list, gas_estimate))) => {
+                    info!(
+                        "Attaching access list with {} entries to tx: {}",
+                        access_list.len(),
+                        tx.id
+                    );
+                    evm_data.access_list = Some(access_list);
+                    if evm_data.gas_limit.is_none() {
+                        evm_data.gas_limit = Some(gas_estimate * GAS_LIMIT_BUFFER_MULTIPLIER / 100);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!("Failed to generate access list for tx: {} : {:?}", tx.id, e);
+                }
+            }
+        }
+
+        if evm_data.gas_limit.is_none() {
+            match self
+                .estimate_tx_gas_limit(&evm_data, &relayer.policies.get_evm_policy())
+                .await
+            {
+                Ok(estimated_gas_limit) => {
+                    evm_data.gas_limit = Some(estimated_gas_limit);
+                }
+                Err(estimation_error) => {
+                    error!(
+                        "Failed to estimate gas limit for tx: {} : {:?}",
+                        tx.id, estimation_error
+                    );
+
+                    let default_gas_limit = get_evm_default_gas_limit_for_tx(&evm_data);
+                    info!(
+                        "Fallback to default gas limit: {} for tx: {}",
+                        default_gas_limit, tx.id
+                    );
+                    evm_data.gas_limit = Some(default_gas_limit);
+                }
+            }
+        }
+
+        // Simulate before a nonce is consumed so a reverting transaction is never sent
+        let simulate = evm_data
+            .simulate
+            .or(relayer.policies.get_evm_policy().simulate_transactions)
+            .unwrap_or(DEFAULT_EVM_SIMULATE_TRANSACTIONS);
+        if simulate {
+            if let Some(revert_reason) = self.simulate_transaction(&evm_data).await? {
+                info!(
+                    "Simulation of transaction {} reverted: {}",
+                    tx.id, revert_reason
+                );
+
+                let update = TransactionUpdateRequest {
+                    status: Some(TransactionStatus::Failed),
+                    status_reason: Some(revert_reason),
+                    ..Default::default()
+                };
+
+                let updated_tx = self
+                    .transaction_repository
+                    .partial_update(tx.id.clone(), update)
+                    .await?;
+
+                self.release_reserved_nonce(&updated_tx, evm_data.nonce)
+                    .await;
+                let _ = self.send_transaction_update_notification(&updated_tx).await;
+                return Ok(updated_tx);
+            }
+        }
+
+        // set the gas price
+        let price_params: PriceParams = self
+            .price_calculator
+            .get_transaction_price_params(&evm_data, relayer)
+            .await?;
+
+        debug!("Gas price: {:?}", price_params.gas_price);
+
+        // Check the rolling window budgets before a nonce is consumed
+        match self
+            .enforce_spending_limits(&tx.id, evm_data.value, price_params.total_cost)
+            .await
+        {
+            Ok(()) => {}
+            Err(TransactionError::SpendingLimitExceeded(reason)) => {
+                info!(
+                    "Spending limit exceeded for transaction {}: {}",
+                    tx.id, reason
+                );
+
+                let update = TransactionUpdateRequest {
+                    status: Some(TransactionStatus::Failed),
+                    status_reason: Some(reason.clone()),
+                    ..Default::default()
+                };
+
+                let updated_tx = self
+                    .transaction_repository
+                    .partial_update(tx.id.clone(), update)
+                    .await?;
+
+                self.release_reserved_nonce(&updated_tx, evm_data.nonce)
+                    .await;
+                let _ = self.send_transaction_update_notification(&updated_tx).await;
+                let _ = self
+                    .send_spending_limit_exceeded_notification(&updated_tx, &reason)
+                    .await;
+                return Err(TransactionError::SpendingLimitExceeded(reason));
+            }
+            Err(e) => return Err(e),
+        }
+
+        // The transaction will not be sent, so its spend no longer counts against the budgets
+        let tx_id = tx.id.clone();
+        let updated_tx = match self
+            .sign_priced_transaction(tx, evm_data, price_params)
+            .await
+        {
+            Ok(updated_tx) => updated_tx,
+            Err(e) => {
+                self.release_spend(&tx_id).await;
+                return Err(e);
+            }
+        };
+
+        self.send_transaction_update_notification(&updated_tx)
+            .await?;
+
+        Ok(updated_tx)
+    }
+
+    /// Submits a transaction for processing.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The transaction model to submit.
+    ///
+    /// # Returns
+    ///
+    /// A result containing the updated transaction model or a `TransactionError`.
+    async fn submit_transaction(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        info!("submitting transaction for tx: {:?}", tx.id);
+
+        let evm_tx_data = tx.network_data.get_evm_transaction_data()?;
+        let raw_tx = evm_tx_data.raw.as_ref().ok_or_else(|| {
+            TransactionError::InvalidType("Raw transaction data is missing".to_string())
+        })?;
+
+        self.broadcast_raw_transaction(raw_tx).await?;
+
+        let update = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Submitted),
+            sent_at: Some(Utc::now().to_rfc3339()),
+            ..Default::default()
+        };
+
+        let updated_tx = self
+            .transaction_repository
+            .partial_update(tx.id.clone(), update)
+            .await?;
+
+        // Schedule status check
+        self.job_producer
+            .produce_check_transaction_status_job(
+                TransactionStatusCheck::new(updated_tx.id.clone(), updated_tx.relayer_id.clone()),
+                None,
+            )
+            .await?;
+
+        self.send_transaction_update_notification(&updated_tx)
+            .await?;
+
+        Ok(updated_tx)
+    }
+
+    /// Handles the status of a transaction.
     ///
     /// # Arguments
     ///
@@ -548,11 +1056,14 @@ where
         self.ensure_sufficient_balance(bumped_price_params.total_cost)
             .await?;
 
+        self.rerecord_spend(&tx.id, final_evm_data.value, bumped_price_params.total_cost)
+            .await?;
+
         let raw_tx = final_evm_data.raw.as_ref().ok_or_else(|| {
             TransactionError::InvalidType("Raw transaction data is missing".to_string())
         })?;
 
-        self.provider.send_raw_transaction(raw_tx).await?;
+        self.broadcast_raw_transaction(raw_tx).await?;
 
         // Track attempt count and hash history
         let mut hashes = tx.hashes.clone();
@@ -602,6 +1113,7 @@ where
         // If the transaction is in Pending state, we can just update its status
         if tx.status == TransactionStatus::Pending {
             info!("Transaction is in Pending state, updating status to Canceled");
+            self.release_spend(&tx.id).await;
             return self
                 .update_transaction_status(tx, TransactionStatus::Canceled)
                 .await;
@@ -688,7 +1200,9 @@ where
         })?;
 
         // First, create updated EVM data without price parameters
-        let updated_evm_data = EvmTransactionData::for_replacement(&old_evm_data, &new_evm_request);
+        let updated_evm_data = EvmTransactionData::for_replacement(&old_evm_data, &new_evm_request)
+            .with_authorization_list(&new_evm_request)
+            .map_err(|e| TransactionError::ValidationError(e.to_string()))?;
 
         // Then determine pricing strategy and calculate price parameters using the updated data
         let price_params = super::replacement::determine_replacement_pricing(
@@ -709,6 +1223,14 @@ where
         self.ensure_sufficient_balance(price_params.total_cost)
             .await?;
 
+        // The replacement may raise the value or fees, so its spend counts against the budgets
+        self.enforce_spending_limits(
+            &old_tx.id,
+            evm_data_with_price_params.value,
+            price_params.total_cost,
+        )
+        .await?;
+
         let sig_result = self
             .signer
             .sign_transaction(NetworkTransactionData::Evm(
@@ -795,8 +1317,8 @@ mod tests {
         domain::evm::price_calculator::PriceParams,
         jobs::MockJobProducerTrait,
         models::{
-            evm::Speed, EvmTransactionData, EvmTransactionRequest, NetworkType,
-            RelayerNetworkPolicy, U256,
+            evm::Speed, EvmTransactionData, EvmTransactionDataTrait, EvmTransactionRequest,
+            NetworkType, RelayerNetworkPolicy, SpendingWindow, U256,
         },
         repositories::{
             MockNetworkRepository, MockRelayerRepository, MockTransactionCounterTrait,
@@ -804,6 +1326,7 @@ mod tests {
         },
         services::{MockEvmProviderTrait, MockSigner},
     };
+    use alloy::sol_types::SolError;
     use chrono::Utc;
     use futures::future::ready;
     use mockall::{mock, predicate::*};
@@ -836,6 +1359,16 @@ mod tests {
             whitelist_receivers: Some(vec!["0xRecipient".to_string()]),
             eip1559_pricing: Some(false),
             private_transactions: Some(false),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
         })
     }
 
@@ -852,6 +1385,7 @@ mod tests {
             policies: RelayerNetworkPolicy::Evm(evm_policy),
             network_type: NetworkType::Evm,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -883,16 +1417,877 @@ mod tests {
                 hash: None,
                 speed: Some(Speed::Fast),
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: Vec::new(),
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
     #[tokio::test]
-    async fn test_prepare_transaction_with_sufficient_balance() {
+    async fn test_prepare_transaction_with_sufficient_balance() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mock_relayer = MockRelayerRepository::new();
+        let mut mock_provider = MockEvmProviderTrait::new();
+        let mut mock_signer = MockSigner::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        let relayer = create_test_relayer();
+        let test_tx = create_test_transaction();
+
+        counter_service
+            .expect_get_and_increment()
+            .returning(|_, _| Box::pin(ready(Ok(42))));
+
+        let price_params = PriceParams {
+            gas_price: Some(30000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            is_min_bumped: None,
+            extra_fee: None,
+            total_cost: U256::from(630000000000000u64),
+        };
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .returning(move |_, _| Ok(price_params.clone()));
+
+        mock_signer.expect_sign_transaction().returning(|_| {
+            Box::pin(ready(Ok(
+                crate::domain::relayer::SignTransactionResponse::Evm(
+                    crate::domain::relayer::SignTransactionResponseEvm {
+                        hash: "0xtx_hash".to_string(),
+                        signature: crate::models::EvmTransactionDataSignature {
+                            r: "r".to_string(),
+                            s: "s".to_string(),
+                            v: 1,
+                            sig: "0xsignature".to_string(),
+                        },
+                        raw: vec![1, 2, 3],
+                    },
+                ),
+            )))
+        });
+
+        mock_provider
+            .expect_get_balance()
+            .with(eq("0xSender"))
+            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64)))));
+
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                if let Some(status) = &update.status {
+                    updated_tx.status = status.clone();
+                }
+                if let Some(network_data) = &update.network_data {
+                    updated_tx.network_data = network_data.clone();
+                }
+                if let Some(hashes) = &update.hashes {
+                    updated_tx.hashes = hashes.clone();
+                }
+                Ok(updated_tx)
+            });
+
+        mock_job_producer
+            .expect_produce_submit_transaction_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let mock_network = MockNetworkRepository::new();
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(mock_network),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let result = evm_transaction.prepare_transaction(test_tx.clone()).await;
+        assert!(result.is_ok());
+        let prepared_tx = result.unwrap();
+        assert_eq!(prepared_tx.status, TransactionStatus::Sent);
+        assert!(!prepared_tx.hashes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_keeps_reserved_nonce() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mock_relayer = MockRelayerRepository::new();
+        let mut mock_provider = MockEvmProviderTrait::new();
+        let mut mock_signer = MockSigner::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        let relayer = create_test_relayer();
+        let mut test_tx = create_test_transaction();
+        if let NetworkTransactionData::Evm(ref mut evm_data) = test_tx.network_data {
+            evm_data.nonce = Some(7);
+        }
+
+        // The nonce was reserved when the transaction was submitted
+        counter_service.expect_get_and_increment().never();
+
+        let price_params = PriceParams {
+            gas_price: Some(30000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            is_min_bumped: None,
+            extra_fee: None,
+            total_cost: U256::from(630000000000000u64),
+        };
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .returning(move |_, _| Ok(price_params.clone()));
+
+        mock_signer
+            .expect_sign_transaction()
+            .withf(|data| matches!(data, NetworkTransactionData::Evm(evm) if evm.nonce == Some(7)))
+            .returning(|_| {
+                Box::pin(ready(Ok(
+                    crate::domain::relayer::SignTransactionResponse::Evm(
+                        crate::domain::relayer::SignTransactionResponseEvm {
+                            hash: "0xtx_hash".to_string(),
+                            signature: crate::models::EvmTransactionDataSignature {
+                                r: "r".to_string(),
+                                s: "s".to_string(),
+                                v: 1,
+                                sig: "0xsignature".to_string(),
+                            },
+                            raw: vec![1, 2, 3],
+                        },
+                    ),
+                )))
+            });
+
+        mock_provider
+            .expect_get_balance()
+            .with(eq("0xSender"))
+            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64)))));
+
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                if let Some(status) = &update.status {
+                    updated_tx.status = status.clone();
+                }
+                if let Some(network_data) = &update.network_data {
+                    updated_tx.network_data = network_data.clone();
+                }
+                if let Some(hashes) = &update.hashes {
+                    updated_tx.hashes = hashes.clone();
+                }
+                Ok(updated_tx)
+            });
+
+        mock_job_producer
+            .expect_produce_submit_transaction_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let mock_network = MockNetworkRepository::new();
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(mock_network),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let result = evm_transaction.prepare_transaction(test_tx.clone()).await;
+        assert!(result.is_ok());
+        let prepared_tx = result.unwrap();
+        assert_eq!(prepared_tx.status, TransactionStatus::Sent);
+        assert_eq!(
+            prepared_tx
+                .network_data
+                .get_evm_transaction_data()
+                .unwrap()
+                .nonce,
+            Some(7)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_seals_bundle_before_reading_it() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mock_relayer = MockRelayerRepository::new();
+        let mut mock_provider = MockEvmProviderTrait::new();
+        let mut mock_signer = MockSigner::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        let relayer = create_test_relayer();
+        let mut test_tx = create_test_transaction();
+        if let NetworkTransactionData::Evm(ref mut evm_data) = test_tx.network_data {
+            evm_data.user_operation_hashes = Some(vec!["0x01".to_string()]);
+        }
+        // An operation was added after the job read the bundle, but before it was sealed
+        let mut stored_tx = test_tx.clone();
+        if let NetworkTransactionData::Evm(ref mut evm_data) = stored_tx.network_data {
+            evm_data.data = Some("0xSealed".to_string());
+            evm_data.user_operation_hashes = Some(vec!["0x01".to_string(), "0x02".to_string()]);
+        }
+
+        let mut sequence = mockall::Sequence::new();
+        mock_transaction
+            .expect_seal_bundle()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_| Ok(()));
+        let stored_tx_clone = stored_tx.clone();
+        mock_transaction
+            .expect_get_by_id()
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(move |_| Ok(stored_tx_clone.clone()));
+
+        counter_service
+            .expect_get_and_increment()
+            .returning(|_, _| Box::pin(ready(Ok(42))));
+
+        let price_params = PriceParams {
+            gas_price: Some(30000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            is_min_bumped: None,
+            extra_fee: None,
+            total_cost: U256::from(630000000000000u64),
+        };
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .returning(move |_, _| Ok(price_params.clone()));
+
+        mock_signer
+            .expect_sign_transaction()
+            .withf(|data| {
+                matches!(data, NetworkTransactionData::Evm(evm)
+                    if evm.data == Some("0xSealed".to_string()))
+            })
+            .times(1)
+            .returning(|_| {
+                Box::pin(ready(Ok(
+                    crate::domain::relayer::SignTransactionResponse::Evm(
+                        crate::domain::relayer::SignTransactionResponseEvm {
+                            hash: "0xtx_hash".to_string(),
+                            signature: crate::models::EvmTransactionDataSignature {
+                                r: "r".to_string(),
+                                s: "s".to_string(),
+                                v: 1,
+                                sig: "0xsignature".to_string(),
+                            },
+                            raw: vec![1, 2, 3],
+                        },
+                    ),
+                )))
+            });
+
+        mock_provider
+            .expect_get_balance()
+            .with(eq("0xSender"))
+            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64)))));
+
+        mock_transaction
+            .expect_partial_update()
+            .returning(move |_, update| {
+                let mut updated_tx = stored_tx.clone();
+                if let Some(status) = &update.status {
+                    updated_tx.status = status.clone();
+                }
+                if let Some(network_data) = &update.network_data {
+                    updated_tx.network_data = network_data.clone();
+                }
+                Ok(updated_tx)
+            });
+
+        mock_job_producer
+            .expect_produce_submit_transaction_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let prepared_tx = evm_transaction.prepare_transaction(test_tx).await.unwrap();
+        assert_eq!(prepared_tx.status, TransactionStatus::Sent);
+        assert_eq!(
+            prepared_tx
+                .network_data
+                .get_evm_transaction_data()
+                .unwrap()
+                .user_operation_hashes
+                .map(|hashes| hashes.len()),
+            Some(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_simulation_revert_fails_without_nonce() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mock_relayer = MockRelayerRepository::new();
+        let mut mock_provider = MockEvmProviderTrait::new();
+        let mut mock_signer = MockSigner::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            simulate_transactions: Some(true),
+            ..Default::default()
+        });
+        let mut test_tx = create_test_transaction();
+        if let NetworkTransactionData::Evm(ref mut evm_data) = test_tx.network_data {
+            evm_data.from = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string();
+            evm_data.to = Some("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_string());
+            evm_data.data = Some("0x".to_string());
+            evm_data.gas_limit = Some(21000);
+            evm_data.nonce = None;
+        }
+
+        mock_provider
+            .expect_call_contract()
+            .withf(|_, block| *block == BlockNumberOrTag::Pending)
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(ready(Err(ProviderError::Reverted {
+                    message: "execution reverted".to_string(),
+                    data: Some(
+                        alloy::sol_types::Revert::from("Insufficient allowance")
+                            .abi_encode()
+                            .into(),
+                    ),
+                })))
+            });
+
+        // A reverting transaction must not consume a nonce or be signed
+        counter_service.expect_get_and_increment().never();
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .never();
+        mock_signer.expect_sign_transaction().never();
+        mock_job_producer
+            .expect_produce_submit_transaction_job()
+            .never();
+
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Failed))
+            .times(1)
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                updated_tx.status = update.status.unwrap();
+                updated_tx.status_reason = update.status_reason;
+                Ok(updated_tx)
+            });
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let result = evm_transaction.prepare_transaction(test_tx).await;
+        let failed_tx = result.unwrap();
+        assert_eq!(failed_tx.status, TransactionStatus::Failed);
+        assert_eq!(
+            failed_tx.status_reason,
+            Some("execution reverted: Insufficient allowance".to_string())
+        );
+    }
+
+    fn create_reverting_batch_entry_transaction(
+        nonce: u64,
+    ) -> (
+        TransactionRepoModel,
+        MockEvmProviderTrait,
+        MockTransactionRepository,
+    ) {
+        let mut test_tx = create_test_transaction();
+        if let NetworkTransactionData::Evm(ref mut evm_data) = test_tx.network_data {
+            evm_data.data = Some("0x".to_string());
+            evm_data.nonce = Some(nonce);
+        }
+
+        let mut mock_provider = MockEvmProviderTrait::new();
+        mock_provider
+            .expect_call_contract()
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(ready(Err(ProviderError::Reverted {
+                    message: "execution reverted".to_string(),
+                    data: None,
+                })))
+            });
+
+        let mut mock_transaction = MockTransactionRepository::new();
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Failed))
+            .times(1)
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                updated_tx.status = update.status.unwrap();
+                updated_tx.status_reason = update.status_reason;
+                Ok(updated_tx)
+            });
+
+        (test_tx, mock_provider, mock_transaction)
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_simulation_revert_fills_reserved_nonce() {
+        let mock_signer = MockSigner::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+        let mut mock_network = MockNetworkRepository::new();
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            simulate_transactions: Some(true),
+            ..Default::default()
+        });
+        // The middle entry of a batch holding nonces 5 to 7 reverts
+        let (test_tx, mock_provider, mut mock_transaction) =
+            create_reverting_batch_entry_transaction(6);
+
+        // The counter has moved past the batch, so the nonce cannot be handed back
+        counter_service
+            .expect_compare_and_set()
+            .withf(|relayer_id, address, expected, value| {
+                relayer_id == "test-relayer-id"
+                    && address == "0xSender"
+                    && *expected == 7
+                    && *value == 6
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(ready(Ok(false))));
+
+        mock_network
+            .expect_get_by_chain_id()
+            .with(eq(NetworkType::Evm), eq(1))
+            .returning(|_, _| {
+                use crate::config::{EvmNetworkConfig, NetworkConfigCommon};
+                use crate::models::{NetworkConfigData, NetworkRepoModel};
+
+                let config = EvmNetworkConfig {
+                    common: NetworkConfigCommon {
+                        network: "mainnet".to_string(),
+                        from: None,
+                        rpc_urls: Some(vec!["https://rpc.example.com".to_string()]),
+                        explorer_urls: None,
+                        average_blocktime_ms: Some(12000),
+                        is_testnet: Some(false),
+                        tags: Some(vec!["mainnet".to_string()]),
+                    },
+                    chain_id: Some(1),
+                    required_confirmations: Some(12),
+                    features: Some(vec!["eip1559".to_string()]),
+                    symbol: Some("ETH".to_string()),
+                    gas_price_cache: None,
+                    private_relay: None,
+                    gas_oracle: None,
+                };
+                Ok(Some(NetworkRepoModel {
+                    id: "evm:mainnet".to_string(),
+                    name: "mainnet".to_string(),
+                    network_type: NetworkType::Evm,
+                    config: NetworkConfigData::Evm(config),
+                }))
+            });
+
+        mock_transaction
+            .expect_create()
+            .withf(|noop_tx| {
+                let evm_data = noop_tx.network_data.get_evm_transaction_data().unwrap();
+                noop_tx.id != "test-tx-id"
+                    && noop_tx.status == TransactionStatus::Pending
+                    && evm_data.nonce == Some(6)
+                    && is_noop(&evm_data)
+            })
+            .times(1)
+            .returning(Ok);
+        mock_job_producer
+            .expect_produce_transaction_request_job()
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        mock_job_producer
+            .expect_produce_submit_transaction_job()
+            .never();
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(MockRelayerRepository::new()),
+            network_repository: Arc::new(mock_network),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: MockPriceCalculator::new(),
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let failed_tx = evm_transaction.prepare_transaction(test_tx).await.unwrap();
+        assert_eq!(failed_tx.status, TransactionStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_simulation_revert_hands_back_last_reserved_nonce() {
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            simulate_transactions: Some(true),
+            ..Default::default()
+        });
+        // The last entry of a batch holding nonces 5 to 7 reverts
+        let (test_tx, mock_provider, mut mock_transaction) =
+            create_reverting_batch_entry_transaction(7);
+
+        counter_service
+            .expect_compare_and_set()
+            .withf(|relayer_id, address, expected, value| {
+                relayer_id == "test-relayer-id"
+                    && address == "0xSender"
+                    && *expected == 8
+                    && *value == 7
+            })
+            .times(1)
+            .returning(|_, _, _, _| Box::pin(ready(Ok(true))));
+
+        // The nonce was handed back, so no NOOP is needed
+        mock_transaction.expect_create().never();
+        mock_job_producer
+            .expect_produce_transaction_request_job()
+            .never();
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(MockRelayerRepository::new()),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: MockPriceCalculator::new(),
+            private_relay: None,
+            signer: MockSigner::new(),
+        };
+
+        let failed_tx = evm_transaction.prepare_transaction(test_tx).await.unwrap();
+        assert_eq!(failed_tx.status, TransactionStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_request_enables_simulation() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mock_relayer = MockRelayerRepository::new();
+        let mut mock_provider = MockEvmProviderTrait::new();
+        let mut mock_signer = MockSigner::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        // Simulation is off in the policy and requested per transaction
+        let relayer = create_test_relayer();
+        let mut test_tx = create_test_transaction();
+        if let NetworkTransactionData::Evm(ref mut evm_data) = test_tx.network_data {
+            evm_data.from = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string();
+            evm_data.to = Some("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_string());
+            evm_data.data = Some("0x".to_string());
+            evm_data.nonce = None;
+            evm_data.simulate = Some(true);
+        }
+
+        mock_provider
+            .expect_call_contract()
+            .withf(|call, block| *block == BlockNumberOrTag::Pending && call.nonce.is_none())
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(alloy::primitives::Bytes::new()))));
+
+        counter_service
+            .expect_get_and_increment()
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(42))));
+
+        let price_params = PriceParams {
+            gas_price: Some(30000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            is_min_bumped: None,
+            extra_fee: None,
+            total_cost: U256::from(630000000000000u64),
+        };
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .returning(move |_, _| Ok(price_params.clone()));
+
+        mock_signer.expect_sign_transaction().returning(|_| {
+            Box::pin(ready(Ok(
+                crate::domain::relayer::SignTransactionResponse::Evm(
+                    crate::domain::relayer::SignTransactionResponseEvm {
+                        hash: "0xtx_hash".to_string(),
+                        signature: crate::models::EvmTransactionDataSignature {
+                            r: "r".to_string(),
+                            s: "s".to_string(),
+                            v: 1,
+                            sig: "0xsignature".to_string(),
+                        },
+                        raw: vec![1, 2, 3],
+                    },
+                ),
+            )))
+        });
+
+        mock_provider
+            .expect_get_balance()
+            .with(eq("0xSender"))
+            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64)))));
+
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                if let Some(status) = &update.status {
+                    updated_tx.status = status.clone();
+                }
+                Ok(updated_tx)
+            });
+
+        mock_job_producer
+            .expect_produce_submit_transaction_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let result = evm_transaction.prepare_transaction(test_tx).await;
+        assert_eq!(result.unwrap().status, TransactionStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_attaches_generated_access_list() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mock_relayer = MockRelayerRepository::new();
+        let mut mock_provider = MockEvmProviderTrait::new();
+        let mut mock_signer = MockSigner::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            access_list_generation: Some(true),
+            ..Default::default()
+        });
+        let mut test_tx = create_test_transaction();
+        if let NetworkTransactionData::Evm(ref mut evm_data) = test_tx.network_data {
+            evm_data.from = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string();
+            evm_data.to = Some("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_string());
+            evm_data.data = Some("0x70a08231".to_string());
+            evm_data.gas_limit = None;
+            evm_data.nonce = None;
+        }
+
+        mock_provider
+            .expect_create_access_list()
+            .withf(|call, block| *block == BlockNumberOrTag::Pending && call.nonce.is_none())
+            .times(1)
+            .returning(|_, _| {
+                Box::pin(ready(Ok(alloy::rpc::types::AccessListResult {
+                    access_list: alloy::rpc::types::AccessList(vec![
+                        alloy::rpc::types::AccessListItem {
+                            address: alloy::primitives::Address::repeat_byte(0xcc),
+                            storage_keys: vec![alloy::primitives::B256::ZERO],
+                        },
+                    ]),
+                    gas_used: U256::from(43_000u64),
+                    error: None,
+                })))
+            });
+        // Cheaper with the access list attached
+        mock_provider
+            .expect_estimate_gas()
+            .times(2)
+            .returning(|tx| {
+                let estimate = if tx.access_list.is_some() {
+                    45_000
+                } else {
+                    50_000
+                };
+                Box::pin(ready(Ok(estimate)))
+            });
+
+        counter_service
+            .expect_get_and_increment()
+            .times(1)
+            .returning(|_, _| Box::pin(ready(Ok(42))));
+
+        let price_params = PriceParams {
+            gas_price: Some(30000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            is_min_bumped: None,
+            extra_fee: None,
+            total_cost: U256::from(630000000000000u64),
+        };
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .returning(move |_, _| Ok(price_params.clone()));
+
+        mock_signer
+            .expect_sign_transaction()
+            .withf(|data| {
+                data.get_evm_transaction_data()
+                    .is_ok_and(|evm_data| evm_data.is_eip2930())
+            })
+            .returning(|_| {
+                Box::pin(ready(Ok(
+                    crate::domain::relayer::SignTransactionResponse::Evm(
+                        crate::domain::relayer::SignTransactionResponseEvm {
+                            hash: "0xtx_hash".to_string(),
+                            signature: crate::models::EvmTransactionDataSignature {
+                                r: "r".to_string(),
+                                s: "s".to_string(),
+                                v: 1,
+                                sig: "0xsignature".to_string(),
+                            },
+                            raw: vec![1, 2, 3],
+                        },
+                    ),
+                )))
+            });
+
+        mock_provider
+            .expect_get_balance()
+            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64)))));
+
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .withf(|_, update| {
+                let Some(NetworkTransactionData::Evm(evm_data)) = &update.network_data else {
+                    return false;
+                };
+                evm_data.gas_limit == Some(49_500)
+                    && evm_data.access_list.as_ref().is_some_and(|list| {
+                        list.len() == 1 && list[0].storage_keys == vec![format!("0x{:064x}", 0)]
+                    })
+            })
+            .times(1)
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                updated_tx.status = update.status.unwrap();
+                updated_tx.network_data = update.network_data.unwrap();
+                Ok(updated_tx)
+            });
+
+        mock_job_producer
+            .expect_produce_submit_transaction_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let result = evm_transaction.prepare_transaction(test_tx).await;
+        assert_eq!(result.unwrap().status, TransactionStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_with_insufficient_balance() {
         let mut mock_transaction = MockTransactionRepository::new();
         let mock_relayer = MockRelayerRepository::new();
         let mut mock_provider = MockEvmProviderTrait::new();
@@ -901,7 +2296,11 @@ mod tests {
         let mut mock_price_calculator = MockPriceCalculator::new();
         let mut counter_service = MockTransactionCounterTrait::new();
 
-        let relayer = create_test_relayer();
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            gas_limit_estimation: Some(false),
+            min_balance: Some(100000000000000000u128),
+            ..Default::default()
+        });
         let test_tx = create_test_transaction();
 
         counter_service
@@ -940,28 +2339,20 @@ mod tests {
         mock_provider
             .expect_get_balance()
             .with(eq("0xSender"))
-            .returning(|_| Box::pin(ready(Ok(U256::from(1000000000000000000u64)))));
+            .returning(|_| Box::pin(ready(Ok(U256::from(90000000000000000u64)))));
 
         let test_tx_clone = test_tx.clone();
         mock_transaction
             .expect_partial_update()
+            .withf(move |id, update| {
+                id == "test-tx-id" && update.status == Some(TransactionStatus::Failed)
+            })
             .returning(move |_, update| {
                 let mut updated_tx = test_tx_clone.clone();
-                if let Some(status) = &update.status {
-                    updated_tx.status = status.clone();
-                }
-                if let Some(network_data) = &update.network_data {
-                    updated_tx.network_data = network_data.clone();
-                }
-                if let Some(hashes) = &update.hashes {
-                    updated_tx.hashes = hashes.clone();
-                }
+                updated_tx.status = update.status.unwrap_or(updated_tx.status);
                 Ok(updated_tx)
             });
 
-        mock_job_producer
-            .expect_produce_submit_transaction_job()
-            .returning(|_, _| Box::pin(ready(Ok(()))));
         mock_job_producer
             .expect_produce_send_notification_job()
             .returning(|_, _| Box::pin(ready(Ok(()))));
@@ -977,20 +2368,22 @@ mod tests {
             transaction_counter_service: Arc::new(counter_service),
             job_producer: Arc::new(mock_job_producer),
             price_calculator: mock_price_calculator,
+            private_relay: None,
             signer: mock_signer,
         };
 
         let result = evm_transaction.prepare_transaction(test_tx.clone()).await;
-        assert!(result.is_ok());
-        let prepared_tx = result.unwrap();
-        assert_eq!(prepared_tx.status, TransactionStatus::Sent);
-        assert!(!prepared_tx.hashes.is_empty());
+        assert!(
+            matches!(result, Err(TransactionError::InsufficientBalance(_))),
+            "Expected InsufficientBalance error, got: {:?}",
+            result
+        );
     }
 
     #[tokio::test]
-    async fn test_prepare_transaction_with_insufficient_balance() {
+    async fn test_prepare_transaction_failure_releases_spend() {
         let mut mock_transaction = MockTransactionRepository::new();
-        let mock_relayer = MockRelayerRepository::new();
+        let mut mock_relayer = MockRelayerRepository::new();
         let mut mock_provider = MockEvmProviderTrait::new();
         let mut mock_signer = MockSigner::new();
         let mut mock_job_producer = MockJobProducerTrait::new();
@@ -1000,6 +2393,7 @@ mod tests {
         let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
             gas_limit_estimation: Some(false),
             min_balance: Some(100000000000000000u128),
+            max_total_value: Some(5_000_000_000_000_000_000),
             ..Default::default()
         });
         let test_tx = create_test_transaction();
@@ -1020,6 +2414,17 @@ mod tests {
             .expect_get_transaction_price_params()
             .returning(move |_, _| Ok(price_params.clone()));
 
+        mock_relayer
+            .expect_record_spend_within_limit()
+            .times(1)
+            .returning(|_, _, _, _, _, _| Ok(None));
+        // The failed transaction is never sent, so its spend is released again
+        mock_relayer
+            .expect_remove_spend()
+            .withf(|relayer_id, tx_id| relayer_id == "test-relayer-id" && tx_id == "test-tx-id")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
         mock_signer.expect_sign_transaction().returning(|_| {
             Box::pin(ready(Ok(
                 crate::domain::relayer::SignTransactionResponse::Evm(
@@ -1042,6 +2447,136 @@ mod tests {
             .with(eq("0xSender"))
             .returning(|_| Box::pin(ready(Ok(U256::from(90000000000000000u64)))));
 
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                updated_tx.status = update.status.unwrap_or(updated_tx.status);
+                Ok(updated_tx)
+            });
+
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(counter_service),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let result = evm_transaction.prepare_transaction(test_tx).await;
+        assert!(matches!(
+            result,
+            Err(TransactionError::InsufficientBalance(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_pending_transaction_releases_spend() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mut mock_relayer = MockRelayerRepository::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            max_gas_spend: Some(1_000_000_000_000_000),
+            ..Default::default()
+        });
+        let mut test_tx = create_test_transaction();
+        test_tx.status = TransactionStatus::Pending;
+
+        mock_relayer
+            .expect_remove_spend()
+            .withf(|relayer_id, tx_id| relayer_id == "test-relayer-id" && tx_id == "test-tx-id")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Canceled))
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                updated_tx.status = update.status.unwrap_or(updated_tx.status);
+                Ok(updated_tx)
+            });
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer: relayer.clone(),
+            provider: MockEvmProviderTrait::new(),
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(MockTransactionCounterTrait::new()),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: MockPriceCalculator::new(),
+            private_relay: None,
+            signer: MockSigner::new(),
+        };
+
+        let result = evm_transaction.cancel_transaction(test_tx).await.unwrap();
+        assert_eq!(result.status, TransactionStatus::Canceled);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_transaction_spending_limit_exceeded() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mut mock_relayer = MockRelayerRepository::new();
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut counter_service = MockTransactionCounterTrait::new();
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            gas_limit_estimation: Some(false),
+            max_gas_spend: Some(1_000_000_000_000_000),
+            spending_window: Some(SpendingWindow::Hour),
+            ..Default::default()
+        });
+        let test_tx = create_test_transaction();
+
+        // The nonce must not be consumed by a rejected transaction
+        counter_service.expect_get_and_increment().never();
+
+        let price_params = PriceParams {
+            gas_price: Some(30000000000),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            is_min_bumped: None,
+            extra_fee: None,
+            total_cost: U256::from(1000630000000000000u64),
+        };
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .returning(move |_, _| Ok(price_params.clone()));
+
+        mock_relayer
+            .expect_record_spend_within_limit()
+            .withf(|relayer_id, tx_id, spend, _, _, limit| {
+                relayer_id == "test-relayer-id"
+                    && tx_id == "test-tx-id"
+                    && spend.value == 1000000000000000000
+                    && spend.gas == 630000000000000
+                    && limit.max_gas == Some(1_000_000_000_000_000)
+            })
+            .times(1)
+            .returning(|_, _, _, _, _, _| {
+                Ok(Some(RelayerSpend {
+                    value: 1000000000000000000,
+                    gas: 1_000_630_000_000_000,
+                }))
+            });
+
         let test_tx_clone = test_tx.clone();
         mock_transaction
             .expect_partial_update()
@@ -1051,33 +2586,36 @@ mod tests {
             .returning(move |_, update| {
                 let mut updated_tx = test_tx_clone.clone();
                 updated_tx.status = update.status.unwrap_or(updated_tx.status);
+                updated_tx.status_reason = update.status_reason;
                 Ok(updated_tx)
             });
 
+        // Transaction update and spending limit notifications
         mock_job_producer
             .expect_produce_send_notification_job()
+            .times(2)
             .returning(|_, _| Box::pin(ready(Ok(()))));
 
-        let mock_network = MockNetworkRepository::new();
-
         let evm_transaction = EvmRelayerTransaction {
             relayer: relayer.clone(),
-            provider: mock_provider,
+            provider: MockEvmProviderTrait::new(),
             relayer_repository: Arc::new(mock_relayer),
-            network_repository: Arc::new(mock_network),
+            network_repository: Arc::new(MockNetworkRepository::new()),
             transaction_repository: Arc::new(mock_transaction),
             transaction_counter_service: Arc::new(counter_service),
             job_producer: Arc::new(mock_job_producer),
             price_calculator: mock_price_calculator,
-            signer: mock_signer,
+            private_relay: None,
+            signer: MockSigner::new(),
         };
 
-        let result = evm_transaction.prepare_transaction(test_tx.clone()).await;
-        assert!(
-            matches!(result, Err(TransactionError::InsufficientBalance(_))),
-            "Expected InsufficientBalance error, got: {:?}",
-            result
-        );
+        let result = evm_transaction.prepare_transaction(test_tx).await;
+        match result {
+            Err(TransactionError::SpendingLimitExceeded(reason)) => {
+                assert!(reason.contains("Gas spend"));
+            }
+            other => panic!("Expected SpendingLimitExceeded error, got: {:?}", other),
+        }
     }
 
     #[tokio::test]
@@ -1128,6 +2666,7 @@ mod tests {
                 transaction_counter_service: Arc::new(counter_service),
                 job_producer: Arc::new(mock_job_producer),
                 price_calculator: mock_price_calculator,
+                private_relay: None,
                 signer: mock_signer,
             };
 
@@ -1241,6 +2780,8 @@ mod tests {
                         features: Some(vec!["eip1559".to_string()]),
                         symbol: Some("ETH".to_string()),
                         gas_price_cache: None,
+                        private_relay: None,
+                        gas_oracle: None,
                     };
                     Ok(Some(NetworkRepoModel {
                         id: "evm:mainnet".to_string(),
@@ -1260,6 +2801,7 @@ mod tests {
                 transaction_counter_service: Arc::new(counter_service),
                 job_producer: Arc::new(mock_job_producer),
                 price_calculator: mock_price_calculator,
+                private_relay: None,
                 signer: mock_signer,
             };
 
@@ -1308,6 +2850,7 @@ mod tests {
                 transaction_counter_service: Arc::new(counter_service),
                 job_producer: Arc::new(mock_job_producer),
                 price_calculator: mock_price_calculator,
+                private_relay: None,
                 signer: mock_signer,
             };
 
@@ -1422,6 +2965,8 @@ mod tests {
                         features: Some(vec!["eip1559".to_string()]),
                         symbol: Some("ETH".to_string()),
                         gas_price_cache: None,
+                        private_relay: None,
+                        gas_oracle: None,
                     };
                     Ok(Some(NetworkRepoModel {
                         id: "evm:mainnet".to_string(),
@@ -1441,6 +2986,7 @@ mod tests {
                 transaction_counter_service: Arc::new(counter_service),
                 job_producer: Arc::new(mock_job_producer),
                 price_calculator: mock_price_calculator,
+                private_relay: None,
                 signer: mock_signer,
             };
 
@@ -1455,6 +3001,13 @@ mod tests {
                 max_priority_fee_per_gas: None,
                 speed: Some(Speed::Fast),
                 valid_until: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                idempotency_key: None,
+                execute_after: None,
+                schedule: None,
+                depends_on: None,
             });
 
             // Call replace_transaction and verify it succeeds
@@ -1511,6 +3064,7 @@ mod tests {
                 transaction_counter_service: Arc::new(counter_service),
                 job_producer: Arc::new(mock_job_producer),
                 price_calculator: mock_price_calculator,
+                private_relay: None,
                 signer: mock_signer,
             };
 
@@ -1525,6 +3079,13 @@ mod tests {
                 max_priority_fee_per_gas: None,
                 speed: Some(Speed::Fast),
                 valid_until: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                idempotency_key: None,
+                execute_after: None,
+                schedule: None,
+                depends_on: None,
             });
 
             // Call replace_transaction and verify it fails
@@ -1540,6 +3101,113 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_replace_transaction_spending_limit_exceeded() {
+        let mut mock_transaction = MockTransactionRepository::new();
+        let mut mock_relayer = MockRelayerRepository::new();
+        let mut mock_provider = MockEvmProviderTrait::new();
+        let mut mock_signer = MockSigner::new();
+        let mut mock_price_calculator = MockPriceCalculator::new();
+        let mut mock_network = MockNetworkRepository::new();
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            max_total_value: Some(1_500_000_000_000_000_000),
+            spending_window: Some(SpendingWindow::Day),
+            ..Default::default()
+        });
+        let mut test_tx = create_test_transaction();
+        test_tx.status = TransactionStatus::Submitted;
+
+        mock_network.expect_get_by_chain_id().returning(|_, _| {
+            use crate::config::{EvmNetworkConfig, NetworkConfigCommon};
+            use crate::models::{NetworkConfigData, NetworkRepoModel};
+
+            Ok(Some(NetworkRepoModel {
+                id: "evm:mainnet".to_string(),
+                name: "mainnet".to_string(),
+                network_type: NetworkType::Evm,
+                config: NetworkConfigData::Evm(EvmNetworkConfig {
+                    common: NetworkConfigCommon {
+                        network: "mainnet".to_string(),
+                        from: None,
+                        rpc_urls: Some(vec!["https://rpc.example.com".to_string()]),
+                        explorer_urls: None,
+                        average_blocktime_ms: Some(12000),
+                        is_testnet: Some(false),
+                        tags: None,
+                    },
+                    chain_id: Some(1),
+                    required_confirmations: Some(12),
+                    features: Some(vec!["eip1559".to_string()]),
+                    symbol: Some("ETH".to_string()),
+                    gas_price_cache: None,
+                    private_relay: None,
+                    gas_oracle: None,
+                }),
+            }))
+        });
+        mock_price_calculator
+            .expect_get_transaction_price_params()
+            .returning(|_, _| {
+                Ok(PriceParams {
+                    gas_price: Some(40000000000),
+                    max_fee_per_gas: None,
+                    max_priority_fee_per_gas: None,
+                    is_min_bumped: Some(true),
+                    extra_fee: Some(U256::ZERO),
+                    total_cost: U256::from(2001000000000000000u64),
+                })
+            });
+        mock_provider
+            .expect_get_balance()
+            .returning(|_| Box::pin(ready(Ok(U256::from(3000000000000000000u64)))));
+
+        // The replacement is recorded under the transaction it replaces
+        mock_relayer
+            .expect_record_spend_within_limit()
+            .withf(|relayer_id, tx_id, spend, _, _, _| {
+                relayer_id == "test-relayer-id"
+                    && tx_id == "test-tx-id"
+                    && spend.value == 2000000000000000000
+            })
+            .times(1)
+            .returning(|_, _, spend, _, _, _| Ok(Some(spend)));
+
+        // A rejected replacement is neither signed nor stored
+        mock_signer.expect_sign_transaction().never();
+        mock_transaction.expect_update_network_data().never();
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer,
+            provider: mock_provider,
+            relayer_repository: Arc::new(mock_relayer),
+            network_repository: Arc::new(mock_network),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(MockTransactionCounterTrait::new()),
+            job_producer: Arc::new(MockJobProducerTrait::new()),
+            price_calculator: mock_price_calculator,
+            private_relay: None,
+            signer: mock_signer,
+        };
+
+        let replacement_request = NetworkTransactionRequest::Evm(EvmTransactionRequest {
+            to: Some("0xRecipient".to_string()),
+            value: U256::from(2000000000000000000u64),
+            speed: Some(Speed::Fast),
+            ..Default::default()
+        });
+
+        let result = evm_transaction
+            .replace_transaction(test_tx, replacement_request)
+            .await;
+        match result {
+            Err(TransactionError::SpendingLimitExceeded(reason)) => {
+                assert!(reason.contains("Total value"));
+            }
+            other => panic!("Expected SpendingLimitExceeded, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_estimate_tx_gas_limit_success() {
         let mock_transaction = MockTransactionRepository::new();
@@ -1571,6 +3239,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         // Mock provider to return 21000 as estimated gas
@@ -1633,6 +3311,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         // Provider should not be called when estimation is disabled
@@ -1693,6 +3381,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         // Mock provider to return 50000 as estimated gas
@@ -1754,6 +3452,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         // Mock provider to return an error
@@ -1923,4 +3631,132 @@ mod tests {
             panic!("Expected EVM network data");
         }
     }
+
+    type TestEvmTransaction = EvmRelayerTransaction<
+        MockEvmProviderTrait,
+        MockRelayerRepository,
+        MockNetworkRepository,
+        MockTransactionRepository,
+        MockJobProducerTrait,
+        MockSigner,
+        MockTransactionCounterTrait,
+        MockPriceCalculator,
+    >;
+
+    fn create_private_submit_transaction(
+        relayer: RelayerRepoModel,
+        mock_provider: MockEvmProviderTrait,
+        private_relay: Option<PrivateRelayService>,
+    ) -> (TestEvmTransaction, TransactionRepoModel) {
+        let mut test_tx = create_test_transaction();
+        test_tx.status = TransactionStatus::Sent;
+        if let NetworkTransactionData::Evm(ref mut evm_data) = test_tx.network_data {
+            evm_data.raw = Some(vec![1, 2, 3]);
+        }
+
+        let mut mock_transaction = MockTransactionRepository::new();
+        let test_tx_clone = test_tx.clone();
+        mock_transaction
+            .expect_partial_update()
+            .returning(move |_, update| {
+                let mut updated_tx = test_tx_clone.clone();
+                if let Some(status) = &update.status {
+                    updated_tx.status = status.clone();
+                }
+                Ok(updated_tx)
+            });
+
+        let mut mock_job_producer = MockJobProducerTrait::new();
+        mock_job_producer
+            .expect_produce_check_transaction_status_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+        mock_job_producer
+            .expect_produce_send_notification_job()
+            .returning(|_, _| Box::pin(ready(Ok(()))));
+
+        let evm_transaction = EvmRelayerTransaction {
+            relayer,
+            provider: mock_provider,
+            relayer_repository: Arc::new(MockRelayerRepository::new()),
+            network_repository: Arc::new(MockNetworkRepository::new()),
+            transaction_repository: Arc::new(mock_transaction),
+            transaction_counter_service: Arc::new(MockTransactionCounterTrait::new()),
+            job_producer: Arc::new(mock_job_producer),
+            price_calculator: MockPriceCalculator::new(),
+            private_relay,
+            signer: MockSigner::new(),
+        };
+
+        (evm_transaction, test_tx)
+    }
+
+    #[tokio::test]
+    async fn test_submit_transaction_public_mempool() {
+        let mut mock_provider = MockEvmProviderTrait::new();
+        mock_provider
+            .expect_send_raw_transaction()
+            .times(1)
+            .returning(|_| Box::pin(ready(Ok("0xtx_hash".to_string()))));
+
+        let (evm_transaction, test_tx) =
+            create_private_submit_transaction(create_test_relayer(), mock_provider, None);
+
+        let result = evm_transaction.submit_transaction(test_tx).await.unwrap();
+        assert_eq!(result.status, TransactionStatus::Submitted);
+    }
+
+    #[tokio::test]
+    async fn test_submit_transaction_through_private_relay() {
+        let mut server = mockito::Server::new_async().await;
+        let relay_mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "method": "eth_sendPrivateTransaction",
+                "params": [{ "tx": "0x010203", "maxBlockNumber": "0x7d" }]
+            })))
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":"0xtx_hash"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let private_relay = PrivateRelayService::new(&crate::config::PrivateRelayConfig {
+            url: server.url(),
+            method: crate::config::PrivateRelayMethod::SendPrivateTransaction,
+            auth_key_env: None,
+        })
+        .unwrap();
+
+        // The public mempool must not be used, so no send_raw_transaction expectation is set
+        let mut mock_provider = MockEvmProviderTrait::new();
+        mock_provider
+            .expect_get_block_number()
+            .returning(|| Box::pin(ready(Ok(100))));
+
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            private_transactions: Some(true),
+            ..Default::default()
+        });
+        let (evm_transaction, test_tx) =
+            create_private_submit_transaction(relayer, mock_provider, Some(private_relay));
+
+        let result = evm_transaction.submit_transaction(test_tx).await.unwrap();
+        assert_eq!(result.status, TransactionStatus::Submitted);
+        relay_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_submit_private_transaction_without_relay() {
+        let relayer = create_test_relayer_with_policy(RelayerEvmPolicy {
+            private_transactions: Some(true),
+            ..Default::default()
+        });
+        let (evm_transaction, test_tx) =
+            create_private_submit_transaction(relayer, MockEvmProviderTrait::new(), None);
+
+        let result = evm_transaction.submit_transaction(test_tx).await;
+        assert!(matches!(
+            result,
+            Err(TransactionError::NetworkConfiguration(_))
+        ));
+    }
 }
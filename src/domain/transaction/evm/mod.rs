@@ -3,6 +3,9 @@
 pub mod evm_transaction;
 pub use evm_transaction::*;
 
+mod authorization;
+pub use authorization::*;
+
 pub mod price_calculator;
 pub use price_calculator::*;
 
@@ -184,6 +184,13 @@ where
             max_fee_per_gas: params.max_fee_per_gas,
             max_priority_fee_per_gas: params.max_priority_fee_per_gas,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -234,7 +241,7 @@ where
         }
 
         final_params.total_cost = final_params.calculate_total_cost(
-            tx_data.is_eip1559(),
+            tx_data.is_eip1559() || tx_data.is_eip7702(),
             tx_data.gas_limit.unwrap_or(DEFAULT_GAS_LIMIT), // Use default gas limit if not provided
             U256::from(tx_data.value),
         );
@@ -314,7 +321,7 @@ where
         let mut final_params = bumped_price_params;
         let value = tx_data.value;
         let gas_limit = tx_data.gas_limit;
-        let is_eip1559 = tx_data.is_eip1559();
+        let is_eip1559 = tx_data.is_eip1559() || tx_data.is_eip7702();
 
         if let Some(svc) = &self.extra_fee_calculator {
             let req = Self::build_request_from(tx_data, &final_params);
@@ -535,6 +542,17 @@ where
             .ok_or(TransactionError::NotSupported(
                 "Speed is required".to_string(),
             ))?;
+
+        // Set-code transactions are typed transactions and always use EIP1559 pricing
+        if tx_data.is_eip7702() {
+            if self.gas_price_service.network().is_legacy() {
+                return Err(TransactionError::NotSupported(
+                    "EIP-7702 transactions are not supported on legacy networks".to_string(),
+                ));
+            }
+            return self.fetch_eip1559_speed_params(speed).await;
+        }
+
         let use_legacy = relayer.policies.get_evm_policy().eip1559_pricing == Some(false)
             || self.gas_price_service.network().is_legacy();
 
@@ -754,6 +772,8 @@ mod tests {
             features: vec![],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -775,6 +795,8 @@ mod tests {
             features: vec!["eip1559".to_string()], // This makes it use EIP1559 pricing
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -791,6 +813,7 @@ mod tests {
             signer_id: "test-signer".to_string(),
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -899,6 +922,41 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_speed_eip7702_transaction_on_legacy_network() {
+        let provider = MockEvmProviderTrait::new();
+        let relayer = create_mock_relayer();
+        let gas_price_service =
+            EvmGasPriceService::new(provider, create_mock_evm_network("celo"), None);
+
+        let tx_data = EvmTransactionData {
+            gas_price: None,
+            speed: Some(Speed::Fast),
+            authorization_list: Some(vec![crate::models::EvmAuthorization {
+                chain_id: 1337,
+                address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
+                nonce: 0,
+                y_parity: 0,
+                r: "0x1".to_string(),
+                s: "0x2".to_string(),
+            }]),
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
+            ..Default::default()
+        };
+
+        let pc = PriceCalculator::new(gas_price_service, None::<NetworkExtraFeeCalculatorService>);
+
+        let result = pc.get_transaction_price_params(&tx_data, &relayer).await;
+        assert!(matches!(result, Err(TransactionError::NotSupported(_))));
+    }
+
     #[tokio::test]
     async fn test_invalid_transaction_type() {
         let mut provider = MockEvmProviderTrait::new();
@@ -1953,6 +2011,8 @@ mod tests {
             features: vec!["eip1559".to_string()],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         // Mock the network to return our arbitrum network
@@ -45,6 +45,13 @@ pub fn check_transaction_compatibility(
     let new_is_legacy = new_evm_data.is_legacy();
     let new_is_eip1559 = new_evm_data.is_eip1559();
 
+    // Set-code transactions are always priced as EIP1559 transactions
+    if old_is_legacy && new_evm_data.is_eip7702() {
+        return Err(TransactionError::ValidationError(
+            "Cannot replace legacy transaction with EIP-7702 transaction".to_string(),
+        ));
+    }
+
     // Allow replacement if new transaction has no explicit prices (will use market prices)
     if !has_explicit_prices(new_evm_data) {
         return Ok(());
@@ -298,8 +305,9 @@ pub async fn calculate_replacement_price<PC: PriceCalculatorTrait>(
     network_lacks_mempool: bool,
 ) -> Result<PriceParams, TransactionError> {
     // Determine transaction type based on old transaction and network policy
-    let use_legacy = old_evm_data.is_legacy()
-        || relayer.policies.get_evm_policy().eip1559_pricing == Some(false);
+    let use_legacy = !new_evm_data.is_eip7702()
+        && (old_evm_data.is_legacy()
+            || relayer.policies.get_evm_policy().eip1559_pricing == Some(false));
 
     // Get fresh market price for the updated transaction data
     let mut price_params = price_calculator
@@ -439,6 +447,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         }
     }
 
@@ -458,6 +476,16 @@ mod tests {
             max_fee_per_gas: Some(30_000_000_000), // 30 gwei
             max_priority_fee_per_gas: Some(2_000_000_000), // 2 gwei
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         }
     }
 
@@ -478,6 +506,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -546,6 +575,28 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_check_transaction_compatibility_eip7702() {
+        let old_legacy = create_legacy_transaction_data();
+        let old_eip1559 = create_eip1559_transaction_data();
+
+        // Set-code replacement without explicit prices
+        let mut new_eip7702 = create_eip1559_transaction_data();
+        new_eip7702.max_fee_per_gas = None;
+        new_eip7702.max_priority_fee_per_gas = None;
+        new_eip7702.authorization_list = Some(vec![crate::models::EvmAuthorization {
+            chain_id: 1,
+            address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            nonce: 0,
+            y_parity: 0,
+            r: "0x1".to_string(),
+            s: "0x2".to_string(),
+        }]);
+
+        assert!(check_transaction_compatibility(&old_eip1559, &new_eip7702).is_ok());
+        assert!(check_transaction_compatibility(&old_legacy, &new_eip7702).is_err());
+    }
+
     #[test]
     fn test_validate_explicit_price_bump_gas_price_cap() {
         let old_tx = create_legacy_transaction_data();
@@ -5,16 +5,21 @@
 use alloy::network::ReceiptResponse;
 use chrono::{DateTime, Duration, Utc};
 use eyre::Result;
-use log::info;
+use log::{info, warn};
 
 use super::EvmRelayerTransaction;
 use super::{
     get_age_of_sent_at, has_enough_confirmations, is_noop, is_transaction_valid, make_noop,
     too_many_attempts, too_many_noop_attempts,
 };
+use crate::config::PrivateRelayMethod;
 use crate::constants::ARBITRUM_TIME_TO_RESUBMIT;
-use crate::models::{EvmNetwork, NetworkRepoModel, NetworkType};
+use crate::models::{
+    produce_transaction_reorged_payload, EvmNetwork, NetworkRepoModel, NetworkType,
+    TransactionReceipt,
+};
 use crate::repositories::{NetworkRepository, RelayerRepository};
+use crate::services::gas::optimism_extra_fee::l1_fee_from_receipt;
 use crate::{
     domain::transaction::evm::price_calculator::PriceCalculatorTrait,
     jobs::JobProducerTrait,
@@ -38,15 +43,24 @@ where
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PC: PriceCalculatorTrait + Send + Sync,
 {
+    /// Checks the on-chain status of a transaction.
+    ///
+    /// A mined transaction whose receipt is no longer found is reported as `Submitted` again.
+    /// A receipt from a different block than the one recorded keeps the transaction mined, with
+    /// confirmations counted from the new block.
+    ///
+    /// # Returns
+    ///
+    /// The transaction status, with the receipt when the transaction is mined.
     pub(super) async fn check_transaction_status(
         &self,
         tx: &TransactionRepoModel,
-    ) -> Result<TransactionStatus, TransactionError> {
+    ) -> Result<(TransactionStatus, Option<TransactionReceipt>), TransactionError> {
         if tx.status == TransactionStatus::Expired
             || tx.status == TransactionStatus::Failed
             || tx.status == TransactionStatus::Confirmed
         {
-            return Ok(tx.status.clone());
+            return Ok((tx.status.clone(), None));
         }
 
         let evm_data = tx.network_data.get_evm_transaction_data()?;
@@ -61,7 +75,7 @@ where
 
         if let Some(receipt) = receipt_result {
             if !receipt.inner.status() {
-                return Ok(TransactionStatus::Failed);
+                return Ok((TransactionStatus::Failed, Some(receipt)));
             }
             let last_block_number = self.provider().get_block_number().await?;
             let tx_block_number = receipt
@@ -92,12 +106,12 @@ where
                 network.required_confirmations,
             ) {
                 info!("Transaction mined but not confirmed: {}", tx_hash);
-                return Ok(TransactionStatus::Mined);
+                return Ok((TransactionStatus::Mined, Some(receipt)));
             }
-            Ok(TransactionStatus::Confirmed)
+            Ok((TransactionStatus::Confirmed, Some(receipt)))
         } else {
             info!("Transaction not yet mined: {}", tx_hash);
-            Ok(TransactionStatus::Submitted)
+            Ok((TransactionStatus::Submitted, None))
         }
     }
 
@@ -186,7 +200,9 @@ where
             return Ok(true);
         }
 
-        if !is_transaction_valid(&tx.created_at, &tx.valid_until) {
+        // Scheduled transactions are only valid from the time they are sent
+        let valid_from = tx.execute_after.as_ref().unwrap_or(&tx.created_at);
+        if !is_transaction_valid(valid_from, &tx.valid_until) {
             info!("Transaction is expired, will replace with NOOP");
             return Ok(true);
         }
@@ -269,11 +285,40 @@ where
             return Ok(resubmitted_tx);
         }
 
+        self.rebroadcast_private_bundle(&tx).await;
         self.schedule_status_check(&tx, Some(5)).await?;
         self.update_transaction_status_if_needed(tx, TransactionStatus::Submitted)
             .await
     }
 
+    /// Re-sends a transaction that was submitted to a private relay as a bundle.
+    ///
+    /// A bundle only targets a single block and never reaches the public mempool, so it has to be
+    /// sent again on every status check until the transaction is mined or resubmitted.
+    async fn rebroadcast_private_bundle(&self, tx: &TransactionRepoModel) {
+        let is_bundle = self
+            .private_relay()
+            .is_some_and(|relay| relay.method() == PrivateRelayMethod::SendBundle);
+        if !is_bundle || !self.uses_private_transactions() {
+            return;
+        }
+
+        let raw_tx = match tx.network_data.get_evm_transaction_data() {
+            Ok(evm_data) => evm_data.raw,
+            Err(_) => None,
+        };
+        let Some(raw_tx) = raw_tx else {
+            return;
+        };
+
+        if let Err(e) = self.broadcast_raw_transaction(&raw_tx).await {
+            warn!(
+                "Failed to rebroadcast private bundle for tx {}: {}",
+                tx.id, e
+            );
+        }
+    }
+
     /// Processes transaction resubmission logic
     async fn handle_resubmission(
         &self,
@@ -331,23 +376,177 @@ where
         Ok(tx)
     }
 
+    /// Updates the transaction status along with the data of its receipt.
+    ///
+    /// A receipt from a different block than the one recorded means the transaction was
+    /// reorged into another block, so a `transaction_reorged` notification carrying the
+    /// previous block is sent as well.
+    async fn update_transaction_with_receipt(
+        &self,
+        tx: TransactionRepoModel,
+        new_status: TransactionStatus,
+        receipt: &TransactionReceipt,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let recorded_evm_data = tx.network_data.get_evm_transaction_data()?;
+        let previous_block = recorded_evm_data
+            .block_hash
+            .clone()
+            .filter(|recorded| {
+                receipt.block_hash.map(|hash| hash.to_string()).as_ref() != Some(recorded)
+            })
+            .map(|recorded| (recorded_evm_data.block_number, recorded));
+
+        let mut evm_data = recorded_evm_data.with_receipt(receipt);
+        evm_data.l1_fee = l1_fee_from_receipt(receipt);
+
+        let confirmed_at =
+            (new_status == TransactionStatus::Confirmed).then(|| Utc::now().to_rfc3339());
+        let update = TransactionUpdateRequest {
+            status: Some(new_status),
+            confirmed_at,
+            network_data: Some(NetworkTransactionData::Evm(evm_data)),
+            ..Default::default()
+        };
+        let updated_tx = self
+            .transaction_repository()
+            .partial_update(tx.id.clone(), update)
+            .await?;
+
+        if let Some((previous_block_number, previous_block_hash)) = previous_block {
+            warn!(
+                "Transaction {} moved from block {} to {:?}",
+                tx.id, previous_block_hash, receipt.block_hash
+            );
+            self.send_transaction_reorged_notification(
+                &updated_tx,
+                previous_block_number,
+                Some(previous_block_hash),
+            )
+            .await?;
+        }
+
+        self.send_transaction_update_notification(&updated_tx)
+            .await?;
+        Ok(updated_tx)
+    }
+
+    /// Sends a `transaction_reorged` notification if a notification ID is configured.
+    async fn send_transaction_reorged_notification(
+        &self,
+        tx: &TransactionRepoModel,
+        previous_block_number: Option<u64>,
+        previous_block_hash: Option<String>,
+    ) -> Result<(), TransactionError> {
+        if let Some(notification_id) = &self.relayer().notification_id {
+            self.job_producer()
+                .produce_send_notification_job(
+                    produce_transaction_reorged_payload(
+                        notification_id,
+                        tx,
+                        previous_block_number,
+                        previous_block_hash,
+                    ),
+                    None,
+                )
+                .await
+                .map_err(|e| {
+                    TransactionError::UnexpectedError(format!("Failed to send notification: {}", e))
+                })?;
+        }
+        Ok(())
+    }
+
     /// Handles transactions in the Mined state.
+    ///
+    /// Records the receipt of the block the transaction was mined in so later checks can detect
+    /// a reorg.
     async fn handle_mined_state(
         &self,
         tx: TransactionRepoModel,
+        receipt: Option<&TransactionReceipt>,
     ) -> Result<TransactionRepoModel, TransactionError> {
         self.schedule_status_check(&tx, Some(5)).await?;
-        self.update_transaction_status_if_needed(tx, TransactionStatus::Mined)
-            .await
+
+        let recorded_block_hash = tx.network_data.get_evm_transaction_data()?.block_hash;
+        match receipt {
+            Some(receipt)
+                if receipt.block_hash.is_some_and(|hash| {
+                    recorded_block_hash.as_ref() != Some(&hash.to_string())
+                }) =>
+            {
+                self.update_transaction_with_receipt(tx, TransactionStatus::Mined, receipt)
+                    .await
+            }
+            _ => {
+                self.update_transaction_status_if_needed(tx, TransactionStatus::Mined)
+                    .await
+            }
+        }
+    }
+
+    /// Handles mined transactions whose block was reorged out of the chain.
+    ///
+    /// The transaction is moved back to Submitted and resubmitted, and a `transaction_reorged`
+    /// notification carrying the block it was previously mined in is sent.
+    async fn handle_reorged_state(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let mut evm_data = tx.network_data.get_evm_transaction_data()?;
+        warn!(
+            "Transaction {} was reorged out of block {:?}, resubmitting",
+            tx.id, evm_data.block_hash
+        );
+
+        let previous_block_number = evm_data.block_number.take();
+        let previous_block_hash = evm_data.block_hash.take();
+        // The receipt of the orphaned block no longer describes the transaction
+        evm_data.gas_used = None;
+        evm_data.effective_gas_price = None;
+        evm_data.l1_fee = None;
+        evm_data.logs = None;
+        let update = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Submitted),
+            network_data: Some(NetworkTransactionData::Evm(evm_data)),
+            ..Default::default()
+        };
+        let updated_tx = self
+            .transaction_repository()
+            .partial_update(tx.id.clone(), update)
+            .await?;
+
+        self.send_transaction_reorged_notification(
+            &updated_tx,
+            previous_block_number,
+            previous_block_hash,
+        )
+        .await?;
+
+        self.send_transaction_resubmit_job(&updated_tx).await?;
+        self.schedule_status_check(&updated_tx, None).await?;
+        Ok(updated_tx)
     }
 
     /// Handles transactions in final states (Confirmed, Failed, Expired).
+    ///
+    /// A transaction that ends without being mined no longer counts against the spending
+    /// budgets.
     async fn handle_final_state(
         &self,
         tx: TransactionRepoModel,
         status: TransactionStatus,
+        receipt: Option<&TransactionReceipt>,
     ) -> Result<TransactionRepoModel, TransactionError> {
-        self.update_transaction_status_if_needed(tx, status).await
+        if receipt.is_none() && status != TransactionStatus::Confirmed && tx.status != status {
+            self.release_spend(&tx.id).await;
+        }
+        match receipt {
+            Some(receipt) => {
+                self.update_transaction_with_receipt(tx, status, receipt)
+                    .await
+            }
+            None => self.update_transaction_status_if_needed(tx, status).await,
+        }
     }
 
     /// Inherent status-handling method.
@@ -360,16 +559,22 @@ where
     ) -> Result<TransactionRepoModel, TransactionError> {
         info!("Checking transaction status for tx: {:?}", tx.id);
 
-        let status = self.check_transaction_status(&tx).await?;
+        let (status, receipt) = self.check_transaction_status(&tx).await?;
         info!("Transaction status: {:?}", status);
 
         match status {
+            // A mined transaction only goes back to Submitted when its receipt disappeared
+            TransactionStatus::Submitted if tx.status == TransactionStatus::Mined => {
+                self.handle_reorged_state(tx).await
+            }
             TransactionStatus::Submitted => self.handle_submitted_state(tx).await,
             TransactionStatus::Pending => self.handle_pending_state(tx).await,
-            TransactionStatus::Mined => self.handle_mined_state(tx).await,
+            TransactionStatus::Mined => self.handle_mined_state(tx, receipt.as_ref()).await,
             TransactionStatus::Confirmed
             | TransactionStatus::Failed
-            | TransactionStatus::Expired => self.handle_final_state(tx, status).await,
+            | TransactionStatus::Expired => {
+                self.handle_final_state(tx, status, receipt.as_ref()).await
+            }
             _ => Err(TransactionError::UnexpectedError(format!(
                 "Unexpected transaction status: {:?}",
                 status
@@ -381,19 +586,20 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        config::{EvmNetworkConfig, NetworkConfigCommon},
+        config::{EvmNetworkConfig, NetworkConfigCommon, PrivateRelayConfig, PrivateRelayMethod},
         domain::transaction::evm::{EvmRelayerTransaction, MockPriceCalculatorTrait},
-        jobs::MockJobProducerTrait,
+        jobs::{MockJobProducerTrait, TransactionCommand},
         models::{
             evm::Speed, EvmTransactionData, NetworkConfigData, NetworkRepoModel,
             NetworkTransactionData, NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy,
-            RelayerRepoModel, TransactionReceipt, TransactionRepoModel, TransactionStatus, U256,
+            RelayerRepoModel, TransactionReceipt, TransactionRepoModel, TransactionStatus,
+            WebhookPayload, U256,
         },
         repositories::{
             MockNetworkRepository, MockRelayerRepository, MockTransactionCounterTrait,
             MockTransactionRepository,
         },
-        services::{MockEvmProviderTrait, MockSigner},
+        services::{MockEvmProviderTrait, MockSigner, PrivateRelayService},
     };
     use alloy::{
         consensus::{Eip658Value, Receipt, ReceiptWithBloom},
@@ -464,6 +670,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string()]),
             symbol: Some("ETH".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
         NetworkRepoModel {
             id: "evm:mainnet".to_string(),
@@ -490,6 +698,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string()]),
             symbol: Some("ETH".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
         NetworkRepoModel {
             id: "evm:arbitrum".to_string(),
@@ -529,11 +739,25 @@ mod tests {
                 hash: None,
                 speed: Some(Speed::Fast),
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: Vec::new(),
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -579,6 +803,7 @@ mod tests {
             address: "0x".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -644,7 +869,7 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
 
-            let status = evm_transaction.check_transaction_status(&tx).await.unwrap();
+            let (status, _) = evm_transaction.check_transaction_status(&tx).await.unwrap();
             assert_eq!(status, TransactionStatus::Submitted);
         }
 
@@ -678,7 +903,7 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
 
-            let status = evm_transaction.check_transaction_status(&tx).await.unwrap();
+            let (status, _) = evm_transaction.check_transaction_status(&tx).await.unwrap();
             assert_eq!(status, TransactionStatus::Mined);
         }
 
@@ -712,7 +937,7 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
 
-            let status = evm_transaction.check_transaction_status(&tx).await.unwrap();
+            let (status, _) = evm_transaction.check_transaction_status(&tx).await.unwrap();
             assert_eq!(status, TransactionStatus::Confirmed);
         }
 
@@ -734,9 +959,41 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
 
-            let status = evm_transaction.check_transaction_status(&tx).await.unwrap();
+            let (status, _) = evm_transaction.check_transaction_status(&tx).await.unwrap();
             assert_eq!(status, TransactionStatus::Failed);
         }
+
+        #[tokio::test]
+        async fn test_reorged_into_different_block() {
+            let mut mocks = default_test_mocks();
+            let relayer = create_test_relayer();
+            let mut tx = make_test_transaction(TransactionStatus::Mined);
+            if let NetworkTransactionData::Evm(ref mut evm_data) = tx.network_data {
+                evm_data.hash = Some("0xFakeHash".to_string());
+                evm_data.block_number = Some(100);
+                evm_data.block_hash = Some(format!("0x{}", "cc".repeat(32)));
+            }
+
+            // The receipt now points at a different block
+            mocks
+                .provider
+                .expect_get_transaction_receipt()
+                .returning(|_| Box::pin(async { Ok(Some(make_mock_receipt(true, Some(101)))) }));
+            mocks
+                .provider
+                .expect_get_block_number()
+                .return_once(|| Box::pin(async { Ok(101) }));
+            mocks
+                .network_repo
+                .expect_get_by_chain_id()
+                .returning(|_, _| Ok(Some(create_test_network_model())));
+
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+
+            let (status, receipt) = evm_transaction.check_transaction_status(&tx).await.unwrap();
+            assert_eq!(status, TransactionStatus::Mined);
+            assert_eq!(receipt.unwrap().block_number, Some(101));
+        }
     }
 
     // Tests for `should_resubmit`
@@ -866,6 +1123,8 @@ mod tests {
                 features: Some(vec!["eip1559".to_string()]),
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             };
             let invalid_network = NetworkRepoModel {
                 id: "evm:invalid".to_string(),
@@ -1029,6 +1288,62 @@ mod tests {
             // We remain in "Submitted" after scheduling the resubmit
             assert_eq!(updated_tx.status, TransactionStatus::Submitted);
         }
+
+        #[tokio::test]
+        async fn test_rebroadcasts_private_bundle() {
+            let mut server = mockito::Server::new_async().await;
+            let relay_mock = server
+                .mock("POST", "/")
+                .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                    "method": "eth_sendBundle",
+                    "params": [{ "txs": ["0x010203"], "blockNumber": "0x65" }]
+                })))
+                .with_status(200)
+                .with_body(r#"{"jsonrpc":"2.0","id":1,"result":{"bundleHash":"0xabc"}}"#)
+                .expect(1)
+                .create_async()
+                .await;
+            let private_relay = PrivateRelayService::new(&PrivateRelayConfig {
+                url: server.url(),
+                method: PrivateRelayMethod::SendBundle,
+                auth_key_env: None,
+            })
+            .unwrap();
+
+            let mut mocks = default_test_mocks();
+            let mut relayer = create_test_relayer();
+            relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                private_transactions: Some(true),
+                ..Default::default()
+            });
+
+            // Recently sent, so no resubmission is needed
+            let mut tx = make_test_transaction(TransactionStatus::Submitted);
+            tx.sent_at = Some(Utc::now().to_rfc3339());
+            if let NetworkTransactionData::Evm(ref mut evm_data) = tx.network_data {
+                evm_data.raw = Some(vec![1, 2, 3]);
+            }
+
+            mocks
+                .network_repo
+                .expect_get_by_chain_id()
+                .returning(|_, _| Ok(Some(create_test_network_model())));
+            mocks
+                .provider
+                .expect_get_block_number()
+                .returning(|| Box::pin(async { Ok(100) }));
+            mocks
+                .job_producer
+                .expect_produce_check_transaction_status_job()
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+
+            let evm_transaction =
+                make_test_evm_relayer_transaction(relayer, mocks).with_private_relay(private_relay);
+            let updated_tx = evm_transaction.handle_submitted_state(tx).await.unwrap();
+
+            assert_eq!(updated_tx.status, TransactionStatus::Submitted);
+            relay_mock.assert_async().await;
+        }
     }
 
     // Tests for `handle_pending_state`
@@ -1140,7 +1455,47 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
             let result = evm_transaction
-                .handle_mined_state(tx.clone())
+                .handle_mined_state(tx.clone(), None)
+                .await
+                .unwrap();
+            assert_eq!(result.status, TransactionStatus::Mined);
+        }
+
+        #[tokio::test]
+        async fn test_records_mined_block() {
+            let mut mocks = default_test_mocks();
+            let relayer = create_test_relayer();
+            let tx = make_test_transaction(TransactionStatus::Submitted);
+            let receipt = make_mock_receipt(true, Some(100));
+
+            mocks
+                .job_producer
+                .expect_produce_check_transaction_status_job()
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .withf(|_, update| {
+                    let Some(NetworkTransactionData::Evm(evm_data)) = &update.network_data else {
+                        return false;
+                    };
+                    update.status == Some(TransactionStatus::Mined)
+                        && evm_data.block_number == Some(100)
+                        && evm_data.block_hash == Some(format!("0x{}", "bb".repeat(32)))
+                        && evm_data.gas_used == Some(21000)
+                        && evm_data.effective_gas_price == Some(1000)
+                })
+                .times(1)
+                .returning(|_, update| {
+                    let mut updated_tx = make_test_transaction(TransactionStatus::Submitted);
+                    updated_tx.status = update.status.unwrap();
+                    updated_tx.network_data = update.network_data.unwrap();
+                    Ok(updated_tx)
+                });
+
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+            let result = evm_transaction
+                .handle_mined_state(tx, Some(&receipt))
                 .await
                 .unwrap();
             assert_eq!(result.status, TransactionStatus::Mined);
@@ -1169,7 +1524,44 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
             let result = evm_transaction
-                .handle_final_state(tx.clone(), TransactionStatus::Confirmed)
+                .handle_final_state(tx.clone(), TransactionStatus::Confirmed, None)
+                .await
+                .unwrap();
+            assert_eq!(result.status, TransactionStatus::Confirmed);
+        }
+
+        #[tokio::test]
+        async fn test_final_state_confirmed_records_receipt() {
+            let mut mocks = default_test_mocks();
+            let relayer = create_test_relayer();
+            let tx = make_test_transaction(TransactionStatus::Mined);
+            let receipt = make_mock_receipt(true, Some(100));
+
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .withf(|_, update| {
+                    let Some(NetworkTransactionData::Evm(evm_data)) = &update.network_data else {
+                        return false;
+                    };
+                    update.status == Some(TransactionStatus::Confirmed)
+                        && update.confirmed_at.is_some()
+                        && evm_data.gas_used == Some(21000)
+                        && evm_data.effective_gas_price == Some(1000)
+                        && evm_data.logs == Some(vec![])
+                        && evm_data.l1_fee.is_none()
+                })
+                .times(1)
+                .returning(|_, update| {
+                    let mut updated_tx = make_test_transaction(TransactionStatus::Mined);
+                    updated_tx.status = update.status.unwrap();
+                    updated_tx.network_data = update.network_data.unwrap();
+                    Ok(updated_tx)
+                });
+
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+            let result = evm_transaction
+                .handle_final_state(tx, TransactionStatus::Confirmed, Some(&receipt))
                 .await
                 .unwrap();
             assert_eq!(result.status, TransactionStatus::Confirmed);
@@ -1193,7 +1585,7 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
             let result = evm_transaction
-                .handle_final_state(tx.clone(), TransactionStatus::Failed)
+                .handle_final_state(tx.clone(), TransactionStatus::Failed, None)
                 .await
                 .unwrap();
             assert_eq!(result.status, TransactionStatus::Failed);
@@ -1217,11 +1609,75 @@ mod tests {
 
             let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
             let result = evm_transaction
-                .handle_final_state(tx.clone(), TransactionStatus::Expired)
+                .handle_final_state(tx.clone(), TransactionStatus::Expired, None)
                 .await
                 .unwrap();
             assert_eq!(result.status, TransactionStatus::Expired);
         }
+
+        #[tokio::test]
+        async fn test_final_state_expired_releases_spend() {
+            let mut mocks = default_test_mocks();
+            let mut relayer = create_test_relayer();
+            relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_total_value: Some(1_000_000_000_000_000_000),
+                ..Default::default()
+            });
+            let tx = make_test_transaction(TransactionStatus::Submitted);
+
+            // The expired transaction no longer counts against the budgets
+            mocks
+                .relayer_repo
+                .expect_remove_spend()
+                .withf(|relayer_id, tx_id| relayer_id == "test-relayer-id" && tx_id == "test-tx-id")
+                .times(1)
+                .returning(|_, _| Ok(()));
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .returning(|_, update| {
+                    let mut updated_tx = make_test_transaction(TransactionStatus::Submitted);
+                    updated_tx.status = update.status.unwrap_or(updated_tx.status);
+                    Ok(updated_tx)
+                });
+
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+            let result = evm_transaction
+                .handle_final_state(tx.clone(), TransactionStatus::Expired, None)
+                .await
+                .unwrap();
+            assert_eq!(result.status, TransactionStatus::Expired);
+        }
+
+        #[tokio::test]
+        async fn test_final_state_reverted_keeps_spend() {
+            let mut mocks = default_test_mocks();
+            let mut relayer = create_test_relayer();
+            relayer.policies = RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                max_gas_spend: Some(1_000_000_000_000_000),
+                ..Default::default()
+            });
+            let tx = make_test_transaction(TransactionStatus::Submitted);
+
+            // A reverted transaction was mined, so its gas was spent
+            mocks.relayer_repo.expect_remove_spend().never();
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .returning(|_, update| {
+                    let mut updated_tx = make_test_transaction(TransactionStatus::Submitted);
+                    updated_tx.status = update.status.unwrap_or(updated_tx.status);
+                    Ok(updated_tx)
+                });
+
+            let receipt = make_mock_receipt(false, Some(100));
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+            let result = evm_transaction
+                .handle_final_state(tx.clone(), TransactionStatus::Failed, Some(&receipt))
+                .await
+                .unwrap();
+            assert_eq!(result.status, TransactionStatus::Failed);
+        }
     }
 
     // Integration tests for `handle_status_impl`
@@ -1376,5 +1832,162 @@ mod tests {
             let result = evm_transaction.handle_status_impl(tx).await.unwrap();
             assert_eq!(result.status, TransactionStatus::Expired);
         }
+
+        #[tokio::test]
+        async fn test_impl_moved_to_different_block_sends_reorged_notification() {
+            let mut mocks = default_test_mocks();
+            let mut relayer = create_test_relayer();
+            relayer.notification_id = Some("test-notification".to_string());
+            let mut tx = make_test_transaction(TransactionStatus::Mined);
+            if let NetworkTransactionData::Evm(ref mut evm_data) = tx.network_data {
+                evm_data.hash = Some("0xFakeHash".to_string());
+                evm_data.block_number = Some(100);
+                evm_data.block_hash = Some(format!("0x{}", "cc".repeat(32)));
+            }
+
+            // The transaction was included again in another block
+            mocks
+                .provider
+                .expect_get_transaction_receipt()
+                .returning(|_| Box::pin(async { Ok(Some(make_mock_receipt(true, Some(101)))) }));
+            mocks
+                .provider
+                .expect_get_block_number()
+                .return_once(|| Box::pin(async { Ok(101) }));
+            mocks
+                .network_repo
+                .expect_get_by_chain_id()
+                .returning(|_, _| Ok(Some(create_test_network_model())));
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .withf(|_, update| {
+                    let Some(NetworkTransactionData::Evm(evm_data)) = &update.network_data else {
+                        return false;
+                    };
+                    update.status == Some(TransactionStatus::Mined)
+                        && evm_data.block_number == Some(101)
+                        && evm_data.block_hash == Some(format!("0x{}", "bb".repeat(32)))
+                })
+                .times(1)
+                .returning(|_, update| {
+                    let mut updated_tx = make_test_transaction(TransactionStatus::Mined);
+                    updated_tx.status = update.status.unwrap();
+                    updated_tx.network_data = update.network_data.unwrap();
+                    Ok(updated_tx)
+                });
+            mocks
+                .job_producer
+                .expect_produce_send_notification_job()
+                .withf(|notification, _| {
+                    notification.notification.event == "transaction_reorged"
+                        && matches!(
+                            &notification.notification.payload,
+                            WebhookPayload::TransactionReorged(payload)
+                                if payload.previous_block_number == Some(100)
+                                    && payload.previous_block_hash
+                                        == Some(format!("0x{}", "cc".repeat(32)))
+                        )
+                })
+                .times(1)
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            mocks
+                .job_producer
+                .expect_produce_send_notification_job()
+                .withf(|notification, _| notification.notification.event != "transaction_reorged")
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            mocks
+                .job_producer
+                .expect_produce_submit_transaction_job()
+                .never();
+            mocks
+                .job_producer
+                .expect_produce_check_transaction_status_job()
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+            let result = evm_transaction.handle_status_impl(tx).await.unwrap();
+            assert_eq!(result.status, TransactionStatus::Mined);
+            let evm_data = result.network_data.get_evm_transaction_data().unwrap();
+            assert_eq!(evm_data.block_number, Some(101));
+        }
+
+        #[tokio::test]
+        async fn test_impl_reorged_branch() {
+            let mut mocks = default_test_mocks();
+            let mut relayer = create_test_relayer();
+            relayer.notification_id = Some("test-notification".to_string());
+            let mut tx = make_test_transaction(TransactionStatus::Mined);
+            if let NetworkTransactionData::Evm(ref mut evm_data) = tx.network_data {
+                evm_data.hash = Some("0xFakeHash".to_string());
+                evm_data.block_number = Some(100);
+                evm_data.block_hash = Some(format!("0x{}", "bb".repeat(32)));
+                evm_data.gas_used = Some(21000);
+                evm_data.effective_gas_price = Some(30_000_000_000);
+                evm_data.l1_fee = Some(1_000_000);
+                evm_data.logs = Some(vec![]);
+            }
+
+            // The receipt disappeared after the transaction was mined
+            mocks
+                .provider
+                .expect_get_transaction_receipt()
+                .returning(|_| Box::pin(async { Ok(None) }));
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .withf(|_, update| {
+                    let Some(NetworkTransactionData::Evm(evm_data)) = &update.network_data else {
+                        return false;
+                    };
+                    update.status == Some(TransactionStatus::Submitted)
+                        && evm_data.block_number.is_none()
+                        && evm_data.block_hash.is_none()
+                        && evm_data.gas_used.is_none()
+                        && evm_data.effective_gas_price.is_none()
+                        && evm_data.l1_fee.is_none()
+                        && evm_data.logs.is_none()
+                })
+                .times(1)
+                .returning(|_, update| {
+                    let mut updated_tx = make_test_transaction(TransactionStatus::Mined);
+                    updated_tx.status = update.status.unwrap();
+                    updated_tx.network_data = update.network_data.unwrap();
+                    Ok(updated_tx)
+                });
+            mocks
+                .job_producer
+                .expect_produce_send_notification_job()
+                .withf(|notification, _| {
+                    notification.notification.event == "transaction_reorged"
+                        && matches!(
+                            &notification.notification.payload,
+                            WebhookPayload::TransactionReorged(payload)
+                                if payload.previous_block_number == Some(100)
+                        )
+                })
+                .times(1)
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            mocks
+                .job_producer
+                .expect_produce_submit_transaction_job()
+                .withf(|job, _| matches!(job.command, TransactionCommand::Resubmit))
+                .times(1)
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            mocks
+                .job_producer
+                .expect_produce_check_transaction_status_job()
+                .times(1)
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+
+            let evm_transaction = make_test_evm_relayer_transaction(relayer, mocks);
+            let result = evm_transaction.handle_status_impl(tx).await.unwrap();
+            assert_eq!(result.status, TransactionStatus::Submitted);
+            let evm_data = result.network_data.get_evm_transaction_data().unwrap();
+            assert_eq!(evm_data.gas_used, None);
+            assert_eq!(evm_data.effective_gas_price, None);
+            assert_eq!(evm_data.l1_fee, None);
+            assert!(evm_data.logs.is_none());
+        }
     }
 }
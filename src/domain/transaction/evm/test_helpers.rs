@@ -75,11 +75,25 @@ pub mod test_utils {
                 hash: None,
                 speed: Some(Speed::Fast),
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: Vec::new(),
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         }
     }
@@ -98,6 +112,7 @@ pub mod test_utils {
             address: "0x".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -7,6 +7,7 @@ use crate::models::{
     EvmTransactionData, TransactionError, TransactionRepoModel, TransactionStatus, U256,
 };
 use crate::services::EvmProviderTrait;
+use alloy::sol_types::{Panic, Revert, SolError};
 use chrono::{DateTime, Duration, Utc};
 use eyre::Result;
 
@@ -22,6 +23,8 @@ pub async fn make_noop<P: EvmProviderTrait>(
     evm_data.value = U256::from(0u64);
     evm_data.data = Some("0x".to_string());
     evm_data.to = Some(evm_data.from.clone());
+    evm_data.authorization_list = None;
+    evm_data.access_list = None;
 
     // Set gas limit based on network type
     if network.is_arbitrum() {
@@ -122,12 +125,34 @@ pub fn get_age_of_sent_at(tx: &TransactionRepoModel) -> Result<Duration, Transac
     Ok(now.signed_duration_since(sent_time))
 }
 
+/// Decodes the revert data of a failed call into a human readable reason.
+///
+/// `Error(string)` and `Panic(uint256)` are decoded; any other payload is reported
+/// by its custom error selector.
+pub fn decode_revert_reason(data: &[u8]) -> String {
+    if let Ok(revert) = Revert::abi_decode(data) {
+        return format!("execution reverted: {}", revert.reason);
+    }
+    if let Ok(panic) = Panic::abi_decode(data) {
+        return format!("execution reverted: {}", panic);
+    }
+    match data.get(..4) {
+        Some(selector) => format!(
+            "execution reverted: custom error 0x{} (data: 0x{})",
+            hex::encode(selector),
+            hex::encode(data)
+        ),
+        None => "execution reverted".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::constants::{ARBITRUM_BASED_TAG, ROLLUP_TAG};
     use crate::models::{evm::Speed, NetworkTransactionData};
     use crate::services::{MockEvmProviderTrait, ProviderError};
+    use alloy::sol_types::PanicKind;
 
     fn create_standard_network() -> EvmNetwork {
         EvmNetwork {
@@ -142,6 +167,8 @@ mod tests {
             features: vec!["eip1559".to_string()],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -158,6 +185,8 @@ mod tests {
             features: vec!["eip1559".to_string()],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -174,6 +203,8 @@ mod tests {
             features: vec!["eip1559".to_string()],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -194,6 +225,16 @@ mod tests {
             speed: Some(Speed::Fast),
             chain_id: 1,
             raw: Some(vec![1, 2, 3]),
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         let network = create_standard_network();
@@ -225,6 +266,16 @@ mod tests {
             speed: Some(Speed::Fast),
             chain_id: 42161, // Arbitrum One
             raw: Some(vec![1, 2, 3]),
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         let network = create_arbitrum_network();
@@ -257,6 +308,16 @@ mod tests {
             speed: Some(Speed::Fast),
             chain_id: 42170, // Arbitrum Nova
             raw: Some(vec![1, 2, 3]),
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         let network = create_arbitrum_nova_network();
@@ -297,6 +358,16 @@ mod tests {
             speed: Some(Speed::Fast),
             chain_id: 42161, // Arbitrum One
             raw: Some(vec![1, 2, 3]),
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         let network = create_arbitrum_network();
@@ -336,6 +407,16 @@ mod tests {
             speed: Some(Speed::Fast),
             chain_id: 42161, // Arbitrum One
             raw: Some(vec![1, 2, 3]),
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         let network = create_arbitrum_network();
@@ -369,6 +450,16 @@ mod tests {
             speed: Some(Speed::Fast),
             chain_id: 1,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
         assert!(is_noop(&noop_tx));
 
@@ -417,11 +508,25 @@ mod tests {
                 speed: Some(Speed::Fast),
                 chain_id: 1,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: vec![], // Start with no attempts
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -464,11 +569,25 @@ mod tests {
                 speed: Some(Speed::Fast),
                 chain_id: 1,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: vec![],
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -642,11 +761,25 @@ mod tests {
                 speed: Some(Speed::Fast),
                 chain_id: 1,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: vec![],
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -684,11 +817,25 @@ mod tests {
                 speed: Some(Speed::Fast),
                 chain_id: 1,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: vec![],
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -729,11 +876,25 @@ mod tests {
                 speed: Some(Speed::Fast),
                 chain_id: 1,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: vec![],
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -746,4 +907,37 @@ mod tests {
             _ => panic!("Expected UnexpectedError for invalid timestamp"),
         }
     }
+
+    #[test]
+    fn test_decode_revert_reason_error_string() {
+        let data = Revert::from("Insufficient allowance").abi_encode();
+        assert_eq!(
+            decode_revert_reason(&data),
+            "execution reverted: Insufficient allowance"
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_reason_panic() {
+        let data = Panic::from(PanicKind::UnderOverflow).abi_encode();
+        assert_eq!(
+            decode_revert_reason(&data),
+            "execution reverted: panic: arithmetic underflow or overflow (0x11)"
+        );
+    }
+
+    #[test]
+    fn test_decode_revert_reason_custom_error() {
+        // Unauthorized(address) with a zero address argument
+        let mut data = vec![0x82, 0xb4, 0x29, 0x00];
+        data.extend_from_slice(&[0u8; 32]);
+
+        let reason = decode_revert_reason(&data);
+        assert!(reason.starts_with("execution reverted: custom error 0x82b42900"));
+    }
+
+    #[test]
+    fn test_decode_revert_reason_empty_data() {
+        assert_eq!(decode_revert_reason(&[]), "execution reverted");
+    }
 }
@@ -26,7 +26,8 @@ use crate::{
             cache::GasPriceCache, evm_gas_price::EvmGasPriceService,
             network_extra_fee::NetworkExtraFeeCalculatorService,
         },
-        get_network_provider, EvmSignerFactory, StellarSignerFactory,
+        get_network_provider, EvmSignerFactory, PrivateRelayService, SolanaSignerFactory,
+        StellarSignerFactory,
     },
 };
 use async_trait::async_trait;
@@ -39,11 +40,14 @@ pub mod evm;
 pub mod solana;
 pub mod stellar;
 
+mod dependencies;
+pub use dependencies::*;
+
 mod util;
 pub use util::*;
 
 // Explicit re-exports to avoid ambiguous glob re-exports
-pub use evm::{DefaultEvmTransaction, EvmRelayerTransaction};
+pub use evm::{sign_evm_authorizations, DefaultEvmTransaction, EvmRelayerTransaction};
 pub use solana::{DefaultSolanaTransaction, SolanaRelayerTransaction};
 pub use stellar::{DefaultStellarTransaction, StellarRelayerTransaction};
 
@@ -273,7 +277,7 @@ impl Transaction for NetworkTransaction {
     ) -> Result<TransactionRepoModel, TransactionError> {
         match self {
             NetworkTransaction::Evm(relayer) => relayer.cancel_transaction(tx).await,
-            NetworkTransaction::Solana(_) => solana_not_supported_transaction(),
+            NetworkTransaction::Solana(relayer) => relayer.cancel_transaction(tx).await,
             NetworkTransaction::Stellar(relayer) => relayer.cancel_transaction(tx).await,
         }
     }
@@ -297,7 +301,9 @@ impl Transaction for NetworkTransaction {
             NetworkTransaction::Evm(relayer) => {
                 relayer.replace_transaction(old_tx, new_tx_request).await
             }
-            NetworkTransaction::Solana(_) => solana_not_supported_transaction(),
+            NetworkTransaction::Solana(relayer) => {
+                relayer.replace_transaction(old_tx, new_tx_request).await
+            }
             NetworkTransaction::Stellar(relayer) => {
                 relayer.replace_transaction(old_tx, new_tx_request).await
             }
@@ -439,21 +445,40 @@ impl RelayerTransactionFactory {
                     Some(network_extra_fee_calculator),
                 );
 
-                Ok(NetworkTransaction::Evm(Box::new(
-                    DefaultEvmTransaction::new(
-                        relayer,
-                        evm_provider,
-                        relayer_repository,
-                        network_repository,
-                        transaction_repository,
-                        transaction_counter_store,
-                        job_producer,
-                        price_calculator,
-                        signer_service,
-                    )?,
-                )))
+                let private_relay = match &network.private_relay {
+                    Some(cfg)
+                        if relayer
+                            .policies
+                            .get_evm_policy()
+                            .private_transactions
+                            .unwrap_or(false) =>
+                    {
+                        Some(PrivateRelayService::new(cfg)?)
+                    }
+                    _ => None,
+                };
+
+                let mut evm_transaction = DefaultEvmTransaction::new(
+                    relayer,
+                    evm_provider,
+                    relayer_repository,
+                    network_repository,
+                    transaction_repository,
+                    transaction_counter_store,
+                    job_producer,
+                    price_calculator,
+                    signer_service,
+                )?;
+                if let Some(private_relay) = private_relay {
+                    evm_transaction = evm_transaction.with_private_relay(private_relay);
+                }
+
+                Ok(NetworkTransaction::Evm(Box::new(evm_transaction)))
             }
             NetworkType::Solana => {
+                let signer_service =
+                    Arc::new(SolanaSignerFactory::create_solana_signer(&signer.into())?);
+
                 let network_repo = network_repository
                     .get_by_name(NetworkType::Solana, &relayer.network)
                     .await
@@ -480,6 +505,7 @@ impl RelayerTransactionFactory {
                     solana_provider,
                     transaction_repository,
                     job_producer,
+                    signer_service,
                 )?))
             }
             NetworkType::Stellar => {
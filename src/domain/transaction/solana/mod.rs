@@ -1,4 +1,16 @@
 mod solana_transaction;
 pub use solana_transaction::*;
 
+mod prepare;
+
+mod submit;
+
+mod replacement;
+
 pub mod status;
+
+mod utils;
+pub use utils::*;
+
+#[cfg(test)]
+pub mod test_helpers;
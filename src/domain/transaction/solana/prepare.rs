@@ -0,0 +1,432 @@
+//! This module contains the preparation-related functionality for Solana transactions.
+//! It validates the transaction against the relayer policy, refreshes the blockhash when
+//! the relayer is the only signer, signs as fee payer and enqueues the submission job.
+
+use log::{info, warn};
+use solana_sdk::{pubkey::Pubkey, transaction::Transaction};
+
+use super::{
+    utils::{
+        decode_solana_transaction, encode_solana_transaction, has_missing_signatures,
+        is_relayer_sole_signer, map_validation_error, parse_relayer_pubkey,
+    },
+    SolanaRelayerTransaction,
+};
+use crate::{
+    domain::{SolanaTransactionValidationError, SolanaTransactionValidator},
+    jobs::{JobProducerTrait, TransactionSend},
+    models::{
+        NetworkTransactionData, RelayerRepoModel, SolanaTransactionData, TransactionError,
+        TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
+    },
+    repositories::{RelayerRepository, Repository, TransactionRepository},
+    services::{SolanaProviderTrait, SolanaSignTrait},
+};
+
+impl<P, RR, TR, J, S> SolanaRelayerTransaction<P, RR, TR, J, S>
+where
+    P: SolanaProviderTrait,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    J: JobProducerTrait + Send + Sync + 'static,
+    S: SolanaSignTrait + Send + Sync + 'static,
+{
+    /// Main preparation method with error handling.
+    pub async fn prepare_transaction_impl(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        if tx.status != TransactionStatus::Pending {
+            info!(
+                "Transaction {} is not pending (status: {:?}), skipping preparation",
+                tx.id, tx.status
+            );
+            return Ok(tx);
+        }
+
+        info!("Preparing Solana transaction: {:?}", tx.id);
+
+        match self.prepare_core(tx.clone()).await {
+            Ok(prepared_tx) => Ok(prepared_tx),
+            Err(error) => self.handle_prepare_failure(tx, error).await,
+        }
+    }
+
+    /// Core preparation logic
+    async fn prepare_core(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let solana_data = tx.network_data.get_solana_transaction_data()?;
+        let mut transaction = decode_solana_transaction(&solana_data)?;
+        let relayer_pubkey = parse_relayer_pubkey(&self.relayer().address)?;
+
+        // Transactions signed only by the relayer can always be built on a fresh blockhash.
+        if is_relayer_sole_signer(&transaction, &relayer_pubkey) {
+            transaction.message.recent_blockhash = self.provider().get_latest_blockhash().await?;
+        } else if has_missing_signatures(&transaction) {
+            return Err(TransactionError::ValidationError(
+                "Transaction is missing signatures from required signers".to_string(),
+            ));
+        }
+
+        let signed_transaction = self.validate_and_sign(transaction, &relayer_pubkey).await?;
+
+        let signature = signed_transaction.signatures[0].to_string();
+        let update_request = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Sent),
+            network_data: Some(NetworkTransactionData::Solana(SolanaTransactionData {
+                transaction: encode_solana_transaction(&signed_transaction)?,
+                signature: Some(signature),
+            })),
+            ..Default::default()
+        };
+
+        let updated_tx = self
+            .transaction_repository()
+            .partial_update(tx.id.clone(), update_request)
+            .await?;
+
+        self.job_producer()
+            .produce_submit_transaction_job(
+                TransactionSend::submit(updated_tx.id.clone(), updated_tx.relayer_id.clone()),
+                None,
+            )
+            .await?;
+
+        self.send_transaction_update_notification(&updated_tx)
+            .await?;
+
+        Ok(updated_tx)
+    }
+
+    /// Applies the relayer policy to the transaction and signs it as fee payer.
+    pub(super) async fn validate_and_sign(
+        &self,
+        transaction: Transaction,
+        relayer_pubkey: &Pubkey,
+    ) -> Result<Transaction, TransactionError> {
+        self.validate_policy(&transaction, relayer_pubkey)
+            .await
+            .map_err(map_validation_error)?;
+
+        let policy = self.relayer().policies.get_solana_policy();
+        let total_fee = self
+            .provider()
+            .calculate_total_fee(&transaction.message)
+            .await?;
+
+        SolanaTransactionValidator::validate_max_fee(total_fee, &policy)
+            .map_err(map_validation_error)?;
+        SolanaTransactionValidator::validate_sufficient_relayer_balance(
+            total_fee,
+            &self.relayer().address,
+            &policy,
+            self.provider(),
+        )
+        .await
+        .map_err(map_validation_error)?;
+
+        self.sign_as_fee_payer(transaction).await
+    }
+
+    /// Runs every policy check the relayer enforces before paying for a transaction.
+    async fn validate_policy(
+        &self,
+        transaction: &Transaction,
+        relayer_pubkey: &Pubkey,
+    ) -> Result<(), SolanaTransactionValidationError> {
+        let policy = self.relayer().policies.get_solana_policy();
+
+        SolanaTransactionValidator::validate_tx_allowed_accounts(transaction, &policy)?;
+        SolanaTransactionValidator::validate_tx_disallowed_accounts(transaction, &policy)?;
+        SolanaTransactionValidator::validate_allowed_programs(transaction, &policy)?;
+        SolanaTransactionValidator::validate_max_signatures(transaction, &policy)?;
+        SolanaTransactionValidator::validate_fee_payer(transaction, relayer_pubkey)?;
+        SolanaTransactionValidator::validate_data_size(transaction, &policy)?;
+
+        SolanaTransactionValidator::validate_blockhash(transaction, self.provider()).await?;
+        SolanaTransactionValidator::validate_lamports_transfers(transaction, relayer_pubkey)
+            .await?;
+        SolanaTransactionValidator::validate_token_transfers(
+            transaction,
+            &policy,
+            self.provider(),
+            relayer_pubkey,
+        )
+        .await?;
+        let simulation =
+            SolanaTransactionValidator::simulate_transaction(transaction, self.provider()).await?;
+        if let Some(err) = simulation.err {
+            return Err(SolanaTransactionValidationError::SimulationError(format!(
+                "{:?}",
+                err
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Signs the transaction message with the relayer key in the fee payer slot.
+    async fn sign_as_fee_payer(
+        &self,
+        mut transaction: Transaction,
+    ) -> Result<Transaction, TransactionError> {
+        let required_signatures = transaction.message.header.num_required_signatures as usize;
+        if transaction.signatures.len() < required_signatures {
+            transaction
+                .signatures
+                .resize(required_signatures, Default::default());
+        }
+
+        let signature = self.signer().sign(&transaction.message_data()).await?;
+        transaction.signatures[0] = signature;
+
+        Ok(transaction)
+    }
+
+    /// Handles preparation failures by marking the transaction as failed.
+    async fn handle_prepare_failure(
+        &self,
+        tx: TransactionRepoModel,
+        error: TransactionError,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let error_reason = format!("Preparation failed: {}", error);
+        warn!("Transaction {} preparation failed: {}", tx.id, error_reason);
+
+        let update_request = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Failed),
+            status_reason: Some(error_reason),
+            ..Default::default()
+        };
+
+        if let Err(finalize_error) = self
+            .finalize_transaction_state(tx.id.clone(), update_request)
+            .await
+        {
+            warn!(
+                "Failed to mark transaction {} as failed: {}",
+                tx.id, finalize_error
+            );
+        }
+
+        Err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::transaction::solana::test_helpers::*,
+        models::{RelayerNetworkPolicy, RelayerSolanaPolicy},
+        services::SolanaProviderError,
+    };
+    use solana_sdk::{hash::Hash, signature::Signature};
+
+    #[tokio::test]
+    async fn test_prepare_refreshes_blockhash_and_signs() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+        let fresh_blockhash = Hash::new_unique();
+
+        let mut mocks = default_test_mocks();
+        mock_successful_validation(&mut mocks.provider, fresh_blockhash);
+        mock_signer(&mut mocks.signer, &relayer_keypair);
+
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(move |_, update| {
+                let Some(NetworkTransactionData::Solana(data)) = &update.network_data else {
+                    return false;
+                };
+                let signed = decode_solana_transaction(data).unwrap();
+                update.status == Some(TransactionStatus::Sent)
+                    && signed.message.recent_blockhash == fresh_blockhash
+                    && data.signature == Some(signed.signatures[0].to_string())
+                    && signed.signatures[0] != Signature::default()
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+        mocks
+            .job_producer
+            .expect_produce_submit_transaction_job()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.prepare_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_skips_non_pending_transaction() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let mut tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+        tx.status = TransactionStatus::Canceled;
+
+        let handler = make_solana_tx_handler(relayer, default_test_mocks());
+        let result = handler.prepare_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Canceled);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_rejects_disallowed_program() {
+        let relayer_keypair = test_keypair();
+        let mut relayer = create_test_relayer(&relayer_keypair);
+        relayer.policies = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+            allowed_programs: Some(vec![Pubkey::new_unique().to_string()]),
+            ..Default::default()
+        });
+        let tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+
+        let mut mocks = default_test_mocks();
+        mocks
+            .provider
+            .expect_get_latest_blockhash()
+            .returning(|| Box::pin(async { Ok(Hash::new_unique()) }));
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| {
+                update.status == Some(TransactionStatus::Failed)
+                    && update
+                        .status_reason
+                        .as_ref()
+                        .is_some_and(|reason| reason.contains("Policy violation"))
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.prepare_transaction_impl(tx).await;
+
+        assert!(matches!(result, Err(TransactionError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_prepare_rejects_missing_co_signer() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let user = test_keypair();
+        let tx = create_test_transaction(&co_signed_transfer(&relayer_keypair, &user, false));
+
+        let mut mocks = default_test_mocks();
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Failed))
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.prepare_transaction_impl(tx).await;
+
+        assert!(
+            matches!(result, Err(TransactionError::ValidationError(msg)) if msg.contains("missing signatures"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prepare_fails_on_insufficient_balance() {
+        let relayer_keypair = test_keypair();
+        let mut relayer = create_test_relayer(&relayer_keypair);
+        relayer.policies = RelayerNetworkPolicy::Solana(RelayerSolanaPolicy {
+            min_balance: Some(1_000_000),
+            ..Default::default()
+        });
+        let tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+
+        let mut mocks = default_test_mocks();
+        mocks
+            .provider
+            .expect_get_latest_blockhash()
+            .returning(|| Box::pin(async { Ok(Hash::new_unique()) }));
+        mocks
+            .provider
+            .expect_is_blockhash_valid()
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+        mocks
+            .provider
+            .expect_simulate_transaction()
+            .returning(|_| Box::pin(async { Ok(successful_simulation()) }));
+        mocks
+            .provider
+            .expect_calculate_total_fee()
+            .returning(|_| Box::pin(async { Ok(5000) }));
+        mocks
+            .provider
+            .expect_get_balance()
+            .returning(|_| Box::pin(async { Ok(10_000) }));
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Failed))
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.prepare_transaction_impl(tx).await;
+
+        assert!(matches!(
+            result,
+            Err(TransactionError::InsufficientBalance(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_prepare_fails_when_blockhash_cannot_be_fetched() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+
+        let mut mocks = default_test_mocks();
+        mocks.provider.expect_get_latest_blockhash().returning(|| {
+            Box::pin(async { Err(SolanaProviderError::RpcError("node down".to_string())) })
+        });
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Failed))
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.prepare_transaction_impl(tx).await;
+
+        assert!(matches!(
+            result,
+            Err(TransactionError::UnderlyingSolanaProvider(_))
+        ));
+    }
+}
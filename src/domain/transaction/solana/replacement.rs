@@ -0,0 +1,224 @@
+//! This module contains the cancellation and replacement functionality for Solana transactions.
+//!
+//! Solana has no nonce-based replacement, so both operations are only possible before the
+//! transaction has been broadcast. Once submitted, a transaction either lands or expires with
+//! its blockhash.
+
+use log::info;
+
+use super::SolanaRelayerTransaction;
+use crate::{
+    jobs::JobProducerTrait,
+    models::{
+        NetworkTransactionData, NetworkTransactionRequest, RelayerRepoModel, SolanaTransactionData,
+        TransactionError, TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
+    },
+    repositories::{RelayerRepository, Repository, TransactionRepository},
+    services::{SolanaProviderTrait, SolanaSignTrait},
+};
+
+impl<P, RR, TR, J, S> SolanaRelayerTransaction<P, RR, TR, J, S>
+where
+    P: SolanaProviderTrait,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    J: JobProducerTrait + Send + Sync + 'static,
+    S: SolanaSignTrait + Send + Sync + 'static,
+{
+    /// Cancels a transaction that has not been broadcast yet.
+    pub async fn cancel_transaction_impl(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        info!("Cancelling Solana transaction: {:?}", tx.id);
+
+        if !matches!(
+            tx.status,
+            TransactionStatus::Pending | TransactionStatus::Sent
+        ) {
+            return Err(TransactionError::ValidationError(format!(
+                "Cannot cancel transaction with status: {:?}",
+                tx.status
+            )));
+        }
+
+        let update_request = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Canceled),
+            is_canceled: Some(true),
+            ..Default::default()
+        };
+
+        self.finalize_transaction_state(tx.id.clone(), update_request)
+            .await
+    }
+
+    /// Replaces the payload of a transaction that has not been prepared yet.
+    pub async fn replace_transaction_impl(
+        &self,
+        old_tx: TransactionRepoModel,
+        new_tx_request: NetworkTransactionRequest,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        info!("Replacing Solana transaction: {:?}", old_tx.id);
+
+        if old_tx.status != TransactionStatus::Pending {
+            return Err(TransactionError::ValidationError(format!(
+                "Cannot replace transaction with status: {:?}",
+                old_tx.status
+            )));
+        }
+
+        let new_solana_request = match new_tx_request {
+            NetworkTransactionRequest::Solana(solana_req) => solana_req,
+            _ => {
+                return Err(TransactionError::InvalidType(
+                    "New transaction request must be Solana type".to_string(),
+                ))
+            }
+        };
+
+        new_solana_request
+            .validate()
+            .map_err(|e| TransactionError::ValidationError(e.to_string()))?;
+
+        let update_request = TransactionUpdateRequest {
+            network_data: Some(NetworkTransactionData::Solana(SolanaTransactionData {
+                transaction: new_solana_request.transaction.into_inner(),
+                signature: None,
+            })),
+            ..Default::default()
+        };
+
+        self.finalize_transaction_state(old_tx.id.clone(), update_request)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::transaction::solana::{test_helpers::*, utils::encode_solana_transaction},
+        models::{EncodedSerializedTransaction, SolanaTransactionRequest},
+        utils::mocks::mockutils::create_mock_evm_transaction_request,
+    };
+    use solana_sdk::transaction::Transaction;
+
+    #[tokio::test]
+    async fn test_cancel_pending_transaction() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+
+        let mut mocks = default_test_mocks();
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|id, update| {
+                id == "tx-1"
+                    && update.status == Some(TransactionStatus::Canceled)
+                    && update.is_canceled == Some(true)
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Canceled);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_submitted_transaction_fails() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let mut tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+        tx.status = TransactionStatus::Submitted;
+
+        let handler = make_solana_tx_handler(relayer, default_test_mocks());
+        let result = handler.cancel_transaction_impl(tx).await;
+
+        assert!(matches!(result, Err(TransactionError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_replace_pending_transaction() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+        let new_transaction = relayer_only_transaction(&relayer_keypair);
+        let expected = encode_solana_transaction(&new_transaction).unwrap();
+
+        let mut mocks = default_test_mocks();
+        let expected_clone = expected.clone();
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(move |_, update| {
+                matches!(
+                    &update.network_data,
+                    Some(NetworkTransactionData::Solana(data))
+                        if data.transaction == expected_clone && data.signature.is_none()
+                )
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.network_data = update.network_data.unwrap();
+                Ok(tx)
+            });
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let request = NetworkTransactionRequest::Solana(SolanaTransactionRequest {
+            transaction: EncodedSerializedTransaction::new(expected.clone()),
+            idempotency_key: None,
+            depends_on: None,
+        });
+        let result = handler.replace_transaction_impl(tx, request).await.unwrap();
+
+        assert_eq!(
+            result
+                .network_data
+                .get_solana_transaction_data()
+                .unwrap()
+                .transaction,
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replace_sent_transaction_fails() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let mut tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+        tx.status = TransactionStatus::Sent;
+
+        let handler = make_solana_tx_handler(relayer, default_test_mocks());
+        let request = NetworkTransactionRequest::Solana(SolanaTransactionRequest {
+            transaction: EncodedSerializedTransaction::new("AAAA".to_string()),
+            idempotency_key: None,
+            depends_on: None,
+        });
+        let result = handler.replace_transaction_impl(tx, request).await;
+
+        assert!(matches!(result, Err(TransactionError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_replace_with_wrong_network_type_fails() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+
+        let handler = make_solana_tx_handler(relayer, default_test_mocks());
+        let request = NetworkTransactionRequest::Evm(create_mock_evm_transaction_request());
+        let result = handler.replace_transaction_impl(tx, request).await;
+
+        assert!(matches!(result, Err(TransactionError::InvalidType(_))));
+    }
+}
@@ -16,22 +16,24 @@ use crate::{
         RelayerRepository, RelayerRepositoryStorage, Repository, TransactionRepository,
         TransactionRepositoryStorage,
     },
-    services::{SolanaProvider, SolanaProviderTrait},
+    services::{SolanaProvider, SolanaProviderTrait, SolanaSignTrait, SolanaSigner},
 };
 
 #[allow(dead_code)]
-pub struct SolanaRelayerTransaction<P, RR, TR, J>
+pub struct SolanaRelayerTransaction<P, RR, TR, J, S>
 where
     P: SolanaProviderTrait,
     RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
     J: JobProducerTrait + Send + Sync + 'static,
+    S: SolanaSignTrait + Send + Sync + 'static,
 {
     relayer: RelayerRepoModel,
     relayer_repository: Arc<RR>,
     provider: Arc<P>,
     job_producer: Arc<J>,
     transaction_repository: Arc<TR>,
+    signer: Arc<S>,
 }
 
 pub type DefaultSolanaTransaction = SolanaRelayerTransaction<
@@ -39,15 +41,17 @@ pub type DefaultSolanaTransaction = SolanaRelayerTransaction<
     RelayerRepositoryStorage,
     TransactionRepositoryStorage,
     JobProducer,
+    SolanaSigner,
 >;
 
 #[allow(dead_code)]
-impl<P, RR, TR, J> SolanaRelayerTransaction<P, RR, TR, J>
+impl<P, RR, TR, J, S> SolanaRelayerTransaction<P, RR, TR, J, S>
 where
     P: SolanaProviderTrait,
     RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
     J: JobProducerTrait + Send + Sync + 'static,
+    S: SolanaSignTrait + Send + Sync + 'static,
 {
     pub fn new(
         relayer: RelayerRepoModel,
@@ -55,6 +59,7 @@ where
         provider: Arc<P>,
         transaction_repository: Arc<TR>,
         job_producer: Arc<J>,
+        signer: Arc<S>,
     ) -> Result<Self, TransactionError> {
         Ok(Self {
             relayer,
@@ -62,6 +67,7 @@ where
             provider,
             transaction_repository,
             job_producer,
+            signer,
         })
     }
 
@@ -81,38 +87,40 @@ where
     pub(super) fn job_producer(&self) -> &J {
         &self.job_producer
     }
+
+    pub(super) fn signer(&self) -> &S {
+        &self.signer
+    }
 }
 
 #[async_trait]
-impl<P, RR, TR, J> Transaction for SolanaRelayerTransaction<P, RR, TR, J>
+impl<P, RR, TR, J, S> Transaction for SolanaRelayerTransaction<P, RR, TR, J, S>
 where
     P: SolanaProviderTrait,
     RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
     J: JobProducerTrait + Send + Sync + 'static,
+    S: SolanaSignTrait + Send + Sync + 'static,
 {
     async fn prepare_transaction(
         &self,
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
-        info!("preparing transaction");
-        Ok(tx)
+        self.prepare_transaction_impl(tx).await
     }
 
     async fn submit_transaction(
         &self,
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
-        info!("submitting transaction");
-        Ok(tx)
+        self.submit_transaction_impl(tx).await
     }
 
     async fn resubmit_transaction(
         &self,
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
-        info!("resubmitting transaction");
-        Ok(tx)
+        self.resubmit_transaction_impl(tx).await
     }
 
     /// Main entry point for transaction status handling
@@ -127,21 +135,25 @@ where
         &self,
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
-        Ok(tx)
+        self.cancel_transaction_impl(tx).await
     }
 
     async fn replace_transaction(
         &self,
-        _old_tx: TransactionRepoModel,
-        _new_tx_request: NetworkTransactionRequest,
+        old_tx: TransactionRepoModel,
+        new_tx_request: NetworkTransactionRequest,
     ) -> Result<TransactionRepoModel, TransactionError> {
-        Ok(_old_tx)
+        self.replace_transaction_impl(old_tx, new_tx_request).await
     }
 
     async fn sign_transaction(
         &self,
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
+        info!(
+            "Solana transactions are signed during preparation: {}",
+            tx.id
+        );
         Ok(tx)
     }
 
@@ -159,7 +171,7 @@ mod tests {
     use crate::{
         jobs::MockJobProducerTrait,
         repositories::{MockRelayerRepository, MockTransactionRepository},
-        services::MockSolanaProviderTrait,
+        services::{MockSolanaProviderTrait, MockSolanaSignTrait},
         utils::mocks::mockutils::{create_mock_solana_relayer, create_mock_solana_transaction},
     };
 
@@ -177,6 +189,7 @@ mod tests {
             provider,
             transaction_repository,
             job_producer,
+            Arc::new(MockSolanaSignTrait::new()),
         );
 
         assert!(transaction.is_ok());
@@ -201,6 +214,7 @@ mod tests {
             provider,
             transaction_repository,
             job_producer,
+            Arc::new(MockSolanaSignTrait::new()),
         )
         .unwrap();
 
@@ -5,29 +5,30 @@
 
 use chrono::Utc;
 use log::{debug, error, info, warn};
-use solana_sdk::signature::Signature;
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
 use std::str::FromStr;
 
-use super::SolanaRelayerTransaction;
+use super::{utils::decode_solana_transaction, SolanaRelayerTransaction};
 use crate::{
-    jobs::{JobProducerTrait, TransactionStatusCheck},
+    jobs::{JobProducerTrait, TransactionSend, TransactionStatusCheck},
     models::{
         produce_transaction_update_notification_payload, RelayerRepoModel, SolanaTransactionStatus,
         TransactionError, TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
     },
     repositories::{transaction::TransactionRepository, RelayerRepository, Repository},
-    services::provider::SolanaProviderTrait,
+    services::{provider::SolanaProviderTrait, SolanaSignTrait},
 };
 
 /// Default delay for retrying status checks after failures (in seconds)
 const SOLANA_DEFAULT_STATUS_RETRY_DELAY_SECONDS: i64 = 10;
 
-impl<P, RR, TR, J> SolanaRelayerTransaction<P, RR, TR, J>
+impl<P, RR, TR, J, S> SolanaRelayerTransaction<P, RR, TR, J, S>
 where
     P: SolanaProviderTrait,
     RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
     J: JobProducerTrait + Send + Sync + 'static,
+    S: SolanaSignTrait + Send + Sync + 'static,
 {
     /// Main status handling method with error handling and retries
     pub async fn handle_transaction_status_impl(
@@ -79,6 +80,29 @@ where
             tx.id, error
         );
 
+        // A transaction that is still unknown once its blockhash has expired can never land,
+        // so it is rebuilt on a fresh blockhash instead of being polled again.
+        if self.is_blockhash_expired(&tx).await {
+            info!(
+                "Blockhash expired for unconfirmed transaction {}. Scheduling resubmission.",
+                tx.id
+            );
+            match self
+                .job_producer()
+                .produce_submit_transaction_job(
+                    TransactionSend::resubmit(tx.id.clone(), tx.relayer_id.clone()),
+                    None,
+                )
+                .await
+            {
+                Ok(()) => return Ok(tx),
+                Err(e) => warn!(
+                    "Failed to schedule resubmission for transaction {}: {}",
+                    tx.id, e
+                ),
+            }
+        }
+
         if let Err(requeue_error) = self
             .schedule_status_check(&tx, Some(2 * SOLANA_DEFAULT_STATUS_RETRY_DELAY_SECONDS))
             .await
@@ -98,6 +122,35 @@ where
         Err(error)
     }
 
+    /// Returns true if a broadcast transaction's blockhash is no longer valid.
+    /// Any failure to decode or query the blockhash is treated as not expired.
+    async fn is_blockhash_expired(&self, tx: &TransactionRepoModel) -> bool {
+        if !matches!(
+            tx.status,
+            TransactionStatus::Sent | TransactionStatus::Submitted
+        ) {
+            return false;
+        }
+
+        let Ok(transaction) = tx
+            .network_data
+            .get_solana_transaction_data()
+            .and_then(|data| decode_solana_transaction(&data))
+        else {
+            return false;
+        };
+
+        matches!(
+            self.provider()
+                .is_blockhash_valid(
+                    &transaction.message.recent_blockhash,
+                    CommitmentConfig::confirmed()
+                )
+                .await,
+            Ok(false)
+        )
+    }
+
     /// Core status checking logic
     async fn check_and_update_status(
         &self,
@@ -125,7 +178,7 @@ where
                 ))
             })?;
 
-        println!("solana_status: {:?}", solana_status);
+        debug!("Solana transaction {} status: {:?}", tx.id, solana_status);
 
         // Map Solana status to repository status and handle accordingly
         match solana_status {
@@ -160,7 +213,7 @@ where
     }
 
     /// Helper method to schedule a transaction status check job
-    async fn schedule_status_check(
+    pub(super) async fn schedule_status_check(
         &self,
         tx: &TransactionRepoModel,
         delay_seconds: Option<i64>,
@@ -243,7 +296,7 @@ where
     }
 
     /// Helper function to update transaction status, save it, and send notification
-    async fn finalize_transaction_state(
+    pub(super) async fn finalize_transaction_state(
         &self,
         tx_id: String,
         update_req: TransactionUpdateRequest,
@@ -263,7 +316,7 @@ where
     }
 
     /// Send webhook notification for transaction updates
-    async fn send_transaction_update_notification(
+    pub(super) async fn send_transaction_update_notification(
         &self,
         tx: &TransactionRepoModel,
     ) -> Result<(), TransactionError> {
@@ -293,7 +346,7 @@ mod tests {
         jobs::MockJobProducerTrait,
         models::{NetworkTransactionData, SolanaTransactionData},
         repositories::{MockRelayerRepository, MockTransactionRepository},
-        services::{MockSolanaProviderTrait, SolanaProviderError},
+        services::{MockSolanaProviderTrait, MockSolanaSignTrait, SolanaProviderError},
         utils::mocks::mockutils::{create_mock_solana_relayer, create_mock_solana_transaction},
     };
     use eyre::Result;
@@ -324,9 +377,15 @@ mod tests {
         let job_producer = Arc::new(MockJobProducerTrait::new());
         let relayer = create_mock_solana_relayer("test-relayer".to_string(), false);
 
-        let handler =
-            SolanaRelayerTransaction::new(relayer, relayer_repo, provider, tx_repo, job_producer)
-                .unwrap();
+        let handler = SolanaRelayerTransaction::new(
+            relayer,
+            relayer_repo,
+            provider,
+            tx_repo,
+            job_producer,
+            Arc::new(MockSolanaSignTrait::new()),
+        )
+        .unwrap();
 
         // Test with Confirmed status
         let tx_confirmed = create_tx_with_signature(TransactionStatus::Confirmed, None);
@@ -382,6 +441,7 @@ mod tests {
             Arc::new(provider),
             tx_repo,
             Arc::new(job_producer),
+            Arc::new(MockSolanaSignTrait::new()),
         )?;
 
         let result = handler.handle_transaction_status_impl(tx.clone()).await;
@@ -437,6 +497,7 @@ mod tests {
             Arc::new(provider),
             Arc::new(tx_repo),
             Arc::new(job_producer),
+            Arc::new(MockSolanaSignTrait::new()),
         )?;
 
         let result = handler.handle_transaction_status_impl(tx.clone()).await;
@@ -486,6 +547,7 @@ mod tests {
             Arc::new(provider),
             Arc::new(tx_repo),
             Arc::new(job_producer),
+            Arc::new(MockSolanaSignTrait::new()),
         )?;
 
         let result = handler.handle_transaction_status_impl(tx.clone()).await;
@@ -528,6 +590,7 @@ mod tests {
             Arc::new(provider),
             tx_repo,
             Arc::new(job_producer),
+            Arc::new(MockSolanaSignTrait::new()),
         )?;
 
         let result = handler.handle_transaction_status_impl(tx.clone()).await;
@@ -576,6 +639,7 @@ mod tests {
             Arc::new(provider),
             Arc::new(tx_repo),
             Arc::new(job_producer),
+            Arc::new(MockSolanaSignTrait::new()),
         )?;
 
         let result = handler.handle_transaction_status_impl(tx.clone()).await;
@@ -586,4 +650,53 @@ mod tests {
         assert_eq!(updated_tx.status, TransactionStatus::Failed);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_handle_status_unknown_with_expired_blockhash_schedules_resubmit() -> Result<()> {
+        use crate::domain::transaction::solana::test_helpers::*;
+
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let mut tx = create_test_transaction(&relayer_only_transaction(&relayer_keypair));
+        tx.status = TransactionStatus::Submitted;
+        tx.network_data = NetworkTransactionData::Solana(SolanaTransactionData {
+            signature: Some(Signature::new_unique().to_string()),
+            ..tx.network_data.get_solana_transaction_data()?
+        });
+
+        let mut mocks = default_test_mocks();
+        mocks
+            .provider
+            .expect_get_transaction_status()
+            .returning(|_| {
+                Box::pin(async {
+                    Err(SolanaProviderError::RpcError(
+                        "Transaction confirmation status not available".to_string(),
+                    ))
+                })
+            });
+        mocks
+            .provider
+            .expect_is_blockhash_valid()
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+        mocks
+            .job_producer
+            .expect_produce_submit_transaction_job()
+            .withf(|job, _| {
+                job.transaction_id == "tx-1"
+                    && matches!(job.command, crate::jobs::TransactionCommand::Resubmit)
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mocks
+            .job_producer
+            .expect_produce_check_transaction_status_job()
+            .never();
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.handle_transaction_status_impl(tx.clone()).await;
+
+        assert_eq!(result?.status, TransactionStatus::Submitted);
+        Ok(())
+    }
 }
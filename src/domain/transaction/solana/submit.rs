@@ -0,0 +1,444 @@
+//! This module contains the submission-related functionality for Solana transactions.
+//! It sends signed transactions through the provider and rebuilds them on a fresh
+//! blockhash when the original one has expired.
+
+use chrono::Utc;
+use log::{info, warn};
+use solana_sdk::{signature::Signature, transaction::Transaction};
+
+use super::{
+    utils::{
+        decode_solana_transaction, encode_solana_transaction, is_blockhash_expired_error,
+        is_relayer_sole_signer, parse_relayer_pubkey,
+    },
+    SolanaRelayerTransaction,
+};
+use crate::{
+    constants::{FINAL_TRANSACTION_STATUSES, SOLANA_STATUS_CHECK_JOB_DELAY_SECONDS},
+    jobs::{JobProducerTrait, TransactionSend},
+    models::{
+        NetworkTransactionData, RelayerRepoModel, SolanaTransactionData, TransactionError,
+        TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
+    },
+    repositories::{RelayerRepository, Repository, TransactionRepository},
+    services::{SolanaProviderTrait, SolanaSignTrait},
+};
+
+impl<P, RR, TR, J, S> SolanaRelayerTransaction<P, RR, TR, J, S>
+where
+    P: SolanaProviderTrait,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    J: JobProducerTrait + Send + Sync + 'static,
+    S: SolanaSignTrait + Send + Sync + 'static,
+{
+    /// Main submission method with error handling.
+    pub async fn submit_transaction_impl(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        if FINAL_TRANSACTION_STATUSES.contains(&tx.status) {
+            info!(
+                "Transaction {} already in final state: {:?}, skipping submission",
+                tx.id, tx.status
+            );
+            return Ok(tx);
+        }
+
+        info!("Submitting Solana transaction: {:?}", tx.id);
+
+        match self.submit_core(tx.clone()).await {
+            Ok(submitted_tx) => Ok(submitted_tx),
+            Err(error) => self.handle_submit_failure(tx, error).await,
+        }
+    }
+
+    /// Core submission logic
+    async fn submit_core(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let solana_data = tx.network_data.get_solana_transaction_data()?;
+        let transaction = decode_solana_transaction(&solana_data)?;
+
+        self.send_and_record(tx, &transaction).await
+    }
+
+    /// Rebuilds the transaction on a fresh blockhash, re-signs it and sends it again.
+    ///
+    /// Only transactions where the relayer is the sole signer can be rebuilt; any other
+    /// transaction is marked as expired since its co-signers would have to sign again.
+    pub async fn resubmit_transaction_impl(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        if FINAL_TRANSACTION_STATUSES.contains(&tx.status) {
+            info!(
+                "Transaction {} already in final state: {:?}, skipping resubmission",
+                tx.id, tx.status
+            );
+            return Ok(tx);
+        }
+
+        info!("Resubmitting Solana transaction: {:?}", tx.id);
+
+        let solana_data = tx.network_data.get_solana_transaction_data()?;
+        let mut transaction = decode_solana_transaction(&solana_data)?;
+        let relayer_pubkey = parse_relayer_pubkey(&self.relayer().address)?;
+
+        if !is_relayer_sole_signer(&transaction, &relayer_pubkey) {
+            warn!(
+                "Transaction {} blockhash expired and it requires additional signers",
+                tx.id
+            );
+            let update_request = TransactionUpdateRequest {
+                status: Some(TransactionStatus::Expired),
+                status_reason: Some(
+                    "Blockhash expired before confirmation and transaction requires additional signers"
+                        .to_string(),
+                ),
+                ..Default::default()
+            };
+            return self
+                .finalize_transaction_state(tx.id.clone(), update_request)
+                .await;
+        }
+
+        let result = async {
+            transaction.message.recent_blockhash = self.provider().get_latest_blockhash().await?;
+            transaction.signatures = vec![
+                Signature::default();
+                transaction.message.header.num_required_signatures
+                    as usize
+            ];
+            let signed_transaction = self.validate_and_sign(transaction, &relayer_pubkey).await?;
+            self.send_and_record(tx.clone(), &signed_transaction).await
+        }
+        .await;
+
+        match result {
+            Ok(resubmitted_tx) => Ok(resubmitted_tx),
+            Err(error) => {
+                let error_reason = format!("Resubmission failed: {}", error);
+                warn!(
+                    "Transaction {} resubmission failed: {}",
+                    tx.id, error_reason
+                );
+                self.mark_as_failed(&tx, error_reason).await;
+                Err(error)
+            }
+        }
+    }
+
+    /// Sends a signed transaction and records the submission.
+    async fn send_and_record(
+        &self,
+        tx: TransactionRepoModel,
+        transaction: &Transaction,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let signature = self.provider().send_transaction(transaction).await?;
+        let signature_str = signature.to_string();
+
+        let mut hashes = tx.hashes.clone();
+        if !hashes.contains(&signature_str) {
+            hashes.push(signature_str.clone());
+        }
+
+        let update_request = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Submitted),
+            sent_at: Some(Utc::now().to_rfc3339()),
+            network_data: Some(NetworkTransactionData::Solana(SolanaTransactionData {
+                transaction: encode_solana_transaction(transaction)?,
+                signature: Some(signature_str),
+            })),
+            hashes: Some(hashes),
+            ..Default::default()
+        };
+
+        let updated_tx = self
+            .transaction_repository()
+            .partial_update(tx.id.clone(), update_request)
+            .await?;
+
+        self.schedule_status_check(&updated_tx, Some(SOLANA_STATUS_CHECK_JOB_DELAY_SECONDS))
+            .await?;
+
+        self.send_transaction_update_notification(&updated_tx)
+            .await?;
+
+        Ok(updated_tx)
+    }
+
+    /// Handles submission failures.
+    /// Expired blockhash errors are handed off to a resubmit job; anything else fails the
+    /// transaction.
+    async fn handle_submit_failure(
+        &self,
+        tx: TransactionRepoModel,
+        error: TransactionError,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let error_reason = format!("Submission failed: {}", error);
+        warn!("Transaction {} submission failed: {}", tx.id, error_reason);
+
+        if is_blockhash_expired_error(&error_reason) {
+            info!(
+                "Blockhash expired for transaction {}. Scheduling resubmission.",
+                tx.id
+            );
+            match self
+                .job_producer()
+                .produce_submit_transaction_job(
+                    TransactionSend::resubmit(tx.id.clone(), tx.relayer_id.clone()),
+                    None,
+                )
+                .await
+            {
+                Ok(()) => return Ok(tx),
+                Err(e) => warn!(
+                    "Failed to schedule resubmission for transaction {}: {}",
+                    tx.id, e
+                ),
+            }
+        }
+
+        self.mark_as_failed(&tx, error_reason).await;
+
+        Err(error)
+    }
+
+    /// Marks the transaction as failed, logging instead of propagating update errors.
+    async fn mark_as_failed(&self, tx: &TransactionRepoModel, reason: String) {
+        let update_request = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Failed),
+            status_reason: Some(reason),
+            ..Default::default()
+        };
+
+        if let Err(finalize_error) = self
+            .finalize_transaction_state(tx.id.clone(), update_request)
+            .await
+        {
+            warn!(
+                "Failed to mark transaction {} as failed: {}",
+                tx.id, finalize_error
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::transaction::solana::test_helpers::*, models::RepositoryError,
+        services::SolanaProviderError,
+    };
+    use solana_sdk::hash::Hash;
+
+    fn signed_transaction_model(
+        relayer_keypair: &solana_sdk::signature::Keypair,
+        status: TransactionStatus,
+    ) -> TransactionRepoModel {
+        let mut transaction = relayer_only_transaction(relayer_keypair);
+        transaction.sign(&[relayer_keypair], transaction.message.recent_blockhash);
+        let mut tx = create_test_transaction(&transaction);
+        tx.status = status;
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_submit_sends_and_schedules_status_check() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = signed_transaction_model(&relayer_keypair, TransactionStatus::Sent);
+        let signature = Signature::new_unique();
+
+        let mut mocks = default_test_mocks();
+        mocks
+            .provider
+            .expect_send_transaction()
+            .times(1)
+            .returning(move |_| Box::pin(async move { Ok(signature) }));
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(move |_, update| {
+                update.status == Some(TransactionStatus::Submitted)
+                    && update.sent_at.is_some()
+                    && update.hashes == Some(vec![signature.to_string()])
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+        mocks
+            .job_producer
+            .expect_produce_check_transaction_status_job()
+            .withf(|check, delay| check.transaction_id == "tx-1" && delay.is_some())
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.submit_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Submitted);
+    }
+
+    #[tokio::test]
+    async fn test_submit_skips_final_transaction() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = signed_transaction_model(&relayer_keypair, TransactionStatus::Canceled);
+
+        let handler = make_solana_tx_handler(relayer, default_test_mocks());
+        let result = handler.submit_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Canceled);
+    }
+
+    #[tokio::test]
+    async fn test_submit_expired_blockhash_schedules_resubmit() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = signed_transaction_model(&relayer_keypair, TransactionStatus::Sent);
+
+        let mut mocks = default_test_mocks();
+        mocks.provider.expect_send_transaction().returning(|_| {
+            Box::pin(async {
+                Err(SolanaProviderError::RpcError(
+                    "Transaction simulation failed: Blockhash not found".to_string(),
+                ))
+            })
+        });
+        mocks
+            .job_producer
+            .expect_produce_submit_transaction_job()
+            .withf(|job, delay| {
+                job.transaction_id == "tx-1"
+                    && matches!(job.command, crate::jobs::TransactionCommand::Resubmit)
+                    && delay.is_none()
+            })
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.submit_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn test_submit_failure_marks_transaction_failed() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = signed_transaction_model(&relayer_keypair, TransactionStatus::Sent);
+
+        let mut mocks = default_test_mocks();
+        mocks.provider.expect_send_transaction().returning(|_| {
+            Box::pin(async {
+                Err(SolanaProviderError::RpcError(
+                    "insufficient funds for rent".to_string(),
+                ))
+            })
+        });
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| {
+                update.status == Some(TransactionStatus::Failed)
+                    && update
+                        .status_reason
+                        .as_ref()
+                        .is_some_and(|reason| reason.starts_with("Submission failed"))
+            })
+            .times(1)
+            .returning(|_, _| Err(RepositoryError::NotFound("tx-1".to_string())));
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.submit_transaction_impl(tx).await;
+
+        assert!(matches!(
+            result,
+            Err(TransactionError::UnderlyingSolanaProvider(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_rebuilds_with_fresh_blockhash() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let tx = signed_transaction_model(&relayer_keypair, TransactionStatus::Submitted);
+        let fresh_blockhash = Hash::new_unique();
+
+        let mut mocks = default_test_mocks();
+        mock_successful_validation(&mut mocks.provider, fresh_blockhash);
+        mock_signer(&mut mocks.signer, &relayer_keypair);
+        mocks
+            .provider
+            .expect_send_transaction()
+            .withf(move |transaction| {
+                transaction.message.recent_blockhash == fresh_blockhash
+                    && transaction.verify().is_ok()
+            })
+            .times(1)
+            .returning(|transaction| {
+                let signature = transaction.signatures[0];
+                Box::pin(async move { Ok(signature) })
+            });
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Submitted))
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                tx.network_data = update.network_data.unwrap();
+                Ok(tx)
+            });
+        mocks
+            .job_producer
+            .expect_produce_check_transaction_status_job()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.resubmit_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Submitted);
+        let data = result.network_data.get_solana_transaction_data().unwrap();
+        let resubmitted = decode_solana_transaction(&data).unwrap();
+        assert_eq!(resubmitted.message.recent_blockhash, fresh_blockhash);
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_with_co_signers_expires_transaction() {
+        let relayer_keypair = test_keypair();
+        let relayer = create_test_relayer(&relayer_keypair);
+        let user = test_keypair();
+        let mut tx = create_test_transaction(&co_signed_transfer(&relayer_keypair, &user, true));
+        tx.status = TransactionStatus::Submitted;
+
+        let mut mocks = default_test_mocks();
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| update.status == Some(TransactionStatus::Expired))
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction(&Transaction::default());
+                tx.id = id;
+                tx.status = update.status.unwrap();
+                Ok(tx)
+            });
+
+        let handler = make_solana_tx_handler(relayer, mocks);
+        let result = handler.resubmit_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Expired);
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use solana_client::rpc_response::RpcSimulateTransactionResult;
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::Transaction,
+};
+use solana_system_interface::instruction as system_instruction;
+
+use super::{utils::encode_solana_transaction, SolanaRelayerTransaction};
+use crate::{
+    jobs::MockJobProducerTrait,
+    models::{
+        NetworkTransactionData, NetworkType, RelayerNetworkPolicy, RelayerRepoModel,
+        RelayerSolanaPolicy, SolanaTransactionData, TransactionRepoModel, TransactionStatus,
+    },
+    repositories::{MockRelayerRepository, MockTransactionRepository},
+    services::{MockSolanaProviderTrait, MockSolanaSignTrait},
+};
+
+pub fn test_keypair() -> Keypair {
+    Keypair::new()
+}
+
+pub fn create_test_relayer(relayer_keypair: &Keypair) -> RelayerRepoModel {
+    RelayerRepoModel {
+        id: "relayer-1".to_string(),
+        name: "Test Solana Relayer".to_string(),
+        network: "devnet".to_string(),
+        paused: false,
+        network_type: NetworkType::Solana,
+        signer_id: "signer-1".to_string(),
+        policies: RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()),
+        address: relayer_keypair.pubkey().to_string(),
+        notification_id: None,
+        system_disabled: false,
+        custom_rpc_urls: None,
+        signer_rotations: vec![],
+    }
+}
+
+/// A transaction whose only required signer is the relayer, acting as fee payer.
+pub fn relayer_only_transaction(relayer_keypair: &Keypair) -> Transaction {
+    let ix = Instruction::new_with_bytes(
+        Pubkey::new_unique(),
+        &[1, 2, 3],
+        vec![AccountMeta::new(Pubkey::new_unique(), false)],
+    );
+    let message =
+        Message::new_with_blockhash(&[ix], Some(&relayer_keypair.pubkey()), &Hash::new_unique());
+    Transaction::new_unsigned(message)
+}
+
+/// A user transfer paid for by the relayer, optionally pre-signed by the user.
+pub fn co_signed_transfer(relayer_keypair: &Keypair, user: &Keypair, signed: bool) -> Transaction {
+    let ix = system_instruction::transfer(&user.pubkey(), &Pubkey::new_unique(), 1000);
+    let message =
+        Message::new_with_blockhash(&[ix], Some(&relayer_keypair.pubkey()), &Hash::new_unique());
+    let mut transaction = Transaction::new_unsigned(message);
+    if signed {
+        transaction.partial_sign(&[user], transaction.message.recent_blockhash);
+    }
+    transaction
+}
+
+pub fn create_test_transaction(transaction: &Transaction) -> TransactionRepoModel {
+    TransactionRepoModel {
+        id: "tx-1".to_string(),
+        relayer_id: "relayer-1".to_string(),
+        status: TransactionStatus::Pending,
+        status_reason: None,
+        created_at: Utc::now().to_rfc3339(),
+        sent_at: None,
+        confirmed_at: None,
+        valid_until: None,
+        delete_at: None,
+        network_data: NetworkTransactionData::Solana(SolanaTransactionData {
+            transaction: encode_solana_transaction(transaction).unwrap(),
+            signature: None,
+        }),
+        priced_at: None,
+        hashes: Vec::new(),
+        network_type: NetworkType::Solana,
+        noop_count: None,
+        is_canceled: Some(false),
+        idempotency_key: None,
+        execute_after: None,
+        schedule: None,
+        depends_on: None,
+    }
+}
+
+pub fn successful_simulation() -> RpcSimulateTransactionResult {
+    RpcSimulateTransactionResult {
+        err: None,
+        logs: None,
+        accounts: None,
+        units_consumed: None,
+        return_data: None,
+        replacement_blockhash: None,
+        inner_instructions: None,
+        loaded_accounts_data_size: None,
+    }
+}
+
+/// Sets up provider expectations for a transaction that passes every policy check.
+pub fn mock_successful_validation(provider: &mut MockSolanaProviderTrait, blockhash: Hash) {
+    provider
+        .expect_get_latest_blockhash()
+        .returning(move || Box::pin(async move { Ok(blockhash) }));
+    provider
+        .expect_is_blockhash_valid()
+        .returning(|_, _| Box::pin(async { Ok(true) }));
+    provider
+        .expect_simulate_transaction()
+        .returning(|_| Box::pin(async { Ok(successful_simulation()) }));
+    provider
+        .expect_calculate_total_fee()
+        .returning(|_| Box::pin(async { Ok(5000) }));
+    provider
+        .expect_get_balance()
+        .returning(|_| Box::pin(async { Ok(1_000_000_000) }));
+}
+
+pub fn mock_signer(signer: &mut MockSolanaSignTrait, relayer_keypair: &Keypair) {
+    let keypair = relayer_keypair.insecure_clone();
+    signer.expect_sign().returning(move |message| {
+        let signature = keypair.sign_message(message);
+        Box::pin(async move { Ok(signature) })
+    });
+}
+
+pub struct TestMocks {
+    pub provider: MockSolanaProviderTrait,
+    pub relayer_repo: MockRelayerRepository,
+    pub tx_repo: MockTransactionRepository,
+    pub job_producer: MockJobProducerTrait,
+    pub signer: MockSolanaSignTrait,
+}
+
+pub fn default_test_mocks() -> TestMocks {
+    TestMocks {
+        provider: MockSolanaProviderTrait::new(),
+        relayer_repo: MockRelayerRepository::new(),
+        tx_repo: MockTransactionRepository::new(),
+        job_producer: MockJobProducerTrait::new(),
+        signer: MockSolanaSignTrait::new(),
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn make_solana_tx_handler(
+    relayer: RelayerRepoModel,
+    mocks: TestMocks,
+) -> SolanaRelayerTransaction<
+    MockSolanaProviderTrait,
+    MockRelayerRepository,
+    MockTransactionRepository,
+    MockJobProducerTrait,
+    MockSolanaSignTrait,
+> {
+    SolanaRelayerTransaction::new(
+        relayer,
+        Arc::new(mocks.relayer_repo),
+        Arc::new(mocks.provider),
+        Arc::new(mocks.tx_repo),
+        Arc::new(mocks.job_producer),
+        Arc::new(mocks.signer),
+    )
+    .expect("handler construction should succeed")
+}
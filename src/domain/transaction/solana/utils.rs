@@ -0,0 +1,183 @@
+//! Utility functions for Solana transaction domain logic.
+use solana_sdk::{pubkey::Pubkey, signature::Signature, transaction::Transaction};
+use std::str::FromStr;
+
+use crate::{
+    domain::SolanaTransactionValidationError,
+    models::{EncodedSerializedTransaction, SolanaTransactionData, TransactionError},
+};
+
+/// Decodes the base64-encoded transaction stored in the Solana transaction data.
+pub fn decode_solana_transaction(
+    solana_data: &SolanaTransactionData,
+) -> Result<Transaction, TransactionError> {
+    Transaction::try_from(EncodedSerializedTransaction::new(
+        solana_data.transaction.clone(),
+    ))
+    .map_err(|e| TransactionError::ValidationError(format!("Invalid Solana transaction: {}", e)))
+}
+
+/// Encodes a Solana transaction into its base64 wire representation.
+pub fn encode_solana_transaction(transaction: &Transaction) -> Result<String, TransactionError> {
+    EncodedSerializedTransaction::try_from(transaction)
+        .map(|encoded| encoded.into_inner())
+        .map_err(|e| {
+            TransactionError::UnexpectedError(format!("Failed to encode Solana transaction: {}", e))
+        })
+}
+
+/// Parses the relayer address into a Solana public key.
+pub fn parse_relayer_pubkey(address: &str) -> Result<Pubkey, TransactionError> {
+    Pubkey::from_str(address).map_err(|e| {
+        TransactionError::ValidationError(format!("Invalid relayer address {}: {}", address, e))
+    })
+}
+
+/// Returns true if the relayer is the only required signer of the transaction.
+///
+/// Only such transactions can have their blockhash refreshed and be re-signed by the relayer,
+/// because changing the message invalidates every other signature.
+pub fn is_relayer_sole_signer(transaction: &Transaction, relayer_pubkey: &Pubkey) -> bool {
+    transaction.message.header.num_required_signatures == 1
+        && transaction.message.account_keys.first() == Some(relayer_pubkey)
+}
+
+/// Returns true if any required signature other than the fee payer's is still missing.
+pub fn has_missing_signatures(transaction: &Transaction) -> bool {
+    let required = transaction.message.header.num_required_signatures as usize;
+    (1..required).any(|index| {
+        transaction
+            .signatures
+            .get(index)
+            .is_none_or(|signature| *signature == Signature::default())
+    })
+}
+
+/// Maps a policy validation failure to the matching transaction error.
+pub fn map_validation_error(error: SolanaTransactionValidationError) -> TransactionError {
+    match error {
+        SolanaTransactionValidationError::InsufficientFunds(msg)
+        | SolanaTransactionValidationError::InsufficientBalance(msg) => {
+            TransactionError::InsufficientBalance(msg)
+        }
+        SolanaTransactionValidationError::SimulationError(msg) => {
+            TransactionError::SimulationFailed(msg)
+        }
+        other => TransactionError::ValidationError(other.to_string()),
+    }
+}
+
+/// Detects if an error is due to an expired or unknown blockhash.
+pub fn is_blockhash_expired_error(error_msg: &str) -> bool {
+    let error_lower = error_msg.to_lowercase();
+    error_lower.contains("blockhash not found") || error_lower.contains("blockhashnotfound")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{
+        hash::Hash, message::Message, signature::Keypair, signer::Signer as SolanaSigner,
+    };
+    use solana_system_interface::instruction as system_instruction;
+
+    fn create_transfer(fee_payer: &Pubkey, source: &Pubkey) -> Transaction {
+        let ix = system_instruction::transfer(source, &Pubkey::new_unique(), 1000);
+        let message = Message::new_with_blockhash(&[ix], Some(fee_payer), &Hash::new_unique());
+        Transaction::new_unsigned(message)
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let relayer = Keypair::new();
+        let tx = create_transfer(&relayer.pubkey(), &relayer.pubkey());
+
+        let encoded = encode_solana_transaction(&tx).unwrap();
+        let decoded = decode_solana_transaction(&SolanaTransactionData {
+            transaction: encoded,
+            signature: None,
+        })
+        .unwrap();
+
+        assert_eq!(decoded.message, tx.message);
+    }
+
+    #[test]
+    fn test_decode_invalid_transaction() {
+        let result = decode_solana_transaction(&SolanaTransactionData {
+            transaction: "not-a-transaction".to_string(),
+            signature: None,
+        });
+
+        assert!(matches!(result, Err(TransactionError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_parse_relayer_pubkey() {
+        let relayer = Keypair::new();
+        assert_eq!(
+            parse_relayer_pubkey(&relayer.pubkey().to_string()).unwrap(),
+            relayer.pubkey()
+        );
+        assert!(parse_relayer_pubkey("0x").is_err());
+    }
+
+    #[test]
+    fn test_is_relayer_sole_signer() {
+        let relayer = Keypair::new();
+        let user = Keypair::new();
+
+        let relayer_only = create_transfer(&relayer.pubkey(), &relayer.pubkey());
+        assert!(is_relayer_sole_signer(&relayer_only, &relayer.pubkey()));
+
+        let with_user = create_transfer(&relayer.pubkey(), &user.pubkey());
+        assert!(!is_relayer_sole_signer(&with_user, &relayer.pubkey()));
+        assert!(!is_relayer_sole_signer(&relayer_only, &user.pubkey()));
+    }
+
+    #[test]
+    fn test_has_missing_signatures() {
+        let relayer = Keypair::new();
+        let user = Keypair::new();
+
+        let mut tx = create_transfer(&relayer.pubkey(), &user.pubkey());
+        assert!(has_missing_signatures(&tx));
+
+        tx.partial_sign(&[&user], tx.message.recent_blockhash);
+        assert!(!has_missing_signatures(&tx));
+
+        let relayer_only = create_transfer(&relayer.pubkey(), &relayer.pubkey());
+        assert!(!has_missing_signatures(&relayer_only));
+    }
+
+    #[test]
+    fn test_map_validation_error() {
+        assert!(matches!(
+            map_validation_error(SolanaTransactionValidationError::InsufficientBalance(
+                "low".to_string()
+            )),
+            TransactionError::InsufficientBalance(_)
+        ));
+        assert!(matches!(
+            map_validation_error(SolanaTransactionValidationError::SimulationError(
+                "failed".to_string()
+            )),
+            TransactionError::SimulationFailed(_)
+        ));
+        assert!(matches!(
+            map_validation_error(SolanaTransactionValidationError::PolicyViolation(
+                "program not allowed".to_string()
+            )),
+            TransactionError::ValidationError(msg) if msg.contains("program not allowed")
+        ));
+    }
+
+    #[test]
+    fn test_is_blockhash_expired_error() {
+        assert!(is_blockhash_expired_error(
+            "RPC client error: Transaction simulation failed: Blockhash not found"
+        ));
+        assert!(is_blockhash_expired_error("BlockhashNotFound"));
+        assert!(!is_blockhash_expired_error("insufficient funds for rent"));
+    }
+}
@@ -0,0 +1,502 @@
+//! This module contains the cancellation functionality for Stellar transactions.
+//!
+//! Transactions that have not been prepared yet hold no sequence number and are simply marked
+//! as canceled. Once a transaction has a sequence number, dropping it would leave a gap that
+//! blocks every later transaction of the relayer, so it is replaced by a `BumpSequence` no-op
+//! with the same sequence number instead. Whichever of the two lands consumes the sequence.
+
+use log::info;
+use soroban_rs::xdr::{
+    BumpSequenceOp, Limits, Memo, Operation, OperationBody, Preconditions, SequenceNumber,
+    Transaction as XdrTransaction, TransactionEnvelope, TransactionExt, TransactionV1Envelope,
+    VecM, WriteXdr,
+};
+
+use super::{
+    lane_gate,
+    prepare::common::{create_signing_data, sign_stellar_transaction},
+    utils::is_bad_sequence_error,
+    StellarRelayerTransaction,
+};
+use crate::{
+    constants::{STELLAR_CANCELLATION_FEE_MULTIPLIER, STELLAR_DEFAULT_TRANSACTION_FEE},
+    domain::xdr_utils::string_to_muxed_account,
+    jobs::JobProducerTrait,
+    models::{
+        NetworkTransactionData, RelayerRepoModel, StellarTransactionData, TransactionError,
+        TransactionInput, TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
+    },
+    repositories::{Repository, TransactionCounterTrait, TransactionRepository},
+    services::{Signer, StellarProviderTrait},
+};
+
+/// Builds an unsigned transaction with a single `BumpSequence` operation.
+///
+/// `bump_to` is zero, so the operation never moves the sequence number past the one consumed
+/// by the transaction itself.
+fn build_bump_sequence_noop(
+    source_account: &str,
+    sequence: i64,
+    fee: u32,
+) -> Result<TransactionEnvelope, TransactionError> {
+    let source_account = string_to_muxed_account(source_account)
+        .map_err(|e| TransactionError::ValidationError(e.to_string()))?;
+
+    let operation = Operation {
+        source_account: None,
+        body: OperationBody::BumpSequence(BumpSequenceOp {
+            bump_to: SequenceNumber(0),
+        }),
+    };
+
+    let tx = XdrTransaction {
+        source_account,
+        fee,
+        seq_num: SequenceNumber(sequence),
+        cond: Preconditions::None,
+        memo: Memo::None,
+        operations: vec![operation].try_into().map_err(|_| {
+            TransactionError::UnexpectedError("Failed to build operations".to_string())
+        })?,
+        ext: TransactionExt::V0,
+    };
+
+    Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+        tx,
+        signatures: VecM::default(),
+    }))
+}
+
+impl<R, T, J, S, P, C> StellarRelayerTransaction<R, T, J, S, P, C>
+where
+    R: Repository<RelayerRepoModel, String> + Send + Sync,
+    T: TransactionRepository + Send + Sync,
+    J: JobProducerTrait + Send + Sync,
+    S: Signer + Send + Sync,
+    P: StellarProviderTrait + Send + Sync,
+    C: TransactionCounterTrait + Send + Sync,
+{
+    /// Cancels a pending, sent or submitted transaction without leaving a sequence gap.
+    pub async fn cancel_transaction_impl(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        info!("Cancelling Stellar transaction: {:?}", tx.id);
+
+        match tx.status {
+            TransactionStatus::Pending => self.cancel_unsent_transaction(tx).await,
+            TransactionStatus::Sent | TransactionStatus::Submitted => {
+                if tx.is_canceled == Some(true) {
+                    info!("Transaction {} is already being canceled", tx.id);
+                    return Ok(tx);
+                }
+
+                let stellar_data = tx.network_data.get_stellar_transaction_data()?;
+                match stellar_data.transaction_input {
+                    // Fee-bump transactions consume the sequence number of the inner
+                    // transaction source, which the relayer cannot sign for.
+                    TransactionInput::SignedXdr { .. } if tx.status == TransactionStatus::Sent => {
+                        self.cancel_unsent_transaction(tx).await
+                    }
+                    TransactionInput::SignedXdr { .. } => Err(TransactionError::ValidationError(
+                        format!("Cannot cancel submitted fee-bump transaction {}", tx.id),
+                    )),
+                    _ => self.replace_with_bump_sequence(tx, stellar_data).await,
+                }
+            }
+            _ => Err(TransactionError::ValidationError(format!(
+                "Cannot cancel transaction with status: {:?}",
+                tx.status
+            ))),
+        }
+    }
+
+    /// Marks a transaction that never reached the network as canceled and hands its lane over.
+    async fn cancel_unsent_transaction(
+        &self,
+        tx: TransactionRepoModel,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let update_request = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Canceled),
+            status_reason: Some("Canceled before submission".to_string()),
+            is_canceled: Some(true),
+            ..Default::default()
+        };
+        let canceled_tx = self
+            .finalize_transaction_state(tx.id.clone(), update_request)
+            .await?;
+
+        if lane_gate::is_owner(&self.relayer().id, &tx.id) {
+            self.enqueue_next_pending_transaction(&tx.id).await?;
+        }
+
+        Ok(canceled_tx)
+    }
+
+    /// Replaces the transaction with a signed `BumpSequence` no-op using the same sequence number.
+    ///
+    /// Sent transactions already have a submit job queued, which will pick up the no-op.
+    /// Submitted transactions are replaced on the network right away, with a fee high enough
+    /// for Stellar Core to evict the original from its queue.
+    async fn replace_with_bump_sequence(
+        &self,
+        tx: TransactionRepoModel,
+        stellar_data: StellarTransactionData,
+    ) -> Result<TransactionRepoModel, TransactionError> {
+        let sequence = stellar_data.sequence_number.ok_or_else(|| {
+            TransactionError::ValidationError(format!(
+                "Transaction {} has no sequence number",
+                tx.id
+            ))
+        })?;
+        let fee = if tx.status == TransactionStatus::Submitted {
+            STELLAR_DEFAULT_TRANSACTION_FEE * STELLAR_CANCELLATION_FEE_MULTIPLIER
+        } else {
+            STELLAR_DEFAULT_TRANSACTION_FEE
+        };
+
+        let noop_envelope = build_bump_sequence_noop(&self.relayer().address, sequence, fee)?;
+        let noop_xdr = noop_envelope.to_xdr_base64(Limits::none()).map_err(|e| {
+            TransactionError::UnexpectedError(format!("Failed to serialize no-op: {}", e))
+        })?;
+        let signing_data = create_signing_data(
+            self.relayer().address.clone(),
+            noop_xdr,
+            stellar_data.network_passphrase.clone(),
+        )
+        .with_sequence_number(sequence)
+        .with_fee(fee);
+        let mut noop_data = sign_stellar_transaction(self.signer(), signing_data).await?;
+
+        let mut hashes = tx.hashes.clone();
+        if tx.status == TransactionStatus::Submitted {
+            let signed_envelope = noop_data
+                .get_envelope_for_submission()
+                .map_err(TransactionError::from)?;
+            let hash = match self.provider().send_transaction(&signed_envelope).await {
+                Ok(hash) => hash,
+                Err(e) if is_bad_sequence_error(&e.to_string()) => {
+                    return Err(TransactionError::ValidationError(format!(
+                        "Transaction {} can no longer be canceled: its sequence number has already been used",
+                        tx.id
+                    )));
+                }
+                Err(e) => return Err(TransactionError::from(e)),
+            };
+            let hash_hex = hex::encode(hash.as_slice());
+            noop_data = noop_data.with_hash(hash_hex.clone());
+            hashes.push(hash_hex);
+        }
+
+        info!(
+            "Replacing transaction {} with a bump-sequence no-op at sequence {}",
+            tx.id, sequence
+        );
+
+        let update_request = TransactionUpdateRequest {
+            status_reason: Some("Cancellation requested".to_string()),
+            network_data: Some(NetworkTransactionData::Stellar(noop_data)),
+            hashes: Some(hashes),
+            noop_count: Some(tx.noop_count.unwrap_or(0) + 1),
+            is_canceled: Some(true),
+            ..Default::default()
+        };
+
+        self.finalize_transaction_state(tx.id.clone(), update_request)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            transaction::stellar::test_helpers::*, SignTransactionResponse,
+            SignTransactionResponseStellar,
+        },
+        models::{RepositoryError, TransactionInput},
+    };
+    use eyre::eyre;
+    use futures::FutureExt;
+    use soroban_rs::xdr::{Hash, ReadXdr};
+
+    fn expect_signature(mocks: &mut TestMocks) {
+        mocks.signer.expect_sign_transaction().returning(|_| {
+            Box::pin(async {
+                Ok(SignTransactionResponse::Stellar(
+                    SignTransactionResponseStellar {
+                        signature: dummy_signature(),
+                    },
+                ))
+            })
+        });
+    }
+
+    fn expect_notification(mocks: &mut TestMocks) {
+        mocks
+            .job_producer
+            .expect_produce_send_notification_job()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+    }
+
+    fn noop_operations(data: &StellarTransactionData) -> Vec<OperationBody> {
+        let xdr = data.signed_envelope_xdr.as_ref().expect("signed envelope");
+        match TransactionEnvelope::from_xdr_base64(xdr, Limits::none()).unwrap() {
+            TransactionEnvelope::Tx(env) => {
+                assert_eq!(env.tx.seq_num, SequenceNumber(1));
+                env.tx.operations.iter().map(|op| op.body.clone()).collect()
+            }
+            _ => panic!("expected a v1 envelope"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_pending_transaction() {
+        let mut relayer = create_test_relayer();
+        relayer.id = "relayer-cancel-pending".to_string();
+        let mut mocks = default_test_mocks();
+        let tx = create_test_transaction(&relayer.id);
+
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|id, update| {
+                id == "tx-1"
+                    && update.status == Some(TransactionStatus::Canceled)
+                    && update.is_canceled == Some(true)
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction("relayer-1");
+                tx.id = id;
+                tx.apply_partial_update(update);
+                Ok(tx)
+            });
+        expect_notification(&mut mocks);
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Canceled);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_pending_transaction_hands_over_lane() {
+        let mut relayer = create_test_relayer();
+        relayer.id = "relayer-cancel-lane".to_string();
+        if let crate::models::RelayerNetworkPolicy::Stellar(ref mut policy) = relayer.policies {
+            policy.concurrent_transactions = Some(false);
+        }
+        let mut mocks = default_test_mocks();
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.id = "tx-owner".to_string();
+
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .returning(|id, update| {
+                let mut tx = create_test_transaction("relayer-cancel-lane");
+                tx.id = id;
+                tx.apply_partial_update(update);
+                Ok(tx)
+            });
+        expect_notification(&mut mocks);
+        mocks.tx_repo.expect_find_by_status().returning(|_, _| {
+            let mut next = create_test_transaction("relayer-cancel-lane");
+            next.id = "tx-next".to_string();
+            Ok(vec![next])
+        });
+        mocks
+            .job_producer
+            .expect_produce_transaction_request_job()
+            .withf(|job, _| job.transaction_id == "tx-next")
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        assert!(lane_gate::claim("relayer-cancel-lane", "tx-owner"));
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        handler.cancel_transaction_impl(tx).await.unwrap();
+
+        assert!(lane_gate::is_owner("relayer-cancel-lane", "tx-next"));
+        lane_gate::free("relayer-cancel-lane", "tx-next");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_sent_transaction_replaces_with_noop() {
+        let relayer = create_test_relayer();
+        let mut mocks = default_test_mocks();
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.status = TransactionStatus::Sent;
+
+        expect_signature(&mut mocks);
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| {
+                let Some(NetworkTransactionData::Stellar(data)) = &update.network_data else {
+                    return false;
+                };
+                update.status.is_none()
+                    && update.is_canceled == Some(true)
+                    && update.noop_count == Some(1)
+                    && data.hash.is_none()
+                    && data.fee == Some(STELLAR_DEFAULT_TRANSACTION_FEE)
+                    && matches!(
+                        noop_operations(data).as_slice(),
+                        [OperationBody::BumpSequence(_)]
+                    )
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction("relayer-1");
+                tx.id = id;
+                tx.status = TransactionStatus::Sent;
+                tx.apply_partial_update(update);
+                Ok(tx)
+            });
+        expect_notification(&mut mocks);
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Sent);
+        assert_eq!(result.is_canceled, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_submitted_transaction_submits_noop() {
+        let relayer = create_test_relayer();
+        let mut mocks = default_test_mocks();
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.status = TransactionStatus::Submitted;
+        tx.hashes = vec!["original".to_string()];
+
+        expect_signature(&mut mocks);
+        mocks
+            .provider
+            .expect_send_transaction()
+            .times(1)
+            .returning(|_| async { Ok(Hash([2u8; 32])) }.boxed());
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .withf(|_, update| {
+                let Some(NetworkTransactionData::Stellar(data)) = &update.network_data else {
+                    return false;
+                };
+                data.hash == Some(hex::encode([2u8; 32]))
+                    && data.fee
+                        == Some(
+                            STELLAR_DEFAULT_TRANSACTION_FEE * STELLAR_CANCELLATION_FEE_MULTIPLIER,
+                        )
+                    && update.hashes.as_ref().map(Vec::len) == Some(2)
+                    && update.is_canceled == Some(true)
+            })
+            .times(1)
+            .returning(|id, update| {
+                let mut tx = create_test_transaction("relayer-1");
+                tx.id = id;
+                tx.status = TransactionStatus::Submitted;
+                tx.apply_partial_update(update);
+                Ok(tx)
+            });
+        expect_notification(&mut mocks);
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Submitted);
+        assert_eq!(result.is_canceled, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_submitted_transaction_after_sequence_used() {
+        let relayer = create_test_relayer();
+        let mut mocks = default_test_mocks();
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.status = TransactionStatus::Submitted;
+
+        expect_signature(&mut mocks);
+        mocks
+            .provider
+            .expect_send_transaction()
+            .returning(|_| async { Err(eyre!("transaction submission failed: TxBadSeq")) }.boxed());
+        mocks.tx_repo.expect_partial_update().never();
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await;
+
+        assert!(
+            matches!(result, Err(TransactionError::ValidationError(msg)) if msg.contains("already been used"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_already_canceled_transaction_is_noop() {
+        let relayer = create_test_relayer();
+        let mut mocks = default_test_mocks();
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.status = TransactionStatus::Submitted;
+        tx.is_canceled = Some(true);
+
+        mocks.provider.expect_send_transaction().never();
+        mocks.tx_repo.expect_partial_update().never();
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx.clone()).await.unwrap();
+
+        assert_eq!(result.id, tx.id);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_submitted_fee_bump_transaction() {
+        let relayer = create_test_relayer();
+        let mocks = default_test_mocks();
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.status = TransactionStatus::Submitted;
+        if let NetworkTransactionData::Stellar(ref mut data) = tx.network_data {
+            data.transaction_input = TransactionInput::SignedXdr {
+                xdr: "AAAA".to_string(),
+                max_fee: 1_000,
+            };
+        }
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await;
+
+        assert!(matches!(result, Err(TransactionError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_confirmed_transaction() {
+        let relayer = create_test_relayer();
+        let mocks = default_test_mocks();
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.status = TransactionStatus::Confirmed;
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await;
+
+        assert!(matches!(result, Err(TransactionError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_propagates_repository_error() {
+        let relayer = create_test_relayer();
+        let mut mocks = default_test_mocks();
+        let tx = create_test_transaction(&relayer.id);
+
+        mocks
+            .tx_repo
+            .expect_partial_update()
+            .returning(|_, _| Err(RepositoryError::NotFound("tx-1".to_string())));
+
+        let handler = make_stellar_tx_handler(relayer, mocks);
+        let result = handler.cancel_transaction_impl(tx).await;
+
+        assert!(result.is_err());
+    }
+}
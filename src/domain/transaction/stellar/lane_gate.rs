@@ -46,6 +46,12 @@ pub fn pass_to(relayer_id: &str, current_tx_id: &str, next_tx_id: &str) {
     }
 }
 
+/// Returns true if tx_id currently owns the lane for relayer_id.
+pub fn is_owner(relayer_id: &str, tx_id: &str) -> bool {
+    BUSY.get(relayer_id)
+        .is_some_and(|owner| owner.value() == tx_id)
+}
+
 /// Free the lane if we still own it.
 ///
 /// This operation is atomic and lock-free per relayer.
@@ -112,6 +118,15 @@ mod tests {
         assert!(claim("r", "tx2")); // new owner
     }
 
+    #[test]
+    fn is_owner_reports_current_owner() {
+        reset();
+        assert!(!is_owner("r", "tx1"));
+        assert!(claim("r", "tx1"));
+        assert!(is_owner("r", "tx1"));
+        assert!(!is_owner("r", "tx2"));
+    }
+
     #[test]
     fn pass_to_by_non_owner_is_noop() {
         reset();
@@ -3,6 +3,8 @@ pub use stellar_transaction::*;
 
 mod prepare;
 
+mod cancel;
+
 mod submit;
 
 mod status;
@@ -39,6 +39,11 @@ where
         &self,
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
+        if tx.status == TransactionStatus::Canceled {
+            info!("Transaction {} was canceled, skipping preparation", tx.id);
+            return Ok(tx);
+        }
+
         if !self.concurrent_transactions_enabled() && !lane_gate::claim(&self.relayer().id, &tx.id)
         {
             info!(
@@ -582,6 +587,22 @@ mod prepare_transaction_tests {
         lane_gate::free(&relayer.id, "other-tx");
     }
 
+    #[tokio::test]
+    async fn prepare_transaction_skips_canceled_transaction() {
+        let mut relayer = create_test_relayer();
+        relayer.id = "relayer-prepare-canceled".to_string();
+        let handler = make_stellar_tx_handler(relayer.clone(), default_test_mocks());
+        let mut tx = create_test_transaction(&relayer.id);
+        tx.status = TransactionStatus::Canceled;
+
+        let result = handler.prepare_transaction_impl(tx).await.unwrap();
+
+        assert_eq!(result.status, TransactionStatus::Canceled);
+        // The lane was never claimed
+        assert!(lane_gate::claim(&relayer.id, "another-tx"));
+        lane_gate::free(&relayer.id, "another-tx");
+    }
+
     #[tokio::test]
     async fn test_prepare_failure_syncs_sequence() {
         let relayer = create_test_relayer();
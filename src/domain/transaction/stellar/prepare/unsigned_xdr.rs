@@ -148,6 +148,15 @@ mod tests {
             Ok(self.sequence)
         }
 
+        async fn get_and_increment_by(
+            &self,
+            _relayer_id: &str,
+            _address: &str,
+            _count: u64,
+        ) -> Result<u64, RepositoryError> {
+            Ok(self.sequence)
+        }
+
         async fn get(
             &self,
             _relayer_id: &str,
@@ -172,6 +181,16 @@ mod tests {
         ) -> Result<(), RepositoryError> {
             Ok(())
         }
+
+        async fn compare_and_set(
+            &self,
+            _relayer_id: &str,
+            _address: &str,
+            _expected: u64,
+            _value: u64,
+        ) -> Result<bool, RepositoryError> {
+            Ok(true)
+        }
     }
 
     struct MockProvider;
@@ -254,6 +273,14 @@ mod tests {
             unimplemented!()
         }
 
+        async fn raw_request_dyn(
+            &self,
+            _method: &str,
+            _params: serde_json::Value,
+        ) -> Result<serde_json::Value, crate::services::ProviderError> {
+            unimplemented!()
+        }
+
         fn rpc_url(&self) -> &str {
             unimplemented!()
         }
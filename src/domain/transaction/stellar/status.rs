@@ -198,8 +198,15 @@ where
                 })
         });
 
+        // A confirmed no-op means the cancellation replaced the original transaction
+        let status = if tx.is_canceled == Some(true) {
+            TransactionStatus::Canceled
+        } else {
+            TransactionStatus::Confirmed
+        };
+
         let update_request = TransactionUpdateRequest {
-            status: Some(TransactionStatus::Confirmed),
+            status: Some(status),
             confirmed_at: Some(Utc::now().to_rfc3339()),
             network_data: updated_network_data,
             ..Default::default()
@@ -446,6 +453,49 @@ mod tests {
             assert!(handled_tx.confirmed_at.is_some());
         }
 
+        #[tokio::test]
+        async fn handle_transaction_status_confirmed_noop_marks_canceled() {
+            let relayer = create_test_relayer();
+            let mut mocks = default_test_mocks();
+
+            let mut tx = create_test_transaction(&relayer.id);
+            tx.status = TransactionStatus::Submitted;
+            tx.is_canceled = Some(true);
+            if let NetworkTransactionData::Stellar(ref mut stellar_data) = tx.network_data {
+                stellar_data.hash = Some(hex::encode([3u8; 32]));
+            }
+
+            mocks
+                .provider
+                .expect_get_transaction()
+                .returning(|_| Box::pin(async { Ok(dummy_get_transaction_response("SUCCESS")) }));
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .withf(|_, update| update.status == Some(TransactionStatus::Canceled))
+                .times(1)
+                .returning(|id, update| {
+                    let mut tx = create_test_transaction("relayer-1");
+                    tx.id = id;
+                    tx.apply_partial_update(update);
+                    Ok(tx)
+                });
+            mocks
+                .job_producer
+                .expect_produce_send_notification_job()
+                .times(1)
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            mocks
+                .tx_repo
+                .expect_find_by_status()
+                .returning(|_, _| Ok(vec![]));
+
+            let handler = make_stellar_tx_handler(relayer, mocks);
+            let result = handler.handle_transaction_status_impl(tx).await.unwrap();
+
+            assert_eq!(result.status, TransactionStatus::Canceled);
+        }
+
         #[tokio::test]
         async fn handle_transaction_status_still_pending() {
             let relayer = create_test_relayer();
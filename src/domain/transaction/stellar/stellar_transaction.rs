@@ -5,7 +5,10 @@
 /// services and repositories to perform these operations asynchronously.
 use crate::{
     constants::DEFAULT_STELLAR_CONCURRENT_TRANSACTIONS,
-    domain::transaction::{stellar::fetch_next_sequence_from_chain, Transaction},
+    domain::transaction::{
+        check_transaction_dependencies, stellar::fetch_next_sequence_from_chain, DependencyState,
+        Transaction,
+    },
     jobs::{JobProducer, JobProducerTrait, TransactionRequest},
     models::{
         produce_transaction_update_notification_payload, NetworkTransactionRequest,
@@ -193,7 +196,10 @@ where
         Ok(())
     }
 
-    /// Finds the oldest pending transaction for a relayer.
+    /// Finds the oldest pending transaction for a relayer that is due to be sent.
+    ///
+    /// Transactions waiting for their prerequisites are skipped, so they do not hold the
+    /// lane while they wait.
     async fn find_oldest_pending_for_relayer(
         &self,
         relayer_id: &str,
@@ -204,7 +210,14 @@ where
             .await
             .map_err(TransactionError::from)?;
 
-        Ok(pending_txs.into_iter().next())
+        for tx in pending_txs.into_iter().filter(|tx| tx.is_due()) {
+            let dependencies =
+                check_transaction_dependencies(&tx, self.transaction_repository()).await?;
+            if dependencies == DependencyState::Ready {
+                return Ok(Some(tx));
+            }
+        }
+        Ok(None)
     }
 
     /// Syncs the sequence number from the blockchain for the relayer's address.
@@ -305,7 +318,7 @@ where
         &self,
         tx: TransactionRepoModel,
     ) -> Result<TransactionRepoModel, TransactionError> {
-        Ok(tx)
+        self.cancel_transaction_impl(tx).await
     }
 
     async fn replace_transaction(
@@ -34,6 +34,11 @@ where
     ) -> Result<TransactionRepoModel, TransactionError> {
         info!("Submitting Stellar transaction: {:?}", tx.id);
 
+        if tx.status == TransactionStatus::Canceled {
+            info!("Transaction {} was canceled, skipping submission", tx.id);
+            return Ok(tx);
+        }
+
         // Call core submission logic with error handling
         match self.submit_core(tx.clone()).await {
             Ok(submitted_tx) => Ok(submitted_tx),
@@ -131,6 +136,21 @@ where
                 }
             }
 
+            // A canceled transaction was only kept to consume its sequence number, and the
+            // counter now matches the chain again, so there is nothing left to retry.
+            if tx.is_canceled == Some(true) {
+                let update_request = TransactionUpdateRequest {
+                    status: Some(TransactionStatus::Canceled),
+                    status_reason: Some(error_reason),
+                    ..Default::default()
+                };
+                let canceled_tx = self
+                    .finalize_transaction_state(tx_id.clone(), update_request)
+                    .await?;
+                self.enqueue_next_pending_transaction(&tx_id).await?;
+                return Ok(canceled_tx);
+            }
+
             // Reset the transaction and re-enqueue it
             info!(
                 "Bad sequence error detected for transaction {}. Resetting and re-enqueueing.",
@@ -641,5 +661,71 @@ mod tests {
                 panic!("Expected Stellar transaction data");
             }
         }
+
+        #[tokio::test]
+        async fn submit_transaction_skips_canceled_transaction() {
+            let relayer = create_test_relayer();
+            let mut mocks = default_test_mocks();
+            mocks.provider.expect_send_transaction().never();
+
+            let handler = make_stellar_tx_handler(relayer.clone(), mocks);
+            let mut tx = create_test_transaction(&relayer.id);
+            tx.status = TransactionStatus::Canceled;
+
+            let result = handler.submit_transaction_impl(tx).await.unwrap();
+            assert_eq!(result.status, TransactionStatus::Canceled);
+        }
+
+        #[tokio::test]
+        async fn test_submit_bad_sequence_for_canceled_transaction_marks_canceled() {
+            let relayer = create_test_relayer();
+            let mut mocks = default_test_mocks();
+
+            mocks.provider.expect_send_transaction().returning(|_| {
+                Box::pin(async { Err(eyre::eyre!("transaction submission failed: TxBadSeq")) })
+            });
+            mocks
+                .provider
+                .expect_get_account()
+                .times(1)
+                .returning(|_| Box::pin(async { Err(eyre::eyre!("account lookup failed")) }));
+
+            // The canceled transaction must not be reset and retried
+            mocks
+                .tx_repo
+                .expect_partial_update()
+                .withf(|_, upd| upd.status == Some(TransactionStatus::Canceled))
+                .times(1)
+                .returning(|id, upd| {
+                    let mut tx = create_test_transaction("relayer-1");
+                    tx.id = id;
+                    tx.apply_partial_update(upd);
+                    Ok::<_, RepositoryError>(tx)
+                });
+            mocks
+                .job_producer
+                .expect_produce_send_notification_job()
+                .times(1)
+                .returning(|_, _| Box::pin(async { Ok(()) }));
+            mocks
+                .job_producer
+                .expect_produce_transaction_request_job()
+                .never();
+            mocks
+                .tx_repo
+                .expect_find_by_status()
+                .returning(|_, _| Ok(vec![]));
+
+            let handler = make_stellar_tx_handler(relayer.clone(), mocks);
+            let mut tx = create_test_transaction(&relayer.id);
+            tx.status = TransactionStatus::Sent;
+            tx.is_canceled = Some(true);
+            if let NetworkTransactionData::Stellar(ref mut data) = tx.network_data {
+                data.signatures.push(dummy_signature());
+            }
+
+            let result = handler.submit_transaction_impl(tx).await.unwrap();
+            assert_eq!(result.status, TransactionStatus::Canceled);
+        }
     }
 }
@@ -39,6 +39,7 @@ pub fn create_test_relayer() -> RelayerRepoModel {
         notification_id: Some("test-notification-id".to_string()),
         system_disabled: false,
         custom_rpc_urls: None,
+        signer_rotations: vec![],
     }
 }
 
@@ -78,6 +79,10 @@ pub fn create_test_transaction(relayer_id: &str) -> TransactionRepoModel {
         network_type: NetworkType::Stellar,
         noop_count: None,
         is_canceled: Some(false),
+        idempotency_key: None,
+        execute_after: None,
+        schedule: None,
+        depends_on: None,
         status_reason: None,
         delete_at: None,
     }
@@ -4,16 +4,20 @@
 //! handle unsupported operations for specific relayers. The module interacts with various
 //! repositories and factories to perform these operations.
 use actix_web::web::ThinData;
+use std::time::Duration;
+use uuid::Uuid;
 
 use crate::{
+    constants::{IDEMPOTENCY_RESERVATION_ATTEMPTS, IDEMPOTENCY_RESERVATION_RETRY_MILLIS},
     domain::get_relayer_by_id,
-    jobs::JobProducerTrait,
+    jobs::{JobProducerTrait, TransactionRequest},
     models::{
-        ApiError, DefaultAppState, NetworkRepoModel, NotificationRepoModel, RelayerRepoModel,
-        SignerRepoModel, ThinDataAppState, TransactionError, TransactionRepoModel,
+        ApiError, DefaultAppState, NetworkRepoModel, NotificationRepoModel, RelayerPoolRepoModel,
+        RelayerRepoModel, RepositoryError, SignerRepoModel, ThinDataAppState, TransactionError,
+        TransactionRepoModel, TransactionStatus, TransactionUpdateRequest,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
 };
@@ -31,9 +35,9 @@ use super::{NetworkTransaction, RelayerTransactionFactory};
 ///
 /// A `Result` containing a `TransactionRepoModel` if successful, or an `ApiError` if an error
 /// occurs.
-pub async fn get_transaction_by_id<J, RR, TR, NR, NFR, SR, TCR, PR>(
+pub async fn get_transaction_by_id<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
     transaction_id: String,
-    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
 ) -> Result<TransactionRepoModel, ApiError>
 where
     J: JobProducerTrait + Send + Sync + 'static,
@@ -44,6 +48,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     state
         .transaction_repository
@@ -52,6 +58,203 @@ where
         .map_err(|e| e.into())
 }
 
+/// Finds a transaction submitted to a relayer with the given idempotency key.
+///
+/// Only transactions created within the idempotency window are returned, so a key can be
+/// reused once the window has passed.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer the transaction was submitted to.
+/// * `idempotency_key` - The idempotency key supplied with the submission.
+/// * `window_seconds` - How long a key identifies the original transaction.
+/// * `state` - A reference to the application state, wrapped in `ThinData`.
+///
+/// # Returns
+///
+/// A `Result` containing the original `TransactionRepoModel`, if any, or an `ApiError` if an
+/// error occurs.
+pub async fn find_transaction_by_idempotency_key<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer_id: &str,
+    idempotency_key: &str,
+    window_seconds: u64,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<Option<TransactionRepoModel>, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let transaction = state
+        .transaction_repository
+        .find_by_idempotency_key(relayer_id, idempotency_key)
+        .await?;
+
+    Ok(transaction.filter(|tx| tx.is_created_within(window_seconds)))
+}
+
+/// Outcome of reserving an idempotency key for a new transaction.
+#[derive(Debug)]
+pub enum IdempotencyReservation {
+    /// The key is reserved under this reservation ID until the transaction is created.
+    Reserved(String),
+    /// The key already identifies this transaction.
+    Existing(Box<TransactionRepoModel>),
+}
+
+/// Reserves an idempotency key for a transaction about to be submitted to a relayer.
+///
+/// The reservation is atomic, so of concurrent submissions with the same key only one
+/// creates a transaction. The others wait for it and return it. A reservation that outlives
+/// the idempotency window of its transaction is taken over.
+///
+/// # Arguments
+///
+/// * `relayer_id` - The ID of the relayer the transaction is submitted to.
+/// * `idempotency_key` - The idempotency key supplied with the submission.
+/// * `window_seconds` - How long a key identifies the original transaction.
+/// * `state` - A reference to the application state, wrapped in `ThinData`.
+///
+/// # Returns
+///
+/// A `Result` containing the `IdempotencyReservation`, or an `ApiError` if the concurrent
+/// submission holding the key does not create its transaction in time.
+pub async fn reserve_transaction_idempotency_key<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    relayer_id: &str,
+    idempotency_key: &str,
+    window_seconds: u64,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<IdempotencyReservation, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    let reservation_id = Uuid::new_v4().to_string();
+
+    for _ in 0..IDEMPOTENCY_RESERVATION_ATTEMPTS {
+        let holder = state
+            .transaction_repository
+            .reserve_idempotency_key(relayer_id, idempotency_key, &reservation_id, window_seconds)
+            .await?;
+        let Some(holder) = holder else {
+            return Ok(IdempotencyReservation::Reserved(reservation_id));
+        };
+
+        match state.transaction_repository.get_by_id(holder.clone()).await {
+            Ok(transaction) if transaction.is_created_within(window_seconds) => {
+                return Ok(IdempotencyReservation::Existing(Box::new(transaction)));
+            }
+            Ok(_) => {
+                state
+                    .transaction_repository
+                    .release_idempotency_key(relayer_id, idempotency_key, &holder)
+                    .await?;
+            }
+            // The submission holding the key has not created its transaction yet
+            Err(RepositoryError::NotFound(_)) => {
+                tokio::time::sleep(Duration::from_millis(IDEMPOTENCY_RESERVATION_RETRY_MILLIS))
+                    .await;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Err(ApiError::Conflict(
+        "A transaction with this idempotency key is still being submitted".to_string(),
+    ))
+}
+
+/// Schedules the next occurrence of a recurring transaction.
+///
+/// The schedule moves to the new occurrence, so the transaction that is about to be sent
+/// no longer recurs and cancelling the pending occurrence ends the series. Only the schedule
+/// of the stored transaction is cleared; if it was cancelled in the meantime the new
+/// occurrence is cancelled as well. Running this again for the same transaction, e.g. when
+/// its job is retried, does nothing.
+///
+/// # Arguments
+///
+/// * `transaction` - The occurrence of the transaction that is about to be sent.
+/// * `state` - A reference to the application state, wrapped in `ThinData`.
+///
+/// # Returns
+///
+/// A `Result` containing the next occurrence, if any, or an `ApiError` if an error occurs.
+pub async fn schedule_next_occurrence<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
+    transaction: &TransactionRepoModel,
+    state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
+) -> Result<Option<TransactionRepoModel>, ApiError>
+where
+    J: JobProducerTrait + Send + Sync + 'static,
+    RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
+    TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
+    NR: NetworkRepository + Repository<NetworkRepoModel, String> + Send + Sync + 'static,
+    NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
+    SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
+    TCR: TransactionCounterTrait + Send + Sync + 'static,
+    PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+{
+    if transaction.schedule.is_none() {
+        return Ok(None);
+    }
+
+    let next = transaction.next_occurrence();
+    if let Some(next) = &next {
+        state.transaction_repository.create(next.clone()).await?;
+        state
+            .job_producer
+            .produce_transaction_request_job(
+                TransactionRequest::new(next.id.clone(), next.relayer_id.clone()),
+                next.scheduled_on(),
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(e.to_string()))?;
+    }
+
+    let update = TransactionUpdateRequest {
+        clear_schedule: Some(true),
+        ..Default::default()
+    };
+    let current = state
+        .transaction_repository
+        .partial_update(transaction.id.clone(), update)
+        .await?;
+
+    // The occurrence was cancelled while its job ran, which ends the series
+    if current.status != TransactionStatus::Pending {
+        if let Some(next) = &next {
+            let update = TransactionUpdateRequest {
+                status: Some(TransactionStatus::Canceled),
+                ..Default::default()
+            };
+            state
+                .transaction_repository
+                .partial_update(next.id.clone(), update)
+                .await?;
+        }
+        return Ok(None);
+    }
+
+    Ok(next)
+}
+
 /// Creates a relayer network transaction instance based on the relayer ID.
 ///
 /// # Arguments
@@ -127,3 +330,179 @@ pub fn solana_not_supported_transaction<T>() -> Result<T, TransactionError> {
         "Endpoint is not supported for Solana relayers".to_string(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mocks::mockutils::create_mock_app_state;
+    use chrono::{Duration, Utc};
+
+    fn create_recurring_transaction() -> TransactionRepoModel {
+        TransactionRepoModel {
+            id: "tx-1".to_string(),
+            relayer_id: "relayer-1".to_string(),
+            execute_after: Some((Utc::now() + Duration::minutes(5)).to_rfc3339()),
+            schedule: Some("0 0 * * * *".to_string()),
+            depends_on: None,
+            ..TransactionRepoModel::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_transaction_idempotency_key_concurrent_submissions() {
+        let app_state = create_mock_app_state(None, None, None, None, None).await;
+        let state = ThinData(app_state);
+
+        let submit = || async {
+            match reserve_transaction_idempotency_key("relayer-1", "order-1", 3600, &state)
+                .await
+                .unwrap()
+            {
+                IdempotencyReservation::Reserved(_) => {
+                    // Leave the concurrent submission time to find the reservation
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                    let transaction = TransactionRepoModel {
+                        id: Uuid::new_v4().to_string(),
+                        relayer_id: "relayer-1".to_string(),
+                        created_at: Utc::now().to_rfc3339(),
+                        idempotency_key: Some("order-1".to_string()),
+                        ..TransactionRepoModel::default()
+                    };
+                    state
+                        .transaction_repository
+                        .create(transaction)
+                        .await
+                        .unwrap()
+                        .id
+                }
+                IdempotencyReservation::Existing(transaction) => transaction.id.clone(),
+            }
+        };
+
+        let (first, second) = tokio::join!(submit(), submit());
+
+        assert_eq!(first, second);
+        assert_eq!(state.transaction_repository.count().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reserve_transaction_idempotency_key_after_window() {
+        let expired = TransactionRepoModel {
+            id: "tx-1".to_string(),
+            relayer_id: "relayer-1".to_string(),
+            created_at: (Utc::now() - Duration::hours(2)).to_rfc3339(),
+            idempotency_key: Some("order-1".to_string()),
+            ..TransactionRepoModel::default()
+        };
+        let app_state = create_mock_app_state(None, None, None, None, Some(vec![expired])).await;
+        let state = ThinData(app_state);
+        state
+            .transaction_repository
+            .reserve_idempotency_key("relayer-1", "order-1", "tx-1", 3600)
+            .await
+            .unwrap();
+
+        let reservation = reserve_transaction_idempotency_key("relayer-1", "order-1", 3600, &state)
+            .await
+            .unwrap();
+
+        assert!(matches!(reservation, IdempotencyReservation::Reserved(_)));
+    }
+
+    #[tokio::test]
+    async fn test_schedule_next_occurrence() {
+        let transaction = create_recurring_transaction();
+        let app_state =
+            create_mock_app_state(None, None, None, None, Some(vec![transaction.clone()])).await;
+        let state = ThinData(app_state);
+
+        let next = schedule_next_occurrence(&transaction, &state)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let stored_next = state
+            .transaction_repository
+            .get_by_id(next.id.clone())
+            .await
+            .unwrap();
+        assert_eq!(stored_next.status, TransactionStatus::Pending);
+        assert_eq!(stored_next.schedule, transaction.schedule);
+
+        let current = state
+            .transaction_repository
+            .get_by_id(transaction.id.clone())
+            .await
+            .unwrap();
+        assert_eq!(current.schedule, None);
+
+        // A retried job for the same occurrence does not schedule another one
+        let retried = schedule_next_occurrence(&current, &state).await.unwrap();
+        assert!(retried.is_none());
+        assert_eq!(
+            state.transaction_repository.list_all().await.unwrap().len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_schedule_next_occurrence_keeps_concurrent_cancellation() {
+        let transaction = create_recurring_transaction();
+        let app_state =
+            create_mock_app_state(None, None, None, None, Some(vec![transaction.clone()])).await;
+        let state = ThinData(app_state);
+
+        // The user cancels the occurrence after its job read it
+        let update = TransactionUpdateRequest {
+            status: Some(TransactionStatus::Canceled),
+            ..Default::default()
+        };
+        state
+            .transaction_repository
+            .partial_update(transaction.id.clone(), update)
+            .await
+            .unwrap();
+
+        let next = schedule_next_occurrence(&transaction, &state)
+            .await
+            .unwrap();
+        assert!(next.is_none());
+
+        let current = state
+            .transaction_repository
+            .get_by_id(transaction.id.clone())
+            .await
+            .unwrap();
+        assert_eq!(current.status, TransactionStatus::Canceled);
+        assert_eq!(current.schedule, None);
+
+        // The occurrence created for the series is cancelled with it
+        let transactions = state.transaction_repository.list_all().await.unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert!(transactions
+            .iter()
+            .all(|tx| tx.status == TransactionStatus::Canceled));
+    }
+
+    #[tokio::test]
+    async fn test_schedule_next_occurrence_ignores_one_off_transactions() {
+        let transaction = TransactionRepoModel {
+            schedule: None,
+            depends_on: None,
+            ..create_recurring_transaction()
+        };
+        let app_state =
+            create_mock_app_state(None, None, None, None, Some(vec![transaction.clone()])).await;
+        let state = ThinData(app_state);
+
+        let next = schedule_next_occurrence(&transaction, &state)
+            .await
+            .unwrap();
+
+        assert!(next.is_none());
+        assert_eq!(
+            state.transaction_repository.list_all().await.unwrap().len(),
+            1
+        );
+    }
+}
@@ -22,6 +22,12 @@ pub use solana_swap_request_handler::*;
 mod transaction_cleanup_handler;
 pub use transaction_cleanup_handler::*;
 
+mod signer_rotation_handler;
+pub use signer_rotation_handler::*;
+
+mod nonce_gap_repair_handler;
+pub use nonce_gap_repair_handler::*;
+
 pub fn handle_result(
     result: Result<(), Report>,
     attempt: Attempt,
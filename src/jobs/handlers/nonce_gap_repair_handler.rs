@@ -0,0 +1,99 @@
+//! Nonce gap repair worker implementation.
+//!
+//! This module implements the nonce gap repair worker that fills nonce gaps of EVM
+//! relayers with NOOP transactions. It runs as a cron job so transactions queued behind
+//! a nonce that will never be used are released without manual intervention.
+
+use actix_web::web::ThinData;
+use apalis::prelude::{Attempt, Data, *};
+use eyre::Result;
+use log::{error, info};
+
+use crate::{
+    constants::WORKER_DEFAULT_MAXIMUM_RETRIES,
+    domain::{get_network_relayer_by_model, Relayer},
+    jobs::handle_result,
+    models::{DefaultAppState, NetworkType},
+    repositories::Repository,
+};
+
+/// Handles periodic nonce gap repair jobs from the queue.
+///
+/// # Arguments
+/// * `job` - The cron reminder job triggering the repair
+/// * `data` - Application state containing repositories
+/// * `attempt` - Current attempt number for retry logic
+///
+/// # Returns
+/// * `Result<(), Error>` - Success or failure of repair processing
+pub async fn nonce_gap_repair_handler(
+    job: NonceGapRepairCronReminder,
+    data: Data<ThinData<DefaultAppState>>,
+    attempt: Attempt,
+) -> Result<(), Error> {
+    let result = handle_request(job, data).await;
+
+    handle_result(
+        result,
+        attempt,
+        "NonceGapRepair",
+        WORKER_DEFAULT_MAXIMUM_RETRIES,
+    )
+}
+
+/// Represents a cron reminder job for repairing nonce gaps.
+#[derive(Default, Debug, Clone)]
+pub struct NonceGapRepairCronReminder();
+
+/// Repairs the nonce gaps of every active EVM relayer.
+///
+/// A failure on one relayer does not prevent the others from being repaired.
+async fn handle_request(
+    _job: NonceGapRepairCronReminder,
+    data: Data<ThinData<DefaultAppState>>,
+) -> Result<()> {
+    let state: &ThinData<DefaultAppState> = &data;
+    let relayers = state.relayer_repository().list_all().await.map_err(|e| {
+        error!("Failed to fetch relayers for nonce gap repair: {}", e);
+        eyre::eyre!("Failed to fetch relayers: {}", e)
+    })?;
+
+    let mut failures = 0;
+    for relayer in relayers.into_iter().filter(|relayer| {
+        relayer.network_type == NetworkType::Evm && !relayer.paused && !relayer.system_disabled
+    }) {
+        let relayer_id = relayer.id.clone();
+        let result = match get_network_relayer_by_model(relayer, state).await {
+            Ok(network_relayer) => network_relayer
+                .repair_nonce_gaps()
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(transactions) if !transactions.is_empty() => info!(
+                "Filled {} nonce gaps of relayer {}",
+                transactions.len(),
+                relayer_id
+            ),
+            Ok(_) => {}
+            Err(e) => {
+                error!(
+                    "Failed to repair nonce gaps of relayer {}: {}",
+                    relayer_id, e
+                );
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(eyre::eyre!(
+            "Failed to repair nonce gaps of {} relayers",
+            failures
+        ));
+    }
+
+    Ok(())
+}
@@ -94,6 +94,17 @@ mod tests {
                 max_priority_fee_per_gas: None,
                 signature: None,
                 speed: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
+                execute_after: None,
+                schedule: None,
             },
         )));
 
@@ -135,6 +146,17 @@ mod tests {
                 max_priority_fee_per_gas: None,
                 signature: None,
                 speed: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
+                execute_after: None,
+                schedule: None,
             },
         )));
 
@@ -156,6 +178,16 @@ mod tests {
                         whitelist_receivers: None,
                         eip1559_pricing: None,
                         private_transactions: Some(false),
+                        simulate_transactions: None,
+                        access_list_generation: None,
+                        entry_point: None,
+                        max_value_per_transaction: None,
+                        max_total_value: None,
+                        max_gas_spend: None,
+                        spending_window: None,
+                        allowed_selectors: None,
+                        trusted_forwarder: None,
+                        authorization_signers: None,
                         min_balance: Some(0),
                         gas_limit_estimation: None,
                     }
@@ -0,0 +1,89 @@
+//! Signer rotation worker implementation.
+//!
+//! This module implements the signer rotation worker that advances relayers with an
+//! active signer rotation. It runs as a cron job so rotations waiting for pending
+//! transactions or a balance sweep complete without further requests.
+
+use actix_web::web::ThinData;
+use apalis::prelude::{Attempt, Data, *};
+use eyre::Result;
+use log::{error, info};
+
+use crate::{
+    constants::WORKER_DEFAULT_MAXIMUM_RETRIES, domain::advance_signer_rotation,
+    jobs::handle_result, models::DefaultAppState, repositories::Repository,
+};
+
+/// Handles periodic signer rotation jobs from the queue.
+///
+/// # Arguments
+/// * `job` - The cron reminder job triggering the rotation check
+/// * `data` - Application state containing repositories
+/// * `attempt` - Current attempt number for retry logic
+///
+/// # Returns
+/// * `Result<(), Error>` - Success or failure of rotation processing
+pub async fn signer_rotation_handler(
+    job: SignerRotationCronReminder,
+    data: Data<ThinData<DefaultAppState>>,
+    attempt: Attempt,
+) -> Result<(), Error> {
+    let result = handle_request(job, data).await;
+
+    handle_result(
+        result,
+        attempt,
+        "SignerRotation",
+        WORKER_DEFAULT_MAXIMUM_RETRIES,
+    )
+}
+
+/// Represents a cron reminder job for advancing signer rotations.
+#[derive(Default, Debug, Clone)]
+pub struct SignerRotationCronReminder();
+
+/// Advances every relayer that has an active signer rotation.
+///
+/// A failure on one relayer does not prevent the others from being advanced.
+async fn handle_request(
+    _job: SignerRotationCronReminder,
+    data: Data<ThinData<DefaultAppState>>,
+) -> Result<()> {
+    let state: &ThinData<DefaultAppState> = &data;
+    let relayers = state.relayer_repository().list_all().await.map_err(|e| {
+        error!("Failed to fetch relayers for signer rotation: {}", e);
+        eyre::eyre!("Failed to fetch relayers: {}", e)
+    })?;
+
+    let rotating: Vec<_> = relayers
+        .into_iter()
+        .filter(|relayer| relayer.active_signer_rotation().is_some())
+        .collect();
+
+    if rotating.is_empty() {
+        return Ok(());
+    }
+
+    info!("Advancing signer rotation for {} relayers", rotating.len());
+
+    let mut failures = 0;
+    for relayer in rotating {
+        let relayer_id = relayer.id.clone();
+        if let Err(e) = advance_signer_rotation(relayer, state).await {
+            error!(
+                "Failed to advance signer rotation for relayer {}: {}",
+                relayer_id, e
+            );
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        return Err(eyre::eyre!(
+            "Failed to advance signer rotation for {} relayers",
+            failures
+        ));
+    }
+
+    Ok(())
+}
@@ -762,6 +762,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
         let now = Utc::now();
 
@@ -805,6 +806,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
         let now = Utc::now();
 
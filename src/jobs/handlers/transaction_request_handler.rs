@@ -5,14 +5,18 @@
 use actix_web::web::ThinData;
 use apalis::prelude::{Attempt, Context, Data, TaskId, Worker, *};
 use apalis_redis::RedisContext;
+use chrono::Utc;
 use eyre::Result;
 use log::info;
 
 use crate::{
-    constants::WORKER_DEFAULT_MAXIMUM_RETRIES,
-    domain::{get_relayer_transaction, get_transaction_by_id, Transaction},
-    jobs::{handle_result, Job, TransactionRequest},
-    models::DefaultAppState,
+    constants::{TRANSACTION_DEPENDENCY_RECHECK_SECONDS, WORKER_DEFAULT_MAXIMUM_RETRIES},
+    domain::{
+        check_transaction_dependencies, fail_dependent_transaction, get_relayer_transaction,
+        get_transaction_by_id, schedule_next_occurrence, DependencyState, Transaction,
+    },
+    jobs::{handle_result, Job, JobProducerTrait, TransactionRequest},
+    models::{DefaultAppState, TransactionStatus},
 };
 
 pub async fn transaction_request_handler(
@@ -47,6 +51,61 @@ async fn handle_request(
 
     let transaction = get_transaction_by_id(request.transaction_id, &state).await?;
 
+    // Scheduled transactions can be cancelled before their job runs
+    if transaction.status != TransactionStatus::Pending {
+        info!(
+            "Transaction {} is {:?}, skipping transaction request",
+            transaction.id, transaction.status
+        );
+        return Ok(());
+    }
+
+    match check_transaction_dependencies(&transaction, state.transaction_repository().as_ref())
+        .await?
+    {
+        DependencyState::Ready => {}
+        DependencyState::Waiting => {
+            info!(
+                "Transaction {} is waiting for its prerequisites",
+                transaction.id
+            );
+            state
+                .job_producer()
+                .produce_transaction_request_job(
+                    TransactionRequest::new(transaction.id, transaction.relayer_id),
+                    Some(Utc::now().timestamp() + TRANSACTION_DEPENDENCY_RECHECK_SECONDS),
+                )
+                .await?;
+            return Ok(());
+        }
+        DependencyState::Failed(reason) => {
+            fail_dependent_transaction(&transaction, reason, &state).await?;
+            return Ok(());
+        }
+    }
+
+    let transaction = if transaction.schedule.is_some() {
+        if let Some(next) = schedule_next_occurrence(&transaction, &state).await? {
+            info!(
+                "Scheduled next occurrence {} of transaction {} at {:?}",
+                next.id, transaction.id, next.execute_after
+            );
+        }
+
+        // The occurrence may have been cancelled while the series moved on
+        let transaction = get_transaction_by_id(transaction.id, &state).await?;
+        if transaction.status != TransactionStatus::Pending {
+            info!(
+                "Transaction {} is {:?}, skipping transaction request",
+                transaction.id, transaction.status
+            );
+            return Ok(());
+        }
+        transaction
+    } else {
+        transaction
+    };
+
     relayer_transaction.prepare_transaction(transaction).await?;
 
     info!("Transaction request handled successfully");
@@ -49,7 +49,7 @@ async fn handle_request(
         }
         TransactionCommand::Cancel { reason } => {
             info!("Cancelling transaction: {:?}", reason);
-            relayer_transaction.submit_transaction(transaction).await?;
+            relayer_transaction.cancel_transaction(transaction).await?;
         }
         TransactionCommand::Resubmit => {
             info!("Resubmitting transaction with updated parameters");
@@ -503,6 +503,17 @@ mod tests {
                     max_priority_fee_per_gas: None,
                     signature: None,
                     speed: None,
+                    authorization_list: None,
+                    access_list: None,
+                    user_operation_hashes: None,
+                    block_number: None,
+                    block_hash: None,
+                    gas_used: None,
+                    effective_gas_price: None,
+                    l1_fee: None,
+                    logs: None,
+                    execute_after: None,
+                    schedule: None,
                 },
             ))),
         );
@@ -28,7 +28,6 @@ use std::sync::Arc;
 
 use actix_governor::{Governor, GovernorConfigBuilder};
 use actix_web::{
-    dev::Service,
     middleware::{self, Logger},
     web::{self},
     App, HttpServer,
@@ -37,9 +36,7 @@ use color_eyre::{eyre::WrapErr, Result};
 use config::Config;
 use metrics::middleware::MetricsMiddleware;
 
-use actix_web::HttpResponse;
-
-use config::ApiKeyRateLimit;
+use config::{ApiKeyRateLimit, ClientIpRateLimit};
 use dotenvy::dotenv;
 use log::info;
 use std::env;
@@ -51,10 +48,9 @@ use openzeppelin_relayer::{
         initialize_workers, process_config_file,
     },
     config,
-    constants::PUBLIC_ENDPOINTS,
     logging::setup_logging,
     metrics,
-    utils::check_authorization_header,
+    utils::api_key_auth_middleware,
 };
 
 fn load_config_file(config_file_path: &str) -> Result<Config> {
@@ -101,6 +97,13 @@ async fn main() -> Result<()> {
         .burst_size(config.rate_limit_burst_size)
         .finish()
         .unwrap();
+    // Clients behind one proxy share an address, so its quota covers several API keys
+    let client_rate_limit_config = GovernorConfigBuilder::default()
+        .requests_per_second(config.client_rate_limit_requests_per_second)
+        .key_extractor(ClientIpRateLimit)
+        .burst_size(config.client_rate_limit_burst_size)
+        .finish()
+        .unwrap();
 
     info!("Starting server on {}:{}", config.host, config.port);
     let app_server = HttpServer::new({
@@ -108,38 +111,21 @@ async fn main() -> Result<()> {
         let server_config_clone = Arc::clone(&server_config);
         let app_state = app_state.clone();
         move || {
-            let config = Arc::clone(&server_config_clone);
             let app = App::new();
 
-            app
-            .wrap_fn(move |req, srv| {
-                let path = req.path();
-
-                let is_public_endpoint = PUBLIC_ENDPOINTS.iter().any(|prefix| path.starts_with(prefix));
-
-                if is_public_endpoint {
-                    return srv.call(req);
-                }
-
-                if check_authorization_header(&req, &config.api_key) {
-                    return srv.call(req);
-                }
-                Box::pin(async move {
-                    Ok(req.into_response(
-                        HttpResponse::Unauthorized().body(
-                            r#"{"success": false, "code":401, "error": "Unauthorized", "message": "Unauthorized"}"#.to_string(),
-                        ),
-                    ))
-                })
-            })
-            .wrap(Governor::new(&rate_limit_config))
-            .wrap(middleware::Compress::default())
-            .wrap(middleware::NormalizePath::trim())
-            .wrap(middleware::DefaultHeaders::new())
-            .wrap(MetricsMiddleware)
-            .wrap(Logger::default())
-            .app_data(app_state.clone())
-            .service(web::scope("/api/v1").configure(api::routes::configure_routes))
+            // The last middleware wrapped runs first: clients are rate limited by address
+            // before authentication, and by API key once the key has been resolved.
+            app.wrap(Governor::new(&rate_limit_config))
+                .wrap(middleware::from_fn(api_key_auth_middleware))
+                .wrap(Governor::new(&client_rate_limit_config))
+                .wrap(middleware::Compress::default())
+                .wrap(middleware::NormalizePath::trim())
+                .wrap(middleware::DefaultHeaders::new())
+                .wrap(MetricsMiddleware)
+                .wrap(Logger::default())
+                .app_data(app_state.clone())
+                .app_data(web::Data::from(Arc::clone(&server_config_clone)))
+                .service(web::scope("/api/v1").configure(api::routes::configure_routes))
         }
     })
     .bind((config.host.as_str(), config.port))
@@ -184,11 +170,11 @@ async fn main() -> Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
     use actix_web::test::TestRequest;
     use openzeppelin_relayer::{
         constants::{AUTHORIZATION_HEADER_NAME, AUTHORIZATION_HEADER_VALUE_PREFIX},
         models::SecretString,
+        utils::check_authorization_header,
     };
 
     #[test]
@@ -0,0 +1,159 @@
+//! API key domain model.
+//!
+//! API keys authenticate callers of the REST API. Besides the root key configured through
+//! the `API_KEY` environment variable, named keys can be created at runtime. Each named key
+//! carries a set of scopes and an optional allowlist of relayers it may act on.
+//!
+//! - **Scopes**: `read`, `send-transactions`, `sign` and `admin` (admin implies all others)
+//! - **Relayer allowlist**: an empty list grants access to every relayer
+//! - **Identity**: the resolved caller attached to each authenticated request
+
+mod repository;
+pub use repository::*;
+
+mod request;
+pub use request::*;
+
+mod response;
+pub use response::*;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Identifier of the identity resolved from the root `API_KEY`
+pub const ROOT_API_KEY_ID: &str = "root";
+
+/// Permission granted to an API key
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApiKeyScope {
+    /// Read-only access to resources
+    Read,
+    /// Submit, replace and cancel transactions and forward JSON-RPC calls
+    SendTransactions,
+    /// Sign arbitrary data, typed data and transactions
+    Sign,
+    /// Full access, including management of relayers, signers and API keys
+    Admin,
+}
+
+impl std::fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiKeyScope::Read => write!(f, "read"),
+            ApiKeyScope::SendTransactions => write!(f, "send-transactions"),
+            ApiKeyScope::Sign => write!(f, "sign"),
+            ApiKeyScope::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+/// Caller identity resolved from the bearer token of a request
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiKeyIdentity {
+    pub id: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub allowed_relayers: Vec<String>,
+}
+
+impl ApiKeyIdentity {
+    /// Identity of the root key, which has unrestricted admin access.
+    pub fn root() -> Self {
+        Self {
+            id: ROOT_API_KEY_ID.to_string(),
+            scopes: vec![ApiKeyScope::Admin],
+            allowed_relayers: vec![],
+        }
+    }
+
+    /// Returns true if the identity holds the scope, either directly or through admin.
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes
+            .iter()
+            .any(|s| *s == scope || *s == ApiKeyScope::Admin)
+    }
+
+    /// Returns true if the identity is not limited to a subset of relayers.
+    pub fn is_unrestricted(&self) -> bool {
+        self.allowed_relayers.is_empty()
+    }
+
+    /// Returns true if the identity may act on the given relayer.
+    pub fn can_access_relayer(&self, relayer_id: &str) -> bool {
+        self.is_unrestricted() || self.allowed_relayers.iter().any(|id| id == relayer_id)
+    }
+
+    /// Key used to bucket requests from this identity in the rate limiter.
+    pub fn rate_limit_key(&self) -> String {
+        format!("api-key:{}", self.id)
+    }
+}
+
+impl From<ApiKeyRepoModel> for ApiKeyIdentity {
+    fn from(model: ApiKeyRepoModel) -> Self {
+        Self {
+            id: model.id,
+            scopes: model.scopes,
+            allowed_relayers: model.allowed_relayers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_serialization() {
+        assert_eq!(
+            serde_json::to_string(&ApiKeyScope::SendTransactions).unwrap(),
+            r#""send-transactions""#
+        );
+        let scope: ApiKeyScope = serde_json::from_str(r#""admin""#).unwrap();
+        assert_eq!(scope, ApiKeyScope::Admin);
+        assert_eq!(
+            ApiKeyScope::SendTransactions.to_string(),
+            "send-transactions"
+        );
+    }
+
+    #[test]
+    fn test_root_identity_has_all_scopes() {
+        let identity = ApiKeyIdentity::root();
+
+        assert!(identity.has_scope(ApiKeyScope::Read));
+        assert!(identity.has_scope(ApiKeyScope::SendTransactions));
+        assert!(identity.has_scope(ApiKeyScope::Sign));
+        assert!(identity.has_scope(ApiKeyScope::Admin));
+        assert!(identity.can_access_relayer("any-relayer"));
+    }
+
+    #[test]
+    fn test_scoped_identity() {
+        let identity = ApiKeyIdentity {
+            id: "key-1".to_string(),
+            scopes: vec![ApiKeyScope::Read, ApiKeyScope::Sign],
+            allowed_relayers: vec!["relayer-1".to_string()],
+        };
+
+        assert!(identity.has_scope(ApiKeyScope::Read));
+        assert!(identity.has_scope(ApiKeyScope::Sign));
+        assert!(!identity.has_scope(ApiKeyScope::SendTransactions));
+        assert!(!identity.has_scope(ApiKeyScope::Admin));
+        assert!(!identity.is_unrestricted());
+        assert!(identity.can_access_relayer("relayer-1"));
+        assert!(!identity.can_access_relayer("relayer-2"));
+    }
+
+    #[test]
+    fn test_rate_limit_key_uses_identity() {
+        let identity = ApiKeyIdentity {
+            id: "key-1".to_string(),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec![],
+        };
+
+        assert_eq!(identity.rate_limit_key(), "api-key:key-1");
+        assert_eq!(ApiKeyIdentity::root().rate_limit_key(), "api-key:root");
+    }
+}
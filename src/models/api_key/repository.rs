@@ -0,0 +1,47 @@
+//! Repository layer model for API keys.
+//!
+//! The key value is stored encrypted using the same mechanism as signer secrets, so
+//! persisted keys are never readable from the storage backend.
+
+use crate::models::{ApiKeyScope, SecretString};
+use crate::utils::{deserialize_secret_string, serialize_secret_string};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiKeyRepoModel {
+    pub id: String,
+    pub name: String,
+    #[serde(
+        serialize_with = "serialize_secret_string",
+        deserialize_with = "deserialize_secret_string"
+    )]
+    pub value: SecretString,
+    pub scopes: Vec<ApiKeyScope>,
+    /// Relayers the key may act on; an empty list grants access to all relayers
+    #[serde(default)]
+    pub allowed_relayers: Vec<String>,
+    pub created_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialization_does_not_expose_value() {
+        let model = ApiKeyRepoModel {
+            id: "key-1".to_string(),
+            name: "ci".to_string(),
+            value: SecretString::new("super-secret-api-key-value"),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec![],
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+        };
+
+        let json = serde_json::to_string(&model).unwrap();
+        assert!(!json.contains("super-secret-api-key-value"));
+
+        let deserialized: ApiKeyRepoModel = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, model);
+    }
+}
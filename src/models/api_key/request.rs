@@ -0,0 +1,96 @@
+//! API request models and validation for API key endpoints.
+
+use crate::models::{ApiError, ApiKeyScope};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request structure for creating a new API key
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyCreateRequest {
+    /// Human readable name used to identify the key
+    pub name: String,
+    /// Permissions granted to the key
+    pub scopes: Vec<ApiKeyScope>,
+    /// Relayers the key may act on; omit or leave empty to allow all relayers
+    #[serde(default)]
+    pub allowed_relayers: Vec<String>,
+}
+
+impl ApiKeyCreateRequest {
+    /// Validates the request fields that do not depend on stored state.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if self.name.trim().is_empty() {
+            return Err(ApiError::BadRequest(
+                "API key name cannot be empty".to_string(),
+            ));
+        }
+
+        if self.scopes.is_empty() {
+            return Err(ApiError::BadRequest(
+                "API key must have at least one scope".to_string(),
+            ));
+        }
+
+        if self.allowed_relayers.iter().any(|id| id.trim().is_empty()) {
+            return Err(ApiError::BadRequest(
+                "Allowed relayer IDs cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_defaults_allowed_relayers() {
+        let request: ApiKeyCreateRequest =
+            serde_json::from_str(r#"{"name": "ci", "scopes": ["read", "sign"]}"#).unwrap();
+
+        assert_eq!(request.name, "ci");
+        assert_eq!(request.scopes, vec![ApiKeyScope::Read, ApiKeyScope::Sign]);
+        assert!(request.allowed_relayers.is_empty());
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_unknown_scope() {
+        let result =
+            serde_json::from_str::<ApiKeyCreateRequest>(r#"{"name": "ci", "scopes": ["write"]}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_name() {
+        let request = ApiKeyCreateRequest {
+            name: "  ".to_string(),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec![],
+        };
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_scopes() {
+        let request = ApiKeyCreateRequest {
+            name: "ci".to_string(),
+            scopes: vec![],
+            allowed_relayers: vec![],
+        };
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_relayer_id() {
+        let request = ApiKeyCreateRequest {
+            name: "ci".to_string(),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec!["".to_string()],
+        };
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+    }
+}
@@ -0,0 +1,65 @@
+//! API response models for API key endpoints.
+//!
+//! The key value is never included in list responses. It is returned exactly once,
+//! in the response to the request that created the key.
+
+use crate::models::{ApiKeyRepoModel, ApiKeyScope};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Response structure for API key endpoints
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct ApiKeyResponse {
+    pub id: String,
+    pub name: String,
+    pub scopes: Vec<ApiKeyScope>,
+    pub allowed_relayers: Vec<String>,
+    pub created_at: String,
+}
+
+impl From<ApiKeyRepoModel> for ApiKeyResponse {
+    fn from(model: ApiKeyRepoModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            scopes: model.scopes,
+            allowed_relayers: model.allowed_relayers,
+            created_at: model.created_at,
+        }
+    }
+}
+
+/// Response returned when an API key is created, including the key value
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct ApiKeyCreateResponse {
+    #[serde(flatten)]
+    pub api_key: ApiKeyResponse,
+    /// The key value; store it securely, it cannot be retrieved again
+    pub key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SecretString;
+
+    #[test]
+    fn test_from_repo_model_hides_value() {
+        let model = ApiKeyRepoModel {
+            id: "key-1".to_string(),
+            name: "ci".to_string(),
+            value: SecretString::new("super-secret-api-key-value"),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec!["relayer-1".to_string()],
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+        };
+
+        let response = ApiKeyResponse::from(model);
+        assert_eq!(response.id, "key-1");
+        assert_eq!(response.name, "ci");
+        assert_eq!(response.allowed_relayers, vec!["relayer-1".to_string()]);
+
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(!json.contains("super-secret-api-key-value"));
+    }
+}
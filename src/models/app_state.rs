@@ -8,15 +8,15 @@ use actix_web::web::ThinData;
 use crate::{
     jobs::{JobProducer, JobProducerTrait},
     models::{
-        NetworkRepoModel, NotificationRepoModel, RelayerRepoModel, SignerRepoModel,
-        TransactionRepoModel,
+        NetworkRepoModel, NotificationRepoModel, RelayerPoolRepoModel, RelayerRepoModel,
+        SignerRepoModel, TransactionRepoModel,
     },
     repositories::{
-        NetworkRepository, NetworkRepositoryStorage, NotificationRepositoryStorage,
-        PluginRepositoryStorage, PluginRepositoryTrait, RelayerRepository,
-        RelayerRepositoryStorage, Repository, SignerRepositoryStorage,
-        TransactionCounterRepositoryStorage, TransactionCounterTrait, TransactionRepository,
-        TransactionRepositoryStorage,
+        ApiKeyRepository, ApiKeyRepositoryStorage, NetworkRepository, NetworkRepositoryStorage,
+        NotificationRepositoryStorage, PluginRepositoryStorage, PluginRepositoryTrait,
+        RelayerPoolRepositoryStorage, RelayerRepository, RelayerRepositoryStorage, Repository,
+        SignerRepositoryStorage, TransactionCounterRepositoryStorage, TransactionCounterTrait,
+        TransactionRepository, TransactionRepositoryStorage,
     },
 };
 
@@ -32,6 +32,8 @@ pub struct AppState<
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 > {
     /// Repository for managing relayer data.
     pub relayer_repository: Arc<RR>,
@@ -49,11 +51,15 @@ pub struct AppState<
     pub job_producer: Arc<J>,
     /// Repository for managing plugins.
     pub plugin_repository: Arc<PR>,
+    /// Repository for managing API keys.
+    pub api_key_repository: Arc<AKR>,
+    /// Repository for managing relayer pools.
+    pub relayer_pool_repository: Arc<RPR>,
 }
 
 /// type alias for the app state wrapped in a ThinData to avoid clippy warnings
-pub type ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR> =
-    ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>;
+pub type ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR> =
+    ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>;
 
 pub type DefaultAppState = AppState<
     JobProducer,
@@ -64,6 +70,8 @@ pub type DefaultAppState = AppState<
     SignerRepositoryStorage,
     TransactionCounterRepositoryStorage,
     PluginRepositoryStorage,
+    ApiKeyRepositoryStorage,
+    RelayerPoolRepositoryStorage,
 >;
 
 impl<
@@ -75,7 +83,9 @@ impl<
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
-    > AppState<J, RR, TR, NR, NFR, SR, TCR, PR>
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
+    > AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>
 {
     /// Returns a clone of the relayer repository.
     ///
@@ -148,6 +158,24 @@ impl<
     pub fn plugin_repository(&self) -> Arc<PR> {
         Arc::clone(&self.plugin_repository)
     }
+
+    /// Returns a clone of the API key repository.
+    ///
+    /// # Returns
+    ///
+    /// An `Arc` pointing to the `ApiKeyRepositoryStorage`.
+    pub fn api_key_repository(&self) -> Arc<AKR> {
+        Arc::clone(&self.api_key_repository)
+    }
+
+    /// Returns a clone of the relayer pool repository.
+    ///
+    /// # Returns
+    ///
+    /// An `Arc` pointing to the `RelayerPoolRepositoryStorage`.
+    pub fn relayer_pool_repository(&self) -> Arc<RPR> {
+        Arc::clone(&self.relayer_pool_repository)
+    }
 }
 
 #[cfg(test)]
@@ -166,6 +194,8 @@ mod tests {
         SignerRepositoryStorage,
         TransactionCounterRepositoryStorage,
         PluginRepositoryStorage,
+        ApiKeyRepositoryStorage,
+        RelayerPoolRepositoryStorage,
     > {
         // Create a mock job producer
         let mut mock_job_producer = MockJobProducerTrait::new();
@@ -198,6 +228,8 @@ mod tests {
             ),
             job_producer: Arc::new(mock_job_producer),
             plugin_repository: Arc::new(PluginRepositoryStorage::new_in_memory()),
+            api_key_repository: Arc::new(ApiKeyRepositoryStorage::new_in_memory()),
+            relayer_pool_repository: Arc::new(RelayerPoolRepositoryStorage::new_in_memory()),
         }
     }
 
@@ -271,4 +303,24 @@ mod tests {
         assert!(Arc::ptr_eq(&store1, &store2));
         assert!(Arc::ptr_eq(&store1, &app_state.plugin_repository));
     }
+
+    #[test]
+    fn test_api_key_repository_getter() {
+        let app_state = create_test_app_state();
+        let store1 = app_state.api_key_repository();
+        let store2 = app_state.api_key_repository();
+
+        assert!(Arc::ptr_eq(&store1, &store2));
+        assert!(Arc::ptr_eq(&store1, &app_state.api_key_repository));
+    }
+
+    #[test]
+    fn test_relayer_pool_repository_getter() {
+        let app_state = create_test_app_state();
+        let store1 = app_state.relayer_pool_repository();
+        let store2 = app_state.relayer_pool_repository();
+
+        assert!(Arc::ptr_eq(&store1, &store2));
+        assert!(Arc::ptr_eq(&store1, &app_state.relayer_pool_repository));
+    }
 }
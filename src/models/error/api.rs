@@ -26,6 +26,12 @@ pub enum ApiError {
 
     #[error("Forbidden: {0}")]
     ForbiddenError(String),
+
+    #[error("Spending limit exceeded: {0}")]
+    SpendingLimitExceeded(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
 }
 
 impl ResponseError for ApiError {
@@ -50,6 +56,10 @@ impl ResponseError for ApiError {
             ApiError::ForbiddenError(msg) => {
                 HttpResponse::Forbidden().json(ApiResponse::<()>::error(msg))
             }
+            ApiError::SpendingLimitExceeded(msg) => HttpResponse::Forbidden().json(
+                ApiResponse::<()>::error(format!("Spending limit exceeded: {}", msg)),
+            ),
+            ApiError::Conflict(msg) => HttpResponse::Conflict().json(ApiResponse::<()>::error(msg)),
         }
     }
 }
@@ -86,6 +96,12 @@ mod tests {
         let forbidden = ApiError::ForbiddenError("Access denied".to_string());
         assert_eq!(forbidden.to_string(), "Forbidden: Access denied");
 
+        let spending_limit = ApiError::SpendingLimitExceeded("Daily budget spent".to_string());
+        assert_eq!(
+            spending_limit.to_string(),
+            "Spending limit exceeded: Daily budget spent"
+        );
+
         // Test Report conversion
         let report = Report::msg("Something went wrong");
         let internal_eyre_error = ApiError::InternalEyreError(report);
@@ -121,6 +137,10 @@ mod tests {
         let response = forbidden.error_response();
         assert_eq!(response.status(), StatusCode::FORBIDDEN);
 
+        let spending_limit = ApiError::SpendingLimitExceeded("Value cap".to_string());
+        let response = spending_limit.error_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
         let report = Report::msg("Internal error");
         let internal_eyre_error = ApiError::InternalEyreError(report);
         let response = internal_eyre_error.error_response();
@@ -45,6 +45,8 @@ pub enum RelayerError {
     DexError(String),
     #[error("Transaction validation error: {0}")]
     ValidationError(String),
+    #[error("Relayer signer rotation in progress")]
+    SignerRotationInProgress,
 }
 
 impl From<RelayerError> for ApiError {
@@ -69,6 +71,9 @@ impl From<RelayerError> for ApiError {
             RelayerError::InvalidDexName(msg) => ApiError::InternalError(msg),
             RelayerError::DexError(msg) => ApiError::InternalError(msg),
             RelayerError::ValidationError(msg) => ApiError::BadRequest(msg),
+            RelayerError::SignerRotationInProgress => {
+                ApiError::ForbiddenError("Relayer signer rotation in progress".to_string())
+            }
         }
     }
 }
@@ -201,6 +206,10 @@ mod tests {
         assert!(matches!(api_error, ApiError::ForbiddenError(_)));
         assert_eq!(api_error.to_string(), "Forbidden: Relayer disabled");
 
+        let rotating = RelayerError::SignerRotationInProgress;
+        let api_error: ApiError = rotating.into();
+        assert!(matches!(api_error, ApiError::ForbiddenError(_)));
+
         let insufficient = RelayerError::InsufficientBalanceError("Not enough funds".to_string());
         let api_error: ApiError = insufficient.into();
         assert!(matches!(api_error, ApiError::BadRequest(_)));
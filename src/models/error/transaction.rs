@@ -44,6 +44,9 @@ pub enum TransactionError {
 
     #[error("Stellar transaction simulation failed: {0}")]
     SimulationFailed(String),
+
+    #[error("Spending limit exceeded: {0}")]
+    SpendingLimitExceeded(String),
 }
 
 impl From<TransactionError> for ApiError {
@@ -62,6 +65,7 @@ impl From<TransactionError> for ApiError {
             TransactionError::SignerError(msg) => ApiError::InternalError(msg),
             TransactionError::InsufficientBalance(msg) => ApiError::BadRequest(msg),
             TransactionError::SimulationFailed(msg) => ApiError::BadRequest(msg),
+            TransactionError::SpendingLimitExceeded(msg) => ApiError::SpendingLimitExceeded(msg),
         }
     }
 }
@@ -150,6 +154,10 @@ mod tests {
                 TransactionError::SimulationFailed("sim failed".to_string()),
                 "Stellar transaction simulation failed: sim failed",
             ),
+            (
+                TransactionError::SpendingLimitExceeded("budget spent".to_string()),
+                "Spending limit exceeded: budget spent",
+            ),
         ];
 
         for (error, expected_message) in test_cases {
@@ -192,6 +200,10 @@ mod tests {
                 TransactionError::SimulationFailed("boom".to_string()),
                 ApiError::BadRequest("boom".to_string()),
             ),
+            (
+                TransactionError::SpendingLimitExceeded("budget spent".to_string()),
+                ApiError::SpendingLimitExceeded("budget spent".to_string()),
+            ),
         ];
 
         for (tx_error, expected_api_error) in test_cases {
@@ -204,6 +216,12 @@ mod tests {
                 (ApiError::InternalError(actual), ApiError::InternalError(expected)) => {
                     assert_eq!(actual, expected);
                 }
+                (
+                    ApiError::SpendingLimitExceeded(actual),
+                    ApiError::SpendingLimitExceeded(expected),
+                ) => {
+                    assert_eq!(actual, expected);
+                }
                 _ => panic!(
                     "Error types don't match: {:?} vs {:?}",
                     api_error, expected_api_error
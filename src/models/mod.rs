@@ -8,6 +8,9 @@ pub use network::*;
 mod app_state;
 pub use app_state::*;
 
+mod api_key;
+pub use api_key::*;
+
 mod api_response;
 pub use api_response::*;
 
@@ -17,6 +20,9 @@ pub use transaction::*;
 pub mod relayer;
 pub use relayer::*;
 
+mod relayer_pool;
+pub use relayer_pool::*;
+
 // Type aliases for backward compatibility with domain logic
 pub use relayer::{SolanaAllowedTokensPolicy, SolanaFeePaymentStrategy, SolanaSwapStrategy};
 
@@ -1,4 +1,4 @@
-use crate::config::GasPriceCacheConfig;
+use crate::config::{GasOracleConfig, GasPriceCacheConfig, PrivateRelayConfig};
 use crate::constants::{
     ARBITRUM_BASED_TAG, LACKS_MEMPOOL_TAGS, OPTIMISM_BASED_TAG, OPTIMISM_TAG, ROLLUP_TAG,
 };
@@ -30,6 +30,10 @@ pub struct EvmNetwork {
     pub symbol: String,
     /// Gas price cache configuration
     pub gas_price_cache: Option<GasPriceCacheConfig>,
+    /// Private relay used by relayers with `private_transactions` enabled
+    pub private_relay: Option<PrivateRelayConfig>,
+    /// Gas oracle used to price transactions
+    pub gas_oracle: Option<GasOracleConfig>,
 }
 
 impl TryFrom<NetworkRepoModel> for EvmNetwork {
@@ -88,6 +92,8 @@ impl TryFrom<NetworkRepoModel> for EvmNetwork {
                     features: evm_config.features.clone().unwrap_or_default(),
                     symbol,
                     gas_price_cache: evm_config.gas_price_cache.clone(),
+                    private_relay: evm_config.private_relay.clone(),
+                    gas_oracle: evm_config.gas_oracle.clone(),
                 })
             }
             _ => Err(RepositoryError::InvalidData(format!(
@@ -181,6 +187,8 @@ mod tests {
             features: vec!["eip1559".to_string()],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -282,6 +290,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string()]),
             symbol: Some("ETH".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let repo_model = NetworkRepoModel {
@@ -1,9 +1,13 @@
 mod evm;
 mod repository;
+mod request;
+mod response;
 mod solana;
 mod stellar;
 
 pub use evm::*;
 pub use repository::*;
+pub use request::*;
+pub use response::*;
 pub use solana::*;
 pub use stellar::*;
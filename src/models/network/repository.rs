@@ -157,6 +157,17 @@ impl TryFrom<NetworkFileConfig> for NetworkRepoModel {
     }
 }
 
+impl From<NetworkConfigData> for NetworkFileConfig {
+    /// Converts stored network configuration data back into its file configuration form.
+    fn from(config: NetworkConfigData) -> Self {
+        match config {
+            NetworkConfigData::Evm(config) => NetworkFileConfig::Evm(config),
+            NetworkConfigData::Solana(config) => NetworkFileConfig::Solana(config),
+            NetworkConfigData::Stellar(config) => NetworkFileConfig::Stellar(config),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +188,8 @@ mod tests {
             features: Some(vec!["eip1559".to_string()]),
             symbol: Some(symbol.to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -394,6 +407,15 @@ mod tests {
         assert_eq!(network_repo.id, "stellar:testnet");
     }
 
+    #[test]
+    fn test_network_file_config_from_config_data() {
+        let config = NetworkConfigData::Stellar(create_stellar_config("testnet", None));
+
+        let file_config = NetworkFileConfig::from(config);
+        assert!(matches!(file_config, NetworkFileConfig::Stellar(_)));
+        assert_eq!(file_config.network_name(), "testnet");
+    }
+
     #[test]
     fn test_serialization_roundtrip() {
         let config = create_evm_config("mainnet", 1, "ETH");
@@ -467,6 +489,8 @@ mod tests {
             features: None,
             symbol: Some("ETH".to_string()),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         };
 
         let network_repo = NetworkRepoModel::new_evm(minimal_config);
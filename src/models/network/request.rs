@@ -0,0 +1,488 @@
+//! API request models for network endpoints.
+//!
+//! Networks managed through the API accept the same fields as entries in the network
+//! configuration files. Fields that only apply to EVM or Stellar networks are rejected
+//! when used with another network type.
+
+use crate::{
+    config::{
+        EvmNetworkConfig, GasOracleConfig, GasPriceCacheConfig, NetworkConfigCommon,
+        NetworkFileConfig, PrivateRelayConfig, SolanaNetworkConfig, StellarNetworkConfig,
+    },
+    models::{ApiError, NetworkConfigData, NetworkRepoModel, NetworkResponse, RelayerNetworkType},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request structure for creating a new network
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkCreateRequest {
+    pub r#type: RelayerNetworkType,
+    /// Network name, unique within its network type (e.g. "sepolia")
+    pub network: String,
+    /// Name of an existing network of the same type to inherit configuration from
+    #[schema(nullable = false)]
+    pub from: Option<String>,
+    #[schema(nullable = false)]
+    pub rpc_urls: Option<Vec<String>>,
+    #[schema(nullable = false)]
+    pub explorer_urls: Option<Vec<String>>,
+    #[schema(nullable = false)]
+    pub average_blocktime_ms: Option<u64>,
+    #[schema(nullable = false)]
+    pub is_testnet: Option<bool>,
+    #[schema(nullable = false)]
+    pub tags: Option<Vec<String>>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub chain_id: Option<u64>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub required_confirmations: Option<u64>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub features: Option<Vec<String>>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub symbol: Option<String>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub gas_price_cache: Option<GasPriceCacheConfig>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub private_relay: Option<PrivateRelayConfig>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub gas_oracle: Option<GasOracleConfig>,
+    /// Stellar only
+    #[schema(nullable = false)]
+    pub passphrase: Option<String>,
+}
+
+/// Request structure for updating an existing network.
+///
+/// The network type, name and parent cannot be changed. Provided fields replace the
+/// stored values; omitted fields are left unchanged.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkUpdateRequest {
+    #[schema(nullable = false)]
+    pub rpc_urls: Option<Vec<String>>,
+    #[schema(nullable = false)]
+    pub explorer_urls: Option<Vec<String>>,
+    #[schema(nullable = false)]
+    pub average_blocktime_ms: Option<u64>,
+    #[schema(nullable = false)]
+    pub is_testnet: Option<bool>,
+    #[schema(nullable = false)]
+    pub tags: Option<Vec<String>>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub chain_id: Option<u64>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub required_confirmations: Option<u64>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub features: Option<Vec<String>>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub symbol: Option<String>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub gas_price_cache: Option<GasPriceCacheConfig>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub private_relay: Option<PrivateRelayConfig>,
+    /// EVM only
+    #[schema(nullable = false)]
+    pub gas_oracle: Option<GasOracleConfig>,
+    /// Stellar only
+    #[schema(nullable = false)]
+    pub passphrase: Option<String>,
+}
+
+/// Rejects fields that do not apply to the given network type.
+fn check_supported_fields(
+    network_type: RelayerNetworkType,
+    evm_fields: &[(&str, bool)],
+    has_passphrase: bool,
+) -> Result<(), ApiError> {
+    let mut unsupported: Vec<&str> = Vec::new();
+    if network_type != RelayerNetworkType::Evm {
+        unsupported.extend(
+            evm_fields
+                .iter()
+                .filter(|(_, is_set)| *is_set)
+                .map(|(name, _)| *name),
+        );
+    }
+    if network_type != RelayerNetworkType::Stellar && has_passphrase {
+        unsupported.push("passphrase");
+    }
+
+    if unsupported.is_empty() {
+        Ok(())
+    } else {
+        Err(ApiError::BadRequest(format!(
+            "Fields not supported for {} networks: {}",
+            network_type,
+            unsupported.join(", ")
+        )))
+    }
+}
+
+impl NetworkCreateRequest {
+    fn evm_fields(&self) -> [(&'static str, bool); 7] {
+        [
+            ("chain_id", self.chain_id.is_some()),
+            (
+                "required_confirmations",
+                self.required_confirmations.is_some(),
+            ),
+            ("features", self.features.is_some()),
+            ("symbol", self.symbol.is_some()),
+            ("gas_price_cache", self.gas_price_cache.is_some()),
+            ("private_relay", self.private_relay.is_some()),
+            ("gas_oracle", self.gas_oracle.is_some()),
+        ]
+    }
+}
+
+impl NetworkUpdateRequest {
+    fn evm_fields(&self) -> [(&'static str, bool); 7] {
+        [
+            ("chain_id", self.chain_id.is_some()),
+            (
+                "required_confirmations",
+                self.required_confirmations.is_some(),
+            ),
+            ("features", self.features.is_some()),
+            ("symbol", self.symbol.is_some()),
+            ("gas_price_cache", self.gas_price_cache.is_some()),
+            ("private_relay", self.private_relay.is_some()),
+            ("gas_oracle", self.gas_oracle.is_some()),
+        ]
+    }
+}
+
+/// Builds the (unresolved) network configuration described by a create request.
+///
+/// Inheritance is not resolved and the result is not validated here, since the parent
+/// network has to be looked up in the repository first.
+impl TryFrom<NetworkCreateRequest> for NetworkFileConfig {
+    type Error = ApiError;
+
+    fn try_from(request: NetworkCreateRequest) -> Result<Self, Self::Error> {
+        check_supported_fields(
+            request.r#type,
+            &request.evm_fields(),
+            request.passphrase.is_some(),
+        )?;
+
+        let common = NetworkConfigCommon {
+            network: request.network,
+            from: request.from,
+            rpc_urls: request.rpc_urls,
+            explorer_urls: request.explorer_urls,
+            average_blocktime_ms: request.average_blocktime_ms,
+            is_testnet: request.is_testnet,
+            tags: request.tags,
+        };
+
+        Ok(match request.r#type {
+            RelayerNetworkType::Evm => NetworkFileConfig::Evm(EvmNetworkConfig {
+                common,
+                chain_id: request.chain_id,
+                required_confirmations: request.required_confirmations,
+                features: request.features,
+                symbol: request.symbol,
+                gas_price_cache: request.gas_price_cache,
+                private_relay: request.private_relay,
+                gas_oracle: request.gas_oracle,
+            }),
+            RelayerNetworkType::Solana => NetworkFileConfig::Solana(SolanaNetworkConfig { common }),
+            RelayerNetworkType::Stellar => NetworkFileConfig::Stellar(StellarNetworkConfig {
+                common,
+                passphrase: request.passphrase,
+            }),
+        })
+    }
+}
+
+impl NetworkRepoModel {
+    /// Applies an update request to the network and validates the result.
+    ///
+    /// # Arguments
+    /// * `request` - The update request with the fields to replace
+    ///
+    /// # Returns
+    /// The updated network, or `ApiError::BadRequest` if a field does not apply to the
+    /// network type or the updated configuration is invalid.
+    pub fn apply_update(&self, request: &NetworkUpdateRequest) -> Result<Self, ApiError> {
+        check_supported_fields(
+            self.network_type,
+            &request.evm_fields(),
+            request.passphrase.is_some(),
+        )?;
+
+        let mut config = self.config.clone();
+        let common = match &mut config {
+            NetworkConfigData::Evm(evm) => {
+                if let Some(chain_id) = request.chain_id {
+                    evm.chain_id = Some(chain_id);
+                }
+                if let Some(required_confirmations) = request.required_confirmations {
+                    evm.required_confirmations = Some(required_confirmations);
+                }
+                if let Some(features) = &request.features {
+                    evm.features = Some(features.clone());
+                }
+                if let Some(symbol) = &request.symbol {
+                    evm.symbol = Some(symbol.clone());
+                }
+                if let Some(gas_price_cache) = &request.gas_price_cache {
+                    evm.gas_price_cache = Some(gas_price_cache.clone());
+                }
+                if let Some(private_relay) = &request.private_relay {
+                    evm.private_relay = Some(private_relay.clone());
+                }
+                if let Some(gas_oracle) = &request.gas_oracle {
+                    evm.gas_oracle = Some(gas_oracle.clone());
+                }
+                &mut evm.common
+            }
+            NetworkConfigData::Solana(solana) => &mut solana.common,
+            NetworkConfigData::Stellar(stellar) => {
+                if let Some(passphrase) = &request.passphrase {
+                    stellar.passphrase = Some(passphrase.clone());
+                }
+                &mut stellar.common
+            }
+        };
+
+        if let Some(rpc_urls) = &request.rpc_urls {
+            common.rpc_urls = Some(rpc_urls.clone());
+        }
+        if let Some(explorer_urls) = &request.explorer_urls {
+            common.explorer_urls = Some(explorer_urls.clone());
+        }
+        if let Some(average_blocktime_ms) = request.average_blocktime_ms {
+            common.average_blocktime_ms = Some(average_blocktime_ms);
+        }
+        if let Some(is_testnet) = request.is_testnet {
+            common.is_testnet = Some(is_testnet);
+        }
+        if let Some(tags) = &request.tags {
+            common.tags = Some(tags.clone());
+        }
+
+        NetworkFileConfig::from(config.clone())
+            .validate()
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+        Ok(Self {
+            config,
+            ..self.clone()
+        })
+    }
+
+    /// Narrows an update of the network's `from` parent to the fields this network inherited.
+    ///
+    /// A field counts as inherited when it still holds the parent's value from before the
+    /// update, so values the network overrides are left alone.
+    ///
+    /// # Arguments
+    /// * `parent` - The parent network before the update
+    /// * `request` - The update request applied to the parent
+    ///
+    /// # Returns
+    /// The update request to apply to this network.
+    pub fn inherited_update(
+        &self,
+        parent: &NetworkRepoModel,
+        request: &NetworkUpdateRequest,
+    ) -> NetworkUpdateRequest {
+        fn inherited<T: Clone + PartialEq>(
+            update: &Option<T>,
+            own: &Option<T>,
+            parent: &Option<T>,
+        ) -> Option<T> {
+            update.clone().filter(|_| own == parent)
+        }
+
+        let own = NetworkResponse::from(self.clone());
+        let parent = NetworkResponse::from(parent.clone());
+        NetworkUpdateRequest {
+            rpc_urls: inherited(&request.rpc_urls, &own.rpc_urls, &parent.rpc_urls),
+            explorer_urls: inherited(
+                &request.explorer_urls,
+                &own.explorer_urls,
+                &parent.explorer_urls,
+            ),
+            average_blocktime_ms: inherited(
+                &request.average_blocktime_ms,
+                &own.average_blocktime_ms,
+                &parent.average_blocktime_ms,
+            ),
+            is_testnet: inherited(&request.is_testnet, &own.is_testnet, &parent.is_testnet),
+            tags: inherited(&request.tags, &own.tags, &parent.tags),
+            chain_id: inherited(&request.chain_id, &own.chain_id, &parent.chain_id),
+            required_confirmations: inherited(
+                &request.required_confirmations,
+                &own.required_confirmations,
+                &parent.required_confirmations,
+            ),
+            features: inherited(&request.features, &own.features, &parent.features),
+            symbol: inherited(&request.symbol, &own.symbol, &parent.symbol),
+            gas_price_cache: inherited(
+                &request.gas_price_cache,
+                &own.gas_price_cache,
+                &parent.gas_price_cache,
+            ),
+            private_relay: inherited(
+                &request.private_relay,
+                &own.private_relay,
+                &parent.private_relay,
+            ),
+            gas_oracle: inherited(&request.gas_oracle, &own.gas_oracle, &parent.gas_oracle),
+            passphrase: inherited(&request.passphrase, &own.passphrase, &parent.passphrase),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mocks::mockutils::create_mock_network;
+
+    fn create_request(network_type: RelayerNetworkType) -> NetworkCreateRequest {
+        NetworkCreateRequest {
+            r#type: network_type,
+            network: "custom".to_string(),
+            from: None,
+            rpc_urls: Some(vec!["https://rpc.example.com".to_string()]),
+            explorer_urls: None,
+            average_blocktime_ms: None,
+            is_testnet: Some(true),
+            tags: None,
+            chain_id: None,
+            required_confirmations: None,
+            features: None,
+            symbol: None,
+            gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
+            passphrase: None,
+        }
+    }
+
+    #[test]
+    fn test_create_request_to_evm_config() {
+        let mut request = create_request(RelayerNetworkType::Evm);
+        request.chain_id = Some(1337);
+        request.symbol = Some("ETH".to_string());
+
+        let config = NetworkFileConfig::try_from(request).unwrap();
+        match config {
+            NetworkFileConfig::Evm(evm) => {
+                assert_eq!(evm.common.network, "custom");
+                assert_eq!(evm.chain_id, Some(1337));
+                assert_eq!(evm.symbol, Some("ETH".to_string()));
+            }
+            _ => panic!("Expected EVM network config"),
+        }
+    }
+
+    #[test]
+    fn test_create_request_to_stellar_config() {
+        let mut request = create_request(RelayerNetworkType::Stellar);
+        request.passphrase = Some("Test SDF Network ; September 2015".to_string());
+
+        let config = NetworkFileConfig::try_from(request).unwrap();
+        match config {
+            NetworkFileConfig::Stellar(stellar) => {
+                assert_eq!(
+                    stellar.passphrase,
+                    Some("Test SDF Network ; September 2015".to_string())
+                );
+            }
+            _ => panic!("Expected Stellar network config"),
+        }
+    }
+
+    #[test]
+    fn test_create_request_rejects_fields_for_other_network_types() {
+        let mut request = create_request(RelayerNetworkType::Solana);
+        request.chain_id = Some(1);
+        request.passphrase = Some("passphrase".to_string());
+
+        let result = NetworkFileConfig::try_from(request);
+        match result {
+            Err(ApiError::BadRequest(msg)) => {
+                assert!(msg.contains("solana"));
+                assert!(msg.contains("chain_id"));
+                assert!(msg.contains("passphrase"));
+            }
+            _ => panic!("Expected BadRequest error"),
+        }
+    }
+
+    #[test]
+    fn test_create_request_rejects_unknown_fields() {
+        let result = serde_json::from_str::<NetworkCreateRequest>(
+            r#"{"type":"evm","network":"custom","unknown":true}"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_apply_update() {
+        let network = create_mock_network();
+        let request = NetworkUpdateRequest {
+            rpc_urls: Some(vec!["https://new-rpc.example.com".to_string()]),
+            required_confirmations: Some(5),
+            ..Default::default()
+        };
+
+        let updated = network.apply_update(&request).unwrap();
+
+        assert_eq!(updated.id, network.id);
+        assert_eq!(
+            updated.common().rpc_urls,
+            Some(vec!["https://new-rpc.example.com".to_string()])
+        );
+        match updated.config {
+            NetworkConfigData::Evm(evm) => {
+                assert_eq!(evm.required_confirmations, Some(5));
+                assert_eq!(evm.chain_id, Some(1));
+            }
+            _ => panic!("Expected EVM network config"),
+        }
+    }
+
+    #[test]
+    fn test_apply_update_rejects_invalid_config() {
+        let network = create_mock_network();
+        let request = NetworkUpdateRequest {
+            rpc_urls: Some(vec!["not a url".to_string()]),
+            ..Default::default()
+        };
+
+        let result = network.apply_update(&request);
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_apply_update_rejects_unsupported_fields() {
+        let network = create_mock_network();
+        let request = NetworkUpdateRequest {
+            passphrase: Some("passphrase".to_string()),
+            ..Default::default()
+        };
+
+        let result = network.apply_update(&request);
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+}
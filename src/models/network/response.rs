@@ -0,0 +1,133 @@
+//! API response models for network endpoints.
+//!
+//! Networks are returned in the same flat shape used by the network configuration files,
+//! with inheritance already resolved.
+
+use crate::{
+    config::{GasOracleConfig, GasPriceCacheConfig, PrivateRelayConfig},
+    models::{NetworkConfigData, NetworkRepoModel, RelayerNetworkType},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Response structure for network API endpoints
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct NetworkResponse {
+    pub id: String,
+    pub r#type: RelayerNetworkType,
+    pub network: String,
+    /// Network this configuration was inherited from, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub rpc_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub explorer_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub average_blocktime_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub is_testnet: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub chain_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub required_confirmations: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub features: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub gas_price_cache: Option<GasPriceCacheConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub private_relay: Option<PrivateRelayConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub gas_oracle: Option<GasOracleConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub passphrase: Option<String>,
+}
+
+impl From<NetworkRepoModel> for NetworkResponse {
+    fn from(model: NetworkRepoModel) -> Self {
+        let common = model.common().clone();
+        let mut response = Self {
+            id: model.id,
+            r#type: model.network_type,
+            network: model.name,
+            from: common.from,
+            rpc_urls: common.rpc_urls,
+            explorer_urls: common.explorer_urls,
+            average_blocktime_ms: common.average_blocktime_ms,
+            is_testnet: common.is_testnet,
+            tags: common.tags,
+            chain_id: None,
+            required_confirmations: None,
+            features: None,
+            symbol: None,
+            gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
+            passphrase: None,
+        };
+
+        match model.config {
+            NetworkConfigData::Evm(evm) => {
+                response.chain_id = evm.chain_id;
+                response.required_confirmations = evm.required_confirmations;
+                response.features = evm.features;
+                response.symbol = evm.symbol;
+                response.gas_price_cache = evm.gas_price_cache;
+                response.private_relay = evm.private_relay;
+                response.gas_oracle = evm.gas_oracle;
+            }
+            NetworkConfigData::Solana(_) => {}
+            NetworkConfigData::Stellar(stellar) => {
+                response.passphrase = stellar.passphrase;
+            }
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::mocks::mockutils::{create_mock_network, create_mock_solana_network};
+
+    #[test]
+    fn test_from_evm_network_repo_model() {
+        let response = NetworkResponse::from(create_mock_network());
+
+        assert_eq!(response.id, "test");
+        assert_eq!(response.r#type, RelayerNetworkType::Evm);
+        assert_eq!(response.network, "test");
+        assert_eq!(response.chain_id, Some(1));
+        assert_eq!(response.symbol, Some("testETH".to_string()));
+        assert_eq!(response.passphrase, None);
+    }
+
+    #[test]
+    fn test_from_solana_network_repo_model_omits_evm_fields() {
+        let response = NetworkResponse::from(create_mock_solana_network());
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(json["type"], "solana");
+        assert!(json.get("chain_id").is_none());
+        assert!(json.get("symbol").is_none());
+    }
+}
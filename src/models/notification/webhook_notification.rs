@@ -35,6 +35,20 @@ pub struct TransactionFailurePayload {
     pub failure_reason: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TransactionReorgedPayload {
+    pub transaction: TransactionResponse,
+    pub previous_block_number: Option<u64>,
+    pub previous_block_hash: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SpendingLimitExceededPayload {
+    pub transaction: TransactionResponse,
+    pub relayer_id: String,
+    pub reason: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct RelayerDisabledPayload {
     pub relayer: RelayerResponse,
@@ -52,6 +66,10 @@ pub enum WebhookPayload {
     Transaction(TransactionResponse),
     #[serde(rename = "transaction_failure")]
     TransactionFailure(TransactionFailurePayload),
+    #[serde(rename = "transaction_reorged")]
+    TransactionReorged(TransactionReorgedPayload),
+    #[serde(rename = "spending_limit_exceeded")]
+    SpendingLimitExceeded(SpendingLimitExceededPayload),
     #[serde(rename = "relayer_disabled")]
     RelayerDisabled(Box<RelayerDisabledPayload>),
     #[serde(rename = "solana_rpc")]
@@ -80,6 +98,47 @@ pub fn produce_transaction_update_notification_payload(
     )
 }
 
+/// Produces a notification payload for a transaction whose block was reorged out of the chain
+pub fn produce_transaction_reorged_payload(
+    notification_id: &str,
+    transaction: &TransactionRepoModel,
+    previous_block_number: Option<u64>,
+    previous_block_hash: Option<String>,
+) -> NotificationSend {
+    let payload = TransactionReorgedPayload {
+        transaction: transaction.clone().into(),
+        previous_block_number,
+        previous_block_hash,
+    };
+    NotificationSend::new(
+        notification_id.to_string(),
+        WebhookNotification::new(
+            "transaction_reorged".to_string(),
+            WebhookPayload::TransactionReorged(payload),
+        ),
+    )
+}
+
+/// Produces a notification payload for a transaction rejected by the relayer's spending limits
+pub fn produce_spending_limit_exceeded_payload(
+    notification_id: &str,
+    transaction: &TransactionRepoModel,
+    reason: &str,
+) -> NotificationSend {
+    let payload = SpendingLimitExceededPayload {
+        transaction: transaction.clone().into(),
+        relayer_id: transaction.relayer_id.clone(),
+        reason: reason.to_string(),
+    };
+    NotificationSend::new(
+        notification_id.to_string(),
+        WebhookNotification::new(
+            "spending_limit_exceeded".to_string(),
+            WebhookPayload::SpendingLimitExceeded(payload),
+        ),
+    )
+}
+
 pub fn produce_relayer_disabled_payload(
     notification_id: &str,
     relayer: &RelayerRepoModel,
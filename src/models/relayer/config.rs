@@ -10,10 +10,13 @@
 //! Used primarily during application startup to parse relayer settings from config files.
 //! Validation is handled by the domain model in mod.rs to ensure reusability.
 
-use super::{Relayer, RelayerNetworkPolicy, RelayerValidationError, RpcConfig};
+use super::{
+    EvmSelectorRule, Relayer, RelayerNetworkPolicy, RelayerValidationError, RpcConfig,
+    SpendingWindow,
+};
 use crate::config::{ConfigFileError, ConfigFileNetworkType, NetworksFileConfig};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -32,6 +35,16 @@ pub struct ConfigFileRelayerEvmPolicy {
     pub private_transactions: Option<bool>,
     pub min_balance: Option<u128>,
     pub gas_limit_estimation: Option<bool>,
+    pub simulate_transactions: Option<bool>,
+    pub access_list_generation: Option<bool>,
+    pub entry_point: Option<String>,
+    pub max_value_per_transaction: Option<u128>,
+    pub max_total_value: Option<u128>,
+    pub max_gas_spend: Option<u128>,
+    pub spending_window: Option<SpendingWindow>,
+    pub allowed_selectors: Option<HashMap<String, Vec<EvmSelectorRule>>>,
+    pub trusted_forwarder: Option<String>,
+    pub authorization_signers: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
@@ -341,6 +354,16 @@ fn convert_config_policies_to_domain(
                 whitelist_receivers: evm_policy.whitelist_receivers,
                 eip1559_pricing: evm_policy.eip1559_pricing,
                 private_transactions: evm_policy.private_transactions,
+                simulate_transactions: evm_policy.simulate_transactions,
+                access_list_generation: evm_policy.access_list_generation,
+                entry_point: evm_policy.entry_point,
+                max_value_per_transaction: evm_policy.max_value_per_transaction,
+                max_total_value: evm_policy.max_total_value,
+                max_gas_spend: evm_policy.max_gas_spend,
+                spending_window: evm_policy.spending_window,
+                allowed_selectors: evm_policy.allowed_selectors,
+                trusted_forwarder: evm_policy.trusted_forwarder,
+                authorization_signers: evm_policy.authorization_signers,
             }))
         }
         ConfigFileRelayerNetworkPolicy::Solana(solana_policy) => {
@@ -779,6 +802,16 @@ mod tests {
             whitelist_receivers: Some(vec!["0x123".to_string(), "0x456".to_string()]),
             eip1559_pricing: Some(true),
             private_transactions: Some(false),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
             min_balance: Some(2000000000000000000),
             gas_limit_estimation: Some(true),
         });
@@ -903,6 +936,16 @@ mod tests {
                     whitelist_receivers: None,
                     eip1559_pricing: Some(true),
                     private_transactions: None,
+                    simulate_transactions: None,
+                    access_list_generation: None,
+                    entry_point: None,
+                    max_value_per_transaction: None,
+                    max_total_value: None,
+                    max_gas_spend: None,
+                    spending_window: None,
+                    allowed_selectors: None,
+                    trusted_forwarder: None,
+                    authorization_signers: None,
                     min_balance: None,
                     gas_limit_estimation: None,
                 },
@@ -1193,6 +1236,16 @@ mod tests {
             whitelist_receivers: Some(vec!["0xabc".to_string()]),
             eip1559_pricing: Some(false),
             private_transactions: Some(true),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
             min_balance: Some(500000000000000000),
             gas_limit_estimation: Some(true),
         };
@@ -26,6 +26,9 @@ pub use repository::*;
 mod rpc_config;
 pub use rpc_config::*;
 
+mod signer_rotation;
+pub use signer_rotation::*;
+
 use crate::{
     config::ConfigFileNetworkType,
     constants::ID_REGEX,
@@ -34,6 +37,7 @@ use crate::{
 use apalis_cron::Schedule;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::str::FromStr;
 use utoipa::ToSchema;
 use validator::Validate;
@@ -103,6 +107,156 @@ pub struct RelayerEvmPolicy {
     pub eip1559_pricing: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub private_transactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub simulate_transactions: Option<bool>,
+    /// Attach an `eth_createAccessList` access list when it lowers the gas estimate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_list_generation: Option<bool>,
+    /// ERC-4337 EntryPoint address; setting it enables the bundler RPC methods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entry_point: Option<String>,
+    /// Maximum value (in wei) a single transaction may transfer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    pub max_value_per_transaction: Option<u128>,
+    /// Maximum value (in wei) transferred across all transactions in the spending window
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    pub max_total_value: Option<u128>,
+    /// Maximum amount (in wei) spent on fees across all transactions in the spending window
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    pub max_gas_spend: Option<u128>,
+    /// Rolling window `max_total_value` and `max_gas_spend` apply to (defaults to a day)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spending_window: Option<SpendingWindow>,
+    /// Function selectors each contract may be called with; calls to other contracts are rejected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_selectors: Option<HashMap<String, Vec<EvmSelectorRule>>>,
+    /// ERC-2771 trusted forwarder; setting it enables relaying signed forward requests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trusted_forwarder: Option<String>,
+    /// Signer IDs that may sign EIP-7702 authorizations in this relayer's transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_signers: Option<Vec<String>>,
+}
+
+/// A function a relayer may call on an allowed contract
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EvmSelectorRule {
+    /// 4-byte function selector, e.g. `0xa9059cbb` for `transfer(address,uint256)`
+    pub selector: String,
+    /// Constraints on the static ABI arguments of the call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub arguments: Option<Vec<EvmArgumentConstraint>>,
+}
+
+/// Constraint on a static ABI-encoded argument of an allowed call
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EvmArgumentConstraint {
+    /// Zero-based position of the argument
+    pub index: usize,
+    /// Maximum value of an unsigned integer argument
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default
+    )]
+    #[schema(nullable = false)]
+    pub max_value: Option<u128>,
+    /// Required value: an address, or an integer in decimal or `0x` hex
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub equals: Option<String>,
+}
+
+/// Parses an address or an integer into the 32-byte word it is ABI-encoded as
+pub fn parse_abi_word(value: &str) -> Option<alloy::primitives::U256> {
+    if let Ok(address) = alloy::primitives::Address::from_str(value) {
+        return Some(alloy::primitives::U256::from_be_slice(address.as_slice()));
+    }
+    alloy::primitives::U256::from_str(value).ok()
+}
+
+/// Rolling window over which relayer spending limits are accounted
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, ToSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SpendingWindow {
+    Hour,
+    #[default]
+    Day,
+}
+
+impl SpendingWindow {
+    /// Length of the window in seconds
+    pub fn duration_seconds(&self) -> i64 {
+        match self {
+            SpendingWindow::Hour => 60 * 60,
+            SpendingWindow::Day => 24 * 60 * 60,
+        }
+    }
+}
+
+/// Value transferred and fees paid by a relayer's transactions, in wei
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RelayerSpend {
+    pub value: u128,
+    pub gas: u128,
+}
+
+impl RelayerSpend {
+    pub fn saturating_add(self, other: RelayerSpend) -> RelayerSpend {
+        RelayerSpend {
+            value: self.value.saturating_add(other.value),
+            gas: self.gas.saturating_add(other.gas),
+        }
+    }
+}
+
+/// Upper bounds on the spend of a relayer over a rolling window, in wei
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RelayerSpendLimit {
+    pub max_value: Option<u128>,
+    pub max_gas: Option<u128>,
+}
+
+impl RelayerSpendLimit {
+    /// Returns true if `spend` goes over either bound
+    pub fn is_exceeded_by(&self, spend: &RelayerSpend) -> bool {
+        self.max_value.is_some_and(|max| spend.value > max)
+            || self.max_gas.is_some_and(|max| spend.gas > max)
+    }
+}
+
+impl RelayerEvmPolicy {
+    /// Returns the rolling window budgets of the policy
+    pub fn spend_limit(&self) -> RelayerSpendLimit {
+        RelayerSpendLimit {
+            max_value: self.max_total_value,
+            max_gas: self.max_gas_spend,
+        }
+    }
+
+    /// Returns true if the policy bounds spending over a rolling window
+    pub fn has_spending_window_limits(&self) -> bool {
+        self.max_total_value.is_some() || self.max_gas_spend.is_some()
+    }
 }
 
 /// Solana token swap configuration
@@ -427,8 +581,8 @@ impl Relayer {
             (RelayerNetworkType::Solana, Some(RelayerNetworkPolicy::Solana(policy))) => {
                 self.validate_solana_policy(policy)?;
             }
-            (RelayerNetworkType::Evm, Some(RelayerNetworkPolicy::Evm(_))) => {
-                // EVM policies don't need special validation currently
+            (RelayerNetworkType::Evm, Some(RelayerNetworkPolicy::Evm(policy))) => {
+                self.validate_evm_policy(policy)?;
             }
             (RelayerNetworkType::Stellar, Some(RelayerNetworkPolicy::Stellar(_))) => {
                 // Stellar policies don't need special validation currently
@@ -452,6 +606,77 @@ impl Relayer {
         Ok(())
     }
 
+    /// Validates EVM-specific policies
+    fn validate_evm_policy(&self, policy: &RelayerEvmPolicy) -> Result<(), RelayerValidationError> {
+        if let Some(entry_point) = &policy.entry_point {
+            if alloy::primitives::Address::from_str(entry_point).is_err() {
+                return Err(RelayerValidationError::InvalidPolicy(format!(
+                    "Invalid entry point address: {}",
+                    entry_point
+                )));
+            }
+        }
+        if let Some(allowed_selectors) = &policy.allowed_selectors {
+            self.validate_evm_allowed_selectors(allowed_selectors)?;
+        }
+        if let Some(trusted_forwarder) = &policy.trusted_forwarder {
+            if alloy::primitives::Address::from_str(trusted_forwarder).is_err() {
+                return Err(RelayerValidationError::InvalidPolicy(format!(
+                    "Invalid trusted forwarder address: {}",
+                    trusted_forwarder
+                )));
+            }
+        }
+        if let Some(authorization_signers) = &policy.authorization_signers {
+            if authorization_signers.contains(&self.signer_id) {
+                return Err(RelayerValidationError::InvalidPolicy(
+                    "Authorization signers cannot include the relayer's own signer".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates the contract addresses, selectors and argument constraints of a selector allowlist
+    fn validate_evm_allowed_selectors(
+        &self,
+        allowed_selectors: &HashMap<String, Vec<EvmSelectorRule>>,
+    ) -> Result<(), RelayerValidationError> {
+        for (address, rules) in allowed_selectors {
+            if alloy::primitives::Address::from_str(address).is_err() {
+                return Err(RelayerValidationError::InvalidPolicy(format!(
+                    "Invalid allowed selectors contract address: {}",
+                    address
+                )));
+            }
+            for rule in rules {
+                if alloy::primitives::FixedBytes::<4>::from_str(&rule.selector).is_err() {
+                    return Err(RelayerValidationError::InvalidPolicy(format!(
+                        "Invalid function selector for {}: {}",
+                        address, rule.selector
+                    )));
+                }
+                for constraint in rule.arguments.iter().flatten() {
+                    if constraint.max_value.is_none() && constraint.equals.is_none() {
+                        return Err(RelayerValidationError::InvalidPolicy(format!(
+                            "Argument {} of selector {} has no constraint",
+                            constraint.index, rule.selector
+                        )));
+                    }
+                    if let Some(equals) = &constraint.equals {
+                        if parse_abi_word(equals).is_none() {
+                            return Err(RelayerValidationError::InvalidPolicy(format!(
+                                "Invalid value for argument {} of selector {}: {}",
+                                constraint.index, rule.selector, equals
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Validates Solana-specific policies
     fn validate_solana_policy(
         &self,
@@ -806,6 +1031,16 @@ mod tests {
             whitelist_receivers: Some(vec!["0x123".to_string(), "0x456".to_string()]),
             eip1559_pricing: Some(false),
             private_transactions: Some(true),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
         };
 
         let serialized = serde_json::to_string(&policy).unwrap();
@@ -1270,6 +1505,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_relayer_validation_evm_invalid_entry_point() {
+        let relayer = Relayer::new(
+            "valid-id".to_string(),
+            "Valid Relayer".to_string(),
+            "mainnet".to_string(),
+            false,
+            RelayerNetworkType::Evm,
+            Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                entry_point: Some("not-an-address".to_string()),
+                ..Default::default()
+            })),
+            "valid-signer".to_string(),
+            None,
+            None,
+        );
+
+        let result = relayer.validate();
+        if let Err(RelayerValidationError::InvalidPolicy(msg)) = result {
+            assert!(msg.contains("Invalid entry point address"));
+        } else {
+            panic!("Expected InvalidPolicy error for invalid entry point");
+        }
+    }
+
+    #[test]
+    fn test_relayer_validation_evm_authorization_signers_exclude_own_signer() {
+        let relayer = Relayer::new(
+            "valid-id".to_string(),
+            "Valid Relayer".to_string(),
+            "mainnet".to_string(),
+            false,
+            RelayerNetworkType::Evm,
+            Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                authorization_signers: Some(vec![
+                    "owner-signer".to_string(),
+                    "valid-signer".to_string(),
+                ]),
+                ..Default::default()
+            })),
+            "valid-signer".to_string(),
+            None,
+            None,
+        );
+
+        let result = relayer.validate();
+        if let Err(RelayerValidationError::InvalidPolicy(msg)) = result {
+            assert!(msg.contains("cannot include the relayer's own signer"));
+        } else {
+            panic!("Expected InvalidPolicy error for the relayer's own signer");
+        }
+    }
+
+    #[test]
+    fn test_relayer_validation_evm_allowed_selectors() {
+        let relayer_with = |selector: &str, constraint: EvmArgumentConstraint| {
+            Relayer::new(
+                "valid-id".to_string(),
+                "Valid Relayer".to_string(),
+                "mainnet".to_string(),
+                false,
+                RelayerNetworkType::Evm,
+                Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                    allowed_selectors: Some(HashMap::from([(
+                        "0x7f8d6a2ba539031e3c18c9528b0dc3a7f603a93b".to_string(),
+                        vec![EvmSelectorRule {
+                            selector: selector.to_string(),
+                            arguments: Some(vec![constraint]),
+                        }],
+                    )])),
+                    ..Default::default()
+                })),
+                "valid-signer".to_string(),
+                None,
+                None,
+            )
+        };
+        let max_constraint = EvmArgumentConstraint {
+            index: 1,
+            max_value: Some(1_000),
+            equals: None,
+        };
+
+        assert!(relayer_with("0xa9059cbb", max_constraint.clone())
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            relayer_with("0xa9059c", max_constraint).validate(),
+            Err(RelayerValidationError::InvalidPolicy(msg)) if msg.contains("Invalid function selector")
+        ));
+        assert!(matches!(
+            relayer_with(
+                "0xa9059cbb",
+                EvmArgumentConstraint {
+                    index: 0,
+                    max_value: None,
+                    equals: None,
+                }
+            )
+            .validate(),
+            Err(RelayerValidationError::InvalidPolicy(msg)) if msg.contains("has no constraint")
+        ));
+        assert!(matches!(
+            relayer_with(
+                "0xa9059cbb",
+                EvmArgumentConstraint {
+                    index: 0,
+                    max_value: None,
+                    equals: Some("not-a-value".to_string()),
+                }
+            )
+            .validate(),
+            Err(RelayerValidationError::InvalidPolicy(_))
+        ));
+    }
+
     #[test]
     fn test_relayer_validation_invalid_rpc_url() {
         let relayer = Relayer::new(
@@ -1857,6 +2208,16 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(false),
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
             })),
             signer_id: "test-signer".to_string(),
             notification_id: Some("old-notification".to_string()),
@@ -1903,6 +2264,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_apply_json_patch_allowed_selectors() {
+        let token = "0x7f8d6a2ba539031e3c18c9528b0dc3a7f603a93b";
+        let router = "0x742d35cc6634c0532925a3b844bc454e4438f44e";
+        let relayer = Relayer {
+            id: "test-relayer".to_string(),
+            name: "Relayer".to_string(),
+            network: "mainnet".to_string(),
+            paused: false,
+            network_type: RelayerNetworkType::Evm,
+            policies: Some(RelayerNetworkPolicy::Evm(RelayerEvmPolicy {
+                allowed_selectors: Some(HashMap::from([(
+                    token.to_string(),
+                    vec![EvmSelectorRule {
+                        selector: "0xa9059cbb".to_string(),
+                        arguments: None,
+                    }],
+                )])),
+                ..Default::default()
+            })),
+            signer_id: "test-signer".to_string(),
+            notification_id: None,
+            custom_rpc_urls: None,
+        };
+
+        // Add a contract and remove another through a merge patch
+        let patch = json!({
+            "policies": {
+                "allowed_selectors": {
+                    token: null,
+                    router: [{
+                        "selector": "0x38ed1739",
+                        "arguments": [{"index": 0, "max_value": "1000"}]
+                    }]
+                }
+            }
+        });
+        let updated_relayer = relayer.apply_json_patch(&patch).unwrap();
+
+        if let Some(RelayerNetworkPolicy::Evm(evm_policy)) = updated_relayer.policies {
+            let allowed_selectors = evm_policy.allowed_selectors.unwrap();
+            assert!(!allowed_selectors.contains_key(token));
+            assert_eq!(
+                allowed_selectors[router],
+                vec![EvmSelectorRule {
+                    selector: "0x38ed1739".to_string(),
+                    arguments: Some(vec![EvmArgumentConstraint {
+                        index: 0,
+                        max_value: Some(1000),
+                        equals: None,
+                    }]),
+                }]
+            );
+        } else {
+            panic!("Expected EVM policy");
+        }
+
+        let invalid_patch = json!({
+            "policies": {"allowed_selectors": {router: [{"selector": "transfer"}]}}
+        });
+        assert!(relayer.apply_json_patch(&invalid_patch).is_err());
+    }
+
     #[test]
     fn test_apply_json_patch_validation_failure() {
         let relayer = Relayer {
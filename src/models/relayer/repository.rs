@@ -3,7 +3,7 @@ use crate::models::{
 };
 use serde::{Deserialize, Serialize};
 
-use super::{RelayerNetworkPolicy, RelayerNetworkType, RpcConfig};
+use super::{RelayerNetworkPolicy, RelayerNetworkType, RpcConfig, SignerRotation};
 
 // Use the domain model RelayerNetworkType directly
 pub type NetworkType = RelayerNetworkType;
@@ -22,14 +22,15 @@ impl RelayerRepoUpdater {
 
     /// Apply updates from a domain model while preserving runtime fields
     ///
-    /// This method ensures that runtime fields (address, system_disabled) from the
-    /// original repository model are preserved when converting from domain model,
+    /// This method ensures that runtime fields (address, system_disabled, signer_rotations)
+    /// from the original repository model are preserved when converting from domain model,
     /// preventing data loss during updates.
     pub fn apply_domain_update(self, domain: Relayer) -> RelayerRepoModel {
         let mut updated = RelayerRepoModel::from(domain);
         // Preserve runtime fields from original
         updated.address = self.original.address;
         updated.system_disabled = self.original.system_disabled;
+        updated.signer_rotations = self.original.signer_rotations;
         updated
     }
 }
@@ -47,6 +48,9 @@ pub struct RelayerRepoModel {
     pub notification_id: Option<String>,
     pub system_disabled: bool,
     pub custom_rpc_urls: Option<Vec<RpcConfig>>,
+    /// History of signer rotations, oldest first
+    #[serde(default)]
+    pub signer_rotations: Vec<SignerRotation>,
 }
 
 impl RelayerRepoModel {
@@ -61,6 +65,22 @@ impl RelayerRepoModel {
 
         Ok(())
     }
+
+    /// Returns the signer rotation that has not reached a final state yet, if any
+    pub fn active_signer_rotation(&self) -> Option<&SignerRotation> {
+        self.signer_rotations
+            .last()
+            .filter(|rotation| rotation.status.is_active())
+    }
+
+    /// Rejects new transactions while the relayer is switching signers
+    pub fn validate_no_active_signer_rotation(&self) -> Result<(), RelayerError> {
+        if self.active_signer_rotation().is_some() {
+            return Err(RelayerError::SignerRotationInProgress);
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for RelayerRepoModel {
@@ -77,6 +97,7 @@ impl Default for RelayerRepoModel {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: Vec::new(),
         }
     }
 }
@@ -124,6 +145,7 @@ impl From<Relayer> for RelayerRepoModel {
             notification_id: relayer.notification_id,
             system_disabled: false,
             custom_rpc_urls: relayer.custom_rpc_urls,
+            signer_rotations: Vec::new(),
         }
     }
 }
@@ -131,8 +153,8 @@ impl From<Relayer> for RelayerRepoModel {
 #[cfg(test)]
 mod tests {
     use crate::models::{
-        RelayerEvmPolicy, RelayerSolanaPolicy, RelayerStellarPolicy, SolanaAllowedTokensPolicy,
-        SolanaFeePaymentStrategy,
+        PendingTransactionsAction, RelayerEvmPolicy, RelayerSolanaPolicy, RelayerStellarPolicy,
+        SignerRotationStatus, SolanaAllowedTokensPolicy, SolanaFeePaymentStrategy,
     };
 
     use super::*;
@@ -150,6 +172,7 @@ mod tests {
             address: "0xtest".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -178,6 +201,7 @@ mod tests {
             address: "SolanaAddress123".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -199,6 +223,7 @@ mod tests {
             address: "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -356,6 +381,16 @@ mod tests {
                 gas_limit_estimation: None,
                 whitelist_receivers: None,
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
             })),
             signer_id: "test_signer".to_string(),
             notification_id: Some("notification_123".to_string()),
@@ -574,6 +609,7 @@ mod tests {
             policies: RelayerNetworkPolicy::Evm(RelayerEvmPolicy::default()),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         // Create a domain model with different business fields
@@ -609,6 +645,63 @@ mod tests {
         assert!(updated.system_disabled);
     }
 
+    fn create_test_signer_rotation(status: SignerRotationStatus) -> SignerRotation {
+        SignerRotation {
+            old_signer_id: "old_signer".to_string(),
+            old_address: "0xold".to_string(),
+            new_signer_id: "new_signer".to_string(),
+            new_address: "0xnew".to_string(),
+            pending_transactions: PendingTransactionsAction::Drain,
+            sweep_balance: false,
+            sweep_transaction_id: None,
+            status,
+            status_reason: None,
+            requested_at: "2025-01-01T00:00:00Z".to_string(),
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn test_relayer_repo_updater_preserves_signer_rotations() {
+        let mut original = create_test_relayer(false, false);
+        original.signer_rotations =
+            vec![create_test_signer_rotation(SignerRotationStatus::Completed)];
+
+        let updated = RelayerRepoUpdater::from_existing(original.clone())
+            .apply_domain_update(Relayer::from(original.clone()));
+
+        assert_eq!(updated.signer_rotations, original.signer_rotations);
+    }
+
+    #[test]
+    fn test_active_signer_rotation() {
+        let mut relayer = create_test_relayer(false, false);
+        assert!(relayer.active_signer_rotation().is_none());
+        assert!(relayer.validate_no_active_signer_rotation().is_ok());
+
+        relayer
+            .signer_rotations
+            .push(create_test_signer_rotation(SignerRotationStatus::Draining));
+        assert!(relayer.active_signer_rotation().is_some());
+        assert!(matches!(
+            relayer.validate_no_active_signer_rotation(),
+            Err(RelayerError::SignerRotationInProgress)
+        ));
+
+        relayer.signer_rotations[0].status = SignerRotationStatus::Completed;
+        assert!(relayer.active_signer_rotation().is_none());
+        assert!(relayer.validate_no_active_signer_rotation().is_ok());
+    }
+
+    #[test]
+    fn test_deserialize_without_signer_rotations() {
+        let mut json = serde_json::to_value(create_test_relayer(false, false)).unwrap();
+        json.as_object_mut().unwrap().remove("signer_rotations");
+
+        let relayer: RelayerRepoModel = serde_json::from_value(json).unwrap();
+        assert!(relayer.signer_rotations.is_empty());
+    }
+
     #[test]
     fn test_relayer_repo_updater_preserves_runtime_fields_solana() {
         // Create an original Solana relayer with runtime fields set
@@ -624,6 +717,7 @@ mod tests {
             policies: RelayerNetworkPolicy::Solana(RelayerSolanaPolicy::default()),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         // Create a domain model with different business fields
@@ -681,6 +775,7 @@ mod tests {
             policies: RelayerNetworkPolicy::Stellar(RelayerStellarPolicy::default()),
             notification_id: Some("original_notification".to_string()),
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         // Create a domain model with different business fields
@@ -856,6 +951,16 @@ mod tests {
                 gas_limit_estimation: None,
                 whitelist_receivers: None,
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
             })),
             signer_id: "evm_signer".to_string(),
             notification_id: Some("evm_notification".to_string()),
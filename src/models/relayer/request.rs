@@ -274,6 +274,16 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(true),
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
                 min_balance: None,
                 gas_limit_estimation: None,
             })),
@@ -12,15 +12,16 @@
 //! with the domain model for business logic.
 
 use super::{
-    Relayer, RelayerEvmPolicy, RelayerNetworkPolicy, RelayerNetworkType, RelayerRepoModel,
-    RelayerSolanaPolicy, RelayerSolanaSwapConfig, RelayerStellarPolicy, RpcConfig,
-    SolanaAllowedTokensPolicy, SolanaFeePaymentStrategy,
+    EvmSelectorRule, Relayer, RelayerEvmPolicy, RelayerNetworkPolicy, RelayerNetworkType,
+    RelayerRepoModel, RelayerSolanaPolicy, RelayerSolanaSwapConfig, RelayerStellarPolicy,
+    RpcConfig, SolanaAllowedTokensPolicy, SolanaFeePaymentStrategy, SpendingWindow,
 };
 use crate::constants::{
     DEFAULT_EVM_GAS_LIMIT_ESTIMATION, DEFAULT_EVM_MIN_BALANCE, DEFAULT_SOLANA_MAX_TX_DATA_SIZE,
     DEFAULT_SOLANA_MIN_BALANCE, DEFAULT_STELLAR_MIN_BALANCE,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use utoipa::ToSchema;
 
 /// Response for delete pending transactions operation
@@ -120,6 +121,24 @@ pub enum RelayerStatus {
     },
 }
 
+/// Nonce analysis of an EVM relayer, comparing the on-chain nonces with the nonces
+/// held by the relayer's transactions
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct NonceHealth {
+    /// Nonce of the next transaction to be mined
+    pub on_chain_nonce: u64,
+    /// Nonce following the transactions known to the node's mempool
+    pub pending_nonce: u64,
+    /// Next nonce the relayer will assign
+    pub next_nonce: u64,
+    /// Nonces held by the relayer's unconfirmed transactions
+    pub assigned_nonces: Vec<u64>,
+    /// Nonces below `next_nonce` that no transaction will use
+    pub gaps: Vec<u64>,
+    /// Whether no nonce gaps were found
+    pub healthy: bool,
+}
+
 /// Convert RelayerNetworkPolicy to RelayerNetworkPolicyResponse based on network type
 fn convert_policy_to_response(
     policy: RelayerNetworkPolicy,
@@ -300,6 +319,16 @@ fn is_empty_policy(policy: &RelayerNetworkPolicy) -> bool {
                 && evm_policy.whitelist_receivers.is_none()
                 && evm_policy.eip1559_pricing.is_none()
                 && evm_policy.private_transactions.is_none()
+                && evm_policy.simulate_transactions.is_none()
+                && evm_policy.access_list_generation.is_none()
+                && evm_policy.entry_point.is_none()
+                && evm_policy.max_value_per_transaction.is_none()
+                && evm_policy.max_total_value.is_none()
+                && evm_policy.max_gas_spend.is_none()
+                && evm_policy.spending_window.is_none()
+                && evm_policy.allowed_selectors.is_none()
+                && evm_policy.trusted_forwarder.is_none()
+                && evm_policy.authorization_signers.is_none()
         }
         RelayerNetworkPolicy::Solana(solana_policy) => {
             solana_policy.allowed_programs.is_none()
@@ -383,6 +412,51 @@ pub struct EvmPolicyResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schema(nullable = false)]
     pub private_transactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub simulate_transactions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub access_list_generation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub entry_point: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::utils::serialize_optional_u128_as_number",
+        deserialize_with = "crate::utils::deserialize_optional_u128_as_number",
+        default
+    )]
+    #[schema(nullable = false)]
+    pub max_value_per_transaction: Option<u128>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::utils::serialize_optional_u128_as_number",
+        deserialize_with = "crate::utils::deserialize_optional_u128_as_number",
+        default
+    )]
+    #[schema(nullable = false)]
+    pub max_total_value: Option<u128>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::utils::serialize_optional_u128_as_number",
+        deserialize_with = "crate::utils::deserialize_optional_u128_as_number",
+        default
+    )]
+    #[schema(nullable = false)]
+    pub max_gas_spend: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub spending_window: Option<SpendingWindow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub allowed_selectors: Option<HashMap<String, Vec<EvmSelectorRule>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub trusted_forwarder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub authorization_signers: Option<Vec<String>>,
 }
 
 /// Solana policy response model for OpenAPI documentation
@@ -453,6 +527,16 @@ impl From<RelayerEvmPolicy> for EvmPolicyResponse {
             whitelist_receivers: policy.whitelist_receivers,
             eip1559_pricing: policy.eip1559_pricing,
             private_transactions: policy.private_transactions,
+            simulate_transactions: policy.simulate_transactions,
+            access_list_generation: policy.access_list_generation,
+            entry_point: policy.entry_point,
+            max_value_per_transaction: policy.max_value_per_transaction,
+            max_total_value: policy.max_total_value,
+            max_gas_spend: policy.max_gas_spend,
+            spending_window: policy.spending_window,
+            allowed_selectors: policy.allowed_selectors,
+            trusted_forwarder: policy.trusted_forwarder,
+            authorization_signers: policy.authorization_signers,
         }
     }
 }
@@ -509,6 +593,16 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(true),
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
                 min_balance: None,
                 gas_limit_estimation: None,
             })),
@@ -532,6 +626,16 @@ mod tests {
                     whitelist_receivers: None,
                     eip1559_pricing: Some(true),
                     private_transactions: None,
+                    simulate_transactions: None,
+                    access_list_generation: None,
+                    entry_point: None,
+                    max_value_per_transaction: None,
+                    max_total_value: None,
+                    max_gas_spend: None,
+                    spending_window: None,
+                    allowed_selectors: None,
+                    trusted_forwarder: None,
+                    authorization_signers: None,
                     min_balance: Some(DEFAULT_EVM_MIN_BALANCE),
                     gas_limit_estimation: Some(DEFAULT_EVM_GAS_LIMIT_ESTIMATION),
                 }
@@ -634,6 +738,16 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(true),
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
                 min_balance: DEFAULT_EVM_MIN_BALANCE,
                 gas_limit_estimation: DEFAULT_EVM_GAS_LIMIT_ESTIMATION,
             })),
@@ -756,6 +870,16 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(true),
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
                 min_balance: DEFAULT_EVM_MIN_BALANCE,
                 gas_limit_estimation: DEFAULT_EVM_GAS_LIMIT_ESTIMATION,
             })),
@@ -876,6 +1000,7 @@ mod tests {
             signer_id: "test-signer".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
             address: "0x123...".to_string(),
             system_disabled: false,
         };
@@ -907,6 +1032,7 @@ mod tests {
             signer_id: "test-signer".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
             address: "SolanaAddress123...".to_string(),
             system_disabled: false,
         };
@@ -938,6 +1064,7 @@ mod tests {
             signer_id: "test-signer".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
             address: "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(),
             system_disabled: false,
         };
@@ -972,10 +1099,21 @@ mod tests {
                 gas_limit_estimation: None,
                 whitelist_receivers: None,
                 private_transactions: None,
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
             }),
             signer_id: "test-signer".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
             address: "0x123...".to_string(),
             system_disabled: false,
         };
@@ -1023,6 +1161,7 @@ mod tests {
             signer_id: "test-signer".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
             address: "SolanaAddress123...".to_string(),
             system_disabled: false,
         };
@@ -1067,6 +1206,7 @@ mod tests {
             signer_id: "test-signer".to_string(),
             notification_id: None,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
             address: "GXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(),
             system_disabled: false,
         };
@@ -0,0 +1,126 @@
+//! Signer rotation models.
+//!
+//! A signer rotation moves a relayer from one signer to another without recreating it.
+//! Each rotation is recorded on the relayer so the history of signers and addresses
+//! used by a relayer is preserved.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// How transactions still pending on the old address are handled during a rotation
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PendingTransactionsAction {
+    /// Wait for pending transactions to reach a final state before switching signers
+    #[default]
+    Drain,
+    /// Cancel pending transactions, then switch signers once the cancellations are final
+    Cancel,
+}
+
+/// Lifecycle status of a signer rotation
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SignerRotationStatus {
+    /// Waiting for transactions on the old address to reach a final state
+    Draining,
+    /// Waiting for the balance sweep to the new address to reach a final state
+    Sweeping,
+    /// The relayer now uses the new signer
+    Completed,
+    /// The rotation could not be completed; the relayer keeps the old signer
+    Failed,
+}
+
+impl SignerRotationStatus {
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            SignerRotationStatus::Draining | SignerRotationStatus::Sweeping
+        )
+    }
+}
+
+/// A signer rotation recorded in the relayer history
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct SignerRotation {
+    pub old_signer_id: String,
+    pub old_address: String,
+    pub new_signer_id: String,
+    pub new_address: String,
+    pub pending_transactions: PendingTransactionsAction,
+    pub sweep_balance: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub sweep_transaction_id: Option<String>,
+    pub status: SignerRotationStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub status_reason: Option<String>,
+    pub requested_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub completed_at: Option<String>,
+}
+
+/// Request to rotate the signer used by a relayer
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RotateSignerRequest {
+    /// ID of the signer the relayer should switch to
+    pub signer_id: String,
+    /// How transactions pending on the current address are handled (default: drain)
+    #[serde(default)]
+    pub pending_transactions: PendingTransactionsAction,
+    /// Transfer the native balance of the current address to the new address (EVM only)
+    #[serde(default)]
+    pub sweep_balance: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_signer_request_defaults() {
+        let request: RotateSignerRequest =
+            serde_json::from_str(r#"{"signer_id": "new-signer"}"#).unwrap();
+
+        assert_eq!(request.signer_id, "new-signer");
+        assert_eq!(
+            request.pending_transactions,
+            PendingTransactionsAction::Drain
+        );
+        assert!(!request.sweep_balance);
+    }
+
+    #[test]
+    fn test_rotate_signer_request_rejects_unknown_fields() {
+        let result = serde_json::from_str::<RotateSignerRequest>(
+            r#"{"signer_id": "new-signer", "address": "0x123"}"#,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rotate_signer_request_cancel() {
+        let request: RotateSignerRequest = serde_json::from_str(
+            r#"{"signer_id": "new-signer", "pending_transactions": "cancel", "sweep_balance": true}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            request.pending_transactions,
+            PendingTransactionsAction::Cancel
+        );
+        assert!(request.sweep_balance);
+    }
+
+    #[test]
+    fn test_signer_rotation_status_is_active() {
+        assert!(SignerRotationStatus::Draining.is_active());
+        assert!(SignerRotationStatus::Sweeping.is_active());
+        assert!(!SignerRotationStatus::Completed.is_active());
+        assert!(!SignerRotationStatus::Failed.is_active());
+    }
+}
@@ -0,0 +1,18 @@
+//! Relayer pool domain model.
+//!
+//! A relayer pool groups relayers operating on the same network behind a single
+//! transaction endpoint. Each transaction is routed to one member, so throughput is no
+//! longer limited by the nonce sequence of a single address.
+//!
+//! - **Membership**: all members share the network of the pool
+//! - **Routing**: the eligible member with the fewest pending transactions is selected
+//! - **Status**: the status of every member, aggregated at pool level
+
+mod repository;
+pub use repository::*;
+
+mod request;
+pub use request::*;
+
+mod response;
+pub use response::*;
@@ -0,0 +1,14 @@
+//! Repository layer model for relayer pools.
+
+use crate::models::NetworkType;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RelayerPoolRepoModel {
+    pub id: String,
+    pub name: String,
+    pub network: String,
+    pub network_type: NetworkType,
+    /// Member relayers, in the order used to break routing ties
+    pub relayer_ids: Vec<String>,
+}
@@ -0,0 +1,152 @@
+//! API request models and validation for relayer pool endpoints.
+
+use crate::{constants::ID_REGEX, models::ApiError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use utoipa::ToSchema;
+
+/// Request structure for creating a new relayer pool
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateRelayerPoolRequest {
+    #[schema(nullable = false)]
+    pub id: Option<String>,
+    pub name: String,
+    /// Member relayers; all of them must operate on the same network
+    pub relayer_ids: Vec<String>,
+}
+
+impl CreateRelayerPoolRequest {
+    /// Validates the request fields that do not depend on stored state.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if let Some(id) = &self.id {
+            if id.is_empty() || id.len() > 36 || !ID_REGEX.is_match(id) {
+                return Err(ApiError::BadRequest(
+                    "Pool ID must be 1 to 36 characters long and contain only letters, numbers, dashes and underscores".to_string(),
+                ));
+            }
+        }
+
+        validate_pool_name(&self.name)?;
+        validate_pool_relayer_ids(&self.relayer_ids)
+    }
+}
+
+/// Request structure for updating a relayer pool
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateRelayerPoolRequest {
+    #[schema(nullable = false)]
+    pub name: Option<String>,
+    /// Replaces the member relayers of the pool
+    #[schema(nullable = false)]
+    pub relayer_ids: Option<Vec<String>>,
+}
+
+impl UpdateRelayerPoolRequest {
+    /// Validates the request fields that do not depend on stored state.
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if let Some(name) = &self.name {
+            validate_pool_name(name)?;
+        }
+
+        if let Some(relayer_ids) = &self.relayer_ids {
+            validate_pool_relayer_ids(relayer_ids)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_pool_name(name: &str) -> Result<(), ApiError> {
+    if name.trim().is_empty() {
+        return Err(ApiError::BadRequest(
+            "Pool name cannot be empty".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_pool_relayer_ids(relayer_ids: &[String]) -> Result<(), ApiError> {
+    if relayer_ids.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Pool must have at least one relayer".to_string(),
+        ));
+    }
+
+    if relayer_ids.iter().any(|id| id.trim().is_empty()) {
+        return Err(ApiError::BadRequest(
+            "Pool relayer IDs cannot be empty".to_string(),
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    if let Some(duplicate) = relayer_ids.iter().find(|id| !seen.insert(id.as_str())) {
+        return Err(ApiError::BadRequest(format!(
+            "Relayer {} is listed more than once",
+            duplicate
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_request(relayer_ids: Vec<&str>) -> CreateRelayerPoolRequest {
+        CreateRelayerPoolRequest {
+            id: Some("pool-1".to_string()),
+            name: "Pool".to_string(),
+            relayer_ids: relayer_ids.into_iter().map(String::from).collect(),
+        }
+    }
+
+    #[test]
+    fn test_validate_create_request() {
+        assert!(create_request(vec!["relayer-1", "relayer-2"])
+            .validate()
+            .is_ok());
+
+        let mut request = create_request(vec!["relayer-1"]);
+        request.id = None;
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_create_request_rejects_invalid_fields() {
+        let mut request = create_request(vec!["relayer-1"]);
+        request.id = Some("invalid id".to_string());
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+
+        let mut request = create_request(vec!["relayer-1"]);
+        request.name = " ".to_string();
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+
+        for relayer_ids in [vec![], vec![""], vec!["relayer-1", "relayer-1"]] {
+            assert!(matches!(
+                create_request(relayer_ids).validate(),
+                Err(ApiError::BadRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_update_request() {
+        assert!(UpdateRelayerPoolRequest::default().validate().is_ok());
+
+        let request = UpdateRelayerPoolRequest {
+            name: None,
+            relayer_ids: Some(vec![]),
+        };
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_deserialize_rejects_unknown_fields() {
+        let result = serde_json::from_str::<UpdateRelayerPoolRequest>(r#"{"network": "sepolia"}"#);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,52 @@
+//! API response models for relayer pool endpoints.
+
+use crate::models::{RelayerNetworkType, RelayerPoolRepoModel, RelayerStatus};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Response structure for relayer pool endpoints
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct RelayerPoolResponse {
+    pub id: String,
+    pub name: String,
+    pub network: String,
+    pub network_type: RelayerNetworkType,
+    pub relayer_ids: Vec<String>,
+}
+
+impl From<RelayerPoolRepoModel> for RelayerPoolResponse {
+    fn from(model: RelayerPoolRepoModel) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            network: model.network,
+            network_type: model.network_type,
+            relayer_ids: model.relayer_ids,
+        }
+    }
+}
+
+/// Status of a relayer pool, aggregated from the status of its members
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct RelayerPoolStatus {
+    pub id: String,
+    pub network: String,
+    /// Members that are neither paused nor disabled
+    pub active_relayers_count: u64,
+    /// Pending transactions across all members
+    pub pending_transactions_count: u64,
+    pub relayers: Vec<RelayerPoolMemberStatus>,
+}
+
+/// Status of a single member of a relayer pool
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct RelayerPoolMemberStatus {
+    pub relayer_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub status: Option<RelayerStatus>,
+    /// Reason the status of the member could not be retrieved
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub error: Option<String>,
+}
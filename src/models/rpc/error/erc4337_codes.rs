@@ -0,0 +1,24 @@
+/// ERC-4337 bundler error codes.
+///
+/// These are the codes defined by the bundler JSON-RPC specification for user
+/// operations rejected during validation.
+pub struct Erc4337ErrorCodes;
+
+impl Erc4337ErrorCodes {
+    /// Rejected by the EntryPoint during account creation or validation.
+    pub const REJECTED_BY_ENTRY_POINT: i32 = -32500;
+
+    /// Rejected by the paymaster's `validatePaymasterUserOp`.
+    pub const REJECTED_BY_PAYMASTER: i32 = -32501;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erc4337_error_codes_values() {
+        assert_eq!(Erc4337ErrorCodes::REJECTED_BY_ENTRY_POINT, -32500);
+        assert_eq!(Erc4337ErrorCodes::REJECTED_BY_PAYMASTER, -32501);
+    }
+}
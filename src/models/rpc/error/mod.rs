@@ -3,10 +3,13 @@
 //! This module contains error code constants for JSON-RPC implementations:
 //! - Common JSON-RPC 2.0 standard error codes
 //! - OpenZeppelin-specific error codes for extended functionality
+//! - ERC-4337 bundler error codes
 //! - Network-specific error codes (Solana, EVM, Stellar)
 
 pub mod common_codes;
+pub mod erc4337_codes;
 pub mod openzeppelin_codes;
 
 pub use common_codes::*;
+pub use erc4337_codes::*;
 pub use openzeppelin_codes::*;
@@ -2,6 +2,9 @@ use alloy::network::{AnyRpcBlock, AnyTransactionReceipt};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+mod user_operation;
+pub use user_operation::*;
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 #[serde(untagged)]
 pub enum EvmRpcResult {
@@ -0,0 +1,136 @@
+//! ERC-4337 user operation models used by the EVM bundler RPC methods.
+//!
+//! Field names and encodings follow the EntryPoint v0.7 bundler JSON-RPC specification.
+use alloy::primitives::{Address, Bytes, B256, U256};
+use serde::{Deserialize, Serialize};
+
+use super::TransactionReceipt;
+
+/// User operation as submitted to `eth_sendUserOperation` and `eth_estimateUserOperationGas`.
+///
+/// Gas fields and the signature may be omitted when requesting a gas estimate.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperation {
+    pub sender: Address,
+    pub nonce: U256,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory_data: Option<Bytes>,
+    pub call_data: Bytes,
+    #[serde(default)]
+    pub call_gas_limit: U256,
+    #[serde(default)]
+    pub verification_gas_limit: U256,
+    #[serde(default)]
+    pub pre_verification_gas: U256,
+    #[serde(default)]
+    pub max_fee_per_gas: U256,
+    #[serde(default)]
+    pub max_priority_fee_per_gas: U256,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_verification_gas_limit: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_post_op_gas_limit: Option<U256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_data: Option<Bytes>,
+    #[serde(default)]
+    pub signature: Bytes,
+}
+
+/// Result of `eth_estimateUserOperationGas`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationGasEstimate {
+    pub pre_verification_gas: U256,
+    pub verification_gas_limit: U256,
+    pub call_gas_limit: U256,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_verification_gas_limit: Option<U256>,
+}
+
+/// Result of `eth_getUserOperationByHash`.
+///
+/// The block fields are empty until the bundle carrying the operation is mined.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationByHash {
+    pub user_operation: UserOperation,
+    pub entry_point: Address,
+    pub transaction_hash: Option<B256>,
+    pub block_hash: Option<B256>,
+    pub block_number: Option<U256>,
+}
+
+/// Result of `eth_getUserOperationReceipt`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserOperationReceipt {
+    pub user_op_hash: B256,
+    pub entry_point: Address,
+    pub sender: Address,
+    pub nonce: U256,
+    pub paymaster: Address,
+    pub actual_gas_cost: U256,
+    pub actual_gas_used: U256,
+    pub success: bool,
+    /// Revert data of the operation's call when it failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<Bytes>,
+    pub logs: Vec<alloy::rpc::types::Log>,
+    pub receipt: TransactionReceipt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_user_operation_deserializes_rpc_encoding() {
+        let op: UserOperation = serde_json::from_value(json!({
+            "sender": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+            "nonce": "0x1",
+            "callData": "0xdeadbeef",
+            "callGasLimit": "0x5208",
+            "verificationGasLimit": "0x186a0",
+            "preVerificationGas": "0xc350",
+            "maxFeePerGas": "0x3b9aca00",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "paymaster": "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC",
+            "paymasterVerificationGasLimit": "0x7530",
+            "paymasterPostOpGasLimit": "0x0",
+            "paymasterData": "0x",
+            "signature": "0x1234"
+        }))
+        .unwrap();
+
+        assert_eq!(op.nonce, U256::from(1));
+        assert_eq!(op.call_gas_limit, U256::from(21_000));
+        assert!(op.factory.is_none());
+        assert_eq!(
+            op.paymaster_verification_gas_limit,
+            Some(U256::from(30_000))
+        );
+
+        let value = serde_json::to_value(&op).unwrap();
+        assert_eq!(value["callGasLimit"], "0x5208");
+        assert!(value.get("factory").is_none());
+    }
+
+    #[test]
+    fn test_user_operation_gas_fields_default_for_estimation() {
+        let op: UserOperation = serde_json::from_value(json!({
+            "sender": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+            "nonce": "0x0",
+            "callData": "0x"
+        }))
+        .unwrap();
+
+        assert_eq!(op.call_gas_limit, U256::ZERO);
+        assert!(op.signature.is_empty());
+    }
+}
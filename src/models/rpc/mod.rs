@@ -130,8 +130,16 @@ pub fn convert_to_internal_rpc_request(
             })
         }
         crate::models::NetworkType::Stellar => {
-            let stellar_request: crate::models::StellarRpcRequest =
-                serde_json::from_value(request.clone()).map_err(|e| {
+            // Soroban RPC methods without params may omit the field entirely
+            let mut request = request.clone();
+            if let Some(obj) = request.as_object_mut() {
+                if obj.get("params").is_none_or(|params| params.is_null()) {
+                    obj.insert("params".to_string(), serde_json::json!({}));
+                }
+            }
+
+            let stellar_request: crate::models::StellarRpcRequest = serde_json::from_value(request)
+                .map_err(|e| {
                     crate::models::ApiError::BadRequest(format!(
                         "Invalid Stellar RPC request: {}",
                         e
@@ -397,11 +405,11 @@ mod tests {
     }
 
     #[test]
-    fn test_convert_stellar_generic_request() {
+    fn test_convert_stellar_get_transaction_request() {
         let request = json!({
             "jsonrpc": "2.0",
-            "method": "GenericRpcRequest",
-            "params": "test_params",
+            "method": "getTransaction",
+            "params": { "hash": "a".repeat(64) },
             "id": 1
         });
 
@@ -411,12 +419,10 @@ mod tests {
         assert_eq!(result.id, Some(JsonRpcId::Number(1)));
 
         match result.params {
-            NetworkRpcRequest::Stellar(stellar_request) => match stellar_request {
-                StellarRpcRequest::GenericRpcRequest(params) => {
-                    assert_eq!(params, "test_params");
-                }
-            },
-            _ => unreachable!("Expected Stellar request"),
+            NetworkRpcRequest::Stellar(StellarRpcRequest::GetTransaction(params)) => {
+                assert_eq!(params.hash, "a".repeat(64));
+            }
+            _ => unreachable!("Expected Stellar GetTransaction request"),
         }
     }
 
@@ -436,14 +442,33 @@ mod tests {
     #[test]
     fn test_convert_stellar_with_defaults() {
         let request = json!({
-            "method": "GenericRpcRequest",
-            "params": "default_test"
+            "method": "getLatestLedger"
         });
 
         let result = convert_to_internal_rpc_request(request, &NetworkType::Stellar).unwrap();
 
         assert_eq!(result.jsonrpc, "2.0");
         assert_eq!(result.id, Some(JsonRpcId::Number(1)));
+        assert!(matches!(
+            result.params,
+            NetworkRpcRequest::Stellar(StellarRpcRequest::GetLatestLedger(_))
+        ));
+    }
+
+    #[test]
+    fn test_convert_stellar_null_params() {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "method": "getFeeStats",
+            "params": null,
+            "id": "fee-stats"
+        });
+
+        let result = convert_to_internal_rpc_request(request, &NetworkType::Stellar).unwrap();
+        assert!(matches!(
+            result.params,
+            NetworkRpcRequest::Stellar(StellarRpcRequest::GetFeeStats(_))
+        ));
     }
 
     #[test]
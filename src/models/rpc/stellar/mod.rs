@@ -1,14 +1,508 @@
+//! Request and result types for the Stellar relayer JSON-RPC endpoint.
+//!
+//! Only the Soroban RPC methods listed in `StellarRpcRequest` are forwarded to the network.
+//! Parameters and results follow the Soroban RPC specification, so field names are camelCase.
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use soroban_rs::xdr::{LedgerKey, Limits, ReadXdr, TransactionEnvelope};
 use utoipa::ToSchema;
 
+/// Maximum number of ledger keys accepted by `getLedgerEntries`
+pub const STELLAR_RPC_MAX_LEDGER_KEYS: usize = 200;
+
+// getLedgerEntries
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetLedgerEntriesRequestParams {
+    /// Base64-encoded `LedgerKey` XDR values
+    pub keys: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerEntryResult {
+    pub key: String,
+    pub xdr: String,
+    pub last_modified_ledger_seq: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live_until_ledger_seq: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLedgerEntriesResult {
+    #[serde(default)]
+    pub entries: Option<Vec<LedgerEntryResult>>,
+    pub latest_ledger: u64,
+}
+
+// simulateTransaction
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SimulateResourceConfig {
+    pub instruction_leeway: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SimulateTransactionRequestParams {
+    /// Base64-encoded `TransactionEnvelope` XDR
+    pub transaction: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_config: Option<SimulateResourceConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+pub struct SimulateHostFunctionResult {
+    #[serde(default)]
+    pub auth: Vec<String>,
+    pub xdr: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateRestorePreamble {
+    pub transaction_data: String,
+    pub min_resource_fee: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateStateChange {
+    #[serde(rename = "type")]
+    pub change_type: String,
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateTransactionResult {
+    pub latest_ledger: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_resource_fee: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<SimulateHostFunctionResult>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub restore_preamble: Option<SimulateRestorePreamble>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_changes: Option<Vec<SimulateStateChange>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+// getEvents
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EventFilter {
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_ids: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topics: Option<Vec<Vec<String>>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventPagination {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetEventsRequestParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_ledger: Option<u64>,
+    #[serde(default)]
+    pub filters: Vec<EventFilter>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<EventPagination>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventInfo {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub ledger: u64,
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paging_token: Option<String>,
+    pub in_successful_contract_call: bool,
+    pub topic: Vec<String>,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetEventsResult {
+    #[serde(default)]
+    pub events: Vec<EventInfo>,
+    pub latest_ledger: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+// getTransaction
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetTransactionRequestParams {
+    /// Hex-encoded transaction hash
+    pub hash: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionResult {
+    pub status: String,
+    pub latest_ledger: u64,
+    pub latest_ledger_close_time: String,
+    pub oldest_ledger: u64,
+    pub oldest_ledger_close_time: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_order: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_bump: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub envelope_xdr: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_xdr: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_meta_xdr: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ledger: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+// getLatestLedger
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetLatestLedgerRequestParams {}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLatestLedgerResult {
+    pub id: String,
+    pub protocol_version: u32,
+    pub sequence: u64,
+}
+
+// getFeeStats
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetFeeStatsRequestParams {}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeDistribution {
+    pub max: String,
+    pub min: String,
+    pub mode: String,
+    pub p10: String,
+    pub p20: String,
+    pub p30: String,
+    pub p40: String,
+    pub p50: String,
+    pub p60: String,
+    pub p70: String,
+    pub p80: String,
+    pub p90: String,
+    pub p95: String,
+    pub p99: String,
+    pub transaction_count: String,
+    pub ledger_count: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFeeStatsResult {
+    pub soroban_inclusion_fee: FeeDistribution,
+    pub inclusion_fee: FeeDistribution,
+    pub latest_ledger: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
-#[serde(untagged)]
+#[serde(tag = "method", rename_all = "camelCase")]
 pub enum StellarRpcResult {
-    GenericRpcResult(String),
+    GetLedgerEntries(GetLedgerEntriesResult),
+    SimulateTransaction(SimulateTransactionResult),
+    GetEvents(GetEventsResult),
+    GetTransaction(GetTransactionResult),
+    GetLatestLedger(GetLatestLedgerResult),
+    GetFeeStats(Box<GetFeeStatsResult>),
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
 #[serde(tag = "method", content = "params")]
+#[schema(as = StellarRpcRequest)]
 pub enum StellarRpcRequest {
-    GenericRpcRequest(String),
+    #[serde(rename = "getLedgerEntries")]
+    #[schema(example = "getLedgerEntries")]
+    GetLedgerEntries(GetLedgerEntriesRequestParams),
+    #[serde(rename = "simulateTransaction")]
+    #[schema(example = "simulateTransaction")]
+    SimulateTransaction(SimulateTransactionRequestParams),
+    #[serde(rename = "getEvents")]
+    #[schema(example = "getEvents")]
+    GetEvents(GetEventsRequestParams),
+    #[serde(rename = "getTransaction")]
+    #[schema(example = "getTransaction")]
+    GetTransaction(GetTransactionRequestParams),
+    #[serde(rename = "getLatestLedger")]
+    #[schema(example = "getLatestLedger")]
+    GetLatestLedger(GetLatestLedgerRequestParams),
+    #[serde(rename = "getFeeStats")]
+    #[schema(example = "getFeeStats")]
+    GetFeeStats(GetFeeStatsRequestParams),
+}
+
+impl StellarRpcRequest {
+    /// Returns the Soroban RPC method name.
+    pub fn method(&self) -> &'static str {
+        match self {
+            StellarRpcRequest::GetLedgerEntries(_) => "getLedgerEntries",
+            StellarRpcRequest::SimulateTransaction(_) => "simulateTransaction",
+            StellarRpcRequest::GetEvents(_) => "getEvents",
+            StellarRpcRequest::GetTransaction(_) => "getTransaction",
+            StellarRpcRequest::GetLatestLedger(_) => "getLatestLedger",
+            StellarRpcRequest::GetFeeStats(_) => "getFeeStats",
+        }
+    }
+
+    /// Returns the params to forward to the Soroban RPC node.
+    ///
+    /// Methods without params return `Value::Null` so the field is omitted from the request.
+    pub fn params(&self) -> Result<Value, serde_json::Error> {
+        match self {
+            StellarRpcRequest::GetLedgerEntries(params) => serde_json::to_value(params),
+            StellarRpcRequest::SimulateTransaction(params) => serde_json::to_value(params),
+            StellarRpcRequest::GetEvents(params) => serde_json::to_value(params),
+            StellarRpcRequest::GetTransaction(params) => serde_json::to_value(params),
+            StellarRpcRequest::GetLatestLedger(_) | StellarRpcRequest::GetFeeStats(_) => {
+                Ok(Value::Null)
+            }
+        }
+    }
+
+    /// Validates the request params before they are forwarded.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            StellarRpcRequest::GetLedgerEntries(params) => {
+                if params.keys.is_empty() {
+                    return Err("keys must not be empty".to_string());
+                }
+                if params.keys.len() > STELLAR_RPC_MAX_LEDGER_KEYS {
+                    return Err(format!(
+                        "keys must not contain more than {} entries",
+                        STELLAR_RPC_MAX_LEDGER_KEYS
+                    ));
+                }
+                for key in &params.keys {
+                    LedgerKey::from_xdr_base64(key, Limits::none())
+                        .map_err(|e| format!("Invalid ledger key XDR: {}", e))?;
+                }
+                Ok(())
+            }
+            StellarRpcRequest::SimulateTransaction(params) => {
+                TransactionEnvelope::from_xdr_base64(&params.transaction, Limits::none())
+                    .map_err(|e| format!("Invalid transaction XDR: {}", e))?;
+                Ok(())
+            }
+            StellarRpcRequest::GetEvents(params) => {
+                if params.start_ledger.is_none()
+                    && params
+                        .pagination
+                        .as_ref()
+                        .and_then(|p| p.cursor.as_ref())
+                        .is_none()
+                {
+                    return Err("Either startLedger or pagination.cursor is required".to_string());
+                }
+                Ok(())
+            }
+            StellarRpcRequest::GetTransaction(params) => {
+                let is_valid_hash =
+                    params.hash.len() == 64 && params.hash.chars().all(|c| c.is_ascii_hexdigit());
+                if !is_valid_hash {
+                    return Err("hash must be a 64 character hex string".to_string());
+                }
+                Ok(())
+            }
+            StellarRpcRequest::GetLatestLedger(_) | StellarRpcRequest::GetFeeStats(_) => Ok(()),
+        }
+    }
+
+    /// Parses the raw Soroban RPC result into the typed result for this method.
+    pub fn parse_result(&self, result: Value) -> Result<StellarRpcResult, serde_json::Error> {
+        Ok(match self {
+            StellarRpcRequest::GetLedgerEntries(_) => {
+                StellarRpcResult::GetLedgerEntries(serde_json::from_value(result)?)
+            }
+            StellarRpcRequest::SimulateTransaction(_) => {
+                StellarRpcResult::SimulateTransaction(serde_json::from_value(result)?)
+            }
+            StellarRpcRequest::GetEvents(_) => {
+                StellarRpcResult::GetEvents(serde_json::from_value(result)?)
+            }
+            StellarRpcRequest::GetTransaction(_) => {
+                StellarRpcResult::GetTransaction(serde_json::from_value(result)?)
+            }
+            StellarRpcRequest::GetLatestLedger(_) => {
+                StellarRpcResult::GetLatestLedger(serde_json::from_value(result)?)
+            }
+            StellarRpcRequest::GetFeeStats(_) => {
+                StellarRpcResult::GetFeeStats(Box::new(serde_json::from_value(result)?))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use soroban_rs::xdr::{AccountId, LedgerKeyAccount, PublicKey, Uint256, WriteXdr};
+
+    fn account_ledger_key() -> String {
+        LedgerKey::Account(LedgerKeyAccount {
+            account_id: AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0u8; 32]))),
+        })
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_requests() {
+        let request: StellarRpcRequest = serde_json::from_value(json!({
+            "method": "getLedgerEntries",
+            "params": { "keys": [account_ledger_key()] }
+        }))
+        .unwrap();
+        assert_eq!(request.method(), "getLedgerEntries");
+        assert!(request.validate().is_ok());
+
+        let request: StellarRpcRequest = serde_json::from_value(json!({
+            "method": "getEvents",
+            "params": {
+                "startLedger": 100,
+                "filters": [{ "type": "contract", "topics": [["*"]] }],
+                "pagination": { "limit": 10 }
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            request.params().unwrap(),
+            json!({
+                "startLedger": 100,
+                "filters": [{ "type": "contract", "topics": [["*"]] }],
+                "pagination": { "limit": 10 }
+            })
+        );
+
+        let request: StellarRpcRequest = serde_json::from_value(json!({
+            "method": "getLatestLedger",
+            "params": {}
+        }))
+        .unwrap();
+        assert_eq!(request.params().unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn test_deserialize_rejects_unsupported_method() {
+        let result: Result<StellarRpcRequest, _> = serde_json::from_value(json!({
+            "method": "sendTransaction",
+            "params": { "transaction": "AAAA" }
+        }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_invalid_params() {
+        let empty_keys =
+            StellarRpcRequest::GetLedgerEntries(GetLedgerEntriesRequestParams { keys: vec![] });
+        assert!(empty_keys.validate().is_err());
+
+        let bad_key = StellarRpcRequest::GetLedgerEntries(GetLedgerEntriesRequestParams {
+            keys: vec!["not-xdr".to_string()],
+        });
+        assert!(bad_key.validate().is_err());
+
+        let bad_tx = StellarRpcRequest::SimulateTransaction(SimulateTransactionRequestParams {
+            transaction: "not-xdr".to_string(),
+            resource_config: None,
+        });
+        assert!(bad_tx.validate().is_err());
+
+        let bad_hash = StellarRpcRequest::GetTransaction(GetTransactionRequestParams {
+            hash: "0x1234".to_string(),
+        });
+        assert!(bad_hash.validate().is_err());
+
+        let no_start = StellarRpcRequest::GetEvents(GetEventsRequestParams {
+            start_ledger: None,
+            filters: vec![],
+            pagination: None,
+        });
+        assert!(no_start.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_result() {
+        let request = StellarRpcRequest::GetLatestLedger(GetLatestLedgerRequestParams {});
+        let result = request
+            .parse_result(json!({
+                "id": "c73c5eac58a441d4eb733c35253ae85f783e018f7be5ef974258fed067aabb36",
+                "protocolVersion": 22,
+                "sequence": 2539605
+            }))
+            .unwrap();
+
+        assert_eq!(
+            result,
+            StellarRpcResult::GetLatestLedger(GetLatestLedgerResult {
+                id: "c73c5eac58a441d4eb733c35253ae85f783e018f7be5ef974258fed067aabb36".to_string(),
+                protocol_version: 22,
+                sequence: 2539605,
+            })
+        );
+
+        let request = StellarRpcRequest::GetTransaction(GetTransactionRequestParams {
+            hash: "a".repeat(64),
+        });
+        let result = request
+            .parse_result(json!({
+                "status": "NOT_FOUND",
+                "latestLedger": 2540076,
+                "latestLedgerCloseTime": "1700086333",
+                "oldestLedger": 2538637,
+                "oldestLedgerCloseTime": "1700078796"
+            }))
+            .unwrap();
+        assert!(matches!(
+            result,
+            StellarRpcResult::GetTransaction(GetTransactionResult { ref status, .. }) if status == "NOT_FOUND"
+        ));
+    }
+
+    #[test]
+    fn test_parse_result_invalid_shape() {
+        let request = StellarRpcRequest::GetFeeStats(GetFeeStatsRequestParams {});
+        assert!(request.parse_result(json!({ "latestLedger": 1 })).is_err());
+    }
 }
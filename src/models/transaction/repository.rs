@@ -13,22 +13,27 @@ use crate::{
     },
     models::{
         transaction::{
-            request::{evm::EvmTransactionRequest, stellar::StellarTransactionRequest},
+            request::{
+                evm::{EvmAuthorizationRequest, EvmTransactionRequest},
+                next_scheduled_execution,
+                stellar::StellarTransactionRequest,
+            },
             stellar::{DecoratedSignature, MemoSpec, OperationSpec},
         },
         AddressError, EvmNetwork, NetworkRepoModel, NetworkTransactionRequest, NetworkType,
         RelayerError, RelayerRepoModel, SignerError, StellarNetwork, StellarValidationError,
-        TransactionError, U256,
+        TransactionError, TransactionReceipt, U256,
     },
     utils::{deserialize_optional_u128, serialize_optional_u128},
 };
 use alloy::{
-    consensus::{TxEip1559, TxLegacy},
-    primitives::{Address as AlloyAddress, Bytes, TxKind},
-    rpc::types::AccessList,
+    consensus::{TxEip1559, TxEip2930, TxEip7702, TxLegacy},
+    eips::eip7702::{Authorization, SignedAuthorization},
+    primitives::{Address as AlloyAddress, Bytes, TxKind, B256},
+    rpc::types::{AccessList, AccessListItem, Log},
 };
 
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, str::FromStr};
 use strum::Display;
@@ -70,6 +75,8 @@ pub struct TransactionUpdateRequest {
     pub is_canceled: Option<bool>,
     /// Timestamp when this transaction should be deleted (for final states)
     pub delete_at: Option<String>,
+    /// Whether to drop the recurrence schedule and prerequisites of the transaction
+    pub clear_schedule: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +99,18 @@ pub struct TransactionRepoModel {
     pub network_type: NetworkType,
     pub noop_count: Option<u32>,
     pub is_canceled: Option<bool>,
+    /// Client-supplied key used to deduplicate retried submissions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idempotency_key: Option<String>,
+    /// Time before which the transaction is not sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execute_after: Option<String>,
+    /// Cron expression on which the transaction recurs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// Transactions that must be confirmed before this one is sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<Vec<String>>,
 }
 
 impl TransactionRepoModel {
@@ -104,12 +123,73 @@ impl TransactionRepoModel {
         Ok(())
     }
 
+    /// Returns the unix timestamp the transaction request job should run at, if the
+    /// transaction is scheduled.
+    pub fn scheduled_on(&self) -> Option<i64> {
+        self.execute_after
+            .as_deref()
+            .and_then(|execute_after| DateTime::parse_from_rfc3339(execute_after).ok())
+            .map(|execute_after| execute_after.timestamp())
+    }
+
+    /// Returns true if the transaction is not scheduled or its scheduled time has passed.
+    pub fn is_due(&self) -> bool {
+        self.scheduled_on()
+            .is_none_or(|scheduled_on| scheduled_on <= Utc::now().timestamp())
+    }
+
+    /// Builds the next occurrence of a recurring transaction.
+    ///
+    /// The occurrence is a fresh pending copy of the transaction that runs at the next
+    /// time of its `schedule` after both now and the current occurrence.
+    ///
+    /// # Returns
+    /// * `Some(TransactionRepoModel)` with the next occurrence
+    /// * `None` if the transaction does not recur or its schedule has no future occurrence
+    pub fn next_occurrence(&self) -> Option<TransactionRepoModel> {
+        let now = Utc::now();
+        let after = self
+            .execute_after
+            .as_deref()
+            .and_then(|execute_after| DateTime::parse_from_rfc3339(execute_after).ok())
+            .map(|execute_after| execute_after.with_timezone(&Utc).max(now))
+            .unwrap_or(now);
+        let execute_after = next_scheduled_execution(self.schedule.as_deref()?, after)?;
+
+        Some(TransactionRepoModel {
+            id: Uuid::new_v4().to_string(),
+            status: TransactionStatus::Pending,
+            status_reason: None,
+            created_at: now.to_rfc3339(),
+            sent_at: None,
+            confirmed_at: None,
+            delete_at: None,
+            priced_at: None,
+            hashes: Vec::new(),
+            noop_count: None,
+            is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: Some(execute_after.to_rfc3339()),
+            ..self.clone()
+        })
+    }
+
     /// Calculate when this transaction should be deleted based on its status and expiration hours
     fn calculate_delete_at(expiration_hours: u64) -> Option<String> {
         let delete_time = Utc::now() + Duration::hours(expiration_hours as i64);
         Some(delete_time.to_rfc3339())
     }
 
+    /// Returns true if the transaction was created less than `window_seconds` ago.
+    pub fn is_created_within(&self, window_seconds: u64) -> bool {
+        DateTime::parse_from_rfc3339(&self.created_at)
+            .map(|created_at| {
+                Utc::now().signed_duration_since(created_at)
+                    < Duration::seconds(window_seconds as i64)
+            })
+            .unwrap_or(false)
+    }
+
     /// Update delete_at field if status changed to a final state
     pub fn update_delete_at_if_final_status(&mut self) {
         if self.delete_at.is_none() && FINAL_TRANSACTION_STATUSES.contains(&self.status) {
@@ -158,6 +238,10 @@ impl TransactionRepoModel {
         if let Some(delete_at) = update.delete_at {
             self.delete_at = Some(delete_at);
         }
+        if update.clear_schedule == Some(true) {
+            self.schedule = None;
+            self.depends_on = None;
+        }
     }
 
     /// Creates a TransactionUpdateRequest to reset this transaction to its pre-prepare state.
@@ -192,6 +276,7 @@ impl TransactionRepoModel {
             noop_count: None,
             is_canceled: None,
             delete_at: None,
+            clear_schedule: None,
         })
     }
 }
@@ -242,6 +327,149 @@ pub struct EvmTransactionDataSignature {
     pub sig: String,
 }
 
+/// Signed EIP-7702 authorization carried by a set-code transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct EvmAuthorization {
+    pub chain_id: u64,
+    pub address: String,
+    pub nonce: u64,
+    pub y_parity: u8,
+    pub r: String,
+    pub s: String,
+}
+
+impl EvmAuthorization {
+    /// Converts the authorization into its Alloy representation.
+    pub fn to_signed_authorization(&self) -> Result<SignedAuthorization, SignerError> {
+        let address = AlloyAddress::from_str(&self.address).map_err(|e| {
+            AddressError::ConversionError(format!("Invalid authorization address: {}", e))
+        })?;
+        let parse_word = |value: &str| {
+            U256::from_str(value).map_err(|e| {
+                SignerError::ConversionError(format!("Invalid authorization signature: {}", e))
+            })
+        };
+
+        Ok(SignedAuthorization::new_unchecked(
+            Authorization {
+                chain_id: U256::from(self.chain_id),
+                address,
+                nonce: self.nonce,
+            },
+            self.y_parity,
+            parse_word(&self.r)?,
+            parse_word(&self.s)?,
+        ))
+    }
+}
+
+impl From<&SignedAuthorization> for EvmAuthorization {
+    fn from(authorization: &SignedAuthorization) -> Self {
+        let inner = authorization.inner();
+        Self {
+            chain_id: inner.chain_id.saturating_to::<u64>(),
+            address: inner.address.to_checksum(None),
+            nonce: inner.nonce,
+            y_parity: authorization.y_parity(),
+            r: format!("0x{:064x}", authorization.r()),
+            s: format!("0x{:064x}", authorization.s()),
+        }
+    }
+}
+
+impl TryFrom<&EvmAuthorizationRequest> for EvmAuthorization {
+    type Error = RelayerError;
+
+    fn try_from(request: &EvmAuthorizationRequest) -> Result<Self, Self::Error> {
+        match (
+            request.chain_id,
+            request.nonce,
+            request.y_parity,
+            &request.r,
+            &request.s,
+        ) {
+            (Some(chain_id), Some(nonce), Some(y_parity), Some(r), Some(s)) => Ok(Self {
+                chain_id,
+                address: request.address.clone(),
+                nonce,
+                y_parity,
+                r: r.clone(),
+                s: s.clone(),
+            }),
+            _ => Err(RelayerError::ValidationError(format!(
+                "Authorization for {} is not signed",
+                request.address
+            ))),
+        }
+    }
+}
+
+/// EIP-2930 access list entry: a contract and the storage slots the transaction touches.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EvmAccessListItem {
+    pub address: String,
+    #[serde(default)]
+    pub storage_keys: Vec<String>,
+}
+
+impl EvmAccessListItem {
+    /// Converts the entry into its Alloy representation.
+    pub fn to_access_list_item(&self) -> Result<AccessListItem, SignerError> {
+        let address = AlloyAddress::from_str(&self.address).map_err(|e| {
+            AddressError::ConversionError(format!("Invalid access list address: {}", e))
+        })?;
+        let storage_keys = self
+            .storage_keys
+            .iter()
+            .map(|key| {
+                B256::from_str(key).map_err(|e| {
+                    SignerError::ConversionError(format!("Invalid access list storage key: {}", e))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AccessListItem {
+            address,
+            storage_keys,
+        })
+    }
+}
+
+impl From<&AccessListItem> for EvmAccessListItem {
+    fn from(item: &AccessListItem) -> Self {
+        Self {
+            address: item.address.to_checksum(None),
+            storage_keys: item
+                .storage_keys
+                .iter()
+                .map(|key| key.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Log emitted by a mined transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct EvmTransactionLog {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+    #[schema(nullable = false)]
+    pub log_index: Option<u64>,
+}
+
+impl From<&Log> for EvmTransactionLog {
+    fn from(log: &Log) -> Self {
+        Self {
+            address: log.address().to_checksum(None),
+            topics: log.topics().iter().map(|topic| topic.to_string()).collect(),
+            data: log.data().data.to_string(),
+            log_index: log.log_index,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvmTransactionData {
     #[serde(
@@ -273,6 +501,39 @@ pub struct EvmTransactionData {
     )]
     pub max_priority_fee_per_gas: Option<u128>,
     pub raw: Option<Vec<u8>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simulate: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization_list: Option<Vec<EvmAuthorization>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access_list: Option<Vec<EvmAccessListItem>>,
+    /// Hashes of the ERC-4337 user operations bundled into this `handleOps` transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_operation_hashes: Option<Vec<String>>,
+    /// Block the transaction was mined in, recorded to detect reorgs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_used: Option<u64>,
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub effective_gas_price: Option<u128>,
+    /// L1 data fee charged on OP-stack networks
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub l1_fee: Option<u128>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logs: Option<Vec<EvmTransactionLog>>,
 }
 
 impl EvmTransactionData {
@@ -314,9 +575,43 @@ impl EvmTransactionData {
             signature: None,
             hash: None,
             raw: None,
+
+            simulate: request.simulate.or(old_data.simulate),
+            authorization_list: None,
+            access_list: request.access_list.clone(),
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         }
     }
 
+    /// Attaches the signed EIP-7702 authorizations of a transaction request.
+    ///
+    /// # Arguments
+    /// * `request` - The transaction request carrying the authorization list
+    ///
+    /// # Returns
+    /// The updated `EvmTransactionData`, or an error if an authorization is not signed
+    pub fn with_authorization_list(
+        mut self,
+        request: &EvmTransactionRequest,
+    ) -> Result<Self, RelayerError> {
+        self.authorization_list = request
+            .authorization_list
+            .as_ref()
+            .map(|list| {
+                list.iter()
+                    .map(EvmAuthorization::try_from)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        Ok(self)
+    }
+
     /// Updates the transaction data with calculated price parameters.
     ///
     /// # Arguments
@@ -356,6 +651,30 @@ impl EvmTransactionData {
         self
     }
 
+    /// Updates the transaction data with the outcome recorded in a transaction receipt.
+    ///
+    /// # Arguments
+    /// * `receipt` - The receipt of the mined transaction
+    ///
+    /// # Returns
+    /// The updated `EvmTransactionData` with block, gas and log information applied
+    pub fn with_receipt(mut self, receipt: &TransactionReceipt) -> Self {
+        self.block_number = receipt.block_number;
+        self.block_hash = receipt.block_hash.map(|hash| hash.to_string());
+        self.gas_used = Some(receipt.gas_used);
+        self.effective_gas_price = Some(receipt.effective_gas_price);
+        self.logs = Some(
+            receipt
+                .inner
+                .inner
+                .logs()
+                .iter()
+                .map(EvmTransactionLog::from)
+                .collect(),
+        );
+        self
+    }
+
     /// Updates the transaction data with signature information from a signed transaction response.
     ///
     /// # Arguments
@@ -389,6 +708,16 @@ impl Default for EvmTransactionData {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         }
     }
 }
@@ -412,6 +741,10 @@ impl Default for TransactionRepoModel {
             hashes: Vec::new(),
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 }
@@ -419,6 +752,8 @@ impl Default for TransactionRepoModel {
 pub trait EvmTransactionDataTrait {
     fn is_legacy(&self) -> bool;
     fn is_eip1559(&self) -> bool;
+    fn is_eip7702(&self) -> bool;
+    fn is_eip2930(&self) -> bool;
     fn is_speed(&self) -> bool;
 }
 
@@ -431,6 +766,20 @@ impl EvmTransactionDataTrait for EvmTransactionData {
         self.max_fee_per_gas.is_some() && self.max_priority_fee_per_gas.is_some()
     }
 
+    fn is_eip7702(&self) -> bool {
+        self.authorization_list
+            .as_ref()
+            .is_some_and(|list| !list.is_empty())
+    }
+
+    fn is_eip2930(&self) -> bool {
+        self.is_legacy()
+            && self
+                .access_list
+                .as_ref()
+                .is_some_and(|list| !list.is_empty())
+    }
+
     fn is_speed(&self) -> bool {
         self.speed.is_some()
     }
@@ -798,6 +1147,12 @@ impl
         ),
     ) -> Result<Self, Self::Error> {
         let now = Utc::now().to_rfc3339();
+        let idempotency_key = request.idempotency_key().map(String::from);
+        let execute_after = request
+            .first_execution()
+            .map(|execute_after| execute_after.to_rfc3339());
+        let schedule = request.schedule().map(String::from);
+        let depends_on = request.depends_on().map(<[String]>::to_vec);
 
         match request {
             NetworkTransactionRequest::Evm(evm_request) => {
@@ -813,26 +1168,43 @@ impl
                     valid_until: evm_request.valid_until.clone(),
                     delete_at: None,
                     network_type: NetworkType::Evm,
-                    network_data: NetworkTransactionData::Evm(EvmTransactionData {
-                        gas_price: evm_request.gas_price,
-                        gas_limit: evm_request.gas_limit,
-                        nonce: None,
-                        value: evm_request.value,
-                        data: evm_request.data.clone(),
-                        from: relayer_model.address.clone(),
-                        to: evm_request.to.clone(),
-                        chain_id: network.id(),
-                        hash: None,
-                        signature: None,
-                        speed: evm_request.speed.clone(),
-                        max_fee_per_gas: evm_request.max_fee_per_gas,
-                        max_priority_fee_per_gas: evm_request.max_priority_fee_per_gas,
-                        raw: None,
-                    }),
+                    network_data: NetworkTransactionData::Evm(
+                        EvmTransactionData {
+                            gas_price: evm_request.gas_price,
+                            gas_limit: evm_request.gas_limit,
+                            nonce: None,
+                            value: evm_request.value,
+                            data: evm_request.data.clone(),
+                            from: relayer_model.address.clone(),
+                            to: evm_request.to.clone(),
+                            chain_id: network.id(),
+                            hash: None,
+                            signature: None,
+                            speed: evm_request.speed.clone(),
+                            max_fee_per_gas: evm_request.max_fee_per_gas,
+                            max_priority_fee_per_gas: evm_request.max_priority_fee_per_gas,
+                            raw: None,
+                            simulate: evm_request.simulate,
+                            authorization_list: None,
+                            access_list: evm_request.access_list.clone(),
+                            user_operation_hashes: None,
+                            block_number: None,
+                            block_hash: None,
+                            gas_used: None,
+                            effective_gas_price: None,
+                            l1_fee: None,
+                            logs: None,
+                        }
+                        .with_authorization_list(evm_request)?,
+                    ),
                     priced_at: None,
                     hashes: Vec::new(),
                     noop_count: None,
                     is_canceled: Some(false),
+                    idempotency_key,
+                    execute_after: execute_after.clone(),
+                    schedule: schedule.clone(),
+                    depends_on: depends_on.clone(),
                 })
             }
             NetworkTransactionRequest::Solana(solana_request) => Ok(Self {
@@ -854,6 +1226,10 @@ impl
                 hashes: Vec::new(),
                 noop_count: None,
                 is_canceled: Some(false),
+                idempotency_key,
+                execute_after,
+                schedule,
+                depends_on,
             }),
             NetworkTransactionRequest::Stellar(stellar_request) => {
                 // Store the source account before consuming the request
@@ -891,6 +1267,10 @@ impl
                     hashes: Vec::new(),
                     noop_count: None,
                     is_canceled: Some(false),
+                    idempotency_key,
+                    execute_after: execute_after.clone(),
+                    schedule: schedule.clone(),
+                    depends_on: depends_on.clone(),
                 })
             }
         }
@@ -922,6 +1302,21 @@ impl EvmTransactionData {
         Bytes::from_str(self.data.as_deref().unwrap_or(""))
             .map_err(|e| SignerError::SigningError(format!("Invalid transaction data: {}", e)))
     }
+
+    /// Converts the transaction's EIP-2930 access list into its Alloy representation.
+    ///
+    /// # Returns
+    /// * `Ok(AccessList)` containing the access list, empty if none is set
+    /// * `Err(SignerError)` if an address or storage key is invalid
+    pub fn to_access_list(&self) -> Result<AccessList, SignerError> {
+        self.access_list
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(EvmAccessListItem::to_access_list_item)
+            .collect::<Result<Vec<_>, _>>()
+            .map(AccessList)
+    }
 }
 
 impl TryFrom<NetworkTransactionData> for TxLegacy {
@@ -971,7 +1366,7 @@ impl TryFrom<NetworkTransactionData> for TxEip1559 {
                     max_priority_fee_per_gas: tx.max_priority_fee_per_gas.unwrap_or(0),
                     to: tx_kind,
                     value: tx.value,
-                    access_list: AccessList::default(),
+                    access_list: tx.to_access_list()?,
                     input: tx.data_to_bytes()?,
                 })
             }
@@ -1011,6 +1406,28 @@ impl TryFrom<EvmTransactionData> for TxLegacy {
     }
 }
 
+impl TryFrom<&EvmTransactionData> for TxEip2930 {
+    type Error = SignerError;
+
+    fn try_from(tx: &EvmTransactionData) -> Result<Self, Self::Error> {
+        let tx_kind = match tx.to_address()? {
+            Some(addr) => TxKind::Call(addr),
+            None => TxKind::Create,
+        };
+
+        Ok(Self {
+            chain_id: tx.chain_id,
+            nonce: tx.nonce.unwrap_or(0),
+            gas_price: tx.gas_price.unwrap_or(0),
+            gas_limit: tx.gas_limit.unwrap_or(DEFAULT_GAS_LIMIT),
+            to: tx_kind,
+            value: tx.value,
+            access_list: tx.to_access_list()?,
+            input: tx.data_to_bytes()?,
+        })
+    }
+}
+
 impl TryFrom<&EvmTransactionData> for TxEip1559 {
     type Error = SignerError;
 
@@ -1028,7 +1445,7 @@ impl TryFrom<&EvmTransactionData> for TxEip1559 {
             max_priority_fee_per_gas: tx.max_priority_fee_per_gas.unwrap_or(0),
             to: tx_kind,
             value: tx.value,
-            access_list: AccessList::default(),
+            access_list: tx.to_access_list()?,
             input: tx.data_to_bytes()?,
         })
     }
@@ -1042,6 +1459,36 @@ impl TryFrom<EvmTransactionData> for TxEip1559 {
     }
 }
 
+impl TryFrom<&EvmTransactionData> for TxEip7702 {
+    type Error = SignerError;
+
+    fn try_from(tx: &EvmTransactionData) -> Result<Self, Self::Error> {
+        let to = tx.to_address()?.ok_or_else(|| {
+            SignerError::SigningError("EIP-7702 transactions require a 'to' address".to_string())
+        })?;
+        let authorization_list = tx
+            .authorization_list
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(EvmAuthorization::to_signed_authorization)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            chain_id: tx.chain_id,
+            nonce: tx.nonce.unwrap_or(0),
+            gas_limit: tx.gas_limit.unwrap_or(DEFAULT_GAS_LIMIT),
+            max_fee_per_gas: tx.max_fee_per_gas.unwrap_or(0),
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas.unwrap_or(0),
+            to,
+            value: tx.value,
+            access_list: tx.to_access_list()?,
+            authorization_list,
+            input: tx.data_to_bytes()?,
+        })
+    }
+}
+
 impl From<&[u8; 65]> for EvmTransactionDataSignature {
     fn from(bytes: &[u8; 65]) -> Self {
         Self {
@@ -1174,6 +1621,10 @@ mod tests {
             network_type: NetworkType::Stellar,
             noop_count: None,
             is_canceled: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -1214,6 +1665,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         }
     }
 
@@ -1368,6 +1829,194 @@ mod tests {
         assert!(!tx_data.is_eip1559());
     }
 
+    fn create_sample_authorization() -> EvmAuthorization {
+        EvmAuthorization {
+            chain_id: 1,
+            address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
+            nonce: 3,
+            y_parity: 1,
+            r: format!("0x{:064x}", 1),
+            s: format!("0x{:064x}", 2),
+        }
+    }
+
+    #[test]
+    fn test_evm_tx_is_eip7702() {
+        let mut tx_data = create_sample_evm_tx_data();
+        assert!(!tx_data.is_eip7702());
+
+        tx_data.authorization_list = Some(vec![]);
+        assert!(!tx_data.is_eip7702());
+
+        tx_data.authorization_list = Some(vec![create_sample_authorization()]);
+        assert!(tx_data.is_eip7702());
+    }
+
+    #[test]
+    fn test_evm_authorization_signed_authorization_round_trip() {
+        let authorization = create_sample_authorization();
+
+        let signed = authorization.to_signed_authorization().unwrap();
+        assert_eq!(signed.inner().nonce, 3);
+        assert_eq!(signed.y_parity(), 1);
+        assert_eq!(signed.r(), U256::from(1));
+        assert_eq!(signed.s(), U256::from(2));
+
+        assert_eq!(EvmAuthorization::from(&signed), authorization);
+    }
+
+    #[test]
+    fn test_tx_eip7702_from_evm_tx_data() {
+        let mut tx_data = create_sample_evm_tx_data();
+        tx_data.gas_price = None;
+        tx_data.max_fee_per_gas = Some(30_000_000_000);
+        tx_data.max_priority_fee_per_gas = Some(2_000_000_000);
+        tx_data.authorization_list = Some(vec![create_sample_authorization()]);
+
+        let tx = TxEip7702::try_from(&tx_data).unwrap();
+        assert_eq!(tx.chain_id, tx_data.chain_id);
+        assert_eq!(tx.max_fee_per_gas, 30_000_000_000);
+        assert_eq!(tx.authorization_list.len(), 1);
+        assert_eq!(
+            tx.to,
+            AlloyAddress::from_str(tx_data.to.as_ref().unwrap()).unwrap()
+        );
+
+        tx_data.to = None;
+        assert!(TxEip7702::try_from(&tx_data).is_err());
+    }
+
+    #[test]
+    fn test_tx_eip2930_from_evm_tx_data() {
+        let mut tx_data = create_sample_evm_tx_data();
+        assert!(!tx_data.is_eip2930());
+
+        tx_data.access_list = Some(vec![EvmAccessListItem {
+            address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44f".to_string(),
+            storage_keys: vec![format!("0x{:064x}", 1)],
+        }]);
+        assert!(tx_data.is_eip2930());
+
+        let tx = TxEip2930::try_from(&tx_data).unwrap();
+        assert_eq!(tx.gas_price, tx_data.gas_price.unwrap());
+        assert_eq!(tx.access_list.len(), 1);
+        assert_eq!(tx.access_list.0[0].storage_keys[0], B256::with_last_byte(1));
+        let item = EvmAccessListItem::from(&tx.access_list.0[0]);
+        assert!(item
+            .address
+            .eq_ignore_ascii_case("0x742d35Cc6634C0532925a3b844Bc454e4438f44f"));
+        assert_eq!(item.storage_keys, vec![format!("0x{:064x}", 1)]);
+
+        // EIP-1559 priced transactions carry the access list themselves
+        tx_data.gas_price = None;
+        tx_data.max_fee_per_gas = Some(30_000_000_000);
+        tx_data.max_priority_fee_per_gas = Some(2_000_000_000);
+        assert!(!tx_data.is_eip2930());
+        assert_eq!(TxEip1559::try_from(&tx_data).unwrap().access_list.len(), 1);
+
+        tx_data.access_list = Some(vec![EvmAccessListItem {
+            address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44f".to_string(),
+            storage_keys: vec!["0x01".to_string()],
+        }]);
+        assert!(TxEip1559::try_from(&tx_data).is_err());
+    }
+
+    #[test]
+    fn test_evm_tx_data_with_receipt() {
+        let topic = format!("0x{}", "cc".repeat(32));
+        let receipt: TransactionReceipt = serde_json::from_value(serde_json::json!({
+            "type": "0x2",
+            "status": "0x1",
+            "cumulativeGasUsed": "0xa410",
+            "logsBloom": format!("0x{}", "00".repeat(256)),
+            "logs": [{
+                "address": "0x742d35cc6634c0532925a3b844bc454e4438f44f",
+                "topics": [topic],
+                "data": "0x01",
+                "blockHash": format!("0x{}", "bb".repeat(32)),
+                "blockNumber": "0x64",
+                "transactionHash": format!("0x{}", "aa".repeat(32)),
+                "transactionIndex": "0x0",
+                "logIndex": "0x3",
+                "removed": false
+            }],
+            "transactionHash": format!("0x{}", "aa".repeat(32)),
+            "transactionIndex": "0x0",
+            "blockHash": format!("0x{}", "bb".repeat(32)),
+            "blockNumber": "0x64",
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x3b9aca00",
+            "from": "0x742d35cc6634c0532925a3b844bc454e4438f44f",
+            "to": "0x742d35cc6634c0532925a3b844bc454e4438f44f",
+            "contractAddress": null
+        }))
+        .unwrap();
+
+        let tx_data = create_sample_evm_tx_data().with_receipt(&receipt);
+
+        assert_eq!(tx_data.block_number, Some(100));
+        assert_eq!(tx_data.block_hash, Some(format!("0x{}", "bb".repeat(32))));
+        assert_eq!(tx_data.gas_used, Some(21_000));
+        assert_eq!(tx_data.effective_gas_price, Some(1_000_000_000));
+        assert_eq!(
+            tx_data.logs,
+            Some(vec![EvmTransactionLog {
+                address: "0x742D35CC6634C0532925a3b844Bc454e4438F44f".to_string(),
+                topics: vec![topic],
+                data: "0x01".to_string(),
+                log_index: Some(3),
+            }])
+        );
+    }
+
+    #[test]
+    fn test_evm_tx_with_authorization_list_requires_signatures() {
+        let signed = EvmAuthorizationRequest {
+            chain_id: Some(1),
+            address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44f".to_string(),
+            nonce: Some(0),
+            y_parity: Some(0),
+            r: Some("0x1".to_string()),
+            s: Some("0x2".to_string()),
+            signer_id: None,
+        };
+        let mut request = EvmTransactionRequest {
+            to: Some("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string()),
+            value: U256::ZERO,
+            data: None,
+            gas_limit: None,
+            gas_price: None,
+            speed: Some(Speed::Fast),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: Some(vec![signed.clone()]),
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        };
+
+        let tx_data = create_sample_evm_tx_data()
+            .with_authorization_list(&request)
+            .unwrap();
+        assert_eq!(tx_data.authorization_list.unwrap().len(), 1);
+
+        request.authorization_list = Some(vec![EvmAuthorizationRequest {
+            y_parity: None,
+            r: None,
+            s: None,
+            signer_id: Some("delegator".to_string()),
+            ..signed
+        }]);
+        assert!(matches!(
+            create_sample_evm_tx_data().with_authorization_list(&request),
+            Err(RelayerError::ValidationError(_))
+        ));
+    }
+
     #[test]
     fn test_evm_tx_is_speed() {
         let mut tx_data = create_sample_evm_tx_data();
@@ -1598,6 +2247,13 @@ mod tests {
             max_priority_fee_per_gas: Some(2000000000), // Should be ignored
             speed: Some(Speed::Fast),
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = EvmTransactionData::for_replacement(&old_data, &new_request);
@@ -1646,6 +2302,13 @@ mod tests {
             max_priority_fee_per_gas: None,
             speed: Some(Speed::Fast),
             valid_until: Some("2024-12-31T23:59:59Z".to_string()),
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         });
 
         let relayer_model = RelayerRepoModel {
@@ -1660,6 +2323,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let network_model = NetworkRepoModel {
@@ -1681,6 +2345,8 @@ mod tests {
                 features: None,
                 symbol: Some("ETH".to_string()),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             }),
         };
 
@@ -1722,6 +2388,8 @@ mod tests {
         let solana_request = NetworkTransactionRequest::Solana(
             crate::models::transaction::request::solana::SolanaTransactionRequest {
                 transaction: EncodedSerializedTransaction::new("transaction_123".to_string()),
+                idempotency_key: None,
+                depends_on: None,
             },
         );
 
@@ -1737,6 +2405,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let network_model = NetworkRepoModel {
@@ -1798,6 +2467,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         });
 
         let relayer_model = RelayerRepoModel {
@@ -1812,6 +2485,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let network_model = NetworkRepoModel {
@@ -1988,6 +2662,13 @@ mod tests {
             max_priority_fee_per_gas: None,
             speed: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = EvmTransactionData::for_replacement(&old_data, &new_request);
@@ -2114,6 +2795,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         (network_model, relayer_model)
@@ -2139,6 +2821,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2167,6 +2853,10 @@ mod tests {
             transaction_xdr: Some(unsigned_xdr.to_string()),
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2251,6 +2941,10 @@ mod tests {
             transaction_xdr: Some(signed_xdr.to_string()),
             fee_bump: Some(true),
             max_fee: Some(20000000),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2280,6 +2974,10 @@ mod tests {
             transaction_xdr: Some(signed_xdr.clone()),
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2306,6 +3004,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: Some(true),
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2339,6 +3041,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2371,6 +3077,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2415,6 +3125,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2457,6 +3171,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2483,6 +3201,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2518,6 +3240,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2545,6 +3271,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2572,6 +3302,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let request = NetworkTransactionRequest::Stellar(stellar_request);
@@ -2885,12 +3619,26 @@ mod tests {
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             priced_at: None,
             hashes: vec![],
             network_type: NetworkType::Evm,
             noop_count: None,
             is_canceled: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -2956,6 +3704,10 @@ mod tests {
             network_type: NetworkType::Evm,
             noop_count: Some(5),
             is_canceled: Some(true),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         // Create a partial update that only changes status
@@ -3010,4 +3762,57 @@ mod tests {
         assert_eq!(transaction.is_canceled, original_transaction.is_canceled);
         assert_eq!(transaction.delete_at, original_transaction.delete_at);
     }
+
+    #[test]
+    fn test_is_created_within() {
+        let mut transaction = create_test_transaction();
+
+        transaction.created_at = Utc::now().to_rfc3339();
+        assert!(transaction.is_created_within(60));
+
+        transaction.created_at = (Utc::now() - Duration::seconds(120)).to_rfc3339();
+        assert!(!transaction.is_created_within(60));
+
+        transaction.created_at = "not-a-date".to_string();
+        assert!(!transaction.is_created_within(60));
+    }
+
+    #[test]
+    fn test_scheduled_on_and_is_due() {
+        let mut transaction = create_test_transaction();
+        assert_eq!(transaction.scheduled_on(), None);
+        assert!(transaction.is_due());
+
+        let execute_after = Utc::now() + Duration::hours(1);
+        transaction.execute_after = Some(execute_after.to_rfc3339());
+        assert_eq!(transaction.scheduled_on(), Some(execute_after.timestamp()));
+        assert!(!transaction.is_due());
+
+        transaction.execute_after = Some((Utc::now() - Duration::seconds(1)).to_rfc3339());
+        assert!(transaction.is_due());
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let mut transaction = create_test_transaction();
+        assert!(transaction.next_occurrence().is_none());
+
+        let execute_after = Utc::now() + Duration::hours(3);
+        transaction.execute_after = Some(execute_after.to_rfc3339());
+        transaction.schedule = Some("0 0 * * * *".to_string());
+        transaction.idempotency_key = Some("payroll".to_string());
+
+        let next = transaction.next_occurrence().unwrap();
+        let next_execution = DateTime::parse_from_rfc3339(next.execute_after.as_ref().unwrap())
+            .unwrap()
+            .with_timezone(&Utc);
+
+        assert_ne!(next.id, transaction.id);
+        assert_eq!(next.relayer_id, transaction.relayer_id);
+        assert_eq!(next.status, TransactionStatus::Pending);
+        assert_eq!(next.schedule, transaction.schedule);
+        assert_eq!(next.idempotency_key, None);
+        assert!(next_execution > execute_after);
+        assert!(next_execution <= execute_after + Duration::hours(1));
+    }
 }
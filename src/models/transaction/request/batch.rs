@@ -0,0 +1,73 @@
+use crate::{
+    constants::MAX_BATCH_TRANSACTIONS,
+    models::{ApiError, NetworkTransactionRequest},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Request to submit several transactions for a relayer at once
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BatchTransactionRequest {
+    /// Transactions to submit, in nonce order
+    #[schema(value_type = Vec<NetworkTransactionRequest>)]
+    pub transactions: Vec<serde_json::Value>,
+    /// Reject the whole batch if any transaction is invalid (default: false)
+    #[serde(default)]
+    pub all_or_nothing: bool,
+}
+
+impl BatchTransactionRequest {
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if self.transactions.is_empty() {
+            return Err(ApiError::BadRequest(
+                "Batch must contain at least one transaction".to_string(),
+            ));
+        }
+
+        if self.transactions.len() > MAX_BATCH_TRANSACTIONS {
+            return Err(ApiError::BadRequest(format!(
+                "Batch cannot contain more than {} transactions",
+                MAX_BATCH_TRANSACTIONS
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_defaults() {
+        let request: BatchTransactionRequest =
+            serde_json::from_value(json!({ "transactions": [{ "value": 1 }] })).unwrap();
+
+        assert_eq!(request.transactions.len(), 1);
+        assert!(!request.all_or_nothing);
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_empty_batch() {
+        let request = BatchTransactionRequest {
+            transactions: vec![],
+            all_or_nothing: true,
+        };
+
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_batch_too_large() {
+        let request = BatchTransactionRequest {
+            transactions: vec![json!({}); MAX_BATCH_TRANSACTIONS + 1],
+            all_or_nothing: false,
+        };
+
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+    }
+}
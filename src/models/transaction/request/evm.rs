@@ -1,9 +1,15 @@
 use crate::{
     constants::ZERO_ADDRESS,
-    models::{ApiError, RelayerNetworkPolicy, RelayerRepoModel, U256},
+    models::{
+        parse_abi_word, ApiError, EvmAccessListItem, EvmSelectorRule, RelayerNetworkPolicy,
+        RelayerRepoModel, U256,
+    },
     utils::calculate_intrinsic_gas,
 };
+use alloy::primitives::{Address, Bytes, FixedBytes, B256};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
 use utoipa::{schema, ToSchema};
 
 #[derive(Deserialize, Serialize, Default, ToSchema)]
@@ -25,6 +31,67 @@ pub struct EvmTransactionRequest {
     pub max_priority_fee_per_gas: Option<u128>,
     #[schema(nullable = false)]
     pub valid_until: Option<String>,
+    /// Overrides the relayer's `simulate_transactions` policy for this transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub simulate: Option<bool>,
+    /// EIP-7702 authorizations; makes the transaction a set-code (type 4) transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub authorization_list: Option<Vec<EvmAuthorizationRequest>>,
+    /// EIP-2930 access list declaring the addresses and storage slots the transaction touches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub access_list: Option<Vec<EvmAccessListItem>>,
+    /// Key identifying retries of the same submission; repeats return the original transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, max_length = 255)]
+    pub idempotency_key: Option<String>,
+    /// RFC 3339 time before which the transaction is not sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub execute_after: Option<String>,
+    /// Cron expression (with seconds) on which the transaction is sent again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub schedule: Option<String>,
+    /// Transactions that must be confirmed before this one is sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, max_items = 16)]
+    pub depends_on: Option<Vec<String>>,
+}
+
+/// EIP-7702 authorization delegating an account's code to a contract.
+///
+/// Either carries the authority's signature (`y_parity`, `r` and `s`) or names a configured
+/// signer in `signer_id` for the relayer to sign it with.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EvmAuthorizationRequest {
+    /// Chain the authorization is valid on, 0 for all chains. Defaults to the relayer's network
+    #[schema(nullable = false)]
+    pub chain_id: Option<u64>,
+    /// Contract the authority delegates to
+    pub address: String,
+    /// Authority nonce. Read from the network when omitted for relayer-signed authorizations
+    #[schema(nullable = false)]
+    pub nonce: Option<u64>,
+    #[schema(nullable = false)]
+    pub y_parity: Option<u8>,
+    #[schema(nullable = false)]
+    pub r: Option<String>,
+    #[schema(nullable = false)]
+    pub s: Option<String>,
+    /// Signer that signs the authorization on behalf of the authority
+    #[schema(nullable = false)]
+    pub signer_id: Option<String>,
+}
+
+impl EvmAuthorizationRequest {
+    /// Returns true if the request carries the authority's signature.
+    pub fn is_signed(&self) -> bool {
+        self.y_parity.is_some() && self.r.is_some() && self.s.is_some()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
@@ -39,8 +106,11 @@ pub enum Speed {
 impl EvmTransactionRequest {
     pub fn validate(&self, relayer: &RelayerRepoModel) -> Result<(), ApiError> {
         validate_target_address(self, relayer)?;
+        validate_spending_limits(self, relayer)?;
         validate_evm_transaction_request(self, relayer)?;
         validate_price_params(self, relayer)?;
+        validate_authorization_list(self)?;
+        validate_access_list(self)?;
         Ok(())
     }
 }
@@ -116,7 +186,217 @@ pub fn validate_target_address(
                 ));
             }
         }
+        if let Some(allowed_selectors) = &evm_policy.allowed_selectors {
+            validate_allowed_selectors(request, allowed_selectors)?;
+        }
+    }
+    Ok(())
+}
+
+/// Checks a contract call against the relayer's selector allowlist.
+/// Transactions without call data are plain transfers and are not restricted.
+pub fn validate_allowed_selectors(
+    request: &EvmTransactionRequest,
+    allowed_selectors: &HashMap<String, Vec<EvmSelectorRule>>,
+) -> Result<(), ApiError> {
+    let data = Bytes::from_str(request.data.as_deref().unwrap_or(""))
+        .map_err(|e| ApiError::BadRequest(format!("Invalid transaction data: {}", e)))?;
+    if data.is_empty() {
+        return Ok(());
+    }
+
+    let Some(target) = request.to.as_deref() else {
+        return Err(ApiError::BadRequest(
+            "Contract deployments are not allowed by the selector allowlist".to_string(),
+        ));
+    };
+    let rules = allowed_selectors
+        .iter()
+        .find(|(address, _)| address.eq_ignore_ascii_case(target))
+        .map(|(_, rules)| rules)
+        .ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Contract {} is not in the selector allowlist",
+                target
+            ))
+        })?;
+
+    if data.len() < 4 {
+        return Err(ApiError::BadRequest(
+            "Transaction data is shorter than a function selector".to_string(),
+        ));
+    }
+    let (selector, arguments) = data.split_at(4);
+    let rule = rules
+        .iter()
+        .find(|rule| {
+            FixedBytes::<4>::from_str(&rule.selector)
+                .is_ok_and(|allowed| allowed.as_slice() == selector)
+        })
+        .ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Function selector 0x{} is not allowed on contract {}",
+                hex::encode(selector),
+                target
+            ))
+        })?;
+
+    for constraint in rule.arguments.iter().flatten() {
+        let offset = constraint.index * 32;
+        let word = arguments.get(offset..offset + 32).ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Call to selector {} is missing argument {}",
+                rule.selector, constraint.index
+            ))
+        })?;
+        let value = U256::from_be_slice(word);
+
+        if let Some(max_value) = constraint.max_value {
+            if value > U256::from(max_value) {
+                return Err(ApiError::BadRequest(format!(
+                    "Argument {} of selector {} exceeds the maximum of {}",
+                    constraint.index, rule.selector, max_value
+                )));
+            }
+        }
+        if let Some(equals) = &constraint.equals {
+            if parse_abi_word(equals) != Some(value) {
+                return Err(ApiError::BadRequest(format!(
+                    "Argument {} of selector {} must be {}",
+                    constraint.index, rule.selector, equals
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rejects transactions whose value alone exceeds the relayer's spending limits.
+/// Rolling window budgets are enforced when the transaction is priced.
+pub fn validate_spending_limits(
+    request: &EvmTransactionRequest,
+    relayer: &RelayerRepoModel,
+) -> Result<(), ApiError> {
+    let RelayerNetworkPolicy::Evm(evm_policy) = &relayer.policies else {
+        return Ok(());
+    };
+
+    if let Some(max_value) = evm_policy.max_value_per_transaction {
+        if request.value > U256::from(max_value) {
+            return Err(ApiError::SpendingLimitExceeded(format!(
+                "Transaction value {} exceeds the maximum of {} per transaction",
+                request.value, max_value
+            )));
+        }
+    }
+    if let Some(max_total_value) = evm_policy.max_total_value {
+        if request.value > U256::from(max_total_value) {
+            return Err(ApiError::SpendingLimitExceeded(format!(
+                "Transaction value {} exceeds the total value limit of {}",
+                request.value, max_total_value
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub fn validate_authorization_list(request: &EvmTransactionRequest) -> Result<(), ApiError> {
+    let Some(authorization_list) = &request.authorization_list else {
+        return Ok(());
+    };
+
+    if authorization_list.is_empty() {
+        return Err(ApiError::BadRequest(
+            "authorization_list cannot be empty".to_string(),
+        ));
+    }
+    if request.to.is_none() {
+        return Err(ApiError::BadRequest(
+            "EIP-7702 transactions require a `to` address".to_string(),
+        ));
+    }
+    if request.gas_price.is_some() {
+        return Err(ApiError::BadRequest(
+            "EIP-7702 transactions use EIP1559 pricing, gasPrice is not supported".to_string(),
+        ));
+    }
+
+    for authorization in authorization_list {
+        if Address::from_str(&authorization.address).is_err() {
+            return Err(ApiError::BadRequest(format!(
+                "Invalid authorization address: {}",
+                authorization.address
+            )));
+        }
+
+        let has_signature_part = authorization.y_parity.is_some()
+            || authorization.r.is_some()
+            || authorization.s.is_some();
+        match (authorization.is_signed(), &authorization.signer_id) {
+            (true, None) => {
+                if authorization.nonce.is_none() {
+                    return Err(ApiError::BadRequest(
+                        "Signed authorizations require a nonce".to_string(),
+                    ));
+                }
+                if authorization.y_parity.is_some_and(|y_parity| y_parity > 1) {
+                    return Err(ApiError::BadRequest(
+                        "Authorization y_parity must be 0 or 1".to_string(),
+                    ));
+                }
+                for value in [&authorization.r, &authorization.s].into_iter().flatten() {
+                    if U256::from_str(value).is_err() {
+                        return Err(ApiError::BadRequest(format!(
+                            "Invalid authorization signature value: {}",
+                            value
+                        )));
+                    }
+                }
+            }
+            (false, Some(_)) if !has_signature_part => {}
+            _ => {
+                return Err(ApiError::BadRequest(
+                    "Each authorization must have either a complete signature (y_parity, r, s) \
+                     or a signer_id"
+                        .to_string(),
+                ));
+            }
+        }
     }
+
+    Ok(())
+}
+
+pub fn validate_access_list(request: &EvmTransactionRequest) -> Result<(), ApiError> {
+    let Some(access_list) = &request.access_list else {
+        return Ok(());
+    };
+
+    if access_list.is_empty() {
+        return Err(ApiError::BadRequest(
+            "access_list cannot be empty".to_string(),
+        ));
+    }
+
+    for item in access_list {
+        if Address::from_str(&item.address).is_err() {
+            return Err(ApiError::BadRequest(format!(
+                "Invalid access list address: {}",
+                item.address
+            )));
+        }
+        if let Some(key) = item
+            .storage_keys
+            .iter()
+            .find(|key| B256::from_str(key).is_err())
+        {
+            return Err(ApiError::BadRequest(format!(
+                "Invalid access list storage key: {}",
+                key
+            )));
+        }
+    }
+
     Ok(())
 }
 
@@ -187,7 +467,9 @@ pub fn validate_price_params(
 
 #[cfg(test)]
 mod tests {
-    use crate::models::{NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy, RpcConfig};
+    use crate::models::{
+        EvmArgumentConstraint, NetworkType, RelayerEvmPolicy, RelayerNetworkPolicy, RpcConfig,
+    };
 
     use super::*;
     use chrono::{Duration, Utc};
@@ -203,6 +485,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -219,6 +508,7 @@ mod tests {
             address: "0x".to_string(),
             notification_id: None,
             custom_rpc_urls: Some(vec![RpcConfig::new("https://test-rpc-url".to_string())]),
+            signer_rotations: vec![],
         }
     }
 
@@ -307,6 +597,114 @@ mod tests {
         assert!(validate_target_address(&request, &relayer).is_ok());
     }
 
+    fn create_transfer_allowlist() -> HashMap<String, Vec<EvmSelectorRule>> {
+        HashMap::from([(
+            "0x7F8D6A2BA539031E3C18C9528B0DC3A7F603A93B".to_string(),
+            vec![EvmSelectorRule {
+                selector: "0xa9059cbb".to_string(),
+                arguments: Some(vec![
+                    EvmArgumentConstraint {
+                        index: 0,
+                        max_value: None,
+                        equals: Some("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string()),
+                    },
+                    EvmArgumentConstraint {
+                        index: 1,
+                        max_value: Some(1_000),
+                        equals: None,
+                    },
+                ]),
+            }],
+        )])
+    }
+
+    fn encode_transfer(amount: u64) -> String {
+        format!(
+            "0xa9059cbb000000000000000000000000742d35cc6634c0532925a3b844bc454e4438f44e{:064x}",
+            amount
+        )
+    }
+
+    #[test]
+    fn test_validate_allowed_selectors() {
+        let allowlist = create_transfer_allowlist();
+        let mut request = create_basic_request();
+        request.to = Some("0x7f8d6a2ba539031e3c18c9528b0dc3a7f603a93b".to_string());
+
+        request.data = Some(encode_transfer(1_000));
+        assert!(validate_allowed_selectors(&request, &allowlist).is_ok());
+
+        // Plain transfers carry no call data and are not restricted
+        request.data = Some("0x".to_string());
+        assert!(validate_allowed_selectors(&request, &allowlist).is_ok());
+
+        // approve(address,uint256) is not allowed
+        request.data = Some(encode_transfer(1).replacen("a9059cbb", "095ea7b3", 1));
+        let result = validate_allowed_selectors(&request, &allowlist);
+        assert!(matches!(result, Err(ApiError::BadRequest(ref msg)) if msg.contains("0x095ea7b3")));
+
+        request.data = Some(encode_transfer(1_001));
+        let result = validate_allowed_selectors(&request, &allowlist);
+        assert!(matches!(result, Err(ApiError::BadRequest(ref msg)) if msg.contains("maximum")));
+
+        request.data = Some(encode_transfer(1).replace("742d35cc", "00000000"));
+        let result = validate_allowed_selectors(&request, &allowlist);
+        assert!(matches!(result, Err(ApiError::BadRequest(ref msg)) if msg.contains("must be")));
+
+        request.data = Some("0xa9059cbb".to_string());
+        let result = validate_allowed_selectors(&request, &allowlist);
+        assert!(matches!(result, Err(ApiError::BadRequest(ref msg)) if msg.contains("missing")));
+
+        request.to = Some("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string());
+        request.data = Some(encode_transfer(1));
+        let result = validate_allowed_selectors(&request, &allowlist);
+        assert!(
+            matches!(result, Err(ApiError::BadRequest(ref msg)) if msg.contains("not in the selector allowlist"))
+        );
+
+        request.to = None;
+        assert!(validate_allowed_selectors(&request, &allowlist).is_err());
+    }
+
+    #[test]
+    fn test_validate_target_address_enforces_allowed_selectors() {
+        let mut request = create_basic_request();
+        request.to = Some("0x7f8d6a2ba539031e3c18c9528b0dc3a7f603a93b".to_string());
+        request.data = Some(encode_transfer(5_000));
+        let mut relayer = create_test_relayer(false, false);
+        assert!(validate_target_address(&request, &relayer).is_ok());
+
+        if let RelayerNetworkPolicy::Evm(ref mut evm_policy) = relayer.policies {
+            evm_policy.allowed_selectors = Some(create_transfer_allowlist());
+        }
+        assert!(validate_target_address(&request, &relayer).is_err());
+    }
+
+    #[test]
+    fn test_validate_spending_limits() {
+        let mut request = create_basic_request();
+        request.value = U256::from(1_000u64);
+        let mut relayer = create_test_relayer(false, false);
+        assert!(validate_spending_limits(&request, &relayer).is_ok());
+
+        if let RelayerNetworkPolicy::Evm(ref mut evm_policy) = relayer.policies {
+            evm_policy.max_value_per_transaction = Some(1_000);
+            evm_policy.max_total_value = Some(5_000);
+        }
+        assert!(validate_spending_limits(&request, &relayer).is_ok());
+
+        request.value = U256::from(1_001u64);
+        let result = validate_spending_limits(&request, &relayer);
+        assert!(matches!(result, Err(ApiError::SpendingLimitExceeded(_))));
+
+        if let RelayerNetworkPolicy::Evm(ref mut evm_policy) = relayer.policies {
+            evm_policy.max_value_per_transaction = None;
+        }
+        request.value = U256::from(5_001u64);
+        let result = validate_spending_limits(&request, &relayer);
+        assert!(matches!(result, Err(ApiError::SpendingLimitExceeded(_))));
+    }
+
     #[test]
     fn test_validate_evm_transaction_request_gas_limit_too_low() {
         let mut request = create_basic_request();
@@ -571,4 +969,117 @@ mod tests {
             "validation should pass when gas_limit is provided even when estimation is enabled"
         );
     }
+
+    fn create_authorization_request() -> EvmAuthorizationRequest {
+        EvmAuthorizationRequest {
+            chain_id: Some(1),
+            address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44f".to_string(),
+            nonce: Some(0),
+            y_parity: Some(1),
+            r: Some("0x1".to_string()),
+            s: Some("0x2".to_string()),
+            signer_id: None,
+        }
+    }
+
+    fn create_eip7702_request(authorization: EvmAuthorizationRequest) -> EvmTransactionRequest {
+        EvmTransactionRequest {
+            gas_price: None,
+            speed: Some(Speed::Fast),
+            authorization_list: Some(vec![authorization]),
+            ..create_basic_request()
+        }
+    }
+
+    #[test]
+    fn test_validate_authorization_list_signed() {
+        let request = create_eip7702_request(create_authorization_request());
+        assert!(validate_authorization_list(&request).is_ok());
+    }
+
+    #[test]
+    fn test_validate_authorization_list_signer_id() {
+        let request = create_eip7702_request(EvmAuthorizationRequest {
+            nonce: None,
+            y_parity: None,
+            r: None,
+            s: None,
+            signer_id: Some("delegator".to_string()),
+            ..create_authorization_request()
+        });
+        assert!(validate_authorization_list(&request).is_ok());
+    }
+
+    #[test]
+    fn test_validate_authorization_list_rejects_partial_signature() {
+        let request = create_eip7702_request(EvmAuthorizationRequest {
+            s: None,
+            ..create_authorization_request()
+        });
+        assert!(matches!(
+            validate_authorization_list(&request),
+            Err(ApiError::BadRequest(_))
+        ));
+
+        let request = create_eip7702_request(EvmAuthorizationRequest {
+            signer_id: Some("delegator".to_string()),
+            ..create_authorization_request()
+        });
+        assert!(matches!(
+            validate_authorization_list(&request),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_authorization_list_requires_eip1559_call() {
+        let mut request = create_eip7702_request(create_authorization_request());
+        request.to = None;
+        assert!(validate_authorization_list(&request).is_err());
+
+        let mut request = create_eip7702_request(create_authorization_request());
+        request.gas_price = Some(1);
+        assert!(validate_authorization_list(&request).is_err());
+
+        let mut request = create_eip7702_request(create_authorization_request());
+        request.authorization_list = Some(vec![]);
+        assert!(validate_authorization_list(&request).is_err());
+    }
+
+    #[test]
+    fn test_validate_authorization_list_invalid_address() {
+        let request = create_eip7702_request(EvmAuthorizationRequest {
+            address: "invalid".to_string(),
+            ..create_authorization_request()
+        });
+        assert!(validate_authorization_list(&request).is_err());
+    }
+
+    #[test]
+    fn test_validate_access_list() {
+        let mut request = create_basic_request();
+        request.access_list = Some(vec![EvmAccessListItem {
+            address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            storage_keys: vec![format!("0x{:064x}", 1)],
+        }]);
+        assert!(validate_access_list(&request).is_ok());
+
+        request.access_list = Some(vec![]);
+        assert!(validate_access_list(&request).is_err());
+
+        request.access_list = Some(vec![EvmAccessListItem {
+            address: "invalid".to_string(),
+            storage_keys: vec![],
+        }]);
+        assert!(validate_access_list(&request).is_err());
+
+        request.access_list = Some(vec![EvmAccessListItem {
+            address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            storage_keys: vec!["0x01".to_string()],
+        }]);
+        assert!(matches!(
+            validate_access_list(&request),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
 }
@@ -0,0 +1,176 @@
+use super::evm::{validate_spending_limits, validate_target_address};
+use crate::models::{evm::Speed, ApiError, EvmTransactionRequest, RelayerRepoModel, U256};
+use alloy::primitives::{Address, Bytes};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use utoipa::ToSchema;
+
+/// Length of an ECDSA signature over a forward request
+const FORWARD_REQUEST_SIGNATURE_LENGTH: usize = 65;
+
+/// ERC-2771 request signed by an end user for the relayer's trusted forwarder
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EvmForwardRequest {
+    /// End user that signed the request
+    pub from: String,
+    /// Contract the forwarder calls on behalf of `from`
+    pub to: String,
+    #[schema(value_type = u128, format = "u128")]
+    pub value: U256,
+    /// Gas forwarded to the call to `to`
+    pub gas: u64,
+    /// Forwarder nonce of `from`
+    #[schema(value_type = u128, format = "u128")]
+    pub nonce: U256,
+    pub data: String,
+}
+
+/// Request to relay a signed ERC-2771 forward request through the trusted forwarder
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ForwardTransactionRequest {
+    pub request: EvmForwardRequest,
+    /// The end user's signature over `request`
+    pub signature: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub speed: Option<Speed>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub valid_until: Option<String>,
+}
+
+impl ForwardTransactionRequest {
+    /// Validates the request and checks the forwarded call against the relayer's receiver
+    /// whitelist, selector allowlist and per-transaction spending limits.
+    pub fn validate(&self, relayer: &RelayerRepoModel) -> Result<(), ApiError> {
+        for (field, address) in [("from", &self.request.from), ("to", &self.request.to)] {
+            Address::from_str(address).map_err(|_| {
+                ApiError::BadRequest(format!("Invalid forward request {} address", field))
+            })?;
+        }
+        Bytes::from_str(&self.request.data)
+            .map_err(|_| ApiError::BadRequest("Invalid forward request data".to_string()))?;
+
+        let signature = Bytes::from_str(&self.signature)
+            .map_err(|_| ApiError::BadRequest("Invalid forward request signature".to_string()))?;
+        if signature.len() != FORWARD_REQUEST_SIGNATURE_LENGTH {
+            return Err(ApiError::BadRequest(format!(
+                "Forward request signature must be {} bytes",
+                FORWARD_REQUEST_SIGNATURE_LENGTH
+            )));
+        }
+
+        let call = self.forwarded_call();
+        validate_target_address(&call, relayer)?;
+        validate_spending_limits(&call, relayer)
+    }
+
+    /// Returns the call the forwarder makes on behalf of the end user, as a transaction
+    /// request, so it can be checked against the relayer's policies.
+    pub fn forwarded_call(&self) -> EvmTransactionRequest {
+        EvmTransactionRequest {
+            to: Some(self.request.to.clone()),
+            value: self.request.value,
+            data: Some(self.request.data.clone()),
+            gas_limit: Some(self.request.gas),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RelayerEvmPolicy, RelayerNetworkPolicy};
+    use serde_json::json;
+
+    fn create_relayer(policy: RelayerEvmPolicy) -> RelayerRepoModel {
+        RelayerRepoModel {
+            policies: RelayerNetworkPolicy::Evm(policy),
+            ..Default::default()
+        }
+    }
+
+    fn create_request() -> ForwardTransactionRequest {
+        serde_json::from_value(json!({
+            "request": {
+                "from": "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+                "to": "0x5FbDB2315678afecb367f032d93F642f64180aa3",
+                "value": 0,
+                "gas": 100000,
+                "nonce": 3,
+                "data": "0xa9059cbb"
+            },
+            "signature": format!("0x{}", "11".repeat(65)),
+            "speed": "fast"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate_forward_request() {
+        let request = create_request();
+
+        assert!(request
+            .validate(&create_relayer(RelayerEvmPolicy::default()))
+            .is_ok());
+        assert_eq!(request.request.nonce, U256::from(3));
+        assert_eq!(request.speed, Some(Speed::Fast));
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_fields() {
+        let relayer = create_relayer(RelayerEvmPolicy::default());
+
+        let mut request = create_request();
+        request.request.to = "not-an-address".to_string();
+        assert!(request.validate(&relayer).is_err());
+
+        let mut request = create_request();
+        request.request.data = "0xzz".to_string();
+        assert!(request.validate(&relayer).is_err());
+
+        let mut request = create_request();
+        request.signature = "0x1234".to_string();
+        assert!(request.validate(&relayer).is_err());
+    }
+
+    #[test]
+    fn test_validate_applies_relayer_policy() {
+        let request = create_request();
+
+        let relayer = create_relayer(RelayerEvmPolicy {
+            whitelist_receivers: Some(vec![
+                "0xe7f1725E7734CE288F8367e1Bb143E90bb3F0512".to_string()
+            ]),
+            ..Default::default()
+        });
+        assert!(matches!(
+            request.validate(&relayer),
+            Err(ApiError::BadRequest(_))
+        ));
+
+        let mut request = create_request();
+        request.request.value = U256::from(1000);
+        let relayer = create_relayer(RelayerEvmPolicy {
+            max_value_per_transaction: Some(100),
+            ..Default::default()
+        });
+        assert!(matches!(
+            request.validate(&relayer),
+            Err(ApiError::SpendingLimitExceeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_forwarded_call() {
+        let request = create_request();
+        let call = request.forwarded_call();
+
+        assert_eq!(call.to, Some(request.request.to.clone()));
+        assert_eq!(call.data, Some("0xa9059cbb".to_string()));
+        assert_eq!(call.gas_limit, Some(100000));
+    }
+}
@@ -1,11 +1,21 @@
+pub mod batch;
 pub mod evm;
+pub mod forward;
 pub mod solana;
 pub mod stellar;
 
-use crate::models::{ApiError, NetworkType, RelayerRepoModel};
+use crate::{
+    constants::{MAX_IDEMPOTENCY_KEY_LENGTH, MAX_TRANSACTION_DEPENDENCIES},
+    models::{ApiError, NetworkType, RelayerRepoModel},
+};
+use apalis_cron::Schedule;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::str::FromStr;
 
-pub use evm::EvmTransactionRequest;
+pub use batch::BatchTransactionRequest;
+pub use evm::{EvmAuthorizationRequest, EvmTransactionRequest};
+pub use forward::{EvmForwardRequest, ForwardTransactionRequest};
 pub use solana::SolanaTransactionRequest;
 pub use stellar::StellarTransactionRequest;
 use utoipa::ToSchema;
@@ -27,20 +37,298 @@ impl NetworkTransactionRequest {
             NetworkType::Evm => Ok(Self::Evm(
                 serde_json::from_value(json).map_err(|e| ApiError::BadRequest(e.to_string()))?,
             )),
-            NetworkType::Solana => Ok(Self::Solana(
-                serde_json::from_value(json).map_err(|e| ApiError::BadRequest(e.to_string()))?,
-            )),
+            NetworkType::Solana => {
+                // A Solana transaction expires with its blockhash, so it cannot be delayed
+                if ["execute_after", "schedule"]
+                    .iter()
+                    .any(|field| json.get(field).is_some_and(|value| !value.is_null()))
+                {
+                    return Err(ApiError::BadRequest(
+                        "Solana transactions do not support execute_after or schedule".to_string(),
+                    ));
+                }
+                Ok(Self::Solana(
+                    serde_json::from_value(json)
+                        .map_err(|e| ApiError::BadRequest(e.to_string()))?,
+                ))
+            }
             NetworkType::Stellar => Ok(Self::Stellar(
                 serde_json::from_value(json).map_err(|e| ApiError::BadRequest(e.to_string()))?,
             )),
         }
     }
 
+    /// Returns the idempotency key supplied with the request, if any.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        match self {
+            NetworkTransactionRequest::Evm(request) => request.idempotency_key.as_deref(),
+            NetworkTransactionRequest::Solana(request) => request.idempotency_key.as_deref(),
+            NetworkTransactionRequest::Stellar(request) => request.idempotency_key.as_deref(),
+        }
+    }
+
+    /// Sets the idempotency key, replacing any key supplied in the request body.
+    pub fn set_idempotency_key(&mut self, key: String) {
+        match self {
+            NetworkTransactionRequest::Evm(request) => request.idempotency_key = Some(key),
+            NetworkTransactionRequest::Solana(request) => request.idempotency_key = Some(key),
+            NetworkTransactionRequest::Stellar(request) => request.idempotency_key = Some(key),
+        }
+    }
+
+    /// Returns the time before which the transaction is not sent, if any.
+    pub fn execute_after(&self) -> Option<&str> {
+        match self {
+            NetworkTransactionRequest::Evm(request) => request.execute_after.as_deref(),
+            NetworkTransactionRequest::Solana(_) => None,
+            NetworkTransactionRequest::Stellar(request) => request.execute_after.as_deref(),
+        }
+    }
+
+    /// Returns the cron expression on which the transaction recurs, if any.
+    pub fn schedule(&self) -> Option<&str> {
+        match self {
+            NetworkTransactionRequest::Evm(request) => request.schedule.as_deref(),
+            NetworkTransactionRequest::Solana(_) => None,
+            NetworkTransactionRequest::Stellar(request) => request.schedule.as_deref(),
+        }
+    }
+
+    /// Returns the IDs of the transactions that must be confirmed first, if any.
+    pub fn depends_on(&self) -> Option<&[String]> {
+        match self {
+            NetworkTransactionRequest::Evm(request) => request.depends_on.as_deref(),
+            NetworkTransactionRequest::Solana(request) => request.depends_on.as_deref(),
+            NetworkTransactionRequest::Stellar(request) => request.depends_on.as_deref(),
+        }
+    }
+
+    /// Returns true if the transaction is delayed or recurring.
+    pub fn is_scheduled(&self) -> bool {
+        self.execute_after().is_some() || self.schedule().is_some()
+    }
+
+    /// Returns the time the transaction is first sent at, if it is scheduled.
+    ///
+    /// This is `execute_after` when set, otherwise the next occurrence of `schedule`.
+    pub fn first_execution(&self) -> Option<DateTime<Utc>> {
+        match self.execute_after() {
+            Some(execute_after) => DateTime::parse_from_rfc3339(execute_after)
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc)),
+            None => next_scheduled_execution(self.schedule()?, Utc::now()),
+        }
+    }
+
     pub fn validate(&self, relayer: &RelayerRepoModel) -> Result<(), ApiError> {
+        validate_schedule(self)?;
+        validate_dependencies(self)?;
+
+        if let Some(key) = self.idempotency_key() {
+            if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+                return Err(ApiError::BadRequest(format!(
+                    "Idempotency key must be between 1 and {} characters",
+                    MAX_IDEMPOTENCY_KEY_LENGTH
+                )));
+            }
+        }
+
         match self {
             NetworkTransactionRequest::Evm(request) => request.validate(relayer),
+            NetworkTransactionRequest::Solana(request) => request.validate(),
             NetworkTransactionRequest::Stellar(request) => request.validate(),
-            _ => Ok(()),
         }
     }
 }
+
+/// Returns the first occurrence of a cron `schedule` strictly after `after`.
+pub fn next_scheduled_execution(schedule: &str, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    Schedule::from_str(schedule).ok()?.after(&after).next()
+}
+
+/// Validates the `execute_after` and `schedule` fields of a request.
+fn validate_schedule(request: &NetworkTransactionRequest) -> Result<(), ApiError> {
+    if let Some(execute_after) = request.execute_after() {
+        let execute_after = DateTime::parse_from_rfc3339(execute_after).map_err(|_| {
+            ApiError::BadRequest("Invalid executeAfter datetime format".to_string())
+        })?;
+        if execute_after < Utc::now() {
+            return Err(ApiError::BadRequest(
+                "The executeAfter time cannot be in the past".to_string(),
+            ));
+        }
+    }
+
+    if let Some(schedule) = request.schedule() {
+        if Schedule::from_str(schedule).is_err() {
+            return Err(ApiError::BadRequest(
+                "Invalid schedule cron expression".to_string(),
+            ));
+        }
+        if next_scheduled_execution(schedule, Utc::now()).is_none() {
+            return Err(ApiError::BadRequest(
+                "The schedule has no future occurrence".to_string(),
+            ));
+        }
+    }
+
+    let valid_until = match request {
+        NetworkTransactionRequest::Evm(request) => request.valid_until.as_deref(),
+        NetworkTransactionRequest::Solana(_) => None,
+        NetworkTransactionRequest::Stellar(request) => request.valid_until.as_deref(),
+    };
+    if let Some(valid_until) = valid_until {
+        if request.schedule().is_some() {
+            return Err(ApiError::BadRequest(
+                "validUntil cannot be combined with a recurring schedule".to_string(),
+            ));
+        }
+        let first_execution = request.first_execution();
+        let valid_until = DateTime::parse_from_rfc3339(valid_until).ok();
+        if let (Some(first_execution), Some(valid_until)) = (first_execution, valid_until) {
+            if valid_until <= first_execution {
+                return Err(ApiError::BadRequest(
+                    "The validUntil time must be after the executeAfter time".to_string(),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the prerequisite transaction IDs of a request.
+fn validate_dependencies(request: &NetworkTransactionRequest) -> Result<(), ApiError> {
+    let Some(depends_on) = request.depends_on() else {
+        return Ok(());
+    };
+
+    if depends_on.len() > MAX_TRANSACTION_DEPENDENCIES {
+        return Err(ApiError::BadRequest(format!(
+            "A transaction cannot depend on more than {} transactions",
+            MAX_TRANSACTION_DEPENDENCIES
+        )));
+    }
+    if depends_on.iter().any(|id| id.is_empty()) {
+        return Err(ApiError::BadRequest(
+            "Prerequisite transaction IDs cannot be empty".to_string(),
+        ));
+    }
+    if depends_on
+        .iter()
+        .enumerate()
+        .any(|(index, id)| depends_on[..index].contains(id))
+    {
+        return Err(ApiError::BadRequest(
+            "Prerequisite transaction IDs must be unique".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn evm_request(
+        execute_after: Option<String>,
+        schedule: Option<&str>,
+        valid_until: Option<String>,
+    ) -> NetworkTransactionRequest {
+        NetworkTransactionRequest::Evm(EvmTransactionRequest {
+            to: Some("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string()),
+            valid_until,
+            execute_after,
+            schedule: schedule.map(String::from),
+            depends_on: None,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_validate_schedule_accepts_future_execution() {
+        let execute_after = (Utc::now() + Duration::hours(1)).to_rfc3339();
+        let valid_until = (Utc::now() + Duration::hours(2)).to_rfc3339();
+        let request = evm_request(Some(execute_after), None, Some(valid_until));
+
+        assert!(validate_schedule(&request).is_ok());
+        assert!(request.is_scheduled());
+    }
+
+    #[test]
+    fn test_validate_schedule_rejects_past_execute_after() {
+        let execute_after = (Utc::now() - Duration::hours(1)).to_rfc3339();
+        let request = evm_request(Some(execute_after), None, None);
+
+        assert!(validate_schedule(&request).is_err());
+        assert!(validate_schedule(&evm_request(Some("tomorrow".into()), None, None)).is_err());
+    }
+
+    #[test]
+    fn test_validate_schedule_rejects_invalid_cron() {
+        let request = evm_request(None, Some("every monday"), None);
+
+        assert!(validate_schedule(&request).is_err());
+        assert!(validate_schedule(&evm_request(None, Some("0 0 9 * * Mon"), None)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_schedule_rejects_valid_until_before_execution() {
+        let execute_after = (Utc::now() + Duration::hours(2)).to_rfc3339();
+        let valid_until = (Utc::now() + Duration::hours(1)).to_rfc3339();
+        let request = evm_request(Some(execute_after), None, Some(valid_until.clone()));
+        assert!(validate_schedule(&request).is_err());
+
+        let request = evm_request(None, Some("0 0 9 * * Mon"), Some(valid_until));
+        assert!(validate_schedule(&request).is_err());
+    }
+
+    #[test]
+    fn test_first_execution_uses_next_cron_occurrence() {
+        let request = evm_request(None, Some("0 0 * * * *"), None);
+        let first_execution = request.first_execution().unwrap();
+
+        assert!(first_execution > Utc::now());
+        assert!(first_execution <= Utc::now() + Duration::hours(1));
+        assert!(!evm_request(None, None, None).is_scheduled());
+    }
+
+    #[test]
+    fn test_from_json_rejects_scheduled_solana_transaction() {
+        for field in ["execute_after", "schedule"] {
+            let json = serde_json::json!({
+                "transaction": "AQAB",
+                field: "0 0 * * * *",
+            });
+
+            let result = NetworkTransactionRequest::from_json(&NetworkType::Solana, json);
+            assert!(
+                matches!(result, Err(ApiError::BadRequest(ref msg)) if msg.contains(field)),
+                "Expected {} to be rejected",
+                field
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_dependencies() {
+        let with_dependencies = |depends_on: Vec<&str>| {
+            NetworkTransactionRequest::Evm(EvmTransactionRequest {
+                depends_on: Some(depends_on.into_iter().map(String::from).collect()),
+                ..Default::default()
+            })
+        };
+
+        assert!(validate_dependencies(&with_dependencies(vec!["tx-1", "tx-2"])).is_ok());
+        assert!(validate_dependencies(&with_dependencies(vec!["tx-1", "tx-1"])).is_err());
+        assert!(validate_dependencies(&with_dependencies(vec![""])).is_err());
+
+        let too_many = (0..=MAX_TRANSACTION_DEPENDENCIES)
+            .map(|index| format!("tx-{}", index))
+            .collect::<Vec<_>>();
+        let request = with_dependencies(too_many.iter().map(String::as_str).collect());
+        assert!(validate_dependencies(&request).is_err());
+    }
+}
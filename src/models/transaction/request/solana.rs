@@ -1,8 +1,88 @@
-use crate::models::EncodedSerializedTransaction;
+use crate::models::{ApiError, EncodedSerializedTransaction};
 use serde::{Deserialize, Serialize};
+use solana_sdk::transaction::Transaction;
 use utoipa::ToSchema;
 
 #[derive(Deserialize, Serialize, ToSchema)]
 pub struct SolanaTransactionRequest {
     pub transaction: EncodedSerializedTransaction,
+    /// Key identifying retries of the same submission; repeats return the original transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, max_length = 255)]
+    pub idempotency_key: Option<String>,
+    /// Transactions that must be confirmed before this one is sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, max_items = 16)]
+    pub depends_on: Option<Vec<String>>,
+}
+
+impl SolanaTransactionRequest {
+    pub fn validate(&self) -> Result<(), ApiError> {
+        let transaction = Transaction::try_from(self.transaction.clone())
+            .map_err(|e| ApiError::BadRequest(format!("Invalid transaction: {}", e)))?;
+
+        if transaction.message.account_keys.is_empty() {
+            return Err(ApiError::BadRequest(
+                "Transaction must include a fee payer account".to_string(),
+            ));
+        }
+
+        if transaction.message.instructions.is_empty() {
+            return Err(ApiError::BadRequest(
+                "Transaction must include at least one instruction".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{hash::Hash, message::Message, pubkey::Pubkey};
+    use solana_system_interface::instruction as system_instruction;
+
+    fn encode(transaction: &Transaction) -> EncodedSerializedTransaction {
+        EncodedSerializedTransaction::try_from(transaction).unwrap()
+    }
+
+    #[test]
+    fn test_validate_valid_transaction() {
+        let fee_payer = Pubkey::new_unique();
+        let ix = system_instruction::transfer(&fee_payer, &Pubkey::new_unique(), 1000);
+        let message = Message::new_with_blockhash(&[ix], Some(&fee_payer), &Hash::new_unique());
+        let request = SolanaTransactionRequest {
+            transaction: encode(&Transaction::new_unsigned(message)),
+            idempotency_key: None,
+            depends_on: None,
+        };
+
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_invalid_encoding() {
+        let request = SolanaTransactionRequest {
+            transaction: EncodedSerializedTransaction::new("not-base64!".to_string()),
+            idempotency_key: None,
+            depends_on: None,
+        };
+
+        assert!(matches!(request.validate(), Err(ApiError::BadRequest(_))));
+    }
+
+    #[test]
+    fn test_validate_without_instructions() {
+        let fee_payer = Pubkey::new_unique();
+        let message = Message::new_with_blockhash(&[], Some(&fee_payer), &Hash::new_unique());
+        let request = SolanaTransactionRequest {
+            transaction: encode(&Transaction::new_unsigned(message)),
+            idempotency_key: None,
+            depends_on: None,
+        };
+
+        let result = request.validate();
+        assert!(matches!(result, Err(ApiError::BadRequest(msg)) if msg.contains("instruction")));
+    }
 }
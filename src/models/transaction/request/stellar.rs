@@ -25,6 +25,22 @@ pub struct StellarTransactionRequest {
     /// Maximum fee in stroops (defaults to 0.1 XLM = 1,000,000 stroops)
     #[schema(nullable = true)]
     pub max_fee: Option<i64>,
+    /// Key identifying retries of the same submission; repeats return the original transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, max_length = 255)]
+    pub idempotency_key: Option<String>,
+    /// RFC 3339 time before which the transaction is not sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub execute_after: Option<String>,
+    /// Cron expression (with seconds) on which the transaction is sent again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub schedule: Option<String>,
+    /// Transactions that must be confirmed before this one is sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false, max_items = 16)]
+    pub depends_on: Option<Vec<String>>,
 }
 
 impl StellarTransactionRequest {
@@ -107,6 +123,10 @@ mod tests {
             transaction_xdr: Some("AAAAA...".to_string()),
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = req.validate();
@@ -130,6 +150,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = req.validate();
@@ -157,6 +181,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: Some(true),
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = req.validate();
@@ -180,6 +208,10 @@ mod tests {
             transaction_xdr: Some("AAAAA...".to_string()),
             fee_bump: Some(true),
             max_fee: Some(10000000),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = req.validate();
@@ -203,6 +235,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = req.validate();
@@ -222,6 +258,10 @@ mod tests {
             transaction_xdr: Some("AAAAA...".to_string()),
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let result = req.validate();
@@ -241,6 +281,10 @@ mod tests {
             transaction_xdr: None,
             fee_bump: None,
             max_fee: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         assert_eq!(
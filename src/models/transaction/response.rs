@@ -1,7 +1,7 @@
 use crate::{
     models::{
-        evm::Speed, EvmTransactionDataSignature, NetworkTransactionData, TransactionRepoModel,
-        TransactionStatus, U256,
+        evm::Speed, EvmAccessListItem, EvmAuthorization, EvmTransactionDataSignature,
+        EvmTransactionLog, NetworkTransactionData, TransactionRepoModel, TransactionStatus, U256,
     },
     utils::{deserialize_optional_u128, deserialize_optional_u64, serialize_optional_u128},
 };
@@ -64,6 +64,54 @@ pub struct EvmTransactionResponse {
     pub max_priority_fee_per_gas: Option<u128>,
     pub signature: Option<EvmTransactionDataSignature>,
     pub speed: Option<Speed>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub authorization_list: Option<Vec<EvmAuthorization>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub access_list: Option<Vec<EvmAccessListItem>>,
+    /// Hashes of the ERC-4337 user operations carried by a bundler transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub user_operation_hashes: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub block_number: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub block_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub gas_used: Option<u64>,
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(nullable = false)]
+    pub effective_gas_price: Option<u128>,
+    /// L1 data fee charged on OP-stack networks
+    #[serde(
+        serialize_with = "serialize_optional_u128",
+        deserialize_with = "deserialize_optional_u128",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(nullable = false)]
+    pub l1_fee: Option<u128>,
+    /// Logs emitted by the mined transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub logs: Option<Vec<EvmTransactionLog>>,
+    /// Time before which the transaction is not sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub execute_after: Option<String>,
+    /// Cron expression on which the transaction recurs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub schedule: Option<String>,
 }
 
 #[derive(Debug, Serialize, Clone, PartialEq, Deserialize, ToSchema)]
@@ -97,6 +145,14 @@ pub struct StellarTransactionResponse {
     pub source_account: String,
     pub fee: u32,
     pub sequence_number: i64,
+    /// Time before which the transaction is not sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub execute_after: Option<String>,
+    /// Cron expression on which the transaction recurs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub schedule: Option<String>,
 }
 
 impl From<TransactionRepoModel> for TransactionResponse {
@@ -123,6 +179,17 @@ impl From<TransactionRepoModel> for TransactionResponse {
                     max_priority_fee_per_gas: evm_data.max_priority_fee_per_gas,
                     signature: evm_data.signature,
                     speed: evm_data.speed,
+                    authorization_list: evm_data.authorization_list,
+                    access_list: evm_data.access_list,
+                    user_operation_hashes: evm_data.user_operation_hashes,
+                    block_number: evm_data.block_number,
+                    block_hash: evm_data.block_hash,
+                    gas_used: evm_data.gas_used,
+                    effective_gas_price: evm_data.effective_gas_price,
+                    l1_fee: evm_data.l1_fee,
+                    logs: evm_data.logs,
+                    execute_after: model.execute_after,
+                    schedule: model.schedule,
                 }))
             }
             NetworkTransactionData::Solana(solana_data) => {
@@ -149,12 +216,69 @@ impl From<TransactionRepoModel> for TransactionResponse {
                     source_account: stellar_data.source_account,
                     fee: stellar_data.fee.unwrap_or(0),
                     sequence_number: stellar_data.sequence_number.unwrap_or(0),
+                    execute_after: model.execute_after,
+                    schedule: model.schedule,
                 }))
             }
         }
     }
 }
 
+/// Outcome of a single transaction in a batch submission
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct BatchTransactionResult {
+    /// Position of the transaction in the submitted batch
+    pub index: usize,
+    /// ID of the created transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub id: Option<String>,
+    /// Reason the transaction was not created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(nullable = false)]
+    pub error: Option<String>,
+}
+
+impl BatchTransactionResult {
+    pub fn created(index: usize, id: String) -> Self {
+        Self {
+            index,
+            id: Some(id),
+            error: None,
+        }
+    }
+
+    pub fn failed(index: usize, error: impl Into<String>) -> Self {
+        Self {
+            index,
+            id: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Result of a batch transaction submission
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct BatchTransactionResponse {
+    /// Number of transactions created
+    pub created: usize,
+    /// Number of transactions rejected
+    pub failed: usize,
+    /// Per-transaction outcome, in request order
+    pub results: Vec<BatchTransactionResult>,
+}
+
+impl From<Vec<BatchTransactionResult>> for BatchTransactionResponse {
+    fn from(results: Vec<BatchTransactionResult>) -> Self {
+        let failed = results.iter().filter(|r| r.error.is_some()).count();
+        Self {
+            created: results.len() - failed,
+            failed,
+            results,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,11 +316,25 @@ mod tests {
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             valid_until: None,
             network_type: NetworkType::Evm,
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -243,6 +381,10 @@ mod tests {
             network_type: NetworkType::Solana,
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -292,6 +434,10 @@ mod tests {
             network_type: NetworkType::Stellar,
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -346,6 +492,10 @@ mod tests {
             network_type: NetworkType::Stellar,
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -388,6 +538,10 @@ mod tests {
             network_type: NetworkType::Solana,
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         };
 
@@ -401,4 +555,21 @@ mod tests {
             _ => panic!("Expected SolanaTransactionResponse"),
         }
     }
+
+    #[test]
+    fn test_batch_transaction_response_counts() {
+        let response = BatchTransactionResponse::from(vec![
+            BatchTransactionResult::created(0, "tx-1".to_string()),
+            BatchTransactionResult::failed(1, "Bad Request: invalid address"),
+            BatchTransactionResult::created(2, "tx-2".to_string()),
+        ]);
+
+        assert_eq!(response.created, 2);
+        assert_eq!(response.failed, 1);
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["results"][0]["id"], "tx-1");
+        assert!(json["results"][0].get("error").is_none());
+        assert!(json["results"][1].get("id").is_none());
+    }
 }
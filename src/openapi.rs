@@ -1,6 +1,9 @@
 use crate::{
     api::routes::{
-        docs::{notification_docs, plugin_docs, relayer_docs, signer_docs},
+        docs::{
+            api_key_docs, network_docs, notification_docs, plugin_docs, relayer_docs,
+            relayer_pool_docs, signer_docs,
+        },
         health, metrics,
     },
     domain, models,
@@ -33,8 +36,11 @@ impl Modify for SecurityAddon {
     tags(
       (name = "Relayers", description = "Relayers are the core components of the OpenZeppelin Relayer API. They are responsible for executing transactions on behalf of users and providing a secure and reliable way to interact with the blockchain."),
       (name = "Plugins", description = "Plugins are TypeScript functions that can be used to extend the OpenZeppelin Relayer API functionality."),
+      (name = "Networks", description = "Networks define the blockchains that relayers can operate on, including their RPC endpoints and chain settings."),
       (name = "Notifications", description = "Notifications are responsible for showing the notifications related to the relayers."),
       (name = "Signers", description = "Signers are responsible for signing the transactions related to the relayers."),
+      (name = "Relayer Pools", description = "Relayer pools group relayers operating on the same network and route each submitted transaction to the least loaded member."),
+      (name = "API Keys", description = "API keys authenticate requests to the OpenZeppelin Relayer API. Each key carries scopes and can be limited to a set of relayers."),
       (name = "Metrics", description = "Metrics are responsible for showing the metrics related to the relayers."),
       (name = "Health", description = "Health is responsible for showing the health of the relayers.")
     ),
@@ -58,6 +64,7 @@ impl Modify for SecurityAddon {
         relayer_docs::doc_get_transaction_by_id,
         relayer_docs::doc_list_transactions,
         relayer_docs::doc_get_relayer_status,
+        relayer_docs::doc_get_relayer_nonce_health,
         relayer_docs::doc_sign_typed_data,
         relayer_docs::doc_sign,
         relayer_docs::doc_sign_transaction,
@@ -65,12 +72,21 @@ impl Modify for SecurityAddon {
         relayer_docs::doc_delete_pending_transactions,
         relayer_docs::doc_rpc,
         relayer_docs::doc_send_transaction,
+        relayer_docs::doc_send_transaction_batch,
+        relayer_docs::doc_send_forward_request,
         relayer_docs::doc_replace_transaction,
+        relayer_docs::doc_rotate_relayer_signer,
+        relayer_docs::doc_list_signer_rotations,
         health::health,
         metrics::list_metrics,
         metrics::metric_detail,
         metrics::scrape_metrics,
         plugin_docs::doc_call_plugin,
+        network_docs::doc_list_networks,
+        network_docs::doc_get_network,
+        network_docs::doc_create_network,
+        network_docs::doc_update_network,
+        network_docs::doc_delete_network,
         notification_docs::doc_list_notifications,
         notification_docs::doc_get_notification,
         notification_docs::doc_create_notification,
@@ -81,6 +97,16 @@ impl Modify for SecurityAddon {
         signer_docs::doc_create_signer,
         signer_docs::doc_update_signer,
         signer_docs::doc_delete_signer,
+        api_key_docs::doc_list_api_keys,
+        api_key_docs::doc_create_api_key,
+        api_key_docs::doc_delete_api_key,
+        relayer_pool_docs::doc_list_relayer_pools,
+        relayer_pool_docs::doc_get_relayer_pool,
+        relayer_pool_docs::doc_create_relayer_pool,
+        relayer_pool_docs::doc_update_relayer_pool,
+        relayer_pool_docs::doc_delete_relayer_pool,
+        relayer_pool_docs::doc_get_relayer_pool_status,
+        relayer_pool_docs::doc_send_pool_transaction,
     ),
     components(schemas(
         models::RelayerResponse,
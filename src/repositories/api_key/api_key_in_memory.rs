@@ -0,0 +1,275 @@
+//! This module defines an in-memory repository for managing API key models.
+//! It provides asynchronous CRUD operations and supports pagination.
+//! The repository is thread-safe, using a `Mutex` to protect access to the underlying data store.
+use crate::{
+    models::{ApiKeyRepoModel, RepositoryError, SecretString},
+    repositories::*,
+};
+use async_trait::async_trait;
+use eyre::Result;
+use std::collections::HashMap;
+use tokio::sync::{Mutex, MutexGuard};
+
+#[derive(Debug)]
+pub struct InMemoryApiKeyRepository {
+    store: Mutex<HashMap<String, ApiKeyRepoModel>>,
+}
+
+impl Clone for InMemoryApiKeyRepository {
+    fn clone(&self) -> Self {
+        // Try to get the current data, or use empty HashMap if lock fails
+        let data = self
+            .store
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
+
+        Self {
+            store: Mutex::new(data),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl InMemoryApiKeyRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn acquire_lock<T>(lock: &Mutex<T>) -> Result<MutexGuard<T>, RepositoryError> {
+        Ok(lock.lock().await)
+    }
+}
+
+impl Default for InMemoryApiKeyRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Repository<ApiKeyRepoModel, String> for InMemoryApiKeyRepository {
+    async fn create(&self, api_key: ApiKeyRepoModel) -> Result<ApiKeyRepoModel, RepositoryError> {
+        let mut store: MutexGuard<'_, HashMap<String, ApiKeyRepoModel>> =
+            Self::acquire_lock(&self.store).await?;
+        if store.contains_key(&api_key.id) {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "API key with ID {} already exists",
+                api_key.id
+            )));
+        }
+        store.insert(api_key.id.clone(), api_key.clone());
+        Ok(api_key)
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<ApiKeyRepoModel, RepositoryError> {
+        let store: MutexGuard<'_, HashMap<String, ApiKeyRepoModel>> =
+            Self::acquire_lock(&self.store).await?;
+        match store.get(&id) {
+            Some(api_key) => Ok(api_key.clone()),
+            None => Err(RepositoryError::NotFound(format!(
+                "API key with ID {} not found",
+                id
+            ))),
+        }
+    }
+
+    #[allow(clippy::map_entry)]
+    async fn update(
+        &self,
+        id: String,
+        api_key: ApiKeyRepoModel,
+    ) -> Result<ApiKeyRepoModel, RepositoryError> {
+        let mut store: MutexGuard<'_, HashMap<String, ApiKeyRepoModel>> =
+            Self::acquire_lock(&self.store).await?;
+        if !store.contains_key(&id) {
+            return Err(RepositoryError::NotFound(format!(
+                "API key with ID {} not found",
+                id
+            )));
+        }
+        store.insert(id, api_key.clone());
+        Ok(api_key)
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        let mut store: MutexGuard<'_, HashMap<String, ApiKeyRepoModel>> =
+            Self::acquire_lock(&self.store).await?;
+        if !store.contains_key(&id) {
+            return Err(RepositoryError::NotFound(format!(
+                "API key with ID {} not found",
+                id
+            )));
+        }
+        store.remove(&id);
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<ApiKeyRepoModel>, RepositoryError> {
+        let store: MutexGuard<'_, HashMap<String, ApiKeyRepoModel>> =
+            Self::acquire_lock(&self.store).await?;
+        let api_keys: Vec<ApiKeyRepoModel> = store.values().cloned().collect();
+        Ok(api_keys)
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<ApiKeyRepoModel>, RepositoryError> {
+        let total = self.count().await?;
+        let start = ((query.page - 1) * query.per_page) as usize;
+        let items: Vec<ApiKeyRepoModel> = self
+            .store
+            .lock()
+            .await
+            .values()
+            .skip(start)
+            .take(query.per_page as usize)
+            .cloned()
+            .collect();
+
+        Ok(PaginatedResult {
+            items,
+            total: total as u64,
+            page: query.page,
+            per_page: query.per_page,
+        })
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        let store: MutexGuard<'_, HashMap<String, ApiKeyRepoModel>> =
+            Self::acquire_lock(&self.store).await?;
+        let length = store.len();
+        Ok(length)
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(!store.is_empty())
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        store.clear();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn get_by_value(
+        &self,
+        value: &SecretString,
+    ) -> Result<Option<ApiKeyRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(store
+            .values()
+            .find(|api_key| &api_key.value == value)
+            .cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::ApiKeyScope;
+
+    use super::*;
+
+    fn create_test_api_key(id: &str, value: &str) -> ApiKeyRepoModel {
+        ApiKeyRepoModel {
+            id: id.to_string(),
+            name: format!("{}-name", id),
+            value: SecretString::new(value),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec![],
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_new_repository_is_empty() {
+        let repo = InMemoryApiKeyRepository::new();
+        assert_eq!(repo.count().await.unwrap(), 0);
+        assert!(!repo.has_entries().await.unwrap());
+    }
+
+    #[actix_web::test]
+    async fn test_create_and_get_api_key() {
+        let repo = InMemoryApiKeyRepository::new();
+        let api_key = create_test_api_key("key-1", "value-1");
+
+        repo.create(api_key.clone()).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 1);
+
+        let stored = repo.get_by_id("key-1".to_string()).await.unwrap();
+        assert_eq!(stored, api_key);
+    }
+
+    #[actix_web::test]
+    async fn test_create_duplicate_api_key() {
+        let repo = InMemoryApiKeyRepository::new();
+        let api_key = create_test_api_key("key-1", "value-1");
+
+        repo.create(api_key.clone()).await.unwrap();
+        let result = repo.create(api_key).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_delete_api_key() {
+        let repo = InMemoryApiKeyRepository::new();
+        repo.create(create_test_api_key("key-1", "value-1"))
+            .await
+            .unwrap();
+
+        repo.delete_by_id("key-1".to_string()).await.unwrap();
+        assert!(matches!(
+            repo.get_by_id("key-1".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+        assert!(matches!(
+            repo.delete_by_id("key-1".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_get_by_value() {
+        let repo = InMemoryApiKeyRepository::new();
+        repo.create(create_test_api_key("key-1", "value-1"))
+            .await
+            .unwrap();
+        repo.create(create_test_api_key("key-2", "value-2"))
+            .await
+            .unwrap();
+
+        let found = repo
+            .get_by_value(&SecretString::new("value-2"))
+            .await
+            .unwrap();
+        assert_eq!(found.map(|k| k.id), Some("key-2".to_string()));
+
+        let missing = repo
+            .get_by_value(&SecretString::new("unknown"))
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_drop_all_entries() {
+        let repo = InMemoryApiKeyRepository::new();
+        repo.create(create_test_api_key("key-1", "value-1"))
+            .await
+            .unwrap();
+        assert!(repo.has_entries().await.unwrap());
+
+        repo.drop_all_entries().await.unwrap();
+        assert!(!repo.has_entries().await.unwrap());
+    }
+}
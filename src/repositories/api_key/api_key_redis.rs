@@ -0,0 +1,570 @@
+//! Redis-backed implementation of the API key repository.
+
+use crate::models::{ApiKeyRepoModel, RepositoryError, SecretString};
+use crate::repositories::redis_base::RedisRepository;
+use crate::repositories::*;
+use async_trait::async_trait;
+use log::{debug, error, warn};
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, RedisError};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::sync::Arc;
+
+const API_KEY_PREFIX: &str = "api_key";
+const API_KEY_LIST_KEY: &str = "api_key_list";
+const API_KEY_LOOKUP_PREFIX: &str = "api_key_lookup";
+
+#[derive(Clone)]
+pub struct RedisApiKeyRepository {
+    pub client: Arc<ConnectionManager>,
+    pub key_prefix: String,
+}
+
+impl RedisRepository for RedisApiKeyRepository {}
+
+impl RedisApiKeyRepository {
+    pub fn new(
+        connection_manager: Arc<ConnectionManager>,
+        key_prefix: String,
+    ) -> Result<Self, RepositoryError> {
+        if key_prefix.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Redis key prefix cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            client: connection_manager,
+            key_prefix,
+        })
+    }
+
+    fn entry_key(&self, id: &str) -> String {
+        format!("{}:{}:{}", self.key_prefix, API_KEY_PREFIX, id)
+    }
+
+    fn api_key_list_key(&self) -> String {
+        format!("{}:{}", self.key_prefix, API_KEY_LIST_KEY)
+    }
+
+    /// Key of the index entry mapping a key value to its ID.
+    ///
+    /// The value is hashed so the index does not store key values in plaintext.
+    fn lookup_key(&self, value: &SecretString) -> String {
+        let digest = value.as_str(|v| hex::encode(Sha256::digest(v.as_bytes())));
+        format!("{}:{}:{}", self.key_prefix, API_KEY_LOOKUP_PREFIX, digest)
+    }
+
+    async fn add_to_list(&self, id: &str) -> Result<(), RepositoryError> {
+        let key = self.api_key_list_key();
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<i64, RedisError> = conn.sadd(&key, id).await;
+        result.map_err(|e| {
+            error!("Failed to add API key {} to list: {}", id, e);
+            RepositoryError::Other(format!("Failed to add API key to list: {}", e))
+        })?;
+
+        debug!("Added API key {} to list", id);
+        Ok(())
+    }
+
+    async fn remove_from_list(&self, id: &str) -> Result<(), RepositoryError> {
+        let key = self.api_key_list_key();
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<i64, RedisError> = conn.srem(&key, id).await;
+        result.map_err(|e| {
+            error!("Failed to remove API key {} from list: {}", id, e);
+            RepositoryError::Other(format!("Failed to remove API key from list: {}", e))
+        })?;
+
+        debug!("Removed API key {} from list", id);
+        Ok(())
+    }
+
+    async fn get_all_ids(&self) -> Result<Vec<String>, RepositoryError> {
+        let key = self.api_key_list_key();
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<Vec<String>, RedisError> = conn.smembers(&key).await;
+        result.map_err(|e| {
+            error!("Failed to get API key IDs: {}", e);
+            RepositoryError::Other(format!("Failed to get API key IDs: {}", e))
+        })
+    }
+
+    /// Batch fetch API keys by IDs
+    async fn get_api_keys_by_ids(
+        &self,
+        ids: &[String],
+    ) -> Result<BatchRetrievalResult<ApiKeyRepoModel>, RepositoryError> {
+        if ids.is_empty() {
+            debug!("No API key IDs provided for batch fetch");
+            return Ok(BatchRetrievalResult {
+                results: vec![],
+                failed_ids: vec![],
+            });
+        }
+
+        let mut conn = self.client.as_ref().clone();
+        let keys: Vec<String> = ids.iter().map(|id| self.entry_key(id)).collect();
+
+        debug!("Batch fetching {} API keys", ids.len());
+
+        let values: Vec<Option<String>> = conn
+            .mget(&keys)
+            .await
+            .map_err(|e| self.map_redis_error(e, "batch_fetch_api_keys"))?;
+
+        let mut api_keys = Vec::new();
+        let mut failed_count = 0;
+        let mut failed_ids = Vec::new();
+
+        for (i, value) in values.into_iter().enumerate() {
+            match value {
+                Some(json) => {
+                    match self.deserialize_entity::<ApiKeyRepoModel>(&json, &ids[i], "api_key") {
+                        Ok(api_key) => api_keys.push(api_key),
+                        Err(e) => {
+                            failed_count += 1;
+                            error!("Failed to deserialize API key {}: {}", ids[i], e);
+                            failed_ids.push(ids[i].clone());
+                        }
+                    }
+                }
+                None => {
+                    warn!("API key {} not found in batch fetch", ids[i]);
+                }
+            }
+        }
+
+        if failed_count > 0 {
+            warn!(
+                "Failed to deserialize {} out of {} API keys in batch",
+                failed_count,
+                ids.len()
+            );
+            warn!("Failed to deserialize API keys: {:?}", failed_ids);
+        }
+
+        debug!("Successfully fetched {} API keys", api_keys.len());
+        Ok(BatchRetrievalResult {
+            results: api_keys,
+            failed_ids,
+        })
+    }
+}
+
+impl fmt::Debug for RedisApiKeyRepository {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisApiKeyRepository")
+            .field("key_prefix", &self.key_prefix)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Repository<ApiKeyRepoModel, String> for RedisApiKeyRepository {
+    async fn create(&self, api_key: ApiKeyRepoModel) -> Result<ApiKeyRepoModel, RepositoryError> {
+        if api_key.id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "API key ID cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&api_key.id);
+        let mut conn = self.client.as_ref().clone();
+
+        // Check if API key already exists
+        let exists: Result<bool, RedisError> = conn.exists(&key).await;
+        match exists {
+            Ok(true) => {
+                return Err(RepositoryError::ConstraintViolation(format!(
+                    "API key with ID {} already exists",
+                    api_key.id
+                )));
+            }
+            Ok(false) => {
+                // Continue with creation
+            }
+            Err(e) => {
+                error!("Failed to check if API key exists: {}", e);
+                return Err(RepositoryError::Other(format!(
+                    "Failed to check API key existence: {}",
+                    e
+                )));
+            }
+        }
+
+        // Serialize API key (encryption happens automatically for human-readable formats)
+        let serialized = self.serialize_entity(&api_key, |s| &s.id, "api_key")?;
+
+        // Store API key
+        let result: Result<(), RedisError> = conn.set(&key, &serialized).await;
+        result.map_err(|e| {
+            error!("Failed to store API key {}: {}", api_key.id, e);
+            RepositoryError::Other(format!("Failed to store API key: {}", e))
+        })?;
+
+        // Index by value for authentication lookups
+        let result: Result<(), RedisError> =
+            conn.set(self.lookup_key(&api_key.value), &api_key.id).await;
+        result.map_err(|e| {
+            error!("Failed to index API key {}: {}", api_key.id, e);
+            RepositoryError::Other(format!("Failed to index API key: {}", e))
+        })?;
+
+        // Add to list
+        self.add_to_list(&api_key.id).await?;
+
+        debug!("Created API key with ID: {}", api_key.id);
+        Ok(api_key)
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<ApiKeyRepoModel, RepositoryError> {
+        if id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "API key ID cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&id);
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<Option<String>, RedisError> = conn.get(&key).await;
+        match result {
+            Ok(Some(data)) => {
+                // Deserialize API key (decryption happens automatically)
+                let api_key = self.deserialize_entity::<ApiKeyRepoModel>(&data, &id, "api_key")?;
+                debug!("Retrieved API key with ID: {}", id);
+                Ok(api_key)
+            }
+            Ok(None) => {
+                debug!("API key with ID {} not found", id);
+                Err(RepositoryError::NotFound(format!(
+                    "API key with ID {} not found",
+                    id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to retrieve API key {}: {}", id, e);
+                Err(RepositoryError::Other(format!(
+                    "Failed to retrieve API key: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    async fn update(
+        &self,
+        id: String,
+        api_key: ApiKeyRepoModel,
+    ) -> Result<ApiKeyRepoModel, RepositoryError> {
+        if id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "API key ID cannot be empty".to_string(),
+            ));
+        }
+
+        if api_key.id != id {
+            return Err(RepositoryError::InvalidData(
+                "API key ID in data does not match provided ID".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&id);
+        let mut conn = self.client.as_ref().clone();
+
+        // Fetch the existing API key so the value index can be kept in sync
+        let existing = self.get_by_id(id.clone()).await?;
+
+        // Serialize API key (encryption happens automatically for human-readable formats)
+        let serialized = self.serialize_entity(&api_key, |s| &s.id, "api_key")?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        pipe.set(&key, &serialized);
+        if existing.value != api_key.value {
+            pipe.del(self.lookup_key(&existing.value));
+            pipe.set(self.lookup_key(&api_key.value), &id);
+        }
+
+        pipe.exec_async(&mut conn).await.map_err(|e| {
+            error!("Failed to update API key {}: {}", id, e);
+            RepositoryError::Other(format!("Failed to update API key: {}", e))
+        })?;
+
+        debug!("Updated API key with ID: {}", id);
+        Ok(api_key)
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        if id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "API key ID cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&id);
+        let mut conn = self.client.as_ref().clone();
+
+        // Fetch the API key so its value index entry can be removed
+        let existing = self.get_by_id(id.clone()).await?;
+
+        // Delete API key and its value index entry
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        pipe.del(&key);
+        pipe.del(self.lookup_key(&existing.value));
+
+        pipe.exec_async(&mut conn).await.map_err(|e| {
+            error!("Failed to delete API key {}: {}", id, e);
+            RepositoryError::Other(format!("Failed to delete API key: {}", e))
+        })?;
+
+        // Remove from list
+        self.remove_from_list(&id).await?;
+
+        debug!("Deleted API key with ID: {}", id);
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<ApiKeyRepoModel>, RepositoryError> {
+        let ids = self.get_all_ids().await?;
+
+        if ids.is_empty() {
+            debug!("No API keys found");
+            return Ok(Vec::new());
+        }
+
+        let api_keys = self.get_api_keys_by_ids(&ids).await?;
+        debug!("Successfully fetched {} API keys", api_keys.results.len());
+        Ok(api_keys.results)
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<ApiKeyRepoModel>, RepositoryError> {
+        if query.per_page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "per_page must be greater than 0".to_string(),
+            ));
+        }
+
+        debug!(
+            "Listing paginated API keys: page {}, per_page {}",
+            query.page, query.per_page
+        );
+
+        let all_ids: Vec<String> = self.get_all_ids().await?;
+        let total = all_ids.len() as u64;
+        let per_page = query.per_page as usize;
+        let page = query.page as usize;
+        let total_pages = all_ids.len().div_ceil(per_page);
+
+        if page > total_pages && !all_ids.is_empty() {
+            debug!(
+                "Requested page {} exceeds total pages {}",
+                page, total_pages
+            );
+            return Ok(PaginatedResult {
+                items: Vec::new(),
+                total,
+                page: query.page,
+                per_page: query.per_page,
+            });
+        }
+
+        let start_idx = (page - 1) * per_page;
+        let end_idx = std::cmp::min(start_idx + per_page, all_ids.len());
+
+        let page_ids = all_ids[start_idx..end_idx].to_vec();
+        let api_keys = self.get_api_keys_by_ids(&page_ids).await?;
+
+        debug!(
+            "Successfully retrieved {} API keys for page {}",
+            api_keys.results.len(),
+            query.page
+        );
+        Ok(PaginatedResult {
+            items: api_keys.results.clone(),
+            total,
+            page: query.page,
+            per_page: query.per_page,
+        })
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        let ids = self.get_all_ids().await?;
+        Ok(ids.len())
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let api_key_list_key = self.api_key_list_key();
+
+        debug!("Checking if API key entries exist");
+
+        let exists: bool = conn
+            .exists(&api_key_list_key)
+            .await
+            .map_err(|e| self.map_redis_error(e, "has_entries_check"))?;
+
+        debug!("API key entries exist: {}", exists);
+        Ok(exists)
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let api_key_list_key = self.api_key_list_key();
+
+        debug!("Dropping all API key entries");
+
+        // Get all API key IDs first
+        let api_key_ids: Vec<String> = conn
+            .smembers(&api_key_list_key)
+            .await
+            .map_err(|e| self.map_redis_error(e, "drop_all_entries_get_ids"))?;
+
+        if api_key_ids.is_empty() {
+            debug!("No API key entries to drop");
+            return Ok(());
+        }
+
+        // Fetch the API keys so their value index entries can be removed
+        let api_keys = self.get_api_keys_by_ids(&api_key_ids).await?;
+
+        // Use pipeline for atomic operations
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+
+        // Delete all individual API key entries
+        for api_key_id in &api_key_ids {
+            let entry_key = self.entry_key(api_key_id);
+            pipe.del(&entry_key);
+        }
+
+        // Delete the value index entries
+        for api_key in &api_keys.results {
+            pipe.del(self.lookup_key(&api_key.value));
+        }
+
+        // Delete the API key list key
+        pipe.del(&api_key_list_key);
+
+        pipe.exec_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "drop_all_entries_pipeline"))?;
+
+        debug!("Dropped {} API key entries", api_key_ids.len());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for RedisApiKeyRepository {
+    async fn get_by_value(
+        &self,
+        value: &SecretString,
+    ) -> Result<Option<ApiKeyRepoModel>, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let id: Option<String> = conn
+            .get(self.lookup_key(value))
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_api_key_by_value"))?;
+
+        let Some(id) = id else {
+            return Ok(None);
+        };
+
+        match self.get_by_id(id).await {
+            // Compare the stored value to guard against stale index entries
+            Ok(api_key) if &api_key.value == value => Ok(Some(api_key)),
+            Ok(_) | Err(RepositoryError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiKeyScope;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn create_test_api_key(id: &str, value: &str) -> ApiKeyRepoModel {
+        ApiKeyRepoModel {
+            id: id.to_string(),
+            name: format!("{}-name", id),
+            value: SecretString::new(value),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec![],
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    async fn setup_test_repo() -> RedisApiKeyRepository {
+        let client =
+            redis::Client::open("redis://127.0.0.1:6379/").expect("Failed to create Redis client");
+        let connection_manager = redis::aio::ConnectionManager::new(client)
+            .await
+            .expect("Failed to create connection manager");
+
+        RedisApiKeyRepository::new(Arc::new(connection_manager), "test".to_string())
+            .expect("Failed to create repository")
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_new_repository_empty_prefix_fails() {
+        let client =
+            redis::Client::open("redis://127.0.0.1:6379/").expect("Failed to create Redis client");
+        let connection_manager = redis::aio::ConnectionManager::new(client)
+            .await
+            .expect("Failed to create connection manager");
+
+        let result = RedisApiKeyRepository::new(Arc::new(connection_manager), "".to_string());
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_create_get_and_delete_api_key() {
+        let repo = setup_test_repo().await;
+        let id = Uuid::new_v4().to_string();
+        let value = Uuid::new_v4().to_string();
+        let api_key = create_test_api_key(&id, &value);
+
+        repo.create(api_key.clone()).await.unwrap();
+        let stored = repo.get_by_id(id.clone()).await.unwrap();
+        assert_eq!(stored, api_key);
+
+        repo.delete_by_id(id.clone()).await.unwrap();
+        assert!(matches!(
+            repo.get_by_id(id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_get_by_value() {
+        let repo = setup_test_repo().await;
+        let id = Uuid::new_v4().to_string();
+        let value = Uuid::new_v4().to_string();
+        repo.create(create_test_api_key(&id, &value)).await.unwrap();
+
+        let found = repo.get_by_value(&SecretString::new(&value)).await.unwrap();
+        assert_eq!(found.map(|k| k.id), Some(id.clone()));
+
+        repo.delete_by_id(id).await.unwrap();
+        let missing = repo.get_by_value(&SecretString::new(&value)).await.unwrap();
+        assert!(missing.is_none());
+    }
+}
@@ -0,0 +1,200 @@
+//! API Key Repository Module
+//!
+//! This module provides the API key repository layer for the OpenZeppelin Relayer service.
+//! It implements the Repository pattern to abstract API key persistence operations,
+//! supporting both in-memory and Redis-backed storage implementations.
+//!
+//! ## Features
+//!
+//! - **CRUD Operations**: Create, read and revoke API keys
+//! - **Secure Storage**: Key values are encrypted at rest like signer secrets
+//! - **Value Lookup**: Resolve the API key matching a bearer token
+//!
+//! ## Repository Implementations
+//!
+//! - [`InMemoryApiKeyRepository`]: Fast in-memory storage for testing/development
+//! - [`RedisApiKeyRepository`]: Redis-backed storage for production environments
+
+mod api_key_in_memory;
+mod api_key_redis;
+
+pub use api_key_in_memory::*;
+pub use api_key_redis::*;
+
+use crate::{
+    models::{ApiKeyRepoModel, RepositoryError, SecretString},
+    repositories::{PaginatedResult, PaginationQuery, Repository},
+};
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait ApiKeyRepository: Repository<ApiKeyRepoModel, String> {
+    /// Get the API key whose value matches the given token
+    async fn get_by_value(
+        &self,
+        value: &SecretString,
+    ) -> Result<Option<ApiKeyRepoModel>, RepositoryError>;
+}
+
+/// Enum wrapper for different API key repository implementations
+#[derive(Debug, Clone)]
+pub enum ApiKeyRepositoryStorage {
+    InMemory(InMemoryApiKeyRepository),
+    Redis(RedisApiKeyRepository),
+}
+
+impl ApiKeyRepositoryStorage {
+    pub fn new_in_memory() -> Self {
+        Self::InMemory(InMemoryApiKeyRepository::new())
+    }
+
+    pub fn new_redis(
+        connection_manager: Arc<ConnectionManager>,
+        key_prefix: String,
+    ) -> Result<Self, RepositoryError> {
+        let redis_repo = RedisApiKeyRepository::new(connection_manager, key_prefix)?;
+        Ok(Self::Redis(redis_repo))
+    }
+}
+
+#[async_trait]
+impl Repository<ApiKeyRepoModel, String> for ApiKeyRepositoryStorage {
+    async fn create(&self, entity: ApiKeyRepoModel) -> Result<ApiKeyRepoModel, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.create(entity).await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.create(entity).await,
+        }
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<ApiKeyRepoModel, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.get_by_id(id).await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.get_by_id(id).await,
+        }
+    }
+
+    async fn list_all(&self) -> Result<Vec<ApiKeyRepoModel>, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.list_all().await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.list_all().await,
+        }
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<ApiKeyRepoModel>, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.list_paginated(query).await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.list_paginated(query).await,
+        }
+    }
+
+    async fn update(
+        &self,
+        id: String,
+        entity: ApiKeyRepoModel,
+    ) -> Result<ApiKeyRepoModel, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.update(id, entity).await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.update(id, entity).await,
+        }
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.delete_by_id(id).await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.delete_by_id(id).await,
+        }
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.count().await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.count().await,
+        }
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.has_entries().await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.has_entries().await,
+        }
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.drop_all_entries().await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.drop_all_entries().await,
+        }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for ApiKeyRepositoryStorage {
+    async fn get_by_value(
+        &self,
+        value: &SecretString,
+    ) -> Result<Option<ApiKeyRepoModel>, RepositoryError> {
+        match self {
+            ApiKeyRepositoryStorage::InMemory(repo) => repo.get_by_value(value).await,
+            ApiKeyRepositoryStorage::Redis(repo) => repo.get_by_value(value).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mockall::mock! {
+    pub ApiKeyRepository {}
+
+    #[async_trait]
+    impl Repository<ApiKeyRepoModel, String> for ApiKeyRepository {
+        async fn create(&self, entity: ApiKeyRepoModel) -> Result<ApiKeyRepoModel, RepositoryError>;
+        async fn get_by_id(&self, id: String) -> Result<ApiKeyRepoModel, RepositoryError>;
+        async fn list_all(&self) -> Result<Vec<ApiKeyRepoModel>, RepositoryError>;
+        async fn list_paginated(&self, query: PaginationQuery) -> Result<PaginatedResult<ApiKeyRepoModel>, RepositoryError>;
+        async fn update(&self, id: String, entity: ApiKeyRepoModel) -> Result<ApiKeyRepoModel, RepositoryError>;
+        async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError>;
+        async fn count(&self) -> Result<usize, RepositoryError>;
+        async fn has_entries(&self) -> Result<bool, RepositoryError>;
+        async fn drop_all_entries(&self) -> Result<(), RepositoryError>;
+    }
+
+    #[async_trait]
+    impl ApiKeyRepository for ApiKeyRepository {
+        async fn get_by_value(&self, value: &SecretString) -> Result<Option<ApiKeyRepoModel>, RepositoryError>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ApiKeyScope;
+
+    #[actix_web::test]
+    async fn test_in_memory_storage_operations() {
+        let repo = ApiKeyRepositoryStorage::new_in_memory();
+        let api_key = ApiKeyRepoModel {
+            id: "key-1".to_string(),
+            name: "ci".to_string(),
+            value: SecretString::new("value-1"),
+            scopes: vec![ApiKeyScope::Read],
+            allowed_relayers: vec![],
+            created_at: "2025-01-01T00:00:00Z".to_string(),
+        };
+
+        repo.create(api_key.clone()).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 1);
+
+        let found = repo
+            .get_by_value(&SecretString::new("value-1"))
+            .await
+            .unwrap();
+        assert_eq!(found, Some(api_key));
+
+        repo.delete_by_id("key-1".to_string()).await.unwrap();
+        assert!(!repo.has_entries().await.unwrap());
+    }
+}
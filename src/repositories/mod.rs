@@ -29,6 +29,12 @@ pub use network::*;
 mod plugin;
 pub use plugin::*;
 
+mod api_key;
+pub use api_key::*;
+
+mod relayer_pool;
+pub use relayer_pool::*;
+
 // Redis base utilities for shared functionality
 pub mod redis_base;
 
@@ -1,6 +1,6 @@
 //! This module defines an in-memory network repository for managing
-//! network configurations. It provides functionality to create and retrieve
-//! network configurations, while update and delete operations are not supported.
+//! network configurations. It provides functionality to create, retrieve,
+//! update and delete network configurations.
 //! The repository is implemented using a `Mutex`-protected `HashMap` to
 //! ensure thread safety in asynchronous contexts.
 
@@ -93,14 +93,39 @@ impl Repository<NetworkRepoModel, String> for InMemoryNetworkRepository {
 
     async fn update(
         &self,
-        _id: String,
-        _network: NetworkRepoModel,
+        id: String,
+        network: NetworkRepoModel,
     ) -> Result<NetworkRepoModel, RepositoryError> {
-        Err(RepositoryError::NotSupported("Not supported".to_string()))
+        let mut store = Self::acquire_lock(&self.store).await?;
+
+        if !store.contains_key(&id) {
+            return Err(RepositoryError::NotFound(format!(
+                "Network with ID {} not found",
+                id
+            )));
+        }
+
+        if id != network.id {
+            return Err(RepositoryError::InvalidData(format!(
+                "ID mismatch: provided ID '{}' doesn't match network ID '{}'",
+                id, network.id
+            )));
+        }
+
+        store.insert(id, network.clone());
+        Ok(network)
     }
 
-    async fn delete_by_id(&self, _id: String) -> Result<(), RepositoryError> {
-        Err(RepositoryError::NotSupported("Not supported".to_string()))
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+
+        match store.remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(RepositoryError::NotFound(format!(
+                "Network with ID {} not found",
+                id
+            ))),
+        }
     }
 
     async fn list_all(&self) -> Result<Vec<NetworkRepoModel>, RepositoryError> {
@@ -111,9 +136,32 @@ impl Repository<NetworkRepoModel, String> for InMemoryNetworkRepository {
 
     async fn list_paginated(
         &self,
-        _query: PaginationQuery,
+        query: PaginationQuery,
     ) -> Result<PaginatedResult<NetworkRepoModel>, RepositoryError> {
-        Err(RepositoryError::NotSupported("Not supported".to_string()))
+        if query.per_page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "per_page must be greater than 0".to_string(),
+            ));
+        }
+
+        let store = Self::acquire_lock(&self.store).await?;
+        let mut networks: Vec<NetworkRepoModel> = store.values().cloned().collect();
+        networks.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let total = networks.len() as u64;
+        let start = (query.page.saturating_sub(1) * query.per_page) as usize;
+        let items = networks
+            .into_iter()
+            .skip(start)
+            .take(query.per_page as usize)
+            .collect();
+
+        Ok(PaginatedResult {
+            items,
+            total,
+            page: query.page,
+            per_page: query.per_page,
+        })
     }
 
     async fn count(&self) -> Result<usize, RepositoryError> {
@@ -195,6 +243,8 @@ mod tests {
                     features: None,
                     symbol: Some("ETH".to_string()),
                     gas_price_cache: None,
+                    private_relay: None,
+                    gas_oracle: None,
                 };
                 NetworkRepoModel::new_evm(evm_config)
             }
@@ -286,32 +336,88 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_unsupported_operations() {
+    async fn test_update_network() {
         let repo = InMemoryNetworkRepository::new();
-        let network = create_test_network("test".to_string(), NetworkType::Evm);
+        let network = create_test_network("mainnet".to_string(), NetworkType::Evm);
+        repo.create(network.clone()).await.unwrap();
 
-        let update_result = repo.update("test".to_string(), network.clone()).await;
-        assert!(matches!(
-            update_result,
-            Err(RepositoryError::NotSupported(_))
-        ));
+        let mut updated = network.clone();
+        if let crate::models::NetworkConfigData::Evm(config) = &mut updated.config {
+            config.required_confirmations = Some(12);
+        }
 
-        let delete_result = repo.delete_by_id("test".to_string()).await;
-        assert!(matches!(
-            delete_result,
-            Err(RepositoryError::NotSupported(_))
-        ));
+        repo.update(network.id.clone(), updated).await.unwrap();
+
+        let stored = repo.get_by_id(network.id.clone()).await.unwrap();
+        match stored.config {
+            crate::models::NetworkConfigData::Evm(config) => {
+                assert_eq!(config.required_confirmations, Some(12))
+            }
+            _ => panic!("Expected EVM network"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_nonexistent_network() {
+        let repo = InMemoryNetworkRepository::new();
+        let network = create_test_network("mainnet".to_string(), NetworkType::Evm);
 
-        let pagination_result = repo
+        let result = repo.update(network.id.clone(), network).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_id_mismatch() {
+        let repo = InMemoryNetworkRepository::new();
+        let network = create_test_network("mainnet".to_string(), NetworkType::Evm);
+        repo.create(network.clone()).await.unwrap();
+
+        let other = create_test_network("sepolia".to_string(), NetworkType::Evm);
+        let result = repo.update(network.id.clone(), other).await;
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_network() {
+        let repo = InMemoryNetworkRepository::new();
+        let network = create_test_network("mainnet".to_string(), NetworkType::Evm);
+        repo.create(network.clone()).await.unwrap();
+
+        repo.delete_by_id(network.id.clone()).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 0);
+
+        let result = repo.delete_by_id(network.id).await;
+        assert!(matches!(result, Err(RepositoryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_paginated() {
+        let repo = InMemoryNetworkRepository::new();
+        for name in ["a", "b", "c"] {
+            repo.create(create_test_network(name.to_string(), NetworkType::Evm))
+                .await
+                .unwrap();
+        }
+
+        let page = repo
+            .list_paginated(PaginationQuery {
+                page: 2,
+                per_page: 2,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].name, "c");
+
+        let result = repo
             .list_paginated(PaginationQuery {
                 page: 1,
-                per_page: 10,
+                per_page: 0,
             })
             .await;
-        assert!(matches!(
-            pagination_result,
-            Err(RepositoryError::NotSupported(_))
-        ));
+        assert!(matches!(result, Err(RepositoryError::InvalidData(_))));
     }
 
     #[tokio::test]
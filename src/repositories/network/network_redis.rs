@@ -722,6 +722,8 @@ mod tests {
                     features: None,
                     symbol: Some("ETH".to_string()),
                     gas_price_cache: None,
+                    private_relay: None,
+                    gas_oracle: None,
                 };
                 NetworkRepoModel::new_evm(evm_config)
             }
@@ -1005,6 +1007,8 @@ mod tests {
                     features: None,
                     symbol: Some("ETH".to_string()),
                     gas_price_cache: None,
+                    private_relay: None,
+                    gas_oracle: None,
                 }),
             })
             .await;
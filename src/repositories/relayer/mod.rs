@@ -27,7 +27,10 @@ pub use relayer_redis::*;
 
 use crate::{
     models::UpdateRelayerRequest,
-    models::{PaginationQuery, RelayerNetworkPolicy, RelayerRepoModel, RepositoryError},
+    models::{
+        PaginationQuery, RelayerNetworkPolicy, RelayerRepoModel, RelayerSpend, RelayerSpendLimit,
+        RepositoryError,
+    },
     repositories::{PaginatedResult, Repository},
 };
 use async_trait::async_trait;
@@ -61,6 +64,42 @@ pub trait RelayerRepository: Repository<RelayerRepoModel, String> + Send + Sync
         id: String,
         policy: RelayerNetworkPolicy,
     ) -> Result<RelayerRepoModel, RepositoryError>;
+    /// Records what a transaction spends at `timestamp` (Unix seconds), replacing any
+    /// earlier record for the same transaction.
+    async fn record_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+    ) -> Result<(), RepositoryError>;
+    /// Atomically records what a transaction spends at `timestamp` (Unix seconds), replacing
+    /// any earlier record for the same transaction, unless the spend recorded since `since`
+    /// would then exceed `limit`.
+    ///
+    /// Returns `None` once the spend is recorded, or the window total including the
+    /// transaction when it exceeds the limit, in which case the records are left unchanged.
+    async fn record_spend_within_limit(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+        since: i64,
+        limit: RelayerSpendLimit,
+    ) -> Result<Option<RelayerSpend>, RepositoryError>;
+    /// Removes the spend recorded for a transaction.
+    async fn remove_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+    ) -> Result<(), RepositoryError>;
+    /// Sums the spend recorded since `since` (Unix seconds), pruning older records.
+    async fn get_spend_since(
+        &self,
+        relayer_id: &str,
+        since: i64,
+    ) -> Result<RelayerSpend, RepositoryError>;
 }
 
 /// Enum wrapper for different relayer repository implementations
@@ -239,6 +278,88 @@ impl RelayerRepository for RelayerRepositoryStorage {
             RelayerRepositoryStorage::Redis(repo) => repo.update_policy(id, policy).await,
         }
     }
+
+    async fn record_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.record_spend(relayer_id, transaction_id, spend, timestamp)
+                    .await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.record_spend(relayer_id, transaction_id, spend, timestamp)
+                    .await
+            }
+        }
+    }
+
+    async fn record_spend_within_limit(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+        since: i64,
+        limit: RelayerSpendLimit,
+    ) -> Result<Option<RelayerSpend>, RepositoryError> {
+        match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.record_spend_within_limit(
+                    relayer_id,
+                    transaction_id,
+                    spend,
+                    timestamp,
+                    since,
+                    limit,
+                )
+                .await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.record_spend_within_limit(
+                    relayer_id,
+                    transaction_id,
+                    spend,
+                    timestamp,
+                    since,
+                    limit,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn remove_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.remove_spend(relayer_id, transaction_id).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => {
+                repo.remove_spend(relayer_id, transaction_id).await
+            }
+        }
+    }
+
+    async fn get_spend_since(
+        &self,
+        relayer_id: &str,
+        since: i64,
+    ) -> Result<RelayerSpend, RepositoryError> {
+        match self {
+            RelayerRepositoryStorage::InMemory(repo) => {
+                repo.get_spend_since(relayer_id, since).await
+            }
+            RelayerRepositoryStorage::Redis(repo) => repo.get_spend_since(relayer_id, since).await,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -260,12 +381,23 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(false),
                 private_transactions: Some(false),
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
             }),
             signer_id: "test".to_string(),
             address: "0x".to_string(),
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -346,6 +478,16 @@ mod tests {
             whitelist_receivers: None,
             eip1559_pricing: Some(true),
             private_transactions: Some(false),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
         });
         let policy_updated = impl_repo
             .update_policy(relayer.id.clone(), new_policy)
@@ -467,5 +609,9 @@ mockall::mock! {
         async fn enable_relayer(&self, relayer_id: String) -> Result<RelayerRepoModel, RepositoryError>;
         async fn disable_relayer(&self, relayer_id: String) -> Result<RelayerRepoModel, RepositoryError>;
         async fn update_policy(&self, id: String, policy: RelayerNetworkPolicy) -> Result<RelayerRepoModel, RepositoryError>;
+        async fn record_spend(&self, relayer_id: &str, transaction_id: &str, spend: RelayerSpend, timestamp: i64) -> Result<(), RepositoryError>;
+        async fn record_spend_within_limit(&self, relayer_id: &str, transaction_id: &str, spend: RelayerSpend, timestamp: i64, since: i64, limit: RelayerSpendLimit) -> Result<Option<RelayerSpend>, RepositoryError>;
+        async fn remove_spend(&self, relayer_id: &str, transaction_id: &str) -> Result<(), RepositoryError>;
+        async fn get_spend_since(&self, relayer_id: &str, since: i64) -> Result<RelayerSpend, RepositoryError>;
     }
 }
@@ -10,7 +10,9 @@
 use crate::models::PaginationQuery;
 use crate::{
     models::UpdateRelayerRequest,
-    models::{RelayerNetworkPolicy, RelayerRepoModel, RepositoryError},
+    models::{
+        RelayerNetworkPolicy, RelayerRepoModel, RelayerSpend, RelayerSpendLimit, RepositoryError,
+    },
 };
 use async_trait::async_trait;
 use eyre::Result;
@@ -19,15 +21,20 @@ use tokio::sync::{Mutex, MutexGuard};
 
 use crate::repositories::{PaginatedResult, RelayerRepository, Repository};
 
+/// Spend records of a relayer, keyed by transaction ID
+type SpendLedger = HashMap<String, (i64, RelayerSpend)>;
+
 #[derive(Debug)]
 pub struct InMemoryRelayerRepository {
     store: Mutex<HashMap<String, RelayerRepoModel>>,
+    spends: Mutex<HashMap<String, SpendLedger>>,
 }
 
 impl InMemoryRelayerRepository {
     pub fn new() -> Self {
         Self {
             store: Mutex::new(HashMap::new()),
+            spends: Mutex::new(HashMap::new()),
         }
     }
     async fn acquire_lock<T>(lock: &Mutex<T>) -> Result<MutexGuard<T>, RepositoryError> {
@@ -49,9 +56,15 @@ impl Clone for InMemoryRelayerRepository {
             .try_lock()
             .map(|guard| guard.clone())
             .unwrap_or_else(|_| HashMap::new());
+        let spends = self
+            .spends
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
 
         Self {
             store: Mutex::new(data),
+            spends: Mutex::new(spends),
         }
     }
 }
@@ -162,6 +175,75 @@ impl RelayerRepository for InMemoryRelayerRepository {
             )))
         }
     }
+
+    async fn record_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+    ) -> Result<(), RepositoryError> {
+        let mut spends = Self::acquire_lock(&self.spends).await?;
+        spends
+            .entry(relayer_id.to_string())
+            .or_default()
+            .insert(transaction_id.to_string(), (timestamp, spend));
+        Ok(())
+    }
+
+    async fn record_spend_within_limit(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+        since: i64,
+        limit: RelayerSpendLimit,
+    ) -> Result<Option<RelayerSpend>, RepositoryError> {
+        let mut spends = Self::acquire_lock(&self.spends).await?;
+        let ledger = spends.entry(relayer_id.to_string()).or_default();
+        ledger.retain(|_, (timestamp, _)| *timestamp >= since);
+
+        let total = ledger
+            .iter()
+            .filter(|(id, _)| id.as_str() != transaction_id)
+            .fold(spend, |total, (_, (_, spend))| total.saturating_add(*spend));
+        if limit.is_exceeded_by(&total) {
+            return Ok(Some(total));
+        }
+
+        ledger.insert(transaction_id.to_string(), (timestamp, spend));
+        Ok(None)
+    }
+
+    async fn remove_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+    ) -> Result<(), RepositoryError> {
+        let mut spends = Self::acquire_lock(&self.spends).await?;
+        if let Some(ledger) = spends.get_mut(relayer_id) {
+            ledger.remove(transaction_id);
+        }
+        Ok(())
+    }
+
+    async fn get_spend_since(
+        &self,
+        relayer_id: &str,
+        since: i64,
+    ) -> Result<RelayerSpend, RepositoryError> {
+        let mut spends = Self::acquire_lock(&self.spends).await?;
+        let Some(ledger) = spends.get_mut(relayer_id) else {
+            return Ok(RelayerSpend::default());
+        };
+        ledger.retain(|_, (timestamp, _)| *timestamp >= since);
+        Ok(ledger
+            .values()
+            .fold(RelayerSpend::default(), |total, (_, spend)| {
+                total.saturating_add(*spend)
+            }))
+    }
 }
 
 #[async_trait]
@@ -261,6 +343,7 @@ impl Repository<RelayerRepoModel, String> for InMemoryRelayerRepository {
     async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
         let mut store = Self::acquire_lock(&self.store).await?;
         store.clear();
+        Self::acquire_lock(&self.spends).await?.clear();
         Ok(())
     }
 }
@@ -283,6 +366,16 @@ mod tests {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(false),
                 private_transactions: Some(false),
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
                 min_balance: Some(0),
                 gas_limit_estimation: Some(true),
             }),
@@ -291,6 +384,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -452,6 +546,16 @@ mod tests {
             whitelist_receivers: Some(vec!["0x1234".to_string()]),
             eip1559_pricing: Some(true),
             private_transactions: Some(true),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
             min_balance: Some(1000000),
             gas_limit_estimation: Some(true),
         });
@@ -517,6 +621,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer2 = RelayerRepoModel {
@@ -531,6 +636,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer3 = RelayerRepoModel {
@@ -545,6 +651,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer4 = RelayerRepoModel {
@@ -559,6 +666,7 @@ mod tests {
             notification_id: Some("notification-1".to_string()),
             system_disabled: true,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         // Add all relayers to the repository
@@ -639,6 +747,7 @@ mod tests {
             notification_id: Some("notification-alpha".to_string()),
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer2 = RelayerRepoModel {
@@ -653,6 +762,7 @@ mod tests {
             notification_id: Some("notification-alpha".to_string()), // Same notification as relayer1
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer3 = RelayerRepoModel {
@@ -667,6 +777,7 @@ mod tests {
             notification_id: Some("notification-beta".to_string()), // Different notification
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer4 = RelayerRepoModel {
@@ -681,6 +792,7 @@ mod tests {
             notification_id: None, // No notification
             system_disabled: true,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         let relayer5 = RelayerRepoModel {
@@ -695,6 +807,7 @@ mod tests {
             notification_id: Some("notification-alpha".to_string()), // Same notification as relayer1 and relayer2
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         };
 
         // Add all relayers to the repository
@@ -798,4 +911,142 @@ mod tests {
         assert!(beta_ids_final.contains(&"relayer-3".to_string()));
         assert!(beta_ids_final.contains(&"relayer-5".to_string()));
     }
+
+    #[actix_web::test]
+    async fn test_spend_tracking() {
+        let repo = InMemoryRelayerRepository::new();
+        let spend = RelayerSpend {
+            value: 100,
+            gas: 10,
+        };
+
+        repo.record_spend("relayer-1", "tx-1", spend, 1_000)
+            .await
+            .unwrap();
+        repo.record_spend("relayer-1", "tx-2", spend, 2_000)
+            .await
+            .unwrap();
+        repo.record_spend("relayer-2", "tx-3", spend, 2_000)
+            .await
+            .unwrap();
+
+        let total = repo.get_spend_since("relayer-1", 0).await.unwrap();
+        assert_eq!(
+            total,
+            RelayerSpend {
+                value: 200,
+                gas: 20
+            }
+        );
+
+        // Records before the window start are pruned
+        let total = repo.get_spend_since("relayer-1", 1_500).await.unwrap();
+        assert_eq!(total, spend);
+        let total = repo.get_spend_since("relayer-1", 0).await.unwrap();
+        assert_eq!(total, spend);
+
+        // Recording the same transaction again replaces its spend
+        repo.record_spend(
+            "relayer-1",
+            "tx-2",
+            RelayerSpend {
+                value: 100,
+                gas: 30,
+            },
+            2_000,
+        )
+        .await
+        .unwrap();
+        let total = repo.get_spend_since("relayer-1", 0).await.unwrap();
+        assert_eq!(total.gas, 30);
+
+        repo.remove_spend("relayer-1", "tx-2").await.unwrap();
+        let total = repo.get_spend_since("relayer-1", 0).await.unwrap();
+        assert_eq!(total, RelayerSpend::default());
+        assert_eq!(repo.get_spend_since("relayer-2", 0).await.unwrap(), spend);
+    }
+
+    #[tokio::test]
+    async fn test_record_spend_within_limit() {
+        let repo = InMemoryRelayerRepository::new();
+        let spend = RelayerSpend {
+            value: 100,
+            gas: 10,
+        };
+        let limit = RelayerSpendLimit {
+            max_value: Some(250),
+            max_gas: None,
+        };
+
+        let exceeded = repo
+            .record_spend_within_limit("relayer-1", "tx-1", spend, 1_000, 0, limit)
+            .await
+            .unwrap();
+        assert_eq!(exceeded, None);
+        let exceeded = repo
+            .record_spend_within_limit("relayer-1", "tx-2", spend, 1_000, 0, limit)
+            .await
+            .unwrap();
+        assert_eq!(exceeded, None);
+
+        // A third transaction goes over the limit and is not recorded
+        let exceeded = repo
+            .record_spend_within_limit("relayer-1", "tx-3", spend, 1_000, 0, limit)
+            .await
+            .unwrap();
+        assert_eq!(
+            exceeded,
+            Some(RelayerSpend {
+                value: 300,
+                gas: 30
+            })
+        );
+        assert_eq!(
+            repo.get_spend_since("relayer-1", 0).await.unwrap(),
+            RelayerSpend {
+                value: 200,
+                gas: 20
+            }
+        );
+
+        // Re-recording a transaction only counts the difference to its earlier spend
+        let raised = RelayerSpend {
+            value: 150,
+            gas: 10,
+        };
+        let exceeded = repo
+            .record_spend_within_limit("relayer-1", "tx-2", raised, 1_000, 0, limit)
+            .await
+            .unwrap();
+        assert_eq!(exceeded, None);
+        let exceeded = repo
+            .record_spend_within_limit(
+                "relayer-1",
+                "tx-2",
+                RelayerSpend {
+                    value: 200,
+                    gas: 10,
+                },
+                1_000,
+                0,
+                limit,
+            )
+            .await
+            .unwrap();
+        assert!(exceeded.is_some());
+        assert_eq!(
+            repo.get_spend_since("relayer-1", 0).await.unwrap(),
+            RelayerSpend {
+                value: 250,
+                gas: 20
+            }
+        );
+
+        // Records outside the window no longer count
+        let exceeded = repo
+            .record_spend_within_limit("relayer-1", "tx-3", spend, 5_000, 2_000, limit)
+            .await
+            .unwrap();
+        assert_eq!(exceeded, None);
+    }
 }
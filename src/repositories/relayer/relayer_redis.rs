@@ -1,7 +1,10 @@
 //! Redis-backed implementation of the RelayerRepository.
 
 use crate::models::UpdateRelayerRequest;
-use crate::models::{PaginationQuery, RelayerNetworkPolicy, RelayerRepoModel, RepositoryError};
+use crate::models::{
+    PaginationQuery, RelayerNetworkPolicy, RelayerRepoModel, RelayerSpend, RelayerSpendLimit,
+    RepositoryError,
+};
 use crate::repositories::redis_base::RedisRepository;
 use crate::repositories::{BatchRetrievalResult, PaginatedResult, RelayerRepository, Repository};
 use async_trait::async_trait;
@@ -13,6 +16,58 @@ use std::sync::Arc;
 
 const RELAYER_PREFIX: &str = "relayer";
 const RELAYER_LIST_KEY: &str = "relayer_list";
+const RELAYER_SPEND_PREFIX: &str = "relayer_spend";
+const RELAYER_SPEND_AMOUNTS_PREFIX: &str = "relayer_spend_amounts";
+
+/// Records a transaction's spend unless the window total would exceed the limits, as a
+/// single atomic step. Amounts are summed as decimal strings because wei values exceed the
+/// integer precision of Lua numbers.
+///
+/// KEYS: spend timestamps, spend amounts.
+/// ARGV: transaction ID, timestamp, window start, value, gas, max value and max gas (empty
+/// when unbounded), serialized amounts.
+/// Returns `{recorded, total value, total gas}`.
+const RECORD_SPEND_WITHIN_LIMIT_SCRIPT: &str = r#"
+local function add(a, b)
+  local digits, carry = {}, 0
+  local i, j = #a, #b
+  while i > 0 or j > 0 or carry > 0 do
+    local digit = carry
+    if i > 0 then digit = digit + tonumber(string.sub(a, i, i)); i = i - 1 end
+    if j > 0 then digit = digit + tonumber(string.sub(b, j, j)); j = j - 1 end
+    table.insert(digits, 1, tostring(digit % 10))
+    carry = math.floor(digit / 10)
+  end
+  return table.concat(digits)
+end
+
+local function exceeds(amount, max)
+  if max == "" then return false end
+  if #amount ~= #max then return #amount > #max end
+  return amount > max
+end
+
+for _, id in ipairs(redis.call("ZRANGEBYSCORE", KEYS[1], "-inf", "(" .. ARGV[3])) do
+  redis.call("HDEL", KEYS[2], id)
+end
+redis.call("ZREMRANGEBYSCORE", KEYS[1], "-inf", "(" .. ARGV[3])
+
+local value, gas = ARGV[4], ARGV[5]
+for _, id in ipairs(redis.call("ZRANGEBYSCORE", KEYS[1], ARGV[3], "+inf")) do
+  local amounts = id ~= ARGV[1] and redis.call("HGET", KEYS[2], id)
+  if amounts then
+    value = add(value, string.match(amounts, '"value":(%d+)') or "0")
+    gas = add(gas, string.match(amounts, '"gas":(%d+)') or "0")
+  end
+end
+
+if exceeds(value, ARGV[6]) or exceeds(gas, ARGV[7]) then
+  return {0, value, gas}
+end
+redis.call("ZADD", KEYS[1], ARGV[2], ARGV[1])
+redis.call("HSET", KEYS[2], ARGV[1], ARGV[8])
+return {1, value, gas}
+"#;
 
 #[derive(Clone)]
 pub struct RedisRelayerRepository {
@@ -49,6 +104,22 @@ impl RedisRelayerRepository {
         format!("{}:{}", self.key_prefix, RELAYER_LIST_KEY)
     }
 
+    /// Generate key for spend timestamps: relayer_spend:{relayer_id} (sorted set of transaction IDs)
+    fn spend_key(&self, relayer_id: &str) -> String {
+        format!(
+            "{}:{}:{}",
+            self.key_prefix, RELAYER_SPEND_PREFIX, relayer_id
+        )
+    }
+
+    /// Generate key for spend amounts: relayer_spend_amounts:{relayer_id} (hash by transaction ID)
+    fn spend_amounts_key(&self, relayer_id: &str) -> String {
+        format!(
+            "{}:{}:{}",
+            self.key_prefix, RELAYER_SPEND_AMOUNTS_PREFIX, relayer_id
+        )
+    }
+
     /// Batch fetch relayers by IDs
     async fn get_relayers_by_ids(
         &self,
@@ -538,6 +609,159 @@ impl RelayerRepository for RedisRelayerRepository {
         // Update the relayer
         self.update(id, relayer).await
     }
+
+    async fn record_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let amounts = serde_json::to_string(&spend).map_err(|e| {
+            RepositoryError::InvalidData(format!(
+                "Failed to serialize spend of transaction {}: {}",
+                transaction_id, e
+            ))
+        })?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        pipe.zadd(self.spend_key(relayer_id), transaction_id, timestamp);
+        pipe.hset(self.spend_amounts_key(relayer_id), transaction_id, amounts);
+        pipe.exec_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "record_spend"))?;
+
+        debug!(
+            "Recorded spend of transaction {} for relayer {}",
+            transaction_id, relayer_id
+        );
+        Ok(())
+    }
+
+    async fn record_spend_within_limit(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+        spend: RelayerSpend,
+        timestamp: i64,
+        since: i64,
+        limit: RelayerSpendLimit,
+    ) -> Result<Option<RelayerSpend>, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let amounts = serde_json::to_string(&spend).map_err(|e| {
+            RepositoryError::InvalidData(format!(
+                "Failed to serialize spend of transaction {}: {}",
+                transaction_id, e
+            ))
+        })?;
+        let bound = |max: Option<u128>| max.map(|max| max.to_string()).unwrap_or_default();
+
+        let (recorded, value, gas): (bool, String, String) =
+            redis::Script::new(RECORD_SPEND_WITHIN_LIMIT_SCRIPT)
+                .key(self.spend_key(relayer_id))
+                .key(self.spend_amounts_key(relayer_id))
+                .arg(transaction_id)
+                .arg(timestamp)
+                .arg(since)
+                .arg(spend.value.to_string())
+                .arg(spend.gas.to_string())
+                .arg(bound(limit.max_value))
+                .arg(bound(limit.max_gas))
+                .arg(amounts)
+                .invoke_async(&mut conn)
+                .await
+                .map_err(|e| self.map_redis_error(e, "record_spend_within_limit"))?;
+
+        if recorded {
+            debug!(
+                "Recorded spend of transaction {} for relayer {}",
+                transaction_id, relayer_id
+            );
+            return Ok(None);
+        }
+
+        let parse = |amount: String| {
+            amount.parse::<u128>().map_err(|e| {
+                RepositoryError::InvalidData(format!(
+                    "Invalid spend total {} of relayer {}: {}",
+                    amount, relayer_id, e
+                ))
+            })
+        };
+        Ok(Some(RelayerSpend {
+            value: parse(value)?,
+            gas: parse(gas)?,
+        }))
+    }
+
+    async fn remove_spend(
+        &self,
+        relayer_id: &str,
+        transaction_id: &str,
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        pipe.zrem(self.spend_key(relayer_id), transaction_id);
+        pipe.hdel(self.spend_amounts_key(relayer_id), transaction_id);
+        pipe.exec_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "remove_spend"))?;
+        Ok(())
+    }
+
+    async fn get_spend_since(
+        &self,
+        relayer_id: &str,
+        since: i64,
+    ) -> Result<RelayerSpend, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let spend_key = self.spend_key(relayer_id);
+        let amounts_key = self.spend_amounts_key(relayer_id);
+        let window_start = format!("({}", since);
+
+        // Prune records that fell out of the window
+        let stale_ids: Vec<String> = conn
+            .zrangebyscore(&spend_key, "-inf", &window_start)
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_spend_since_stale"))?;
+        if !stale_ids.is_empty() {
+            let mut pipe = redis::pipe();
+            pipe.atomic();
+            pipe.zrembyscore(&spend_key, "-inf", &window_start);
+            pipe.hdel(&amounts_key, &stale_ids);
+            pipe.exec_async(&mut conn)
+                .await
+                .map_err(|e| self.map_redis_error(e, "get_spend_since_prune"))?;
+        }
+
+        let ids: Vec<String> = conn
+            .zrangebyscore(&spend_key, since, "+inf")
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_spend_since_ids"))?;
+        if ids.is_empty() {
+            return Ok(RelayerSpend::default());
+        }
+
+        let values: Vec<Option<String>> = redis::cmd("HMGET")
+            .arg(&amounts_key)
+            .arg(&ids)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_spend_since_amounts"))?;
+
+        let mut total = RelayerSpend::default();
+        for (id, value) in ids.iter().zip(values) {
+            if let Some(json) = value {
+                let spend: RelayerSpend = self.deserialize_entity(&json, id, "spend")?;
+                total = total.saturating_add(spend);
+            }
+        }
+        Ok(total)
+    }
 }
 
 #[cfg(test)]
@@ -560,6 +784,7 @@ mod tests {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -853,6 +1078,16 @@ mod tests {
             whitelist_receivers: Some(vec!["0x123".to_string()]),
             eip1559_pricing: Some(true),
             private_transactions: Some(true),
+            simulate_transactions: None,
+            access_list_generation: None,
+            entry_point: None,
+            max_value_per_transaction: None,
+            max_total_value: None,
+            max_gas_spend: None,
+            spending_window: None,
+            allowed_selectors: None,
+            trusted_forwarder: None,
+            authorization_signers: None,
             min_balance: Some(1000000000000000000),
             gas_limit_estimation: Some(true),
         });
@@ -875,6 +1110,91 @@ mod tests {
         }
     }
 
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_spend_tracking() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let spend = RelayerSpend {
+            value: 100,
+            gas: 10,
+        };
+
+        repo.record_spend(&relayer_id, "tx-1", spend, 1_000)
+            .await
+            .unwrap();
+        repo.record_spend(&relayer_id, "tx-2", spend, 2_000)
+            .await
+            .unwrap();
+
+        let total = repo.get_spend_since(&relayer_id, 0).await.unwrap();
+        assert_eq!(
+            total,
+            RelayerSpend {
+                value: 200,
+                gas: 20
+            }
+        );
+
+        let total = repo.get_spend_since(&relayer_id, 1_500).await.unwrap();
+        assert_eq!(total, spend);
+
+        repo.remove_spend(&relayer_id, "tx-2").await.unwrap();
+        let total = repo.get_spend_since(&relayer_id, 0).await.unwrap();
+        assert_eq!(total, RelayerSpend::default());
+    }
+
+    #[ignore = "Requires active Redis instance"]
+    #[tokio::test]
+    async fn test_record_spend_within_limit() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        // Above the integer precision of Lua numbers
+        let spend = RelayerSpend {
+            value: 10_000_000_000_000_000_001,
+            gas: 10,
+        };
+        let limit = RelayerSpendLimit {
+            max_value: Some(20_000_000_000_000_000_002),
+            max_gas: None,
+        };
+
+        for tx_id in ["tx-1", "tx-2"] {
+            let exceeded = repo
+                .record_spend_within_limit(&relayer_id, tx_id, spend, 1_000, 0, limit)
+                .await
+                .unwrap();
+            assert_eq!(exceeded, None);
+        }
+
+        let exceeded = repo
+            .record_spend_within_limit(&relayer_id, "tx-3", spend, 1_000, 0, limit)
+            .await
+            .unwrap();
+        assert_eq!(
+            exceeded,
+            Some(RelayerSpend {
+                value: 30_000_000_000_000_000_003,
+                gas: 30
+            })
+        );
+
+        // Re-recording a transaction replaces its earlier spend
+        let exceeded = repo
+            .record_spend_within_limit(&relayer_id, "tx-2", spend, 1_000, 0, limit)
+            .await
+            .unwrap();
+        assert_eq!(exceeded, None);
+        let total = repo.get_spend_since(&relayer_id, 0).await.unwrap();
+        assert_eq!(
+            total,
+            RelayerSpend {
+                value: 20_000_000_000_000_000_002,
+                gas: 20
+            }
+        );
+    }
+
     #[ignore = "Requires active Redis instance"]
     #[tokio::test]
     async fn test_debug_implementation() {
@@ -0,0 +1,146 @@
+//! Relayer Pool Repository Module
+//!
+//! This module provides the relayer pool repository layer for the OpenZeppelin Relayer service.
+//! It implements the Repository pattern to abstract relayer pool persistence operations,
+//! supporting both in-memory and Redis-backed storage implementations.
+//!
+//! ## Repository Implementations
+//!
+//! - [`InMemoryRelayerPoolRepository`]: Fast in-memory storage for testing/development
+//! - [`RedisRelayerPoolRepository`]: Redis-backed storage for production environments
+
+mod relayer_pool_in_memory;
+mod relayer_pool_redis;
+
+pub use relayer_pool_in_memory::*;
+pub use relayer_pool_redis::*;
+
+use crate::{
+    models::{RelayerPoolRepoModel, RepositoryError},
+    repositories::{PaginatedResult, PaginationQuery, Repository},
+};
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use std::sync::Arc;
+
+/// Enum wrapper for different relayer pool repository implementations
+#[derive(Debug, Clone)]
+pub enum RelayerPoolRepositoryStorage {
+    InMemory(InMemoryRelayerPoolRepository),
+    Redis(RedisRelayerPoolRepository),
+}
+
+impl RelayerPoolRepositoryStorage {
+    pub fn new_in_memory() -> Self {
+        Self::InMemory(InMemoryRelayerPoolRepository::new())
+    }
+
+    pub fn new_redis(
+        connection_manager: Arc<ConnectionManager>,
+        key_prefix: String,
+    ) -> Result<Self, RepositoryError> {
+        let redis_repo = RedisRelayerPoolRepository::new(connection_manager, key_prefix)?;
+        Ok(Self::Redis(redis_repo))
+    }
+}
+
+#[async_trait]
+impl Repository<RelayerPoolRepoModel, String> for RelayerPoolRepositoryStorage {
+    async fn create(
+        &self,
+        entity: RelayerPoolRepoModel,
+    ) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.create(entity).await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.create(entity).await,
+        }
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.get_by_id(id).await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.get_by_id(id).await,
+        }
+    }
+
+    async fn list_all(&self) -> Result<Vec<RelayerPoolRepoModel>, RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.list_all().await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.list_all().await,
+        }
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerPoolRepoModel>, RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.list_paginated(query).await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.list_paginated(query).await,
+        }
+    }
+
+    async fn update(
+        &self,
+        id: String,
+        entity: RelayerPoolRepoModel,
+    ) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.update(id, entity).await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.update(id, entity).await,
+        }
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.delete_by_id(id).await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.delete_by_id(id).await,
+        }
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.count().await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.count().await,
+        }
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.has_entries().await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.has_entries().await,
+        }
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        match self {
+            RelayerPoolRepositoryStorage::InMemory(repo) => repo.drop_all_entries().await,
+            RelayerPoolRepositoryStorage::Redis(repo) => repo.drop_all_entries().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NetworkType;
+
+    #[actix_web::test]
+    async fn test_in_memory_storage_operations() {
+        let repo = RelayerPoolRepositoryStorage::new_in_memory();
+        let pool = RelayerPoolRepoModel {
+            id: "pool-1".to_string(),
+            name: "Pool".to_string(),
+            network: "sepolia".to_string(),
+            network_type: NetworkType::Evm,
+            relayer_ids: vec!["relayer-1".to_string()],
+        };
+
+        repo.create(pool.clone()).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 1);
+        assert_eq!(repo.list_all().await.unwrap(), vec![pool]);
+
+        repo.delete_by_id("pool-1".to_string()).await.unwrap();
+        assert!(!repo.has_entries().await.unwrap());
+    }
+}
@@ -0,0 +1,239 @@
+//! This module defines an in-memory repository for managing relayer pool models.
+//! It provides asynchronous CRUD operations and supports pagination.
+//! The repository is thread-safe, using a `Mutex` to protect access to the underlying data store.
+use crate::{
+    models::{RelayerPoolRepoModel, RepositoryError},
+    repositories::*,
+};
+use async_trait::async_trait;
+use eyre::Result;
+use std::collections::HashMap;
+use tokio::sync::{Mutex, MutexGuard};
+
+#[derive(Debug)]
+pub struct InMemoryRelayerPoolRepository {
+    store: Mutex<HashMap<String, RelayerPoolRepoModel>>,
+}
+
+impl Clone for InMemoryRelayerPoolRepository {
+    fn clone(&self) -> Self {
+        // Try to get the current data, or use empty HashMap if lock fails
+        let data = self
+            .store
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
+
+        Self {
+            store: Mutex::new(data),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl InMemoryRelayerPoolRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn acquire_lock<T>(lock: &Mutex<T>) -> Result<MutexGuard<T>, RepositoryError> {
+        Ok(lock.lock().await)
+    }
+}
+
+impl Default for InMemoryRelayerPoolRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Repository<RelayerPoolRepoModel, String> for InMemoryRelayerPoolRepository {
+    async fn create(
+        &self,
+        pool: RelayerPoolRepoModel,
+    ) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        if store.contains_key(&pool.id) {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "Relayer pool with ID {} already exists",
+                pool.id
+            )));
+        }
+        store.insert(pool.id.clone(), pool.clone());
+        Ok(pool)
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        match store.get(&id) {
+            Some(pool) => Ok(pool.clone()),
+            None => Err(RepositoryError::NotFound(format!(
+                "Relayer pool with ID {} not found",
+                id
+            ))),
+        }
+    }
+
+    #[allow(clippy::map_entry)]
+    async fn update(
+        &self,
+        id: String,
+        pool: RelayerPoolRepoModel,
+    ) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        if !store.contains_key(&id) {
+            return Err(RepositoryError::NotFound(format!(
+                "Relayer pool with ID {} not found",
+                id
+            )));
+        }
+        if id != pool.id {
+            return Err(RepositoryError::InvalidData(format!(
+                "ID mismatch: URL parameter '{}' does not match entity ID '{}'",
+                id, pool.id
+            )));
+        }
+        store.insert(id, pool.clone());
+        Ok(pool)
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        match store.remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(RepositoryError::NotFound(format!(
+                "Relayer pool with ID {} not found",
+                id
+            ))),
+        }
+    }
+
+    async fn list_all(&self) -> Result<Vec<RelayerPoolRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(store.values().cloned().collect())
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerPoolRepoModel>, RepositoryError> {
+        let total = self.count().await?;
+        let start = ((query.page - 1) * query.per_page) as usize;
+        let items: Vec<RelayerPoolRepoModel> = self
+            .store
+            .lock()
+            .await
+            .values()
+            .skip(start)
+            .take(query.per_page as usize)
+            .cloned()
+            .collect();
+
+        Ok(PaginatedResult {
+            items,
+            total: total as u64,
+            page: query.page,
+            per_page: query.per_page,
+        })
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(store.len())
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(!store.is_empty())
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        let mut store = Self::acquire_lock(&self.store).await?;
+        store.clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NetworkType;
+
+    fn create_test_pool(id: &str) -> RelayerPoolRepoModel {
+        RelayerPoolRepoModel {
+            id: id.to_string(),
+            name: format!("{}-name", id),
+            network: "sepolia".to_string(),
+            network_type: NetworkType::Evm,
+            relayer_ids: vec!["relayer-1".to_string(), "relayer-2".to_string()],
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_create_and_get_pool() {
+        let repo = InMemoryRelayerPoolRepository::new();
+        let pool = create_test_pool("pool-1");
+
+        repo.create(pool.clone()).await.unwrap();
+        assert_eq!(repo.count().await.unwrap(), 1);
+        assert_eq!(repo.get_by_id("pool-1".to_string()).await.unwrap(), pool);
+
+        let result = repo.create(pool).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryError::ConstraintViolation(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_update_pool() {
+        let repo = InMemoryRelayerPoolRepository::new();
+        let mut pool = create_test_pool("pool-1");
+        repo.create(pool.clone()).await.unwrap();
+
+        pool.relayer_ids = vec!["relayer-3".to_string()];
+        repo.update("pool-1".to_string(), pool.clone())
+            .await
+            .unwrap();
+        assert_eq!(repo.get_by_id("pool-1".to_string()).await.unwrap(), pool);
+
+        assert!(matches!(
+            repo.update("pool-2".to_string(), pool).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_delete_pool() {
+        let repo = InMemoryRelayerPoolRepository::new();
+        repo.create(create_test_pool("pool-1")).await.unwrap();
+
+        repo.delete_by_id("pool-1".to_string()).await.unwrap();
+        assert!(!repo.has_entries().await.unwrap());
+        assert!(matches!(
+            repo.delete_by_id("pool-1".to_string()).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_list_paginated() {
+        let repo = InMemoryRelayerPoolRepository::new();
+        for id in ["pool-1", "pool-2", "pool-3"] {
+            repo.create(create_test_pool(id)).await.unwrap();
+        }
+
+        let page = repo
+            .list_paginated(PaginationQuery {
+                page: 2,
+                per_page: 2,
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items.len(), 1);
+    }
+}
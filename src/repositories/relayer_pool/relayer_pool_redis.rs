@@ -0,0 +1,483 @@
+//! Redis-backed implementation of the relayer pool repository.
+
+use crate::models::{RelayerPoolRepoModel, RepositoryError};
+use crate::repositories::redis_base::RedisRepository;
+use crate::repositories::*;
+use async_trait::async_trait;
+use log::{debug, error, warn};
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, RedisError};
+use std::fmt;
+use std::sync::Arc;
+
+const RELAYER_POOL_PREFIX: &str = "relayer_pool";
+const RELAYER_POOL_LIST_KEY: &str = "relayer_pool_list";
+
+#[derive(Clone)]
+pub struct RedisRelayerPoolRepository {
+    pub client: Arc<ConnectionManager>,
+    pub key_prefix: String,
+}
+
+impl RedisRepository for RedisRelayerPoolRepository {}
+
+impl RedisRelayerPoolRepository {
+    pub fn new(
+        connection_manager: Arc<ConnectionManager>,
+        key_prefix: String,
+    ) -> Result<Self, RepositoryError> {
+        if key_prefix.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Redis key prefix cannot be empty".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            client: connection_manager,
+            key_prefix,
+        })
+    }
+
+    fn entry_key(&self, id: &str) -> String {
+        format!("{}:{}:{}", self.key_prefix, RELAYER_POOL_PREFIX, id)
+    }
+
+    fn pool_list_key(&self) -> String {
+        format!("{}:{}", self.key_prefix, RELAYER_POOL_LIST_KEY)
+    }
+
+    async fn add_to_list(&self, id: &str) -> Result<(), RepositoryError> {
+        let key = self.pool_list_key();
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<i64, RedisError> = conn.sadd(&key, id).await;
+        result.map_err(|e| {
+            error!("Failed to add relayer pool {} to list: {}", id, e);
+            RepositoryError::Other(format!("Failed to add relayer pool to list: {}", e))
+        })?;
+
+        debug!("Added relayer pool {} to list", id);
+        Ok(())
+    }
+
+    async fn remove_from_list(&self, id: &str) -> Result<(), RepositoryError> {
+        let key = self.pool_list_key();
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<i64, RedisError> = conn.srem(&key, id).await;
+        result.map_err(|e| {
+            error!("Failed to remove relayer pool {} from list: {}", id, e);
+            RepositoryError::Other(format!("Failed to remove relayer pool from list: {}", e))
+        })?;
+
+        debug!("Removed relayer pool {} from list", id);
+        Ok(())
+    }
+
+    async fn get_all_ids(&self) -> Result<Vec<String>, RepositoryError> {
+        let key = self.pool_list_key();
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<Vec<String>, RedisError> = conn.smembers(&key).await;
+        result.map_err(|e| {
+            error!("Failed to get relayer pool IDs: {}", e);
+            RepositoryError::Other(format!("Failed to get relayer pool IDs: {}", e))
+        })
+    }
+
+    /// Batch fetch relayer pools by IDs
+    async fn get_pools_by_ids(
+        &self,
+        ids: &[String],
+    ) -> Result<BatchRetrievalResult<RelayerPoolRepoModel>, RepositoryError> {
+        if ids.is_empty() {
+            debug!("No relayer pool IDs provided for batch fetch");
+            return Ok(BatchRetrievalResult {
+                results: vec![],
+                failed_ids: vec![],
+            });
+        }
+
+        let mut conn = self.client.as_ref().clone();
+        let keys: Vec<String> = ids.iter().map(|id| self.entry_key(id)).collect();
+
+        debug!("Batch fetching {} relayer pools", ids.len());
+
+        let values: Vec<Option<String>> = conn
+            .mget(&keys)
+            .await
+            .map_err(|e| self.map_redis_error(e, "batch_fetch_relayer_pools"))?;
+
+        let mut pools = Vec::new();
+        let mut failed_count = 0;
+        let mut failed_ids = Vec::new();
+
+        for (i, value) in values.into_iter().enumerate() {
+            match value {
+                Some(json) => {
+                    match self.deserialize_entity::<RelayerPoolRepoModel>(
+                        &json,
+                        &ids[i],
+                        "relayer_pool",
+                    ) {
+                        Ok(pool) => pools.push(pool),
+                        Err(e) => {
+                            failed_count += 1;
+                            error!("Failed to deserialize relayer pool {}: {}", ids[i], e);
+                            failed_ids.push(ids[i].clone());
+                        }
+                    }
+                }
+                None => {
+                    warn!("Relayer pool {} not found in batch fetch", ids[i]);
+                }
+            }
+        }
+
+        if failed_count > 0 {
+            warn!(
+                "Failed to deserialize {} out of {} relayer pools in batch",
+                failed_count,
+                ids.len()
+            );
+            warn!("Failed to deserialize relayer pools: {:?}", failed_ids);
+        }
+
+        debug!("Successfully fetched {} relayer pools", pools.len());
+        Ok(BatchRetrievalResult {
+            results: pools,
+            failed_ids,
+        })
+    }
+}
+
+impl fmt::Debug for RedisRelayerPoolRepository {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisRelayerPoolRepository")
+            .field("key_prefix", &self.key_prefix)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl Repository<RelayerPoolRepoModel, String> for RedisRelayerPoolRepository {
+    async fn create(
+        &self,
+        pool: RelayerPoolRepoModel,
+    ) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        if pool.id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer pool ID cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&pool.id);
+        let mut conn = self.client.as_ref().clone();
+
+        // Check if relayer pool already exists
+        let exists: Result<bool, RedisError> = conn.exists(&key).await;
+        match exists {
+            Ok(true) => {
+                return Err(RepositoryError::ConstraintViolation(format!(
+                    "Relayer pool with ID {} already exists",
+                    pool.id
+                )));
+            }
+            Ok(false) => {
+                // Continue with creation
+            }
+            Err(e) => {
+                error!("Failed to check if relayer pool exists: {}", e);
+                return Err(RepositoryError::Other(format!(
+                    "Failed to check relayer pool existence: {}",
+                    e
+                )));
+            }
+        }
+
+        let serialized = self.serialize_entity(&pool, |s| &s.id, "relayer_pool")?;
+
+        // Store relayer pool
+        let result: Result<(), RedisError> = conn.set(&key, &serialized).await;
+        result.map_err(|e| {
+            error!("Failed to store relayer pool {}: {}", pool.id, e);
+            RepositoryError::Other(format!("Failed to store relayer pool: {}", e))
+        })?;
+
+        // Add to list
+        self.add_to_list(&pool.id).await?;
+
+        debug!("Created relayer pool with ID: {}", pool.id);
+        Ok(pool)
+    }
+
+    async fn get_by_id(&self, id: String) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        if id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer pool ID cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&id);
+        let mut conn = self.client.as_ref().clone();
+
+        let result: Result<Option<String>, RedisError> = conn.get(&key).await;
+        match result {
+            Ok(Some(data)) => {
+                let pool =
+                    self.deserialize_entity::<RelayerPoolRepoModel>(&data, &id, "relayer_pool")?;
+                debug!("Retrieved relayer pool with ID: {}", id);
+                Ok(pool)
+            }
+            Ok(None) => {
+                debug!("Relayer pool with ID {} not found", id);
+                Err(RepositoryError::NotFound(format!(
+                    "Relayer pool with ID {} not found",
+                    id
+                )))
+            }
+            Err(e) => {
+                error!("Failed to retrieve relayer pool {}: {}", id, e);
+                Err(RepositoryError::Other(format!(
+                    "Failed to retrieve relayer pool: {}",
+                    e
+                )))
+            }
+        }
+    }
+
+    async fn update(
+        &self,
+        id: String,
+        pool: RelayerPoolRepoModel,
+    ) -> Result<RelayerPoolRepoModel, RepositoryError> {
+        if id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer pool ID cannot be empty".to_string(),
+            ));
+        }
+
+        if pool.id != id {
+            return Err(RepositoryError::InvalidData(
+                "Relayer pool ID in data does not match provided ID".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&id);
+        let mut conn = self.client.as_ref().clone();
+
+        // Make sure the relayer pool exists before overwriting it
+        self.get_by_id(id.clone()).await?;
+
+        let serialized = self.serialize_entity(&pool, |s| &s.id, "relayer_pool")?;
+
+        let result: Result<(), RedisError> = conn.set(&key, &serialized).await;
+        result.map_err(|e| {
+            error!("Failed to update relayer pool {}: {}", id, e);
+            RepositoryError::Other(format!("Failed to update relayer pool: {}", e))
+        })?;
+
+        debug!("Updated relayer pool with ID: {}", id);
+        Ok(pool)
+    }
+
+    async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
+        if id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer pool ID cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.entry_key(&id);
+        let mut conn = self.client.as_ref().clone();
+
+        // Make sure the relayer pool exists before deleting it
+        self.get_by_id(id.clone()).await?;
+
+        let result: Result<(), RedisError> = conn.del(&key).await;
+        result.map_err(|e| {
+            error!("Failed to delete relayer pool {}: {}", id, e);
+            RepositoryError::Other(format!("Failed to delete relayer pool: {}", e))
+        })?;
+
+        // Remove from list
+        self.remove_from_list(&id).await?;
+
+        debug!("Deleted relayer pool with ID: {}", id);
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<RelayerPoolRepoModel>, RepositoryError> {
+        let ids = self.get_all_ids().await?;
+
+        if ids.is_empty() {
+            debug!("No relayer pools found");
+            return Ok(Vec::new());
+        }
+
+        let pools = self.get_pools_by_ids(&ids).await?;
+        debug!("Successfully fetched {} relayer pools", pools.results.len());
+        Ok(pools.results)
+    }
+
+    async fn list_paginated(
+        &self,
+        query: PaginationQuery,
+    ) -> Result<PaginatedResult<RelayerPoolRepoModel>, RepositoryError> {
+        if query.per_page == 0 {
+            return Err(RepositoryError::InvalidData(
+                "per_page must be greater than 0".to_string(),
+            ));
+        }
+
+        debug!(
+            "Listing paginated relayer pools: page {}, per_page {}",
+            query.page, query.per_page
+        );
+
+        let all_ids: Vec<String> = self.get_all_ids().await?;
+        let total = all_ids.len() as u64;
+        let per_page = query.per_page as usize;
+        let page = query.page as usize;
+        let total_pages = all_ids.len().div_ceil(per_page);
+
+        if page > total_pages && !all_ids.is_empty() {
+            debug!(
+                "Requested page {} exceeds total pages {}",
+                page, total_pages
+            );
+            return Ok(PaginatedResult {
+                items: Vec::new(),
+                total,
+                page: query.page,
+                per_page: query.per_page,
+            });
+        }
+
+        let start_idx = (page - 1) * per_page;
+        let end_idx = std::cmp::min(start_idx + per_page, all_ids.len());
+
+        let page_ids = all_ids[start_idx..end_idx].to_vec();
+        let pools = self.get_pools_by_ids(&page_ids).await?;
+
+        debug!(
+            "Successfully retrieved {} relayer pools for page {}",
+            pools.results.len(),
+            query.page
+        );
+        Ok(PaginatedResult {
+            items: pools.results.clone(),
+            total,
+            page: query.page,
+            per_page: query.per_page,
+        })
+    }
+
+    async fn count(&self) -> Result<usize, RepositoryError> {
+        let ids = self.get_all_ids().await?;
+        Ok(ids.len())
+    }
+
+    async fn has_entries(&self) -> Result<bool, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let pool_list_key = self.pool_list_key();
+
+        debug!("Checking if relayer pool entries exist");
+
+        let exists: bool = conn
+            .exists(&pool_list_key)
+            .await
+            .map_err(|e| self.map_redis_error(e, "has_entries_check"))?;
+
+        debug!("Relayer pool entries exist: {}", exists);
+        Ok(exists)
+    }
+
+    async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let pool_list_key = self.pool_list_key();
+
+        debug!("Dropping all relayer pool entries");
+
+        // Get all relayer pool IDs first
+        let pool_ids: Vec<String> = conn
+            .smembers(&pool_list_key)
+            .await
+            .map_err(|e| self.map_redis_error(e, "drop_all_entries_get_ids"))?;
+
+        if pool_ids.is_empty() {
+            debug!("No relayer pool entries to drop");
+            return Ok(());
+        }
+
+        // Use pipeline for atomic operations
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+
+        // Delete all individual relayer pool entries
+        for pool_id in &pool_ids {
+            let entry_key = self.entry_key(pool_id);
+            pipe.del(&entry_key);
+        }
+
+        // Delete the relayer pool list key
+        pipe.del(&pool_list_key);
+
+        pipe.exec_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "drop_all_entries_pipeline"))?;
+
+        debug!("Dropped {} relayer pool entries", pool_ids.len());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NetworkType;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn create_test_pool(id: &str) -> RelayerPoolRepoModel {
+        RelayerPoolRepoModel {
+            id: id.to_string(),
+            name: format!("{}-name", id),
+            network: "sepolia".to_string(),
+            network_type: NetworkType::Evm,
+            relayer_ids: vec!["relayer-1".to_string(), "relayer-2".to_string()],
+        }
+    }
+
+    async fn setup_test_repo() -> RedisRelayerPoolRepository {
+        let client =
+            redis::Client::open("redis://127.0.0.1:6379/").expect("Failed to create Redis client");
+        let connection_manager = redis::aio::ConnectionManager::new(client)
+            .await
+            .expect("Failed to create connection manager");
+
+        RedisRelayerPoolRepository::new(Arc::new(connection_manager), "test".to_string())
+            .expect("Failed to create repository")
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_create_update_and_delete_pool() {
+        let repo = setup_test_repo().await;
+        let id = Uuid::new_v4().to_string();
+        let mut pool = create_test_pool(&id);
+
+        repo.create(pool.clone()).await.unwrap();
+        assert_eq!(repo.get_by_id(id.clone()).await.unwrap(), pool);
+
+        pool.relayer_ids = vec!["relayer-3".to_string()];
+        repo.update(id.clone(), pool.clone()).await.unwrap();
+        assert_eq!(repo.get_by_id(id.clone()).await.unwrap(), pool);
+
+        repo.delete_by_id(id.clone()).await.unwrap();
+        assert!(matches!(
+            repo.get_by_id(id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+}
@@ -59,6 +59,52 @@ pub trait TransactionRepository: Repository<TransactionRepoModel, String> {
         nonce: u64,
     ) -> Result<Option<TransactionRepoModel>, RepositoryError>;
 
+    /// Find a transaction by relayer ID and idempotency key
+    async fn find_by_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError>;
+
+    /// Atomically reserve an idempotency key for `ttl_seconds`, returning the ID the key is
+    /// already reserved for, if any. Creating a transaction with the key takes over the
+    /// reservation.
+    async fn reserve_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+        ttl_seconds: u64,
+    ) -> Result<Option<String>, RepositoryError>;
+
+    /// Release an idempotency key if it is still reserved for `reservation_id`
+    async fn release_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// Find the EVM bundle transaction carrying an ERC-4337 user operation
+    async fn find_by_user_operation_hash(
+        &self,
+        relayer_id: &str,
+        user_operation_hash: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError>;
+
+    /// Atomically replace the network data of an EVM bundle that is pending, not sealed and
+    /// still carries `operation_count` user operations. Returns `None` if the bundle was
+    /// sealed or changed in the meantime.
+    async fn update_open_bundle(
+        &self,
+        tx_id: String,
+        operation_count: usize,
+        network_data: NetworkTransactionData,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError>;
+
+    /// Seal an EVM bundle, so no more user operations are added to it
+    async fn seal_bundle(&self, tx_id: String) -> Result<(), RepositoryError>;
+
     /// Update the status of a transaction
     async fn update_status(
         &self,
@@ -117,6 +163,12 @@ mockall::mock! {
       async fn find_by_relayer_id(&self, relayer_id: &str, query: PaginationQuery) -> Result<PaginatedResult<TransactionRepoModel>, RepositoryError>;
       async fn find_by_status(&self, relayer_id: &str, statuses: &[TransactionStatus]) -> Result<Vec<TransactionRepoModel>, RepositoryError>;
       async fn find_by_nonce(&self, relayer_id: &str, nonce: u64) -> Result<Option<TransactionRepoModel>, RepositoryError>;
+      async fn find_by_idempotency_key(&self, relayer_id: &str, idempotency_key: &str) -> Result<Option<TransactionRepoModel>, RepositoryError>;
+      async fn reserve_idempotency_key(&self, relayer_id: &str, idempotency_key: &str, reservation_id: &str, ttl_seconds: u64) -> Result<Option<String>, RepositoryError>;
+      async fn release_idempotency_key(&self, relayer_id: &str, idempotency_key: &str, reservation_id: &str) -> Result<(), RepositoryError>;
+      async fn find_by_user_operation_hash(&self, relayer_id: &str, user_operation_hash: &str) -> Result<Option<TransactionRepoModel>, RepositoryError>;
+      async fn update_open_bundle(&self, tx_id: String, operation_count: usize, network_data: NetworkTransactionData) -> Result<Option<TransactionRepoModel>, RepositoryError>;
+      async fn seal_bundle(&self, tx_id: String) -> Result<(), RepositoryError>;
       async fn update_status(&self, tx_id: String, status: TransactionStatus) -> Result<TransactionRepoModel, RepositoryError>;
       async fn partial_update(&self, tx_id: String, update: TransactionUpdateRequest) -> Result<TransactionRepoModel, RepositoryError>;
       async fn update_network_data(&self, tx_id: String, network_data: NetworkTransactionData) -> Result<TransactionRepoModel, RepositoryError>;
@@ -195,6 +247,112 @@ impl TransactionRepository for TransactionRepositoryStorage {
         }
     }
 
+    async fn find_by_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        match self {
+            TransactionRepositoryStorage::InMemory(repo) => {
+                repo.find_by_idempotency_key(relayer_id, idempotency_key)
+                    .await
+            }
+            TransactionRepositoryStorage::Redis(repo) => {
+                repo.find_by_idempotency_key(relayer_id, idempotency_key)
+                    .await
+            }
+        }
+    }
+
+    async fn reserve_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+        ttl_seconds: u64,
+    ) -> Result<Option<String>, RepositoryError> {
+        match self {
+            TransactionRepositoryStorage::InMemory(repo) => {
+                repo.reserve_idempotency_key(
+                    relayer_id,
+                    idempotency_key,
+                    reservation_id,
+                    ttl_seconds,
+                )
+                .await
+            }
+            TransactionRepositoryStorage::Redis(repo) => {
+                repo.reserve_idempotency_key(
+                    relayer_id,
+                    idempotency_key,
+                    reservation_id,
+                    ttl_seconds,
+                )
+                .await
+            }
+        }
+    }
+
+    async fn release_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+    ) -> Result<(), RepositoryError> {
+        match self {
+            TransactionRepositoryStorage::InMemory(repo) => {
+                repo.release_idempotency_key(relayer_id, idempotency_key, reservation_id)
+                    .await
+            }
+            TransactionRepositoryStorage::Redis(repo) => {
+                repo.release_idempotency_key(relayer_id, idempotency_key, reservation_id)
+                    .await
+            }
+        }
+    }
+
+    async fn find_by_user_operation_hash(
+        &self,
+        relayer_id: &str,
+        user_operation_hash: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        match self {
+            TransactionRepositoryStorage::InMemory(repo) => {
+                repo.find_by_user_operation_hash(relayer_id, user_operation_hash)
+                    .await
+            }
+            TransactionRepositoryStorage::Redis(repo) => {
+                repo.find_by_user_operation_hash(relayer_id, user_operation_hash)
+                    .await
+            }
+        }
+    }
+
+    async fn update_open_bundle(
+        &self,
+        tx_id: String,
+        operation_count: usize,
+        network_data: NetworkTransactionData,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        match self {
+            TransactionRepositoryStorage::InMemory(repo) => {
+                repo.update_open_bundle(tx_id, operation_count, network_data)
+                    .await
+            }
+            TransactionRepositoryStorage::Redis(repo) => {
+                repo.update_open_bundle(tx_id, operation_count, network_data)
+                    .await
+            }
+        }
+    }
+
+    async fn seal_bundle(&self, tx_id: String) -> Result<(), RepositoryError> {
+        match self {
+            TransactionRepositoryStorage::InMemory(repo) => repo.seal_bundle(tx_id).await,
+            TransactionRepositoryStorage::Redis(repo) => repo.seal_bundle(tx_id).await,
+        }
+    }
+
     async fn update_status(
         &self,
         tx_id: String,
@@ -389,6 +547,7 @@ mod tests {
             noop_count: None,
             is_canceled: None,
             delete_at: None,
+            clear_schedule: None,
         }
     }
 
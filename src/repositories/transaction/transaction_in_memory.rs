@@ -11,14 +11,21 @@ use crate::{
     repositories::*,
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use eyre::Result;
 use itertools::Itertools;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tokio::sync::{Mutex, MutexGuard};
 
+/// Idempotency key reservations by relayer ID and key, with the reserved ID and the
+/// timestamp the reservation expires at
+type IdempotencyReservations = HashMap<(String, String), (String, i64)>;
+
 #[derive(Debug)]
 pub struct InMemoryTransactionRepository {
     store: Mutex<HashMap<String, TransactionRepoModel>>,
+    idempotency_reservations: Mutex<IdempotencyReservations>,
+    sealed_bundles: Mutex<HashSet<String>>,
 }
 
 impl Clone for InMemoryTransactionRepository {
@@ -29,9 +36,21 @@ impl Clone for InMemoryTransactionRepository {
             .try_lock()
             .map(|guard| guard.clone())
             .unwrap_or_else(|_| HashMap::new());
+        let reservations = self
+            .idempotency_reservations
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashMap::new());
+        let sealed_bundles = self
+            .sealed_bundles
+            .try_lock()
+            .map(|guard| guard.clone())
+            .unwrap_or_else(|_| HashSet::new());
 
         Self {
             store: Mutex::new(data),
+            idempotency_reservations: Mutex::new(reservations),
+            sealed_bundles: Mutex::new(sealed_bundles),
         }
     }
 }
@@ -40,6 +59,8 @@ impl InMemoryTransactionRepository {
     pub fn new() -> Self {
         Self {
             store: Mutex::new(HashMap::new()),
+            idempotency_reservations: Mutex::new(HashMap::new()),
+            sealed_bundles: Mutex::new(HashSet::new()),
         }
     }
 
@@ -64,6 +85,15 @@ impl Repository<TransactionRepoModel, String> for InMemoryTransactionRepository
             )));
         }
         store.insert(tx.id.clone(), tx.clone());
+
+        if let Some(idempotency_key) = &tx.idempotency_key {
+            let mut reservations = Self::acquire_lock(&self.idempotency_reservations).await?;
+            if let Some(reservation) =
+                reservations.get_mut(&(tx.relayer_id.clone(), idempotency_key.clone()))
+            {
+                reservation.0 = tx.id.clone();
+            }
+        }
         Ok(tx)
     }
 
@@ -96,7 +126,17 @@ impl Repository<TransactionRepoModel, String> for InMemoryTransactionRepository
 
     async fn delete_by_id(&self, id: String) -> Result<(), RepositoryError> {
         let mut store = Self::acquire_lock(&self.store).await?;
-        if store.remove(&id).is_some() {
+        if let Some(tx) = store.remove(&id) {
+            if let Some(idempotency_key) = tx.idempotency_key {
+                let mut reservations = Self::acquire_lock(&self.idempotency_reservations).await?;
+                let key = (tx.relayer_id, idempotency_key);
+                if reservations
+                    .get(&key)
+                    .is_some_and(|(holder, _)| *holder == id)
+                {
+                    reservations.remove(&key);
+                }
+            }
             Ok(())
         } else {
             Err(RepositoryError::NotFound(format!(
@@ -146,6 +186,10 @@ impl Repository<TransactionRepoModel, String> for InMemoryTransactionRepository
     async fn drop_all_entries(&self) -> Result<(), RepositoryError> {
         let mut store = Self::acquire_lock(&self.store).await?;
         store.clear();
+        Self::acquire_lock(&self.idempotency_reservations)
+            .await?
+            .clear();
+        Self::acquire_lock(&self.sealed_bundles).await?.clear();
         Ok(())
     }
 }
@@ -235,6 +279,123 @@ impl TransactionRepository for InMemoryTransactionRepository {
         Ok(filtered.into_iter().next())
     }
 
+    async fn find_by_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        // A key can be reused once its window has passed, so the latest match is the one
+        // the key currently identifies
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(store
+            .values()
+            .filter(|tx| {
+                tx.relayer_id == relayer_id
+                    && tx.idempotency_key.as_deref() == Some(idempotency_key)
+            })
+            .max_by_key(|tx| DateTime::parse_from_rfc3339(&tx.created_at).ok())
+            .cloned())
+    }
+
+    async fn reserve_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+        ttl_seconds: u64,
+    ) -> Result<Option<String>, RepositoryError> {
+        // Held across the check and the insert so that creations and other reservations
+        // see either no reservation or the complete one.
+        let _store = Self::acquire_lock(&self.store).await?;
+        let mut reservations = Self::acquire_lock(&self.idempotency_reservations).await?;
+        let now = Utc::now().timestamp();
+        let key = (relayer_id.to_string(), idempotency_key.to_string());
+
+        if let Some((holder, expires_at)) = reservations.get(&key) {
+            if *expires_at > now {
+                return Ok(Some(holder.clone()));
+            }
+        }
+        reservations.insert(key, (reservation_id.to_string(), now + ttl_seconds as i64));
+        Ok(None)
+    }
+
+    async fn release_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+    ) -> Result<(), RepositoryError> {
+        let _store = Self::acquire_lock(&self.store).await?;
+        let mut reservations = Self::acquire_lock(&self.idempotency_reservations).await?;
+        let key = (relayer_id.to_string(), idempotency_key.to_string());
+        if reservations
+            .get(&key)
+            .is_some_and(|(holder, _)| holder == reservation_id)
+        {
+            reservations.remove(&key);
+        }
+        Ok(())
+    }
+
+    async fn find_by_user_operation_hash(
+        &self,
+        relayer_id: &str,
+        user_operation_hash: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        let store = Self::acquire_lock(&self.store).await?;
+        Ok(store
+            .values()
+            .find(|tx| {
+                tx.relayer_id == relayer_id
+                    && tx
+                        .network_data
+                        .get_evm_transaction_data()
+                        .ok()
+                        .and_then(|data| data.user_operation_hashes)
+                        .is_some_and(|hashes| hashes.iter().any(|h| h == user_operation_hash))
+            })
+            .cloned())
+    }
+
+    async fn update_open_bundle(
+        &self,
+        tx_id: String,
+        operation_count: usize,
+        network_data: NetworkTransactionData,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        // Held across the check and the update, like sealing
+        let mut store = Self::acquire_lock(&self.store).await?;
+        let sealed_bundles = Self::acquire_lock(&self.sealed_bundles).await?;
+
+        let tx = store.get_mut(&tx_id).ok_or_else(|| {
+            RepositoryError::NotFound(format!("Transaction with ID {} not found", tx_id))
+        })?;
+        let current_count = tx
+            .network_data
+            .get_evm_transaction_data()
+            .ok()
+            .and_then(|data| data.user_operation_hashes)
+            .map(|hashes| hashes.len());
+        if sealed_bundles.contains(&tx_id)
+            || tx.status != TransactionStatus::Pending
+            || current_count != Some(operation_count)
+        {
+            return Ok(None);
+        }
+
+        tx.network_data = network_data;
+        Ok(Some(tx.clone()))
+    }
+
+    async fn seal_bundle(&self, tx_id: String) -> Result<(), RepositoryError> {
+        let _store = Self::acquire_lock(&self.store).await?;
+        Self::acquire_lock(&self.sealed_bundles)
+            .await?
+            .insert(tx_id);
+        Ok(())
+    }
+
     async fn update_status(
         &self,
         tx_id: String,
@@ -348,9 +509,23 @@ mod tests {
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -383,9 +558,23 @@ mod tests {
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -508,6 +697,7 @@ mod tests {
             noop_count: None,
             is_canceled: None,
             delete_at: None,
+            clear_schedule: None,
         };
         let updated_tx1 = repo
             .partial_update("test-tx-id".to_string(), update1)
@@ -528,6 +718,7 @@ mod tests {
             noop_count: None,
             is_canceled: None,
             delete_at: None,
+            clear_schedule: None,
         };
         let updated_tx2 = repo
             .partial_update("test-tx-id".to_string(), update2)
@@ -555,6 +746,7 @@ mod tests {
             noop_count: None,
             is_canceled: None,
             delete_at: None,
+            clear_schedule: None,
         };
         let result = repo
             .partial_update("non-existent-id".to_string(), update3)
@@ -694,6 +886,171 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    async fn test_find_by_idempotency_key_returns_latest() {
+        let repo = InMemoryTransactionRepository::new();
+
+        // The key was used again after the window of the first transaction passed
+        for (id, created_at) in [
+            ("test-1", "2025-01-01T00:00:00+00:00"),
+            ("test-2", "2025-01-03T00:00:00+00:00"),
+            ("test-3", "2025-01-02T00:00:00+00:00"),
+        ] {
+            let mut tx = create_test_transaction(id);
+            tx.idempotency_key = Some("order-1".to_string());
+            tx.created_at = created_at.to_string();
+            repo.create(tx).await.unwrap();
+        }
+
+        let result = repo
+            .find_by_idempotency_key("relayer-1", "order-1")
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().id, "test-2");
+    }
+
+    #[tokio::test]
+    async fn test_reserve_idempotency_key() {
+        let repo = InMemoryTransactionRepository::new();
+
+        let holder = repo
+            .reserve_idempotency_key("relayer-1", "order-1", "reservation-1", 60)
+            .await
+            .unwrap();
+        assert!(holder.is_none());
+
+        let holder = repo
+            .reserve_idempotency_key("relayer-1", "order-1", "reservation-2", 60)
+            .await
+            .unwrap();
+        assert_eq!(holder, Some("reservation-1".to_string()));
+
+        // Creating the transaction takes over the reservation
+        let mut tx = create_test_transaction("tx-1");
+        tx.relayer_id = "relayer-1".to_string();
+        tx.idempotency_key = Some("order-1".to_string());
+        repo.create(tx).await.unwrap();
+        let holder = repo
+            .reserve_idempotency_key("relayer-1", "order-1", "reservation-2", 60)
+            .await
+            .unwrap();
+        assert_eq!(holder, Some("tx-1".to_string()));
+
+        // Only the holder can release the key
+        repo.release_idempotency_key("relayer-1", "order-1", "reservation-1")
+            .await
+            .unwrap();
+        let holder = repo
+            .reserve_idempotency_key("relayer-1", "order-1", "reservation-2", 60)
+            .await
+            .unwrap();
+        assert_eq!(holder, Some("tx-1".to_string()));
+
+        repo.delete_by_id("tx-1".to_string()).await.unwrap();
+        let holder = repo
+            .reserve_idempotency_key("relayer-1", "order-1", "reservation-2", 60)
+            .await
+            .unwrap();
+        assert!(holder.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_idempotency_key() {
+        let repo = InMemoryTransactionRepository::new();
+
+        let mut tx1 = create_test_transaction("test-1");
+        tx1.idempotency_key = Some("order-1".to_string());
+        let mut tx2 = create_test_transaction("test-2");
+        tx2.relayer_id = "relayer-2".to_string();
+        tx2.idempotency_key = Some("order-1".to_string());
+
+        repo.create(tx1).await.unwrap();
+        repo.create(tx2).await.unwrap();
+        repo.create(create_test_transaction("test-3"))
+            .await
+            .unwrap();
+
+        let result = repo
+            .find_by_idempotency_key("relayer-1", "order-1")
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().id, "test-1");
+
+        // Keys are scoped to the relayer
+        let result = repo
+            .find_by_idempotency_key("relayer-2", "order-1")
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().id, "test-2");
+
+        let result = repo
+            .find_by_idempotency_key("relayer-1", "order-2")
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_user_operation_hash() {
+        let repo = InMemoryTransactionRepository::new();
+        let op_hash = format!("0x{}", "ab".repeat(32));
+
+        let mut tx1 = create_test_transaction("test-1");
+        if let NetworkTransactionData::Evm(ref mut data) = tx1.network_data {
+            data.user_operation_hashes = Some(vec![op_hash.clone()]);
+        }
+        repo.create(tx1).await.unwrap();
+        repo.create(create_test_transaction("test-2"))
+            .await
+            .unwrap();
+
+        let result = repo
+            .find_by_user_operation_hash("relayer-1", &op_hash)
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().id, "test-1");
+
+        let result = repo
+            .find_by_user_operation_hash("relayer-2", &op_hash)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_update_open_bundle() {
+        let repo = InMemoryTransactionRepository::new();
+        let mut tx = create_test_transaction("test-1");
+        if let NetworkTransactionData::Evm(ref mut data) = tx.network_data {
+            data.user_operation_hashes = Some(vec!["0x01".to_string()]);
+        }
+        repo.create(tx.clone()).await.unwrap();
+
+        let mut network_data = tx.network_data.get_evm_transaction_data().unwrap();
+        network_data.user_operation_hashes = Some(vec!["0x01".to_string(), "0x02".to_string()]);
+        let network_data = NetworkTransactionData::Evm(network_data);
+
+        // The bundle already carries another operation than the caller read
+        let result = repo
+            .update_open_bundle("test-1".to_string(), 2, network_data.clone())
+            .await
+            .unwrap();
+        assert!(result.is_none());
+
+        let result = repo
+            .update_open_bundle("test-1".to_string(), 1, network_data.clone())
+            .await
+            .unwrap();
+        assert!(result.is_some());
+
+        repo.seal_bundle("test-1".to_string()).await.unwrap();
+        let result = repo
+            .update_open_bundle("test-1".to_string(), 2, network_data)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
     #[tokio::test]
     async fn test_update_network_data() {
         let repo = InMemoryTransactionRepository::new();
@@ -714,6 +1071,16 @@ mod tests {
             signature: None,
             hash: Some("0xUpdated".to_string()),
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
             speed: None,
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
@@ -19,9 +19,25 @@ const RELAYER_PREFIX: &str = "relayer";
 const TX_PREFIX: &str = "tx";
 const STATUS_PREFIX: &str = "status";
 const NONCE_PREFIX: &str = "nonce";
+const IDEMPOTENCY_PREFIX: &str = "idempotency";
+const USER_OPERATION_PREFIX: &str = "user_op";
+const SEALED_BUNDLE_PREFIX: &str = "sealed_bundle";
 const TX_TO_RELAYER_PREFIX: &str = "tx_to_relayer";
 const RELAYER_LIST_KEY: &str = "relayer_list";
 
+/// How long a sealed bundle stays marked; bundles only collect operations for seconds
+const SEALED_BUNDLE_TTL_SECONDS: u64 = 86400;
+
+/// Replaces KEYS[1] with ARGV[2] if it still holds ARGV[1] and the bundle is not sealed
+/// (KEYS[2]); returns 1 when replaced
+const UPDATE_OPEN_BUNDLE_SCRIPT: &str = r#"
+if redis.call("EXISTS", KEYS[2]) == 1 or redis.call("GET", KEYS[1]) ~= ARGV[1] then
+  return 0
+end
+redis.call("SET", KEYS[1], ARGV[2])
+return 1
+"#;
+
 #[derive(Clone)]
 pub struct RedisTransactionRepository {
     pub client: Arc<ConnectionManager>,
@@ -79,6 +95,43 @@ impl RedisTransactionRepository {
         )
     }
 
+    /// Generate key for relayer idempotency index: relayer:{relayer_id}:idempotency:{key}
+    fn relayer_idempotency_key(&self, relayer_id: &str, idempotency_key: &str) -> String {
+        format!(
+            "{}:{}:{}:{}:{}",
+            self.key_prefix, RELAYER_PREFIX, relayer_id, IDEMPOTENCY_PREFIX, idempotency_key
+        )
+    }
+
+    /// Generate key for relayer user operation index: relayer:{relayer_id}:user_op:{hash}
+    fn relayer_user_operation_key(&self, relayer_id: &str, user_operation_hash: &str) -> String {
+        format!(
+            "{}:{}:{}:{}:{}",
+            self.key_prefix, RELAYER_PREFIX, relayer_id, USER_OPERATION_PREFIX, user_operation_hash
+        )
+    }
+
+    /// Generate key marking a sealed bundle: relayer:{relayer_id}:sealed_bundle:{tx_id}
+    fn sealed_bundle_key(&self, relayer_id: &str, tx_id: &str) -> String {
+        format!(
+            "{}:{}:{}:{}:{}",
+            self.key_prefix, RELAYER_PREFIX, relayer_id, SEALED_BUNDLE_PREFIX, tx_id
+        )
+    }
+
+    /// Look up the relayer a transaction belongs to
+    async fn find_relayer_id(&self, tx_id: &str) -> Result<String, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let relayer_id: Option<String> = conn
+            .get(self.tx_to_relayer_key(tx_id))
+            .await
+            .map_err(|e| self.map_redis_error(e, "find_relayer_id"))?;
+
+        relayer_id.ok_or_else(|| {
+            RepositoryError::NotFound(format!("Transaction with ID {} not found", tx_id))
+        })
+    }
+
     /// Generate key for relayer list: relayer_list (set of all relayer IDs)
     fn relayer_list_key(&self) -> String {
         format!("{}:{}", self.key_prefix, RELAYER_LIST_KEY)
@@ -191,6 +244,15 @@ impl RedisTransactionRepository {
         }
     }
 
+    /// Extract the ERC-4337 user operation hashes carried by an EVM bundle transaction
+    fn extract_user_operation_hashes(&self, network_data: &NetworkTransactionData) -> Vec<String> {
+        network_data
+            .get_evm_transaction_data()
+            .ok()
+            .and_then(|tx_data| tx_data.user_operation_hashes)
+            .unwrap_or_default()
+    }
+
     /// Update indexes atomically with comprehensive error handling
     async fn update_indexes(
         &self,
@@ -217,6 +279,19 @@ impl RedisTransactionRepository {
             debug!("Added nonce index for tx {} with nonce {}", tx.id, nonce);
         }
 
+        // A new transaction takes over the reservation of its idempotency key, keeping the
+        // reservation's expiry. Updates leave the index alone, as the key may have been
+        // reserved again after the window passed.
+        if let (Some(idempotency_key), None) = (&tx.idempotency_key, old_tx) {
+            let key = self.relayer_idempotency_key(&tx.relayer_id, idempotency_key);
+            pipe.cmd("SET").arg(&key).arg(&tx.id).arg("KEEPTTL");
+        }
+
+        for user_operation_hash in self.extract_user_operation_hashes(&tx.network_data) {
+            let key = self.relayer_user_operation_key(&tx.relayer_id, &user_operation_hash);
+            pipe.set(&key, &tx.id);
+        }
+
         // Remove old indexes if updating
         if let Some(old) = old_tx {
             if old.status != tx.status {
@@ -240,6 +315,15 @@ impl RedisTransactionRepository {
                     );
                 }
             }
+
+            // Drop user operation indexes no longer carried by the transaction
+            let new_hashes = self.extract_user_operation_hashes(&tx.network_data);
+            for old_hash in self.extract_user_operation_hashes(&old.network_data) {
+                if !new_hashes.contains(&old_hash) {
+                    let key = self.relayer_user_operation_key(&old.relayer_id, &old_hash);
+                    pipe.del(&key);
+                }
+            }
         }
 
         // Execute all operations in a single pipeline
@@ -274,6 +358,18 @@ impl RedisTransactionRepository {
             debug!("Removing nonce index for tx {} with nonce {}", tx.id, nonce);
         }
 
+        // Remove idempotency index if exists
+        if let Some(idempotency_key) = &tx.idempotency_key {
+            let key = self.relayer_idempotency_key(&tx.relayer_id, idempotency_key);
+            pipe.del(&key);
+        }
+
+        // Remove user operation indexes if any
+        for user_operation_hash in self.extract_user_operation_hashes(&tx.network_data) {
+            let key = self.relayer_user_operation_key(&tx.relayer_id, &user_operation_hash);
+            pipe.del(&key);
+        }
+
         // Remove reverse lookup
         let reverse_key = self.tx_to_relayer_key(&tx.id);
         pipe.del(&reverse_key);
@@ -874,6 +970,189 @@ impl TransactionRepository for RedisTransactionRepository {
         }
     }
 
+    async fn find_by_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let key = self.relayer_idempotency_key(relayer_id, idempotency_key);
+
+        let tx_id: Option<String> = conn
+            .get(key)
+            .await
+            .map_err(|e| self.map_redis_error(e, "find_by_idempotency_key"))?;
+
+        match tx_id {
+            Some(tx_id) => match self.get_by_id(tx_id).await {
+                Ok(tx) => Ok(Some(tx)),
+                Err(RepositoryError::NotFound(_)) => {
+                    warn!("Stale idempotency index found for relayer {}", relayer_id);
+                    Ok(None)
+                }
+                Err(e) => Err(e),
+            },
+            None => Ok(None),
+        }
+    }
+
+    async fn reserve_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+        ttl_seconds: u64,
+    ) -> Result<Option<String>, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let key = self.relayer_idempotency_key(relayer_id, idempotency_key);
+
+        // The reservation can expire between SET NX and GET, so try again in that case
+        for _ in 0..2 {
+            let reserved: Option<String> = redis::cmd("SET")
+                .arg(&key)
+                .arg(reservation_id)
+                .arg("NX")
+                .arg("EX")
+                .arg(ttl_seconds.max(1))
+                .query_async(&mut conn)
+                .await
+                .map_err(|e| self.map_redis_error(e, "reserve_idempotency_key"))?;
+            if reserved.is_some() {
+                return Ok(None);
+            }
+
+            let holder: Option<String> = conn
+                .get(&key)
+                .await
+                .map_err(|e| self.map_redis_error(e, "reserve_idempotency_key_holder"))?;
+            if holder.is_some() {
+                return Ok(holder);
+            }
+        }
+
+        Err(RepositoryError::Unknown(format!(
+            "Failed to reserve idempotency key for relayer {}",
+            relayer_id
+        )))
+    }
+
+    async fn release_idempotency_key(
+        &self,
+        relayer_id: &str,
+        idempotency_key: &str,
+        reservation_id: &str,
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let key = self.relayer_idempotency_key(relayer_id, idempotency_key);
+
+        redis::Script::new(
+            r#"if redis.call("GET", KEYS[1]) == ARGV[1] then return redis.call("DEL", KEYS[1]) end return 0"#,
+        )
+        .key(&key)
+        .arg(reservation_id)
+        .invoke_async::<()>(&mut conn)
+        .await
+        .map_err(|e| self.map_redis_error(e, "release_idempotency_key"))
+    }
+
+    async fn find_by_user_operation_hash(
+        &self,
+        relayer_id: &str,
+        user_operation_hash: &str,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        let mut conn = self.client.as_ref().clone();
+        let key = self.relayer_user_operation_key(relayer_id, user_operation_hash);
+
+        let tx_id: Option<String> = conn
+            .get(key)
+            .await
+            .map_err(|e| self.map_redis_error(e, "find_by_user_operation_hash"))?;
+
+        match tx_id {
+            Some(tx_id) => match self.get_by_id(tx_id).await {
+                Ok(tx) => Ok(Some(tx)),
+                Err(RepositoryError::NotFound(_)) => {
+                    warn!(
+                        "Stale user operation index found for relayer {}",
+                        relayer_id
+                    );
+                    Ok(None)
+                }
+                Err(e) => Err(e),
+            },
+            None => Ok(None),
+        }
+    }
+
+    async fn update_open_bundle(
+        &self,
+        tx_id: String,
+        operation_count: usize,
+        network_data: NetworkTransactionData,
+    ) -> Result<Option<TransactionRepoModel>, RepositoryError> {
+        let relayer_id = self.find_relayer_id(&tx_id).await?;
+        let key = self.tx_key(&relayer_id, &tx_id);
+        let mut conn = self.client.as_ref().clone();
+
+        let current: Option<String> = conn
+            .get(&key)
+            .await
+            .map_err(|e| self.map_redis_error(e, "update_open_bundle_get"))?;
+        let current = current.ok_or_else(|| {
+            RepositoryError::NotFound(format!("Transaction with ID {} not found", tx_id))
+        })?;
+        let old_tx =
+            self.deserialize_entity::<TransactionRepoModel>(&current, &tx_id, "transaction")?;
+
+        let current_count = old_tx
+            .network_data
+            .get_evm_transaction_data()
+            .ok()
+            .and_then(|data| data.user_operation_hashes)
+            .map(|hashes| hashes.len());
+        if old_tx.status != TransactionStatus::Pending || current_count != Some(operation_count) {
+            return Ok(None);
+        }
+
+        let mut tx = old_tx.clone();
+        tx.network_data = network_data;
+        let value = self.serialize_entity(&tx, |t| &t.id, "transaction")?;
+
+        // Written only if nothing, including sealing, happened since the bundle was read
+        let applied: i64 = redis::Script::new(UPDATE_OPEN_BUNDLE_SCRIPT)
+            .key(&key)
+            .key(self.sealed_bundle_key(&relayer_id, &tx_id))
+            .arg(&current)
+            .arg(&value)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "update_open_bundle"))?;
+        if applied == 0 {
+            debug!("Bundle {} was sealed or changed", tx_id);
+            return Ok(None);
+        }
+
+        self.update_indexes(&tx, Some(&old_tx)).await?;
+        Ok(Some(tx))
+    }
+
+    async fn seal_bundle(&self, tx_id: String) -> Result<(), RepositoryError> {
+        let relayer_id = self.find_relayer_id(&tx_id).await?;
+        let mut conn = self.client.as_ref().clone();
+
+        let _: () = conn
+            .set_ex(
+                self.sealed_bundle_key(&relayer_id, &tx_id),
+                1,
+                SEALED_BUNDLE_TTL_SECONDS,
+            )
+            .await
+            .map_err(|e| self.map_redis_error(e, "seal_bundle"))?;
+
+        debug!("Sealed bundle {}", tx_id);
+        Ok(())
+    }
+
     async fn update_status(
         &self,
         tx_id: String,
@@ -998,9 +1277,23 @@ mod tests {
                 max_fee_per_gas: None,
                 max_priority_fee_per_gas: None,
                 raw: None,
+                simulate: None,
+                authorization_list: None,
+                access_list: None,
+                user_operation_hashes: None,
+                block_number: None,
+                block_hash: None,
+                gas_used: None,
+                effective_gas_price: None,
+                l1_fee: None,
+                logs: None,
             }),
             noop_count: None,
             is_canceled: Some(false),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -1394,6 +1687,145 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_find_by_idempotency_key() {
+        let repo = setup_test_repo().await;
+        let random_id = Uuid::new_v4().to_string();
+        let relayer_id = Uuid::new_v4().to_string();
+
+        let mut tx = create_test_transaction_with_relayer(&random_id, &relayer_id);
+        tx.idempotency_key = Some("order-1".to_string());
+        repo.create(tx).await.unwrap();
+
+        let result = repo
+            .find_by_idempotency_key(&relayer_id, "order-1")
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().id, random_id);
+
+        // The index is removed together with the transaction
+        repo.delete_by_id(random_id).await.unwrap();
+        let result = repo
+            .find_by_idempotency_key(&relayer_id, "order-1")
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_reserve_idempotency_key() {
+        let repo = setup_test_repo().await;
+        let random_id = Uuid::new_v4().to_string();
+        let relayer_id = Uuid::new_v4().to_string();
+
+        let holder = repo
+            .reserve_idempotency_key(&relayer_id, "order-1", "reservation-1", 60)
+            .await
+            .unwrap();
+        assert!(holder.is_none());
+
+        let holder = repo
+            .reserve_idempotency_key(&relayer_id, "order-1", "reservation-2", 60)
+            .await
+            .unwrap();
+        assert_eq!(holder, Some("reservation-1".to_string()));
+
+        // Creating the transaction takes over the reservation
+        let mut tx = create_test_transaction_with_relayer(&random_id, &relayer_id);
+        tx.idempotency_key = Some("order-1".to_string());
+        repo.create(tx).await.unwrap();
+        let holder = repo
+            .reserve_idempotency_key(&relayer_id, "order-1", "reservation-2", 60)
+            .await
+            .unwrap();
+        assert_eq!(holder, Some(random_id.clone()));
+
+        // Only the holder can release the key
+        repo.release_idempotency_key(&relayer_id, "order-1", "reservation-1")
+            .await
+            .unwrap();
+        let holder = repo
+            .reserve_idempotency_key(&relayer_id, "order-1", "reservation-2", 60)
+            .await
+            .unwrap();
+        assert_eq!(holder, Some(random_id));
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_find_by_user_operation_hash() {
+        let repo = setup_test_repo().await;
+        let random_id = Uuid::new_v4().to_string();
+        let relayer_id = Uuid::new_v4().to_string();
+        let op_hash = format!("0x{}", "ab".repeat(32));
+
+        let mut tx = create_test_transaction_with_relayer(&random_id, &relayer_id);
+        if let NetworkTransactionData::Evm(ref mut data) = tx.network_data {
+            data.user_operation_hashes = Some(vec![op_hash.clone()]);
+        }
+        repo.create(tx.clone()).await.unwrap();
+
+        let result = repo
+            .find_by_user_operation_hash(&relayer_id, &op_hash)
+            .await
+            .unwrap();
+        assert_eq!(result.unwrap().id, random_id);
+
+        // Dropping the hash from the transaction removes its index
+        if let NetworkTransactionData::Evm(ref mut data) = tx.network_data {
+            data.user_operation_hashes = None;
+        }
+        repo.update_network_data(random_id.clone(), tx.network_data)
+            .await
+            .unwrap();
+        let result = repo
+            .find_by_user_operation_hash(&relayer_id, &op_hash)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_update_open_bundle() {
+        let repo = setup_test_repo().await;
+        let tx_id = uuid::Uuid::new_v4().to_string();
+        let mut tx = create_test_transaction(&tx_id);
+        if let NetworkTransactionData::Evm(ref mut data) = tx.network_data {
+            data.user_operation_hashes = Some(vec![format!("0x{}", "01".repeat(32))]);
+        }
+        repo.create(tx.clone()).await.unwrap();
+
+        let mut network_data = tx.network_data.get_evm_transaction_data().unwrap();
+        network_data
+            .user_operation_hashes
+            .as_mut()
+            .unwrap()
+            .push(format!("0x{}", "02".repeat(32)));
+        let network_data = NetworkTransactionData::Evm(network_data);
+
+        let result = repo
+            .update_open_bundle(tx_id.clone(), 2, network_data.clone())
+            .await
+            .unwrap();
+        assert!(result.is_none());
+
+        let result = repo
+            .update_open_bundle(tx_id.clone(), 1, network_data.clone())
+            .await
+            .unwrap();
+        assert!(result.is_some());
+
+        repo.seal_bundle(tx_id.clone()).await.unwrap();
+        let result = repo
+            .update_open_bundle(tx_id, 2, network_data)
+            .await
+            .unwrap();
+        assert!(result.is_none());
+    }
+
     #[tokio::test]
     #[ignore = "Requires active Redis instance"]
     async fn test_update_status() {
@@ -1429,6 +1861,7 @@ mod tests {
             priced_at: None,
             noop_count: None,
             delete_at: None,
+            clear_schedule: None,
         };
 
         let updated = repo
@@ -1509,6 +1942,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         });
 
         let updated = repo
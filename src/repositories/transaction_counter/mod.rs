@@ -15,8 +15,10 @@
 //!
 //! - **Get**: Retrieve current counter value
 //! - **Get and Increment**: Atomically get current value and increment
+//! - **Get and Increment By**: Atomically reserve a contiguous range of values
 //! - **Decrement**: Decrement counter (for rollbacks)
 //! - **Set**: Set counter to specific value
+//! - **Compare And Set**: Atomically set counter only while it holds an expected value
 //!
 pub mod transaction_counter_in_memory;
 pub mod transaction_counter_redis;
@@ -55,10 +57,29 @@ pub trait TransactionCounterTrait {
         address: &str,
     ) -> Result<u64, RepositoryError>;
 
+    /// Atomically reserves `count` consecutive values and returns the first one.
+    async fn get_and_increment_by(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        count: u64,
+    ) -> Result<u64, RepositoryError>;
+
     async fn decrement(&self, relayer_id: &str, address: &str) -> Result<u64, RepositoryError>;
 
     async fn set(&self, relayer_id: &str, address: &str, value: u64)
         -> Result<(), RepositoryError>;
+
+    /// Atomically sets the counter to `value` if it currently holds `expected`.
+    ///
+    /// Returns whether the counter was updated.
+    async fn compare_and_set(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        expected: u64,
+        value: u64,
+    ) -> Result<bool, RepositoryError>;
 }
 
 /// Enum wrapper for different transaction counter repository implementations
@@ -111,6 +132,26 @@ impl TransactionCounterTrait for TransactionCounterRepositoryStorage {
         }
     }
 
+    async fn get_and_increment_by(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        count: u64,
+    ) -> Result<u64, RepositoryError> {
+        match self {
+            TransactionCounterRepositoryStorage::InMemory(counter) => {
+                counter
+                    .get_and_increment_by(relayer_id, address, count)
+                    .await
+            }
+            TransactionCounterRepositoryStorage::Redis(counter) => {
+                counter
+                    .get_and_increment_by(relayer_id, address, count)
+                    .await
+            }
+        }
+    }
+
     async fn decrement(&self, relayer_id: &str, address: &str) -> Result<u64, RepositoryError> {
         match self {
             TransactionCounterRepositoryStorage::InMemory(counter) => {
@@ -137,6 +178,27 @@ impl TransactionCounterTrait for TransactionCounterRepositoryStorage {
             }
         }
     }
+
+    async fn compare_and_set(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        expected: u64,
+        value: u64,
+    ) -> Result<bool, RepositoryError> {
+        match self {
+            TransactionCounterRepositoryStorage::InMemory(counter) => {
+                counter
+                    .compare_and_set(relayer_id, address, expected, value)
+                    .await
+            }
+            TransactionCounterRepositoryStorage::Redis(counter) => {
+                counter
+                    .compare_and_set(relayer_id, address, expected, value)
+                    .await
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -45,6 +45,21 @@ impl TransactionCounterTrait for InMemoryTransactionCounter {
         Ok(current)
     }
 
+    async fn get_and_increment_by(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        count: u64,
+    ) -> Result<u64, RepositoryError> {
+        let mut entry = self
+            .store
+            .entry((relayer_id.to_string(), address.to_string()))
+            .or_insert(0);
+        let current = *entry;
+        *entry += count;
+        Ok(current)
+    }
+
     async fn decrement(&self, relayer_id: &str, address: &str) -> Result<u64, RepositoryError> {
         let mut entry = self
             .store
@@ -68,6 +83,25 @@ impl TransactionCounterTrait for InMemoryTransactionCounter {
             .insert((relayer_id.to_string(), address.to_string()), value);
         Ok(())
     }
+
+    async fn compare_and_set(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        expected: u64,
+        value: u64,
+    ) -> Result<bool, RepositoryError> {
+        match self
+            .store
+            .get_mut(&(relayer_id.to_string(), address.to_string()))
+        {
+            Some(mut entry) if *entry == expected => {
+                *entry = value;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -81,6 +115,57 @@ mod tests {
         assert!(matches!(result, Err(RepositoryError::NotFound(_))));
     }
 
+    #[tokio::test]
+    async fn test_get_and_increment_by() {
+        let store = InMemoryTransactionCounter::new();
+
+        assert_eq!(
+            store
+                .get_and_increment_by("relayer_1", "0x1234", 3)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            store
+                .get_and_increment("relayer_1", "0x1234")
+                .await
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            store
+                .get_and_increment_by("relayer_1", "0x1234", 2)
+                .await
+                .unwrap(),
+            4
+        );
+        assert_eq!(store.get("relayer_1", "0x1234").await.unwrap(), Some(6));
+    }
+
+    #[tokio::test]
+    async fn test_compare_and_set() {
+        let store = InMemoryTransactionCounter::new();
+
+        assert!(!store
+            .compare_and_set("relayer_1", "0x1234", 0, 5)
+            .await
+            .unwrap());
+
+        store.set("relayer_1", "0x1234", 10).await.unwrap();
+        assert!(!store
+            .compare_and_set("relayer_1", "0x1234", 9, 5)
+            .await
+            .unwrap());
+        assert_eq!(store.get("relayer_1", "0x1234").await.unwrap(), Some(10));
+
+        assert!(store
+            .compare_and_set("relayer_1", "0x1234", 10, 5)
+            .await
+            .unwrap());
+        assert_eq!(store.get("relayer_1", "0x1234").await.unwrap(), Some(5));
+    }
+
     #[tokio::test]
     async fn test_nonce_store() {
         let store = InMemoryTransactionCounter::new();
@@ -17,6 +17,15 @@ use std::sync::Arc;
 
 const COUNTER_PREFIX: &str = "transaction_counter";
 
+/// Sets KEYS[1] to ARGV[2] only while it holds ARGV[1]; returns 1 when set
+const COMPARE_AND_SET_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+  redis.call("SET", KEYS[1], ARGV[2])
+  return 1
+end
+return 0
+"#;
+
 #[derive(Clone)]
 pub struct RedisTransactionCounter {
     pub client: Arc<ConnectionManager>,
@@ -137,6 +146,43 @@ impl TransactionCounterTrait for RedisTransactionCounter {
         Ok(current)
     }
 
+    async fn get_and_increment_by(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        count: u64,
+    ) -> Result<u64, RepositoryError> {
+        if relayer_id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer ID cannot be empty".to_string(),
+            ));
+        }
+
+        if address.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Address cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.counter_key(relayer_id, address);
+        debug!(
+            "Reserving {} counter values for relayer {} and address {}",
+            count, relayer_id, address
+        );
+
+        let mut conn = self.client.as_ref().clone();
+
+        // INCRBY is atomic, so concurrent reservations never overlap
+        let next: u64 = conn
+            .incr(&key, count)
+            .await
+            .map_err(|e| self.map_redis_error(e, "get_and_increment_by"))?;
+
+        let first = next - count;
+        debug!("Reserved counter values {} to {}", first, next);
+        Ok(first)
+    }
+
     async fn decrement(&self, relayer_id: &str, address: &str) -> Result<u64, RepositoryError> {
         if relayer_id.is_empty() {
             return Err(RepositoryError::InvalidData(
@@ -217,6 +263,45 @@ impl TransactionCounterTrait for RedisTransactionCounter {
         debug!("Counter set to {}", value);
         Ok(())
     }
+
+    async fn compare_and_set(
+        &self,
+        relayer_id: &str,
+        address: &str,
+        expected: u64,
+        value: u64,
+    ) -> Result<bool, RepositoryError> {
+        if relayer_id.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Relayer ID cannot be empty".to_string(),
+            ));
+        }
+
+        if address.is_empty() {
+            return Err(RepositoryError::InvalidData(
+                "Address cannot be empty".to_string(),
+            ));
+        }
+
+        let key = self.counter_key(relayer_id, address);
+        debug!(
+            "Setting counter for relayer {} and address {} from {} to {}",
+            relayer_id, address, expected, value
+        );
+
+        let mut conn = self.client.as_ref().clone();
+
+        let updated: i64 = redis::Script::new(COMPARE_AND_SET_SCRIPT)
+            .key(&key)
+            .arg(expected)
+            .arg(value)
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e| self.map_redis_error(e, "compare_and_set_counter"))?;
+
+        debug!("Counter compare-and-set applied: {}", updated == 1);
+        Ok(updated == 1)
+    }
 }
 
 #[cfg(test)]
@@ -260,6 +345,27 @@ mod tests {
         assert_eq!(result, Some(100));
     }
 
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_compare_and_set() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let address = uuid::Uuid::new_v4().to_string();
+
+        repo.set(&relayer_id, &address, 10).await.unwrap();
+        assert!(!repo
+            .compare_and_set(&relayer_id, &address, 9, 5)
+            .await
+            .unwrap());
+        assert_eq!(repo.get(&relayer_id, &address).await.unwrap(), Some(10));
+
+        assert!(repo
+            .compare_and_set(&relayer_id, &address, 10, 5)
+            .await
+            .unwrap());
+        assert_eq!(repo.get(&relayer_id, &address).await.unwrap(), Some(5));
+    }
+
     #[tokio::test]
     #[ignore = "Requires active Redis instance"]
     async fn test_get_and_increment() {
@@ -282,6 +388,26 @@ mod tests {
         assert_eq!(current, Some(2));
     }
 
+    #[tokio::test]
+    #[ignore = "Requires active Redis instance"]
+    async fn test_get_and_increment_by() {
+        let repo = setup_test_repo().await;
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+        let address = uuid::Uuid::new_v4().to_string();
+
+        let result = repo
+            .get_and_increment_by(&relayer_id, &address, 5)
+            .await
+            .unwrap();
+        assert_eq!(result, 0);
+
+        let result = repo.get_and_increment(&relayer_id, &address).await.unwrap();
+        assert_eq!(result, 5);
+
+        let current = repo.get(&relayer_id, &address).await.unwrap();
+        assert_eq!(current, Some(6));
+    }
+
     #[tokio::test]
     #[ignore = "Requires active Redis instance"]
     async fn test_decrement() {
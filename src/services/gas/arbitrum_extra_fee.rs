@@ -0,0 +1,268 @@
+use crate::{
+    constants::ARBITRUM_NODE_INTERFACE_ADDRESS,
+    models::{evm::EvmTransactionRequest, TransactionError, U256},
+    services::provider::evm::EvmProviderTrait,
+};
+use alloy::{
+    primitives::{Address, Bytes, TxKind},
+    rpc::types::{BlockNumberOrTag, TransactionInput, TransactionRequest},
+    sol,
+    sol_types::SolCall,
+};
+
+sol! {
+    interface INodeInterface {
+        function gasEstimateComponents(address to, bool contractCreation, bytes calldata data)
+            external
+            payable
+            returns (
+                uint64 gasEstimate,
+                uint64 gasEstimateForL1,
+                uint256 baseFee,
+                uint256 l1BaseFeeEstimate
+            );
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ArbitrumFeeData {
+    pub gas_estimate: U256,
+    pub gas_estimate_for_l1: U256,
+    pub base_fee: U256,
+    pub l1_base_fee_estimate: U256,
+}
+
+/// Estimates the L1 data fee of Arbitrum transactions through the NodeInterface precompile.
+///
+/// Arbitrum charges for posting a transaction to L1 in L2 gas, so the L1 component is already
+/// part of `eth_estimateGas` and must fit in the transaction's gas limit. The extra fee is
+/// therefore only the L1 gas the gas limit leaves uncovered, priced at the current L2 base fee.
+#[derive(Debug, Clone)]
+pub struct ArbitrumExtraFeeService<P> {
+    provider: P,
+    node_interface_address: Address,
+}
+
+impl<P: EvmProviderTrait> ArbitrumExtraFeeService<P> {
+    pub fn new(provider: P) -> Self {
+        Self {
+            provider,
+            node_interface_address: ARBITRUM_NODE_INTERFACE_ADDRESS.parse().unwrap(),
+        }
+    }
+
+    fn create_estimate_call(
+        &self,
+        tx: &EvmTransactionRequest,
+    ) -> Result<TransactionRequest, TransactionError> {
+        let to = match &tx.to {
+            Some(to) => to.parse::<Address>().map_err(|e| {
+                TransactionError::ValidationError(format!("Invalid 'to' address: {}", e))
+            })?,
+            None => Address::ZERO,
+        };
+        let data = match &tx.data {
+            Some(hex_str) => hex::decode(hex_str.trim_start_matches("0x")).map_err(|e| {
+                TransactionError::ValidationError(format!("Invalid transaction data: {}", e))
+            })?,
+            None => Vec::new(),
+        };
+
+        let call = INodeInterface::gasEstimateComponentsCall {
+            to,
+            contractCreation: tx.to.is_none(),
+            data: Bytes::from(data),
+        };
+        Ok(TransactionRequest {
+            to: Some(TxKind::Call(self.node_interface_address)),
+            input: TransactionInput::from(Bytes::from(call.abi_encode())),
+            ..Default::default()
+        })
+    }
+
+    pub async fn fetch_fee_data(
+        &self,
+        tx: &EvmTransactionRequest,
+    ) -> Result<ArbitrumFeeData, TransactionError> {
+        let call = self.create_estimate_call(tx)?;
+        let bytes = self
+            .provider
+            .call_contract(&call, BlockNumberOrTag::Latest)
+            .await
+            .map_err(|e| TransactionError::UnexpectedError(e.to_string()))?;
+        let estimate = INodeInterface::gasEstimateComponentsCall::abi_decode_returns(&bytes)
+            .map_err(|e| TransactionError::UnexpectedError(e.to_string()))?;
+
+        Ok(ArbitrumFeeData {
+            gas_estimate: U256::from(estimate.gasEstimate),
+            gas_estimate_for_l1: U256::from(estimate.gasEstimateForL1),
+            base_fee: estimate.baseFee,
+            l1_base_fee_estimate: estimate.l1BaseFeeEstimate,
+        })
+    }
+
+    /// Returns the cost of the L1 gas that the transaction's gas limit does not cover.
+    ///
+    /// A transaction without a gas limit gets one from `eth_estimateGas`, which already includes
+    /// the L1 component, so no extra fee is charged for it.
+    pub fn calculate_fee(
+        &self,
+        fee_data: &ArbitrumFeeData,
+        tx: &EvmTransactionRequest,
+    ) -> Result<U256, TransactionError> {
+        let Some(gas_limit) = tx.gas_limit else {
+            return Ok(U256::ZERO);
+        };
+        let l2_gas = fee_data
+            .gas_estimate
+            .saturating_sub(fee_data.gas_estimate_for_l1);
+        let covered_l1_gas = U256::from(gas_limit).saturating_sub(l2_gas);
+        let uncovered_l1_gas = fee_data.gas_estimate_for_l1.saturating_sub(covered_l1_gas);
+
+        Ok(uncovered_l1_gas.saturating_mul(fee_data.base_fee))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::provider::evm::MockEvmProviderTrait;
+    use alloy::sol_types::SolValue;
+
+    fn create_test_request() -> EvmTransactionRequest {
+        EvmTransactionRequest {
+            to: Some("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string()),
+            value: U256::ZERO,
+            data: Some("0x01020304".to_string()),
+            gas_limit: Some(21000),
+            gas_price: Some(100_000_000),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_and_calculate_fee() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_call_contract()
+            .withf(|call, _| {
+                let Ok(decoded) = INodeInterface::gasEstimateComponentsCall::abi_decode(
+                    call.input.input().unwrap(),
+                ) else {
+                    return false;
+                };
+                call.to
+                    == Some(TxKind::Call(
+                        ARBITRUM_NODE_INTERFACE_ADDRESS.parse().unwrap(),
+                    ))
+                    && !decoded.contractCreation
+                    && decoded.data.as_ref() == [1, 2, 3, 4]
+            })
+            .returning(|_, _| {
+                let encoded = (
+                    30_000u64,
+                    1_500u64,
+                    U256::from(10_000_000u64),
+                    U256::from(30_000_000_000u64),
+                )
+                    .abi_encode_params();
+                Box::pin(async move { Ok(Bytes::from(encoded)) })
+            });
+
+        let service = ArbitrumExtraFeeService::new(provider);
+        let tx = create_test_request();
+        let fee_data = service.fetch_fee_data(&tx).await.unwrap();
+
+        assert_eq!(fee_data.gas_estimate, U256::from(30_000u64));
+        assert_eq!(fee_data.gas_estimate_for_l1, U256::from(1_500u64));
+        assert_eq!(fee_data.l1_base_fee_estimate, U256::from(30_000_000_000u64));
+        assert_eq!(
+            service.calculate_fee(&fee_data, &tx).unwrap(),
+            U256::from(15_000_000_000u64)
+        );
+    }
+
+    fn create_test_fee_data() -> ArbitrumFeeData {
+        ArbitrumFeeData {
+            gas_estimate: U256::from(30_000u64),
+            gas_estimate_for_l1: U256::from(1_500u64),
+            base_fee: U256::from(10_000_000u64),
+            l1_base_fee_estimate: U256::from(30_000_000_000u64),
+        }
+    }
+
+    #[test]
+    fn test_calculate_fee_gas_limit_covers_l1_component() {
+        let service = ArbitrumExtraFeeService::new(MockEvmProviderTrait::new());
+        let tx = EvmTransactionRequest {
+            gas_limit: Some(30_000),
+            ..create_test_request()
+        };
+
+        assert_eq!(
+            service.calculate_fee(&create_test_fee_data(), &tx).unwrap(),
+            U256::ZERO
+        );
+    }
+
+    #[test]
+    fn test_calculate_fee_gas_limit_partially_covers_l1_component() {
+        let service = ArbitrumExtraFeeService::new(MockEvmProviderTrait::new());
+        let tx = EvmTransactionRequest {
+            gas_limit: Some(29_000),
+            ..create_test_request()
+        };
+
+        // 28_500 L2 gas leaves 500 of the 1_500 L1 gas covered by the gas limit
+        assert_eq!(
+            service.calculate_fee(&create_test_fee_data(), &tx).unwrap(),
+            U256::from(10_000_000_000u64)
+        );
+    }
+
+    #[test]
+    fn test_calculate_fee_without_gas_limit() {
+        let service = ArbitrumExtraFeeService::new(MockEvmProviderTrait::new());
+        let tx = EvmTransactionRequest {
+            gas_limit: None,
+            ..create_test_request()
+        };
+
+        assert_eq!(
+            service.calculate_fee(&create_test_fee_data(), &tx).unwrap(),
+            U256::ZERO
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_fee_data_invalid_data() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider.expect_call_contract().never();
+
+        let service = ArbitrumExtraFeeService::new(provider);
+        let tx = EvmTransactionRequest {
+            data: Some("0xzz".to_string()),
+            ..create_test_request()
+        };
+        let result = service.fetch_fee_data(&tx).await;
+
+        assert!(matches!(result, Err(TransactionError::ValidationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_fee_data_rpc_error() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider.expect_call_contract().returning(|_, _| {
+            Box::pin(async {
+                Err(crate::services::ProviderError::Other(
+                    "execution reverted".to_string(),
+                ))
+            })
+        });
+
+        let service = ArbitrumExtraFeeService::new(provider);
+        let result = service.fetch_fee_data(&create_test_request()).await;
+
+        assert!(matches!(result, Err(TransactionError::UnexpectedError(_))));
+    }
+}
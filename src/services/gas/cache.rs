@@ -8,7 +8,10 @@ use crate::{
     config::GasPriceCacheConfig,
     constants::{GAS_PRICE_CACHE_REFRESH_TIMEOUT_SECS, HISTORICAL_BLOCKS},
     models::{EvmNetwork, TransactionError},
-    services::{gas::l2_fee::L2FeeData, EvmProviderTrait},
+    services::{
+        gas::{evm_gas_price::GasPrices, gas_oracle::GasOracle, l2_fee::L2FeeData},
+        EvmProviderTrait,
+    },
 };
 use alloy::rpc::types::{BlockNumberOrTag, FeeHistory};
 use dashmap::DashMap;
@@ -82,11 +85,52 @@ impl GasPriceCacheEntry {
     }
 }
 
+/// Prices computed by a network's gas oracle.
+#[derive(Debug, Clone)]
+pub struct GasOracleSnapshot {
+    pub prices: GasPrices,
+    pub is_stale: bool,
+}
+
+/// Represents an entry of gas oracle prices in the gas price cache.
+#[derive(Clone, Debug)]
+pub struct GasOracleCacheEntry {
+    pub prices: GasPrices,
+    pub fetched_at: Instant,
+    pub stale_after: Duration,
+    pub expire_after: Duration,
+}
+
+impl GasOracleCacheEntry {
+    /// Creates a new cache entry.
+    pub fn new(prices: GasPrices, stale_after: Duration, expire_after: Duration) -> Self {
+        Self {
+            prices,
+            fetched_at: Instant::now(),
+            stale_after,
+            expire_after,
+        }
+    }
+
+    /// Checks if the cache entry is stale but not expired
+    pub fn is_stale(&self) -> bool {
+        let elapsed = self.fetched_at.elapsed();
+        elapsed >= self.stale_after && elapsed < self.expire_after
+    }
+
+    /// Checks if the cache entry has expired
+    pub fn is_expired(&self) -> bool {
+        self.fetched_at.elapsed() >= self.expire_after
+    }
+}
+
 /// Thread-safe gas price cache supporting multiple networks
 #[derive(Debug)]
 pub struct GasPriceCache {
     /// Cache storage mapping chain_id to cached entries
     entries: Arc<DashMap<u64, Arc<RwLock<GasPriceCacheEntry>>>>,
+    /// Cache storage mapping chain_id to gas oracle prices
+    oracle_entries: Arc<DashMap<u64, GasOracleCacheEntry>>,
     /// Network-specific cache configurations
     network_configs: Arc<DashMap<u64, GasPriceCacheConfig>>,
     /// Track ongoing refresh operations to prevent duplicates
@@ -107,6 +151,7 @@ impl GasPriceCache {
     fn create_instance() -> Self {
         Self {
             entries: Arc::new(DashMap::new()),
+            oracle_entries: Arc::new(DashMap::new()),
             network_configs: Arc::new(DashMap::new()),
             refreshing_networks: Arc::new(DashMap::new()),
         }
@@ -124,7 +169,8 @@ impl GasPriceCache {
     pub fn remove_network(&self, chain_id: u64) -> bool {
         let config_removed = self.network_configs.remove(&chain_id).is_some();
         let entries_removed = self.entries.remove(&chain_id).is_some();
-        config_removed || entries_removed
+        let oracle_entries_removed = self.oracle_entries.remove(&chain_id).is_some();
+        config_removed || entries_removed || oracle_entries_removed
     }
 
     /// Returns a snapshot of cached gas pricing components if present and not expired.
@@ -217,6 +263,7 @@ impl GasPriceCache {
     /// Clears all cache entries
     pub fn clear(&self) {
         self.entries.clear();
+        self.oracle_entries.clear();
     }
 
     /// Returns the number of cached entries
@@ -306,6 +353,96 @@ impl GasPriceCache {
 
         true
     }
+
+    /// Returns the cached prices of a network's gas oracle if present and not expired.
+    pub fn get_oracle_snapshot(&self, chain_id: u64) -> Option<GasOracleSnapshot> {
+        let config = self.network_configs.get(&chain_id)?;
+        if !config.enabled {
+            return None;
+        }
+
+        let entry = self.oracle_entries.get(&chain_id)?;
+        if entry.is_expired() {
+            return None;
+        }
+        Some(GasOracleSnapshot {
+            prices: entry.prices.clone(),
+            is_stale: entry.is_stale(),
+        })
+    }
+
+    /// Stores the prices of a network's gas oracle if caching is enabled for the network.
+    pub fn set_oracle_prices(&self, chain_id: u64, prices: GasPrices) {
+        let Some(cfg) = self.network_configs.get(&chain_id) else {
+            return;
+        };
+        if !cfg.enabled {
+            return;
+        }
+
+        self.oracle_entries.insert(
+            chain_id,
+            GasOracleCacheEntry::new(
+                prices,
+                Duration::from_millis(cfg.stale_after_ms),
+                Duration::from_millis(cfg.expire_after_ms),
+            ),
+        );
+    }
+
+    /// Triggers a background refresh of a network's gas oracle prices if not already refreshing.
+    pub fn refresh_oracle_in_background(&self, network: &EvmNetwork, oracle: GasOracle) -> bool {
+        let now = Instant::now();
+
+        let cleanup_threshold = Duration::from_secs(GAS_PRICE_CACHE_REFRESH_TIMEOUT_SECS);
+        self.refreshing_networks
+            .retain(|_, started_at| now.duration_since(*started_at) < cleanup_threshold);
+
+        let already_refreshing = self
+            .refreshing_networks
+            .insert(network.chain_id, now)
+            .is_some();
+        if already_refreshing {
+            return false;
+        }
+
+        let network = network.clone();
+
+        let oracle_entries = self.oracle_entries.clone();
+        let network_configs = self.network_configs.clone();
+        let refreshing_networks = self.refreshing_networks.clone();
+
+        tokio::spawn(async move {
+            let refresh = async {
+                let provider = crate::services::get_network_provider(&network, None).ok()?;
+                let prices = oracle.fetch_prices(&provider).await.ok()?;
+
+                let cfg = network_configs.get(&network.chain_id)?;
+                if !cfg.enabled {
+                    return None;
+                }
+
+                oracle_entries.insert(
+                    network.chain_id,
+                    GasOracleCacheEntry::new(
+                        prices,
+                        Duration::from_millis(cfg.stale_after_ms),
+                        Duration::from_millis(cfg.expire_after_ms),
+                    ),
+                );
+                info!(
+                    "Updated gas oracle prices for chain_id {} in background",
+                    network.chain_id
+                );
+                Some(())
+            };
+
+            let _ = refresh.await;
+            refreshing_networks.remove(&network.chain_id);
+        });
+
+        true
+    }
 }
 
 #[cfg(test)]
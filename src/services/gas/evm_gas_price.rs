@@ -4,7 +4,10 @@
 use crate::{
     constants::HISTORICAL_BLOCKS,
     models::{evm::Speed, EvmNetwork, EvmTransactionData, TransactionError},
-    services::{gas::cache::GasPriceCache, EvmProviderTrait},
+    services::{
+        gas::{cache::GasPriceCache, gas_oracle::GasOracle},
+        EvmProviderTrait,
+    },
 };
 use alloy::rpc::types::{BlockNumberOrTag, FeeHistory};
 use eyre::Result;
@@ -154,6 +157,7 @@ pub struct EvmGasPriceService<P: EvmProviderTrait> {
     provider: P,
     network: EvmNetwork,
     cache: Option<std::sync::Arc<GasPriceCache>>,
+    oracle: Option<GasOracle>,
 }
 
 impl<P: EvmProviderTrait> EvmGasPriceService<P> {
@@ -162,10 +166,12 @@ impl<P: EvmProviderTrait> EvmGasPriceService<P> {
         network: EvmNetwork,
         cache: Option<std::sync::Arc<GasPriceCache>>,
     ) -> Self {
+        let oracle = network.gas_oracle.as_ref().and_then(GasOracle::from_config);
         Self {
             provider,
             network,
             cache,
+            oracle,
         }
     }
 
@@ -173,92 +179,104 @@ impl<P: EvmProviderTrait> EvmGasPriceService<P> {
         &self.network
     }
 
-    fn build_legacy_prices_from_base(base_gas_price: u128) -> SpeedPrices {
-        let legacy_price_pairs: Vec<(Speed, u128)> = Speed::multiplier()
-            .into_iter()
-            .map(|(speed, multiplier)| {
-                let price_for_speed = (base_gas_price * multiplier) / 100;
-                (speed, price_for_speed)
-            })
-            .collect();
-
-        SpeedPrices {
-            safe_low: legacy_price_pairs
-                .iter()
-                .find(|(s, _)| *s == Speed::SafeLow)
-                .map(|(_, p)| *p)
-                .unwrap_or(0),
-            average: legacy_price_pairs
-                .iter()
-                .find(|(s, _)| *s == Speed::Average)
-                .map(|(_, p)| *p)
-                .unwrap_or(0),
-            fast: legacy_price_pairs
-                .iter()
-                .find(|(s, _)| *s == Speed::Fast)
-                .map(|(_, p)| *p)
-                .unwrap_or(0),
-            fastest: legacy_price_pairs
-                .iter()
-                .find(|(s, _)| *s == Speed::Fastest)
-                .map(|(_, p)| *p)
-                .unwrap_or(0),
+    /// Returns the prices of the network's gas oracle, served from the cache when enabled.
+    async fn get_oracle_prices(&self, oracle: &GasOracle) -> Result<GasPrices, TransactionError> {
+        let Some(cache) = &self.cache else {
+            return oracle.fetch_prices(&self.provider).await;
+        };
+
+        if let Some(snapshot) = cache.get_oracle_snapshot(self.network.chain_id) {
+            if snapshot.is_stale {
+                cache.refresh_oracle_in_background(&self.network, oracle.clone());
+            }
+            return Ok(snapshot.prices);
         }
-    }
 
-    fn percentile_index_for_speed(speed: Speed) -> (usize, f64) {
-        SPEED_PERCENTILES
-            .iter()
-            .enumerate()
-            .find(|(_, (s, _))| *s == speed)
-            .map(|(idx, (_, p))| (idx, *p))
-            .unwrap_or((0, 30.0))
+        let prices = oracle.fetch_prices(&self.provider).await?;
+        cache.set_oracle_prices(self.network.chain_id, prices.clone());
+        Ok(prices)
     }
 
     fn reward_percentiles_ordered() -> Vec<f64> {
         SPEED_PERCENTILES.iter().map(|(_, p)| *p).collect()
     }
+}
 
-    fn compute_max_priority_fees_from_history(fee_history: &FeeHistory) -> SpeedPrices {
-        fn avg_priority_fee_wei(fee_history: &FeeHistory, idx: usize, percentile: f64) -> u128 {
-            let rewards_gwei: Vec<f64> = fee_history
-                .reward
-                .as_ref()
-                .map(|reward_rows| {
-                    reward_rows
-                        .iter()
-                        .filter_map(|block_rewards| {
-                            let reward = block_rewards[idx];
-                            if reward > 0 {
-                                Some(reward as f64 / GWEI)
-                            } else {
-                                None
-                            }
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
+/// Builds the legacy price of each speed by applying the speed multipliers to a base gas price.
+pub(crate) fn build_legacy_prices_from_base(base_gas_price: u128) -> SpeedPrices {
+    let legacy_price_pairs: Vec<(Speed, u128)> = Speed::multiplier()
+        .into_iter()
+        .map(|(speed, multiplier)| {
+            let price_for_speed = (base_gas_price * multiplier) / 100;
+            (speed, price_for_speed)
+        })
+        .collect();
 
-            let avg_gwei = if rewards_gwei.is_empty() {
-                (1.0 * percentile) / 100.0
-            } else {
-                rewards_gwei.iter().sum::<f64>() / rewards_gwei.len() as f64
-            };
+    SpeedPrices {
+        safe_low: legacy_price_pairs
+            .iter()
+            .find(|(s, _)| *s == Speed::SafeLow)
+            .map(|(_, p)| *p)
+            .unwrap_or(0),
+        average: legacy_price_pairs
+            .iter()
+            .find(|(s, _)| *s == Speed::Average)
+            .map(|(_, p)| *p)
+            .unwrap_or(0),
+        fast: legacy_price_pairs
+            .iter()
+            .find(|(s, _)| *s == Speed::Fast)
+            .map(|(_, p)| *p)
+            .unwrap_or(0),
+        fastest: legacy_price_pairs
+            .iter()
+            .find(|(s, _)| *s == Speed::Fastest)
+            .map(|(_, p)| *p)
+            .unwrap_or(0),
+    }
+}
 
-            (avg_gwei * GWEI) as u128
-        }
+/// Averages the priority fee rewards of each speed over the blocks of a fee history.
+///
+/// `percentiles` are the reward percentiles the fee history was requested with, ordered from
+/// the slowest to the fastest speed.
+pub(crate) fn compute_max_priority_fees_from_history(
+    fee_history: &FeeHistory,
+    percentiles: &[f64],
+) -> SpeedPrices {
+    fn avg_priority_fee_wei(fee_history: &FeeHistory, idx: usize, percentile: f64) -> u128 {
+        let rewards_gwei: Vec<f64> = fee_history
+            .reward
+            .as_ref()
+            .map(|reward_rows| {
+                reward_rows
+                    .iter()
+                    .filter_map(|block_rewards| {
+                        let reward = block_rewards[idx];
+                        if reward > 0 {
+                            Some(reward as f64 / GWEI)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let avg_gwei = if rewards_gwei.is_empty() {
+            (1.0 * percentile) / 100.0
+        } else {
+            rewards_gwei.iter().sum::<f64>() / rewards_gwei.len() as f64
+        };
 
-        let (i0, p0) = Self::percentile_index_for_speed(Speed::SafeLow);
-        let (i1, p1) = Self::percentile_index_for_speed(Speed::Average);
-        let (i2, p2) = Self::percentile_index_for_speed(Speed::Fast);
-        let (i3, p3) = Self::percentile_index_for_speed(Speed::Fastest);
+        (avg_gwei * GWEI) as u128
+    }
 
-        SpeedPrices {
-            safe_low: avg_priority_fee_wei(fee_history, i0, p0),
-            average: avg_priority_fee_wei(fee_history, i1, p1),
-            fast: avg_priority_fee_wei(fee_history, i2, p2),
-            fastest: avg_priority_fee_wei(fee_history, i3, p3),
-        }
+    SpeedPrices {
+        safe_low: avg_priority_fee_wei(fee_history, 0, percentiles[0]),
+        average: avg_priority_fee_wei(fee_history, 1, percentiles[1]),
+        fast: avg_priority_fee_wei(fee_history, 2, percentiles[2]),
+        fastest: avg_priority_fee_wei(fee_history, 3, percentiles[3]),
     }
 }
 
@@ -278,6 +296,10 @@ impl<P: EvmProviderTrait + Send + Sync + 'static> EvmGasPriceServiceTrait
     }
 
     async fn get_legacy_prices_from_json_rpc(&self) -> Result<SpeedPrices, TransactionError> {
+        if let Some(oracle) = &self.oracle {
+            return Ok(self.get_oracle_prices(oracle).await?.legacy_prices);
+        }
+
         let base = if let Some(cache) = &self.cache {
             if let Some(snapshot) = cache.get_snapshot(self.network.chain_id).await {
                 if snapshot.is_stale {
@@ -298,10 +320,14 @@ impl<P: EvmProviderTrait + Send + Sync + 'static> EvmGasPriceServiceTrait
             self.provider.get_gas_price().await?
         };
 
-        Ok(Self::build_legacy_prices_from_base(base))
+        Ok(build_legacy_prices_from_base(base))
     }
 
     async fn get_current_base_fee(&self) -> Result<u128, TransactionError> {
+        if let Some(oracle) = &self.oracle {
+            return Ok(self.get_oracle_prices(oracle).await?.base_fee_per_gas);
+        }
+
         if let Some(cache) = &self.cache {
             if let Some(snapshot) = cache.get_snapshot(self.network.chain_id).await {
                 if snapshot.is_stale {
@@ -326,14 +352,21 @@ impl<P: EvmProviderTrait + Send + Sync + 'static> EvmGasPriceServiceTrait
     }
 
     async fn get_prices_from_json_rpc(&self) -> Result<GasPrices, TransactionError> {
+        if let Some(oracle) = &self.oracle {
+            return self.get_oracle_prices(oracle).await;
+        }
+
         if let Some(cache) = &self.cache {
             if let Some(snapshot) = cache.get_snapshot(self.network.chain_id).await {
                 let gas_price = snapshot.gas_price;
                 let base_fee = snapshot.base_fee_per_gas;
                 let fee_history = snapshot.fee_history.clone();
                 let is_stale = snapshot.is_stale;
-                let legacy_prices = Self::build_legacy_prices_from_base(gas_price);
-                let max_priority_fees = Self::compute_max_priority_fees_from_history(&fee_history);
+                let legacy_prices = build_legacy_prices_from_base(gas_price);
+                let max_priority_fees = compute_max_priority_fees_from_history(
+                    &fee_history,
+                    &Self::reward_percentiles_ordered(),
+                );
 
                 // If stale, serve cached immediately and refresh in background
                 if is_stale {
@@ -379,7 +412,10 @@ impl<P: EvmProviderTrait + Send + Sync + 'static> EvmGasPriceServiceTrait
             }
         )?;
 
-        let max_priority_fees = Self::compute_max_priority_fees_from_history(&fee_history);
+        let max_priority_fees = compute_max_priority_fees_from_history(
+            &fee_history,
+            &Self::reward_percentiles_ordered(),
+        );
 
         Ok(GasPrices {
             legacy_prices,
@@ -417,6 +453,8 @@ mod tests {
             features: vec!["eip1559".to_string()],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -601,4 +639,55 @@ mod tests {
         assert_eq!(prices.max_priority_fee_per_gas.fast, 3_000_000_000);
         assert_eq!(prices.max_priority_fee_per_gas.fastest, 4_000_000_000);
     }
+
+    #[tokio::test]
+    async fn test_get_prices_from_gas_oracle_cached() {
+        use crate::config::{FeeHistoryGasOracleConfig, GasOracleConfig, GasPriceCacheConfig};
+
+        let mut mock_provider = MockEvmProviderTrait::new();
+        mock_provider
+            .expect_get_fee_history()
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    Ok(FeeHistory {
+                        oldest_block: 100,
+                        base_fee_per_gas: vec![4_000_000_000, 5_000_000_000],
+                        gas_used_ratio: vec![0.5],
+                        reward: Some(vec![vec![
+                            1_000_000_000,
+                            2_000_000_000,
+                            3_000_000_000,
+                            4_000_000_000,
+                        ]]),
+                        base_fee_per_blob_gas: vec![],
+                        blob_gas_used_ratio: vec![],
+                    })
+                })
+            });
+
+        let mut network = create_test_evm_network();
+        network.gas_oracle = Some(GasOracleConfig::FeeHistory(
+            FeeHistoryGasOracleConfig::default(),
+        ));
+        let cache = std::sync::Arc::new(GasPriceCache::new_instance());
+        cache.configure_network(
+            network.chain_id,
+            GasPriceCacheConfig {
+                enabled: true,
+                stale_after_ms: 30_000,
+                expire_after_ms: 60_000,
+            },
+        );
+
+        let service = EvmGasPriceService::new(mock_provider, network, Some(cache));
+        let prices = service.get_prices_from_json_rpc().await.unwrap();
+        assert_eq!(prices.base_fee_per_gas, 5_000_000_000);
+        assert_eq!(prices.legacy_prices.fast, 8_000_000_000);
+
+        // Served from the cache without querying the node again
+        assert_eq!(service.get_current_base_fee().await.unwrap(), 5_000_000_000);
+        let legacy_prices = service.get_legacy_prices_from_json_rpc().await.unwrap();
+        assert_eq!(legacy_prices.safe_low, 6_000_000_000);
+    }
 }
@@ -0,0 +1,444 @@
+//! Gas oracles that replace the node-based pricing of `EvmGasPriceService`.
+//!
+//! Networks pick a strategy with their `gas_oracle` configuration:
+//! - Node (default): `eth_gasPrice` and `eth_feeHistory` with fixed speed multipliers,
+//!   implemented by `EvmGasPriceService` itself
+//! - Fee history: priority fee percentiles over a configurable window of recent blocks, on
+//!   top of the base fee of the next block
+//! - HTTP: an external service serving prices as JSON, read through configurable JSON pointers
+use std::time::Duration;
+
+use alloy::rpc::types::BlockNumberOrTag;
+use reqwest::Client;
+use serde_json::Value;
+
+use crate::{
+    config::{
+        FeeHistoryGasOracleConfig, GasOracleConfig, GasOracleUnit, HttpGasOracleConfig,
+        SpeedFieldMapping,
+    },
+    constants::GAS_ORACLE_TIMEOUT_SECONDS,
+    models::TransactionError,
+    services::{
+        gas::evm_gas_price::{
+            build_legacy_prices_from_base, compute_max_priority_fees_from_history, GasPrices,
+            SpeedPrices,
+        },
+        EvmProviderTrait,
+    },
+};
+
+const WEI_PER_GWEI: f64 = 1e9;
+
+#[derive(Debug, Clone)]
+pub enum GasOracle {
+    FeeHistory(FeeHistoryGasOracle),
+    Http(HttpGasOracle),
+}
+
+impl GasOracle {
+    /// Creates the oracle for a network's gas oracle configuration.
+    ///
+    /// Returns `None` for the node strategy, which is built into `EvmGasPriceService`.
+    pub fn from_config(config: &GasOracleConfig) -> Option<Self> {
+        match config {
+            GasOracleConfig::Node => None,
+            GasOracleConfig::FeeHistory(config) => {
+                Some(GasOracle::FeeHistory(FeeHistoryGasOracle::new(config)))
+            }
+            GasOracleConfig::Http(config) => Some(GasOracle::Http(HttpGasOracle::new(config))),
+        }
+    }
+
+    pub async fn fetch_prices<P: EvmProviderTrait>(
+        &self,
+        provider: &P,
+    ) -> Result<GasPrices, TransactionError> {
+        match self {
+            GasOracle::FeeHistory(oracle) => oracle.fetch_prices(provider).await,
+            GasOracle::Http(oracle) => oracle.fetch_prices(provider).await,
+        }
+    }
+}
+
+/// Prices transactions from the priority fees paid in recent blocks.
+#[derive(Debug, Clone)]
+pub struct FeeHistoryGasOracle {
+    block_count: u64,
+    reward_percentiles: Vec<f64>,
+}
+
+impl FeeHistoryGasOracle {
+    pub fn new(config: &FeeHistoryGasOracleConfig) -> Self {
+        let percentiles = config.reward_percentiles;
+        Self {
+            block_count: config.block_count,
+            reward_percentiles: [
+                percentiles.safe_low,
+                percentiles.average,
+                percentiles.fast,
+                percentiles.fastest,
+            ]
+            .into_iter()
+            .map(f64::from)
+            .collect(),
+        }
+    }
+
+    pub async fn fetch_prices<P: EvmProviderTrait>(
+        &self,
+        provider: &P,
+    ) -> Result<GasPrices, TransactionError> {
+        let fee_history = provider
+            .get_fee_history(
+                self.block_count,
+                BlockNumberOrTag::Latest,
+                self.reward_percentiles.clone(),
+            )
+            .await
+            .map_err(|e| {
+                TransactionError::NetworkConfiguration(format!(
+                    "Failed to fetch fee history data: {}",
+                    e
+                ))
+            })?;
+
+        // The last base fee of a fee history is the one of the next block
+        let base_fee_per_gas = fee_history.base_fee_per_gas.last().copied().unwrap_or(0);
+        let max_priority_fee_per_gas =
+            compute_max_priority_fees_from_history(&fee_history, &self.reward_percentiles);
+
+        let legacy_prices = if base_fee_per_gas > 0 {
+            add_base_fee(&max_priority_fee_per_gas, base_fee_per_gas)
+        } else {
+            // Networks without a base fee only have the node's gas price to go on
+            build_legacy_prices_from_base(provider.get_gas_price().await?)
+        };
+
+        Ok(GasPrices {
+            legacy_prices,
+            max_priority_fee_per_gas,
+            base_fee_per_gas,
+        })
+    }
+}
+
+/// Reads prices from an external oracle serving them as JSON.
+#[derive(Debug, Clone)]
+pub struct HttpGasOracle {
+    client: Client,
+    config: HttpGasOracleConfig,
+}
+
+impl HttpGasOracle {
+    pub fn new(config: &HttpGasOracleConfig) -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(GAS_ORACLE_TIMEOUT_SECONDS))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            client,
+            config: config.clone(),
+        }
+    }
+
+    pub async fn fetch_prices<P: EvmProviderTrait>(
+        &self,
+        provider: &P,
+    ) -> Result<GasPrices, TransactionError> {
+        let response: Value = self
+            .client
+            .get(&self.config.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                TransactionError::NetworkConfiguration(format!("Failed to query gas oracle: {}", e))
+            })?
+            .json()
+            .await
+            .map_err(|e| {
+                TransactionError::NetworkConfiguration(format!(
+                    "Invalid gas oracle response: {}",
+                    e
+                ))
+            })?;
+
+        let max_priority_fee_per_gas =
+            self.read_speed_prices(&response, &self.config.max_priority_fee_per_gas)?;
+
+        let base_fee_per_gas = match &self.config.base_fee_per_gas {
+            Some(pointer) => self.read_price(&response, pointer)?,
+            None => {
+                let block = provider.get_block_by_number().await?;
+                block.header.base_fee_per_gas.unwrap_or(0).into()
+            }
+        };
+
+        let legacy_prices = match &self.config.gas_price {
+            Some(mapping) => self.read_speed_prices(&response, mapping)?,
+            None => add_base_fee(&max_priority_fee_per_gas, base_fee_per_gas),
+        };
+
+        Ok(GasPrices {
+            legacy_prices,
+            max_priority_fee_per_gas,
+            base_fee_per_gas,
+        })
+    }
+
+    fn read_speed_prices(
+        &self,
+        response: &Value,
+        mapping: &SpeedFieldMapping,
+    ) -> Result<SpeedPrices, TransactionError> {
+        Ok(SpeedPrices {
+            safe_low: self.read_price(response, &mapping.safe_low)?,
+            average: self.read_price(response, &mapping.average)?,
+            fast: self.read_price(response, &mapping.fast)?,
+            fastest: self.read_price(response, &mapping.fastest)?,
+        })
+    }
+
+    /// Reads the price at a JSON pointer, given as a number or a numeric string, in wei.
+    fn read_price(&self, response: &Value, pointer: &str) -> Result<u128, TransactionError> {
+        let value = match response.pointer(pointer) {
+            Some(Value::Number(number)) => number.as_f64(),
+            Some(Value::String(text)) => text.trim().parse::<f64>().ok(),
+            _ => None,
+        }
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .ok_or_else(|| {
+            TransactionError::NetworkConfiguration(format!(
+                "Gas oracle response has no valid price at '{}'",
+                pointer
+            ))
+        })?;
+
+        let wei = match self.config.unit {
+            GasOracleUnit::Wei => value,
+            GasOracleUnit::Gwei => value * WEI_PER_GWEI,
+        };
+        Ok(wei as u128)
+    }
+}
+
+fn add_base_fee(priority_fees: &SpeedPrices, base_fee: u128) -> SpeedPrices {
+    SpeedPrices {
+        safe_low: base_fee.saturating_add(priority_fees.safe_low),
+        average: base_fee.saturating_add(priority_fees.average),
+        fast: base_fee.saturating_add(priority_fees.fast),
+        fastest: base_fee.saturating_add(priority_fees.fastest),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        network::AnyRpcBlock,
+        rpc::types::{Block, FeeHistory},
+    };
+
+    use super::*;
+    use crate::{config::SpeedPercentiles, services::provider::evm::MockEvmProviderTrait};
+
+    fn create_fee_history(base_fee_per_gas: Vec<u128>) -> FeeHistory {
+        FeeHistory {
+            oldest_block: 100,
+            base_fee_per_gas,
+            gas_used_ratio: vec![0.5, 0.5],
+            reward: Some(vec![
+                vec![1_000_000_000, 2_000_000_000, 3_000_000_000, 4_000_000_000],
+                vec![3_000_000_000, 4_000_000_000, 5_000_000_000, 6_000_000_000],
+            ]),
+            base_fee_per_blob_gas: vec![],
+            blob_gas_used_ratio: vec![],
+        }
+    }
+
+    fn create_http_config(url: String) -> HttpGasOracleConfig {
+        HttpGasOracleConfig {
+            url,
+            unit: GasOracleUnit::Gwei,
+            max_priority_fee_per_gas: SpeedFieldMapping {
+                safe_low: "/safeLow/maxPriorityFee".to_string(),
+                average: "/standard/maxPriorityFee".to_string(),
+                fast: "/fast/maxPriorityFee".to_string(),
+                fastest: "/fast/maxPriorityFee".to_string(),
+            },
+            gas_price: None,
+            base_fee_per_gas: Some("/estimatedBaseFee".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_node_strategy_has_no_oracle() {
+        assert!(GasOracle::from_config(&GasOracleConfig::Node).is_none());
+        assert!(GasOracle::from_config(&GasOracleConfig::FeeHistory(
+            FeeHistoryGasOracleConfig::default()
+        ))
+        .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_fee_history_oracle_prices() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_fee_history()
+            .withf(|block_count, _, percentiles| {
+                *block_count == 2 && percentiles == &vec![10.0, 40.0, 70.0, 95.0]
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    Ok(create_fee_history(vec![
+                        8_000_000_000,
+                        9_000_000_000,
+                        10_000_000_000,
+                    ]))
+                })
+            });
+
+        let oracle = FeeHistoryGasOracle::new(&FeeHistoryGasOracleConfig {
+            block_count: 2,
+            reward_percentiles: SpeedPercentiles {
+                safe_low: 10,
+                average: 40,
+                fast: 70,
+                fastest: 95,
+            },
+        });
+        let prices = oracle.fetch_prices(&provider).await.unwrap();
+
+        assert_eq!(prices.base_fee_per_gas, 10_000_000_000);
+        assert_eq!(prices.max_priority_fee_per_gas.safe_low, 2_000_000_000);
+        assert_eq!(prices.max_priority_fee_per_gas.fastest, 5_000_000_000);
+        assert_eq!(prices.legacy_prices.safe_low, 12_000_000_000);
+        assert_eq!(prices.legacy_prices.fastest, 15_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_fee_history_oracle_without_base_fee_uses_gas_price() {
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_fee_history()
+            .returning(|_, _, _| Box::pin(async { Ok(create_fee_history(vec![0, 0, 0])) }));
+        provider
+            .expect_get_gas_price()
+            .times(1)
+            .returning(|| Box::pin(async { Ok(10_000_000_000) }));
+
+        let oracle = FeeHistoryGasOracle::new(&FeeHistoryGasOracleConfig::default());
+        let prices = oracle.fetch_prices(&provider).await.unwrap();
+
+        assert_eq!(prices.base_fee_per_gas, 0);
+        assert_eq!(prices.legacy_prices.average, 12_500_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_http_oracle_reads_mapped_fields() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "safeLow": {"maxPriorityFee": 30, "maxFee": 31},
+                    "standard": {"maxPriorityFee": "32.5", "maxFee": 34},
+                    "fast": {"maxPriorityFee": 40.25, "maxFee": 42},
+                    "estimatedBaseFee": 0.5
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let oracle = HttpGasOracle::new(&create_http_config(server.url()));
+        let prices = oracle
+            .fetch_prices(&MockEvmProviderTrait::new())
+            .await
+            .unwrap();
+
+        assert_eq!(prices.base_fee_per_gas, 500_000_000);
+        assert_eq!(prices.max_priority_fee_per_gas.safe_low, 30_000_000_000);
+        assert_eq!(prices.max_priority_fee_per_gas.average, 32_500_000_000);
+        assert_eq!(prices.max_priority_fee_per_gas.fastest, 40_250_000_000);
+        assert_eq!(prices.legacy_prices.fast, 40_750_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_http_oracle_uses_block_base_fee_when_unmapped() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/")
+            .with_status(200)
+            .with_body(
+                r#"{
+                    "safeLow": {"maxPriorityFee": 1},
+                    "standard": {"maxPriorityFee": 2},
+                    "fast": {"maxPriorityFee": 3}
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let mut provider = MockEvmProviderTrait::new();
+        provider
+            .expect_get_block_by_number()
+            .times(1)
+            .returning(|| {
+                Box::pin(async {
+                    let mut block: Block = Block::default();
+                    block.header.base_fee_per_gas = Some(7_000_000_000);
+                    Ok(AnyRpcBlock::from(block))
+                })
+            });
+
+        let mut config = create_http_config(server.url());
+        config.base_fee_per_gas = None;
+        let prices = HttpGasOracle::new(&config)
+            .fetch_prices(&provider)
+            .await
+            .unwrap();
+
+        assert_eq!(prices.base_fee_per_gas, 7_000_000_000);
+        assert_eq!(prices.legacy_prices.average, 9_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_http_oracle_missing_field() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/")
+            .with_status(200)
+            .with_body(r#"{"safeLow": {"maxPriorityFee": "n/a"}}"#)
+            .create_async()
+            .await;
+
+        let oracle = HttpGasOracle::new(&create_http_config(server.url()));
+        let result = oracle.fetch_prices(&MockEvmProviderTrait::new()).await;
+
+        assert!(matches!(
+            result,
+            Err(TransactionError::NetworkConfiguration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_http_oracle_error_status() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let oracle = HttpGasOracle::new(&create_http_config(server.url()));
+        let result = oracle.fetch_prices(&MockEvmProviderTrait::new()).await;
+
+        assert!(matches!(
+            result,
+            Err(TransactionError::NetworkConfiguration(_))
+        ));
+    }
+}
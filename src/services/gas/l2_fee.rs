@@ -6,10 +6,14 @@
 //!
 //! Currently supports:
 //! - Optimism: Calculates L1 data availability fees in addition to L2 execution fees
+//! - Arbitrum: Estimates the L1 component of the fee through the NodeInterface precompile
 use crate::{
     models::{evm::EvmTransactionRequest, EvmNetwork, TransactionError, U256},
     services::{
-        gas::optimism_extra_fee::{OptimismExtraFeeService, OptimismFeeData},
+        gas::{
+            arbitrum_extra_fee::{ArbitrumExtraFeeService, ArbitrumFeeData},
+            optimism_extra_fee::{OptimismExtraFeeService, OptimismFeeData},
+        },
         provider::evm::EvmProviderTrait,
     },
 };
@@ -17,17 +21,23 @@ use crate::{
 #[derive(Debug, Clone)]
 pub enum L2FeeData {
     Optimism(OptimismFeeData),
+    Arbitrum(ArbitrumFeeData),
 }
 
 #[derive(Debug, Clone)]
 pub enum L2FeeService<P> {
     Optimism(OptimismExtraFeeService<P>),
+    Arbitrum(ArbitrumExtraFeeService<P>),
 }
 
 impl<P: EvmProviderTrait + Clone> L2FeeService<P> {
-    pub async fn fetch_fee_data(&self) -> Result<L2FeeData, TransactionError> {
+    pub async fn fetch_fee_data(
+        &self,
+        tx: &EvmTransactionRequest,
+    ) -> Result<L2FeeData, TransactionError> {
         match self {
             L2FeeService::Optimism(svc) => svc.fetch_fee_data().await.map(L2FeeData::Optimism),
+            L2FeeService::Arbitrum(svc) => svc.fetch_fee_data(tx).await.map(L2FeeData::Arbitrum),
         }
     }
 
@@ -38,6 +48,10 @@ impl<P: EvmProviderTrait + Clone> L2FeeService<P> {
     ) -> Result<U256, TransactionError> {
         match (self, fee_data) {
             (L2FeeService::Optimism(svc), L2FeeData::Optimism(data)) => svc.calculate_fee(data, tx),
+            (L2FeeService::Arbitrum(svc), L2FeeData::Arbitrum(data)) => svc.calculate_fee(data, tx),
+            _ => Err(TransactionError::UnexpectedError(
+                "L2 fee data does not match the fee service".to_string(),
+            )),
         }
     }
 }
@@ -51,6 +65,10 @@ pub fn l2_fee_service_factory<P: EvmProviderTrait + Clone>(
         Some(L2FeeService::Optimism(OptimismExtraFeeService::new(
             provider,
         )))
+    } else if network.is_arbitrum() {
+        Some(L2FeeService::Arbitrum(ArbitrumExtraFeeService::new(
+            provider,
+        )))
     } else {
         None
     }
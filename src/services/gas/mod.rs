@@ -1,6 +1,8 @@
 //! This module contains services related to gas price estimation and calculation.
+pub mod arbitrum_extra_fee;
 pub mod cache;
 pub mod evm_gas_price;
+pub mod gas_oracle;
 pub mod l2_fee;
 pub mod network_extra_fee;
 pub mod optimism_extra_fee;
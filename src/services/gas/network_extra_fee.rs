@@ -24,7 +24,7 @@ impl NetworkExtraFeeCalculatorService {
 impl NetworkExtraFeeCalculatorServiceTrait for NetworkExtraFeeCalculatorService {
     async fn get_extra_fee(&self, tx: &EvmTransactionRequest) -> Result<U256, TransactionError> {
         if let Some(l2_fee_service) = l2_fee_service_factory(&self.network, self.provider.clone()) {
-            let fee_data = l2_fee_service.fetch_fee_data().await?;
+            let fee_data = l2_fee_service.fetch_fee_data(tx).await?;
             let fee = l2_fee_service.calculate_fee(&fee_data, tx)?;
             Ok(fee)
         } else {
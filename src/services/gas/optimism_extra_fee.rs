@@ -1,11 +1,11 @@
 use crate::{
     constants::OPTIMISM_GAS_PRICE_ORACLE_ADDRESS,
-    models::{evm::EvmTransactionRequest, TransactionError, U256},
+    models::{evm::EvmTransactionRequest, TransactionError, TransactionReceipt, U256},
     services::provider::evm::EvmProviderTrait,
 };
 use alloy::{
-    primitives::{Address, Bytes, TxKind},
-    rpc::types::{TransactionInput, TransactionRequest},
+    primitives::{Address, Bytes, TxKind, U128},
+    rpc::types::{BlockNumberOrTag, TransactionInput, TransactionRequest},
 };
 
 #[derive(Debug, Clone)]
@@ -60,7 +60,7 @@ impl<P: EvmProviderTrait> OptimismExtraFeeService<P> {
         let call = self.create_contract_call(selector);
         let bytes = self
             .provider
-            .call_contract(&call)
+            .call_contract(&call, BlockNumberOrTag::Latest)
             .await
             .map_err(|e| TransactionError::UnexpectedError(e.to_string()))?;
         Ok(U256::from_be_slice(bytes.as_ref()))
@@ -119,3 +119,12 @@ impl<P: EvmProviderTrait> OptimismExtraFeeService<P> {
         Ok(tx_compressed_size.saturating_mul(weighted_gas_price))
     }
 }
+
+/// Returns the L1 data fee an OP-stack node reports in the `l1Fee` field of a receipt.
+pub fn l1_fee_from_receipt(receipt: &TransactionReceipt) -> Option<u128> {
+    receipt
+        .other
+        .get_deserialized::<U128>("l1Fee")
+        .and_then(|fee| fee.ok())
+        .map(|fee| fee.to::<u128>())
+}
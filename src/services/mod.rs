@@ -11,6 +11,9 @@ pub use signer::*;
 mod notification;
 pub use notification::*;
 
+mod private_relay;
+pub use private_relay::*;
+
 mod transaction_counter;
 pub use transaction_counter::*;
 
@@ -6,10 +6,11 @@ use crate::{
     jobs::JobProducerTrait,
     models::{
         AppState, NetworkRepoModel, NotificationRepoModel, PluginCallRequest, PluginModel,
-        RelayerRepoModel, SignerRepoModel, ThinDataAppState, TransactionRepoModel,
+        RelayerPoolRepoModel, RelayerRepoModel, SignerRepoModel, ThinDataAppState,
+        TransactionRepoModel,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
 };
@@ -87,11 +88,11 @@ impl<R: PluginRunnerTrait> PluginService<R> {
     }
 
     #[allow(clippy::type_complexity)]
-    async fn call_plugin<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn call_plugin<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         plugin: PluginModel,
         plugin_call_request: PluginCallRequest,
-        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<PluginCallResponse, PluginError>
     where
         J: JobProducerTrait + Send + Sync + 'static,
@@ -106,6 +107,8 @@ impl<R: PluginRunnerTrait> PluginService<R> {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let socket_path = format!("/tmp/{}.sock", Uuid::new_v4());
         let script_path = Self::resolve_plugin_path(&plugin.path);
@@ -138,7 +141,7 @@ impl<R: PluginRunnerTrait> PluginService<R> {
 
 #[async_trait]
 #[cfg_attr(test, automock)]
-pub trait PluginServiceTrait<J, TR, RR, NR, NFR, SR, TCR, PR>: Send + Sync
+pub trait PluginServiceTrait<J, TR, RR, NR, NFR, SR, TCR, PR, AKR, RPR>: Send + Sync
 where
     J: JobProducerTrait + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
@@ -148,19 +151,21 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     fn new(runner: PluginRunner) -> Self;
     async fn call_plugin(
         &self,
         plugin: PluginModel,
         plugin_call_request: PluginCallRequest,
-        state: Arc<web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>>,
+        state: Arc<web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>>,
     ) -> Result<PluginCallResponse, PluginError>;
 }
 
 #[async_trait]
-impl<J, TR, RR, NR, NFR, SR, TCR, PR> PluginServiceTrait<J, TR, RR, NR, NFR, SR, TCR, PR>
-    for PluginService<PluginRunner>
+impl<J, TR, RR, NR, NFR, SR, TCR, PR, AKR, RPR>
+    PluginServiceTrait<J, TR, RR, NR, NFR, SR, TCR, PR, AKR, RPR> for PluginService<PluginRunner>
 where
     J: JobProducerTrait + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
@@ -170,6 +175,8 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     fn new(runner: PluginRunner) -> Self {
         Self::new(runner)
@@ -179,7 +186,7 @@ where
         &self,
         plugin: PluginModel,
         plugin_call_request: PluginCallRequest,
-        state: Arc<web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>>,
+        state: Arc<web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>>,
     ) -> Result<PluginCallResponse, PluginError> {
         self.call_plugin(plugin, plugin_call_request, state).await
     }
@@ -194,8 +201,9 @@ mod tests {
         jobs::MockJobProducerTrait,
         models::PluginModel,
         repositories::{
-            NetworkRepositoryStorage, NotificationRepositoryStorage, PluginRepositoryStorage,
-            RelayerRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage,
+            ApiKeyRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage,
+            PluginRepositoryStorage, RelayerPoolRepositoryStorage, RelayerRepositoryStorage,
+            SignerRepositoryStorage, TransactionCounterRepositoryStorage,
             TransactionRepositoryStorage,
         },
         utils::mocks::mockutils::create_mock_app_state,
@@ -237,12 +245,14 @@ mod tests {
             SignerRepositoryStorage,
             TransactionCounterRepositoryStorage,
             PluginRepositoryStorage,
+            ApiKeyRepositoryStorage,
+            RelayerPoolRepositoryStorage,
         > = create_mock_app_state(None, None, None, Some(vec![plugin.clone()]), None).await;
 
         let mut plugin_runner = MockPluginRunnerTrait::default();
 
         plugin_runner
-            .expect_run::<MockJobProducerTrait, RelayerRepositoryStorage, TransactionRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage, PluginRepositoryStorage>()
+            .expect_run::<MockJobProducerTrait, RelayerRepositoryStorage, TransactionRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage, PluginRepositoryStorage, ApiKeyRepositoryStorage, RelayerPoolRepositoryStorage>()
             .returning(|_, _, _, _, _| {
                 Ok(ScriptResult {
                     logs: vec![LogEntry {
@@ -10,11 +10,12 @@ use crate::domain::{
 };
 use crate::jobs::JobProducerTrait;
 use crate::models::{
-    AppState, NetworkRepoModel, NetworkTransactionRequest, NotificationRepoModel, RelayerRepoModel,
-    SignerRepoModel, ThinDataAppState, TransactionRepoModel, TransactionResponse,
+    AppState, NetworkRepoModel, NetworkTransactionRequest, NotificationRepoModel,
+    RelayerPoolRepoModel, RelayerRepoModel, SignerRepoModel, ThinDataAppState,
+    TransactionRepoModel, TransactionResponse,
 };
 use crate::repositories::{
-    NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+    ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
     TransactionCounterTrait, TransactionRepository,
 };
 use crate::services::plugins::PluginError;
@@ -65,7 +66,7 @@ pub struct Response {
 
 #[async_trait]
 #[cfg_attr(test, automock)]
-pub trait RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR>: Send + Sync
+pub trait RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>: Send + Sync
 where
     J: JobProducerTrait + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
@@ -75,46 +76,48 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     async fn handle_request(
         &self,
         request: Request,
-        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Response;
 
     async fn process_request(
         &self,
         request: Request,
-        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<Response, PluginError>;
 
     async fn handle_send_transaction(
         &self,
         request: Request,
-        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<Response, PluginError>;
 
     async fn handle_get_transaction(
         &self,
         request: Request,
-        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<Response, PluginError>;
 
     async fn handle_get_relayer_status(
         &self,
         request: Request,
-        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<Response, PluginError>;
 
     async fn handle_sign_transaction(
         &self,
         request: Request,
-        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<Response, PluginError>;
     async fn handle_get_relayer_info(
         &self,
         request: Request,
-        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: &web::ThinData<AppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<Response, PluginError>;
 }
 
@@ -122,10 +125,10 @@ where
 pub struct RelayerApi;
 
 impl RelayerApi {
-    pub async fn handle_request<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    pub async fn handle_request<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Response
     where
         J: JobProducerTrait + 'static,
@@ -140,6 +143,8 @@ impl RelayerApi {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         match self.process_request(request.clone(), state).await {
             Ok(response) => response,
@@ -151,10 +156,10 @@ impl RelayerApi {
         }
     }
 
-    async fn process_request<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn process_request<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError>
     where
         J: JobProducerTrait + 'static,
@@ -169,6 +174,8 @@ impl RelayerApi {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         match request.method {
             PluginMethod::SendTransaction => self.handle_send_transaction(request, state).await,
@@ -179,10 +186,10 @@ impl RelayerApi {
         }
     }
 
-    async fn handle_send_transaction<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn handle_send_transaction<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError>
     where
         J: JobProducerTrait + 'static,
@@ -197,6 +204,8 @@ impl RelayerApi {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let relayer_repo_model = get_relayer_by_id(request.relayer_id.clone(), state)
             .await
@@ -206,6 +215,10 @@ impl RelayerApi {
             .validate_active_state()
             .map_err(|e| PluginError::RelayerError(e.to_string()))?;
 
+        relayer_repo_model
+            .validate_no_active_signer_rotation()
+            .map_err(|e| PluginError::RelayerError(e.to_string()))?;
+
         let network_relayer = get_network_relayer(request.relayer_id.clone(), state)
             .await
             .map_err(|e| PluginError::RelayerError(e.to_string()))?;
@@ -236,10 +249,10 @@ impl RelayerApi {
         })
     }
 
-    async fn handle_get_transaction<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn handle_get_transaction<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError>
     where
         J: JobProducerTrait + 'static,
@@ -254,6 +267,8 @@ impl RelayerApi {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         // validation purpose only, checks if relayer exists
         get_relayer_by_id(request.relayer_id.clone(), state)
@@ -280,10 +295,10 @@ impl RelayerApi {
         })
     }
 
-    async fn handle_get_relayer_status<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn handle_get_relayer_status<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError>
     where
         J: JobProducerTrait + 'static,
@@ -298,6 +313,8 @@ impl RelayerApi {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let network_relayer = get_network_relayer(request.relayer_id.clone(), state)
             .await
@@ -318,10 +335,10 @@ impl RelayerApi {
         })
     }
 
-    async fn handle_sign_transaction<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn handle_sign_transaction<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError>
     where
         J: JobProducerTrait + 'static,
@@ -336,6 +353,8 @@ impl RelayerApi {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let sign_request: SignTransactionRequest = serde_json::from_value(request.payload)
             .map_err(|e| PluginError::InvalidPayload(e.to_string()))?;
@@ -359,10 +378,10 @@ impl RelayerApi {
         })
     }
 
-    async fn handle_get_relayer_info<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn handle_get_relayer_info<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError>
     where
         J: JobProducerTrait + 'static,
@@ -377,6 +396,8 @@ impl RelayerApi {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let relayer = get_relayer_by_id(request.relayer_id.clone(), state)
             .await
@@ -393,8 +414,8 @@ impl RelayerApi {
 }
 
 #[async_trait]
-impl<J, RR, TR, NR, NFR, SR, TCR, PR> RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR>
-    for RelayerApi
+impl<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>
+    RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR> for RelayerApi
 where
     J: JobProducerTrait + 'static,
     TR: TransactionRepository + Repository<TransactionRepoModel, String> + Send + Sync + 'static,
@@ -404,11 +425,13 @@ where
     SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
     TCR: TransactionCounterTrait + Send + Sync + 'static,
     PR: PluginRepositoryTrait + Send + Sync + 'static,
+    AKR: ApiKeyRepository + Send + Sync + 'static,
+    RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
 {
     async fn handle_request(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Response {
         self.handle_request(request, state).await
     }
@@ -416,7 +439,7 @@ where
     async fn process_request(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError> {
         self.process_request(request, state).await
     }
@@ -424,7 +447,7 @@ where
     async fn handle_send_transaction(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError> {
         self.handle_send_transaction(request, state).await
     }
@@ -432,7 +455,7 @@ where
     async fn handle_get_transaction(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError> {
         self.handle_get_transaction(request, state).await
     }
@@ -440,7 +463,7 @@ where
     async fn handle_get_relayer_status(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError> {
         self.handle_get_relayer_status(request, state).await
     }
@@ -448,7 +471,7 @@ where
     async fn handle_sign_transaction(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError> {
         self.handle_sign_transaction(request, state).await
     }
@@ -456,7 +479,7 @@ where
     async fn handle_get_relayer_info(
         &self,
         request: Request,
-        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>,
+        state: &ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>,
     ) -> Result<Response, PluginError> {
         self.handle_get_relayer_info(request, state).await
     }
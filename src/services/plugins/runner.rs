@@ -12,11 +12,11 @@ use crate::services::plugins::{RelayerApi, ScriptExecutor, ScriptResult, SocketS
 use crate::{
     jobs::JobProducerTrait,
     models::{
-        NetworkRepoModel, NotificationRepoModel, RelayerRepoModel, SignerRepoModel,
-        ThinDataAppState, TransactionRepoModel,
+        NetworkRepoModel, NotificationRepoModel, RelayerPoolRepoModel, RelayerRepoModel,
+        SignerRepoModel, ThinDataAppState, TransactionRepoModel,
     },
     repositories::{
-        NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+        ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
         TransactionCounterTrait, TransactionRepository,
     },
 };
@@ -32,13 +32,13 @@ use mockall::automock;
 #[async_trait]
 pub trait PluginRunnerTrait {
     #[allow(clippy::type_complexity)]
-    async fn run<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn run<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         socket_path: &str,
         script_path: String,
         timeout_duration: Duration,
         script_params: String,
-        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<ScriptResult, PluginError>
     where
         J: JobProducerTrait + Send + Sync + 'static,
@@ -52,7 +52,9 @@ pub trait PluginRunnerTrait {
         NFR: Repository<NotificationRepoModel, String> + Send + Sync + 'static,
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
-        PR: PluginRepositoryTrait + Send + Sync + 'static;
+        PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static;
 }
 
 #[derive(Default)]
@@ -60,13 +62,13 @@ pub struct PluginRunner;
 
 #[allow(clippy::type_complexity)]
 impl PluginRunner {
-    async fn run<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn run<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         socket_path: &str,
         script_path: String,
         timeout_duration: Duration,
         script_params: String,
-        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<ScriptResult, PluginError>
     where
         J: JobProducerTrait + 'static,
@@ -81,6 +83,8 @@ impl PluginRunner {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let socket_service = SocketService::new(socket_path)?;
         let socket_path_clone = socket_service.socket_path().to_string();
@@ -127,13 +131,13 @@ impl PluginRunner {
 
 #[async_trait]
 impl PluginRunnerTrait for PluginRunner {
-    async fn run<J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn run<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         &self,
         socket_path: &str,
         script_path: String,
         timeout_duration: Duration,
         script_params: String,
-        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
     ) -> Result<ScriptResult, PluginError>
     where
         J: JobProducerTrait + Send + Sync + 'static,
@@ -148,6 +152,8 @@ impl PluginRunnerTrait for PluginRunner {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         self.run(
             socket_path,
@@ -168,8 +174,9 @@ mod tests {
     use crate::{
         jobs::MockJobProducerTrait,
         repositories::{
-            NetworkRepositoryStorage, NotificationRepositoryStorage, PluginRepositoryStorage,
-            RelayerRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage,
+            ApiKeyRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage,
+            PluginRepositoryStorage, RelayerPoolRepositoryStorage, RelayerRepositoryStorage,
+            SignerRepositoryStorage, TransactionCounterRepositoryStorage,
             TransactionRepositoryStorage,
         },
         services::plugins::LogLevel,
@@ -213,7 +220,7 @@ mod tests {
 
         let plugin_runner = PluginRunner;
         let result = plugin_runner
-            .run::<MockJobProducerTrait, RelayerRepositoryStorage, TransactionRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage, PluginRepositoryStorage>(
+            .run::<MockJobProducerTrait, RelayerRepositoryStorage, TransactionRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage, PluginRepositoryStorage, ApiKeyRepositoryStorage, RelayerPoolRepositoryStorage>(
                 &socket_path.display().to_string(),
                 script_path.display().to_string(),
                 Duration::from_secs(10),
@@ -260,7 +267,7 @@ mod tests {
 
         // Use 100ms timeout for a 200ms script
         let result = plugin_runner
-        .run::<MockJobProducerTrait, RelayerRepositoryStorage, TransactionRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage, PluginRepositoryStorage>(
+        .run::<MockJobProducerTrait, RelayerRepositoryStorage, TransactionRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage, SignerRepositoryStorage, TransactionCounterRepositoryStorage, PluginRepositoryStorage, ApiKeyRepositoryStorage, RelayerPoolRepositoryStorage>(
             &socket_path.display().to_string(),
                 script_path.display().to_string(),
                 Duration::from_millis(100), // 100ms timeout
@@ -50,11 +50,11 @@
 
 use crate::jobs::JobProducerTrait;
 use crate::models::{
-    NetworkRepoModel, NotificationRepoModel, RelayerRepoModel, SignerRepoModel, ThinDataAppState,
-    TransactionRepoModel,
+    NetworkRepoModel, NotificationRepoModel, RelayerPoolRepoModel, RelayerRepoModel,
+    SignerRepoModel, ThinDataAppState, TransactionRepoModel,
 };
 use crate::repositories::{
-    NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
+    ApiKeyRepository, NetworkRepository, PluginRepositoryTrait, RelayerRepository, Repository,
     TransactionCounterTrait, TransactionRepository,
 };
 use std::sync::Arc;
@@ -107,14 +107,14 @@ impl SocketService {
     ///
     /// A vector of traces.
     #[allow(clippy::type_complexity)]
-    pub async fn listen<RA, J, RR, TR, NR, NFR, SR, TCR, PR>(
+    pub async fn listen<RA, J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         self,
         shutdown_rx: oneshot::Receiver<()>,
-        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
         relayer_api: Arc<RA>,
     ) -> Result<Vec<serde_json::Value>, PluginError>
     where
-        RA: RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR> + 'static + Send + Sync,
+        RA: RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR> + 'static + Send + Sync,
         J: JobProducerTrait + Send + Sync + 'static,
         RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
         TR: TransactionRepository
@@ -127,6 +127,8 @@ impl SocketService {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let mut shutdown = shutdown_rx;
 
@@ -137,7 +139,7 @@ impl SocketService {
             let relayer_api = Arc::clone(&relayer_api);
             tokio::select! {
                 Ok((stream, _)) = self.listener.accept() => {
-                    let result = tokio::spawn(Self::handle_connection::<RA, J, RR, TR, NR, NFR, SR, TCR, PR>(stream, state, relayer_api))
+                    let result = tokio::spawn(Self::handle_connection::<RA, J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(stream, state, relayer_api))
                         .await
                         .map_err(|e| PluginError::SocketError(e.to_string()))?;
 
@@ -168,13 +170,13 @@ impl SocketService {
     ///
     /// A vector of traces.
     #[allow(clippy::type_complexity)]
-    async fn handle_connection<RA, J, RR, TR, NR, NFR, SR, TCR, PR>(
+    async fn handle_connection<RA, J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>(
         stream: UnixStream,
-        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR>>,
+        state: Arc<ThinDataAppState<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR>>,
         relayer_api: Arc<RA>,
     ) -> Result<Vec<serde_json::Value>, PluginError>
     where
-        RA: RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR> + 'static + Send + Sync,
+        RA: RelayerApiTrait<J, RR, TR, NR, NFR, SR, TCR, PR, AKR, RPR> + 'static + Send + Sync,
         J: JobProducerTrait + 'static,
         RR: RelayerRepository + Repository<RelayerRepoModel, String> + Send + Sync + 'static,
         TR: TransactionRepository
@@ -187,6 +189,8 @@ impl SocketService {
         SR: Repository<SignerRepoModel, String> + Send + Sync + 'static,
         TCR: TransactionCounterTrait + Send + Sync + 'static,
         PR: PluginRepositoryTrait + Send + Sync + 'static,
+        AKR: ApiKeyRepository + Send + Sync + 'static,
+        RPR: Repository<RelayerPoolRepoModel, String> + Send + Sync + 'static,
     {
         let (r, mut w) = stream.into_split();
         let mut reader = BufReader::new(r).lines();
@@ -0,0 +1,363 @@
+//! This module provides the `PrivateRelayService` for submitting signed EVM transactions to a
+//! private relay (such as Flashbots Protect or a builder endpoint) instead of broadcasting them
+//! to the public mempool.
+//!
+//! Two submission methods are supported:
+//! - `eth_sendPrivateTransaction`: the relay keeps trying to include the transaction until
+//!   `maxBlockNumber`.
+//! - `eth_sendBundle`: the transaction is sent as a single-transaction bundle that is only valid
+//!   for the next block, so it has to be re-sent until it is included.
+//!
+//! When an auth key is configured, each request carries an `X-Flashbots-Signature` header
+//! containing the EIP-191 signature of the keccak256 hash of the request body.
+use std::time::Duration;
+
+use alloy::{
+    primitives::keccak256,
+    signers::{local::PrivateKeySigner, SignerSync},
+};
+use log::debug;
+use reqwest::Client;
+use serde_json::{json, Value};
+
+use crate::{
+    config::{PrivateRelayConfig, PrivateRelayMethod},
+    constants::{PRIVATE_RELAY_TIMEOUT_SECONDS, PRIVATE_TX_MAX_BLOCKS},
+    services::ProviderError,
+};
+
+pub const FLASHBOTS_SIGNATURE_HEADER: &str = "X-Flashbots-Signature";
+
+#[derive(Debug, Clone)]
+pub struct PrivateRelayService {
+    client: Client,
+    url: String,
+    method: PrivateRelayMethod,
+    auth_signer: Option<PrivateKeySigner>,
+}
+
+impl PrivateRelayService {
+    /// Creates a new private relay client from the network configuration.
+    ///
+    /// The auth key, if configured, is read from the environment variable named in the config.
+    pub fn new(config: &PrivateRelayConfig) -> Result<Self, ProviderError> {
+        let auth_signer = match &config.auth_key_env {
+            Some(env_name) => {
+                let key = std::env::var(env_name).map_err(|_| {
+                    ProviderError::NetworkConfiguration(format!(
+                        "Environment variable {} not found",
+                        env_name
+                    ))
+                })?;
+                let signer = key.trim().parse::<PrivateKeySigner>().map_err(|e| {
+                    ProviderError::NetworkConfiguration(format!(
+                        "Invalid private relay auth key: {}",
+                        e
+                    ))
+                })?;
+                Some(signer)
+            }
+            None => None,
+        };
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(PRIVATE_RELAY_TIMEOUT_SECONDS))
+            .build()
+            .map_err(|e| {
+                ProviderError::NetworkConfiguration(format!("Failed to build HTTP client: {}", e))
+            })?;
+
+        Ok(Self {
+            client,
+            url: config.url.clone(),
+            method: config.method,
+            auth_signer,
+        })
+    }
+
+    pub fn method(&self) -> PrivateRelayMethod {
+        self.method
+    }
+
+    /// Builds the JSON-RPC request body for a signed raw transaction.
+    ///
+    /// `current_block` determines the block range in which the relay may include the transaction.
+    fn build_request(&self, raw_tx: &[u8], current_block: u64) -> Value {
+        let raw_tx = format!("0x{}", hex::encode(raw_tx));
+        let (method, params) = match self.method {
+            PrivateRelayMethod::SendPrivateTransaction => (
+                "eth_sendPrivateTransaction",
+                json!([{
+                    "tx": raw_tx,
+                    "maxBlockNumber": format!("0x{:x}", current_block + PRIVATE_TX_MAX_BLOCKS),
+                }]),
+            ),
+            PrivateRelayMethod::SendBundle => (
+                "eth_sendBundle",
+                json!([{
+                    "txs": [raw_tx],
+                    "blockNumber": format!("0x{:x}", current_block + 1),
+                }]),
+            ),
+        };
+
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        })
+    }
+
+    /// Signs the request body for the `X-Flashbots-Signature` header.
+    fn sign_body(signer: &PrivateKeySigner, body: &str) -> Result<String, ProviderError> {
+        let body_hash = format!("0x{}", hex::encode(keccak256(body.as_bytes())));
+        let signature = signer
+            .sign_message_sync(body_hash.as_bytes())
+            .map_err(|e| ProviderError::Other(format!("Failed to sign relay request: {}", e)))?;
+
+        Ok(format!(
+            "{}:0x{}",
+            signer.address(),
+            hex::encode(signature.as_bytes())
+        ))
+    }
+
+    /// Submits a signed raw transaction to the private relay.
+    ///
+    /// # Arguments
+    /// * `raw_tx` - The signed, RLP-encoded transaction
+    /// * `current_block` - The latest block number, used to compute the inclusion range
+    pub async fn send_raw_transaction(
+        &self,
+        raw_tx: &[u8],
+        current_block: u64,
+    ) -> Result<Value, ProviderError> {
+        let body = self.build_request(raw_tx, current_block).to_string();
+
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", "application/json");
+        if let Some(signer) = &self.auth_signer {
+            request = request.header(FLASHBOTS_SIGNATURE_HEADER, Self::sign_body(signer, &body)?);
+        }
+
+        let response = request.body(body).send().await.map_err(|e| {
+            if e.is_timeout() {
+                ProviderError::Timeout
+            } else {
+                ProviderError::Other(format!("Private relay request failed: {}", e))
+            }
+        })?;
+
+        let status = response.status();
+        let response_body = response
+            .text()
+            .await
+            .map_err(|e| ProviderError::Other(format!("Failed to read relay response: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(ProviderError::RequestError {
+                error: response_body,
+                status_code: status.as_u16(),
+            });
+        }
+
+        let response_json: Value = serde_json::from_str(&response_body)
+            .map_err(|e| ProviderError::Other(format!("Invalid private relay response: {}", e)))?;
+
+        if let Some(error) = response_json.get("error") {
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| error.to_string());
+            return Err(ProviderError::Other(format!(
+                "Private relay rejected transaction: {}",
+                message
+            )));
+        }
+
+        debug!("Private relay response: {}", response_json);
+
+        Ok(response_json.get("result").cloned().unwrap_or(Value::Null))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{Address, Signature};
+    use mockito::Matcher;
+
+    const TEST_AUTH_KEY: &str =
+        "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    fn relay_config(url: String, method: PrivateRelayMethod) -> PrivateRelayConfig {
+        PrivateRelayConfig {
+            url,
+            method,
+            auth_key_env: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_private_transaction() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "eth_sendPrivateTransaction",
+                "params": [{ "tx": "0x010203", "maxBlockNumber": "0x7d" }]
+            })))
+            .match_header(FLASHBOTS_SIGNATURE_HEADER, Matcher::Missing)
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":"0xabc"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let service = PrivateRelayService::new(&relay_config(
+            server.url(),
+            PrivateRelayMethod::SendPrivateTransaction,
+        ))
+        .unwrap();
+
+        let result = service.send_raw_transaction(&[1, 2, 3], 100).await.unwrap();
+
+        assert_eq!(result, json!("0xabc"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_bundle_targets_next_block() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_body(Matcher::PartialJson(json!({
+                "method": "eth_sendBundle",
+                "params": [{ "txs": ["0x010203"], "blockNumber": "0x65" }]
+            })))
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":{"bundleHash":"0xdef"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let service =
+            PrivateRelayService::new(&relay_config(server.url(), PrivateRelayMethod::SendBundle))
+                .unwrap();
+
+        let result = service.send_raw_transaction(&[1, 2, 3], 100).await.unwrap();
+
+        assert_eq!(result["bundleHash"], "0xdef");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_with_auth_signature() {
+        let env_name = "TEST_PRIVATE_RELAY_AUTH_KEY";
+        std::env::set_var(env_name, TEST_AUTH_KEY);
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_header(FLASHBOTS_SIGNATURE_HEADER, Matcher::Any)
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":"0xabc"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut config = relay_config(server.url(), PrivateRelayMethod::SendPrivateTransaction);
+        config.auth_key_env = Some(env_name.to_string());
+        let service = PrivateRelayService::new(&config).unwrap();
+
+        service.send_raw_transaction(&[1, 2, 3], 100).await.unwrap();
+        mock.assert_async().await;
+        std::env::remove_var(env_name);
+    }
+
+    #[test]
+    fn test_sign_body_recovers_auth_address() {
+        let signer = TEST_AUTH_KEY.parse::<PrivateKeySigner>().unwrap();
+        let body = r#"{"jsonrpc":"2.0","id":1,"method":"eth_sendBundle","params":[]}"#;
+
+        let header = PrivateRelayService::sign_body(&signer, body).unwrap();
+        let (address, signature) = header.split_once(':').unwrap();
+
+        let signature =
+            Signature::from_raw(&hex::decode(signature.trim_start_matches("0x")).unwrap()).unwrap();
+        let body_hash = format!("0x{}", hex::encode(keccak256(body.as_bytes())));
+        let recovered = signature
+            .recover_address_from_msg(body_hash.as_bytes())
+            .unwrap();
+
+        assert_eq!(address.parse::<Address>().unwrap(), signer.address());
+        assert_eq!(recovered, signer.address());
+    }
+
+    #[test]
+    fn test_new_with_missing_auth_key_env() {
+        let mut config = relay_config(
+            "http://localhost".to_string(),
+            PrivateRelayMethod::SendPrivateTransaction,
+        );
+        config.auth_key_env = Some("TEST_PRIVATE_RELAY_MISSING_KEY".to_string());
+
+        let result = PrivateRelayService::new(&config);
+        assert!(matches!(
+            result,
+            Err(ProviderError::NetworkConfiguration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_rejected_by_relay() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(
+                r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32000,"message":"nonce too low"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let service = PrivateRelayService::new(&relay_config(
+            server.url(),
+            PrivateRelayMethod::SendPrivateTransaction,
+        ))
+        .unwrap();
+
+        let result = service.send_raw_transaction(&[1, 2, 3], 100).await;
+        assert!(matches!(result, Err(ProviderError::Other(msg)) if msg.contains("nonce too low")));
+    }
+
+    #[tokio::test]
+    async fn test_send_http_error() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_status(403)
+            .with_body("forbidden")
+            .create_async()
+            .await;
+
+        let service = PrivateRelayService::new(&relay_config(
+            server.url(),
+            PrivateRelayMethod::SendPrivateTransaction,
+        ))
+        .unwrap();
+
+        let result = service.send_raw_transaction(&[1, 2, 3], 100).await;
+        assert!(matches!(
+            result,
+            Err(ProviderError::RequestError {
+                status_code: 403,
+                ..
+            })
+        ));
+    }
+}
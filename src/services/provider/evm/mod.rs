@@ -15,7 +15,9 @@ use alloy::{
     },
     rpc::{
         client::ClientBuilder,
-        types::{BlockNumberOrTag, FeeHistory, TransactionInput, TransactionRequest},
+        types::{
+            AccessListResult, BlockNumberOrTag, FeeHistory, TransactionInput, TransactionRequest,
+        },
     },
     transports::http::Http,
 };
@@ -104,6 +106,13 @@ pub trait EvmProviderTrait: Send + Sync {
     /// * `address` - The address to query the transaction count for
     async fn get_transaction_count(&self, address: &str) -> Result<u64, ProviderError>;
 
+    /// Gets the transaction count (nonce) for an address including transactions that are
+    /// still in the node's mempool.
+    ///
+    /// # Arguments
+    /// * `address` - The address to query the pending transaction count for
+    async fn get_pending_transaction_count(&self, address: &str) -> Result<u64, ProviderError>;
+
     /// Gets the fee history for a range of blocks.
     ///
     /// # Arguments
@@ -133,7 +142,23 @@ pub trait EvmProviderTrait: Send + Sync {
     ///
     /// # Arguments
     /// * `tx` - The transaction request to call the contract function
-    async fn call_contract(&self, tx: &TransactionRequest) -> Result<Bytes, ProviderError>;
+    /// * `block` - The block to execute the call against
+    async fn call_contract(
+        &self,
+        tx: &TransactionRequest,
+        block: BlockNumberOrTag,
+    ) -> Result<Bytes, ProviderError>;
+
+    /// Generates an EIP-2930 access list for a transaction with `eth_createAccessList`.
+    ///
+    /// # Arguments
+    /// * `tx` - The transaction request to generate the access list for
+    /// * `block` - The block to execute the transaction against
+    async fn create_access_list(
+        &self,
+        tx: &TransactionRequest,
+        block: BlockNumberOrTag,
+    ) -> Result<AccessListResult, ProviderError>;
 
     /// Sends a raw JSON-RPC request.
     ///
@@ -207,6 +232,9 @@ impl EvmProvider {
             // Only retry these specific error types
             ProviderError::Timeout | ProviderError::RateLimited | ProviderError::BadGateway => true,
 
+            // A revert is deterministic, retrying or switching providers won't help
+            ProviderError::Reverted { .. } => false,
+
             // Any other errors are not automatically retriable
             _ => {
                 // Optionally inspect error message for network-related issues
@@ -388,6 +416,24 @@ impl EvmProviderTrait for EvmProvider {
         .await
     }
 
+    async fn get_pending_transaction_count(&self, address: &str) -> Result<u64, ProviderError> {
+        let parsed_address = address
+            .parse::<alloy::primitives::Address>()
+            .map_err(|e| ProviderError::InvalidAddress(e.to_string()))?;
+
+        self.retry_rpc_call(
+            "get_pending_transaction_count",
+            move |provider| async move {
+                provider
+                    .get_transaction_count(parsed_address)
+                    .pending()
+                    .await
+                    .map_err(ProviderError::from)
+            },
+        )
+        .await
+    }
+
     async fn get_fee_history(
         &self,
         block_count: u64,
@@ -439,12 +485,35 @@ impl EvmProviderTrait for EvmProvider {
         .await
     }
 
-    async fn call_contract(&self, tx: &TransactionRequest) -> Result<Bytes, ProviderError> {
+    async fn call_contract(
+        &self,
+        tx: &TransactionRequest,
+        block: BlockNumberOrTag,
+    ) -> Result<Bytes, ProviderError> {
         self.retry_rpc_call("call_contract", move |provider| {
             let tx_req = tx.clone();
             async move {
                 provider
                     .call(tx_req.into())
+                    .block(block.into())
+                    .await
+                    .map_err(ProviderError::from)
+            }
+        })
+        .await
+    }
+
+    async fn create_access_list(
+        &self,
+        tx: &TransactionRequest,
+        block: BlockNumberOrTag,
+    ) -> Result<AccessListResult, ProviderError> {
+        self.retry_rpc_call("create_access_list", move |provider| {
+            let tx_req = tx.clone().into();
+            async move {
+                provider
+                    .create_access_list(&tx_req)
+                    .block_id(block.into())
                     .await
                     .map_err(ProviderError::from)
             }
@@ -487,15 +556,12 @@ impl TryFrom<&EvmTransactionData> for TransactionRequest {
             from: Some(tx.from.clone().parse().map_err(|_| {
                 TransactionError::InvalidType("Invalid address format".to_string())
             })?),
-            to: Some(TxKind::Call(
-                tx.to
-                    .clone()
-                    .unwrap_or("".to_string())
-                    .parse()
-                    .map_err(|_| {
-                        TransactionError::InvalidType("Invalid address format".to_string())
-                    })?,
-            )),
+            to: Some(match &tx.to {
+                Some(to) => TxKind::Call(to.parse().map_err(|_| {
+                    TransactionError::InvalidType("Invalid address format".to_string())
+                })?),
+                None => TxKind::Create,
+            }),
             gas_price: tx
                 .gas_price
                 .map(|gp| {
@@ -533,6 +599,20 @@ impl TryFrom<&EvmTransactionData> for TransactionRequest {
                     })
                 })
                 .transpose()?,
+            authorization_list: tx
+                .authorization_list
+                .as_ref()
+                .map(|list| {
+                    list.iter()
+                        .map(|authorization| authorization.to_signed_authorization())
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?,
+            access_list: tx
+                .access_list
+                .as_ref()
+                .map(|_| tx.to_access_list())
+                .transpose()?,
             ..Default::default()
         })
     }
@@ -681,6 +761,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         let result = TransactionRequest::try_from(&tx_data);
@@ -1019,6 +1109,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         mock.expect_estimate_gas()
@@ -1059,6 +1159,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         let result = TransactionRequest::try_from(&tx_data);
@@ -1143,9 +1253,9 @@ mod tests {
 
         // Setup mock for call_contract
         mock.expect_call_contract()
-            .with(mockall::predicate::always())
+            .with(mockall::predicate::always(), mockall::predicate::always())
             .times(1)
-            .returning(|_| {
+            .returning(|_, _| {
                 async {
                     Ok(Bytes::from(
                         hex::decode(
@@ -1157,7 +1267,7 @@ mod tests {
                 .boxed()
             });
 
-        let result = mock.call_contract(&tx).await;
+        let result = mock.call_contract(&tx, BlockNumberOrTag::Latest).await;
         assert!(result.is_ok());
 
         let data = result.unwrap();
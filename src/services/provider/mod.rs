@@ -5,7 +5,7 @@ use crate::models::{EvmNetwork, RpcConfig, SolanaNetwork, StellarNetwork};
 use serde::Serialize;
 use thiserror::Error;
 
-use alloy::transports::RpcError;
+use alloy::{primitives::Bytes, transports::RpcError};
 
 pub mod evm;
 pub use evm::*;
@@ -37,6 +37,11 @@ pub enum ProviderError {
     BadGateway,
     #[error("Request error (HTTP {status_code}): {error}")]
     RequestError { error: String, status_code: u16 },
+    #[error("Execution reverted: {message}")]
+    Reverted {
+        message: String,
+        data: Option<Bytes>,
+    },
     #[error("Other provider error: {0}")]
     Other(String),
 }
@@ -145,6 +150,12 @@ where
                 // Fallback for other transport error types
                 ProviderError::Other(format!("Transport error: {}", transport_err))
             }
+            RpcError::ErrorResp(json_rpc_err) if json_rpc_err.message.contains("revert") => {
+                ProviderError::Reverted {
+                    data: json_rpc_err.as_revert_data(),
+                    message: json_rpc_err.message.to_string(),
+                }
+            }
             RpcError::ErrorResp(json_rpc_err) => ProviderError::Other(format!(
                 "JSON-RPC error ({}): {}",
                 json_rpc_err.code, json_rpc_err.message
@@ -309,6 +320,8 @@ mod tests {
             features: vec![],
             symbol: "ETH".to_string(),
             gas_price_cache: None,
+            private_relay: None,
+            gas_oracle: None,
         }
     }
 
@@ -449,6 +462,40 @@ mod tests {
         assert!(matches!(provider_error, ProviderError::Other(_)));
     }
 
+    #[test]
+    fn test_from_rpc_error_revert_keeps_revert_data() {
+        use alloy::transports::TransportErrorKind;
+
+        let payload = serde_json::from_str(
+            r#"{"code": 3, "message": "execution reverted", "data": "0xdeadbeef"}"#,
+        )
+        .unwrap();
+        let rpc_error: RpcError<TransportErrorKind> = RpcError::ErrorResp(payload);
+        let provider_error = ProviderError::from(rpc_error);
+
+        match provider_error {
+            ProviderError::Reverted { message, data } => {
+                assert_eq!(message, "execution reverted");
+                assert_eq!(data, Some(Bytes::from(vec![0xde, 0xad, 0xbe, 0xef])));
+            }
+            other => panic!("Expected Reverted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_rpc_error_non_revert_is_other() {
+        use alloy::transports::TransportErrorKind;
+
+        let payload =
+            serde_json::from_str(r#"{"code": -32000, "message": "nonce too low"}"#).unwrap();
+        let rpc_error: RpcError<TransportErrorKind> = RpcError::ErrorResp(payload);
+
+        assert!(matches!(
+            ProviderError::from(rpc_error),
+            ProviderError::Other(_)
+        ));
+    }
+
     #[test]
     fn test_from_eyre_report_other_error() {
         let eyre_error: eyre::Report = eyre::eyre!("Generic error");
@@ -1,11 +1,15 @@
-//! Stellar Provider implementation for interacting with Stellar blockchain networks.
+//! Stellar Provider implementation for interacting with Stellar networks.
 //!
 //! This module provides functionality to interact with Stellar networks through RPC calls.
 //! It implements common operations like getting accounts, sending transactions, and querying
-//! blockchain state and events.
+//! blockchain state and events. Calls are retried with exponential backoff and fail over to
+//! other configured RPC endpoints.
+
+use std::time::Duration;
 
 use async_trait::async_trait;
 use eyre::{eyre, Result};
+use serde_json::{json, Value};
 use soroban_rs::stellar_rpc_client::Client;
 use soroban_rs::stellar_rpc_client::{
     EventStart, EventType, GetEventsResponse, GetLatestLedgerResponse, GetLedgerEntriesResponse,
@@ -21,7 +25,8 @@ use soroban_rs::SorobanTransactionResponse;
 use mockall::automock;
 
 use crate::models::RpcConfig;
-use crate::services::provider::ProviderError;
+use crate::services::provider::rpc_selector::RpcSelector;
+use crate::services::provider::{retry_rpc_call, ProviderError, RetryConfig};
 
 #[derive(Debug, Clone)]
 pub struct GetEventsRequest {
@@ -34,7 +39,13 @@ pub struct GetEventsRequest {
 
 #[derive(Clone, Debug)]
 pub struct StellarProvider {
-    client: Client,
+    /// RPC selector for managing and selecting endpoints
+    selector: RpcSelector,
+    /// HTTP client used for raw JSON-RPC requests
+    http_client: reqwest::Client,
+    /// Configuration for retry behavior
+    retry_config: RetryConfig,
+    /// URL of the highest-weight endpoint
     rpc_url: String,
 }
 
@@ -61,11 +72,15 @@ pub trait StellarProviderTrait: Send + Sync {
     ) -> Result<GetTransactionsResponse>;
     async fn get_ledger_entries(&self, keys: &[LedgerKey]) -> Result<GetLedgerEntriesResponse>;
     async fn get_events(&self, request: GetEventsRequest) -> Result<GetEventsResponse>;
+    /// Sends a raw JSON-RPC request and returns the `result` field of the response.
+    ///
+    /// A `Null` value for `params` omits the field from the request.
+    async fn raw_request_dyn(&self, method: &str, params: Value) -> Result<Value, ProviderError>;
     fn rpc_url(&self) -> &str;
 }
 
 impl StellarProvider {
-    pub fn new(mut rpc_configs: Vec<RpcConfig>, _timeout: u64) -> Result<Self, ProviderError> {
+    pub fn new(mut rpc_configs: Vec<RpcConfig>, timeout: u64) -> Result<Self, ProviderError> {
         if rpc_configs.is_empty() {
             return Err(ProviderError::NetworkConfiguration(
                 "No RPC configurations provided for StellarProvider".to_string(),
@@ -85,24 +100,129 @@ impl StellarProvider {
 
         rpc_configs.sort_by_key(|config| std::cmp::Reverse(config.get_weight()));
 
-        let selected_config = &rpc_configs[0];
-        let url = &selected_config.url;
+        let rpc_url = rpc_configs[0].url.clone();
 
-        let client = Client::new(url).map_err(|e| {
-            ProviderError::NetworkConfiguration(format!(
-                "Failed to create Stellar RPC client: {} - URL: '{}'",
-                e, url
-            ))
+        // Make sure every endpoint can be used before accepting the configuration
+        for config in &rpc_configs {
+            Self::initialize_provider(&config.url)?;
+        }
+
+        let mut http_client = reqwest::Client::builder();
+        if timeout > 0 {
+            http_client = http_client.timeout(Duration::from_secs(timeout));
+        }
+        let http_client = http_client
+            .build()
+            .map_err(|e| ProviderError::Other(format!("Failed to build HTTP client: {}", e)))?;
+
+        let selector = RpcSelector::new(rpc_configs).map_err(|e| {
+            ProviderError::NetworkConfiguration(format!("Failed to create RPC selector: {}", e))
         })?;
+
         Ok(Self {
-            client,
-            rpc_url: url.clone(),
+            selector,
+            http_client,
+            retry_config: RetryConfig::from_env(),
+            rpc_url,
         })
     }
 
     pub fn rpc_url(&self) -> &str {
         &self.rpc_url
     }
+
+    /// Initialize a Stellar RPC client for a given URL
+    fn initialize_provider(url: &str) -> Result<Client, ProviderError> {
+        Client::new(url).map_err(|e| {
+            ProviderError::NetworkConfiguration(format!(
+                "Failed to create Stellar RPC client: {} - URL: '{}'",
+                e, url
+            ))
+        })
+    }
+
+    fn should_mark_provider_failed(error: &ProviderError) -> bool {
+        match error {
+            ProviderError::RequestError { status_code, .. } => {
+                matches!(*status_code, 500..=599 | 401 | 403 | 404 | 410)
+            }
+            _ => false,
+        }
+    }
+
+    // Errors that are retriable
+    fn is_retriable_error(error: &ProviderError) -> bool {
+        match error {
+            ProviderError::Timeout | ProviderError::RateLimited | ProviderError::BadGateway => true,
+            _ => {
+                let err_msg = error.to_string().to_lowercase();
+                err_msg.contains("timeout")
+                    || err_msg.contains("connection")
+                    || err_msg.contains("reset")
+            }
+        }
+    }
+
+    /// Helper method to retry SDK client calls with exponential backoff and endpoint failover
+    async fn retry_rpc_call<T, F, Fut>(&self, operation_name: &str, operation: F) -> Result<T>
+    where
+        F: Fn(Client) -> Fut,
+        Fut: std::future::Future<Output = Result<T, soroban_rs::stellar_rpc_client::Error>>,
+    {
+        retry_rpc_call(
+            &self.selector,
+            operation_name,
+            Self::is_retriable_error,
+            Self::should_mark_provider_failed,
+            Self::initialize_provider,
+            |client| {
+                let fut = operation(client);
+                async move { fut.await.map_err(|e| ProviderError::Other(e.to_string())) }
+            },
+            Some(self.retry_config.clone()),
+        )
+        .await
+        .map_err(|e| eyre!(e))
+    }
+
+    /// Sends a single JSON-RPC request to `url`.
+    async fn send_raw_request(
+        client: &reqwest::Client,
+        url: &str,
+        method: &str,
+        params: &Value,
+    ) -> Result<Value, ProviderError> {
+        let mut body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+        });
+        if !params.is_null() {
+            body["params"] = params.clone();
+        }
+
+        let response = client
+            .post(url)
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?;
+        let mut response: Value = response.json().await?;
+
+        if let Some(error) = response.get("error") {
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| error.to_string());
+            return Err(ProviderError::Other(format!("RPC error: {}", message)));
+        }
+
+        Ok(response
+            .get_mut("result")
+            .map(Value::take)
+            .unwrap_or(Value::Null))
+    }
 }
 
 impl AsRef<StellarProvider> for StellarProvider {
@@ -114,89 +234,122 @@ impl AsRef<StellarProvider> for StellarProvider {
 #[async_trait]
 impl StellarProviderTrait for StellarProvider {
     async fn get_account(&self, account_id: &str) -> Result<AccountEntry> {
-        self.client
-            .get_account(account_id)
-            .await
-            .map_err(|e| eyre!("Failed to get account: {}", e))
+        self.retry_rpc_call("get_account", |client| async move {
+            client.get_account(account_id).await
+        })
+        .await
+        .map_err(|e| eyre!("Failed to get account: {}", e))
     }
 
     async fn simulate_transaction_envelope(
         &self,
         tx_envelope: &TransactionEnvelope,
     ) -> Result<SimulateTransactionResponse> {
-        self.client
-            .simulate_transaction_envelope(tx_envelope)
-            .await
-            .map_err(|e| eyre!("Failed to simulate transaction: {}", e))
+        self.retry_rpc_call("simulate_transaction_envelope", |client| async move {
+            client.simulate_transaction_envelope(tx_envelope).await
+        })
+        .await
+        .map_err(|e| eyre!("Failed to simulate transaction: {}", e))
     }
 
     async fn send_transaction_polling(
         &self,
         tx_envelope: &TransactionEnvelope,
     ) -> Result<SorobanTransactionResponse> {
-        self.client
-            .send_transaction_polling(tx_envelope)
-            .await
-            .map(SorobanTransactionResponse::from)
-            .map_err(|e| eyre!("Failed to send transaction (polling): {}", e))
+        self.retry_rpc_call("send_transaction_polling", |client| async move {
+            client.send_transaction_polling(tx_envelope).await
+        })
+        .await
+        .map(SorobanTransactionResponse::from)
+        .map_err(|e| eyre!("Failed to send transaction (polling): {}", e))
     }
 
     async fn get_network(&self) -> Result<GetNetworkResponse> {
-        self.client
-            .get_network()
-            .await
-            .map_err(|e| eyre!("Failed to get network: {}", e))
+        self.retry_rpc_call(
+            "get_network",
+            |client| async move { client.get_network().await },
+        )
+        .await
+        .map_err(|e| eyre!("Failed to get network: {}", e))
     }
 
     async fn get_latest_ledger(&self) -> Result<GetLatestLedgerResponse> {
-        self.client
-            .get_latest_ledger()
-            .await
-            .map_err(|e| eyre!("Failed to get latest ledger: {}", e))
+        self.retry_rpc_call("get_latest_ledger", |client| async move {
+            client.get_latest_ledger().await
+        })
+        .await
+        .map_err(|e| eyre!("Failed to get latest ledger: {}", e))
     }
 
     async fn send_transaction(&self, tx_envelope: &TransactionEnvelope) -> Result<Hash> {
-        self.client
-            .send_transaction(tx_envelope)
-            .await
-            .map_err(|e| eyre!("Failed to send transaction: {}", e))
+        self.retry_rpc_call("send_transaction", |client| async move {
+            client.send_transaction(tx_envelope).await
+        })
+        .await
+        .map_err(|e| eyre!("Failed to send transaction: {}", e))
     }
 
     async fn get_transaction(&self, tx_id: &Hash) -> Result<GetTransactionResponse> {
-        self.client
-            .get_transaction(tx_id)
-            .await
-            .map_err(|e| eyre!("Failed to get transaction: {}", e))
+        self.retry_rpc_call("get_transaction", |client| async move {
+            client.get_transaction(tx_id).await
+        })
+        .await
+        .map_err(|e| eyre!("Failed to get transaction: {}", e))
     }
 
     async fn get_transactions(
         &self,
         request: GetTransactionsRequest,
     ) -> Result<GetTransactionsResponse> {
-        self.client
-            .get_transactions(request)
-            .await
-            .map_err(|e| eyre!("Failed to get transactions: {}", e))
+        self.retry_rpc_call("get_transactions", |client| {
+            let request = request.clone();
+            async move { client.get_transactions(request).await }
+        })
+        .await
+        .map_err(|e| eyre!("Failed to get transactions: {}", e))
     }
 
     async fn get_ledger_entries(&self, keys: &[LedgerKey]) -> Result<GetLedgerEntriesResponse> {
-        self.client
-            .get_ledger_entries(keys)
-            .await
-            .map_err(|e| eyre!("Failed to get ledger entries: {}", e))
+        self.retry_rpc_call("get_ledger_entries", |client| async move {
+            client.get_ledger_entries(keys).await
+        })
+        .await
+        .map_err(|e| eyre!("Failed to get ledger entries: {}", e))
     }
 
     async fn get_events(&self, request: GetEventsRequest) -> Result<GetEventsResponse> {
-        self.client
-            .get_events(
-                request.start,
-                request.event_type,
-                &request.contract_ids,
-                &request.topics,
-                request.limit,
-            )
-            .await
-            .map_err(|e| eyre!("Failed to get events: {}", e))
+        self.retry_rpc_call("get_events", |client| {
+            let request = request.clone();
+            async move {
+                client
+                    .get_events(
+                        request.start,
+                        request.event_type,
+                        &request.contract_ids,
+                        &request.topics,
+                        request.limit,
+                    )
+                    .await
+            }
+        })
+        .await
+        .map_err(|e| eyre!("Failed to get events: {}", e))
+    }
+
+    async fn raw_request_dyn(&self, method: &str, params: Value) -> Result<Value, ProviderError> {
+        retry_rpc_call(
+            &self.selector,
+            method,
+            Self::is_retriable_error,
+            Self::should_mark_provider_failed,
+            |url| Ok((self.http_client.clone(), url.to_string())),
+            |(client, url)| {
+                let params = &params;
+                async move { Self::send_raw_request(&client, &url, method, params).await }
+            },
+            Some(self.retry_config.clone()),
+        )
+        .await
     }
 
     fn rpc_url(&self) -> &str {
@@ -212,6 +365,7 @@ mod tests {
     };
     use eyre::eyre;
     use futures::FutureExt;
+    use lazy_static::lazy_static;
     use mockall::predicate as p;
     use soroban_rs::stellar_rpc_client::{
         EventStart, GetEventsResponse, GetLatestLedgerResponse, GetLedgerEntriesResponse,
@@ -225,6 +379,44 @@ mod tests {
     };
     use soroban_rs::{create_mock_set_options_tx_envelope, SorobanTransactionResponse};
     use std::str::FromStr;
+    use std::sync::Mutex;
+
+    lazy_static! {
+        static ref STELLAR_TEST_ENV_MUTEX: Mutex<()> = Mutex::new(());
+    }
+
+    struct StellarTestEnvGuard {
+        _mutex_guard: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl StellarTestEnvGuard {
+        fn new(mutex_guard: std::sync::MutexGuard<'static, ()>) -> Self {
+            std::env::set_var(
+                "API_KEY",
+                "test_api_key_for_stellar_provider_new_this_is_long_enough_32_chars",
+            );
+            std::env::set_var("REDIS_URL", "redis://test-dummy-url-for-stellar-provider");
+
+            Self {
+                _mutex_guard: mutex_guard,
+            }
+        }
+    }
+
+    impl Drop for StellarTestEnvGuard {
+        fn drop(&mut self) {
+            std::env::remove_var("API_KEY");
+            std::env::remove_var("REDIS_URL");
+        }
+    }
+
+    // Helper function to set up the test environment
+    fn setup_test_env() -> StellarTestEnvGuard {
+        let guard = STELLAR_TEST_ENV_MUTEX
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        StellarTestEnvGuard::new(guard)
+    }
 
     fn dummy_hash() -> Hash {
         Hash([0u8; 32])
@@ -341,6 +533,7 @@ mod tests {
 
     #[test]
     fn test_new_provider() {
+        let _env_guard = setup_test_env();
         let provider =
             StellarProvider::new(vec![RpcConfig::new("http://localhost:8000".to_string())], 0);
         assert!(provider.is_ok());
@@ -357,6 +550,7 @@ mod tests {
 
     #[test]
     fn test_new_provider_selects_highest_weight() {
+        let _env_guard = setup_test_env();
         let configs = vec![
             RpcConfig::with_weight("http://rpc1.example.com".to_string(), 10).unwrap(),
             RpcConfig::with_weight("http://rpc2.example.com".to_string(), 100).unwrap(), // Highest weight
@@ -371,6 +565,7 @@ mod tests {
 
     #[test]
     fn test_new_provider_ignores_weight_zero() {
+        let _env_guard = setup_test_env();
         let configs = vec![
             RpcConfig::with_weight("http://rpc1.example.com".to_string(), 0).unwrap(), // Weight 0
             RpcConfig::with_weight("http://rpc2.example.com".to_string(), 100).unwrap(), // Should be selected
@@ -608,6 +803,112 @@ mod tests {
         assert_send_sync::<StellarProvider>();
     }
 
+    #[tokio::test]
+    async fn test_raw_request_dyn_success() {
+        let _env_guard = setup_test_env();
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "method": "getTransaction",
+                "params": { "hash": "abc" }
+            })))
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":{"status":"NOT_FOUND"}}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let provider = StellarProvider::new(vec![RpcConfig::new(server.url())], 30).unwrap();
+        let result = provider
+            .raw_request_dyn("getTransaction", serde_json::json!({ "hash": "abc" }))
+            .await
+            .unwrap();
+
+        assert_eq!(result["status"], "NOT_FOUND");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_dyn_omits_null_params() {
+        let _env_guard = setup_test_env();
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "getLatestLedger"
+            })))
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":{"sequence":1}}"#)
+            .create_async()
+            .await;
+
+        let provider = StellarProvider::new(vec![RpcConfig::new(server.url())], 30).unwrap();
+        let result = provider
+            .raw_request_dyn("getLatestLedger", serde_json::Value::Null)
+            .await
+            .unwrap();
+
+        assert_eq!(result["sequence"], 1);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_dyn_rpc_error() {
+        let _env_guard = setup_test_env();
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(
+                r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"invalid hash"}}"#,
+            )
+            .create_async()
+            .await;
+
+        let provider = StellarProvider::new(vec![RpcConfig::new(server.url())], 30).unwrap();
+        let result = provider
+            .raw_request_dyn("getTransaction", serde_json::json!({ "hash": "abc" }))
+            .await;
+
+        assert!(matches!(result, Err(ProviderError::Other(msg)) if msg.contains("invalid hash")));
+    }
+
+    #[tokio::test]
+    async fn test_raw_request_dyn_fails_over_to_healthy_endpoint() {
+        let _env_guard = setup_test_env();
+        let mut failing_server = mockito::Server::new_async().await;
+        failing_server
+            .mock("POST", "/")
+            .with_status(502)
+            .create_async()
+            .await;
+        let mut healthy_server = mockito::Server::new_async().await;
+        healthy_server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(r#"{"jsonrpc":"2.0","id":1,"result":{"sequence":42}}"#)
+            .create_async()
+            .await;
+
+        let provider = StellarProvider::new(
+            vec![
+                RpcConfig::new(failing_server.url()),
+                RpcConfig::new(healthy_server.url()),
+            ],
+            30,
+        )
+        .unwrap();
+
+        let result = provider
+            .raw_request_dyn("getLatestLedger", serde_json::Value::Null)
+            .await
+            .unwrap();
+        assert_eq!(result["sequence"], 42);
+    }
+
     #[cfg(test)]
     mod concrete_tests {
         use super::*;
@@ -615,6 +916,7 @@ mod tests {
         const NON_EXISTENT_URL: &str = "http://127.0.0.1:9999";
 
         fn setup_provider() -> StellarProvider {
+            let _env_guard = setup_test_env();
             StellarProvider::new(vec![RpcConfig::new(NON_EXISTENT_URL.to_string())], 0)
                 .expect("Provider creation should succeed even with bad URL")
         }
@@ -1,6 +1,11 @@
 use alloy::{
-    consensus::{SignableTransaction, TxEip1559, TxLegacy},
+    consensus::{
+        transaction::RlpEcdsaEncodableTx, SignableTransaction, TxEip1559, TxEip2930, TxEip7702,
+        TxLegacy,
+    },
+    eips::eip7702::{constants::MAGIC, Authorization, SignedAuthorization},
     primitives::{eip191_hash_message, utils::eip191_message, Signature},
+    rlp::Encodable,
 };
 use async_trait::async_trait;
 
@@ -13,7 +18,10 @@ use crate::{
         Address, EvmTransactionDataSignature, EvmTransactionDataTrait, NetworkTransactionData,
         SignerError,
     },
-    services::{AwsKmsClient, AwsKmsEvmService, AwsKmsService, DataSignerTrait, Signer},
+    services::{
+        eip712_signing_payload, AuthorizationSignerTrait, AwsKmsClient, AwsKmsEvmService,
+        AwsKmsService, DataSignerTrait, Signer,
+    },
 };
 
 pub type DefaultAwsKmsService = AwsKmsService<AwsKmsClient>;
@@ -38,6 +46,58 @@ impl<T: AwsKmsEvmService> AwsKmsSigner<T> {
     }
 }
 
+impl<T: AwsKmsEvmService> AwsKmsSigner<T> {
+    /// Signs an EIP-2718 typed transaction (EIP-2930, EIP-1559 or EIP-7702)
+    async fn sign_typed_transaction<Tx>(
+        &self,
+        unsigned_tx: Tx,
+    ) -> Result<SignTransactionResponse, SignerError>
+    where
+        Tx: SignableTransaction<Signature> + RlpEcdsaEncodableTx + Send,
+    {
+        // Prepare transaction for signing
+        let payload = unsigned_tx.encoded_for_signing();
+
+        // Sign payload
+        let signed_bytes = self.aws_kms_service.sign_payload_evm(&payload).await?;
+
+        // Ensure we have the right signature length
+        if signed_bytes.len() != 65 {
+            return Err(SignerError::SigningError(format!(
+                "Invalid signature length from AWS KMS: expected 65 bytes, got {}",
+                signed_bytes.len()
+            )));
+        }
+
+        // Construct primitive signature
+        let signature = Signature::from_raw(&signed_bytes)
+            .map_err(|e| SignerError::ConversionError(e.to_string()))?;
+
+        // Extract signature array bytes
+        let mut signature_bytes = signature.as_bytes();
+
+        // Construct a signed transaction
+        let signed_tx = unsigned_tx.into_signed(signature);
+
+        // Adjust v value for typed transactions (27/28 -> 0/1)
+        if signature_bytes[64] == 27 {
+            signature_bytes[64] = 0;
+        } else if signature_bytes[64] == 28 {
+            signature_bytes[64] = 1;
+        }
+
+        // RLP encode the signed transaction
+        let mut raw = Vec::with_capacity(signed_tx.eip2718_encoded_length());
+        signed_tx.eip2718_encode(&mut raw);
+
+        Ok(SignTransactionResponse::Evm(SignTransactionResponseEvm {
+            hash: signed_tx.hash().to_string(),
+            signature: EvmTransactionDataSignature::from(&signature_bytes),
+            raw,
+        }))
+    }
+}
+
 #[async_trait]
 impl<T: AwsKmsEvmService> Signer for AwsKmsSigner<T> {
     async fn address(&self) -> Result<Address, SignerError> {
@@ -52,50 +112,15 @@ impl<T: AwsKmsEvmService> Signer for AwsKmsSigner<T> {
     ) -> Result<SignTransactionResponse, SignerError> {
         let evm_data = transaction.get_evm_transaction_data()?;
 
-        if evm_data.is_eip1559() {
-            // Handle EIP-1559 transaction
-            let unsigned_tx = TxEip1559::try_from(transaction)?;
-
-            // Prepare transaction for signing
-            let payload = unsigned_tx.encoded_for_signing();
-
-            // Sign payload
-            let signed_bytes = self.aws_kms_service.sign_payload_evm(&payload).await?;
-
-            // Ensure we have the right signature length
-            if signed_bytes.len() != 65 {
-                return Err(SignerError::SigningError(format!(
-                    "Invalid signature length from AWS KMS: expected 65 bytes, got {}",
-                    signed_bytes.len()
-                )));
-            }
-
-            // Construct primitive signature
-            let signature = Signature::from_raw(&signed_bytes)
-                .map_err(|e| SignerError::ConversionError(e.to_string()))?;
-
-            // Extract signature array bytes
-            let mut signature_bytes = signature.as_bytes();
-
-            // Construct a signed transaction
-            let signed_tx = unsigned_tx.into_signed(signature);
-
-            // Adjust v value for EIP-1559 (27/28 -> 0/1)
-            if signature_bytes[64] == 27 {
-                signature_bytes[64] = 0;
-            } else if signature_bytes[64] == 28 {
-                signature_bytes[64] = 1;
-            }
-
-            // RLP encode the signed transaction
-            let mut raw = Vec::with_capacity(signed_tx.eip2718_encoded_length());
-            signed_tx.eip2718_encode(&mut raw);
-
-            Ok(SignTransactionResponse::Evm(SignTransactionResponseEvm {
-                hash: signed_tx.hash().to_string(),
-                signature: EvmTransactionDataSignature::from(&signature_bytes),
-                raw,
-            }))
+        if evm_data.is_eip7702() {
+            self.sign_typed_transaction(TxEip7702::try_from(&evm_data)?)
+                .await
+        } else if evm_data.is_eip1559() {
+            self.sign_typed_transaction(TxEip1559::try_from(transaction)?)
+                .await
+        } else if evm_data.is_eip2930() {
+            self.sign_typed_transaction(TxEip2930::try_from(&evm_data)?)
+                .await
         } else {
             // Handle legacy transaction
             let unsigned_tx = TxLegacy::try_from(transaction)?;
@@ -164,13 +189,45 @@ impl<T: AwsKmsEvmService> DataSignerTrait for AwsKmsSigner<T> {
 
     async fn sign_typed_data(
         &self,
-        _typed_data: SignTypedDataRequest,
+        request: SignTypedDataRequest,
     ) -> Result<SignDataResponse, SignerError> {
-        // EIP-712 typed data signing requires specific handling
-        // This is a placeholder that you'll need to implement based on your needs
-        Err(SignerError::NotImplemented(
-            "EIP-712 typed data signing not yet implemented for AWS KMS".into(),
-        ))
+        let payload = eip712_signing_payload(&request)?;
+
+        // The payload is keccak256-hashed by the KMS service before signing
+        let signature_bytes = self.aws_kms_service.sign_payload_evm(&payload).await?;
+
+        if signature_bytes.len() != 65 {
+            return Err(SignerError::SigningError(format!(
+                "Invalid signature length from AWS KMS: expected 65 bytes, got {}",
+                signature_bytes.len()
+            )));
+        }
+
+        Ok(SignDataResponse::Evm(SignDataResponseEvm {
+            r: hex::encode(&signature_bytes[0..32]),
+            s: hex::encode(&signature_bytes[32..64]),
+            v: signature_bytes[64],
+            sig: hex::encode(&signature_bytes),
+        }))
+    }
+}
+
+#[async_trait]
+impl<T: AwsKmsEvmService> AuthorizationSignerTrait for AwsKmsSigner<T> {
+    async fn sign_authorization(
+        &self,
+        authorization: Authorization,
+    ) -> Result<SignedAuthorization, SignerError> {
+        // The payload is keccak256-hashed by the KMS service before signing
+        let mut payload = vec![MAGIC];
+        authorization.encode(&mut payload);
+
+        let signature_bytes = self.aws_kms_service.sign_payload_evm(&payload).await?;
+
+        let signature = Signature::from_raw(&signature_bytes)
+            .map_err(|e| SignerError::ConversionError(e.to_string()))?;
+
+        Ok(authorization.into_signed(signature))
     }
 }
 
@@ -207,6 +264,16 @@ mod tests {
             hash: None,
             signature: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             speed: None,
@@ -271,6 +338,32 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_sign_typed_data() {
+        let signer = setup_mock_aws_signer();
+        let request = SignTypedDataRequest {
+            domain_separator: Some(hex::encode([1u8; 32])),
+            hash_struct_message: Some(hex::encode([2u8; 32])),
+            typed_data: None,
+        };
+        let expected_hash = crate::services::eip712_signing_hash(&request).unwrap();
+
+        let result = signer.sign_typed_data(request).await.unwrap();
+
+        match result {
+            SignDataResponse::Evm(sig) => {
+                assert!(sig.v == 27 || sig.v == 28, "Invalid v: {}", sig.v);
+
+                let signature = Signature::from_raw(&hex::decode(&sig.sig).unwrap()).unwrap();
+                let recovered = signature
+                    .recover_address_from_prehash(&expected_hash)
+                    .unwrap();
+                assert_eq!(Address::from(recovered), signer.address().await.unwrap());
+            }
+            _ => panic!("Expected EVM signature"),
+        }
+    }
+
     #[tokio::test]
     async fn test_sign_transaction_with_contract_creation() {
         let signer = setup_mock_aws_signer();
@@ -3,12 +3,17 @@
 //! This module implements [`Signer`] and [`DataSignerTrait`] using Google Cloud KMS
 //! to securely sign Ethereum transactions and arbitrary data without exposing private keys.
 //!
-//! Supports both legacy and EIP-1559 transactions, plus EIP-191 data signing.
-//! EIP-712 typed data signing is not yet implemented.
+//! Supports legacy, EIP-1559 and EIP-7702 transactions, plus EIP-191 data signing,
+//! EIP-712 typed data signing and EIP-7702 authorization signing.
 
 use alloy::{
-    consensus::{SignableTransaction, TxEip1559, TxLegacy},
+    consensus::{
+        transaction::RlpEcdsaEncodableTx, SignableTransaction, TxEip1559, TxEip2930, TxEip7702,
+        TxLegacy,
+    },
+    eips::eip7702::{constants::MAGIC, Authorization, SignedAuthorization},
     primitives::{eip191_hash_message, utils::eip191_message, Signature},
+    rlp::Encodable,
 };
 use async_trait::async_trait;
 
@@ -21,7 +26,10 @@ use crate::{
         Address, EvmTransactionDataSignature, EvmTransactionDataTrait, NetworkTransactionData,
         SignerError,
     },
-    services::{DataSignerTrait, GoogleCloudKmsEvmService, GoogleCloudKmsService, Signer},
+    services::{
+        eip712_signing_payload, AuthorizationSignerTrait, DataSignerTrait,
+        GoogleCloudKmsEvmService, GoogleCloudKmsService, Signer,
+    },
     utils::base64_encode,
 };
 
@@ -33,6 +41,49 @@ impl GoogleCloudKmsSigner {
     pub fn new(gcp_kms_service: GoogleCloudKmsService) -> Self {
         Self { gcp_kms_service }
     }
+
+    /// Signs an EIP-2718 typed transaction (EIP-2930, EIP-1559 or EIP-7702)
+    async fn sign_typed_transaction<Tx>(
+        &self,
+        unsigned_tx: Tx,
+    ) -> Result<SignTransactionResponse, SignerError>
+    where
+        Tx: SignableTransaction<Signature> + RlpEcdsaEncodableTx + Send,
+    {
+        let payload = unsigned_tx.encoded_for_signing();
+        let signed_bytes = self.gcp_kms_service.sign_payload_evm(&payload).await?;
+
+        // Ensure we have the right signature length
+        if signed_bytes.len() != 65 {
+            return Err(SignerError::SigningError(format!(
+                "Invalid signature length from Google Cloud KMS: expected 65 bytes, got {}",
+                signed_bytes.len()
+            )));
+        }
+
+        let signature = Signature::from_raw(&signed_bytes)
+            .map_err(|e| SignerError::ConversionError(e.to_string()))?;
+
+        let mut signature_bytes = signature.as_bytes();
+        let signed_tx = unsigned_tx.into_signed(signature);
+
+        // Adjust v value for typed transactions (27/28 -> 0/1)
+        if signature_bytes[64] == 27 {
+            signature_bytes[64] = 0;
+        } else if signature_bytes[64] == 28 {
+            signature_bytes[64] = 1;
+        }
+
+        // RLP encode the signed transaction
+        let mut raw = Vec::with_capacity(signed_tx.eip2718_encoded_length());
+        signed_tx.eip2718_encode(&mut raw);
+
+        Ok(SignTransactionResponse::Evm(SignTransactionResponseEvm {
+            hash: signed_tx.hash().to_string(),
+            signature: EvmTransactionDataSignature::from(&signature_bytes),
+            raw,
+        }))
+    }
 }
 
 #[async_trait]
@@ -48,43 +99,15 @@ impl Signer for GoogleCloudKmsSigner {
     ) -> Result<SignTransactionResponse, SignerError> {
         let evm_data = transaction.get_evm_transaction_data()?;
 
-        if evm_data.is_eip1559() {
-            // Handle EIP-1559 transaction
-            let unsigned_tx = TxEip1559::try_from(transaction)?;
-
-            let payload = unsigned_tx.encoded_for_signing();
-            let signed_bytes = self.gcp_kms_service.sign_payload_evm(&payload).await?;
-
-            // Ensure we have the right signature length
-            if signed_bytes.len() != 65 {
-                return Err(SignerError::SigningError(format!(
-                    "Invalid signature length from Google Cloud KMS: expected 65 bytes, got {}",
-                    signed_bytes.len()
-                )));
-            }
-
-            let signature = Signature::from_raw(&signed_bytes)
-                .map_err(|e| SignerError::ConversionError(e.to_string()))?;
-
-            let mut signature_bytes = signature.as_bytes();
-            let signed_tx = unsigned_tx.into_signed(signature);
-
-            // Adjust v value for EIP-1559 (27/28 -> 0/1)
-            if signature_bytes[64] == 27 {
-                signature_bytes[64] = 0;
-            } else if signature_bytes[64] == 28 {
-                signature_bytes[64] = 1;
-            }
-
-            // RLP encode the signed transaction
-            let mut raw = Vec::with_capacity(signed_tx.eip2718_encoded_length());
-            signed_tx.eip2718_encode(&mut raw);
-
-            Ok(SignTransactionResponse::Evm(SignTransactionResponseEvm {
-                hash: signed_tx.hash().to_string(),
-                signature: EvmTransactionDataSignature::from(&signature_bytes),
-                raw,
-            }))
+        if evm_data.is_eip7702() {
+            self.sign_typed_transaction(TxEip7702::try_from(&evm_data)?)
+                .await
+        } else if evm_data.is_eip1559() {
+            self.sign_typed_transaction(TxEip1559::try_from(transaction)?)
+                .await
+        } else if evm_data.is_eip2930() {
+            self.sign_typed_transaction(TxEip2930::try_from(&evm_data)?)
+                .await
         } else {
             // Handle legacy transaction
             let unsigned_tx = TxLegacy::try_from(transaction)?;
@@ -151,13 +174,45 @@ impl DataSignerTrait for GoogleCloudKmsSigner {
 
     async fn sign_typed_data(
         &self,
-        _typed_data: SignTypedDataRequest,
+        request: SignTypedDataRequest,
     ) -> Result<SignDataResponse, SignerError> {
-        // EIP-712 typed data signing requires specific handling
-        // This is a placeholder that you'll need to implement based on your needs
-        Err(SignerError::NotImplemented(
-            "EIP-712 typed data signing not yet implemented for Google Cloud KMS".into(),
-        ))
+        let payload = eip712_signing_payload(&request)?;
+
+        // The payload is keccak256-hashed by the KMS service before signing
+        let signature_bytes = self.gcp_kms_service.sign_payload_evm(&payload).await?;
+
+        if signature_bytes.len() != 65 {
+            return Err(SignerError::SigningError(format!(
+                "Invalid signature length from Google Cloud KMS: expected 65 bytes, got {}",
+                signature_bytes.len()
+            )));
+        }
+
+        Ok(SignDataResponse::Evm(SignDataResponseEvm {
+            r: hex::encode(&signature_bytes[0..32]),
+            s: hex::encode(&signature_bytes[32..64]),
+            v: signature_bytes[64],
+            sig: hex::encode(&signature_bytes),
+        }))
+    }
+}
+
+#[async_trait]
+impl AuthorizationSignerTrait for GoogleCloudKmsSigner {
+    async fn sign_authorization(
+        &self,
+        authorization: Authorization,
+    ) -> Result<SignedAuthorization, SignerError> {
+        // The payload is keccak256-hashed by the KMS service before signing
+        let mut payload = vec![MAGIC];
+        authorization.encode(&mut payload);
+
+        let signature_bytes = self.gcp_kms_service.sign_payload_evm(&payload).await?;
+
+        let signature = Signature::from_raw(&signature_bytes)
+            .map_err(|e| SignerError::ConversionError(e.to_string()))?;
+
+        Ok(authorization.into_signed(signature))
     }
 }
 
@@ -331,4 +386,19 @@ mod tests {
             panic!("Expected EVM response");
         }
     }
+
+    #[tokio::test]
+    async fn test_sign_typed_data_invalid_request() {
+        let mock_server = mockito::Server::new_async().await;
+        let signer = setup_mock_gcp_signer(&mock_server).await;
+
+        let request = SignTypedDataRequest {
+            domain_separator: Some("0x1234".to_string()),
+            hash_struct_message: Some("0x5678".to_string()),
+            typed_data: None,
+        };
+
+        let result = signer.sign_typed_data(request).await;
+        assert!(matches!(result, Err(SignerError::ParseError(_))));
+    }
 }
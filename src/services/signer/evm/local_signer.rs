@@ -5,8 +5,10 @@
 //!
 //! ## Features
 //!
-//! - Support for both legacy and EIP-1559 transaction types
+//! - Support for legacy, EIP-1559 and EIP-7702 transaction types
 //! - Message signing with standard Ethereum prefixing
+//! - EIP-712 typed data signing
+//! - EIP-7702 authorization signing
 //! - Implementation of the `DataSignerTrait` for EVM-specific operations
 //!
 //! ## Security Considerations
@@ -14,7 +16,11 @@
 //! This implementation stores private keys in memory and should primarily be used
 //! for development and testing purposes, not production
 use alloy::{
-    consensus::{SignableTransaction, TxEip1559, TxLegacy},
+    consensus::{
+        transaction::RlpEcdsaEncodableTx, SignableTransaction, TxEip1559, TxEip2930, TxEip7702,
+        TxLegacy,
+    },
+    eips::eip7702::{Authorization, SignedAuthorization},
     network::{EthereumWallet, TransactionBuilder, TxSigner},
     rpc::types::Transaction,
     signers::{
@@ -23,7 +29,9 @@ use alloy::{
     },
 };
 
-use alloy::primitives::{address, Address as AlloyAddress, Bytes, FixedBytes, TxKind, U256};
+use alloy::primitives::{
+    address, Address as AlloyAddress, Bytes, FixedBytes, Signature, TxKind, U256,
+};
 
 use async_trait::async_trait;
 
@@ -40,7 +48,7 @@ use crate::{
     services::Signer,
 };
 
-use super::DataSignerTrait;
+use super::{eip712_signing_hash, AuthorizationSignerTrait, DataSignerTrait};
 
 use alloy::rpc::types::TransactionRequest;
 
@@ -68,6 +76,42 @@ impl LocalSigner {
             local_signer_client,
         })
     }
+
+    /// Signs an EIP-2718 typed transaction (EIP-2930, EIP-1559 or EIP-7702)
+    async fn sign_typed_transaction<Tx>(
+        &self,
+        mut unsigned_tx: Tx,
+    ) -> Result<SignTransactionResponse, SignerError>
+    where
+        Tx: SignableTransaction<Signature> + RlpEcdsaEncodableTx + Send,
+    {
+        let signature = self
+            .local_signer_client
+            .sign_transaction(&mut unsigned_tx)
+            .await
+            .map_err(|e| {
+                SignerError::SigningError(format!("Failed to sign typed transaction: {e}"))
+            })?;
+
+        let signed_tx = unsigned_tx.into_signed(signature);
+        let mut signature_bytes = signature.as_bytes();
+
+        // Adjust v value for typed transactions (27/28 -> 0/1)
+        if signature_bytes[64] == 27 {
+            signature_bytes[64] = 0;
+        } else if signature_bytes[64] == 28 {
+            signature_bytes[64] = 1;
+        }
+
+        let mut raw = Vec::with_capacity(signed_tx.eip2718_encoded_length());
+        signed_tx.eip2718_encode(&mut raw);
+
+        Ok(SignTransactionResponse::Evm(SignTransactionResponseEvm {
+            hash: signed_tx.hash().to_string(),
+            signature: EvmTransactionDataSignature::from(&signature_bytes),
+            raw,
+        }))
+    }
 }
 
 impl From<AlloyAddress> for Address {
@@ -88,36 +132,15 @@ impl Signer for LocalSigner {
         transaction: NetworkTransactionData,
     ) -> Result<SignTransactionResponse, SignerError> {
         let evm_data = transaction.get_evm_transaction_data()?;
-        if evm_data.is_eip1559() {
-            // Handle EIP-1559 transaction
-            let mut unsigned_tx = TxEip1559::try_from(transaction)?;
-
-            let signature = self
-                .local_signer_client
-                .sign_transaction(&mut unsigned_tx)
+        if evm_data.is_eip7702() {
+            self.sign_typed_transaction(TxEip7702::try_from(&evm_data)?)
+                .await
+        } else if evm_data.is_eip1559() {
+            self.sign_typed_transaction(TxEip1559::try_from(transaction)?)
+                .await
+        } else if evm_data.is_eip2930() {
+            self.sign_typed_transaction(TxEip2930::try_from(&evm_data)?)
                 .await
-                .map_err(|e| {
-                    SignerError::SigningError(format!("Failed to sign EIP-1559 transaction: {e}"))
-                })?;
-
-            let signed_tx = unsigned_tx.into_signed(signature);
-            let mut signature_bytes = signature.as_bytes();
-
-            // Adjust v value for EIP-1559 (27/28 -> 0/1)
-            if signature_bytes[64] == 27 {
-                signature_bytes[64] = 0;
-            } else if signature_bytes[64] == 28 {
-                signature_bytes[64] = 1;
-            }
-
-            let mut raw = Vec::with_capacity(signed_tx.eip2718_encoded_length());
-            signed_tx.eip2718_encode(&mut raw);
-
-            Ok(SignTransactionResponse::Evm(SignTransactionResponseEvm {
-                hash: signed_tx.hash().to_string(),
-                signature: EvmTransactionDataSignature::from(&signature_bytes),
-                raw,
-            }))
         } else {
             // Handle legacy transaction
             let mut unsigned_tx = TxLegacy::try_from(transaction.clone())?;
@@ -168,9 +191,42 @@ impl DataSignerTrait for LocalSigner {
 
     async fn sign_typed_data(
         &self,
-        _typed_data: SignTypedDataRequest,
+        request: SignTypedDataRequest,
     ) -> Result<SignDataResponse, SignerError> {
-        todo!()
+        let signing_hash = eip712_signing_hash(&request)?;
+
+        let signature = self
+            .local_signer_client
+            .sign_hash(&signing_hash)
+            .await
+            .map_err(|e| SignerError::SigningError(format!("Failed to sign typed data: {}", e)))?;
+
+        let ste = signature.as_bytes();
+
+        Ok(SignDataResponse::Evm(SignDataResponseEvm {
+            r: hex::encode(&ste[0..32]),
+            s: hex::encode(&ste[32..64]),
+            v: ste[64],
+            sig: hex::encode(ste),
+        }))
+    }
+}
+
+#[async_trait]
+impl AuthorizationSignerTrait for LocalSigner {
+    async fn sign_authorization(
+        &self,
+        authorization: Authorization,
+    ) -> Result<SignedAuthorization, SignerError> {
+        let signature = self
+            .local_signer_client
+            .sign_hash(&authorization.signature_hash())
+            .await
+            .map_err(|e| {
+                SignerError::SigningError(format!("Failed to sign authorization: {}", e))
+            })?;
+
+        Ok(authorization.into_signed(signature))
     }
 }
 
@@ -205,6 +261,16 @@ mod tests {
             hash: None,
             signature: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             speed: None,
@@ -268,6 +334,49 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_sign_typed_data() {
+        let signer = LocalSigner::new(&create_test_signer_model()).unwrap();
+        let request = SignTypedDataRequest {
+            domain_separator: Some(hex::encode([1u8; 32])),
+            hash_struct_message: Some(hex::encode([2u8; 32])),
+            typed_data: None,
+        };
+        let expected_hash = eip712_signing_hash(&request).unwrap();
+
+        let result = signer.sign_typed_data(request).await.unwrap();
+
+        match result {
+            SignDataResponse::Evm(sig) => {
+                assert_eq!(sig.r.len(), 64);
+                assert_eq!(sig.s.len(), 64);
+                assert!(sig.v == 27 || sig.v == 28);
+
+                let signature =
+                    alloy::primitives::Signature::from_raw(&hex::decode(&sig.sig).unwrap())
+                        .unwrap();
+                let recovered = signature
+                    .recover_address_from_prehash(&expected_hash)
+                    .unwrap();
+                assert_eq!(Address::from(recovered), signer.address().await.unwrap());
+            }
+            _ => panic!("Expected EVM signature"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_typed_data_missing_fields() {
+        let signer = LocalSigner::new(&create_test_signer_model()).unwrap();
+        let request = SignTypedDataRequest {
+            domain_separator: None,
+            hash_struct_message: None,
+            typed_data: None,
+        };
+
+        let result = signer.sign_typed_data(request).await;
+        assert!(matches!(result, Err(SignerError::ParseError(_))));
+    }
+
     #[tokio::test]
     async fn test_sign_transaction_with_contract_creation() {
         let signer = LocalSigner::new(&create_test_signer_model()).unwrap();
@@ -344,4 +453,78 @@ mod tests {
             _ => panic!("Expected EVM transaction response"),
         }
     }
+
+    #[tokio::test]
+    async fn test_sign_eip7702_transaction() {
+        let signer = LocalSigner::new(&create_test_signer_model()).unwrap();
+        let mut tx = create_test_transaction();
+
+        if let NetworkTransactionData::Evm(ref mut evm_tx) = tx {
+            evm_tx.gas_price = None;
+            evm_tx.max_fee_per_gas = Some(30_000_000_000);
+            evm_tx.max_priority_fee_per_gas = Some(2_000_000_000);
+            evm_tx.authorization_list = Some(vec![crate::models::EvmAuthorization {
+                chain_id: 1,
+                address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44f".to_string(),
+                nonce: 0,
+                y_parity: 0,
+                r: "0x1".to_string(),
+                s: "0x2".to_string(),
+            }]);
+        }
+
+        match signer.sign_transaction(tx).await.unwrap() {
+            SignTransactionResponse::Evm(signed_tx) => {
+                // EIP-2718 envelope of a set-code transaction
+                assert_eq!(signed_tx.raw[0], 0x04);
+                assert!(signed_tx.signature.v == 0 || signed_tx.signature.v == 1);
+            }
+            _ => panic!("Expected EVM transaction response"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_eip2930_transaction() {
+        let signer = LocalSigner::new(&create_test_signer_model()).unwrap();
+        let mut tx = create_test_transaction();
+
+        if let NetworkTransactionData::Evm(ref mut evm_tx) = tx {
+            evm_tx.access_list = Some(vec![crate::models::EvmAccessListItem {
+                address: "0x742d35Cc6634C0532925a3b844Bc454e4438f44f".to_string(),
+                storage_keys: vec![format!("0x{:064x}", 0)],
+            }]);
+        }
+
+        match signer.sign_transaction(tx).await.unwrap() {
+            SignTransactionResponse::Evm(signed_tx) => {
+                // EIP-2718 envelope of an access list transaction
+                assert_eq!(signed_tx.raw[0], 0x01);
+                assert!(signed_tx.signature.v == 0 || signed_tx.signature.v == 1);
+            }
+            _ => panic!("Expected EVM transaction response"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_authorization() {
+        let signer = LocalSigner::new(&create_test_signer_model()).unwrap();
+        let authorization = Authorization {
+            chain_id: U256::from(1),
+            address: address!("742d35Cc6634C0532925a3b844Bc454e4438f44f"),
+            nonce: 7,
+        };
+
+        let signed = signer
+            .sign_authorization(authorization.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(signed.inner(), &authorization);
+        let authority = signed
+            .signature()
+            .unwrap()
+            .recover_address_from_prehash(&authorization.signature_hash())
+            .unwrap();
+        assert_eq!(Address::from(authority), signer.address().await.unwrap());
+    }
 }
@@ -24,6 +24,10 @@ use oz_keystore::HashicorpCloudClient;
 use turnkey_signer::*;
 use vault_signer::*;
 
+use alloy::{
+    eips::eip7702::{Authorization, SignedAuthorization},
+    primitives::{keccak256, B256},
+};
 use async_trait::async_trait;
 use color_eyre::config;
 use std::sync::Arc;
@@ -60,6 +64,71 @@ pub trait DataSignerTrait: Send + Sync {
     ) -> Result<SignDataResponse, SignerError>;
 }
 
+#[async_trait]
+pub trait AuthorizationSignerTrait: Send + Sync {
+    /// Signs an EIP-7702 authorization with the signer's key
+    async fn sign_authorization(
+        &self,
+        authorization: Authorization,
+    ) -> Result<SignedAuthorization, SignerError>;
+}
+
+/// Builds the EIP-712 signing payload `0x1901 ‖ domainSeparator ‖ hashStruct(message)`.
+///
+/// When the request contains the full typed data, the domain separator and struct hash are
+/// computed from it (including nested and array types); otherwise the pre-computed hashes are
+/// used. The digest to sign is the keccak256 hash of the returned payload.
+pub fn eip712_signing_payload(request: &SignTypedDataRequest) -> Result<Vec<u8>, SignerError> {
+    let mut payload = vec![0x19, 0x01];
+
+    if let Some(typed_data) = &request.typed_data {
+        payload.extend_from_slice(typed_data.domain.separator().as_slice());
+        // Signing the domain itself carries no message hash, matching eth_signTypedData_v4
+        if typed_data.primary_type != "EIP712Domain" {
+            let hash_struct = typed_data
+                .hash_struct()
+                .map_err(|e| SignerError::ParseError(format!("Invalid typed data: {}", e)))?;
+            payload.extend_from_slice(hash_struct.as_slice());
+        }
+        return Ok(payload);
+    }
+
+    let (Some(domain_separator), Some(hash_struct_message)) =
+        (&request.domain_separator, &request.hash_struct_message)
+    else {
+        return Err(SignerError::ParseError(
+            "Either typed_data or both domain_separator and hash_struct_message are required"
+                .to_string(),
+        ));
+    };
+
+    payload.extend_from_slice(parse_eip712_hash(domain_separator, "domain_separator")?.as_slice());
+    payload.extend_from_slice(
+        parse_eip712_hash(hash_struct_message, "hash_struct_message")?.as_slice(),
+    );
+    Ok(payload)
+}
+
+/// Computes the EIP-712 digest to be signed for the request.
+pub fn eip712_signing_hash(request: &SignTypedDataRequest) -> Result<B256, SignerError> {
+    eip712_signing_payload(request).map(keccak256)
+}
+
+fn parse_eip712_hash(value: &str, field: &str) -> Result<B256, SignerError> {
+    let bytes = hex::decode(value.trim_start_matches("0x"))
+        .map_err(|e| SignerError::ParseError(format!("Invalid {} hex: {}", field, e)))?;
+
+    if bytes.len() != 32 {
+        return Err(SignerError::ParseError(format!(
+            "Invalid {} length: expected 32 bytes, got {}",
+            field,
+            bytes.len()
+        )));
+    }
+
+    Ok(B256::from_slice(&bytes))
+}
+
 pub enum EvmSigner {
     Local(LocalSigner),
     Vault(VaultSigner<VaultService>),
@@ -120,6 +189,22 @@ impl DataSignerTrait for EvmSigner {
     }
 }
 
+#[async_trait]
+impl AuthorizationSignerTrait for EvmSigner {
+    async fn sign_authorization(
+        &self,
+        authorization: Authorization,
+    ) -> Result<SignedAuthorization, SignerError> {
+        match self {
+            Self::Local(signer) => signer.sign_authorization(authorization).await,
+            Self::Vault(signer) => signer.sign_authorization(authorization).await,
+            Self::Turnkey(signer) => signer.sign_authorization(authorization).await,
+            Self::AwsKms(signer) => signer.sign_authorization(authorization).await,
+            Self::GoogleCloudKms(signer) => signer.sign_authorization(authorization).await,
+        }
+    }
+}
+
 pub struct EvmSignerFactory;
 
 impl EvmSignerFactory {
@@ -437,6 +522,16 @@ mod tests {
             hash: None,
             signature: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             speed: None,
@@ -530,4 +625,192 @@ mod tests {
             }
         }
     }
+
+    // Example from the EIP-712 specification
+    const MAIL_DOMAIN_SEPARATOR: &str =
+        "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f";
+    const MAIL_HASH_STRUCT: &str =
+        "0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e";
+    const MAIL_SIGNING_HASH: &str =
+        "0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2";
+
+    fn mail_typed_data() -> serde_json::Value {
+        serde_json::json!({
+            "types": {
+                "EIP712Domain": [
+                    { "name": "name", "type": "string" },
+                    { "name": "version", "type": "string" },
+                    { "name": "chainId", "type": "uint256" },
+                    { "name": "verifyingContract", "type": "address" }
+                ],
+                "Person": [
+                    { "name": "name", "type": "string" },
+                    { "name": "wallet", "type": "address" }
+                ],
+                "Mail": [
+                    { "name": "from", "type": "Person" },
+                    { "name": "to", "type": "Person" },
+                    { "name": "contents", "type": "string" }
+                ]
+            },
+            "primaryType": "Mail",
+            "domain": {
+                "name": "Ether Mail",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+            },
+            "message": {
+                "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                "contents": "Hello, Bob!"
+            }
+        })
+    }
+
+    fn typed_data_request(typed_data: serde_json::Value) -> SignTypedDataRequest {
+        SignTypedDataRequest {
+            domain_separator: None,
+            hash_struct_message: None,
+            typed_data: Some(serde_json::from_value(typed_data).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_eip712_signing_hash_from_typed_data() {
+        let request = typed_data_request(mail_typed_data());
+
+        let payload = eip712_signing_payload(&request).unwrap();
+        assert_eq!(&payload[..2], &[0x19, 0x01]);
+        assert_eq!(hex::encode(&payload[2..34]), &MAIL_DOMAIN_SEPARATOR[2..]);
+        assert_eq!(hex::encode(&payload[34..]), &MAIL_HASH_STRUCT[2..]);
+
+        let hash = eip712_signing_hash(&request).unwrap();
+        assert_eq!(hash, B256::from_str(MAIL_SIGNING_HASH).unwrap());
+    }
+
+    #[test]
+    fn test_eip712_signing_hash_from_precomputed_hashes() {
+        let request = SignTypedDataRequest {
+            domain_separator: Some(MAIL_DOMAIN_SEPARATOR.to_string()),
+            hash_struct_message: Some(MAIL_HASH_STRUCT[2..].to_string()),
+            typed_data: None,
+        };
+
+        let hash = eip712_signing_hash(&request).unwrap();
+        assert_eq!(hash, B256::from_str(MAIL_SIGNING_HASH).unwrap());
+    }
+
+    #[test]
+    fn test_eip712_signing_hash_with_array_types() {
+        let mut typed_data = mail_typed_data();
+        typed_data["types"]["Group"] = serde_json::json!([
+            { "name": "name", "type": "string" },
+            { "name": "members", "type": "Person[]" },
+            { "name": "scores", "type": "uint256[]" }
+        ]);
+        typed_data["primaryType"] = serde_json::json!("Group");
+        typed_data["message"] = serde_json::json!({
+            "name": "Friends",
+            "members": [
+                { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" }
+            ],
+            "scores": [1, 2, 3]
+        });
+        let request = typed_data_request(typed_data.clone());
+
+        let payload = eip712_signing_payload(&request).unwrap();
+        assert_eq!(payload.len(), 66);
+        assert_eq!(hex::encode(&payload[2..34]), &MAIL_DOMAIN_SEPARATOR[2..]);
+
+        // Reordering array elements must change the struct hash
+        typed_data["message"]["scores"] = serde_json::json!([3, 2, 1]);
+        let reordered = eip712_signing_payload(&typed_data_request(typed_data)).unwrap();
+        assert_ne!(payload, reordered);
+    }
+
+    #[test]
+    fn test_eip712_signing_payload_for_domain_only() {
+        let mut typed_data = mail_typed_data();
+        typed_data["primaryType"] = serde_json::json!("EIP712Domain");
+        typed_data["message"] = serde_json::json!({});
+
+        let payload = eip712_signing_payload(&typed_data_request(typed_data)).unwrap();
+        assert_eq!(payload.len(), 34);
+    }
+
+    #[test]
+    fn test_eip712_signing_payload_invalid_message() {
+        let mut typed_data = mail_typed_data();
+        typed_data["message"]["from"] = serde_json::json!("not a person");
+
+        let result = eip712_signing_payload(&typed_data_request(typed_data));
+        assert!(matches!(result, Err(SignerError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_eip712_signing_payload_missing_fields() {
+        let request = SignTypedDataRequest {
+            domain_separator: Some(MAIL_DOMAIN_SEPARATOR.to_string()),
+            hash_struct_message: None,
+            typed_data: None,
+        };
+
+        let result = eip712_signing_payload(&request);
+        assert!(matches!(result, Err(SignerError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_eip712_signing_payload_invalid_hash() {
+        let request = SignTypedDataRequest {
+            domain_separator: Some("0x1234".to_string()),
+            hash_struct_message: Some(MAIL_HASH_STRUCT.to_string()),
+            typed_data: None,
+        };
+        let result = eip712_signing_payload(&request);
+        assert!(
+            matches!(result, Err(SignerError::ParseError(msg)) if msg.contains("domain_separator"))
+        );
+
+        let request = SignTypedDataRequest {
+            domain_separator: Some(MAIL_DOMAIN_SEPARATOR.to_string()),
+            hash_struct_message: Some("not-hex".to_string()),
+            typed_data: None,
+        };
+        let result = eip712_signing_payload(&request);
+        assert!(
+            matches!(result, Err(SignerError::ParseError(msg)) if msg.contains("hash_struct_message"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sign_typed_data_evm_signer_local() {
+        let signer_model = SignerDomainModel {
+            id: "test".to_string(),
+            config: SignerConfig::Local(LocalSignerConfig {
+                raw_key: test_key_bytes(),
+            }),
+        };
+
+        let signer = EvmSignerFactory::create_evm_signer(signer_model)
+            .await
+            .unwrap();
+        let result = signer
+            .sign_typed_data(typed_data_request(mail_typed_data()))
+            .await
+            .unwrap();
+
+        match result {
+            SignDataResponse::Evm(sig) => {
+                let signature =
+                    alloy::primitives::Signature::from_raw(&hex::decode(sig.sig).unwrap()).unwrap();
+                let recovered = signature
+                    .recover_address_from_prehash(&B256::from_str(MAIL_SIGNING_HASH).unwrap())
+                    .unwrap();
+                assert_eq!(Address::from(recovered), test_key_address());
+            }
+            _ => panic!("Expected EVM signature"),
+        }
+    }
 }
@@ -5,8 +5,10 @@
 //!
 //! ## Features
 //!
-//! - Secure signing of EVM transactions (both legacy and EIP-1559)
+//! - Secure signing of EVM transactions (legacy, EIP-2930, EIP-1559 and EIP-7702)
 //! - Message signing with EIP-191 prefixing
+//! - EIP-712 typed data signing
+//! - EIP-7702 authorization signing
 //! - Remote key management through Turnkey's secure infrastructure
 //!
 //! ## Security Notes
@@ -16,8 +18,12 @@
 use std::str::FromStr;
 
 use alloy::{
-    consensus::{SignableTransaction, TxEip1559, TxLegacy},
-    primitives::{eip191_hash_message, keccak256},
+    consensus::{
+        transaction::RlpEcdsaEncodableTx, SignableTransaction, TxEip1559, TxEip2930, TxEip7702,
+        TxLegacy,
+    },
+    eips::eip7702::{Authorization, SignedAuthorization},
+    primitives::{eip191_hash_message, keccak256, Signature, B256},
 };
 use async_trait::async_trait;
 use log::{debug, info}; // Import FutureExt to enable the `boxed` method
@@ -34,7 +40,7 @@ use crate::{
     services::{Signer, TurnkeyService, TurnkeyServiceTrait},
 };
 
-use super::DataSignerTrait;
+use super::{eip712_signing_hash, AuthorizationSignerTrait, DataSignerTrait};
 
 pub type DefaultTurnkeyService = TurnkeyService;
 
@@ -51,6 +57,53 @@ impl TurnkeySigner<DefaultTurnkeyService> {
     }
 }
 
+impl<T: TurnkeyServiceTrait> TurnkeySigner<T> {
+    /// Signs a 32-byte digest with the Turnkey key
+    async fn sign_hash(&self, hash: B256) -> Result<Signature, SignerError> {
+        let signature_bytes = self.turnkey_service.sign_evm(hash.as_ref()).await?;
+
+        if signature_bytes.len() != 65 {
+            return Err(SignerError::SigningError(format!(
+                "Invalid signature length from Turnkey: expected 65 bytes, got {}",
+                signature_bytes.len()
+            )));
+        }
+
+        Signature::from_raw(&signature_bytes)
+            .map_err(|e| SignerError::ConversionError(e.to_string()))
+    }
+
+    /// Signs a typed transaction by its signature hash and encodes it locally
+    async fn sign_typed_transaction<Tx>(
+        &self,
+        unsigned_tx: Tx,
+    ) -> Result<SignTransactionResponse, SignerError>
+    where
+        Tx: SignableTransaction<Signature> + RlpEcdsaEncodableTx + Send,
+    {
+        let signature = self.sign_hash(unsigned_tx.signature_hash()).await?;
+
+        let mut signature_bytes = signature.as_bytes();
+        let signed_tx = unsigned_tx.into_signed(signature);
+
+        // Adjust v value for typed transactions (27/28 -> 0/1)
+        if signature_bytes[64] == 27 {
+            signature_bytes[64] = 0;
+        } else if signature_bytes[64] == 28 {
+            signature_bytes[64] = 1;
+        }
+
+        let mut raw = Vec::with_capacity(signed_tx.eip2718_encoded_length());
+        signed_tx.eip2718_encode(&mut raw);
+
+        Ok(SignTransactionResponse::Evm(SignTransactionResponseEvm {
+            hash: signed_tx.hash().to_string(),
+            signature: EvmTransactionDataSignature::from(&signature_bytes),
+            raw,
+        }))
+    }
+}
+
 #[cfg(test)]
 impl<T: TurnkeyServiceTrait> TurnkeySigner<T> {
     pub fn new_with_service(turnkey_service: T) -> Self {
@@ -76,6 +129,18 @@ impl<T: TurnkeyServiceTrait> Signer for TurnkeySigner<T> {
     ) -> Result<SignTransactionResponse, SignerError> {
         let evm_data = transaction.get_evm_transaction_data()?;
 
+        // Set-code and access list transactions are signed by digest and assembled locally
+        if evm_data.is_eip7702() {
+            return self
+                .sign_typed_transaction(TxEip7702::try_from(&evm_data)?)
+                .await;
+        }
+        if evm_data.is_eip2930() {
+            return self
+                .sign_typed_transaction(TxEip2930::try_from(&evm_data)?)
+                .await;
+        }
+
         // Prepare data for signing based on transaction type
         let (unsigned_tx_bytes, is_eip1559) = if evm_data.is_eip1559() {
             let tx = TxEip1559::try_from(transaction)?;
@@ -170,13 +235,37 @@ impl<T: TurnkeyServiceTrait> DataSignerTrait for TurnkeySigner<T> {
 
     async fn sign_typed_data(
         &self,
-        _typed_data: SignTypedDataRequest,
+        request: SignTypedDataRequest,
     ) -> Result<SignDataResponse, SignerError> {
-        // EIP-712 typed data signing requires specific handling
-        // This is a placeholder that you'll need to implement based on your needs
-        Err(SignerError::NotImplemented(
-            "EIP-712 typed data signing not yet implemented for Turnkey".into(),
-        ))
+        let signing_hash = eip712_signing_hash(&request)?;
+
+        let signature_bytes = self.turnkey_service.sign_evm(signing_hash.as_ref()).await?;
+
+        if signature_bytes.len() != 65 {
+            return Err(SignerError::SigningError(format!(
+                "Invalid signature length from Turnkey: expected 65 bytes, got {}",
+                signature_bytes.len()
+            )));
+        }
+
+        Ok(SignDataResponse::Evm(SignDataResponseEvm {
+            r: hex::encode(&signature_bytes[0..32]),
+            s: hex::encode(&signature_bytes[32..64]),
+            v: signature_bytes[64],
+            sig: hex::encode(&signature_bytes),
+        }))
+    }
+}
+
+#[async_trait]
+impl<T: TurnkeyServiceTrait> AuthorizationSignerTrait for TurnkeySigner<T> {
+    async fn sign_authorization(
+        &self,
+        authorization: Authorization,
+    ) -> Result<SignedAuthorization, SignerError> {
+        let signature = self.sign_hash(authorization.signature_hash()).await?;
+
+        Ok(authorization.into_signed(signature))
     }
 }
 
@@ -279,6 +368,16 @@ mod tests {
             hash: None,
             signature: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
             max_fee_per_gas: Some(1),
             max_priority_fee_per_gas: Some(1),
             speed: None,
@@ -372,21 +471,58 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_sign_typed_data_not_implemented() {
+    async fn test_sign_typed_data() {
+        let mut mock_service = MockTurnkeyServiceTrait::new();
+
+        let domain_separator = [1u8; 32];
+        let hash_struct = [2u8; 32];
+        let mut payload = vec![0x19, 0x01];
+        payload.extend_from_slice(&domain_separator);
+        payload.extend_from_slice(&hash_struct);
+        let expected_hash = keccak256(&payload);
+
+        mock_service
+            .expect_sign_evm()
+            .withf(move |message| message == expected_hash.as_slice())
+            .times(1)
+            .returning(|_| {
+                let mut signature = vec![3u8; 64];
+                signature.push(27);
+                Box::pin(async { Ok(signature) })
+            });
+
+        let signer = TurnkeySigner::new_for_testing(mock_service);
+        let request = SignTypedDataRequest {
+            domain_separator: Some(hex::encode(domain_separator)),
+            hash_struct_message: Some(format!("0x{}", hex::encode(hash_struct))),
+            typed_data: None,
+        };
+
+        let result = signer.sign_typed_data(request).await.unwrap();
+        match result {
+            SignDataResponse::Evm(sig) => {
+                assert_eq!(sig.r, hex::encode([3u8; 32]));
+                assert_eq!(sig.s, hex::encode([3u8; 32]));
+                assert_eq!(sig.v, 27);
+                assert_eq!(sig.sig.len(), 130);
+            }
+            _ => panic!("Expected EVM signature"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sign_typed_data_invalid_request() {
         let mock_service = MockTurnkeyServiceTrait::new();
         let signer = TurnkeySigner::new_for_testing(mock_service);
 
         let request = SignTypedDataRequest {
-            domain_separator: "test-domain".to_string(),
-            hash_struct_message: "test-struct".to_string(),
+            domain_separator: Some("test-domain".to_string()),
+            hash_struct_message: Some("test-struct".to_string()),
+            typed_data: None,
         };
 
         let result = signer.sign_typed_data(request).await;
-        assert!(result.is_err());
-        match result {
-            Err(SignerError::NotImplemented(_)) => {}
-            _ => panic!("Expected NotImplemented error variant"),
-        }
+        assert!(matches!(result, Err(SignerError::ParseError(_))));
     }
 
     #[tokio::test]
@@ -406,6 +542,16 @@ mod tests {
             hash: None,
             signature: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
             max_fee_per_gas: None,          // Not used in legacy transactions
             max_priority_fee_per_gas: None, // Not used in legacy transactions
             speed: None,
@@ -457,6 +603,16 @@ mod tests {
             hash: None,
             signature: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
             max_fee_per_gas: Some(1),
             max_priority_fee_per_gas: Some(1),
             speed: None,
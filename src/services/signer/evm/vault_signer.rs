@@ -4,6 +4,7 @@
 //! for secure key management. The private key is fetched once during signer creation and cached
 //! in memory for optimal performance.
 
+use alloy::eips::eip7702::{Authorization, SignedAuthorization};
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use secrets::SecretVec;
@@ -23,7 +24,7 @@ use crate::{
         VaultSignerConfig,
     },
     services::{
-        signer::evm::{local_signer::LocalSigner, DataSignerTrait},
+        signer::evm::{local_signer::LocalSigner, AuthorizationSignerTrait, DataSignerTrait},
         vault::{VaultService, VaultServiceTrait},
         Signer,
     },
@@ -235,6 +236,17 @@ impl<T: VaultServiceTrait + Clone> DataSignerTrait for VaultSigner<T> {
     }
 }
 
+#[async_trait]
+impl<T: VaultServiceTrait + Clone> AuthorizationSignerTrait for VaultSigner<T> {
+    async fn sign_authorization(
+        &self,
+        authorization: Authorization,
+    ) -> Result<SignedAuthorization, SignerError> {
+        let signer = self.get_local_signer().await?;
+        signer.sign_authorization(authorization).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
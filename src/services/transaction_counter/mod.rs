@@ -1,7 +1,7 @@
 //! This module provides a service for managing transaction counters.
 //!
 //! The `TransactionCounterService` struct offers methods to get, increment,
-//! decrement, and set transaction counts associated with a specific relayer
+//! reserve, decrement, and set transaction counts associated with a specific relayer
 //! and address. It uses an in-memory store to keep track of these counts.
 use std::sync::Arc;
 
@@ -33,8 +33,14 @@ impl<T> TransactionCounterService<T> {
 pub trait TransactionCounterServiceTrait: Send + Sync {
     async fn get(&self) -> Result<Option<u64>, TransactionCounterError>;
     async fn get_and_increment(&self) -> Result<u64, TransactionCounterError>;
+    async fn get_and_increment_by(&self, count: u64) -> Result<u64, TransactionCounterError>;
     async fn decrement(&self) -> Result<u64, TransactionCounterError>;
     async fn set(&self, value: u64) -> Result<(), TransactionCounterError>;
+    async fn compare_and_set(
+        &self,
+        expected: u64,
+        value: u64,
+    ) -> Result<bool, TransactionCounterError>;
 }
 
 #[async_trait]
@@ -57,6 +63,13 @@ where
             .map_err(|e| TransactionCounterError::NotFound(e.to_string()))
     }
 
+    async fn get_and_increment_by(&self, count: u64) -> Result<u64, TransactionCounterError> {
+        self.store
+            .get_and_increment_by(&self.relayer_id, &self.address, count)
+            .await
+            .map_err(|e| TransactionCounterError::NotFound(e.to_string()))
+    }
+
     async fn decrement(&self) -> Result<u64, TransactionCounterError> {
         self.store
             .decrement(&self.relayer_id, &self.address)
@@ -70,6 +83,17 @@ where
             .await
             .map_err(|e| TransactionCounterError::NotFound(e.to_string()))
     }
+
+    async fn compare_and_set(
+        &self,
+        expected: u64,
+        value: u64,
+    ) -> Result<bool, TransactionCounterError> {
+        self.store
+            .compare_and_set(&self.relayer_id, &self.address, expected, value)
+            .await
+            .map_err(|e| TransactionCounterError::NotFound(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -90,4 +114,27 @@ mod tests {
         assert!(service.set(10).await.is_ok());
         assert_eq!(service.get().await.unwrap(), Some(10));
     }
+
+    #[tokio::test]
+    async fn test_transaction_counter_get_and_increment_by() {
+        let store = Arc::new(InMemoryTransactionCounter::default());
+        let service =
+            TransactionCounterService::new("relayer_id".to_string(), "address".to_string(), store);
+
+        assert_eq!(service.get_and_increment().await.unwrap(), 0);
+        assert_eq!(service.get_and_increment_by(3).await.unwrap(), 1);
+        assert_eq!(service.get_and_increment().await.unwrap(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_counter_compare_and_set() {
+        let store = Arc::new(InMemoryTransactionCounter::default());
+        let service =
+            TransactionCounterService::new("relayer_id".to_string(), "address".to_string(), store);
+
+        assert_eq!(service.get_and_increment_by(3).await.unwrap(), 0);
+        assert!(!service.compare_and_set(2, 0).await.unwrap());
+        assert!(service.compare_and_set(3, 0).await.unwrap());
+        assert_eq!(service.get().await.unwrap(), Some(0));
+    }
 }
@@ -1,41 +1,211 @@
-use actix_web::dev::ServiceRequest;
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::Method,
+    middleware::Next,
+    web::{self, ThinData},
+    HttpMessage, HttpResponse, ResponseError,
+};
+use log::{debug, error};
 
 use crate::{
-    constants::{AUTHORIZATION_HEADER_NAME, AUTHORIZATION_HEADER_VALUE_PREFIX},
-    models::SecretString,
+    config::ServerConfig,
+    constants::{AUTHORIZATION_HEADER_NAME, AUTHORIZATION_HEADER_VALUE_PREFIX, PUBLIC_ENDPOINTS},
+    models::{ApiError, ApiKeyIdentity, ApiKeyScope, DefaultAppState, SecretString},
+    repositories::ApiKeyRepository,
 };
 
+/// Prefix of the routes served by the REST API
+const API_PATH_PREFIX: &str = "/api/v1";
+
+/// Response body returned when a request cannot be authenticated
+const UNAUTHORIZED_RESPONSE_BODY: &str =
+    r#"{"success": false, "code":401, "error": "Unauthorized", "message": "Unauthorized"}"#;
+
+/// Extracts the bearer token from the authorization header of the request.
+///
+/// Returns `None` unless the request has exactly one authorization header holding a
+/// non-empty token after the expected prefix (e.g., "Bearer ").
+pub fn extract_bearer_token(req: &ServiceRequest) -> Option<SecretString> {
+    // Ensure there is exactly one Authorization header
+    let headers: Vec<_> = req.headers().get_all(AUTHORIZATION_HEADER_NAME).collect();
+    if headers.len() != 1 {
+        return None;
+    }
+
+    let key = headers[0].to_str().ok()?;
+    let token = key.strip_prefix(AUTHORIZATION_HEADER_VALUE_PREFIX)?;
+
+    if token.is_empty() || token.contains(' ') {
+        return None;
+    }
+
+    Some(SecretString::new(token))
+}
+
 /// Checks if the authorization header in the request matches the expected API key.
 ///
 /// This function extracts the authorization header from the request, verifies that it starts
 /// with the expected prefix (e.g., "Bearer "), and then compares the remaining part of the header
 /// value with the expected API key.
 pub fn check_authorization_header(req: &ServiceRequest, expected_key: &SecretString) -> bool {
-    // Ensure there is exactly one Authorization header
-    let headers: Vec<_> = req.headers().get_all(AUTHORIZATION_HEADER_NAME).collect();
-    if headers.len() != 1 {
-        return false;
-    }
+    extract_bearer_token(req).is_some_and(|token| &token == expected_key)
+}
 
-    if let Ok(key) = headers[0].to_str() {
-        if !key.starts_with(AUTHORIZATION_HEADER_VALUE_PREFIX) {
-            return false;
+/// Returns true if the path is served without authentication.
+pub fn is_public_endpoint(path: &str) -> bool {
+    PUBLIC_ENDPOINTS
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+}
+
+/// Splits a request path into its segments below the API prefix.
+fn api_path_segments(path: &str) -> Vec<&str> {
+    path.strip_prefix(API_PATH_PREFIX)
+        .unwrap_or(path)
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// Returns the scope required to call the endpoint at `path` with `method`.
+///
+/// API key management always requires `admin`. Other read-only requests require `read`.
+/// Submitting and managing transactions (directly or through a relayer pool), forwarding
+/// JSON-RPC calls and calling plugins require `send-transactions`, the signing endpoints
+/// require `sign`, and every other mutation (relayers, pools, signers, networks,
+/// notifications) requires `admin`.
+pub fn required_scope(method: &Method, path: &str) -> ApiKeyScope {
+    let segments = api_path_segments(path);
+
+    match segments.as_slice() {
+        ["api-keys", ..] => ApiKeyScope::Admin,
+        _ if method == Method::GET || method == Method::HEAD => ApiKeyScope::Read,
+        ["relayers", _, "transactions", ..] | ["relayers", _, "rpc"] => {
+            ApiKeyScope::SendTransactions
         }
-        let prefix_len = AUTHORIZATION_HEADER_VALUE_PREFIX.len();
-        let token = &key[prefix_len..];
+        ["relayers", _, "sign" | "sign-typed-data" | "sign-transaction"] => ApiKeyScope::Sign,
+        ["plugins", _, "call"] | ["pools", _, "transactions"] => ApiKeyScope::SendTransactions,
+        _ => ApiKeyScope::Admin,
+    }
+}
+
+/// Checks that the identity may call the endpoint at `path` with `method`.
+///
+/// Identities limited to a set of relayers may only call endpoints of those relayers and
+/// the health check.
+pub fn authorize_request(
+    identity: &ApiKeyIdentity,
+    method: &Method,
+    path: &str,
+) -> Result<(), ApiError> {
+    let scope = required_scope(method, path);
+    if !identity.has_scope(scope) {
+        return Err(ApiError::ForbiddenError(format!(
+            "API key is missing the {} scope",
+            scope
+        )));
+    }
+
+    if identity.is_unrestricted() {
+        return Ok(());
+    }
+
+    match api_path_segments(path).as_slice() {
+        ["health"] => Ok(()),
+        ["relayers", relayer_id, ..] if identity.can_access_relayer(relayer_id) => Ok(()),
+        _ => Err(ApiError::ForbiddenError(
+            "API key is not allowed to access this resource".to_string(),
+        )),
+    }
+}
+
+/// Resolves the identity of the caller from the bearer token of the request.
+///
+/// The root key configured through `API_KEY` resolves to an unrestricted admin identity;
+/// any other token must match an API key stored in the repository.
+pub async fn resolve_api_key_identity<AKR: ApiKeyRepository>(
+    req: &ServiceRequest,
+    root_key: &SecretString,
+    api_key_repository: &AKR,
+) -> Result<ApiKeyIdentity, ApiError> {
+    let token = extract_bearer_token(req)
+        .ok_or_else(|| ApiError::Unauthorized("Unauthorized".to_string()))?;
+
+    if &token == root_key {
+        return Ok(ApiKeyIdentity::root());
+    }
 
-        if token.is_empty() || token.contains(' ') {
-            return false;
+    match api_key_repository.get_by_value(&token).await {
+        Ok(Some(api_key)) => Ok(ApiKeyIdentity::from(api_key)),
+        Ok(None) => Err(ApiError::Unauthorized("Unauthorized".to_string())),
+        Err(e) => {
+            error!("Failed to look up API key: {}", e);
+            Err(ApiError::InternalError(
+                "Failed to authenticate request".to_string(),
+            ))
         }
+    }
+}
 
-        return &SecretString::new(token) == expected_key;
+/// Authenticates and authorizes a request.
+///
+/// Returns the identity of the caller if it may call the requested endpoint.
+pub async fn authenticate_request<AKR: ApiKeyRepository>(
+    req: &ServiceRequest,
+    root_key: &SecretString,
+    api_key_repository: &AKR,
+) -> Result<ApiKeyIdentity, ApiError> {
+    let identity = resolve_api_key_identity(req, root_key, api_key_repository).await?;
+    authorize_request(&identity, req.method(), req.path())?;
+    Ok(identity)
+}
+
+/// Middleware authenticating every non-public request against the configured API keys.
+///
+/// The resolved [`ApiKeyIdentity`] is stored in the request extensions, where the rate
+/// limiter and handlers can read it.
+pub async fn api_key_auth_middleware<B: MessageBody>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, actix_web::Error> {
+    if is_public_endpoint(req.path()) {
+        return next.call(req).await.map(|res| res.map_into_left_body());
+    }
+
+    let config = req.app_data::<web::Data<ServerConfig>>().cloned();
+    let state = req.app_data::<ThinData<DefaultAppState>>().cloned();
+    let (Some(config), Some(state)) = (config, state) else {
+        error!("Server configuration or application state missing from app data");
+        let response =
+            ApiError::InternalError("Failed to authenticate request".to_string()).error_response();
+        return Ok(req.into_response(response).map_into_right_body());
+    };
+
+    let api_key_repository = state.api_key_repository();
+    match authenticate_request(&req, &config.api_key, api_key_repository.as_ref()).await {
+        Ok(identity) => {
+            req.extensions_mut().insert(identity);
+            next.call(req).await.map(|res| res.map_into_left_body())
+        }
+        Err(ApiError::Unauthorized(_)) => {
+            let response = HttpResponse::Unauthorized().body(UNAUTHORIZED_RESPONSE_BODY);
+            Ok(req.into_response(response).map_into_right_body())
+        }
+        Err(e) => {
+            debug!("Rejected request to {}: {}", req.path(), e);
+            Ok(req.into_response(e.error_response()).map_into_right_body())
+        }
     }
-    false
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        models::ApiKeyRepoModel,
+        repositories::{ApiKeyRepositoryStorage, Repository},
+    };
     use actix_web::test::TestRequest;
 
     #[test]
@@ -144,4 +314,224 @@ mod tests {
 
         assert!(!check_authorization_header(&req, &SecretString::new("")));
     }
+
+    fn scoped_identity(scopes: Vec<ApiKeyScope>, allowed_relayers: Vec<&str>) -> ApiKeyIdentity {
+        ApiKeyIdentity {
+            id: "key-1".to_string(),
+            scopes,
+            allowed_relayers: allowed_relayers.into_iter().map(String::from).collect(),
+        }
+    }
+
+    fn bearer_request(token: &str) -> ServiceRequest {
+        TestRequest::default()
+            .insert_header((
+                AUTHORIZATION_HEADER_NAME,
+                format!("{}{}", AUTHORIZATION_HEADER_VALUE_PREFIX, token),
+            ))
+            .to_srv_request()
+    }
+
+    #[test]
+    fn test_extract_bearer_token() {
+        let req = bearer_request("test_key");
+        assert_eq!(
+            extract_bearer_token(&req),
+            Some(SecretString::new("test_key"))
+        );
+
+        let req = TestRequest::default().to_srv_request();
+        assert!(extract_bearer_token(&req).is_none());
+    }
+
+    #[test]
+    fn test_required_scope() {
+        let cases = [
+            (Method::GET, "/api/v1/relayers", ApiKeyScope::Read),
+            (
+                Method::GET,
+                "/api/v1/relayers/r1/transactions",
+                ApiKeyScope::Read,
+            ),
+            (
+                Method::POST,
+                "/api/v1/relayers/r1/transactions",
+                ApiKeyScope::SendTransactions,
+            ),
+            (
+                Method::PUT,
+                "/api/v1/relayers/r1/transactions/tx1",
+                ApiKeyScope::SendTransactions,
+            ),
+            (
+                Method::DELETE,
+                "/api/v1/relayers/r1/transactions/pending",
+                ApiKeyScope::SendTransactions,
+            ),
+            (
+                Method::POST,
+                "/api/v1/relayers/r1/rpc",
+                ApiKeyScope::SendTransactions,
+            ),
+            (Method::POST, "/api/v1/relayers/r1/sign", ApiKeyScope::Sign),
+            (
+                Method::POST,
+                "/api/v1/relayers/r1/sign-typed-data",
+                ApiKeyScope::Sign,
+            ),
+            (
+                Method::POST,
+                "/api/v1/relayers/r1/sign-transaction",
+                ApiKeyScope::Sign,
+            ),
+            (
+                Method::POST,
+                "/api/v1/plugins/p1/call",
+                ApiKeyScope::SendTransactions,
+            ),
+            (
+                Method::POST,
+                "/api/v1/pools/p1/transactions",
+                ApiKeyScope::SendTransactions,
+            ),
+            (Method::POST, "/api/v1/relayers", ApiKeyScope::Admin),
+            (Method::PATCH, "/api/v1/relayers/r1", ApiKeyScope::Admin),
+            (Method::PATCH, "/api/v1/pools/p1", ApiKeyScope::Admin),
+            (Method::DELETE, "/api/v1/signers/s1", ApiKeyScope::Admin),
+            (
+                Method::POST,
+                "/api/v1/relayers/r1/signer-rotations",
+                ApiKeyScope::Admin,
+            ),
+            (Method::GET, "/api/v1/api-keys", ApiKeyScope::Admin),
+            (Method::POST, "/api/v1/api-keys", ApiKeyScope::Admin),
+        ];
+
+        for (method, path, expected) in cases {
+            assert_eq!(
+                required_scope(&method, path),
+                expected,
+                "{} {}",
+                method,
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_authorize_request_checks_scope() {
+        let identity = scoped_identity(vec![ApiKeyScope::Read], vec![]);
+
+        assert!(authorize_request(&identity, &Method::GET, "/api/v1/relayers").is_ok());
+        assert!(matches!(
+            authorize_request(&identity, &Method::POST, "/api/v1/relayers/r1/transactions"),
+            Err(ApiError::ForbiddenError(_))
+        ));
+    }
+
+    #[test]
+    fn test_authorize_request_admin_implies_all_scopes() {
+        let identity = scoped_identity(vec![ApiKeyScope::Admin], vec![]);
+
+        assert!(authorize_request(&identity, &Method::POST, "/api/v1/relayers/r1/sign").is_ok());
+        assert!(authorize_request(&identity, &Method::DELETE, "/api/v1/api-keys/k1").is_ok());
+    }
+
+    #[test]
+    fn test_authorize_request_checks_allowed_relayers() {
+        let identity = scoped_identity(
+            vec![ApiKeyScope::Read, ApiKeyScope::SendTransactions],
+            vec!["r1"],
+        );
+
+        assert!(
+            authorize_request(&identity, &Method::POST, "/api/v1/relayers/r1/transactions").is_ok()
+        );
+        assert!(matches!(
+            authorize_request(&identity, &Method::POST, "/api/v1/relayers/r2/transactions"),
+            Err(ApiError::ForbiddenError(_))
+        ));
+        // Endpoints not tied to an allowed relayer are rejected
+        assert!(matches!(
+            authorize_request(&identity, &Method::GET, "/api/v1/relayers"),
+            Err(ApiError::ForbiddenError(_))
+        ));
+        assert!(matches!(
+            authorize_request(&identity, &Method::POST, "/api/v1/plugins/p1/call"),
+            Err(ApiError::ForbiddenError(_))
+        ));
+        assert!(authorize_request(&identity, &Method::GET, "/api/v1/health").is_ok());
+    }
+
+    #[test]
+    fn test_authorize_request_restricted_admin_cannot_manage_api_keys() {
+        let identity = scoped_identity(vec![ApiKeyScope::Admin], vec!["r1"]);
+
+        assert!(matches!(
+            authorize_request(&identity, &Method::POST, "/api/v1/api-keys"),
+            Err(ApiError::ForbiddenError(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_resolve_api_key_identity() {
+        let repository = ApiKeyRepositoryStorage::new_in_memory();
+        repository
+            .create(ApiKeyRepoModel {
+                id: "key-1".to_string(),
+                name: "ci".to_string(),
+                value: SecretString::new("stored_key"),
+                scopes: vec![ApiKeyScope::Sign],
+                allowed_relayers: vec!["r1".to_string()],
+                created_at: "2025-01-01T00:00:00Z".to_string(),
+            })
+            .await
+            .unwrap();
+        let root_key = SecretString::new("root_key");
+
+        let identity =
+            resolve_api_key_identity(&bearer_request("root_key"), &root_key, &repository)
+                .await
+                .unwrap();
+        assert_eq!(identity, ApiKeyIdentity::root());
+
+        let identity =
+            resolve_api_key_identity(&bearer_request("stored_key"), &root_key, &repository)
+                .await
+                .unwrap();
+        assert_eq!(identity.id, "key-1");
+        assert_eq!(identity.scopes, vec![ApiKeyScope::Sign]);
+        assert_eq!(identity.allowed_relayers, vec!["r1".to_string()]);
+
+        let result =
+            resolve_api_key_identity(&bearer_request("unknown_key"), &root_key, &repository).await;
+        assert!(matches!(result, Err(ApiError::Unauthorized(_))));
+    }
+
+    #[actix_web::test]
+    async fn test_authenticate_request_forbidden() {
+        let repository = ApiKeyRepositoryStorage::new_in_memory();
+        repository
+            .create(ApiKeyRepoModel {
+                id: "key-1".to_string(),
+                name: "reader".to_string(),
+                value: SecretString::new("stored_key"),
+                scopes: vec![ApiKeyScope::Read],
+                allowed_relayers: vec![],
+                created_at: "2025-01-01T00:00:00Z".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let req = TestRequest::post()
+            .uri("/api/v1/relayers/r1/transactions")
+            .insert_header((
+                AUTHORIZATION_HEADER_NAME,
+                format!("{}{}", AUTHORIZATION_HEADER_VALUE_PREFIX, "stored_key"),
+            ))
+            .to_srv_request();
+
+        let result = authenticate_request(&req, &SecretString::new("root_key"), &repository).await;
+        assert!(matches!(result, Err(ApiError::ForbiddenError(_))));
+    }
 }
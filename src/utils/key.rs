@@ -7,6 +7,14 @@ pub fn unsafe_generate_random_private_key() -> Vec<u8> {
     pk
 }
 
+/// Generates a random API key value: 32 random bytes, hex encoded.
+pub fn generate_api_key_value() -> String {
+    let mut rng = rand::rng();
+    let mut bytes = [0u8; 32];
+    rng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -35,4 +43,14 @@ mod tests {
             "Private key should not be all zeros"
         );
     }
+
+    #[test]
+    fn test_generate_api_key_value() {
+        let first = generate_api_key_value();
+        let second = generate_api_key_value();
+
+        assert_eq!(first.len(), 64);
+        assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(first, second);
+    }
 }
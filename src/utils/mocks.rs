@@ -20,8 +20,9 @@ pub mod mockutils {
             SignerRepoModel, SolanaTransactionData, TransactionRepoModel, TransactionStatus,
         },
         repositories::{
-            NetworkRepositoryStorage, NotificationRepositoryStorage, PluginRepositoryStorage,
-            PluginRepositoryTrait, RelayerRepositoryStorage, Repository, SignerRepositoryStorage,
+            ApiKeyRepositoryStorage, NetworkRepositoryStorage, NotificationRepositoryStorage,
+            PluginRepositoryStorage, PluginRepositoryTrait, RelayerPoolRepositoryStorage,
+            RelayerRepositoryStorage, Repository, SignerRepositoryStorage,
             TransactionCounterRepositoryStorage, TransactionRepositoryStorage,
         },
     };
@@ -42,6 +43,7 @@ pub mod mockutils {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -57,6 +59,16 @@ pub mod mockutils {
                 whitelist_receivers: None,
                 eip1559_pricing: Some(false),
                 private_transactions: Some(false),
+                simulate_transactions: None,
+                access_list_generation: None,
+                entry_point: None,
+                max_value_per_transaction: None,
+                max_total_value: None,
+                max_gas_spend: None,
+                spending_window: None,
+                allowed_selectors: None,
+                trusted_forwarder: None,
+                authorization_signers: None,
                 min_balance: Some(0),
                 gas_limit_estimation: Some(false),
             }),
@@ -65,6 +77,7 @@ pub mod mockutils {
             notification_id: None,
             system_disabled: false,
             custom_rpc_urls: None,
+            signer_rotations: vec![],
         }
     }
 
@@ -106,6 +119,8 @@ pub mod mockutils {
                 symbol: Some("testETH".to_string()),
                 chain_id: Some(1),
                 gas_price_cache: None,
+                private_relay: None,
+                gas_oracle: None,
             }),
         }
     }
@@ -146,6 +161,10 @@ pub mod mockutils {
             network_type: NetworkType::Evm,
             noop_count: None,
             is_canceled: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -168,6 +187,10 @@ pub mod mockutils {
             network_type: NetworkType::Solana,
             noop_count: None,
             is_canceled: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
             delete_at: None,
         }
     }
@@ -187,6 +210,8 @@ pub mod mockutils {
         SignerRepositoryStorage,
         TransactionCounterRepositoryStorage,
         PluginRepositoryStorage,
+        ApiKeyRepositoryStorage,
+        RelayerPoolRepositoryStorage,
     > {
         let relayer_repository = Arc::new(RelayerRepositoryStorage::new_in_memory());
         if let Some(relayers) = relayers {
@@ -256,6 +281,8 @@ pub mod mockutils {
             ),
             job_producer: Arc::new(mock_job_producer),
             plugin_repository,
+            api_key_repository: Arc::new(ApiKeyRepositoryStorage::new_in_memory()),
+            relayer_pool_repository: Arc::new(RelayerPoolRepositoryStorage::new_in_memory()),
         }
     }
 
@@ -270,6 +297,13 @@ pub mod mockutils {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         }
     }
 
@@ -282,6 +316,8 @@ pub mod mockutils {
             api_key: SecretString::new("test_api_key_1234567890_test_key_32"),
             rate_limit_requests_per_second: 100,
             rate_limit_burst_size: 300,
+            client_rate_limit_requests_per_second: 1000,
+            client_rate_limit_burst_size: 3000,
             metrics_port: 8081,
             enable_swagger: false,
             redis_connection_timeout_ms: 5000,
@@ -297,6 +333,7 @@ pub mod mockutils {
                 "test_encryption_key_1234567890_test_key_32",
             )),
             transaction_expiration_hours: 4,
+            idempotency_window_seconds: 86400,
         }
     }
 }
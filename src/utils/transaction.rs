@@ -1,9 +1,10 @@
 use crate::constants::{
     COMPLEX_GAS_LIMIT, DEFAULT_GAS_LIMIT, DEFAULT_TRANSACTION_SPEED, ERC20_TRANSFER_GAS_LIMIT,
-    ERC721_TRANSFER_GAS_LIMIT, GAS_TX_CREATE_CONTRACT, GAS_TX_DATA_NONZERO, GAS_TX_DATA_ZERO,
+    ERC721_TRANSFER_GAS_LIMIT, GAS_PER_ACCESS_LIST_ADDRESS, GAS_PER_ACCESS_LIST_STORAGE_KEY,
+    GAS_PER_AUTHORIZATION, GAS_TX_CREATE_CONTRACT, GAS_TX_DATA_NONZERO, GAS_TX_DATA_ZERO,
 };
 use crate::models::evm::Speed;
-use crate::models::{EvmTransactionData, EvmTransactionRequest};
+use crate::models::{EvmAccessListItem, EvmTransactionData, EvmTransactionRequest};
 use crate::utils::time::minutes_ms;
 
 /// Gets the resubmit timeout for a given speed
@@ -48,6 +49,15 @@ pub fn get_resubmit_timeout_with_backoff(timeout: i64, attempts: usize) -> i64 {
 /// # Returns
 /// The default gas limit for the transaction
 pub fn get_evm_default_gas_limit_for_tx(tx: &EvmTransactionData) -> u64 {
+    let authorization_gas = tx
+        .authorization_list
+        .as_ref()
+        .map_or(0, |list| list.len() as u64 * GAS_PER_AUTHORIZATION);
+
+    authorization_gas + access_list_gas(tx.access_list.as_deref()) + default_gas_limit_for_data(tx)
+}
+
+fn default_gas_limit_for_data(tx: &EvmTransactionData) -> u64 {
     if tx.data.is_none() {
         DEFAULT_GAS_LIMIT
     } else if tx.data.as_ref().unwrap().starts_with("0xa9059cbb") {
@@ -83,7 +93,26 @@ pub fn calculate_intrinsic_gas(tx: &EvmTransactionRequest) -> u64 {
         None => 0,
     };
 
-    base_gas + data_gas
+    let authorization_gas = tx
+        .authorization_list
+        .as_ref()
+        .map_or(0, |list| list.len() as u64 * GAS_PER_AUTHORIZATION);
+
+    base_gas + data_gas + authorization_gas + access_list_gas(tx.access_list.as_deref())
+}
+
+/// Calculates the intrinsic gas charged for an EIP-2930 access list
+///
+/// # Arguments
+/// * `access_list` - The access list entries, if any
+///
+/// # Returns
+/// The gas for the access list
+fn access_list_gas(access_list: Option<&[EvmAccessListItem]>) -> u64 {
+    access_list.unwrap_or_default().iter().fold(0, |gas, item| {
+        gas + GAS_PER_ACCESS_LIST_ADDRESS
+            + item.storage_keys.len() as u64 * GAS_PER_ACCESS_LIST_STORAGE_KEY
+    })
 }
 
 /// Calculates the gas for a given transaction data
@@ -175,6 +204,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         assert_eq!(get_evm_default_gas_limit_for_tx(&tx), DEFAULT_GAS_LIMIT);
@@ -197,6 +236,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         assert_eq!(
@@ -222,6 +271,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         assert_eq!(
@@ -247,6 +306,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         assert_eq!(get_evm_default_gas_limit_for_tx(&tx), COMPLEX_GAS_LIMIT);
@@ -269,6 +338,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         assert_eq!(get_evm_default_gas_limit_for_tx(&tx), COMPLEX_GAS_LIMIT);
@@ -291,6 +370,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         assert_eq!(get_evm_default_gas_limit_for_tx(&tx), COMPLEX_GAS_LIMIT);
@@ -314,6 +403,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         // Should still match ERC20 transfer since it starts with the signature
@@ -341,6 +440,16 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             raw: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            user_operation_hashes: None,
+            block_number: None,
+            block_hash: None,
+            gas_used: None,
+            effective_gas_price: None,
+            l1_fee: None,
+            logs: None,
         };
 
         // Should not match since the function signature is case-sensitive
@@ -389,11 +498,84 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         assert_eq!(calculate_intrinsic_gas(&tx), DEFAULT_GAS_LIMIT);
     }
 
+    #[test]
+    fn test_calculate_intrinsic_gas_with_authorizations() {
+        let authorization = crate::models::EvmAuthorizationRequest {
+            chain_id: Some(1),
+            address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            nonce: None,
+            y_parity: None,
+            r: None,
+            s: None,
+            signer_id: Some("delegator".to_string()),
+        };
+        let tx = EvmTransactionRequest {
+            to: Some("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()),
+            value: crate::models::U256::from(0u128),
+            data: None,
+            gas_limit: None,
+            gas_price: None,
+            speed: Some(Speed::Average),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: Some(vec![authorization.clone(), authorization]),
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        };
+
+        assert_eq!(
+            calculate_intrinsic_gas(&tx),
+            DEFAULT_GAS_LIMIT + 2 * GAS_PER_AUTHORIZATION
+        );
+    }
+
+    #[test]
+    fn test_calculate_intrinsic_gas_with_access_list() {
+        let tx = EvmTransactionRequest {
+            to: Some("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()),
+            value: crate::models::U256::from(0u128),
+            data: None,
+            gas_limit: None,
+            gas_price: None,
+            speed: Some(Speed::Average),
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: Some(vec![EvmAccessListItem {
+                address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                storage_keys: vec![format!("0x{:064x}", 0), format!("0x{:064x}", 1)],
+            }]),
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
+        };
+
+        assert_eq!(
+            calculate_intrinsic_gas(&tx),
+            DEFAULT_GAS_LIMIT + GAS_PER_ACCESS_LIST_ADDRESS + 2 * GAS_PER_ACCESS_LIST_STORAGE_KEY
+        );
+    }
+
     #[test]
     fn test_calculate_intrinsic_gas_contract_creation_no_data() {
         let tx = EvmTransactionRequest {
@@ -406,6 +588,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         assert_eq!(calculate_intrinsic_gas(&tx), GAS_TX_CREATE_CONTRACT);
@@ -423,6 +612,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let expected_gas = DEFAULT_GAS_LIMIT + 4 * GAS_TX_DATA_NONZERO;
@@ -441,6 +637,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         // 1 zero byte + 3 non-zero bytes
@@ -460,6 +663,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         // 1 zero byte + 3 non-zero bytes
@@ -479,6 +689,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         // Invalid hex should result in 0 data gas
@@ -497,6 +714,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         // Empty data should result in 0 data gas
@@ -515,6 +739,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         let data_bytes = hex::decode("a9059cbb000000000000000000000000742d35cc6634c0532925a3b844bc454e4438f44e0000000000000000000000000000000000000000000000000de0b6b3a7640000").unwrap();
@@ -539,6 +770,13 @@ mod tests {
             max_fee_per_gas: None,
             max_priority_fee_per_gas: None,
             valid_until: None,
+            simulate: None,
+            authorization_list: None,
+            access_list: None,
+            idempotency_key: None,
+            execute_after: None,
+            schedule: None,
+            depends_on: None,
         };
 
         // 1000 non-zero bytes
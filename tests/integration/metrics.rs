@@ -20,6 +20,8 @@ async fn test_authorization_middleware_success() {
         config_file_path: "./config/config.json".to_string(),
         rate_limit_requests_per_second: 10,
         rate_limit_burst_size: 10,
+        client_rate_limit_requests_per_second: 100,
+        client_rate_limit_burst_size: 100,
         enable_swagger: false,
         redis_connection_timeout_ms: 5000,
         redis_key_prefix: "test".to_string(),
@@ -32,6 +34,7 @@ async fn test_authorization_middleware_success() {
         reset_storage_on_start: false,
         storage_encryption_key: None,
         transaction_expiration_hours: 4,
+        idempotency_window_seconds: 86400,
     });
 
     let app = test::init_service(
@@ -78,6 +81,8 @@ async fn test_authorization_middleware_failure() {
         config_file_path: "./config/config.json".to_string(),
         rate_limit_requests_per_second: 10,
         rate_limit_burst_size: 10,
+        client_rate_limit_requests_per_second: 100,
+        client_rate_limit_burst_size: 100,
         enable_swagger: false,
         redis_connection_timeout_ms: 5000,
         redis_key_prefix: "test".to_string(),
@@ -90,6 +95,7 @@ async fn test_authorization_middleware_failure() {
         reset_storage_on_start: false,
         storage_encryption_key: None,
         transaction_expiration_hours: 4,
+        idempotency_window_seconds: 86400,
     });
 
     let app = test::init_service(